@@ -23,6 +23,7 @@ pub mod get_session_details;
 pub mod latest_actor;
 pub mod latest_actor_id;
 pub mod latest_actor_name;
+pub mod search;
 
 pub(crate) fn get_functions() -> Vec<ndc_models::FunctionInfo> {
     vec![
@@ -41,6 +42,7 @@ pub(crate) fn get_functions() -> Vec<ndc_models::FunctionInfo> {
         get_actors_by_movie_id::function_info(),
         get_institutions_by_institution_query::function_info(),
         get_session_details::function_info(),
+        search::function_info(),
     ]
 }
 
@@ -68,6 +70,7 @@ pub(crate) fn get_function_by_name(
             get_institutions_by_institution_query::rows(arguments, state)
         }
         "get_session_details" => get_session_details::rows(arguments),
+        "search" => search::rows(arguments, state),
         _ => Err((
             StatusCode::BAD_REQUEST,
             Json(ndc_models::ErrorResponse {
@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+
+use axum::{http::StatusCode, Json};
+use ndc_models;
+
+use crate::{
+    arguments::{check_all_arguments_used, parse_string_argument},
+    query::Result,
+    state::{AppState, Row},
+};
+
+pub(crate) fn function_info() -> ndc_models::FunctionInfo {
+    ndc_models::FunctionInfo {
+        name: "search".into(),
+        description: Some("Search actors by name and movies by title".into()),
+        arguments: BTreeMap::from_iter([(
+            "query".into(),
+            ndc_models::ArgumentInfo {
+                description: Some("the text that names or titles must contain".into()),
+                argument_type: ndc_models::Type::Named {
+                    name: "String".into(),
+                },
+            },
+        )]),
+        result_type: ndc_models::Type::Array {
+            element_type: Box::new(ndc_models::Type::Named {
+                name: "search_result".into(),
+            }),
+        },
+    }
+}
+
+pub(crate) fn rows(
+    arguments: &BTreeMap<ndc_models::ArgumentName, serde_json::Value>,
+    state: &AppState,
+) -> Result<Vec<Row>> {
+    let mut arguments = arguments
+        .iter()
+        .map(|(k, v)| (k.clone(), v))
+        .collect::<BTreeMap<_, _>>();
+    let query = parse_string_argument("query", &mut arguments)?;
+    check_all_arguments_used(&arguments)?;
+
+    let mut results = vec![];
+    for actor in state.actors.values() {
+        let name = get_string_field(actor, "name")?;
+        if name.contains(query) {
+            results.push(serde_json::json!({
+                "kind": "actor",
+                "id": actor.get("id"),
+                "name": name,
+                "title": null,
+            }));
+        }
+    }
+    for movie in state.movies.values() {
+        let title = get_string_field(movie, "title")?;
+        if title.contains(query) {
+            results.push(serde_json::json!({
+                "kind": "movie",
+                "id": movie.get("id"),
+                "name": null,
+                "title": title,
+            }));
+        }
+    }
+
+    Ok(vec![BTreeMap::from_iter([(
+        "__value".into(),
+        serde_json::Value::Array(results),
+    )])])
+}
+
+fn get_string_field<'a>(row: &'a Row, field_name: &str) -> Result<&'a str> {
+    row.get(field_name)
+        .and_then(serde_json::Value::as_str)
+        .ok_or_else(|| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ndc_models::ErrorResponse {
+                    message: format!("{field_name} not found"),
+                    details: serde_json::Value::Null,
+                }),
+            )
+        })
+}
@@ -3,12 +3,18 @@ use std::collections::BTreeMap;
 use axum::{http::StatusCode, Json};
 use ndc_models;
 
-use crate::{query::Result, state::AppState};
+use crate::{
+    query::{eval_expression, eval_nested_field, Result},
+    state::{AppState, Row},
+};
 
 pub mod add_movie_with_genres;
+pub mod delete_actors;
+pub mod insert_actors;
 pub mod login;
 pub mod noop_procedure;
 pub mod update_actor_name_by_id;
+pub mod update_actors;
 pub mod uppercase_actor_name_by_id;
 pub mod uppercase_all_actor_names;
 pub mod uppercase_all_actor_names_return_names_list;
@@ -24,6 +30,9 @@ pub(crate) fn get_procedures() -> Vec<ndc_models::ProcedureInfo> {
         login::procedure_info(),
         noop_procedure::procedure_info(),
         add_movie_with_genres::procedure_info(),
+        insert_actors::procedure_info(),
+        update_actors::procedure_info(),
+        delete_actors::procedure_info(),
     ]
 }
 
@@ -53,6 +62,15 @@ pub(crate) fn execute_procedure(
         "add_movie_with_genres" => {
             add_movie_with_genres::execute(arguments, fields, collection_relationships, state)
         }
+        "insert_actors" => {
+            insert_actors::execute(arguments, fields, collection_relationships, state)
+        }
+        "update_actors" => {
+            update_actors::execute(arguments, fields, collection_relationships, state)
+        }
+        "delete_actors" => {
+            delete_actors::execute(arguments, fields, collection_relationships, state)
+        }
         _ => Err((
             StatusCode::BAD_REQUEST,
             Json(ndc_models::ErrorResponse {
@@ -62,3 +80,64 @@ pub(crate) fn execute_procedure(
         )),
     }
 }
+
+/// Checks that an actor affected by a mutation satisfies its `post_check` predicate
+pub(crate) fn check_actor_post_check(
+    collection_relationships: &BTreeMap<ndc_models::RelationshipName, ndc_models::Relationship>,
+    state: &AppState,
+    post_check: &ndc_models::Expression,
+    row: &Row,
+) -> Result<()> {
+    if eval_expression(
+        collection_relationships,
+        &BTreeMap::new(),
+        state,
+        post_check,
+        row,
+        row,
+    )? {
+        Ok(())
+    } else {
+        Err((
+            StatusCode::CONFLICT,
+            Json(ndc_models::ErrorResponse {
+                message: "post_check failed".into(),
+                details: serde_json::Value::Null,
+            }),
+        ))
+    }
+}
+
+/// The result of the mutations of actors, with the number of affected actors and the affected
+/// actors
+pub(crate) fn actor_mutation_response(
+    affected_rows: &[Row],
+    fields: Option<&ndc_models::NestedField>,
+    collection_relationships: &BTreeMap<ndc_models::RelationshipName, ndc_models::Relationship>,
+    state: &AppState,
+) -> Result<serde_json::Value> {
+    let returning = serde_json::to_value(affected_rows).map_err(|_| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ndc_models::ErrorResponse {
+                message: "cannot encode response".into(),
+                details: serde_json::Value::Null,
+            }),
+        )
+    })?;
+    let response = serde_json::json!({
+        "affected_rows": affected_rows.len(),
+        "returning": returning,
+    });
+    match fields {
+        None => Ok(response),
+        Some(nested_field) => Ok(eval_nested_field(
+            collection_relationships,
+            &BTreeMap::new(),
+            state,
+            response,
+            nested_field,
+        )?
+        .0),
+    }
+}
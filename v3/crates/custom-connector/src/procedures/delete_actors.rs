@@ -0,0 +1,61 @@
+use std::collections::BTreeMap;
+
+use ndc_models;
+
+use crate::{
+    arguments::{check_all_arguments_used, parse_expression_argument},
+    procedures::actor_mutation_response,
+    query::{eval_expression, Result},
+    state::AppState,
+};
+
+pub(crate) fn procedure_info() -> ndc_models::ProcedureInfo {
+    ndc_models::ProcedureInfo {
+        name: "delete_actors".into(),
+        description: Some("Delete the actors matching a predicate".into()),
+        arguments: BTreeMap::from_iter([(
+            "pre_check".into(),
+            ndc_models::ArgumentInfo {
+                description: Some("Select the actors to delete".into()),
+                argument_type: ndc_models::Type::Predicate {
+                    object_type_name: "actor".into(),
+                },
+            },
+        )]),
+        result_type: ndc_models::Type::Named {
+            name: "actor_mutation_response".into(),
+        },
+    }
+}
+
+pub(crate) fn execute(
+    arguments: &BTreeMap<ndc_models::ArgumentName, serde_json::Value>,
+    fields: Option<&ndc_models::NestedField>,
+    collection_relationships: &BTreeMap<ndc_models::RelationshipName, ndc_models::Relationship>,
+    state: &mut AppState,
+) -> Result<serde_json::Value> {
+    let mut arguments = arguments
+        .iter()
+        .map(|(k, v)| (k.clone(), v))
+        .collect::<BTreeMap<_, _>>();
+    let pre_check = parse_expression_argument("pre_check", &mut arguments)?;
+    check_all_arguments_used(&arguments)?;
+
+    let mut deleted_rows = vec![];
+    let current_state = state.actors.clone();
+    for (actor_id, actor) in &current_state {
+        if eval_expression(
+            collection_relationships,
+            &BTreeMap::new(),
+            state,
+            &pre_check,
+            actor,
+            actor,
+        )? {
+            state.actors.remove(actor_id);
+            deleted_rows.push(actor.clone());
+        }
+    }
+
+    actor_mutation_response(&deleted_rows, fields, collection_relationships, state)
+}
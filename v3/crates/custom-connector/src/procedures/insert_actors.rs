@@ -0,0 +1,84 @@
+use std::collections::BTreeMap;
+
+use axum::{http::StatusCode, Json};
+use ndc_models;
+
+use crate::{
+    arguments::{check_all_arguments_used, parse_expression_argument, parse_object_array_argument},
+    procedures::{actor_mutation_response, check_actor_post_check},
+    query::Result,
+    state::{AppState, Row},
+    types::actor::get_actor_id,
+};
+
+pub(crate) fn procedure_info() -> ndc_models::ProcedureInfo {
+    ndc_models::ProcedureInfo {
+        name: "insert_actors".into(),
+        description: Some("Insert actors".into()),
+        arguments: BTreeMap::from_iter([
+            (
+                "objects".into(),
+                ndc_models::ArgumentInfo {
+                    description: Some("The actors to insert".into()),
+                    argument_type: ndc_models::Type::Array {
+                        element_type: Box::new(ndc_models::Type::Named {
+                            name: "actor".into(),
+                        }),
+                    },
+                },
+            ),
+            (
+                "post_check".into(),
+                ndc_models::ArgumentInfo {
+                    description: Some("Validate if the inserted actors are allowed".into()),
+                    argument_type: ndc_models::Type::Predicate {
+                        object_type_name: "actor".into(),
+                    },
+                },
+            ),
+        ]),
+        result_type: ndc_models::Type::Named {
+            name: "actor_mutation_response".into(),
+        },
+    }
+}
+
+pub(crate) fn execute(
+    arguments: &BTreeMap<ndc_models::ArgumentName, serde_json::Value>,
+    fields: Option<&ndc_models::NestedField>,
+    collection_relationships: &BTreeMap<ndc_models::RelationshipName, ndc_models::Relationship>,
+    state: &mut AppState,
+) -> Result<serde_json::Value> {
+    let mut arguments = arguments
+        .iter()
+        .map(|(k, v)| (k.clone(), v))
+        .collect::<BTreeMap<_, _>>();
+    let objects = parse_object_array_argument("objects", &mut arguments)?;
+    let post_check = parse_expression_argument("post_check", &mut arguments)?;
+    check_all_arguments_used(&arguments)?;
+
+    let mut inserted_rows = vec![];
+    for actor_obj in objects {
+        let new_row = actor_obj
+            .iter()
+            .map(|(k, v)| (ndc_models::FieldName::from(k.as_str()), v.clone()))
+            .collect::<Row>();
+        let id_int = get_actor_id(&new_row)?;
+        if state.actors.contains_key(&id_int) {
+            return Err((
+                StatusCode::CONFLICT,
+                Json(ndc_models::ErrorResponse {
+                    message: format!("actor with id {id_int} already exists"),
+                    details: serde_json::Value::Null,
+                }),
+            ));
+        }
+        state.actors.insert(id_int, new_row.clone());
+        inserted_rows.push(new_row);
+    }
+
+    for row in &inserted_rows {
+        check_actor_post_check(collection_relationships, state, &post_check, row)?;
+    }
+    actor_mutation_response(&inserted_rows, fields, collection_relationships, state)
+}
@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+
+use ndc_models;
+
+use crate::{
+    arguments::{check_all_arguments_used, parse_expression_argument, parse_object_argument},
+    procedures::{actor_mutation_response, check_actor_post_check},
+    query::{eval_expression, Result},
+    state::AppState,
+};
+
+pub(crate) fn procedure_info() -> ndc_models::ProcedureInfo {
+    ndc_models::ProcedureInfo {
+        name: "update_actors".into(),
+        description: Some("Update the actors matching a predicate".into()),
+        arguments: BTreeMap::from_iter([
+            (
+                "pre_check".into(),
+                ndc_models::ArgumentInfo {
+                    description: Some("Select the actors to update".into()),
+                    argument_type: ndc_models::Type::Predicate {
+                        object_type_name: "actor".into(),
+                    },
+                },
+            ),
+            (
+                "update_columns".into(),
+                ndc_models::ArgumentInfo {
+                    description: Some("The new values of the updated fields".into()),
+                    argument_type: ndc_models::Type::Named {
+                        name: "actor".into(),
+                    },
+                },
+            ),
+            (
+                "post_check".into(),
+                ndc_models::ArgumentInfo {
+                    description: Some("Validate if the updated actors are allowed".into()),
+                    argument_type: ndc_models::Type::Predicate {
+                        object_type_name: "actor".into(),
+                    },
+                },
+            ),
+        ]),
+        result_type: ndc_models::Type::Named {
+            name: "actor_mutation_response".into(),
+        },
+    }
+}
+
+pub(crate) fn execute(
+    arguments: &BTreeMap<ndc_models::ArgumentName, serde_json::Value>,
+    fields: Option<&ndc_models::NestedField>,
+    collection_relationships: &BTreeMap<ndc_models::RelationshipName, ndc_models::Relationship>,
+    state: &mut AppState,
+) -> Result<serde_json::Value> {
+    let mut arguments = arguments
+        .iter()
+        .map(|(k, v)| (k.clone(), v))
+        .collect::<BTreeMap<_, _>>();
+    let pre_check = parse_expression_argument("pre_check", &mut arguments)?;
+    let update_columns = parse_object_argument("update_columns", &mut arguments)?;
+    let post_check = parse_expression_argument("post_check", &mut arguments)?;
+    check_all_arguments_used(&arguments)?;
+
+    let mut updated_rows = vec![];
+    let current_state = state.actors.clone();
+    for (actor_id, actor) in &current_state {
+        if !eval_expression(
+            collection_relationships,
+            &BTreeMap::new(),
+            state,
+            &pre_check,
+            actor,
+            actor,
+        )? {
+            continue;
+        }
+        let mut new_row = actor.clone();
+        for (column, value) in update_columns {
+            new_row.insert(column.as_str().into(), value.clone());
+        }
+        state.actors.insert(*actor_id, new_row.clone());
+        updated_rows.push(new_row);
+    }
+
+    for row in &updated_rows {
+        check_actor_post_check(collection_relationships, state, &post_check, row)?;
+    }
+    actor_mutation_response(&updated_rows, fields, collection_relationships, state)
+}
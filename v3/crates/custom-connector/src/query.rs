@@ -5,7 +5,6 @@ use std::{
 
 use axum::{http::StatusCode, Json};
 use indexmap::IndexMap;
-use itertools::Itertools;
 use ndc_models;
 use regex::Regex;
 
@@ -295,15 +294,22 @@ fn eval_groups(
     grouping: &ndc_models::Grouping,
     paginated: &[Row],
 ) -> Result<Vec<ndc_models::Group>> {
-    let chunks: Vec<Chunk> = paginated
-        .iter()
-        .chunk_by(|row| eval_dimensions(row, &grouping.dimensions).expect("cannot eval dimensions"))
-        .into_iter()
-        .map(|(dimensions, rows)| Chunk {
-            dimensions,
-            rows: rows.cloned().collect(),
-        })
-        .collect();
+    // Every row with the same dimensions belongs to the same group, whether or not the rows are
+    // ordered by the dimensions. The groups keep the order of their first rows.
+    let mut chunks: Vec<Chunk> = vec![];
+    for row in paginated {
+        let dimensions = eval_dimensions(row, &grouping.dimensions)?;
+        match chunks
+            .iter_mut()
+            .find(|chunk| chunk.dimensions == dimensions)
+        {
+            Some(chunk) => chunk.rows.push(row.clone()),
+            None => chunks.push(Chunk {
+                dimensions,
+                rows: vec![row.clone()],
+            }),
+        }
+    }
 
     let sorted = group_sort(chunks, grouping.order_by.as_ref())?;
 
@@ -635,6 +641,13 @@ fn eval_aggregate_function(
     function: &ndc_models::AggregateFunctionName,
     values: &[&serde_json::Value],
 ) -> Result<serde_json::Value> {
+    // The value of the first row is returned as is, even if it is null, so that it can be used
+    // to select the first row of each group
+    if function.as_str() == "first" {
+        return Ok(values
+            .first()
+            .map_or(serde_json::Value::Null, |value| (*value).clone()));
+    }
     if let Some((first_value, _)) = values.split_first() {
         if first_value.is_i64() {
             eval_aggregate_function_i64(function, values)
@@ -1165,7 +1178,13 @@ pub(crate) fn eval_expression(
 
         ndc_models::Expression::UnaryComparisonOperator { column, operator } => match operator {
             ndc_models::UnaryComparisonOperator::IsNull => {
-                let val = eval_comparison_target(column, item)?;
+                let val = eval_comparison_target(
+                    collection_relationships,
+                    variables,
+                    state,
+                    column,
+                    item,
+                )?;
                 Ok(val.is_null())
             }
         },
@@ -1176,7 +1195,13 @@ pub(crate) fn eval_expression(
             value,
         } => match operator.as_str() {
             "_eq" => {
-                let left_val = eval_comparison_target(column, item)?;
+                let left_val = eval_comparison_target(
+                    collection_relationships,
+                    variables,
+                    state,
+                    column,
+                    item,
+                )?;
                 let right_vals =
                     eval_comparison_value(collection_relationships, variables, state, value, item)?;
                 for right_val in &right_vals {
@@ -1187,9 +1212,43 @@ pub(crate) fn eval_expression(
 
                 Ok(false)
             }
+            "_gt" => {
+                let left_val = eval_comparison_target(
+                    collection_relationships,
+                    variables,
+                    state,
+                    column,
+                    item,
+                )?;
+                let right_vals =
+                    eval_comparison_value(collection_relationships, variables, state, value, item)?;
+                for right_val in &right_vals {
+                    let (Some(left_num), Some(right_num)) = (left_val.as_f64(), right_val.as_f64())
+                    else {
+                        return Err((
+                            StatusCode::BAD_REQUEST,
+                            Json(ndc_models::ErrorResponse {
+                                message: "_gt is only supported on numbers".into(),
+                                details: serde_json::Value::Null,
+                            }),
+                        ));
+                    };
+                    if left_num > right_num {
+                        return Ok(true);
+                    }
+                }
+
+                Ok(false)
+            }
             "istarts_with" | "iends_with" | "_contains" | "starts_with" | "ends_with"
             | "_icontains" => {
-                let column_val = eval_comparison_target(column, item)?;
+                let column_val = eval_comparison_target(
+                    collection_relationships,
+                    variables,
+                    state,
+                    column,
+                    item,
+                )?;
                 let column_str = column_val.as_str().ok_or((
                     StatusCode::BAD_REQUEST,
                     Json(ndc_models::ErrorResponse {
@@ -1229,7 +1288,13 @@ pub(crate) fn eval_expression(
                 Ok(false)
             }
             "like" => {
-                let column_val = eval_comparison_target(column, item)?;
+                let column_val = eval_comparison_target(
+                    collection_relationships,
+                    variables,
+                    state,
+                    column,
+                    item,
+                )?;
                 let column_str = column_val.as_str().ok_or((
                     StatusCode::BAD_REQUEST,
                     Json(ndc_models::ErrorResponse {
@@ -1421,6 +1486,9 @@ fn eval_in_collection(
 }
 
 fn eval_comparison_target(
+    collection_relationships: &BTreeMap<ndc_models::RelationshipName, ndc_models::Relationship>,
+    variables: &BTreeMap<ndc_models::VariableName, serde_json::Value>,
+    state: &AppState,
     target: &ndc_models::ComparisonTarget,
     item: &Row,
 ) -> Result<serde_json::Value> {
@@ -1430,16 +1498,10 @@ fn eval_comparison_target(
             arguments: _,
             field_path,
         } => Ok(eval_column_field_path(item, name, field_path.as_ref())?),
-        ndc_models::ComparisonTarget::Aggregate {
-            aggregate: _,
-            path: _,
-        } => Err((
-            StatusCode::NOT_IMPLEMENTED,
-            Json(ndc_models::ErrorResponse {
-                message: "aggregate predicates not supported".into(),
-                details: serde_json::Value::Null,
-            }),
-        )),
+        ndc_models::ComparisonTarget::Aggregate { aggregate, path } => {
+            let rows = eval_path(collection_relationships, variables, state, path, item)?;
+            eval_aggregate(aggregate, &rows)
+        }
     }
 }
 
@@ -32,7 +32,7 @@ pub fn get_capabilities(state: &AppState) -> ndc_models::CapabilitiesResponse {
             query: ndc_models::QueryCapabilities {
                 explain: None,
                 aggregates: Some(ndc_models::AggregateCapabilities {
-                    filter_by: None,
+                    filter_by: Some(ndc_models::LeafCapability {}),
                     group_by: Some(ndc_models::GroupByCapabilities {
                         filter: Some(ndc_models::LeafCapability {}),
                         order: Some(ndc_models::LeafCapability {}),
@@ -11,6 +11,7 @@ pub mod location;
 pub mod login;
 pub mod movie;
 pub mod name_query;
+pub mod search_result;
 pub mod staff_member;
 
 pub(crate) fn scalar_types() -> BTreeMap<ndc_models::ScalarTypeName, ndc_models::ScalarType> {
@@ -20,6 +21,16 @@ pub(crate) fn scalar_types() -> BTreeMap<ndc_models::ScalarTypeName, ndc_models:
             ndc_models::ScalarType {
                 representation: ndc_models::TypeRepresentation::String,
                 aggregate_functions: BTreeMap::from_iter([
+                    (
+                        "first".into(),
+                        ndc_models::AggregateFunctionDefinition::Custom {
+                            result_type: ndc_models::Type::Nullable {
+                                underlying_type: Box::new(ndc_models::Type::Named {
+                                    name: "String".into(),
+                                }),
+                            },
+                        },
+                    ),
                     ("max".into(), ndc_models::AggregateFunctionDefinition::Max),
                     ("min".into(), ndc_models::AggregateFunctionDefinition::Min),
                 ]),
@@ -124,13 +135,29 @@ pub(crate) fn scalar_types() -> BTreeMap<ndc_models::ScalarTypeName, ndc_models:
             ndc_models::ScalarType {
                 representation: ndc_models::TypeRepresentation::Int32,
                 aggregate_functions: BTreeMap::from_iter([
+                    (
+                        "first".into(),
+                        ndc_models::AggregateFunctionDefinition::Custom {
+                            result_type: ndc_models::Type::Nullable {
+                                underlying_type: Box::new(ndc_models::Type::Named {
+                                    name: "Int".into(),
+                                }),
+                            },
+                        },
+                    ),
                     ("max".into(), ndc_models::AggregateFunctionDefinition::Max),
                     ("min".into(), ndc_models::AggregateFunctionDefinition::Min),
                 ]),
-                comparison_operators: BTreeMap::from_iter([(
-                    "_eq".into(),
-                    ndc_models::ComparisonOperatorDefinition::Equal,
-                )]),
+                comparison_operators: BTreeMap::from_iter([
+                    (
+                        "_eq".into(),
+                        ndc_models::ComparisonOperatorDefinition::Equal,
+                    ),
+                    (
+                        "_gt".into(),
+                        ndc_models::ComparisonOperatorDefinition::GreaterThan,
+                    ),
+                ]),
                 extraction_functions: BTreeMap::new(),
             },
         ),
@@ -174,6 +201,10 @@ pub(crate) fn scalar_types() -> BTreeMap<ndc_models::ScalarTypeName, ndc_models:
 pub(crate) fn object_types() -> BTreeMap<ndc_models::ObjectTypeName, ndc_models::ObjectType> {
     BTreeMap::from_iter([
         ("actor".into(), actor::definition()),
+        (
+            "actor_mutation_response".into(),
+            actor::definition_mutation_response(),
+        ),
         ("city".into(), city::definition()),
         ("country".into(), country::definition()),
         (
@@ -183,6 +214,7 @@ pub(crate) fn object_types() -> BTreeMap<ndc_models::ObjectTypeName, ndc_models:
         ("movie".into(), movie::definition()),
         ("genre".into(), genre::definition()),
         ("name_query".into(), name_query::definition()),
+        ("search_result".into(), search_result::definition()),
         ("institution".into(), institution::definition()),
         ("location".into(), location::definition()),
         ("staff_member".into(), staff_member::definition()),
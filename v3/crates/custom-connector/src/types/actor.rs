@@ -82,3 +82,64 @@ pub(crate) fn get_actor_movie_id(
         })?;
     Ok(actor_movie_id_int)
 }
+
+pub(crate) fn definition_mutation_response() -> ndc_models::ObjectType {
+    ndc_models::ObjectType {
+        description: Some("Response of a mutation of actors".into()),
+        fields: BTreeMap::from_iter([
+            (
+                "affected_rows".into(),
+                ndc_models::ObjectField {
+                    description: Some("The number of affected actors".into()),
+                    r#type: ndc_models::Type::Named { name: "Int".into() },
+                    arguments: BTreeMap::new(),
+                },
+            ),
+            (
+                "returning".into(),
+                ndc_models::ObjectField {
+                    description: Some("The affected actors".into()),
+                    r#type: ndc_models::Type::Array {
+                        element_type: Box::new(ndc_models::Type::Named {
+                            name: "actor".into(),
+                        }),
+                    },
+                    arguments: BTreeMap::new(),
+                },
+            ),
+        ]),
+        foreign_keys: BTreeMap::new(),
+    }
+}
+
+pub(crate) fn get_actor_id(
+    actor: &BTreeMap<ndc_models::FieldName, serde_json::Value>,
+) -> Result<i32> {
+    let actor_id = actor.get("id").ok_or((
+        StatusCode::BAD_REQUEST,
+        Json(ndc_models::ErrorResponse {
+            message: "actor id not found".into(),
+            details: serde_json::Value::Null,
+        }),
+    ))?;
+    let actor_id_int = actor_id
+        .as_i64()
+        .ok_or((
+            StatusCode::BAD_REQUEST,
+            Json(ndc_models::ErrorResponse {
+                message: "actor id is not an integer".into(),
+                details: serde_json::Value::Null,
+            }),
+        ))?
+        .try_into()
+        .map_err(|_| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ndc_models::ErrorResponse {
+                    message: "actor id is out of range".into(),
+                    details: serde_json::Value::Null,
+                }),
+            )
+        })?;
+    Ok(actor_id_int)
+}
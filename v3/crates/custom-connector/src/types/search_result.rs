@@ -0,0 +1,54 @@
+use std::collections::BTreeMap;
+
+use ndc_models;
+
+pub(crate) fn definition() -> ndc_models::ObjectType {
+    ndc_models::ObjectType {
+        description: Some("An actor or a movie matching a search".into()),
+        fields: BTreeMap::from_iter([
+            (
+                "kind".into(),
+                ndc_models::ObjectField {
+                    description: Some("Whether the result is an 'actor' or a 'movie'".into()),
+                    r#type: ndc_models::Type::Named {
+                        name: "String".into(),
+                    },
+                    arguments: BTreeMap::new(),
+                },
+            ),
+            (
+                "id".into(),
+                ndc_models::ObjectField {
+                    description: Some("The primary key of the actor or movie".into()),
+                    r#type: ndc_models::Type::Named { name: "Int".into() },
+                    arguments: BTreeMap::new(),
+                },
+            ),
+            (
+                "name".into(),
+                ndc_models::ObjectField {
+                    description: Some("The actor's name, if the result is an actor".into()),
+                    r#type: ndc_models::Type::Nullable {
+                        underlying_type: Box::new(ndc_models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                    arguments: BTreeMap::new(),
+                },
+            ),
+            (
+                "title".into(),
+                ndc_models::ObjectField {
+                    description: Some("The movie's title, if the result is a movie".into()),
+                    r#type: ndc_models::Type::Nullable {
+                        underlying_type: Box::new(ndc_models::Type::Named {
+                            name: "String".into(),
+                        }),
+                    },
+                    arguments: BTreeMap::new(),
+                },
+            ),
+        ]),
+        foreign_keys: BTreeMap::new(),
+    }
+}
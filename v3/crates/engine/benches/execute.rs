@@ -194,10 +194,17 @@ pub fn bench_execute(
                     .unwrap()
                 {
                     RequestPlan::QueryPlan(query_plan) => {
-                        execute_query_plan(&http_context, query_plan, None).await
+                        execute_query_plan(&http_context, query_plan, None, &session.variables)
+                            .await
                     }
                     RequestPlan::MutationPlan(mutation_plan) => {
-                        execute_mutation_plan(&http_context, mutation_plan, None).await
+                        execute_mutation_plan(
+                            &http_context,
+                            mutation_plan,
+                            None,
+                            &session.variables,
+                        )
+                        .await
                     }
                     RequestPlan::SubscriptionPlan(alias, subscription_plan) => {
                         // subscriptions are not supported
@@ -209,6 +216,7 @@ pub fn bench_execute(
                                 .is_nullable(),
                             result,
                             headers: None,
+                            page_info: None,
                         };
                         ExecuteQueryResult {
                             root_fields: IndexMap::from([(alias, root_field_result)]),
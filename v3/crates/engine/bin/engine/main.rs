@@ -1,6 +1,7 @@
 use clap::Parser;
 use engine::{
-    get_base_routes, get_cors_layer, get_jsonapi_route, get_metadata_routes,
+    get_base_routes, get_cors_layer, get_explain_permissions_route, get_jsonapi_route,
+    get_metadata_routes,
     internal_flags::{resolve_unstable_features, UnstableFeature},
     StartupError, VERSION,
 };
@@ -66,6 +67,11 @@ struct ServerOptions {
     #[arg(long, env = "EXPOSE_INTERNAL_ERRORS")]
     expose_internal_errors: bool,
 
+    /// Serve `/v1/explain/permissions`, restricted to identities that can assume this role.
+    /// It shows the permissions that apply to a GraphQL request for the role of the session.
+    #[arg(long, value_name = "ROLE", env = "EXPLAIN_PERMISSIONS_ROLE")]
+    explain_permissions_role: Option<String>,
+
     /// Log traces to stdout.
     #[arg(long, env = "EXPORT_TRACES_STDOUT")]
     export_traces_stdout: bool,
@@ -145,6 +151,15 @@ async fn start_engine(server: &ServerOptions) -> Result<(), StartupError> {
         app = app.merge(get_metadata_routes(path).await?);
     }
 
+    // If `--explain-permissions-role` is specified we serve `/v1/explain/permissions` to
+    // identities that can assume the given role.
+    if let Some(role) = &server.explain_permissions_role {
+        app = app.merge(get_explain_permissions_route(
+            state.clone(),
+            hasura_authn_core::Role::new(role),
+        ));
+    }
+
     // If `--enable-cors` is specified, we add a CORS layer to the app.
     if server.enable_cors {
         app = app.layer(get_cors_layer(&server.cors_allow_origin));
@@ -173,6 +188,10 @@ async fn start_engine(server: &ServerOptions) -> Result<(), StartupError> {
             .graphql_websocket_server
             .shutdown("Shutting server down")
             .await;
+        state
+            .graphql_event_stream_server
+            .shutdown("Shutting server down")
+            .await;
     }))
     .await
     .unwrap();
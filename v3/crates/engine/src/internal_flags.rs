@@ -18,6 +18,8 @@
 #[serde(rename_all = "snake_case")]
 pub enum UnstableFeature {
     EnableNdcV02Support,
+    /// Deprecated: aggregation predicates are now always enabled. Kept so that existing
+    /// `UNSTABLE_FEATURES` settings still parse.
     EnableAggregationPredicates,
     EnableOpenDdPipelineForGraphql,
 }
@@ -33,7 +35,7 @@ pub fn resolve_unstable_features(
                 features.enable_ndc_v02_support = true;
             }
             UnstableFeature::EnableAggregationPredicates => {
-                features.enable_aggregation_predicates = true;
+                // aggregation predicates are always enabled, this is a no-op
             }
             UnstableFeature::EnableOpenDdPipelineForGraphql => {
                 // this does not currently affect metadata resolve behaviour
@@ -11,7 +11,10 @@ pub use middleware::{
     authentication_middleware, explain_request_tracing_middleware,
     graphql_request_tracing_middleware, plugins_middleware,
 };
-pub use routes::{get_base_routes, get_cors_layer, get_jsonapi_route, get_metadata_routes};
+pub use routes::{
+    get_base_routes, get_cors_layer, get_explain_permissions_route, get_jsonapi_route,
+    get_metadata_routes,
+};
 pub use state::build_state;
 pub use types::{EngineState, RequestType, StartupError};
 
@@ -128,6 +128,11 @@ pub async fn plugins_middleware(
     request: Request<axum::body::Body>,
     next: Next,
 ) -> axum::response::Result<axum::response::Response<Body>> {
+    // Event streams and multipart responses can't be buffered into a single response. Plugins
+    // are run for them by the subscription execution instead, for each operation and result.
+    if graphql_ws::accepts_event_stream(&headers_map) {
+        return Ok(next.run(request).await);
+    }
     let (parts, body) = request.into_parts();
     let bytes = body
         .collect()
@@ -136,6 +141,15 @@ pub async fn plugins_middleware(
             (reqwest::StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response()
         })?
         .to_bytes();
+    // Only operations with deferred fragments get a multipart response, the same as in the
+    // GraphQL route handler
+    if graphql_ws::accepts_multipart_mixed(&headers_map)
+        && serde_json::from_slice::<lang_graphql::http::RawRequest>(&bytes)
+            .is_ok_and(|request| graphql_ws::has_deferred_fragments(&request))
+    {
+        let recreated_request = Request::from_parts(parts, axum::body::Body::from(bytes));
+        return Ok(next.run(recreated_request).await);
+    }
     let raw_request = bytes.clone();
 
     // Check if the pre_parse_plugins_config is empty
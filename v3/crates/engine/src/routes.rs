@@ -1,5 +1,9 @@
 mod graphql;
-pub use graphql::{handle_explain_request, handle_request, handle_websocket_request};
+pub use graphql::{
+    handle_event_stream, handle_event_stream_operation_complete, handle_event_stream_request,
+    handle_event_stream_reservation, handle_explain_permissions_request, handle_explain_request,
+    handle_request, handle_websocket_request, ExplainPermissionsRole,
+};
 mod jsonapi;
 pub use jsonapi::create_json_api_router;
 
@@ -10,6 +14,7 @@ use axum::{
     Router,
 };
 use base64::engine::Engine;
+use hasura_authn_core::Role;
 use std::hash;
 use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
@@ -60,6 +65,33 @@ pub fn get_base_routes(state: EngineState) -> Router {
         .layer(TraceLayer::new_for_http())
         .with_state(state.clone());
 
+    // The '/graphql/stream' route for GraphQL over Server-Sent Events. Pre-parse and
+    // pre-response plugins are run by the subscription execution for each operation and
+    // result, as the `plugins_middleware` can only handle a single response body.
+    let graphql_event_stream_route = Router::new()
+        .route(
+            "/graphql/stream",
+            post(handle_event_stream_request)
+                .put(handle_event_stream_reservation)
+                .get(handle_event_stream)
+                .delete(handle_event_stream_operation_complete),
+        )
+        .layer(axum::middleware::from_fn(
+            hasura_authn_core::resolve_session,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            authentication_middleware,
+        ))
+        .layer(axum::middleware::from_fn(|request, next| {
+            graphql_request_tracing_middleware(RequestType::EventStream, request, next)
+        }))
+        // *PLEASE DO NOT ADD ANY MIDDLEWARE
+        // BEFORE THE `graphql_request_tracing_middleware`*
+        // Refer to it for more details.
+        .layer(TraceLayer::new_for_http())
+        .with_state(state.clone());
+
     let explain_route = Router::new()
         .route("/v1/explain", post(handle_explain_request))
         .layer(axum::middleware::from_fn(
@@ -96,6 +128,8 @@ pub fn get_base_routes(state: EngineState) -> Router {
         .merge(graphql_route)
         // The '/graphql' route for websocket
         .merge(graphql_ws_route)
+        // The '/graphql/stream' route for server-sent events
+        .merge(graphql_event_stream_route)
         // The '/v1/explain' route
         .merge(explain_route)
         // The '/health' route
@@ -124,6 +158,32 @@ pub async fn get_metadata_routes(
     Ok(metadata_routes)
 }
 
+/// Serve `/v1/explain/permissions`, which explains the permissions that apply to a GraphQL
+/// request for the role of the session. Only identities that can assume `admin_role` may use it.
+pub fn get_explain_permissions_route(state: EngineState, admin_role: Role) -> Router {
+    Router::new()
+        .route(
+            "/v1/explain/permissions",
+            post(handle_explain_permissions_request),
+        )
+        .layer(axum::Extension(ExplainPermissionsRole(admin_role)))
+        .layer(axum::middleware::from_fn(
+            hasura_authn_core::resolve_session,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            authentication_middleware,
+        ))
+        .layer(axum::middleware::from_fn(
+            explain_request_tracing_middleware,
+        ))
+        // *PLEASE DO NOT ADD ANY MIDDLEWARE
+        // BEFORE THE `explain_request_tracing_middleware`*
+        // Refer to it for more details.
+        .layer(TraceLayer::new_for_http())
+        .with_state(state)
+}
+
 pub fn get_jsonapi_route(state: EngineState) -> Router {
     create_json_api_router(state)
 }
@@ -1,21 +1,40 @@
 use axum::{
-    extract::{ConnectInfo, State},
+    extract::{ConnectInfo, Query, State},
     response::IntoResponse,
     Extension, Json,
 };
 use futures_util::FutureExt;
 
 use crate::EngineState;
-use hasura_authn_core::Session;
+use hasura_authn_core::{Identity, Role, Session};
 use lang_graphql as gql;
 use tracing_util::{set_status_on_current_span, SpanVisibility};
 
 pub async fn handle_request(
+    ConnectInfo(client_address): ConnectInfo<std::net::SocketAddr>,
     headers: axum::http::header::HeaderMap,
     State(state): State<EngineState>,
     Extension(session): Extension<Session>,
     Json(request): Json<gql::http::RawRequest>,
-) -> gql::http::Response {
+) -> axum::response::Response {
+    // Serve the request over Server-Sent Events if the client asks for an event stream
+    if graphql_ws::accepts_event_stream(&headers) {
+        let context = make_graphql_ws_context(&state);
+        return state
+            .graphql_event_stream_server
+            .handle_distinct_connection(client_address, session, headers, request, context)
+            .await;
+    }
+    // Deliver the response incrementally if the client accepts multipart responses, and the
+    // operation has deferred fragments. Other operations get a regular JSON response.
+    if graphql_ws::accepts_multipart_mixed(&headers) && graphql_ws::has_deferred_fragments(&request)
+    {
+        let context = make_graphql_ws_context(&state);
+        return state
+            .graphql_event_stream_server
+            .handle_multipart_request(client_address, session, headers, request, context)
+            .await;
+    }
     let tracer = tracing_util::global_tracer();
     let response = tracer
         .in_span_async(
@@ -50,7 +69,7 @@ pub async fn handle_request(
     // The only way to determine the error is to inspect the status code from the `Response` struct.
     // In `/graphql` API, all responses are sent with `200` OK including errors, which leaves no way to deduce errors in the tracing middleware.
     set_status_on_current_span(&response);
-    response.inner()
+    response.inner().into_response()
 }
 
 pub async fn handle_explain_request(
@@ -88,27 +107,121 @@ pub async fn handle_explain_request(
     response
 }
 
+/// The role that an identity must be able to assume to explain permissions.
+#[derive(Clone)]
+pub struct ExplainPermissionsRole(pub Role);
+
+/// Explains the permissions that apply to a GraphQL request for the role of the session.
+pub async fn handle_explain_permissions_request(
+    State(state): State<EngineState>,
+    Extension(ExplainPermissionsRole(admin_role)): Extension<ExplainPermissionsRole>,
+    Extension(identity): Extension<Identity>,
+    Extension(session): Extension<Session>,
+    Json(request): Json<gql::http::RawRequest>,
+) -> axum::response::Response {
+    if identity.get_role_authorization(Some(&admin_role)).is_err() {
+        return gql::http::Response::error_message_with_status(
+            axum::http::StatusCode::FORBIDDEN,
+            format!("explaining permissions requires the {admin_role} role"),
+            false,
+        )
+        .into_response();
+    }
+    let response = graphql_frontend::explain_permissions(
+        state.expose_internal_errors,
+        &state.graphql_state,
+        &state.resolved_metadata,
+        &session,
+        &request,
+    );
+
+    // Set the span as error if the response contains an error
+    set_status_on_current_span(&response);
+    response.into_response()
+}
+
 pub async fn handle_websocket_request(
     ConnectInfo(client_address): ConnectInfo<std::net::SocketAddr>,
     headers: axum::http::header::HeaderMap,
     State(engine_state): State<EngineState>,
     ws: axum::extract::ws::WebSocketUpgrade,
 ) -> impl IntoResponse {
-    // Create the context for the websocket server
-    let context = graphql_ws::Context {
+    let context = make_graphql_ws_context(&engine_state);
+    engine_state
+        .graphql_websocket_server
+        .upgrade_and_handle_websocket(client_address, ws, &headers, context)
+}
+
+/// Handles a GraphQL operation over Server-Sent Events, either on an event stream of its own,
+/// or on a reserved event stream when the request carries its token.
+pub async fn handle_event_stream_request(
+    ConnectInfo(client_address): ConnectInfo<std::net::SocketAddr>,
+    headers: axum::http::header::HeaderMap,
+    State(engine_state): State<EngineState>,
+    Extension(session): Extension<Session>,
+    Query(params): Query<graphql_ws::EventStreamParams>,
+    Json(request): Json<graphql_ws::EventStreamRequest>,
+) -> impl IntoResponse {
+    let context = make_graphql_ws_context(&engine_state);
+    engine_state
+        .graphql_event_stream_server
+        .handle_operation_request(client_address, session, headers, &params, request, context)
+        .await
+}
+
+/// Reserves an event stream, for running several operations over a single connection.
+pub async fn handle_event_stream_reservation(
+    headers: axum::http::header::HeaderMap,
+    State(engine_state): State<EngineState>,
+    Extension(session): Extension<Session>,
+) -> impl IntoResponse {
+    let context = make_graphql_ws_context(&engine_state);
+    engine_state
+        .graphql_event_stream_server
+        .handle_reservation(session, headers, context)
+        .await
+}
+
+/// Opens a reserved event stream.
+pub async fn handle_event_stream(
+    headers: axum::http::header::HeaderMap,
+    State(engine_state): State<EngineState>,
+    Extension(session): Extension<Session>,
+    Query(params): Query<graphql_ws::EventStreamParams>,
+) -> impl IntoResponse {
+    engine_state
+        .graphql_event_stream_server
+        .handle_event_stream(&session, &headers, &params)
+        .await
+}
+
+/// Stops an operation running on a reserved event stream.
+pub async fn handle_event_stream_operation_complete(
+    headers: axum::http::header::HeaderMap,
+    State(engine_state): State<EngineState>,
+    Extension(session): Extension<Session>,
+    Query(params): Query<graphql_ws::EventStreamParams>,
+) -> impl IntoResponse {
+    engine_state
+        .graphql_event_stream_server
+        .handle_operation_complete(&session, &headers, &params)
+        .await
+}
+
+/// Create the context for the websocket and event stream servers
+fn make_graphql_ws_context(
+    engine_state: &EngineState,
+) -> graphql_ws::Context<graphql_ws::NoOpWebSocketMetrics> {
+    graphql_ws::Context {
         connection_expiry: graphql_ws::ConnectionExpiry::Never,
         request_pipeline: engine_state.request_pipeline,
-        metadata: engine_state.resolved_metadata,
-        http_context: engine_state.http_context,
+        metadata: engine_state.resolved_metadata.clone(),
+        http_context: engine_state.http_context.clone(),
         project_id: None, // project_id is not needed for OSS v3-engine.
         expose_internal_errors: engine_state.expose_internal_errors,
-        schema: engine_state.graphql_state,
-        auth_config: engine_state.auth_config,
-        plugin_configs: engine_state.plugin_configs,
+        schema: engine_state.graphql_state.clone(),
+        auth_config: engine_state.auth_config.clone(),
+        plugin_configs: engine_state.plugin_configs.clone(),
         metrics: graphql_ws::NoOpWebSocketMetrics, // No metrics implementation
-    };
-
-    engine_state
-        .graphql_websocket_server
-        .upgrade_and_handle_websocket(client_address, ws, &headers, context)
+    }
 }
@@ -50,6 +50,10 @@ pub fn build_state(
 
     let (jsonapi_catalog, _json_api_warnings) = jsonapi::Catalog::new(&resolved_metadata);
 
+    let graphql_websocket_server = graphql_ws::WebSocketServer::new();
+    // Event streams share the subscription multiplexer of the websocket server
+    let graphql_event_stream_server = graphql_ws::EventStreamServer::new(&graphql_websocket_server);
+
     let state = EngineState {
         request_pipeline,
         expose_internal_errors,
@@ -59,7 +63,8 @@ pub fn build_state(
         resolved_metadata,
         auth_config: Arc::new(auth_config),
         plugin_configs: Arc::new(plugin_configs),
-        graphql_websocket_server: Arc::new(graphql_ws::WebSocketServer::new()),
+        graphql_websocket_server: Arc::new(graphql_websocket_server),
+        graphql_event_stream_server: Arc::new(graphql_event_stream_server),
     };
     Ok(state)
 }
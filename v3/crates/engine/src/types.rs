@@ -20,6 +20,8 @@ pub struct EngineState {
     pub plugin_configs: Arc<LifecyclePluginConfigs>,
     pub graphql_websocket_server:
         Arc<graphql_ws::WebSocketServer<graphql_ws::NoOpWebSocketMetrics>>,
+    pub graphql_event_stream_server:
+        Arc<graphql_ws::EventStreamServer<graphql_ws::NoOpWebSocketMetrics>>,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -41,6 +43,7 @@ impl TraceableError for StartupError {
 pub enum RequestType {
     Http,
     WebSocket,
+    EventStream,
 }
 
 impl RequestType {
@@ -50,6 +53,7 @@ impl RequestType {
         match self {
             RequestType::Http => "http",
             RequestType::WebSocket => "websocket",
+            RequestType::EventStream => "event-stream",
         }
     }
 }
@@ -602,6 +602,69 @@ pub fn test_execute_explain(
     })
 }
 
+#[allow(dead_code)]
+pub fn test_execute_explain_permissions(
+    test_path_string: &str,
+    test_metadata_path: &str,
+    common_metadata_paths: &[&str],
+) -> anyhow::Result<()> {
+    let root_test_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests");
+    let mut test_ctx = setup(&root_test_dir);
+    let test_path = root_test_dir.join(test_path_string);
+    let expected_response_file = test_path_string.to_string() + "/expected.json";
+
+    let metadata = merge_with_common_metadata(
+        &root_test_dir.join(test_metadata_path),
+        common_metadata_paths
+            .iter()
+            .map(|path| root_test_dir.join(path)),
+    )?;
+    let (resolved_metadata, _) = metadata_resolve::resolve(
+        open_dds::traits::OpenDd::deserialize(metadata, jsonpath::JSONPath::new())?,
+        &test_metadata_resolve_configuration(),
+    )?;
+    let arc_resolved_metadata = Arc::new(resolved_metadata);
+    let schema = GDS::build_schema(&GDS {
+        metadata: arc_resolved_metadata.clone(),
+    })?;
+
+    let session_variables: HashMap<SessionVariableName, JsonSessionVariableValue> =
+        json::from_str(read_to_string(&test_path.join("session_variables.json"))?.as_ref())?;
+    let session = resolve_session(
+        session_variables
+            .into_iter()
+            .map(|(k, v)| (k, v.into()))
+            .collect(),
+    )?;
+    let raw_request = RawRequest {
+        operation_name: None,
+        query: read_to_string(&test_path.join("request.gql"))?,
+        variables: None,
+    };
+    let response = graphql_frontend::explain_permissions(
+        ExposeInternalErrors::Expose,
+        &schema,
+        &arc_resolved_metadata,
+        &session,
+        &raw_request,
+    );
+
+    let mut expected = test_ctx.mint.new_goldenfile_with_differ(
+        expected_response_file,
+        Box::new(|file1, file2| {
+            let json1: serde_json::Value =
+                serde_json::from_reader(File::open(file1).unwrap()).unwrap();
+            let json2: serde_json::Value =
+                serde_json::from_reader(File::open(file2).unwrap()).unwrap();
+            if json1 != json2 {
+                text_diff(file1, file2);
+            }
+        }),
+    )?;
+    write!(expected, "{}", serde_json::to_string_pretty(&response)?)?;
+    Ok(())
+}
+
 // This is where we'll want to enable pre-release features in tests
 pub(crate) fn test_metadata_resolve_configuration() -> metadata_resolve::configuration::Configuration
 {
@@ -682,7 +745,13 @@ async fn run_query_graphql_ws(
     let (channel_sender, mut channel_receiver) =
         tokio::sync::mpsc::channel::<graphql_ws::Message>(10);
     let websocket_id = graphql_ws::WebSocketId::new();
-    let dummy_conn = graphql_ws::Connection::new(websocket_id, context, channel_sender);
+    let dummy_conn = graphql_ws::Connection::new(
+        websocket_id,
+        graphql_ws::Protocol::GraphqlTransportWs,
+        context,
+        channel_sender,
+        graphql_ws::Multiplexer::new(),
+    );
     let operation_id = graphql_ws::OperationId("some-operation-id".to_string());
     // Using the internal function. The actual 'execute_request' function from
     // graphl_ws crate needs a parent span context for linking purposes.
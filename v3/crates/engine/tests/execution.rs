@@ -167,6 +167,27 @@ fn test_model_select_many_multiple_field_arguments() -> anyhow::Result<()> {
     )
 }
 
+// Tests field argument presets in type output permissions: the preset argument is applied along
+// with the arguments given in the query
+#[test]
+fn test_model_select_many_field_arguments_presets() -> anyhow::Result<()> {
+    common::test_execution_expectation_for_multiple_ndc_versions(
+        "execute/models/select_many/field_arguments/presets",
+        &[],
+        BTreeMap::from([
+            (
+                NdcVersion::V01,
+                vec!["execute/common_metadata/custom_connector_v01_schema.json"],
+            ),
+            (
+                NdcVersion::V02,
+                vec!["execute/common_metadata/custom_connector_v02_schema.json"],
+            ),
+        ]),
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
 // Nested selection tests
 #[test]
 fn test_model_select_many_nested_select() -> anyhow::Result<()> {
@@ -423,6 +444,80 @@ fn test_model_select_many_type_permission_where() -> anyhow::Result<()> {
     )
 }
 
+// Fields that are only selectable on the objects that match a condition
+// The OpenDD pipeline does not evaluate the conditions of conditionally allowed fields, so it is skipped here
+#[test]
+fn test_model_select_many_type_permission_conditional_fields() -> anyhow::Result<()> {
+    let test_path_string = "execute/models/select_many/type_permission/conditional_fields";
+    let common_metadata_path_string = "execute/common_metadata/custom_connector_v02_schema.json";
+    common::test_execution_expectation(
+        test_path_string,
+        &[common_metadata_path_string],
+        common::TestOpenDDPipeline::Skip,
+    )
+}
+
+#[test]
+fn test_model_select_many_enum_types_select() -> anyhow::Result<()> {
+    let test_path_string = "execute/models/select_many/enum_types/select";
+    let ndc_metadata_path_string = "execute/common_metadata/custom_connector_v02_schema.json";
+    let common_metadata_path_string = "execute/models/select_many/enum_types/common_metadata.json";
+    common::test_execution_expectation(
+        test_path_string,
+        &[ndc_metadata_path_string, common_metadata_path_string],
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
+#[test]
+fn test_model_select_many_enum_types_invalid_value() -> anyhow::Result<()> {
+    let test_path_string = "execute/models/select_many/enum_types/invalid_value";
+    let ndc_metadata_path_string = "execute/common_metadata/custom_connector_v02_schema.json";
+    let common_metadata_path_string = "execute/models/select_many/enum_types/common_metadata.json";
+    common::test_execution_expectation(
+        test_path_string,
+        &[ndc_metadata_path_string, common_metadata_path_string],
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
+#[test]
+fn test_model_select_many_role_inheritance() -> anyhow::Result<()> {
+    let test_path_string = "execute/models/select_many/role_inheritance";
+    let common_metadata_path_string = "execute/common_metadata/custom_connector_v02_schema.json";
+    common::test_execution_expectation(
+        test_path_string,
+        &[common_metadata_path_string],
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
+// Distinct rows selected with the distinct_on argument
+// The OpenDD pipeline does not support distinct_on yet, so it is skipped here
+#[test]
+fn test_model_select_many_distinct_on() -> anyhow::Result<()> {
+    let test_path_string = "execute/models/select_many/distinct_on";
+    let common_metadata_path_string = "execute/common_metadata/custom_connector_v02_schema.json";
+    common::test_execution_expectation(
+        test_path_string,
+        &[common_metadata_path_string],
+        common::TestOpenDDPipeline::Skip,
+    )
+}
+
+// Only the orderable fields of the model can be chosen in the distinct_on argument
+// The OpenDD pipeline does not support distinct_on yet, so it is skipped here
+#[test]
+fn test_model_select_many_distinct_on_not_orderable_field() -> anyhow::Result<()> {
+    let test_path_string = "execute/models/select_many/distinct_on_not_orderable_field";
+    let common_metadata_path_string = "execute/common_metadata/custom_connector_v02_schema.json";
+    common::test_execution_expectation(
+        test_path_string,
+        &[common_metadata_path_string],
+        common::TestOpenDDPipeline::Skip,
+    )
+}
+
 // Where Tests
 #[test]
 fn test_model_select_many_where() -> anyhow::Result<()> {
@@ -702,6 +797,23 @@ fn test_model_select_many_where_array_relationship_nested() -> anyhow::Result<()
     )
 }
 
+// Aggregate comparisons across an array relationship in boolean expressions, eg. movies with
+// more than one actor. The rows are filtered by the permissions of the target model before they
+// are aggregated.
+#[test]
+fn test_model_select_many_where_array_relationship_aggregate() -> anyhow::Result<()> {
+    let test_path_string =
+        "execute/models/select_many/where/relationships/boolean_expression_type/array/aggregate";
+    common::test_execution_expectation(
+        test_path_string,
+        &[
+            "execute/common_metadata/custom_connector_v02_schema.json",
+            "execute/aggregates/common_metadata/supergraph.json",
+        ],
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
 // Object relationships in boolean expressions (Simple, Nested object relationships). We also test multi column boolean expressions
 #[test]
 fn test_model_select_many_where_object_relationship_simple() -> anyhow::Result<()> {
@@ -956,6 +1068,29 @@ fn test_model_select_many_limit() -> anyhow::Result<()> {
     )
 }
 
+#[test]
+fn test_model_select_many_permission_limit() -> anyhow::Result<()> {
+    let test_path_string = "execute/models/select_many/limit_offset/permission_limit";
+    let ndc_metadata_path_string = "execute/common_metadata/postgres_connector_schema.json";
+
+    common::test_execution_expectation(
+        test_path_string,
+        &[ndc_metadata_path_string],
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
+#[test]
+fn test_model_select_many_cursor_pagination() -> anyhow::Result<()> {
+    let test_path_string = "execute/models/select_many/cursor_pagination";
+    let common_metadata_path_string = "execute/common_metadata/postgres_connector_schema.json";
+    common::test_execution_expectation(
+        test_path_string,
+        &[common_metadata_path_string],
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
 // Test is_null in model select permissions
 #[test]
 fn test_model_select_many_predicate_is_null() -> anyhow::Result<()> {
@@ -1127,6 +1262,19 @@ fn test_relay_node_model_select_permissions() -> anyhow::Result<()> {
     )
 }
 
+// Relay connections over a model and over an array relationship
+// The OpenDD pipeline does not support connections yet, so it is skipped here
+#[test]
+fn test_relay_connection() -> anyhow::Result<()> {
+    let test_path_string = "execute/relay/relay_connection";
+    let common_metadata_path_string = "execute/common_metadata/postgres_connector_schema.json";
+    common::test_execution_expectation(
+        test_path_string,
+        &[common_metadata_path_string],
+        common::TestOpenDDPipeline::Skip,
+    )
+}
+
 #[test]
 fn test_typename() -> anyhow::Result<()> {
     let test_path_string = "execute/typename";
@@ -1155,6 +1303,19 @@ fn test_command_functions() -> anyhow::Result<()> {
     )
 }
 
+// Union and interface output types of commands
+// The OpenDD pipeline does not support union or interface types yet, so it is skipped here
+#[test]
+fn test_command_functions_abstract_types() -> anyhow::Result<()> {
+    let test_path_string = "execute/commands/functions/abstract_types";
+    let common_metadata_path_string = "execute/common_metadata/custom_connector_v02_schema.json";
+    common::test_execution_expectation(
+        test_path_string,
+        &[common_metadata_path_string],
+        common::TestOpenDDPipeline::Skip,
+    )
+}
+
 #[test]
 fn test_command_object_type_input_arguments() -> anyhow::Result<()> {
     let test_path_string = "execute/commands/object_type_input_arguments";
@@ -1225,6 +1386,49 @@ fn test_command_functions_object_output_type_command_permissions() -> anyhow::Re
     )
 }
 
+// Tests a query command whose execution is allowed for role user_1 only when the actor_id
+// argument matches the x-hasura-user-id session variable
+#[test]
+fn test_command_functions_object_output_type_argument_predicate() -> anyhow::Result<()> {
+    common::test_execution_expectation_for_multiple_ndc_versions(
+        "execute/commands/functions/object_output_type/argument_predicate",
+        &["execute/common_metadata/command_metadata.json"],
+        BTreeMap::from([
+            (
+                NdcVersion::V01,
+                vec!["execute/common_metadata/custom_connector_v01_schema.json"],
+            ),
+            (
+                NdcVersion::V02,
+                vec!["execute/common_metadata/custom_connector_v02_schema.json"],
+            ),
+        ]),
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
+// Tests a query command returning the object type of the Actors model, whose output is only
+// returned to role user_1 when it matches the output filter (movie_id = 1)
+#[test]
+fn test_command_functions_object_output_type_output_filter() -> anyhow::Result<()> {
+    common::test_execution_expectation_for_multiple_ndc_versions(
+        "execute/commands/functions/object_output_type/output_filter",
+        &["execute/common_metadata/command_metadata.json"],
+        BTreeMap::from([
+            // This test can't use the old NDC v0.1.x connector, the embedded actors data has changed
+            // (
+            //     NdcVersion::V01,
+            //     vec!["execute/common_metadata/custom_connector_v01_schema.json"],
+            // ),
+            (
+                NdcVersion::V02,
+                vec!["execute/common_metadata/custom_connector_v02_schema.json"],
+            ),
+        ]),
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
 // Tests a query command with object (commandActor) output type (different object output subset field permissions for roles: admin,
 // user_1, user_2)
 #[test]
@@ -1375,6 +1579,49 @@ fn test_command_procedures_object_output_type_command_permissions() -> anyhow::R
     )
 }
 
+// Tests a mutation command whose execution is allowed for role user_1 only when the id
+// argument matches the x-hasura-user-id session variable
+#[test]
+fn test_command_procedures_object_output_type_argument_predicate() -> anyhow::Result<()> {
+    common::test_execution_expectation_for_multiple_ndc_versions(
+        "execute/commands/procedures/object_output_type/argument_predicate",
+        &["execute/common_metadata/command_metadata.json"],
+        BTreeMap::from([
+            (
+                NdcVersion::V01,
+                vec!["execute/common_metadata/custom_connector_v01_schema.json"],
+            ),
+            (
+                NdcVersion::V02,
+                vec!["execute/common_metadata/custom_connector_v02_schema.json"],
+            ),
+        ]),
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
+// Tests a mutation command returning the object type of the Actors model, whose output is only
+// returned to role user_1 when it matches the output filter (movie_id = 1) after the procedure has run
+#[test]
+fn test_command_procedures_object_output_type_output_filter() -> anyhow::Result<()> {
+    common::test_execution_expectation_for_multiple_ndc_versions(
+        "execute/commands/procedures/object_output_type/output_filter",
+        &["execute/common_metadata/command_metadata.json"],
+        BTreeMap::from([
+            // This test can't use the old NDC v0.1.x connector, the embedded actors data has changed
+            // (
+            //     NdcVersion::V01,
+            //     vec!["execute/common_metadata/custom_connector_v01_schema.json"],
+            // ),
+            (
+                NdcVersion::V02,
+                vec!["execute/common_metadata/custom_connector_v02_schema.json"],
+            ),
+        ]),
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
 // Tests a mutation command with object (commandActor) output type (different object output subset field permissions for roles: admin,
 // user_1, user_2)
 #[test]
@@ -1983,6 +2230,37 @@ fn test_model_select_many_remote_relationship_predicate_object_nested() -> anyho
     )
 }
 
+// Tests using remote relationships in predicates
+// Object relationship to a model in another subgraph
+#[test]
+fn test_model_select_many_remote_relationship_predicate_object_simple_across_subgraphs(
+) -> anyhow::Result<()> {
+    let test_path_string =
+        "execute/models/select_many/remote_relationship_predicates/object/simple_across_subgraphs";
+    let common_metadata_path_string = "execute/common_metadata/two_postgres_connector_schema.json";
+    common::test_execution_expectation(
+        test_path_string,
+        &[common_metadata_path_string],
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
+// Tests using remote relationships in the `where` argument
+// Object relationship to a model in another subgraph
+// The OpenDD pipeline does not yet support relationships in `where`, so it is skipped here
+#[test]
+fn test_model_select_many_remote_relationship_predicate_object_where_across_subgraphs(
+) -> anyhow::Result<()> {
+    let test_path_string =
+        "execute/models/select_many/remote_relationship_predicates/object/where_across_subgraphs";
+    let common_metadata_path_string = "execute/common_metadata/two_postgres_connector_schema.json";
+    common::test_execution_expectation(
+        test_path_string,
+        &[common_metadata_path_string],
+        common::TestOpenDDPipeline::Skip,
+    )
+}
+
 // Tests using remote relationships in predicates
 // We have the following relationships:
 //  1.  'TracksRemote' array relationship to 'Album' model
@@ -2054,6 +2332,91 @@ fn test_model_select_many_remote_relationship_predicate_object_two_relationship_
     )
 }
 
+// Tests the generated insert mutation of a model: the input field presets are applied to the
+// inserted objects, and the inserted objects must satisfy the select permission of the model
+#[test]
+fn test_model_mutations_insert() -> anyhow::Result<()> {
+    common::test_execution_expectation_for_multiple_ndc_versions(
+        "execute/models/mutations/insert",
+        &[
+            "execute/common_metadata/command_metadata.json",
+            "execute/models/mutations/common_metadata.json",
+        ],
+        BTreeMap::from([
+            // Not supported in v0.1.x because the old custom connector doesn't have the mutation procedures of actors
+            (
+                NdcVersion::V02,
+                vec!["execute/common_metadata/custom_connector_v02_schema.json"],
+            ),
+        ]),
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
+// Tests the generated update by unique key mutation of a model: only the objects allowed by the
+// select permission are updated, the input field presets are applied to the new values, and the
+// updated objects must satisfy the select permission
+#[test]
+fn test_model_mutations_update_by_unique_key() -> anyhow::Result<()> {
+    common::test_execution_expectation_for_multiple_ndc_versions(
+        "execute/models/mutations/update_by_unique_key",
+        &[
+            "execute/common_metadata/command_metadata.json",
+            "execute/models/mutations/common_metadata.json",
+        ],
+        BTreeMap::from([
+            // Not supported in v0.1.x because the old custom connector doesn't have the mutation procedures of actors
+            (
+                NdcVersion::V02,
+                vec!["execute/common_metadata/custom_connector_v02_schema.json"],
+            ),
+        ]),
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
+// Tests the generated update mutation of a model, with a `where` filter combined with the select
+// permission of the model
+#[test]
+fn test_model_mutations_update() -> anyhow::Result<()> {
+    common::test_execution_expectation_for_multiple_ndc_versions(
+        "execute/models/mutations/update",
+        &[
+            "execute/common_metadata/command_metadata.json",
+            "execute/models/mutations/common_metadata.json",
+        ],
+        BTreeMap::from([
+            // Not supported in v0.1.x because the old custom connector doesn't have the mutation procedures of actors
+            (
+                NdcVersion::V02,
+                vec!["execute/common_metadata/custom_connector_v02_schema.json"],
+            ),
+        ]),
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
+// Tests the generated delete mutation of a model, with a `where` filter combined with the select
+// permission of the model
+#[test]
+fn test_model_mutations_delete() -> anyhow::Result<()> {
+    common::test_execution_expectation_for_multiple_ndc_versions(
+        "execute/models/mutations/delete",
+        &[
+            "execute/common_metadata/command_metadata.json",
+            "execute/models/mutations/common_metadata.json",
+        ],
+        BTreeMap::from([
+            // Not supported in v0.1.x because the old custom connector doesn't have the mutation procedures of actors
+            (
+                NdcVersion::V02,
+                vec!["execute/common_metadata/custom_connector_v02_schema.json"],
+            ),
+        ]),
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
 #[test]
 fn test_graphql_descriptions() -> anyhow::Result<()> {
     common::test_execution_expectation_for_multiple_ndc_versions(
@@ -2114,6 +2477,20 @@ fn test_aggregates_root_field_simple_select() -> anyhow::Result<()> {
     )
 }
 
+#[test]
+fn test_aggregates_root_field_permissions() -> anyhow::Result<()> {
+    let test_path_string = "execute/aggregates/root_field/permissions";
+    common::test_execution_expectation(
+        test_path_string,
+        &[
+            "execute/aggregates/common_metadata/postgres_connector_schema.json",
+            "execute/aggregates/common_metadata/pg_types.json",
+            "execute/aggregates/common_metadata/supergraph.json",
+        ],
+        common::TestOpenDDPipeline::YesPlease,
+    )
+}
+
 #[test]
 fn test_aggregates_root_field_filtering() -> anyhow::Result<()> {
     let test_path_string = "execute/aggregates/root_field/filtering";
@@ -2171,6 +2548,28 @@ fn test_aggregates_root_field_custom_count_return_type() -> anyhow::Result<()> {
     )
 }
 
+// Groups returned by a group by root field, filtered, ordered by aggregates and paginated
+// The OpenDD pipeline does not support group by fields yet, so it is skipped here
+#[test]
+fn test_aggregates_root_field_group_by() -> anyhow::Result<()> {
+    let test_path_string = "execute/aggregates/root_field/group_by";
+    common::test_execution_expectation_for_multiple_ndc_versions(
+        test_path_string,
+        &[
+            "execute/aggregates/common_metadata/custom_connector_v02_types.json",
+            "execute/aggregates/common_metadata/supergraph.json",
+        ],
+        BTreeMap::from([
+            // This test can't use the old NDC v0.1.x connector, it does not support grouping
+            (
+                NdcVersion::V02,
+                vec!["execute/aggregates/common_metadata/custom_connector_v02_schema.json"],
+            ),
+        ]),
+        common::TestOpenDDPipeline::Skip,
+    )
+}
+
 #[test]
 fn test_aggregates_root_field_typename() -> anyhow::Result<()> {
     let test_path_string = "execute/aggregates/root_field/typename";
@@ -81,3 +81,12 @@ fn test_remote_relationship_filter_array() -> anyhow::Result<()> {
         &["execute/common_metadata/postgres_connector_schema.json"],
     )
 }
+
+#[test]
+fn test_explain_permissions_relationship_filter() -> anyhow::Result<()> {
+    common::test_execute_explain_permissions(
+        "explain/permissions/relationship_filter",
+        "execute/relationships/array/metadata.json",
+        &["execute/common_metadata/postgres_connector_schema.json"],
+    )
+}
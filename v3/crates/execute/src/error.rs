@@ -1,7 +1,8 @@
 use engine_types::ExposeInternalErrors;
 use gql::{ast::common as ast, http::GraphQLError};
 use lang_graphql as gql;
-use open_dds::relationships::RelationshipName;
+use metadata_resolve::Qualified;
+use open_dds::{commands::CommandName, relationships::RelationshipName};
 use plan_types::RemotePredicateKey;
 use reqwest::StatusCode;
 use serde_json as json;
@@ -37,6 +38,14 @@ pub enum FieldError {
     #[error("Relationship '{name}' is either remote or not having 'relation_comparisons' NDC capability; not supported for filtering")]
     RelationshipPredicatesNotSupported { name: RelationshipName },
 
+    #[error("permission denied: an object returned by the command {command_name:} does not match the output filter of the role")]
+    CommandOutputFilterNotSatisfied {
+        command_name: Qualified<CommandName>,
+    },
+
+    #[error("placing nulls first or last when ordering is not supported by the data connector")]
+    NullsOrderNotSupported,
+
     #[error("internal error: {0}")]
     InternalError(#[from] FieldInternalError),
 }
@@ -50,7 +59,9 @@ impl FieldError {
             Self::InternalError(internal) => internal.get_details(),
             Self::FieldNotFoundInService { .. }
             | Self::SubscriptionsNotSupported
-            | Self::RelationshipPredicatesNotSupported { .. } => None,
+            | Self::RelationshipPredicatesNotSupported { .. }
+            | Self::NullsOrderNotSupported
+            | Self::CommandOutputFilterNotSatisfied { .. } => None,
         }
     }
 
@@ -85,6 +96,8 @@ impl TraceableError for FieldError {
             Self::NDCExpected { .. }
             | Self::FieldNotFoundInService { .. }
             | Self::RelationshipPredicatesNotSupported { .. }
+            | Self::CommandOutputFilterNotSatisfied { .. }
+            | Self::NullsOrderNotSupported
             | Self::SubscriptionsNotSupported => ErrorVisibility::User,
             Self::InternalError(internal_error) => internal_error.visibility(),
         }
@@ -2,6 +2,7 @@
 //! should not contain frontend-specific logic
 use crate::error;
 use std::sync::Arc;
+mod multiplex;
 mod ndc_request;
 mod remote_joins;
 mod remote_predicates;
@@ -13,9 +14,10 @@ pub use ndc_request::{
     make_ndc_mutation_request, make_ndc_query_request, v01::NdcV01CompatibilityError,
 };
 use plan_types::{
-    ExecutionTree, JoinLocations, NDCMutationExecution, NDCQueryExecution,
-    NDCSubscriptionExecution, PredicateQueryTrees, ProcessResponseAs, QueryExecutionPlan,
-    RemotePredicateKey, ResolvedFilterExpression,
+    ComparisonTarget, ComparisonValue, CursorPagination, ExecutionTree, JoinLocations,
+    LocalFieldComparison, NDCMutationExecution, NDCQueryExecution, NDCSubscriptionExecution,
+    PredicateQueryTrees, ProcessResponseAs, QueryExecutionPlan, RemotePredicateKey,
+    ResolvedFilterExpression, StreamCursor, VariableName,
 };
 pub use remote_predicates::replace_predicates_in_query_execution_plan;
 use std::collections::BTreeMap;
@@ -31,6 +33,7 @@ pub async fn resolve_ndc_query_execution(
         execution_span_attribute,
         ref field_span_attribute,
         process_response_as,
+        cursor_pagination: _,
     } = ndc_query;
 
     execute_execution_tree(
@@ -197,8 +200,9 @@ async fn execute_ndc_query<'s>(
     Ok(response.as_latest_rowsets())
 }
 
-// given results of ndc query, do any joins, and process result
-async fn run_remote_joins<'s, 'ir>(
+/// Given results of ndc query, do any joins, and process result. Also used to run the remote
+/// joins deferred with `@defer` once the initial response has been sent.
+pub async fn run_remote_joins<'s, 'ir>(
     http_context: &HttpContext,
     remote_join_executions: JoinLocations,
     execution_span_attribute: &'static str,
@@ -273,6 +277,7 @@ pub async fn resolve_ndc_subscription_execution<'s, 'ir>(
         field_span_attribute: _,
         process_response_as,
         polling_interval_ms,
+        stream_cursor: _,
     } = execution;
     // Remote relationships and relationships without NDC comparison capability are not allowed in predicates for subscriptions.
     // Only allow local relationships and fields that can be pushed down to NDC.
@@ -285,3 +290,212 @@ pub async fn resolve_ndc_subscription_execution<'s, 'ir>(
         polling_interval_ms,
     })
 }
+
+/// A subscription NDC query which can be polled together with other subscriptions of the
+/// same shape. Literal values in the query have been replaced with NDC variables, so the
+/// `query_execution_plan` is shared by all such subscriptions, and each subscriber only
+/// contributes its own `variables` to the request.
+pub struct NDCMultiplexedSubscriptionQuery {
+    pub query_execution_plan: QueryExecutionPlan,
+    pub variables: BTreeMap<VariableName, serde_json::Value>,
+    pub process_response_as: ProcessResponseAs,
+    pub polling_interval_ms: u64,
+}
+
+/// Resolve a subscription execution plan to a multiplexable NDC query.
+/// Returns the execution plan unchanged if the query cannot be multiplexed, ie. the data
+/// connector does not support query variables.
+pub fn resolve_multiplexed_ndc_subscription_execution(
+    execution: NDCSubscriptionExecution,
+) -> Result<NDCMultiplexedSubscriptionQuery, NDCSubscriptionExecution> {
+    if execution.stream_cursor.is_some()
+        || !multiplex::can_parameterize(&execution.query_execution_plan)
+    {
+        return Err(execution);
+    }
+    let NDCSubscriptionExecution {
+        query_execution_plan,
+        execution_span_attribute: _,
+        field_span_attribute: _,
+        process_response_as,
+        polling_interval_ms,
+        stream_cursor: _,
+    } = execution;
+    let (query_execution_plan, variables) =
+        multiplex::parameterize_query_execution_plan(query_execution_plan);
+    Ok(NDCMultiplexedSubscriptionQuery {
+        query_execution_plan,
+        variables,
+        process_response_as,
+        polling_interval_ms,
+    })
+}
+
+/// Make the NDC query request for a multiplexed subscription, with one variable set per
+/// subscriber. The connector returns one rowset per variable set, in the same order.
+pub fn make_multiplexed_ndc_query_request(
+    query_execution_plan: &QueryExecutionPlan,
+    variable_sets: Vec<BTreeMap<VariableName, serde_json::Value>>,
+) -> Result<ndc::NdcQueryRequest, FieldError> {
+    make_ndc_query_request(QueryExecutionPlan {
+        variables: Some(variable_sets),
+        ..query_execution_plan.clone()
+    })
+}
+
+/// A streaming subscription NDC query. Each poll fetches the next batch of rows after the
+/// current value of the cursor, which is then advanced to the cursor of the last row.
+pub struct NDCStreamSubscriptionQuery {
+    pub query_execution_plan: QueryExecutionPlan,
+    pub cursor: StreamCursor,
+    pub process_response_as: ProcessResponseAs,
+    pub polling_interval_ms: u64,
+}
+
+/// Resolve a subscription execution plan to a streaming NDC query.
+/// Returns the execution plan unchanged if it is not a streaming subscription.
+pub fn resolve_stream_ndc_subscription_execution(
+    execution: NDCSubscriptionExecution,
+) -> Result<NDCStreamSubscriptionQuery, NDCSubscriptionExecution> {
+    match execution {
+        NDCSubscriptionExecution {
+            query_execution_plan,
+            execution_span_attribute: _,
+            field_span_attribute: _,
+            process_response_as,
+            polling_interval_ms,
+            stream_cursor: Some(cursor),
+        } => Ok(NDCStreamSubscriptionQuery {
+            query_execution_plan,
+            cursor,
+            process_response_as,
+            polling_interval_ms,
+        }),
+        execution => Err(execution),
+    }
+}
+
+/// Make the NDC query request for the next batch of a streaming subscription, ie. the rows
+/// matching the query whose cursor column is greater than `cursor_value`.
+pub fn make_stream_ndc_query_request(
+    query_execution_plan: &QueryExecutionPlan,
+    cursor: &StreamCursor,
+    cursor_value: &serde_json::Value,
+) -> Result<ndc::NdcQueryRequest, FieldError> {
+    let cursor_predicate =
+        ResolvedFilterExpression::LocalFieldComparison(LocalFieldComparison::BinaryComparison {
+            column: ComparisonTarget::Column {
+                name: cursor.column.clone(),
+                field_path: vec![],
+            },
+            operator: cursor.greater_than_operator.clone(),
+            value: ComparisonValue::Scalar {
+                value: cursor_value.clone(),
+            },
+        });
+    let mut query_execution_plan = query_execution_plan.clone();
+    let predicate = match query_execution_plan.query_node.predicate.take() {
+        None => cursor_predicate,
+        Some(predicate) => ResolvedFilterExpression::And {
+            expressions: vec![predicate, cursor_predicate],
+        },
+    };
+    query_execution_plan.query_node.predicate = Some(predicate);
+    make_ndc_query_request(query_execution_plan)
+}
+
+/// Read the value of the stream cursor from the last row of a batch.
+/// Returns `None` if the batch is empty.
+pub fn get_stream_cursor_value(
+    cursor: &StreamCursor,
+    rowset: &ndc_models::RowSet,
+) -> Option<serde_json::Value> {
+    let last_row = rowset.rows.as_ref()?.last()?;
+    last_row
+        .get(cursor.field_alias.as_str())
+        .map(|value| value.0.clone())
+}
+
+/// A page of rows of a cursor paginated query, described by the cursors of its first and last
+/// rows, and whether it is preceded or followed by more rows.
+pub struct CursorPage {
+    pub has_next_page: bool,
+    pub has_previous_page: bool,
+    /// The values of the ordering fields of the first row of the page
+    pub start_cursor: Option<Vec<serde_json::Value>>,
+    /// The values of the ordering fields of the last row of the page
+    pub end_cursor: Option<Vec<serde_json::Value>>,
+}
+
+/// Trim the rows of a cursor paginated query to the requested page, and read the cursors of
+/// its first and last rows. The rows of a backwards page are put back in the requested order.
+pub fn paginate_rowset(
+    cursor_pagination: &CursorPagination,
+    rowset: &mut ndc_models::RowSet,
+) -> Result<CursorPage, FieldError> {
+    let rows = rowset.rows.get_or_insert_with(Vec::new);
+    // One row more than the page size is fetched, to tell whether there are more rows
+    let has_more_rows = match cursor_pagination.page_size {
+        None => false,
+        Some(page_size) => {
+            let page_size = usize::try_from(page_size).unwrap_or(usize::MAX);
+            let has_more_rows = rows.len() > page_size;
+            rows.truncate(page_size);
+            has_more_rows
+        }
+    };
+    if cursor_pagination.backwards {
+        rows.reverse();
+    }
+    let get_cursor = |row| get_row_cursor(cursor_pagination, row);
+    Ok(CursorPage {
+        has_next_page: has_more_rows && !cursor_pagination.backwards,
+        has_previous_page: has_more_rows && cursor_pagination.backwards,
+        start_cursor: rows.first().map(get_cursor).transpose()?,
+        end_cursor: rows.last().map(get_cursor).transpose()?,
+    })
+}
+
+/// Turn the groups of a query selecting distinct rows into its rows. Distinct rows are fetched by
+/// grouping the rows on the distinct columns, and the aggregates of each group are the fields of
+/// its first row. Row sets that have rows are left as they are.
+pub fn rows_from_distinct_groups(rowset: &mut ndc_models::RowSet) {
+    if rowset.rows.is_none() {
+        if let Some(groups) = rowset.groups.take() {
+            rowset.rows = Some(
+                groups
+                    .into_iter()
+                    .map(|group| {
+                        group
+                            .aggregates
+                            .into_iter()
+                            .map(|(field_name, value)| {
+                                (field_name, ndc_models::RowFieldValue(value))
+                            })
+                            .collect()
+                    })
+                    .collect(),
+            );
+        }
+    }
+}
+
+/// Read the cursor of a row of a cursor paginated query: the values of its ordering fields
+pub fn get_row_cursor(
+    cursor_pagination: &CursorPagination,
+    row: &indexmap::IndexMap<ndc_models::FieldName, ndc_models::RowFieldValue>,
+) -> Result<Vec<serde_json::Value>, FieldError> {
+    cursor_pagination
+        .cursor_field_aliases
+        .iter()
+        .map(|field_alias| {
+            row.get(field_alias.as_str())
+                .map(|value| value.0.clone())
+                .ok_or_else(|| {
+                    FieldError::from(error::NDCUnexpectedError::BadNDCResponse {
+                        summary: format!("missing field: {field_alias}"),
+                    })
+                })
+        })
+        .collect()
+}
@@ -0,0 +1,183 @@
+//! Parameterization of subscription queries, so that subscriptions which differ only in
+//! the literal values they compare against (eg: an `id` argument, or a session variable in a
+//! permission filter) produce the same NDC query and can be polled together, providing one
+//! NDC variable set per subscriber.
+use plan_types::{
+    AggregateComparison, Argument, ComparisonValue, LocalFieldComparison, QueryExecutionPlan,
+    ResolvedFilterExpression, VariableName,
+};
+use std::collections::BTreeMap;
+
+/// Prefix of the NDC variables that literal values are lifted into. Remote join variables
+/// are prefixed with `$`, so these can never clash with them.
+const SUBSCRIPTION_PARAMETER_PREFIX: &str = "__subscription_param_";
+
+/// The literal values lifted out of a query, in the order in which they were found.
+#[derive(Default)]
+struct Parameters(BTreeMap<VariableName, serde_json::Value>);
+
+impl Parameters {
+    fn lift(&mut self, value: serde_json::Value) -> VariableName {
+        let name = VariableName(format!("{SUBSCRIPTION_PARAMETER_PREFIX}{}", self.0.len()));
+        self.0.insert(name.clone(), value);
+        name
+    }
+}
+
+/// Whether the query can be parameterized, ie. the data connector supports query variables
+/// and the query does not already use variables of its own.
+pub(crate) fn can_parameterize(query_execution_plan: &QueryExecutionPlan) -> bool {
+    query_execution_plan
+        .data_connector
+        .capabilities
+        .supports_query_variables
+        && query_execution_plan.variables.is_none()
+}
+
+/// Replace the literal values in the root predicate and the collection arguments of the
+/// query with NDC variables. Returns the parameterized plan along with the variable set
+/// that reproduces the original query.
+pub(crate) fn parameterize_query_execution_plan(
+    query_execution_plan: QueryExecutionPlan,
+) -> (
+    QueryExecutionPlan,
+    BTreeMap<VariableName, serde_json::Value>,
+) {
+    let mut parameters = Parameters::default();
+
+    let arguments = query_execution_plan
+        .arguments
+        .into_iter()
+        .map(|(argument_name, argument)| {
+            (
+                argument_name,
+                parameterize_argument(argument, &mut parameters),
+            )
+        })
+        .collect();
+
+    let mut query_node = query_execution_plan.query_node;
+    query_node.predicate = query_node
+        .predicate
+        .map(|predicate| parameterize_filter_expression(predicate, &mut parameters));
+
+    (
+        QueryExecutionPlan {
+            query_node,
+            collection: query_execution_plan.collection,
+            arguments,
+            collection_relationships: query_execution_plan.collection_relationships,
+            variables: None,
+            data_connector: query_execution_plan.data_connector,
+        },
+        parameters.0,
+    )
+}
+
+fn parameterize_argument(argument: Argument, parameters: &mut Parameters) -> Argument {
+    match argument {
+        Argument::Literal { value } => Argument::Variable {
+            name: parameters.lift(value),
+        },
+        Argument::BooleanExpression { predicate } => Argument::BooleanExpression {
+            predicate: parameterize_filter_expression(predicate, parameters),
+        },
+        Argument::Variable { name } => Argument::Variable { name },
+    }
+}
+
+fn parameterize_filter_expression(
+    expression: ResolvedFilterExpression,
+    parameters: &mut Parameters,
+) -> ResolvedFilterExpression {
+    match expression {
+        ResolvedFilterExpression::And { expressions } => ResolvedFilterExpression::And {
+            expressions: expressions
+                .into_iter()
+                .map(|expression| parameterize_filter_expression(expression, parameters))
+                .collect(),
+        },
+        ResolvedFilterExpression::Or { expressions } => ResolvedFilterExpression::Or {
+            expressions: expressions
+                .into_iter()
+                .map(|expression| parameterize_filter_expression(expression, parameters))
+                .collect(),
+        },
+        ResolvedFilterExpression::Not { expression } => ResolvedFilterExpression::Not {
+            expression: Box::new(parameterize_filter_expression(*expression, parameters)),
+        },
+        ResolvedFilterExpression::LocalFieldComparison(
+            LocalFieldComparison::BinaryComparison {
+                column,
+                operator,
+                value: ComparisonValue::Scalar { value },
+            },
+        ) => {
+            ResolvedFilterExpression::LocalFieldComparison(LocalFieldComparison::BinaryComparison {
+                column,
+                operator,
+                value: ComparisonValue::Variable {
+                    name: parameters.lift(value),
+                },
+            })
+        }
+        ResolvedFilterExpression::LocalNestedArray {
+            column,
+            field_path,
+            predicate,
+        } => ResolvedFilterExpression::LocalNestedArray {
+            column,
+            field_path,
+            predicate: Box::new(parameterize_filter_expression(*predicate, parameters)),
+        },
+        ResolvedFilterExpression::LocalNestedScalarArray {
+            column,
+            field_path,
+            predicate,
+        } => ResolvedFilterExpression::LocalNestedScalarArray {
+            column,
+            field_path,
+            predicate: Box::new(parameterize_filter_expression(*predicate, parameters)),
+        },
+        ResolvedFilterExpression::LocalRelationshipComparison {
+            field_path,
+            relationship,
+            predicate,
+        } => ResolvedFilterExpression::LocalRelationshipComparison {
+            field_path,
+            relationship,
+            predicate: Box::new(parameterize_filter_expression(*predicate, parameters)),
+        },
+        ResolvedFilterExpression::LocalRelationshipAggregateComparison {
+            field_path,
+            relationship,
+            filter_predicate,
+            aggregate,
+            comparison,
+        } => ResolvedFilterExpression::LocalRelationshipAggregateComparison {
+            field_path,
+            relationship,
+            filter_predicate: filter_predicate.map(|filter_predicate| {
+                Box::new(parameterize_filter_expression(
+                    *filter_predicate,
+                    parameters,
+                ))
+            }),
+            aggregate,
+            comparison: match comparison {
+                AggregateComparison::BinaryComparison {
+                    operator,
+                    value: ComparisonValue::Scalar { value },
+                } => AggregateComparison::BinaryComparison {
+                    operator,
+                    value: ComparisonValue::Variable {
+                        name: parameters.lift(value),
+                    },
+                },
+                comparison => comparison,
+            },
+        },
+        expression @ (ResolvedFilterExpression::LocalFieldComparison(_)
+        | ResolvedFilterExpression::RemoteRelationshipComparison { .. }) => expression,
+    }
+}
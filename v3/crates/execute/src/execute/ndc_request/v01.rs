@@ -26,6 +26,15 @@ pub enum NdcV01CompatibilityError {
 
     #[error("Groupings are not supported in NDC v0.1.x")]
     GroupByNotSupported,
+
+    #[error("Relationships to fields of nested objects are not supported in NDC v0.1.x")]
+    NestedRelationshipTargetsNotSupported,
+
+    #[error("Ordering by a count of column values is not supported in NDC v0.1.x")]
+    ColumnCountOrderByTargetsNotSupported,
+
+    #[error("Comparisons against aggregates are not supported in NDC v0.1.x")]
+    AggregateComparisonsNotSupported,
 }
 
 pub fn make_query_request(
@@ -37,7 +46,7 @@ pub fn make_query_request(
         arguments: make_arguments(query_execution_plan.arguments)?,
         collection_relationships: make_collection_relationships(
             query_execution_plan.collection_relationships,
-        ),
+        )?,
         variables: make_variables(query_execution_plan.variables),
     };
     Ok(query_request)
@@ -61,7 +70,7 @@ pub fn make_mutation_request(
         operations: vec![mutation_operation],
         collection_relationships: make_collection_relationships(
             mutation_execution_plan.collection_relationships,
-        ),
+        )?,
     };
 
     Ok(mutation_request)
@@ -309,6 +318,11 @@ fn make_expression(
                 predicate: Some(Box::new(ndc_expression)),
             })
         }
+        ResolvedFilterExpression::LocalRelationshipAggregateComparison { .. } => Err(
+            FieldError::InternalError(FieldInternalError::NdcV01CompatibilityError(
+                NdcV01CompatibilityError::AggregateComparisonsNotSupported,
+            )),
+        ),
         // we are generating NDC request for one connector, we can ignore anything remote
         ResolvedFilterExpression::RemoteRelationshipComparison {
             remote_predicate_id: _,
@@ -422,30 +436,37 @@ fn make_nested_array(nested_field: NestedArray) -> Result<ndc_models_v01::Nested
 
 fn make_collection_relationships(
     collection_relationships: BTreeMap<plan_types::NdcRelationshipName, Relationship>,
-) -> BTreeMap<ndc_models_v01::RelationshipName, ndc_models_v01::Relationship> {
+) -> Result<BTreeMap<ndc_models_v01::RelationshipName, ndc_models_v01::Relationship>, FieldError> {
     collection_relationships
         .into_iter()
         .map(|(name, relationship)| {
-            (
+            Ok((
                 ndc_models_v01::RelationshipName::from(name.as_str()),
-                make_relationship(relationship),
-            )
+                make_relationship(relationship)?,
+            ))
         })
-        .collect::<BTreeMap<_, _>>()
+        .collect::<Result<BTreeMap<_, _>, FieldError>>()
 }
 
-fn make_relationship(relationship: Relationship) -> ndc_models_v01::Relationship {
-    ndc_models_v01::Relationship {
+fn make_relationship(
+    relationship: Relationship,
+) -> Result<ndc_models_v01::Relationship, FieldError> {
+    Ok(ndc_models_v01::Relationship {
         column_mapping: relationship
             .column_mapping
             .into_iter()
             .map(|(s, t)| {
-                (
+                let [t]: [_; 1] = t.try_into().map_err(|_| {
+                    FieldError::InternalError(FieldInternalError::NdcV01CompatibilityError(
+                        NdcV01CompatibilityError::NestedRelationshipTargetsNotSupported,
+                    ))
+                })?;
+                Ok((
                     ndc_models_v01::FieldName::new(s.into_inner()),
                     ndc_models_v01::FieldName::new(t.into_inner()),
-                )
+                ))
             })
-            .collect(),
+            .collect::<Result<_, FieldError>>()?,
         relationship_type: match relationship.relationship_type {
             open_dds::relationships::RelationshipType::Object => {
                 ndc_models_v01::RelationshipType::Object
@@ -458,7 +479,7 @@ fn make_relationship(relationship: Relationship) -> ndc_models_v01::Relationship
             relationship.target_collection.into_inner(),
         ),
         arguments: make_relationship_arguments(relationship.arguments),
-    }
+    })
 }
 
 fn make_order_by(
@@ -472,6 +493,13 @@ fn make_order_by(
                     order_direction: match element.order_direction {
                         OrderByDirection::Asc => ndc_models_v01::OrderDirection::Asc,
                         OrderByDirection::Desc => ndc_models_v01::OrderDirection::Desc,
+                        // NDC order directions cannot place nulls first or last
+                        OrderByDirection::AscNullsFirst
+                        | OrderByDirection::AscNullsLast
+                        | OrderByDirection::DescNullsFirst
+                        | OrderByDirection::DescNullsLast => {
+                            return Err(FieldError::NullsOrderNotSupported);
+                        }
                     },
                     target: make_order_by_target(element.target)?,
                 })
@@ -488,57 +516,106 @@ fn make_order_by_target(
             name,
             field_path,
             relationship_path,
+        } => Ok(ndc_models_v01::OrderByTarget::Column {
+            name: ndc_models_v01::FieldName::new(name.into_inner()),
+            path: make_order_by_path(relationship_path)?,
+            field_path: if field_path.is_empty() {
+                None
+            } else {
+                Some(
+                    field_path
+                        .iter()
+                        .map(|name| ndc_models_v01::FieldName::from(name.as_str()))
+                        .collect(),
+                )
+            },
+        }),
+        OrderByTarget::Aggregate {
+            relationship_path,
+            aggregate,
         } => {
-            let mut order_by_element_path = Vec::new();
-            // When using a nested relationship column, you'll have to provide all the relationships(paths)
-            // NDC has to traverse to access the column. The ordering of that paths is important.
-            // The order decides how to access the column.
-            //
-            // For example, if you have a model called `User` with a relationship column called `Posts`
-            // which has a relationship column called `Comments` which has a non-relationship column
-            // called `text`, you'll have to provide the following paths to access the `text` column:
-            // ["UserPosts", "PostsComments"]
-            for path_element in relationship_path {
-                if !path_element.field_path.is_empty() {
-                    return Err(FieldError::InternalError(
-                        FieldInternalError::NdcV01CompatibilityError(
-                            NdcV01CompatibilityError::NestedRelationshipsInOrderByTargetsNotSupported,
-                        ),
-                    ));
+            let path = make_order_by_path(relationship_path)?;
+            match aggregate {
+                AggregateFieldSelection::Count { column_path } if column_path.is_empty() => {
+                    Ok(ndc_models_v01::OrderByTarget::StarCountAggregate { path })
                 }
-
-                order_by_element_path.push(ndc_models_v01::PathElement {
-                    relationship: ndc_models_v01::RelationshipName::from(
-                        path_element.relationship_name.as_str(),
+                // NDC v0.1.x can only order by a count of rows
+                AggregateFieldSelection::Count { .. }
+                | AggregateFieldSelection::CountDistinct { .. } => Err(FieldError::InternalError(
+                    FieldInternalError::NdcV01CompatibilityError(
+                        NdcV01CompatibilityError::ColumnCountOrderByTargetsNotSupported,
                     ),
-                    arguments: BTreeMap::new(),
-                    predicate: match path_element.filter_predicate {
-                        Some(predicate) => Some(Box::new(make_expression(predicate)?)),
-                        // We convert all None predicates into an always true predicate to work
-                        // around a bug in the postgres connector
-                        None => Some(Box::new(ndc_models_v01::Expression::And {
-                            expressions: vec![],
-                        })),
-                    },
-                });
+                )),
+                AggregateFieldSelection::AggregationFunction {
+                    function_name,
+                    column_path,
+                } => {
+                    let nonempty::NonEmpty {
+                        head: column,
+                        tail: field_path,
+                    } = column_path;
+                    Ok(ndc_models_v01::OrderByTarget::SingleColumnAggregate {
+                        column: ndc_models_v01::FieldName::from(column.into_inner()),
+                        field_path: if field_path.is_empty() {
+                            None
+                        } else {
+                            Some(
+                                field_path
+                                    .into_iter()
+                                    .map(|column_name| {
+                                        ndc_models_v01::FieldName::from(column_name.into_inner())
+                                    })
+                                    .collect(),
+                            )
+                        },
+                        function: ndc_models_v01::AggregateFunctionName::from(
+                            function_name.as_str(),
+                        ),
+                        path,
+                    })
+                }
             }
+        }
+    }
+}
 
-            Ok(ndc_models_v01::OrderByTarget::Column {
-                name: ndc_models_v01::FieldName::new(name.into_inner()),
-                path: order_by_element_path,
-                field_path: if field_path.is_empty() {
-                    None
-                } else {
-                    Some(
-                        field_path
-                            .iter()
-                            .map(|name| ndc_models_v01::FieldName::from(name.as_str()))
-                            .collect(),
-                    )
-                },
-            })
+fn make_order_by_path(
+    relationship_path: Vec<plan_types::RelationshipPathElement<ResolvedFilterExpression>>,
+) -> Result<Vec<ndc_models_v01::PathElement>, FieldError> {
+    let mut order_by_element_path = Vec::new();
+    // When using a nested relationship column, you'll have to provide all the relationships(paths)
+    // NDC has to traverse to access the column. The ordering of that paths is important.
+    // The order decides how to access the column.
+    //
+    // For example, if you have a model called `User` with a relationship column called `Posts`
+    // which has a relationship column called `Comments` which has a non-relationship column
+    // called `text`, you'll have to provide the following paths to access the `text` column:
+    // ["UserPosts", "PostsComments"]
+    for path_element in relationship_path {
+        if !path_element.field_path.is_empty() {
+            return Err(FieldError::InternalError(
+                FieldInternalError::NdcV01CompatibilityError(
+                    NdcV01CompatibilityError::NestedRelationshipsInOrderByTargetsNotSupported,
+                ),
+            ));
         }
+
+        order_by_element_path.push(ndc_models_v01::PathElement {
+            relationship: ndc_models_v01::RelationshipName::from(
+                path_element.relationship_name.as_str(),
+            ),
+            arguments: BTreeMap::new(),
+            predicate: match path_element.filter_predicate {
+                Some(predicate) => Some(Box::new(make_expression(predicate)?)),
+                // We convert all None predicates into an always true predicate to work
+                // around a bug in the postgres connector
+                None => Some(Box::new(ndc_models_v01::Expression::And {
+                    expressions: vec![],
+                })),
+            },
+        });
     }
+    Ok(order_by_element_path)
 }
 
 /// Translates the internal IR 'AggregateSelectionSet' into an NDC query aggregates selection
@@ -97,7 +97,7 @@ fn make_query(query_node: QueryNodeNew) -> Result<ndc_models_v02::Query, FieldEr
         predicate: ndc_predicate,
         aggregates: query_node.aggregates.map(make_aggregates),
         fields: ndc_fields,
-        groups: query_node.group_by.map(make_group_by),
+        groups: query_node.group_by.map(make_group_by).transpose()?,
     })
 }
 
@@ -307,6 +307,57 @@ pub fn make_expression(
                 predicate: Some(Box::new(ndc_expression)),
             })
         }
+        ResolvedFilterExpression::LocalRelationshipAggregateComparison {
+            field_path,
+            relationship,
+            filter_predicate,
+            aggregate,
+            comparison,
+        } => {
+            let path_element = ndc_models_v02::PathElement {
+                field_path: if field_path.is_empty() {
+                    None
+                } else {
+                    Some(
+                        field_path
+                            .into_iter()
+                            .map(|f| ndc_models_v02::FieldName::new(f.into_inner()))
+                            .collect(),
+                    )
+                },
+                relationship: ndc_models_v02::RelationshipName::from(relationship.as_str()),
+                arguments: BTreeMap::new(),
+                predicate: filter_predicate
+                    .map(|filter_predicate| make_expression(*filter_predicate))
+                    .transpose()?
+                    .map(Box::new),
+            };
+            let column = ndc_models_v02::ComparisonTarget::Aggregate {
+                aggregate: make_aggregate(aggregate),
+                path: vec![path_element],
+            };
+            Ok(match comparison {
+                plan_types::AggregateComparison::UnaryComparison { operator } => {
+                    ndc_models_v02::Expression::UnaryComparisonOperator {
+                        column,
+                        operator: match operator {
+                            metadata_resolve::UnaryComparisonOperator::IsNull => {
+                                ndc_models_v02::UnaryComparisonOperator::IsNull
+                            }
+                        },
+                    }
+                }
+                plan_types::AggregateComparison::BinaryComparison { operator, value } => {
+                    ndc_models_v02::Expression::BinaryComparisonOperator {
+                        column,
+                        operator: ndc_models_v02::ComparisonOperatorName::new(
+                            operator.into_inner(),
+                        ),
+                        value: make_comparison_value(value),
+                    }
+                }
+            })
+        }
         // we are generating NDC request for one connector, we can ignore anything remote
         ResolvedFilterExpression::RemoteRelationshipComparison {
             remote_predicate_id: _,
@@ -440,7 +491,9 @@ fn make_relationship(relationship: Relationship) -> ndc_models_v02::Relationship
             .map(|(s, t)| {
                 (
                     ndc_models_v02::FieldName::new(s.into_inner()),
-                    vec![ndc_models_v02::FieldName::new(t.into_inner())],
+                    t.into_iter()
+                        .map(|t| ndc_models_v02::FieldName::new(t.into_inner()))
+                        .collect(),
                 )
             })
             .collect(),
@@ -459,7 +512,7 @@ fn make_relationship(relationship: Relationship) -> ndc_models_v02::Relationship
     }
 }
 
-fn make_group_by(grouping: plan_types::Grouping) -> ndc_models_v02::Grouping {
+fn make_group_by(grouping: plan_types::Grouping) -> Result<ndc_models_v02::Grouping, FieldError> {
     let aggregates = make_aggregates(plan_types::AggregateSelectionSet {
         fields: grouping.aggregates,
     });
@@ -494,14 +547,45 @@ fn make_group_by(grouping: plan_types::Grouping) -> ndc_models_v02::Grouping {
             }
         })
         .collect();
-    ndc_models_v02::Grouping {
+    // only send an ordering if there are actually elements
+    let order_by = if grouping.order_by.is_empty() {
+        None
+    } else {
+        Some(make_group_order_by(grouping.order_by)?)
+    };
+    Ok(ndc_models_v02::Grouping {
         aggregates,
         dimensions,
-        limit: None,
-        offset: None,
-        order_by: None,
+        limit: grouping.limit,
+        offset: grouping.offset,
+        order_by,
         predicate: None,
-    }
+    })
+}
+
+fn make_group_order_by(
+    order_by_elements: Vec<plan_types::GroupOrderByElement>,
+) -> Result<ndc_models_v02::GroupOrderBy, FieldError> {
+    Ok(ndc_models_v02::GroupOrderBy {
+        elements: order_by_elements
+            .into_iter()
+            .map(|element| {
+                Ok(ndc_models_v02::GroupOrderByElement {
+                    order_direction: make_order_direction(element.order_direction)?,
+                    target: match element.target {
+                        plan_types::GroupOrderByTarget::Dimension { index } => {
+                            ndc_models_v02::GroupOrderByTarget::Dimension { index }
+                        }
+                        plan_types::GroupOrderByTarget::Aggregate { aggregate } => {
+                            ndc_models_v02::GroupOrderByTarget::Aggregate {
+                                aggregate: make_aggregate(aggregate),
+                            }
+                        }
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>, FieldError>>()?,
+    })
 }
 
 fn make_order_by(
@@ -512,10 +596,7 @@ fn make_order_by(
             .into_iter()
             .map(|element| {
                 Ok(ndc_models_v02::OrderByElement {
-                    order_direction: match element.order_direction {
-                        OrderByDirection::Asc => ndc_models_v02::OrderDirection::Asc,
-                        OrderByDirection::Desc => ndc_models_v02::OrderDirection::Desc,
-                    },
+                    order_direction: make_order_direction(element.order_direction)?,
                     target: make_order_by_target(element.target)?,
                 })
             })
@@ -523,6 +604,20 @@ fn make_order_by(
     })
 }
 
+fn make_order_direction(
+    order_direction: OrderByDirection,
+) -> Result<ndc_models_v02::OrderDirection, FieldError> {
+    match order_direction {
+        OrderByDirection::Asc => Ok(ndc_models_v02::OrderDirection::Asc),
+        OrderByDirection::Desc => Ok(ndc_models_v02::OrderDirection::Desc),
+        // NDC order directions cannot place nulls first or last
+        OrderByDirection::AscNullsFirst
+        | OrderByDirection::AscNullsLast
+        | OrderByDirection::DescNullsFirst
+        | OrderByDirection::DescNullsLast => Err(FieldError::NullsOrderNotSupported),
+    }
+}
+
 fn make_order_by_target(
     target: OrderByTarget<ResolvedFilterExpression>,
 ) -> Result<ndc_models_v02::OrderByTarget, FieldError> {
@@ -531,58 +626,68 @@ fn make_order_by_target(
             name,
             field_path,
             relationship_path,
-        } => {
-            let mut order_by_element_path = Vec::new();
-            // When using a nested relationship column, you'll have to provide all the relationships(paths)
-            // NDC has to traverse to access the column. The ordering of that paths is important.
-            // The order decides how to access the column.
-            //
-            // For example, if you have a model called `User` with a relationship column called `Posts`
-            // which has a relationship column called `Comments` which has a non-relationship column
-            // called `text`, you'll have to provide the following paths to access the `text` column:
-            // ["UserPosts", "PostsComments"]
-            for path_element in relationship_path {
-                order_by_element_path.push(ndc_models_v02::PathElement {
-                    field_path: if path_element.field_path.is_empty() {
-                        None
-                    } else {
-                        Some(
-                            path_element
-                                .field_path
-                                .iter()
-                                .map(|name| ndc_models_v02::FieldName::from(name.as_str()))
-                                .collect(),
-                        )
-                    },
-                    relationship: ndc_models_v02::RelationshipName::from(
-                        path_element.relationship_name.as_str(),
-                    ),
-                    arguments: BTreeMap::new(),
-                    predicate: path_element
-                        .filter_predicate
-                        .map(make_expression)
-                        .transpose()?
-                        .map(Box::new),
-                });
-            }
+        } => Ok(ndc_models_v02::OrderByTarget::Column {
+            name: ndc_models_v02::FieldName::new(name.into_inner()),
+            arguments: BTreeMap::new(),
+            path: make_order_by_path(relationship_path)?,
+            field_path: if field_path.is_empty() {
+                None
+            } else {
+                Some(
+                    field_path
+                        .iter()
+                        .map(|name| ndc_models_v02::FieldName::from(name.as_str()))
+                        .collect(),
+                )
+            },
+        }),
+        OrderByTarget::Aggregate {
+            relationship_path,
+            aggregate,
+        } => Ok(ndc_models_v02::OrderByTarget::Aggregate {
+            path: make_order_by_path(relationship_path)?,
+            aggregate: make_aggregate(aggregate),
+        }),
+    }
+}
 
-            Ok(ndc_models_v02::OrderByTarget::Column {
-                name: ndc_models_v02::FieldName::new(name.into_inner()),
-                arguments: BTreeMap::new(),
-                path: order_by_element_path,
-                field_path: if field_path.is_empty() {
-                    None
-                } else {
-                    Some(
-                        field_path
-                            .iter()
-                            .map(|name| ndc_models_v02::FieldName::from(name.as_str()))
-                            .collect(),
-                    )
-                },
-            })
-        }
+fn make_order_by_path(
+    relationship_path: Vec<plan_types::RelationshipPathElement<ResolvedFilterExpression>>,
+) -> Result<Vec<ndc_models_v02::PathElement>, FieldError> {
+    let mut order_by_element_path = Vec::new();
+    // When using a nested relationship column, you'll have to provide all the relationships(paths)
+    // NDC has to traverse to access the column. The ordering of that paths is important.
+    // The order decides how to access the column.
+    //
+    // For example, if you have a model called `User` with a relationship column called `Posts`
+    // which has a relationship column called `Comments` which has a non-relationship column
+    // called `text`, you'll have to provide the following paths to access the `text` column:
+    // ["UserPosts", "PostsComments"]
+    for path_element in relationship_path {
+        order_by_element_path.push(ndc_models_v02::PathElement {
+            field_path: if path_element.field_path.is_empty() {
+                None
+            } else {
+                Some(
+                    path_element
+                        .field_path
+                        .iter()
+                        .map(|name| ndc_models_v02::FieldName::from(name.as_str()))
+                        .collect(),
+                )
+            },
+            relationship: ndc_models_v02::RelationshipName::from(
+                path_element.relationship_name.as_str(),
+            ),
+            arguments: BTreeMap::new(),
+            predicate: path_element
+                .filter_predicate
+                .map(make_expression)
+                .transpose()?
+                .map(Box::new),
+        });
     }
+    Ok(order_by_element_path)
 }
 
 /// Translates the internal IR 'AggregateSelectionSet' into an NDC query aggregates selection
@@ -593,49 +698,48 @@ fn make_aggregates(
         .fields
         .into_iter()
         .map(|(field_name, aggregate_selection)| {
-            let aggregate = match aggregate_selection {
-                AggregateFieldSelection::Count { column_path, .. } => {
-                    make_count_aggregate(column_path, false)
-                }
-                AggregateFieldSelection::CountDistinct { column_path, .. } => {
-                    make_count_aggregate(column_path, true)
-                }
-                AggregateFieldSelection::AggregationFunction {
-                    function_name,
-                    column_path,
-                } => {
-                    let nonempty::NonEmpty {
-                        head: column,
-                        tail: field_path,
-                    } = column_path;
-                    let nested_field_path = field_path
-                        .into_iter()
-                        .map(|column_name| {
-                            ndc_models_v02::FieldName::from(column_name.into_inner())
-                        })
-                        .collect::<Vec<_>>();
-                    ndc_models_v02::Aggregate::SingleColumn {
-                        column: ndc_models_v02::FieldName::from(column.into_inner()),
-                        arguments: BTreeMap::new(),
-                        field_path: if nested_field_path.is_empty() {
-                            None
-                        } else {
-                            Some(nested_field_path)
-                        },
-                        function: ndc_models_v02::AggregateFunctionName::from(
-                            function_name.as_str(),
-                        ),
-                    }
-                }
-            };
             (
                 ndc_models_v02::FieldName::from(field_name.as_str()),
-                aggregate,
+                make_aggregate(aggregate_selection),
             )
         })
         .collect()
 }
 
+fn make_aggregate(aggregate_selection: AggregateFieldSelection) -> ndc_models_v02::Aggregate {
+    match aggregate_selection {
+        AggregateFieldSelection::Count { column_path, .. } => {
+            make_count_aggregate(column_path, false)
+        }
+        AggregateFieldSelection::CountDistinct { column_path, .. } => {
+            make_count_aggregate(column_path, true)
+        }
+        AggregateFieldSelection::AggregationFunction {
+            function_name,
+            column_path,
+        } => {
+            let nonempty::NonEmpty {
+                head: column,
+                tail: field_path,
+            } = column_path;
+            let nested_field_path = field_path
+                .into_iter()
+                .map(|column_name| ndc_models_v02::FieldName::from(column_name.into_inner()))
+                .collect::<Vec<_>>();
+            ndc_models_v02::Aggregate::SingleColumn {
+                column: ndc_models_v02::FieldName::from(column.into_inner()),
+                arguments: BTreeMap::new(),
+                field_path: if nested_field_path.is_empty() {
+                    None
+                } else {
+                    Some(nested_field_path)
+                },
+                function: ndc_models_v02::AggregateFunctionName::from(function_name.as_str()),
+            }
+        }
+    }
+}
+
 /// Creates the appropriate NDC count aggregation based on whether we're selecting
 /// a column (nested or otherwise) or not
 fn make_count_aggregate(
@@ -94,6 +94,14 @@ pub async fn execute_join_locations(
 ) -> Result<(), error::FieldError> {
     let tracer = tracing_util::global_tracer();
 
+    // the joins are made on the rows of the LHS response, which are fetched as groups when
+    // selecting distinct rows. The groups of a group by query are returned as they are.
+    if !matches!(lhs_response_type, ProcessResponseAs::Groups { .. }) {
+        for row_set in lhs_response.iter_mut() {
+            super::rows_from_distinct_groups(row_set);
+        }
+    }
+
     // collect the join column arguments from the LHS response
     let mut location_path = Vec::new();
     let next_join_nodes = tracer.in_span(
@@ -12,7 +12,10 @@ use std::collections::{BTreeMap, HashSet};
 use json_ext::ValueExt;
 
 use super::error;
-use plan_types::FUNCTION_IR_VALUE_COLUMN_NAME;
+use plan_types::{
+    make_model_argument_variable_name, make_model_field_variable_name,
+    FUNCTION_IR_VALUE_COLUMN_NAME,
+};
 use plan_types::{CommandReturnKind, ProcessResponseAs, VariableName};
 use plan_types::{
     JoinLocations, JoinNode, LocationKind, RemoteJoin, RemoteJoinArgument, SourceFieldAlias,
@@ -102,10 +105,20 @@ fn collect_argument_from_rows(
         if let Some(ref rows) = row_set.rows {
             for row in rows {
                 match lhs_response_type {
-                    ProcessResponseAs::Array { .. } | ProcessResponseAs::Object { .. } => {
+                    ProcessResponseAs::Array { .. }
+                    | ProcessResponseAs::Object { .. }
+                    | ProcessResponseAs::Connection { .. } => {
                         collect_argument_from_row(row, join_fields, path, &mut arguments)?;
                     }
-                    ProcessResponseAs::Aggregates { .. } => {
+                    ProcessResponseAs::ModelMutationResponse => {
+                        return Err(error::FieldInternalError::InternalGeneric {
+                            description:
+                                "Unexpected model mutation response on the LHS of a remote join"
+                                    .to_owned(),
+                        }
+                        .into())
+                    }
+                    ProcessResponseAs::Aggregates { .. } | ProcessResponseAs::Groups { .. } => {
                         return Err(error::FieldInternalError::InternalGeneric {
                             description:
                                 "Unexpected aggregate response on the LHS of a remote join"
@@ -118,6 +131,8 @@ fn collect_argument_from_rows(
                         is_nullable,
                         return_kind,
                         response_config: _,
+                        check_output_filter: _,
+                        type_discrimination: _,
                     } => {
                         let mut command_rows =
                             resolve_command_response_row(row, *is_nullable, *return_kind)?;
@@ -183,7 +198,11 @@ pub(crate) fn get_join_fields(join_node: &RemoteJoin) -> Vec<(&SourceFieldAlias,
             TargetField::ModelField((_, field_mapping)) => {
                 // use the target field name here to create the variable
                 // name to be used in RHS
-                let variable_name = VariableName(format!("${}", &field_mapping.column));
+                let variable_name = make_model_field_variable_name(field_mapping);
+                join_fields.push((src_alias, variable_name));
+            }
+            TargetField::ModelArgument(argument_name) => {
+                let variable_name = make_model_argument_variable_name(argument_name);
                 join_fields.push((src_alias, variable_name));
             }
             TargetField::CommandField(argument_name) => {
@@ -215,8 +234,16 @@ pub(crate) fn get_value<'n>(
     pick_alias: &SourceFieldAlias,
     row: &'n IndexMap<ndc_models::FieldName, ndc_models::RowFieldValue>,
 ) -> &'n json::Value {
-    match row.get(pick_alias.0.as_str()) {
-        Some(v) => &v.0,
+    match row.get(pick_alias.alias.as_str()) {
+        // walk into nested objects when the join field is inside one; a missing
+        // or null object along the way makes the value null
+        Some(v) => pick_alias
+            .nested_path
+            .iter()
+            .try_fold(&v.0, |value, nested_column| {
+                value.get(nested_column.as_str())
+            })
+            .unwrap_or(&json::Value::Null),
         None => &json::Value::Null,
     }
 }
@@ -252,7 +279,7 @@ fn rows_from_row_field_value(
             LocationKind::LocalRelationship => {
                 // Get the NDC response with nested selection (i.e. in case of
                 // relationships) as a RowSet
-                let row_set = nested_val
+                let mut row_set = nested_val
                     // TODO: remove clone -> depends on ndc-client providing an API e.g. as_mut_rowset()
                     .clone()
                     .as_rowset()
@@ -261,6 +288,7 @@ fn rows_from_row_field_value(
                             .to_string()
                             + &nested_val.0.to_string(),
                     })?;
+                crate::execute::rows_from_distinct_groups(&mut row_set);
                 Ok(row_set.rows)
             }
         }?;
@@ -180,6 +180,7 @@ fn visit_location_path_and_insert_value(
     match location_kind {
         LocationKind::LocalRelationship => {
             let mut row_set: ndc_models::RowSet = json::from_value(row_field_val.0.clone())?;
+            crate::execute::rows_from_distinct_groups(&mut row_set);
             let mut rows = row_set
                 .rows
                 .ok_or(error::FieldInternalError::InternalGeneric {
@@ -102,25 +102,46 @@ fn replace_predicates_in_order_by_target(
             name,
             field_path,
         } => Ok(OrderByTarget::Column {
-            relationship_path: relationship_path
-                .into_iter()
-                .map(|relationship_path_element| {
-                    Ok(plan_types::RelationshipPathElement {
-                        field_path: relationship_path_element.field_path,
-                        relationship_name: relationship_path_element.relationship_name,
-                        filter_predicate: relationship_path_element
-                            .filter_predicate
-                            .map(|pred| replace_predicates_in_filter_expression(pred, predicates))
-                            .transpose()?,
-                    })
-                })
-                .collect::<Result<Vec<_>, FilterPredicateError>>()?,
+            relationship_path: replace_predicates_in_relationship_path(
+                relationship_path,
+                predicates,
+            )?,
             name,
             field_path,
         }),
+        OrderByTarget::Aggregate {
+            relationship_path,
+            aggregate,
+        } => Ok(OrderByTarget::Aggregate {
+            relationship_path: replace_predicates_in_relationship_path(
+                relationship_path,
+                predicates,
+            )?,
+            aggregate,
+        }),
     }
 }
 
+fn replace_predicates_in_relationship_path(
+    relationship_path: Vec<plan_types::RelationshipPathElement<ResolvedFilterExpression>>,
+    predicates: &BTreeMap<RemotePredicateKey, ResolvedFilterExpression>,
+) -> Result<Vec<plan_types::RelationshipPathElement<ResolvedFilterExpression>>, FilterPredicateError>
+{
+    relationship_path
+        .into_iter()
+        .map(|relationship_path_element| {
+            Ok(plan_types::RelationshipPathElement {
+                field_path: relationship_path_element.field_path,
+                relationship_name: relationship_path_element.relationship_name,
+                filter_predicate: relationship_path_element
+                    .filter_predicate
+                    .map(|pred| replace_predicates_in_filter_expression(pred, predicates))
+                    .transpose()?,
+            })
+        })
+        .collect()
+}
+
 fn replace_predicates_in_nested_field(
     nested_field: NestedField,
     predicates: &BTreeMap<RemotePredicateKey, ResolvedFilterExpression>,
@@ -271,6 +292,24 @@ fn replace_predicates_in_filter_expression(
                 *predicate, predicates,
             )?),
         },
+        ResolvedFilterExpression::LocalRelationshipAggregateComparison {
+            field_path,
+            relationship,
+            filter_predicate,
+            aggregate,
+            comparison,
+        } => ResolvedFilterExpression::LocalRelationshipAggregateComparison {
+            field_path,
+            relationship,
+            filter_predicate: filter_predicate
+                .map(|filter_predicate| {
+                    replace_predicates_in_filter_expression(*filter_predicate, predicates)
+                        .map(Box::new)
+                })
+                .transpose()?,
+            aggregate,
+            comparison,
+        },
     })
 }
 
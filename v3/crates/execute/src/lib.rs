@@ -5,8 +5,12 @@ pub mod ndc;
 // we explicitly export things used by other crates
 pub use error::{FieldError, FieldInternalError, NDCUnexpectedError};
 pub use execute::{
-    execute_remote_predicates, make_ndc_mutation_request, make_ndc_query_request,
-    replace_predicates_in_query_execution_plan, resolve_ndc_mutation_execution,
+    execute_remote_predicates, get_row_cursor, get_stream_cursor_value,
+    make_multiplexed_ndc_query_request, make_ndc_mutation_request, make_ndc_query_request,
+    make_stream_ndc_query_request, paginate_rowset, replace_predicates_in_query_execution_plan,
+    resolve_multiplexed_ndc_subscription_execution, resolve_ndc_mutation_execution,
     resolve_ndc_query_execution, resolve_ndc_subscription_execution,
+    resolve_stream_ndc_subscription_execution, rows_from_distinct_groups, run_remote_joins,
+    CursorPage, NDCMultiplexedSubscriptionQuery, NDCStreamSubscriptionQuery,
 };
 pub use ndc::fetch_from_data_connector;
@@ -1,6 +1,11 @@
+mod defer;
 mod types;
 
-use crate::process_response::{process_mutation_response, process_response, ProcessedResponse};
+use crate::process_response::{
+    process_mutation_response, process_response, process_response_from_borrowed_rows,
+    ProcessedResponse,
+};
+pub use defer::DeferredQueryExecution;
 use engine_types::{HttpContext, ProjectId};
 use execute::FieldError;
 use execute::{resolve_ndc_mutation_execution, resolve_ndc_query_execution};
@@ -10,10 +15,11 @@ use graphql_ir::MutationPlan;
 use graphql_ir::{ApolloFederationSelect, NodeQueryPlan, QueryPlan};
 use graphql_schema::GDSRoleNamespaceGetter;
 use graphql_schema::GDS;
+use hasura_authn_core::SessionVariables;
 use indexmap::IndexMap;
 use lang_graphql as gql;
 use lang_graphql::ast::common as ast;
-use plan_types::{NDCMutationExecution, NDCQueryExecution};
+use plan_types::{CursorPagination, NDCMutationExecution, NDCQueryExecution, ProcessResponseAs};
 use tracing_util::{set_attribute_on_active_span, AttributeVisibility};
 pub use types::{ExecuteQueryResult, RootFieldResult};
 
@@ -23,6 +29,7 @@ pub async fn execute_query_plan<'n, 's, 'ir>(
     http_context: &HttpContext,
     query_plan: QueryPlan<'n, 's, 'ir>,
     project_id: Option<&ProjectId>,
+    session_variables: &SessionVariables,
 ) -> ExecuteQueryResult {
     let mut root_fields = IndexMap::new();
 
@@ -30,8 +37,14 @@ pub async fn execute_query_plan<'n, 's, 'ir>(
     // To run the field plans parallely, we will need to use tokio::spawn for each field plan.
     let executed_root_fields =
         futures_ext::execute_concurrently(query_plan.into_iter(), |(alias, field_plan)| async {
-            let plan_result =
-                execute_query_field_plan(&alias, http_context, field_plan, project_id).await;
+            let plan_result = execute_query_field_plan(
+                &alias,
+                http_context,
+                field_plan,
+                project_id,
+                session_variables,
+            )
+            .await;
             (alias, plan_result)
         })
         .await;
@@ -43,12 +56,176 @@ pub async fn execute_query_plan<'n, 's, 'ir>(
     ExecuteQueryResult { root_fields }
 }
 
+/// Given an entire plan for a query, produce the initial result of an incremental response,
+/// which leaves out the fragments deferred with `@defer`, along with the deferred execution of
+/// those fragments.
+pub async fn execute_query_plan_incremental<'n, 's, 'ir>(
+    http_context: &HttpContext,
+    query_plan: QueryPlan<'n, 's, 'ir>,
+    project_id: Option<&ProjectId>,
+    session_variables: &SessionVariables,
+) -> (ExecuteQueryResult, DeferredQueryExecution<'ir, 's>) {
+    let mut root_fields = IndexMap::new();
+    let mut deferred_root_fields = Vec::new();
+
+    let executed_root_fields =
+        futures_ext::execute_concurrently(query_plan.into_iter(), |(alias, field_plan)| async {
+            let plan_result = execute_incremental_query_field_plan(
+                &alias,
+                http_context,
+                field_plan,
+                project_id,
+                session_variables,
+            )
+            .await;
+            (alias, plan_result)
+        })
+        .await;
+
+    for (alias, (root_field, deferred_root_field)) in executed_root_fields {
+        root_fields.insert(alias, root_field);
+        deferred_root_fields.extend(deferred_root_field);
+    }
+
+    (
+        ExecuteQueryResult { root_fields },
+        DeferredQueryExecution {
+            root_fields: deferred_root_fields,
+        },
+    )
+}
+
+/// Execute a single root field's query plan, leaving out the remote joins of its deferred
+/// fields, which are returned to be executed later. Only the deferred fields of model
+/// selections are delivered incrementally; those of other root fields are sent in the initial
+/// payload, as servers are allowed to by the incremental delivery RFC.
+async fn execute_incremental_query_field_plan<'n, 's, 'ir>(
+    field_alias: &ast::Alias,
+    http_context: &HttpContext,
+    query_plan: NodeQueryPlan<'n, 's, 'ir>,
+    project_id: Option<&ProjectId>,
+    session_variables: &SessionVariables,
+) -> (RootFieldResult, Option<defer::DeferredRootField<'ir, 's>>) {
+    match query_plan {
+        NodeQueryPlan::NDCQueryExecution {
+            query_execution,
+            selection_set,
+        } if selection_set.has_deferred_fields()
+            && matches!(
+                query_execution.process_response_as,
+                ProcessResponseAs::Array { .. } | ProcessResponseAs::Object { .. }
+            ) =>
+        {
+            let NDCQueryExecution {
+                mut execution_tree,
+                execution_span_attribute,
+                field_span_attribute,
+                process_response_as,
+                cursor_pagination,
+            } = query_execution;
+            let (initial_join_locations, deferred_join_locations) = defer::split_join_locations(
+                std::mem::take(&mut execution_tree.remote_join_executions),
+                selection_set,
+            );
+            execution_tree.remote_join_executions = initial_join_locations;
+            let initial_selection_set = selection_set.without_deferred_fields();
+            let query_execution = NDCQueryExecution {
+                execution_tree,
+                execution_span_attribute,
+                field_span_attribute,
+                process_response_as: process_response_as.clone(),
+                cursor_pagination: cursor_pagination.clone(),
+            };
+
+            let tracer = tracing_util::global_tracer();
+            let result = tracer
+                .in_span_async(
+                    "execute_query_field_plan",
+                    format!("{field_alias} field planning"),
+                    tracing_util::SpanVisibility::User,
+                    || {
+                        Box::pin(async {
+                            let mut row_sets = execute::resolve_ndc_query_execution(
+                                http_context,
+                                query_execution,
+                                project_id,
+                            )
+                            .await?;
+                            let page_info = cursor_pagination
+                                .as_ref()
+                                .map(|cursor_pagination| {
+                                    paginate_row_sets(cursor_pagination, &mut row_sets)
+                                })
+                                .transpose()?;
+                            // The rows are kept to run the deferred remote joins on them
+                            let processed_response = process_response_from_borrowed_rows(
+                                &initial_selection_set,
+                                &row_sets,
+                                &process_response_as,
+                                session_variables,
+                            )?;
+                            Ok::<_, FieldError>((processed_response, page_info, row_sets))
+                        })
+                    },
+                )
+                .await;
+
+            match result {
+                Ok((processed_response, page_info, row_sets)) => {
+                    // The deferred fragments are found in the initial payload, to report the
+                    // errors of their execution at their paths
+                    let fragments = defer::collect_deferred_fragments(
+                        field_alias,
+                        &processed_response.response,
+                        selection_set,
+                    );
+                    (
+                        RootFieldResult::from_processed_response(
+                            process_response_as.is_nullable(),
+                            Ok(processed_response),
+                        )
+                        .with_page_info(page_info),
+                        Some(defer::DeferredRootField {
+                            alias: field_alias.clone(),
+                            selection_set,
+                            row_sets,
+                            fragments,
+                            join_locations: deferred_join_locations,
+                            process_response_as,
+                            execution_span_attribute,
+                        }),
+                    )
+                }
+                Err(error) => (
+                    RootFieldResult::from_processed_response(
+                        process_response_as.is_nullable(),
+                        Err(error),
+                    ),
+                    None,
+                ),
+            }
+        }
+        query_plan => (
+            execute_query_field_plan(
+                field_alias,
+                http_context,
+                query_plan,
+                project_id,
+                session_variables,
+            )
+            .await,
+            None,
+        ),
+    }
+}
+
 /// Execute a single root field's query plan to produce a result.
 async fn execute_query_field_plan<'n, 's, 'ir>(
     field_alias: &ast::Alias,
     http_context: &HttpContext,
     query_plan: NodeQueryPlan<'n, 's, 'ir>,
     project_id: Option<&ProjectId>,
+    session_variables: &SessionVariables,
 ) -> RootFieldResult {
     let tracer = tracing_util::global_tracer();
 
@@ -107,17 +284,24 @@ async fn execute_query_field_plan<'n, 's, 'ir>(
                             selection_set,
                         } => {
                             let process_response_as = &ndc_query.process_response_as.clone();
+                            let cursor_pagination = ndc_query.cursor_pagination.clone();
+                            let mut page_info = None;
                             let processed_response = execute::resolve_ndc_query_execution(
                                 http_context,
                                 ndc_query,
                                 project_id,
                             )
                             .await
-                            .and_then(|vec_sets| {
+                            .and_then(|mut vec_sets| {
+                                if let Some(cursor_pagination) = &cursor_pagination {
+                                    page_info =
+                                        Some(paginate_row_sets(cursor_pagination, &mut vec_sets)?);
+                                }
                                 process_response(
                                     selection_set,
                                     vec_sets,
                                     process_response_as,
+                                    session_variables,
                                 )
                             });
 
@@ -125,14 +309,20 @@ async fn execute_query_field_plan<'n, 's, 'ir>(
                                 process_response_as.is_nullable(),
                                 processed_response,
                             )
+                            .with_page_info(page_info)
                         }
 
                         NodeQueryPlan::RelayNodeSelect(optional_query) => RootFieldResult::from_processed_response(
                             optional_query.as_ref().map_or(true, |(ndc_query,_selection_set)| {
                                 ndc_query.process_response_as.is_nullable()
                             }),
-                            resolve_optional_ndc_select(http_context, optional_query, project_id)
-                                .await,
+                            resolve_optional_ndc_select(
+                                http_context,
+                                optional_query,
+                                project_id,
+                                session_variables,
+                            )
+                            .await,
                         ),
                         NodeQueryPlan::ApolloFederationSelect(
                             ApolloFederationSelect::EntitiesSelect(entity_execution_plans),
@@ -147,6 +337,7 @@ async fn execute_query_field_plan<'n, 's, 'ir>(
                                         http_context,
                                         Some(query),
                                         project_id,
+                                        session_variables,
                                     )
                                     .await,)
                                 };
@@ -197,6 +388,30 @@ async fn execute_query_field_plan<'n, 's, 'ir>(
         .await
 }
 
+/// Trims the rows of a cursor paginated root field to the requested page, and builds the page
+/// info of the page, which is returned in the extensions of the response.
+fn paginate_row_sets(
+    cursor_pagination: &CursorPagination,
+    row_sets: &mut [ndc_models::RowSet],
+) -> Result<serde_json::Value, FieldError> {
+    let row_set =
+        row_sets
+            .first_mut()
+            .ok_or_else(|| execute::NDCUnexpectedError::BadNDCResponse {
+                summary: "Unable to parse response from NDC, a row set was expected".into(),
+            })?;
+    let page = execute::paginate_rowset(cursor_pagination, row_set)?;
+    let encode_cursor = |cursor: Option<Vec<serde_json::Value>>| {
+        cursor.map(graphql_ir::encode_pagination_cursor).transpose()
+    };
+    Ok(serde_json::json!({
+        "hasNextPage": page.has_next_page,
+        "hasPreviousPage": page.has_previous_page,
+        "startCursor": encode_cursor(page.start_cursor)?,
+        "endCursor": encode_cursor(page.end_cursor)?,
+    }))
+}
+
 fn resolve_type_name(type_name: ast::TypeName) -> Result<serde_json::Value, FieldError> {
     Ok(serde_json::to_value(type_name)?)
 }
@@ -207,6 +422,7 @@ async fn execute_mutation_field_plan<'n, 's>(
     mutation_plan: NDCMutationExecution,
     selection_set: &normalized_ast::SelectionSet<'_, GDS>,
     project_id: Option<&ProjectId>,
+    session_variables: &SessionVariables,
 ) -> RootFieldResult {
     let tracer = tracing_util::global_tracer();
     tracer
@@ -225,6 +441,7 @@ async fn execute_mutation_field_plan<'n, 's>(
                                     selection_set,
                                     mutation_response,
                                     process_response_as,
+                                    session_variables,
                                 )
                             });
 
@@ -245,6 +462,7 @@ pub async fn execute_mutation_plan<'n, 's>(
     http_context: &HttpContext,
     mutation_plan: MutationPlan<'n, 's>,
     project_id: Option<&ProjectId>,
+    session_variables: &SessionVariables,
 ) -> ExecuteQueryResult {
     let mut root_fields = IndexMap::new();
     let mut executed_root_fields = Vec::new();
@@ -270,6 +488,7 @@ pub async fn execute_mutation_plan<'n, 's>(
                     field_plan.mutation_execution,
                     field_plan.selection_set,
                     project_id,
+                    session_variables,
                 )
                 .await,
             ));
@@ -316,6 +535,7 @@ async fn resolve_optional_ndc_select(
     http_context: &HttpContext,
     optional_query: Option<(NDCQueryExecution, &normalized_ast::SelectionSet<'_, GDS>)>,
     project_id: Option<&ProjectId>,
+    session_variables: &SessionVariables,
 ) -> Result<ProcessedResponse, FieldError> {
     match optional_query {
         None => Ok(ProcessedResponse {
@@ -326,7 +546,14 @@ async fn resolve_optional_ndc_select(
             let process_response_as = &ndc_query.process_response_as.clone();
             resolve_ndc_query_execution(http_context, ndc_query, project_id)
                 .await
-                .and_then(|row_sets| process_response(selection_set, row_sets, process_response_as))
+                .and_then(|row_sets| {
+                    process_response(
+                        selection_set,
+                        row_sets,
+                        process_response_as,
+                        session_variables,
+                    )
+                })
         }
     }
 }
@@ -0,0 +1,311 @@
+//! Execution of the fragments deferred with `@defer`.
+//!
+//! The remote joins of deferred fields are left out of the execution of a root field, so that
+//! the initial payload of the response is sent as soon as the rest of the root field is
+//! executed. The deferred remote joins are then run on the rows of that execution, and the
+//! deferred fields are sent in subsequent payloads, at the paths of the objects they were
+//! selected on.
+
+use futures_util::stream::{FuturesUnordered, Stream, StreamExt};
+use indexmap::IndexMap;
+use lang_graphql as gql;
+use lang_graphql::ast::common as ast;
+use lang_graphql::normalized_ast;
+use nonempty::NonEmpty;
+use serde_json as json;
+use tracing_util::SpanVisibility;
+
+use crate::process_response::process_response;
+use engine_types::{ExposeInternalErrors, HttpContext, ProjectId};
+use graphql_schema::GDS;
+use hasura_authn_core::SessionVariables;
+use plan_types::{JoinLocations, JoinNode, Location, ProcessResponseAs};
+
+/// The deferred fields of a root field, executed once the initial payload is sent.
+pub(crate) struct DeferredRootField<'ir, 's> {
+    pub(crate) alias: ast::Alias,
+    pub(crate) selection_set: &'ir normalized_ast::SelectionSet<'s, GDS>,
+    /// The rows of the initial execution of the root field
+    pub(crate) row_sets: Vec<ndc_models::RowSet>,
+    /// The deferred fragments of the root field, as found in the initial payload
+    pub(crate) fragments: Vec<DeferredFragment>,
+    pub(crate) join_locations: JoinLocations,
+    pub(crate) process_response_as: ProcessResponseAs,
+    pub(crate) execution_span_attribute: &'static str,
+}
+
+/// A deferred fragment, by its label and the path of the object it was selected on.
+pub(crate) struct DeferredFragment {
+    label: Option<String>,
+    path: gql::http::Path,
+}
+
+/// The fragments of a query deferred with `@defer`, to be executed once the initial payload of
+/// the response is sent.
+pub struct DeferredQueryExecution<'ir, 's> {
+    pub(crate) root_fields: Vec<DeferredRootField<'ir, 's>>,
+}
+
+impl<'ir, 's> DeferredQueryExecution<'ir, 's> {
+    /// Whether there is nothing deferred, ie. the response is not incremental.
+    pub fn is_empty(&self) -> bool {
+        self.root_fields.is_empty()
+    }
+
+    /// Executes the deferred fragments. A subsequent payload is yielded for each root field
+    /// as soon as its deferred fragments are executed.
+    pub fn execute<'a>(
+        self,
+        http_context: &'a HttpContext,
+        project_id: Option<&'a ProjectId>,
+        expose_internal_errors: ExposeInternalErrors,
+        session_variables: &'a SessionVariables,
+    ) -> impl Stream<Item = gql::http::Response> + 'a
+    where
+        'ir: 'a,
+        's: 'a,
+    {
+        let payload_count = self.root_fields.len();
+        self.root_fields
+            .into_iter()
+            .map(|root_field| {
+                execute_deferred_root_field(
+                    http_context,
+                    root_field,
+                    project_id,
+                    expose_internal_errors,
+                    session_variables,
+                )
+            })
+            .collect::<FuturesUnordered<_>>()
+            .enumerate()
+            .map(move |(index, incremental)| {
+                gql::http::Response::subsequent(incremental, index + 1 < payload_count)
+            })
+    }
+}
+
+async fn execute_deferred_root_field(
+    http_context: &HttpContext,
+    root_field: DeferredRootField<'_, '_>,
+    project_id: Option<&ProjectId>,
+    expose_internal_errors: ExposeInternalErrors,
+    session_variables: &SessionVariables,
+) -> Vec<gql::http::IncrementalResult> {
+    let DeferredRootField {
+        alias,
+        selection_set,
+        row_sets,
+        fragments,
+        join_locations,
+        process_response_as,
+        execution_span_attribute,
+    } = root_field;
+    let tracer = tracing_util::global_tracer();
+    let result = tracer
+        .in_span_async(
+            "execute_deferred_root_field",
+            format!("{alias} deferred fragments"),
+            SpanVisibility::User,
+            || {
+                Box::pin(async {
+                    execute::run_remote_joins(
+                        http_context,
+                        join_locations,
+                        execution_span_attribute,
+                        &process_response_as,
+                        project_id,
+                        row_sets,
+                    )
+                    .await
+                    .and_then(|row_sets| {
+                        process_response(
+                            selection_set,
+                            row_sets,
+                            &process_response_as,
+                            session_variables,
+                        )
+                    })
+                })
+            },
+        )
+        .await;
+    match result {
+        Ok(processed_response) => {
+            let mut results = Vec::new();
+            visit_deferred_fragments(
+                &processed_response.response,
+                selection_set,
+                &mut vec![gql::http::PathSegment::field(alias.0)],
+                &mut |path, label, data| {
+                    results.push(gql::http::IncrementalResult {
+                        data: Some(data),
+                        path: path.clone(),
+                        label,
+                        errors: None,
+                    });
+                },
+            );
+            results
+        }
+        // The remote joins of all the deferred fragments of the root field are executed
+        // together, so the error is reported for each of them, at its own path.
+        Err(error) => fragments
+            .into_iter()
+            .map(
+                |DeferredFragment { label, path }| gql::http::IncrementalResult {
+                    data: None,
+                    errors: Some(NonEmpty::new(
+                        error.to_graphql_error(expose_internal_errors, Some(path.clone())),
+                    )),
+                    path,
+                    label,
+                },
+            )
+            .collect(),
+    }
+}
+
+/// Collects the deferred fragments of a root field from its initial payload, in which the
+/// deferred fields are left out.
+pub(crate) fn collect_deferred_fragments(
+    alias: &ast::Alias,
+    initial_response: &json::Value,
+    selection_set: &normalized_ast::SelectionSet<'_, GDS>,
+) -> Vec<DeferredFragment> {
+    let mut fragments = Vec::new();
+    visit_deferred_fragments(
+        initial_response,
+        selection_set,
+        &mut vec![gql::http::PathSegment::field(alias.0.clone())],
+        &mut |path, label, _data| {
+            fragments.push(DeferredFragment {
+                label,
+                path: path.clone(),
+            });
+        },
+    );
+    fragments
+}
+
+/// Splits the remote joins of a root field into those of the initial payload, and those of
+/// deferred fields.
+///
+/// Only the remote joins reached through local relationships and nested fields are deferred.
+/// Those nested in the selection set of another remote join are executed along with it.
+pub(crate) fn split_join_locations(
+    join_locations: JoinLocations,
+    selection_set: &normalized_ast::SelectionSet<'_, GDS>,
+) -> (JoinLocations, JoinLocations) {
+    let mut initial = JoinLocations::new();
+    let mut deferred = JoinLocations::new();
+    for (alias, location) in join_locations.locations {
+        let field = selection_set
+            .fields
+            .values()
+            .find(|field| field.alias.0.as_str() == alias);
+        match (field, location.join_node) {
+            (Some(field), join_node) if field.deferred.is_some() => {
+                deferred.locations.insert(
+                    alias,
+                    Location {
+                        join_node,
+                        rest: location.rest,
+                    },
+                );
+            }
+            (_, JoinNode::Remote(join_node)) => {
+                initial.locations.insert(
+                    alias,
+                    Location {
+                        join_node: JoinNode::Remote(join_node),
+                        rest: location.rest,
+                    },
+                );
+            }
+            (field, JoinNode::Local(location_kind)) => {
+                let (initial_rest, deferred_rest) = split_join_locations(
+                    location.rest,
+                    field.map_or(selection_set, |field| &field.selection_set),
+                );
+                if !initial_rest.is_empty() {
+                    initial.locations.insert(
+                        alias.clone(),
+                        Location {
+                            join_node: JoinNode::Local(location_kind),
+                            rest: initial_rest,
+                        },
+                    );
+                }
+                if !deferred_rest.is_empty() {
+                    deferred.locations.insert(
+                        alias,
+                        Location {
+                            join_node: JoinNode::Local(location_kind),
+                            rest: deferred_rest,
+                        },
+                    );
+                }
+            }
+        }
+    }
+    (initial, deferred)
+}
+
+/// Visits the deferred fragments of a processed response: the deferred fields of each object,
+/// grouped by the label of their fragment, along with the path of the object. A fragment is
+/// visited even if its fields are missing from the object, as in an initial payload.
+fn visit_deferred_fragments(
+    value: &json::Value,
+    selection_set: &normalized_ast::SelectionSet<'_, GDS>,
+    path: &mut gql::http::Path,
+    visit: &mut impl FnMut(&gql::http::Path, Option<String>, IndexMap<ast::Alias, json::Value>),
+) {
+    match value {
+        json::Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                path.push(gql::http::PathSegment::index(index));
+                visit_deferred_fragments(item, selection_set, path, visit);
+                path.pop();
+            }
+        }
+        json::Value::Object(object) => {
+            let mut deferred_fragments: IndexMap<
+                Option<String>,
+                IndexMap<ast::Alias, json::Value>,
+            > = IndexMap::new();
+            for (alias, field) in &selection_set.fields {
+                let field_value = object.get(alias.0.as_str());
+                match &field.deferred {
+                    Some(deferred) => {
+                        let fragment = deferred_fragments
+                            .entry(deferred.label.clone())
+                            .or_default();
+                        if let Some(field_value) = field_value {
+                            fragment.insert(alias.clone(), field_value.clone());
+                        }
+                    }
+                    None => {
+                        if let Some(field_value) = field_value {
+                            path.push(gql::http::PathSegment::field(alias.0.clone()));
+                            visit_deferred_fragments(
+                                field_value,
+                                &field.selection_set,
+                                path,
+                                visit,
+                            );
+                            path.pop();
+                        }
+                    }
+                }
+            }
+            for (label, data) in deferred_fragments {
+                visit(path, label, data);
+            }
+        }
+        json::Value::Null
+        | json::Value::Bool(_)
+        | json::Value::Number(_)
+        | json::Value::String(_) => {}
+    }
+}
@@ -13,6 +13,8 @@ pub struct RootFieldResult {
     pub is_nullable: bool,
     pub result: Result<json::Value, FieldError>,
     pub headers: Option<reqwest::header::HeaderMap>,
+    /// The page info of a cursor paginated root field
+    pub page_info: Option<json::Value>,
 }
 
 impl Traceable for RootFieldResult {
@@ -29,6 +31,7 @@ impl RootFieldResult {
             is_nullable,
             result,
             headers: None,
+            page_info: None,
         }
     }
     pub fn from_processed_response(
@@ -40,14 +43,22 @@ impl RootFieldResult {
                 is_nullable,
                 result: Ok(processed_response.response),
                 headers: processed_response.response_headers.map(|h| h.0),
+                page_info: None,
             },
             Err(field_error) => Self {
                 is_nullable,
                 result: Err(field_error),
                 headers: None,
+                page_info: None,
             },
         }
     }
+
+    #[must_use]
+    pub fn with_page_info(mut self, page_info: Option<json::Value>) -> Self {
+        self.page_info = page_info;
+        self
+    }
 }
 
 #[derive(Debug)]
@@ -68,6 +79,7 @@ impl ExecuteQueryResult {
         let mut data = IndexMap::new();
         let mut errors = Vec::new();
         let mut headers = Vec::new();
+        let mut page_infos = json::Map::new();
         for (alias, field_result) in self.root_fields {
             let result = match field_result.result {
                 Ok(value) => value,
@@ -88,6 +100,10 @@ impl ExecuteQueryResult {
                     }
                 }
             };
+            // the page info of a paginated root field is only returned along with its rows
+            if let Some(page_info) = field_result.page_info {
+                page_infos.insert(alias.to_string(), page_info);
+            }
             data.insert(alias, result);
 
             // if this root field result has headers, collect it
@@ -96,7 +112,12 @@ impl ExecuteQueryResult {
             }
         }
 
-        gql::http::Response::partial(data, errors, Self::merge_headers(headers))
+        let response = gql::http::Response::partial(data, errors, Self::merge_headers(headers));
+        if page_infos.is_empty() {
+            response
+        } else {
+            response.with_extension("pageInfo".to_string(), json::Value::Object(page_infos))
+        }
     }
 
     // merge all the headers of all root fields
@@ -1,3 +1,4 @@
+pub mod permissions;
 pub mod types;
 use super::steps;
 
@@ -353,7 +354,7 @@ async fn get_execution_steps(
     data_connector: &metadata_resolve::DataConnectorLink,
 ) -> Result<NonEmpty<Box<types::Step>>, crate::RequestError> {
     let mut sequence_steps = match process_response_as {
-        ProcessResponseAs::CommandResponse { .. } => {
+        ProcessResponseAs::CommandResponse { .. } | ProcessResponseAs::ModelMutationResponse => {
             // A command execution node
             let data_connector_explain = fetch_explain_from_data_connector(
                 expose_internal_errors,
@@ -372,7 +373,9 @@ async fn get_execution_steps(
         }
         ProcessResponseAs::Array { .. }
         | ProcessResponseAs::Object { .. }
-        | ProcessResponseAs::Aggregates { .. } => {
+        | ProcessResponseAs::Aggregates { .. }
+        | ProcessResponseAs::Connection { .. }
+        | ProcessResponseAs::Groups { .. } => {
             // A model execution node
             let data_connector_explain = fetch_explain_from_data_connector(
                 expose_internal_errors,
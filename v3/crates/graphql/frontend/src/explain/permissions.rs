@@ -0,0 +1,551 @@
+//! Explains the permissions that apply to a GraphQL request for the role of the session, to
+//! help debug why a role can or cannot see some data.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use engine_types::ExposeInternalErrors;
+use graphql_schema::{Annotation, InputAnnotation, OutputAnnotation, RootFieldAnnotation, GDS};
+use hasura_authn_core::{Role, Session, SessionVariables};
+use lang_graphql as gql;
+use lang_graphql::http::{GraphQLError, RawRequest};
+use lang_graphql::normalized_ast;
+use metadata_resolve::{
+    FieldCondition, FieldConditionOperator, FilterPermission, ModelPredicate, Qualified,
+    QualifiedTypeReference, UnaryComparisonOperator, ValueExpression, ValueExpressionOrPredicate,
+};
+use nonempty::NonEmpty;
+use open_dds::{commands::CommandName, models::ModelName, types::CustomTypeName};
+use serde::Serialize;
+use serde_json::json;
+use tracing_util::{SpanVisibility, Traceable};
+
+use super::super::steps;
+use super::super::types::GraphQLErrors;
+use crate::RequestError;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionsExplainResponse {
+    permissions: Option<PermissionsExplanation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    errors: Option<NonEmpty<GraphQLError>>,
+}
+
+impl Traceable for PermissionsExplainResponse {
+    type ErrorType<'a> = GraphQLErrors<'a>;
+
+    fn get_error(&self) -> Option<GraphQLErrors<'_>> {
+        self.errors.as_ref().map(GraphQLErrors)
+    }
+}
+
+impl PermissionsExplainResponse {
+    pub fn error(error: GraphQLError) -> Self {
+        Self {
+            permissions: None,
+            errors: Some(nonempty::nonempty![error]),
+        }
+    }
+    pub fn does_contain_error(&self) -> bool {
+        self.errors.is_some()
+    }
+}
+
+impl axum::response::IntoResponse for PermissionsExplainResponse {
+    fn into_response(self) -> axum::response::Response {
+        axum::Json(self).into_response()
+    }
+}
+
+/// The permissions of the role for every model, command, relationship and type used by a
+/// request.
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PermissionsExplanation {
+    pub(crate) role: Role,
+    pub(crate) models: BTreeMap<String, ModelPermissionsExplanation>,
+    pub(crate) commands: BTreeMap<String, CommandPermissionsExplanation>,
+    pub(crate) relationships: Vec<RelationshipExplanation>,
+    pub(crate) types: BTreeMap<String, TypePermissionsExplanation>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ModelPermissionsExplanation {
+    /// Whether the role can select from the model
+    pub(crate) allowed: bool,
+    /// The filter applied to the rows of the model, with session variables substituted.
+    /// Null if every row can be selected.
+    pub(crate) filter: Option<serde_json::Value>,
+    pub(crate) argument_presets: BTreeMap<String, serde_json::Value>,
+    pub(crate) limit: Option<u32>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CommandPermissionsExplanation {
+    /// Whether the role can execute the command
+    pub(crate) allowed: bool,
+    pub(crate) argument_presets: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RelationshipExplanation {
+    pub(crate) source_type: String,
+    pub(crate) relationship_name: String,
+    pub(crate) target: RelationshipTargetExplanation,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type", content = "value")]
+pub(crate) enum RelationshipTargetExplanation {
+    Model(String),
+    Command(String),
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TypePermissionsExplanation {
+    /// Fields that the role cannot see in an output context
+    pub(crate) hidden_fields: Vec<String>,
+    /// Conditions of the fields that the role can only see on some objects
+    pub(crate) conditional_fields: BTreeMap<String, serde_json::Value>,
+    pub(crate) field_argument_presets: BTreeMap<String, BTreeMap<String, serde_json::Value>>,
+    /// Presets of the fields when the type is used in an input context
+    pub(crate) field_presets: BTreeMap<String, serde_json::Value>,
+}
+
+/// Explain the permissions that the role of the session has on the models, commands,
+/// relationships and types used by a GraphQL request.
+pub fn explain_permissions(
+    expose_internal_errors: ExposeInternalErrors,
+    schema: &gql::schema::Schema<GDS>,
+    metadata: &metadata_resolve::Metadata,
+    session: &Session,
+    raw_request: &RawRequest,
+) -> PermissionsExplainResponse {
+    let tracer = tracing_util::global_tracer();
+    tracer.in_span(
+        "explain_permissions",
+        "Explain the permissions of a request",
+        SpanVisibility::User,
+        || {
+            explain_permissions_internal(schema, metadata, session, raw_request).map_or_else(
+                |e| PermissionsExplainResponse::error(e.to_graphql_error(expose_internal_errors)),
+                |permissions| PermissionsExplainResponse {
+                    permissions: Some(permissions),
+                    errors: None,
+                },
+            )
+        },
+    )
+}
+
+fn explain_permissions_internal(
+    schema: &gql::schema::Schema<GDS>,
+    metadata: &metadata_resolve::Metadata,
+    session: &Session,
+    raw_request: &RawRequest,
+) -> Result<PermissionsExplanation, RequestError> {
+    let query = steps::parse_query(&raw_request.query)?;
+    let normalized_request = steps::normalize_request(schema, session, query, raw_request)?;
+
+    let mut used_objects = UsedObjects::default();
+    used_objects.collect_from_selection_set(&normalized_request.selection_set);
+
+    let mut models = BTreeMap::new();
+    for model_name in used_objects.models {
+        if let Some(model) = metadata.models.get(model_name) {
+            models.insert(
+                model_name.to_string(),
+                explain_model_permissions(model, session)?,
+            );
+        }
+    }
+
+    let mut commands = BTreeMap::new();
+    for command_name in used_objects.commands {
+        if let Some(command) = metadata.commands.get(command_name) {
+            let explanation = match command.permissions.get(&session.role) {
+                None => CommandPermissionsExplanation {
+                    allowed: false,
+                    argument_presets: BTreeMap::new(),
+                },
+                Some(permission) => CommandPermissionsExplanation {
+                    allowed: permission.allow_execution,
+                    argument_presets: explain_argument_presets(
+                        &permission.argument_presets,
+                        &session.variables,
+                    )?,
+                },
+            };
+            commands.insert(command_name.to_string(), explanation);
+        }
+    }
+
+    let mut types = BTreeMap::new();
+    for type_name in used_objects.types {
+        if let Some(object_type) = metadata.object_types.get(type_name) {
+            types.insert(
+                type_name.to_string(),
+                explain_type_permissions(object_type, session)?,
+            );
+        }
+    }
+
+    Ok(PermissionsExplanation {
+        role: session.role.clone(),
+        models,
+        commands,
+        relationships: used_objects.relationships,
+        types,
+    })
+}
+
+/// The models, commands, relationships and object types used by a request.
+#[derive(Default)]
+struct UsedObjects<'s> {
+    models: BTreeSet<&'s Qualified<ModelName>>,
+    commands: BTreeSet<&'s Qualified<CommandName>>,
+    relationships: Vec<RelationshipExplanation>,
+    types: BTreeSet<&'s Qualified<CustomTypeName>>,
+}
+
+impl<'s> UsedObjects<'s> {
+    fn collect_from_selection_set(
+        &mut self,
+        selection_set: &normalized_ast::SelectionSet<'s, GDS>,
+    ) {
+        for field in selection_set.fields.values() {
+            for field_call in field.field_calls.values() {
+                self.collect_from_annotation(field_call.info.generic);
+                for argument in field_call.arguments.values() {
+                    self.collect_from_input_field(argument);
+                }
+            }
+            self.collect_from_selection_set(&field.selection_set);
+        }
+    }
+
+    fn collect_from_input_field(&mut self, input_field: &normalized_ast::InputField<'s, GDS>) {
+        self.collect_from_annotation(input_field.info.generic);
+        self.collect_from_input_value(&input_field.value);
+    }
+
+    fn collect_from_input_value(&mut self, value: &normalized_ast::Value<'s, GDS>) {
+        match value {
+            normalized_ast::Value::List(values) => {
+                for value in values {
+                    self.collect_from_input_value(value);
+                }
+            }
+            normalized_ast::Value::Object(object) => {
+                for input_field in object.values() {
+                    self.collect_from_input_field(input_field);
+                }
+            }
+            normalized_ast::Value::SimpleValue(_) | normalized_ast::Value::Json(_) => {}
+        }
+    }
+
+    fn collect_from_annotation(&mut self, annotation: &'s Annotation) {
+        match annotation {
+            Annotation::Output(OutputAnnotation::RootField(
+                RootFieldAnnotation::Model {
+                    data_type, name, ..
+                }
+                | RootFieldAnnotation::ModelSubscription {
+                    data_type, name, ..
+                }
+                | RootFieldAnnotation::ModelStreamSubscription {
+                    data_type, name, ..
+                }
+                | RootFieldAnnotation::ModelMutation {
+                    data_type, name, ..
+                },
+            )) => {
+                self.models.insert(name);
+                self.types.insert(data_type);
+            }
+            Annotation::Output(OutputAnnotation::RootField(
+                RootFieldAnnotation::FunctionCommand { name, .. }
+                | RootFieldAnnotation::ProcedureCommand { name, .. },
+            )) => {
+                self.commands.insert(name);
+            }
+            Annotation::Output(OutputAnnotation::Field { parent_type, .. })
+            | Annotation::Input(InputAnnotation::InputObjectField { parent_type, .. }) => {
+                self.types.insert(parent_type);
+            }
+            Annotation::Output(
+                OutputAnnotation::RelationshipToModel(relationship)
+                | OutputAnnotation::RelationshipToModelConnection(relationship),
+            ) => {
+                self.models.insert(&relationship.model_name);
+                self.types.insert(&relationship.source_type);
+                self.types.insert(&relationship.target_type);
+                self.add_relationship(RelationshipExplanation {
+                    source_type: relationship.source_type.to_string(),
+                    relationship_name: relationship.relationship_name.to_string(),
+                    target: RelationshipTargetExplanation::Model(
+                        relationship.model_name.to_string(),
+                    ),
+                });
+            }
+            Annotation::Output(OutputAnnotation::RelationshipToModelAggregate(relationship)) => {
+                self.models.insert(&relationship.model_name);
+                self.types.insert(&relationship.source_type);
+                self.add_relationship(RelationshipExplanation {
+                    source_type: relationship.source_type.to_string(),
+                    relationship_name: relationship.relationship_name.to_string(),
+                    target: RelationshipTargetExplanation::Model(
+                        relationship.model_name.to_string(),
+                    ),
+                });
+            }
+            Annotation::Output(OutputAnnotation::RelationshipToCommand(relationship)) => {
+                self.commands.insert(&relationship.command_name);
+                self.types.insert(&relationship.source_type);
+                self.add_relationship(RelationshipExplanation {
+                    source_type: relationship.source_type.to_string(),
+                    relationship_name: relationship.relationship_name.to_string(),
+                    target: RelationshipTargetExplanation::Command(
+                        relationship.command_name.to_string(),
+                    ),
+                });
+            }
+            Annotation::Output(_) | Annotation::Input(_) => {}
+        }
+    }
+
+    fn add_relationship(&mut self, relationship: RelationshipExplanation) {
+        if !self.relationships.contains(&relationship) {
+            self.relationships.push(relationship);
+        }
+    }
+}
+
+fn explain_model_permissions(
+    model: &metadata_resolve::ModelWithPermissions,
+    session: &Session,
+) -> Result<ModelPermissionsExplanation, RequestError> {
+    let Some(select_permission) = model.select_permissions.get(&session.role) else {
+        return Ok(ModelPermissionsExplanation {
+            allowed: false,
+            filter: None,
+            argument_presets: BTreeMap::new(),
+            limit: None,
+        });
+    };
+    let filter = match &select_permission.filter {
+        FilterPermission::AllowAll => None,
+        FilterPermission::Filter(predicate) => {
+            Some(explain_model_predicate(predicate, &session.variables)?)
+        }
+    };
+    Ok(ModelPermissionsExplanation {
+        allowed: true,
+        filter,
+        argument_presets: explain_argument_presets(
+            &select_permission.argument_presets,
+            &session.variables,
+        )?,
+        limit: select_permission.limit,
+    })
+}
+
+fn explain_type_permissions(
+    object_type: &metadata_resolve::ObjectTypeWithRelationships,
+    session: &Session,
+) -> Result<TypePermissionsExplanation, RequestError> {
+    let fields = &object_type.object_type.fields;
+    let output_permission = object_type.type_output_permissions.get(&session.role);
+
+    let hidden_fields = fields
+        .keys()
+        .filter(|field_name| {
+            output_permission.map_or(true, |permission| {
+                !permission.allowed_fields.contains(*field_name)
+            })
+        })
+        .map(ToString::to_string)
+        .collect();
+
+    let mut conditional_fields = BTreeMap::new();
+    let mut field_argument_presets = BTreeMap::new();
+    if let Some(output_permission) = output_permission {
+        for (field_name, condition) in &output_permission.field_conditions {
+            conditional_fields.insert(
+                field_name.to_string(),
+                explain_field_condition(condition, &session.variables)?,
+            );
+        }
+        for (field_name, argument_presets) in &output_permission.field_argument_presets {
+            let mut presets = BTreeMap::new();
+            for (argument_name, value) in argument_presets {
+                let argument_type = fields
+                    .get(field_name)
+                    .and_then(|field| field.field_arguments.get(argument_name))
+                    .map(|argument| &argument.argument_type)
+                    .ok_or_else(|| {
+                        RequestError::ExplainError(format!(
+                            "unknown argument {argument_name} of field {field_name}"
+                        ))
+                    })?;
+                presets.insert(
+                    argument_name.to_string(),
+                    resolve_value_expression(value, argument_type, &session.variables)?,
+                );
+            }
+            field_argument_presets.insert(field_name.to_string(), presets);
+        }
+    }
+
+    let mut field_presets = BTreeMap::new();
+    if let Some(input_permission) = object_type.type_input_permissions.get(&session.role) {
+        for (field_name, preset) in &input_permission.field_presets {
+            let field_type = fields
+                .get(field_name)
+                .map(|field| &field.field_type)
+                .ok_or_else(|| RequestError::ExplainError(format!("unknown field {field_name}")))?;
+            field_presets.insert(
+                field_name.to_string(),
+                resolve_value_expression(&preset.value, field_type, &session.variables)?,
+            );
+        }
+    }
+
+    Ok(TypePermissionsExplanation {
+        hidden_fields,
+        conditional_fields,
+        field_argument_presets,
+        field_presets,
+    })
+}
+
+fn explain_argument_presets<K: ToString>(
+    argument_presets: &BTreeMap<K, (QualifiedTypeReference, ValueExpressionOrPredicate)>,
+    session_variables: &SessionVariables,
+) -> Result<BTreeMap<String, serde_json::Value>, RequestError> {
+    argument_presets
+        .iter()
+        .map(|(argument_name, (argument_type, value))| {
+            let value = match value {
+                ValueExpressionOrPredicate::Literal(value) => value.clone(),
+                ValueExpressionOrPredicate::SessionVariable(session_variable) => {
+                    resolve_value_expression(
+                        &ValueExpression::SessionVariable(session_variable.clone()),
+                        argument_type,
+                        session_variables,
+                    )?
+                }
+                ValueExpressionOrPredicate::BooleanExpression(predicate) => {
+                    explain_model_predicate(predicate, session_variables)?
+                }
+            };
+            Ok((argument_name.to_string(), value))
+        })
+        .collect()
+}
+
+/// Render a model predicate in the shape of an OpenDD predicate, with session variables
+/// substituted.
+fn explain_model_predicate(
+    predicate: &ModelPredicate,
+    session_variables: &SessionVariables,
+) -> Result<serde_json::Value, RequestError> {
+    Ok(match predicate {
+        ModelPredicate::UnaryFieldComparison {
+            field,
+            operator: UnaryComparisonOperator::IsNull,
+            ..
+        } => json!({ "fieldIsNull": { "field": field } }),
+        ModelPredicate::BinaryFieldComparison {
+            field,
+            operator,
+            argument_type,
+            value,
+            ..
+        } => json!({
+            "fieldComparison": {
+                "field": field,
+                "operator": operator,
+                "value": resolve_value_expression(value, argument_type, session_variables)?,
+            }
+        }),
+        ModelPredicate::Relationship {
+            relationship_info,
+            predicate,
+        } => json!({
+            "relationship": {
+                "name": relationship_info.relationship_name,
+                "predicate": explain_model_predicate(predicate, session_variables)?,
+            }
+        }),
+        ModelPredicate::And(predicates) => json!({
+            "and": predicates
+                .iter()
+                .map(|predicate| explain_model_predicate(predicate, session_variables))
+                .collect::<Result<Vec<_>, _>>()?
+        }),
+        ModelPredicate::Or(predicates) => json!({
+            "or": predicates
+                .iter()
+                .map(|predicate| explain_model_predicate(predicate, session_variables))
+                .collect::<Result<Vec<_>, _>>()?
+        }),
+        ModelPredicate::Not(predicate) => {
+            json!({ "not": explain_model_predicate(predicate, session_variables)? })
+        }
+    })
+}
+
+fn explain_field_condition(
+    condition: &FieldCondition,
+    session_variables: &SessionVariables,
+) -> Result<serde_json::Value, RequestError> {
+    Ok(match condition {
+        FieldCondition::FieldComparison {
+            field,
+            field_type,
+            operator,
+            value,
+        } => json!({
+            "fieldComparison": {
+                "field": field,
+                "operator": match operator {
+                    FieldConditionOperator::Equal => "_eq",
+                    FieldConditionOperator::NotEqual => "_neq",
+                },
+                "value": resolve_value_expression(value, field_type, session_variables)?,
+            }
+        }),
+        FieldCondition::FieldIsNull { field } => json!({ "fieldIsNull": { "field": field } }),
+        FieldCondition::And(conditions) => json!({
+            "and": conditions
+                .iter()
+                .map(|condition| explain_field_condition(condition, session_variables))
+                .collect::<Result<Vec<_>, _>>()?
+        }),
+        FieldCondition::Or(conditions) => json!({
+            "or": conditions
+                .iter()
+                .map(|condition| explain_field_condition(condition, session_variables))
+                .collect::<Result<Vec<_>, _>>()?
+        }),
+        FieldCondition::Not(condition) => {
+            json!({ "not": explain_field_condition(condition, session_variables)? })
+        }
+    })
+}
+
+fn resolve_value_expression(
+    value: &ValueExpression,
+    value_type: &QualifiedTypeReference,
+    session_variables: &SessionVariables,
+) -> Result<serde_json::Value, RequestError> {
+    plan::make_argument_from_value_expression(value, value_type, session_variables)
+        .map_err(|error| RequestError::ExplainError(error.to_string()))
+}
@@ -8,8 +8,12 @@ mod steps;
 mod types;
 
 pub use error::RequestError;
-pub use execute::{execute_mutation_plan, execute_query_plan, ExecuteQueryResult, RootFieldResult};
+pub use execute::{
+    execute_mutation_plan, execute_query_plan, execute_query_plan_incremental,
+    DeferredQueryExecution, ExecuteQueryResult, RootFieldResult,
+};
 pub use explain::execute_explain;
+pub use explain::permissions::{explain_permissions, PermissionsExplainResponse};
 pub use explain::types::{redact_ndc_explain, ExplainResponse};
 pub use process_response::process_response;
 pub use query::{
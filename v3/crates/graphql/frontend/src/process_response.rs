@@ -8,18 +8,26 @@ use std::collections::hash_map::RandomState;
 use std::collections::BTreeMap;
 
 use base64::{engine::general_purpose, Engine};
+use hasura_authn_core::SessionVariables;
 use indexmap::IndexMap;
 use lang_graphql::ast::common::{self as ast, Alias, TypeName};
 use lang_graphql::normalized_ast;
 use open_dds::commands::CommandName;
 use open_dds::types::FieldName;
 
-use graphql_ir::{global_id_col_format, GLOBAL_ID_VERSION};
-use graphql_schema::{AggregateOutputAnnotation, Annotation, GlobalID, OutputAnnotation, GDS};
+use graphql_ir::{
+    abstract_member_col_format, condition_col_format, global_id_col_format, DISCRIMINATOR_COL,
+    GLOBAL_ID_VERSION,
+};
+use graphql_schema::{
+    AggregateOutputAnnotation, Annotation, ConnectionOutputAnnotation, GlobalID,
+    GroupByOutputAnnotation, InputAnnotation, ModelInputAnnotation,
+    ModelMutationResponseOutputAnnotation, NamespaceAnnotation, OutputAnnotation, TypeKind, GDS,
+};
 use metadata_resolve::data_connectors;
 use metadata_resolve::Qualified;
-use plan_types::FUNCTION_IR_VALUE_COLUMN_NAME;
-use plan_types::{CommandReturnKind, ProcessResponseAs};
+use plan_types::{CommandReturnKind, CursorPagination, NdcFieldAlias, ProcessResponseAs};
+use plan_types::{COMMAND_OUTPUT_FILTER_ALIAS, FUNCTION_IR_VALUE_COLUMN_NAME};
 
 trait KeyValueResponse {
     fn remove(&mut self, key: &str) -> Option<json::Value>;
@@ -36,6 +44,19 @@ impl KeyValueResponse for IndexMap<ndc_models::FieldName, ndc_models::RowFieldVa
     }
 }
 
+// Rows borrowed from a row set are left untouched: the values of their fields are cloned instead
+// of being moved out, eg. to keep the rows for the deferred fields of an incremental response.
+impl KeyValueResponse for &IndexMap<ndc_models::FieldName, ndc_models::RowFieldValue> {
+    fn remove(&mut self, key: &str) -> Option<json::Value> {
+        self.get(key).map(|row_field| row_field.0.clone())
+    }
+}
+impl KeyValueResponse for &IndexMap<ndc_models::FieldName, json::Value> {
+    fn remove(&mut self, key: &str) -> Option<json::Value> {
+        self.get(key).cloned()
+    }
+}
+
 // Workaround for the performance issue documented in ENG-1073
 //
 // Assumes the input is an object. Used for our performance workaround, bypassing RowSet, which
@@ -84,12 +105,142 @@ where
     Ok(json::Value::String(global_id_value))
 }
 
+/// Evaluates the condition of a conditionally allowed field on a row, using the
+/// fields that were fetched for the condition along with the field.
+fn process_field_condition<T>(
+    row: &mut T,
+    condition: &metadata_resolve::FieldCondition,
+    field_alias: &Alias,
+    session_variables: &SessionVariables,
+) -> Result<bool, execute::FieldError>
+where
+    T: KeyValueResponse,
+{
+    let mut condition_values = BTreeMap::new();
+    for field_name in condition.fields() {
+        let condition_col_name = condition_col_format(field_alias, field_name);
+        let field_json_value_result = row.remove(condition_col_name.as_str()).ok_or_else(|| {
+            execute::NDCUnexpectedError::BadNDCResponse {
+                summary: format!("missing field: {}", condition_col_name.as_str()),
+            }
+        })?;
+        condition_values.insert(field_name, field_json_value_result);
+    }
+    evaluate_field_condition(condition, &condition_values, session_variables)
+}
+
+/// Comparisons of null values evaluate to false, so the field is hidden when the
+/// fields used by the condition are null.
+fn evaluate_field_condition(
+    condition: &metadata_resolve::FieldCondition,
+    condition_values: &BTreeMap<&FieldName, json::Value>,
+    session_variables: &SessionVariables,
+) -> Result<bool, execute::FieldError> {
+    match condition {
+        metadata_resolve::FieldCondition::FieldComparison {
+            field,
+            field_type,
+            operator,
+            value,
+        } => {
+            let field_value = condition_values.get(field).unwrap_or(&json::Value::Null);
+            if field_value.is_null() {
+                return Ok(false);
+            }
+            let value =
+                plan::make_argument_from_value_expression(value, field_type, session_variables)
+                    .map_err(|error| execute::FieldInternalError::InternalGeneric {
+                        description: format!("unable to evaluate field condition: {error}"),
+                    })?;
+            Ok(match operator {
+                metadata_resolve::FieldConditionOperator::Equal => *field_value == value,
+                metadata_resolve::FieldConditionOperator::NotEqual => *field_value != value,
+            })
+        }
+        metadata_resolve::FieldCondition::FieldIsNull { field } => Ok(condition_values
+            .get(field)
+            .map_or(true, json::Value::is_null)),
+        metadata_resolve::FieldCondition::And(conditions) => {
+            for condition in conditions {
+                if !evaluate_field_condition(condition, condition_values, session_variables)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        metadata_resolve::FieldCondition::Or(conditions) => {
+            for condition in conditions {
+                if evaluate_field_condition(condition, condition_values, session_variables)? {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+        metadata_resolve::FieldCondition::Not(condition) => Ok(!evaluate_field_condition(
+            condition,
+            condition_values,
+            session_variables,
+        )?),
+    }
+}
+
+/// Whether the command permission for the role has an output filter, from the
+/// namespaced annotation of a command relationship field.
+fn has_command_output_filter(field_call: &normalized_ast::FieldCall<'_, GDS>) -> bool {
+    matches!(
+        field_call.info.namespaced,
+        Some(NamespaceAnnotation::Command {
+            output_filter: Some(_),
+            ..
+        })
+    )
+}
+
+/// Check an object returned by a command against the output filter of the command
+/// permission, removing the rows that were joined from the model of the object to
+/// evaluate the filter. The object satisfies the filter when a row was joined for it.
+fn check_command_output_filter<T>(
+    row: &mut T,
+    command_name: &Qualified<CommandName>,
+    check_output_filter: bool,
+) -> Result<(), execute::FieldError>
+where
+    T: KeyValueResponse,
+{
+    if !check_output_filter {
+        return Ok(());
+    }
+    let filter_value = row.remove(COMMAND_OUTPUT_FILTER_ALIAS).ok_or_else(|| {
+        execute::NDCUnexpectedError::BadNDCResponse {
+            summary: format!("missing field: {COMMAND_OUTPUT_FILTER_ALIAS}"),
+        }
+    })?;
+    let filter_rows = match filter_value {
+        json::Value::Null => None,
+        value => {
+            json::from_value::<ndc_models::RowSet>(value)
+                .map_err(|err| execute::NDCUnexpectedError::BadNDCResponse {
+                    summary: format!("Unable to parse RowSet: {err}"),
+                })?
+                .rows
+        }
+    };
+    if filter_rows.is_some_and(|rows| !rows.is_empty()) {
+        Ok(())
+    } else {
+        Err(execute::FieldError::CommandOutputFilterNotSatisfied {
+            command_name: command_name.clone(),
+        })
+    }
+}
+
 /// Processes a single NDC row and adds `__typename`
 /// where needed.
 fn process_single_query_response_row<T>(
     mut row: T,
     selection_set: &normalized_ast::SelectionSet<'_, GDS>,
     response_config: Option<&Arc<data_connectors::CommandsResponseConfig>>,
+    session_variables: &SessionVariables,
 ) -> Result<IndexMap<ast::Alias, json::Value>, execute::FieldError>
 where
     T: KeyValueResponse,
@@ -128,17 +279,36 @@ where
                                 }
                             })?;
 
+                            // Conditionally allowed fields are null on the objects that
+                            // don't match their condition
+                            if let Some(NamespaceAnnotation::ObjectField {
+                                condition: Some(condition),
+                                ..
+                            }) = field_call.info.namespaced
+                            {
+                                if !process_field_condition(
+                                    &mut row,
+                                    condition,
+                                    &field.alias,
+                                    session_variables,
+                                )? {
+                                    return Ok(json::Value::Null);
+                                }
+                            }
+
                             if field.type_container.is_list() {
                                 process_field_selection_as_list(
                                     value,
                                     &field.selection_set,
                                     response_config,
+                                    session_variables,
                                 )
                             } else {
                                 process_field_selection_as_object(
                                     value,
                                     &field.selection_set,
                                     response_config,
+                                    session_variables,
                                 )
                             }
                         }
@@ -152,7 +322,10 @@ where
                             let rows_set_rows = field_json_value_result
                                 .get_mut("rows")
                                 .and_then(|j| j.as_array_mut())
-                                .map(std::mem::take);
+                                .map(std::mem::take)
+                                .or_else(|| {
+                                    distinct_rows_from_groups(&mut field_json_value_result)
+                                });
                             // Depending upon the field's type (list or object),
                             // process the selection set accordingly.
                             if field.type_container.is_list() {
@@ -160,6 +333,7 @@ where
                                     rows_set_rows,
                                     &field.selection_set,
                                     response_config,
+                                    session_variables,
                                 )
                                 // NOTE: I assume a Null returned here is internal error, but
                                 // this behavior is preserved for now:
@@ -171,10 +345,36 @@ where
                                     rows_set_rows,
                                     &field.selection_set,
                                     response_config,
+                                    session_variables,
                                 )
                                 .map(|v| v.map_or(json::Value::Null, json_ext::alias_map_to_value))
                             }
                         }
+                        OutputAnnotation::RelationshipToModelConnection { .. } => {
+                            let field_json_value_result = row
+                                .remove(field.alias.0.as_str())
+                                .ok_or_else(|| execute::NDCUnexpectedError::BadNDCResponse {
+                                    summary: format!("missing field: {}", field.alias.clone()),
+                                })?;
+                            match serde_json::from_value(field_json_value_result) {
+                                Err(err) => Err(execute::NDCUnexpectedError::BadNDCResponse {
+                                    summary: format!("Unable to parse RowSet: {err}"),
+                                })?,
+                                Ok(row_set) => {
+                                    let cursor_pagination =
+                                        relationship_connection_cursor_pagination(
+                                            field_call, &row_set,
+                                        )?;
+                                    process_connection(
+                                        row_set,
+                                        &cursor_pagination,
+                                        &field.selection_set,
+                                        session_variables,
+                                    )
+                                    .map(json_ext::alias_map_to_value)
+                                }
+                            }
+                        }
                         OutputAnnotation::RelationshipToModelAggregate { .. } => {
                             let field_json_value_result = row
                                 .remove(field.alias.0.as_str())
@@ -222,6 +422,15 @@ where
                                         is_nullable,
                                         return_kind,
                                         response_config,
+                                        has_command_output_filter(field_call),
+                                        match &command_relationship_annotation.target_base_type_kind
+                                        {
+                                            TypeKind::Abstract(type_discrimination) => {
+                                                Some(type_discrimination)
+                                            }
+                                            TypeKind::Scalar | TypeKind::Object => None,
+                                        },
+                                        session_variables,
                                     )
                                     .map(|v| match v {
                                         None => json::Value::Null,
@@ -245,10 +454,260 @@ where
     )
 }
 
+/// The distinct rows of a relationship selected with `distinct_on`, which are fetched as the
+/// groups of the row set. The aggregates of each group are the fields of a distinct row.
+fn distinct_rows_from_groups(row_set: &mut json::Value) -> Option<Vec<json::Value>> {
+    let groups = row_set.get_mut("groups")?.as_array_mut()?;
+    Some(
+        groups
+            .iter_mut()
+            .filter_map(|group| group.get_mut("aggregates").map(json::Value::take))
+            .collect(),
+    )
+}
+
+/// Processes the rows of a page of a Relay connection into its edges and page info. Each row
+/// is the node of an edge, whose cursor is built from the ordering fields of the row.
+fn process_connection(
+    mut row_set: ndc_models::RowSet,
+    cursor_pagination: &CursorPagination,
+    selection_set: &normalized_ast::SelectionSet<'_, GDS>,
+    session_variables: &SessionVariables,
+) -> Result<IndexMap<ast::Alias, json::Value>, execute::FieldError> {
+    let page = execute::paginate_rowset(cursor_pagination, &mut row_set)?;
+    let rows = row_set.rows.unwrap_or_default();
+    let encode_cursor = |cursor: Option<Vec<json::Value>>| {
+        Ok::<_, execute::FieldError>(
+            cursor
+                .map(graphql_ir::encode_pagination_cursor)
+                .transpose()?
+                .map_or(json::Value::Null, json::Value::String),
+        )
+    };
+    selection_set.as_object_selection_set(|_type_name, field, field_call| {
+        match field_call.info.generic {
+            Annotation::Output(OutputAnnotation::Connection(ConnectionOutputAnnotation::Edges)) => {
+                let edges = rows
+                    .iter()
+                    .map(|row| {
+                        field.selection_set.as_object_selection_set(
+                            |_type_name, edge_field, edge_field_call| match edge_field_call
+                                .info
+                                .generic
+                            {
+                                Annotation::Output(OutputAnnotation::Connection(
+                                    ConnectionOutputAnnotation::EdgeCursor,
+                                )) => encode_cursor(Some(execute::get_row_cursor(
+                                    cursor_pagination,
+                                    row,
+                                )?)),
+                                Annotation::Output(OutputAnnotation::Connection(
+                                    ConnectionOutputAnnotation::EdgeNode,
+                                )) => process_single_query_response_row(
+                                    row.clone(),
+                                    &edge_field.selection_set,
+                                    None,
+                                    session_variables,
+                                )
+                                .map(json_ext::alias_map_to_value),
+                                annotation => {
+                                    Err(execute::FieldInternalError::UnexpectedAnnotation {
+                                        annotation: annotation.clone(),
+                                    })?
+                                }
+                            },
+                        )
+                    })
+                    .collect::<Result<Vec<_>, execute::FieldError>>()?;
+                Ok(json_ext::vec_alias_map_to_value(edges))
+            }
+            Annotation::Output(OutputAnnotation::Connection(
+                ConnectionOutputAnnotation::PageInfo,
+            )) => field
+                .selection_set
+                .as_object_selection_set(|_type_name, _page_info_field, page_info_field_call| {
+                    match page_info_field_call.info.generic {
+                        Annotation::Output(OutputAnnotation::Connection(
+                            ConnectionOutputAnnotation::HasNextPage,
+                        )) => Ok(json::Value::Bool(page.has_next_page)),
+                        Annotation::Output(OutputAnnotation::Connection(
+                            ConnectionOutputAnnotation::HasPreviousPage,
+                        )) => Ok(json::Value::Bool(page.has_previous_page)),
+                        Annotation::Output(OutputAnnotation::Connection(
+                            ConnectionOutputAnnotation::StartCursor,
+                        )) => encode_cursor(page.start_cursor.clone()),
+                        Annotation::Output(OutputAnnotation::Connection(
+                            ConnectionOutputAnnotation::EndCursor,
+                        )) => encode_cursor(page.end_cursor.clone()),
+                        annotation => Err(execute::FieldInternalError::UnexpectedAnnotation {
+                            annotation: annotation.clone(),
+                        })?,
+                    }
+                })
+                .map(json_ext::alias_map_to_value),
+            annotation => Err(execute::FieldInternalError::UnexpectedAnnotation {
+                annotation: annotation.clone(),
+            })?,
+        }
+    })
+}
+
+/// Processes the result of a generated model mutation, an object with the number of affected
+/// rows and the affected rows, which are fetched by the aliases of their fields.
+fn process_model_mutation_response(
+    mut result: json::Value,
+    selection_set: &normalized_ast::SelectionSet<'_, GDS>,
+    session_variables: &SessionVariables,
+) -> Result<IndexMap<ast::Alias, json::Value>, execute::FieldError> {
+    selection_set.as_object_selection_set(|_type_name, field, field_call| {
+        let mut take_field = || {
+            result
+                .get_mut(field.alias.0.as_str())
+                .map(json::Value::take)
+                .ok_or_else(|| execute::NDCUnexpectedError::BadNDCResponse {
+                    summary: format!("missing field: {}", field.alias.clone()),
+                })
+        };
+        match field_call.info.generic {
+            Annotation::Output(OutputAnnotation::ModelMutationResponse(
+                ModelMutationResponseOutputAnnotation::AffectedRows,
+            )) => Ok(take_field()?),
+            Annotation::Output(OutputAnnotation::ModelMutationResponse(
+                ModelMutationResponseOutputAnnotation::Returning,
+            )) => process_field_selection_as_list(
+                take_field()?,
+                &field.selection_set,
+                None,
+                session_variables,
+            ),
+            annotation => Err(execute::FieldInternalError::UnexpectedAnnotation {
+                annotation: annotation.clone(),
+            })?,
+        }
+    })
+}
+
+/// Processes the groups of the rows of a model. The values of the dimensions of each group are
+/// returned in the order of the dimension aliases, and its aggregates by their aliases.
+fn process_groups(
+    row_set: ndc_models::RowSet,
+    dimensions: &[NdcFieldAlias],
+    selection_set: &normalized_ast::SelectionSet<'_, GDS>,
+) -> Result<json::Value, execute::FieldError> {
+    let groups = row_set
+        .groups
+        .ok_or_else(|| execute::NDCUnexpectedError::BadNDCResponse {
+            summary: "Unable to parse response from NDC, RowSet groups property was null when it was expected to be an array".to_owned(),
+        })?;
+    let result = groups
+        .into_iter()
+        .map(|mut group| {
+            selection_set.as_object_selection_set(|_type_name, group_field, group_field_call| {
+                match group_field_call.info.generic {
+                    Annotation::Output(OutputAnnotation::GroupBy(
+                        GroupByOutputAnnotation::Dimensions,
+                    )) => group_field
+                        .selection_set
+                        .as_object_selection_set(|_type_name, dimension_field, _| {
+                            let alias = graphql_ir::mk_alias_from_graphql_field_path(&[
+                                &group_field.alias,
+                                &dimension_field.alias,
+                            ]);
+                            dimensions
+                                .iter()
+                                .position(|dimension| *dimension == alias)
+                                .and_then(|index| group.dimensions.get(index))
+                                .cloned()
+                                .ok_or_else(|| {
+                                    execute::FieldError::from(
+                                        execute::NDCUnexpectedError::BadNDCResponse {
+                                            summary: format!("missing dimension field: {alias}"),
+                                        },
+                                    )
+                                })
+                        })
+                        .map(json_ext::alias_map_to_value),
+                    Annotation::Output(OutputAnnotation::GroupBy(
+                        GroupByOutputAnnotation::Aggregates,
+                    )) => reshape_aggregate_fields(
+                        &mut group.aggregates,
+                        &[&group_field.alias],
+                        &group_field.selection_set,
+                    ),
+                    annotation => Err(execute::FieldInternalError::UnexpectedAnnotation {
+                        annotation: annotation.clone(),
+                    })?,
+                }
+            })
+        })
+        .collect::<Result<Vec<_>, execute::FieldError>>()?;
+    Ok(json_ext::vec_alias_map_to_value(result))
+}
+
+/// The cursor pagination of a relationship connection field. It is planned along with the
+/// relationship, from the arguments of the field and the limit of the select permission of the
+/// target model.
+fn relationship_connection_cursor_pagination(
+    field_call: &normalized_ast::FieldCall<'_, GDS>,
+    row_set: &ndc_models::RowSet,
+) -> Result<CursorPagination, execute::FieldError> {
+    let mut page_size = None;
+    let mut backwards = false;
+    for argument in field_call.arguments.values() {
+        // An explicit `null` requests the same page as an omitted argument
+        if argument.value.is_null() {
+            continue;
+        }
+        match argument.info.generic {
+            Annotation::Input(InputAnnotation::Model(ModelInputAnnotation::ModelFirstArgument)) => {
+                page_size = Some(argument.value.as_int_u32()?);
+            }
+            Annotation::Input(InputAnnotation::Model(ModelInputAnnotation::ModelLastArgument)) => {
+                page_size = Some(argument.value.as_int_u32()?);
+                backwards = true;
+            }
+            Annotation::Input(InputAnnotation::Model(
+                ModelInputAnnotation::ModelBeforeArgument,
+            )) => {
+                backwards = true;
+            }
+            _ => {}
+        }
+    }
+    if let Some(NamespaceAnnotation::Model {
+        limit: Some(permission_limit),
+        ..
+    }) = field_call.info.namespaced
+    {
+        page_size = Some(page_size.map_or(*permission_limit, |page_size| {
+            page_size.min(*permission_limit)
+        }));
+    }
+    // The ordering fields are fetched with consecutive aliases, so they are read from any row.
+    // The cursors of an empty page are never read.
+    let cursor_field_aliases = row_set
+        .rows
+        .as_ref()
+        .and_then(|rows| rows.first())
+        .map(|row| {
+            (0..)
+                .map(graphql_ir::pagination_cursor_field_alias)
+                .take_while(|field_alias| row.contains_key(field_alias.as_str()))
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(CursorPagination {
+        page_size,
+        backwards,
+        cursor_field_aliases,
+    })
+}
+
 fn process_selection_set_as_list<T>(
     rows: Option<Vec<T>>,
     selection_set: &normalized_ast::SelectionSet<'_, GDS>,
     response_config: Option<&Arc<data_connectors::CommandsResponseConfig>>,
+    session_variables: &SessionVariables,
 ) -> Result<Option<Vec<IndexMap<ast::Alias, json::Value>>>, execute::FieldError>
 where
     T: KeyValueResponse,
@@ -256,7 +715,14 @@ where
     let processed_response = rows
         .map(|rows| {
             rows.into_iter()
-                .map(|row| process_single_query_response_row(row, selection_set, response_config))
+                .map(|row| {
+                    process_single_query_response_row(
+                        row,
+                        selection_set,
+                        response_config,
+                        session_variables,
+                    )
+                })
                 .collect()
         })
         .transpose()?;
@@ -267,13 +733,21 @@ fn process_selection_set_as_object<T>(
     rows: Option<Vec<T>>,
     selection_set: &normalized_ast::SelectionSet<'_, GDS>,
     response_config: Option<&Arc<data_connectors::CommandsResponseConfig>>,
+    session_variables: &SessionVariables,
 ) -> Result<Option<IndexMap<ast::Alias, json::Value>>, execute::FieldError>
 where
     T: KeyValueResponse,
 {
     let processed_response = rows
         .and_then(|rows| rows.into_iter().next())
-        .map(|row| process_single_query_response_row(row, selection_set, response_config))
+        .map(|row| {
+            process_single_query_response_row(
+                row,
+                selection_set,
+                response_config,
+                session_variables,
+            )
+        })
         .transpose()?;
     Ok(processed_response)
 }
@@ -282,6 +756,7 @@ pub fn process_field_selection_as_list(
     value: json::Value,
     selection_set: &normalized_ast::SelectionSet<'_, GDS>,
     response_config: Option<&Arc<data_connectors::CommandsResponseConfig>>,
+    session_variables: &SessionVariables,
 ) -> Result<json::Value, execute::FieldError> {
     if selection_set.fields.is_empty() || value.is_null() {
         // If selection set is empty we return the whole value without further processing.
@@ -292,7 +767,14 @@ pub fn process_field_selection_as_list(
             json::from_value(value)?;
         let processed_rows: Vec<IndexMap<Alias, json::Value>> = rows
             .into_iter()
-            .map(|row| process_single_query_response_row(row, selection_set, response_config))
+            .map(|row| {
+                process_single_query_response_row(
+                    row,
+                    selection_set,
+                    response_config,
+                    session_variables,
+                )
+            })
             .collect::<Result<Vec<_>, _>>()?;
         Ok(json::to_value(processed_rows)?)
     }
@@ -302,6 +784,7 @@ pub fn process_field_selection_as_object(
     value: json::Value,
     selection_set: &normalized_ast::SelectionSet<'_, GDS>,
     response_config: Option<&Arc<data_connectors::CommandsResponseConfig>>,
+    session_variables: &SessionVariables,
 ) -> Result<json::Value, execute::FieldError> {
     if selection_set.fields.is_empty() || value.is_null() {
         // If selection set is empty we return the whole value without further processing.
@@ -310,7 +793,12 @@ pub fn process_field_selection_as_object(
     } else {
         let row: IndexMap<ndc_models::FieldName, ndc_models::RowFieldValue> =
             json::from_value(value)?;
-        let processed_row = process_single_query_response_row(row, selection_set, response_config)?;
+        let processed_row = process_single_query_response_row(
+            row,
+            selection_set,
+            response_config,
+            session_variables,
+        )?;
         Ok(json::to_value(processed_row)?)
     }
 }
@@ -322,6 +810,9 @@ pub fn process_command_rows(
     is_nullable: bool,
     return_kind: CommandReturnKind,
     response_config: Option<&Arc<data_connectors::CommandsResponseConfig>>,
+    check_output_filter: bool,
+    type_discrimination: Option<&metadata_resolve::TypeDiscrimination>,
+    session_variables: &SessionVariables,
 ) -> Result<Option<ProcessedResponse>, execute::FieldError> {
     match rows {
         None => Err(execute::NDCUnexpectedError::BadNDCResponse {
@@ -343,11 +834,15 @@ pub fn process_command_rows(
                 .next()
                 .map(|row| {
                     process_command_response_row(
+                        command_name,
                         row,
                         selection_set,
                         is_nullable,
                         return_kind,
                         response_config,
+                        check_output_filter,
+                        type_discrimination,
+                        session_variables,
                     )
                 })
                 .transpose()?;
@@ -357,11 +852,15 @@ pub fn process_command_rows(
 }
 
 fn process_command_response_row(
+    command_name: &Qualified<CommandName>,
     mut row: IndexMap<ndc_models::FieldName, ndc_models::RowFieldValue>,
     selection_set: &normalized_ast::SelectionSet<'_, GDS>,
     is_nullable: bool,
     return_kind: CommandReturnKind,
     response_config: Option<&Arc<data_connectors::CommandsResponseConfig>>,
+    check_output_filter: bool,
+    type_discrimination: Option<&metadata_resolve::TypeDiscrimination>,
+    session_variables: &SessionVariables,
 ) -> Result<ProcessedResponse, execute::FieldError> {
     let field_value_result = row
         .swap_remove(FUNCTION_IR_VALUE_COLUMN_NAME)
@@ -371,11 +870,15 @@ fn process_command_response_row(
 
     let ndc_result = extract_response_headers_and_result(field_value_result.0, response_config)?;
     let field_result = process_command_field_value(
+        command_name,
         ndc_result.response,
         selection_set,
         is_nullable,
         return_kind,
         response_config,
+        check_output_filter,
+        type_discrimination,
+        session_variables,
     )?;
     Ok(ProcessedResponse {
         response_headers: ndc_result.response_headers,
@@ -384,11 +887,15 @@ fn process_command_response_row(
 }
 
 fn process_command_field_value(
+    command_name: &Qualified<CommandName>,
     field_value_result: serde_json::Value,
     selection_set: &normalized_ast::SelectionSet<'_, GDS>,
     is_nullable: bool,
     return_kind: CommandReturnKind,
     response_config: Option<&Arc<data_connectors::CommandsResponseConfig>>,
+    check_output_filter: bool,
+    type_discrimination: Option<&metadata_resolve::TypeDiscrimination>,
+    session_variables: &SessionVariables,
 ) -> Result<json::Value, execute::FieldError> {
     // When no selection set for commands, return back the value from the
     // connector without any processing.
@@ -414,12 +921,16 @@ fn process_command_field_value(
                     summary: "Unable to parse response from NDC, object value expected".into(),
                 })?,
                 CommandReturnKind::Object => {
-                    let index_map: IndexMap<ndc_models::FieldName, json::Value> =
+                    let mut index_map: IndexMap<ndc_models::FieldName, json::Value> =
                         json::from_value(json::Value::Object(result_map))?;
-                    let value = process_single_query_response_row(
+                    let value = process_command_object(
                         index_map,
+                        command_name,
                         selection_set,
                         response_config,
+                        check_output_filter,
+                        type_discrimination,
+                        session_variables,
                     )?;
                     Ok(json::to_value(value)?)
                 }
@@ -431,7 +942,17 @@ fn process_command_field_value(
 
                     let r: Vec<IndexMap<Alias, json::Value>> = array_values
                         .into_iter()
-                        .map(|value| process_single_query_response_row(value, selection_set, response_config))
+                        .map(|value| {
+                            process_command_object(
+                                value,
+                                command_name,
+                                selection_set,
+                                response_config,
+                                check_output_filter,
+                                type_discrimination,
+                                session_variables,
+                            )
+                        })
                         .collect::<Result<Vec<IndexMap<ast::Alias, json::Value>>, execute::FieldError>>(
                         )?;
 
@@ -450,6 +971,86 @@ fn process_command_field_value(
     }
 }
 
+/// Processes an object returned by a command, after checking it against the
+/// output filter of the command permission.
+fn process_command_object(
+    mut row: IndexMap<ndc_models::FieldName, json::Value>,
+    command_name: &Qualified<CommandName>,
+    selection_set: &normalized_ast::SelectionSet<'_, GDS>,
+    response_config: Option<&Arc<data_connectors::CommandsResponseConfig>>,
+    check_output_filter: bool,
+    type_discrimination: Option<&metadata_resolve::TypeDiscrimination>,
+    session_variables: &SessionVariables,
+) -> Result<IndexMap<ast::Alias, json::Value>, execute::FieldError> {
+    check_command_output_filter(&mut row, command_name, check_output_filter)?;
+    match type_discrimination {
+        None => process_single_query_response_row(
+            row,
+            selection_set,
+            response_config,
+            session_variables,
+        ),
+        Some(type_discrimination) => process_abstract_type_row(
+            row,
+            selection_set,
+            type_discrimination,
+            response_config,
+            session_variables,
+        ),
+    }
+}
+
+/// Processes an object of a union or interface type. The value of the discriminator
+/// field picks the member object type, whose fields were fetched under aliases
+/// prefixed with the name of the member. `__typename` and the inline fragments of
+/// the selection set are resolved against that member.
+fn process_abstract_type_row(
+    mut row: IndexMap<ndc_models::FieldName, json::Value>,
+    selection_set: &normalized_ast::SelectionSet<'_, GDS>,
+    type_discrimination: &metadata_resolve::TypeDiscrimination,
+    response_config: Option<&Arc<data_connectors::CommandsResponseConfig>>,
+    session_variables: &SessionVariables,
+) -> Result<IndexMap<ast::Alias, json::Value>, execute::FieldError> {
+    let discriminator_value = row.swap_remove(DISCRIMINATOR_COL).ok_or_else(|| {
+        execute::NDCUnexpectedError::BadNDCResponse {
+            summary: format!("missing field: {DISCRIMINATOR_COL}"),
+        }
+    })?;
+    let member = discriminator_value
+        .as_str()
+        .and_then(|value| type_discrimination.member_for_value(value))
+        .ok_or_else(|| execute::NDCUnexpectedError::BadNDCResponse {
+            summary: format!(
+                "unexpected value {discriminator_value} of the discriminator field {}",
+                type_discrimination.discriminator_field
+            ),
+        })?;
+    let member_graphql_type_name = member.graphql_type_name.as_ref().ok_or_else(|| {
+        execute::FieldInternalError::InternalGeneric {
+            description: format!(
+                "no GraphQL type name for the member type {}",
+                member.object_type
+            ),
+        }
+    })?;
+    let member_prefix = abstract_member_col_format(member_graphql_type_name, "");
+    let member_row: IndexMap<ndc_models::FieldName, json::Value> = row
+        .into_iter()
+        .filter_map(|(alias, value)| {
+            alias
+                .as_str()
+                .strip_prefix(member_prefix.as_str())
+                .map(|member_alias| (ndc_models::FieldName::from(member_alias), value))
+        })
+        .collect();
+    process_single_query_response_row(
+        member_row,
+        &selection_set.filter_field_calls_by_typename(member_graphql_type_name.clone()),
+        response_config,
+        session_variables,
+    )
+}
+
 fn process_aggregate_requested_fields(
     row_set: ndc_models::RowSet,
     aggregate_output_selection_set: &normalized_ast::SelectionSet<'_, GDS>,
@@ -515,6 +1116,7 @@ pub fn process_response(
     selection_set: &normalized_ast::SelectionSet<'_, GDS>,
     rows_sets: Vec<ndc_models::RowSet>,
     process_response_as: &ProcessResponseAs,
+    session_variables: &SessionVariables,
 ) -> Result<ProcessedResponse, execute::FieldError> {
     let tracer = tracing_util::global_tracer();
     // Post process the response to add the `__typename` fields
@@ -523,10 +1125,19 @@ pub fn process_response(
         "Process response",
         SpanVisibility::Internal,
         || {
-            let row_set = get_single_rowset(rows_sets)?;
+            let mut row_set = get_single_rowset(rows_sets)?;
+            // the groups of a group by field are processed as they are
+            if !matches!(process_response_as, ProcessResponseAs::Groups { .. }) {
+                execute::rows_from_distinct_groups(&mut row_set);
+            }
             match process_response_as {
                 ProcessResponseAs::Array { .. } => {
-                    let result = process_selection_set_as_list(row_set.rows, selection_set, None)?;
+                    let result = process_selection_set_as_list(
+                        row_set.rows,
+                        selection_set,
+                        None,
+                        session_variables,
+                    )?;
                     let response = json::to_value(result).map_err(execute::FieldError::from)?;
                     Ok(ProcessedResponse {
                         response,
@@ -534,8 +1145,12 @@ pub fn process_response(
                     })
                 }
                 ProcessResponseAs::Object { .. } => {
-                    let result =
-                        process_selection_set_as_object(row_set.rows, selection_set, None)?;
+                    let result = process_selection_set_as_object(
+                        row_set.rows,
+                        selection_set,
+                        None,
+                        session_variables,
+                    )?;
                     let response = json::to_value(result).map_err(execute::FieldError::from)?;
                     Ok(ProcessedResponse {
                         response,
@@ -547,6 +1162,8 @@ pub fn process_response(
                     is_nullable,
                     return_kind,
                     response_config,
+                    check_output_filter,
+                    type_discrimination,
                 } => {
                     let result = process_command_rows(
                         command_name,
@@ -555,6 +1172,9 @@ pub fn process_response(
                         *is_nullable,
                         *return_kind,
                         response_config.as_ref(),
+                        *check_output_filter,
+                        type_discrimination.as_ref(),
+                        session_variables,
                     )?;
                     match result {
                         None => Ok(ProcessedResponse {
@@ -572,27 +1192,129 @@ pub fn process_response(
                         response: result,
                     })
                 }
+                ProcessResponseAs::Groups { dimensions } => {
+                    let result = process_groups(row_set, dimensions, selection_set)?;
+                    Ok(ProcessedResponse {
+                        response: result,
+                        response_headers: None,
+                    })
+                }
+                ProcessResponseAs::ModelMutationResponse => {
+                    Err(execute::FieldInternalError::InternalGeneric {
+                        description: "Model mutation responses are only returned by mutations"
+                            .to_string(),
+                    })?
+                }
+                ProcessResponseAs::Connection { cursor_pagination } => {
+                    let result = process_connection(
+                        row_set,
+                        cursor_pagination,
+                        selection_set,
+                        session_variables,
+                    )?;
+                    Ok(ProcessedResponse {
+                        response: json_ext::alias_map_to_value(result),
+                        response_headers: None,
+                    })
+                }
             }
         },
     )
 }
 
+/// Processes the rows of a model selection without taking ownership of them, cloning only the
+/// values of the fields in the selection set. This is used for the initial payload of an
+/// incremental response, whose rows are kept to run the remote joins of the deferred fields.
+pub(crate) fn process_response_from_borrowed_rows(
+    selection_set: &normalized_ast::SelectionSet<'_, GDS>,
+    rows_sets: &[ndc_models::RowSet],
+    process_response_as: &ProcessResponseAs,
+    session_variables: &SessionVariables,
+) -> Result<ProcessedResponse, execute::FieldError> {
+    let tracer = tracing_util::global_tracer();
+    tracer.in_span(
+        "process_response",
+        "Process response",
+        SpanVisibility::Internal,
+        || {
+            let row_set = rows_sets
+                .first()
+                .ok_or(execute::NDCUnexpectedError::BadNDCResponse {
+                    summary: "missing rowset".into(),
+                })?;
+            // The distinct rows of a selection with `distinct_on` are the aggregates of its
+            // groups, as in `execute::rows_from_distinct_groups`
+            let distinct_rows = match (&row_set.rows, &row_set.groups) {
+                (None, Some(groups)) => {
+                    Some(groups.iter().map(|group| &group.aggregates).collect())
+                }
+                _ => None,
+            };
+            let rows = row_set
+                .rows
+                .as_ref()
+                .map(|rows| rows.iter().collect::<Vec<_>>());
+            let response = match (process_response_as, distinct_rows) {
+                (ProcessResponseAs::Array { .. }, Some(distinct_rows)) => {
+                    json::to_value(process_selection_set_as_list(
+                        Some(distinct_rows),
+                        selection_set,
+                        None,
+                        session_variables,
+                    )?)
+                }
+                (ProcessResponseAs::Array { .. }, None) => json::to_value(
+                    process_selection_set_as_list(rows, selection_set, None, session_variables)?,
+                ),
+                (ProcessResponseAs::Object { .. }, Some(distinct_rows)) => {
+                    json::to_value(process_selection_set_as_object(
+                        Some(distinct_rows),
+                        selection_set,
+                        None,
+                        session_variables,
+                    )?)
+                }
+                (ProcessResponseAs::Object { .. }, None) => json::to_value(
+                    process_selection_set_as_object(rows, selection_set, None, session_variables)?,
+                ),
+                _ => Err(execute::FieldInternalError::InternalGeneric {
+                    description: "only the rows of model selections can be processed borrowed"
+                        .to_string(),
+                })?,
+            }
+            .map_err(execute::FieldError::from)?;
+            Ok(ProcessedResponse {
+                response,
+                response_headers: None,
+            })
+        },
+    )
+}
+
 pub fn process_command_mutation_response(
+    command_name: &Qualified<CommandName>,
     mutation_result: ndc_models::MutationOperationResults,
     selection_set: &normalized_ast::SelectionSet<'_, GDS>,
     is_nullable: bool,
     return_kind: CommandReturnKind,
     response_config: Option<&Arc<data_connectors::CommandsResponseConfig>>,
+    check_output_filter: bool,
+    type_discrimination: Option<&metadata_resolve::TypeDiscrimination>,
+    session_variables: &SessionVariables,
 ) -> Result<ProcessedResponse, execute::FieldError> {
     match mutation_result {
         ndc_models::MutationOperationResults::Procedure { result } => {
             let ndc_result = extract_response_headers_and_result(result, response_config)?;
             let field_result = process_command_field_value(
+                command_name,
                 ndc_result.response,
                 selection_set,
                 is_nullable,
                 return_kind,
                 response_config,
+                check_output_filter,
+                type_discrimination,
+                session_variables,
             )?;
             Ok(ProcessedResponse {
                 response_headers: ndc_result.response_headers,
@@ -606,6 +1328,7 @@ pub fn process_mutation_response(
     selection_set: &normalized_ast::SelectionSet<'_, GDS>,
     connector_response: ndc_models::MutationResponse,
     process_response_as: &ProcessResponseAs,
+    session_variables: &SessionVariables,
 ) -> Result<ProcessedResponse, execute::FieldError> {
     let tracer = tracing_util::global_tracer();
 
@@ -628,19 +1351,39 @@ pub fn process_mutation_response(
 
             match process_response_as {
                 ProcessResponseAs::CommandResponse {
-                    command_name: _,
+                    command_name,
                     is_nullable,
                     return_kind,
                     response_config,
+                    check_output_filter,
+                    type_discrimination,
                 } => process_command_mutation_response(
+                    command_name,
                     mutation_results,
                     selection_set,
                     *is_nullable,
                     *return_kind,
                     response_config.as_ref(),
+                    *check_output_filter,
+                    type_discrimination.as_ref(),
+                    session_variables,
                 ),
+                ProcessResponseAs::ModelMutationResponse => match mutation_results {
+                    ndc_models::MutationOperationResults::Procedure { result } => {
+                        let response = process_model_mutation_response(
+                            result,
+                            selection_set,
+                            session_variables,
+                        )?;
+                        Ok(ProcessedResponse {
+                            response: json_ext::alias_map_to_value(response),
+                            response_headers: None,
+                        })
+                    }
+                },
                 _ => Err(execute::FieldInternalError::InternalGeneric {
-                    description: "Only commands are supported for mutations".to_string(),
+                    description: "Only commands and model mutations are supported for mutations"
+                        .to_string(),
                 })?,
             }
         },
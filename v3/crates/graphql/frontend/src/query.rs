@@ -110,12 +110,18 @@ pub async fn execute_query_internal(
                                             http_context,
                                             mutation_plan,
                                             project_id,
+                                            &session.variables,
                                         )
                                         .await
                                     }
                                     graphql_ir::RequestPlan::QueryPlan(query_plan) => {
-                                        execute_query_plan(http_context, query_plan, project_id)
-                                            .await
+                                        execute_query_plan(
+                                            http_context,
+                                            query_plan,
+                                            project_id,
+                                            &session.variables,
+                                        )
+                                        .await
                                     }
                                     graphql_ir::RequestPlan::SubscriptionPlan(
                                         alias,
@@ -131,6 +137,7 @@ pub async fn execute_query_internal(
                                                 .is_nullable(),
                                             result,
                                             headers: None,
+                                            page_info: None,
                                         };
                                         ExecuteQueryResult {
                                             root_fields: IndexMap::from([(
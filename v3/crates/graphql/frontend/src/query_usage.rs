@@ -172,6 +172,9 @@ fn analyze_input_annotation(annotation: &graphql_schema::InputAnnotation) -> Vec
             }
             graphql_schema::ObjectBooleanExpressionField::RelationshipField(
                 relationship_annotation,
+            )
+            | graphql_schema::ObjectBooleanExpressionField::RelationshipAggregateField(
+                relationship_annotation,
             ) => {
                 let DeprecatedDetails {
                     is_deprecated,
@@ -194,11 +197,13 @@ fn analyze_input_annotation(annotation: &graphql_schema::InputAnnotation) -> Vec
         },
         graphql_schema::InputAnnotation::BooleanExpression(
             graphql_schema::BooleanExpressionAnnotation::BooleanExpressionRootField
-            | graphql_schema::BooleanExpressionAnnotation::ScalarBooleanExpressionField(_),
+            | graphql_schema::BooleanExpressionAnnotation::ScalarBooleanExpressionField(_)
+            | graphql_schema::BooleanExpressionAnnotation::AggregateBooleanExpressionField(_),
         )
         | graphql_schema::InputAnnotation::CommandArgument { .. }
         | graphql_schema::InputAnnotation::Relay(_)
         | graphql_schema::InputAnnotation::FieldArgument { argument_name: _ }
+        | graphql_schema::InputAnnotation::EnumValue { type_name: _ }
         | graphql_schema::InputAnnotation::ApolloFederationRepresentationsInput(_) => {}
     }
     result
@@ -214,6 +219,24 @@ fn analyze_model_input_annotation(
             parent_type,
             deprecated,
             ..
+        }
+        | graphql_schema::ModelInputAnnotation::ModelStreamCursorField {
+            field_name,
+            parent_type,
+            deprecated,
+            ..
+        }
+        | graphql_schema::ModelInputAnnotation::ModelDistinctOnField {
+            field_name,
+            parent_type,
+            deprecated,
+            ..
+        }
+        | graphql_schema::ModelInputAnnotation::ModelGroupOrderByDimension {
+            field_name,
+            parent_type,
+            deprecated,
+            ..
         } => {
             let DeprecatedDetails {
                 is_deprecated,
@@ -251,10 +274,23 @@ fn analyze_model_input_annotation(
         | graphql_schema::ModelInputAnnotation::ModelOrderByExpression
         | graphql_schema::ModelInputAnnotation::ModelOrderByNestedExpression { .. }
         | graphql_schema::ModelInputAnnotation::ModelOrderByDirection { .. }
+        | graphql_schema::ModelInputAnnotation::ModelOrderByAggregationFunction(_)
+        | graphql_schema::ModelInputAnnotation::ModelOrderByAggregatableField { .. }
         | graphql_schema::ModelInputAnnotation::ModelLimitArgument
         | graphql_schema::ModelInputAnnotation::ModelOffsetArgument
         | graphql_schema::ModelInputAnnotation::ModelUniqueIdentifierArgument { .. }
-        | graphql_schema::ModelInputAnnotation::ModelFilterInputArgument => {}
+        | graphql_schema::ModelInputAnnotation::ModelFilterInputArgument
+        | graphql_schema::ModelInputAnnotation::ModelFirstArgument
+        | graphql_schema::ModelInputAnnotation::ModelAfterArgument
+        | graphql_schema::ModelInputAnnotation::ModelLastArgument
+        | graphql_schema::ModelInputAnnotation::ModelBeforeArgument
+        | graphql_schema::ModelInputAnnotation::ModelStreamBatchSizeArgument
+        | graphql_schema::ModelInputAnnotation::ModelStreamCursorArgument
+        | graphql_schema::ModelInputAnnotation::ModelDistinctOnArgument
+        | graphql_schema::ModelInputAnnotation::ModelGroupOrderByExpression
+        | graphql_schema::ModelInputAnnotation::ModelGroupOrderByAggregates
+        | graphql_schema::ModelInputAnnotation::ModelInsertObjectsArgument
+        | graphql_schema::ModelInputAnnotation::ModelSetArgument => {}
     }
     result
 }
@@ -264,7 +300,9 @@ fn analyze_output_annotation(annotation: &graphql_schema::OutputAnnotation) -> V
     match annotation {
         graphql_schema::OutputAnnotation::RootField(root_field) => match root_field {
             graphql_schema::RootFieldAnnotation::Model { name, .. }
-            | graphql_schema::RootFieldAnnotation::ModelSubscription { name, .. } => {
+            | graphql_schema::RootFieldAnnotation::ModelSubscription { name, .. }
+            | graphql_schema::RootFieldAnnotation::ModelStreamSubscription { name, .. }
+            | graphql_schema::RootFieldAnnotation::ModelMutation { name, .. } => {
                 result.push(OpenddObject::Model {
                     name: name.to_owned(),
                 });
@@ -296,7 +334,8 @@ fn analyze_output_annotation(annotation: &graphql_schema::OutputAnnotation) -> V
                 deprecated_reason: reason,
             }));
         }
-        graphql_schema::OutputAnnotation::RelationshipToModel(relationship) => {
+        graphql_schema::OutputAnnotation::RelationshipToModel(relationship)
+        | graphql_schema::OutputAnnotation::RelationshipToModelConnection(relationship) => {
             let DeprecatedDetails {
                 is_deprecated,
                 reason,
@@ -352,10 +391,35 @@ fn analyze_output_annotation(annotation: &graphql_schema::OutputAnnotation) -> V
                 deprecated_reason: reason,
             }));
         }
+        graphql_schema::OutputAnnotation::GroupBy(
+            graphql_schema::GroupByOutputAnnotation::Dimension {
+                field_name,
+                parent_type,
+                deprecated,
+                ..
+            },
+        ) => {
+            let DeprecatedDetails {
+                is_deprecated,
+                reason,
+            } = get_deprecated_details(deprecated.as_ref());
+            result.push(OpenddObject::Field(FieldUsage {
+                name: field_name.to_owned(),
+                opendd_type: parent_type.to_owned(),
+                deprecated: is_deprecated,
+                deprecated_reason: reason,
+            }));
+        }
         graphql_schema::OutputAnnotation::GlobalIDField { .. }
         | graphql_schema::OutputAnnotation::RelayNodeInterfaceID { .. }
         | graphql_schema::OutputAnnotation::SDL
-        | graphql_schema::OutputAnnotation::Aggregate(_) => {}
+        | graphql_schema::OutputAnnotation::Aggregate(_)
+        | graphql_schema::OutputAnnotation::Connection(_)
+        | graphql_schema::OutputAnnotation::ModelMutationResponse(_)
+        | graphql_schema::OutputAnnotation::GroupBy(
+            graphql_schema::GroupByOutputAnnotation::Dimensions
+            | graphql_schema::GroupByOutputAnnotation::Aggregates,
+        ) => {}
     }
     result
 }
@@ -365,17 +429,39 @@ fn analyze_namespace_annotation(
 ) -> Vec<OpenddObject> {
     let mut result = Vec::new();
     match annotation {
-        graphql_schema::NamespaceAnnotation::Command(argument_presets) => {
+        graphql_schema::NamespaceAnnotation::Command {
+            argument_presets,
+            output_filter,
+        } => {
             result.extend(analyze_argument_presets(argument_presets));
+            result.extend(output_filter.as_ref().map(|output_filter| {
+                OpenddObject::Permission(PermissionUsage::FilterPredicate(analyze_model_predicate(
+                    &output_filter.predicate,
+                )))
+            }));
         }
         graphql_schema::NamespaceAnnotation::Model {
             filter,
             argument_presets,
             allow_subscriptions: _,
+            limit: _,
+            allow_aggregations: _,
         } => {
             result.extend(analyze_filter_permission(filter));
             result.extend(analyze_argument_presets(argument_presets));
         }
+        graphql_schema::NamespaceAnnotation::ObjectField {
+            argument_presets,
+            condition: _,
+        } => {
+            if !argument_presets.is_empty() {
+                result.push(OpenddObject::Permission(PermissionUsage::ArgumentPresets(
+                    ArgumentPresetsUsage {
+                        arguments: argument_presets.keys().cloned().collect(),
+                    },
+                )));
+            }
+        }
         graphql_schema::NamespaceAnnotation::InputFieldPresets {
             presets_fields,
             type_name,
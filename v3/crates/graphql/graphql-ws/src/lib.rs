@@ -1,10 +1,19 @@
 pub(crate) mod metrics;
+pub(crate) mod multipart;
+pub(crate) mod multiplexer;
 pub(crate) mod poller;
 pub(crate) mod protocol;
+pub(crate) mod sse;
 pub(crate) mod websocket;
 
 pub use metrics::{NoOpWebSocketMetrics, WebSocketMetrics};
-pub use protocol::types::OperationId;
+pub use multipart::{accepts_multipart_mixed, has_deferred_fragments};
+pub use multiplexer::Multiplexer;
+pub use protocol::types::{OperationId, Protocol};
+pub use sse::{
+    accepts_event_stream, EventStreamError, EventStreamParams, EventStreamRequest,
+    EventStreamServer, EVENT_STREAM_TOKEN_HEADER,
+};
 pub use websocket::{
     types::{ConnectionExpiry, Context, WebSocketId},
     WebSocketServer,
@@ -14,7 +23,7 @@ pub use websocket::{
 pub use protocol::{
     subscribe::{execute_query_internal, send_request_error},
     types::ServerMessage,
-    GRAPHQL_WS_PROTOCOL,
+    GRAPHQL_WS_PROTOCOL, SUBSCRIPTIONS_TRANSPORT_WS_PROTOCOL,
 };
 pub use websocket::{
     types::{Connection, Connections, Message},
@@ -0,0 +1,311 @@
+//! Incremental delivery over HTTP as `multipart/mixed` responses, for operations with fragments
+//! deferred with `@defer`.
+//! ref: <https://github.com/graphql/graphql-over-http/blob/main/rfcs/IncrementalDelivery.md>
+//!
+//! Like an event stream, the request is served by a `Connection`, so that the operation is
+//! executed by the same code as over `graphql-transport-ws`. Each result of the operation is
+//! sent as a part of the response.
+
+use axum::{
+    extract::ws,
+    http::{header, HeaderMap, HeaderValue},
+    response::{IntoResponse, Response},
+};
+use hasura_authn_core::Session;
+use lang_graphql::ast::{common::Name, executable, spanning::Spanning};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use tokio::sync::mpsc;
+
+use crate::metrics::WebSocketMetrics;
+use crate::protocol;
+use crate::protocol::types::{OperationId, ServerMessage};
+use crate::sse::{self, ConnectionGuard, EventStreamServer};
+use crate::websocket::types;
+
+static MULTIPART_MIXED: &str = "multipart/mixed";
+static MULTIPART_MIXED_CONTENT_TYPE: &str = "multipart/mixed; boundary=\"-\"; deferSpec=20220824";
+static PART_DELIMITER: &str = "\r\n---\r\ncontent-type: application/json; charset=utf-8\r\n\r\n";
+static FINAL_DELIMITER: &str = "\r\n-----\r\n";
+
+/// Returns whether the request prefers its response as `multipart/mixed`, ie. the quality value
+/// of `multipart/mixed` in its `Accept` headers is higher than that of JSON. As clients asking
+/// for incremental delivery list JSON as a fallback with the same quality value, eg.
+/// `multipart/mixed;deferSpec=20220824, application/json`, a tie goes to the media type listed
+/// first.
+pub fn accepts_multipart_mixed(headers: &HeaderMap) -> bool {
+    let media_ranges = sse::parse_accept_headers(headers);
+    let multipart_quality = sse::media_type_quality(&media_ranges, MULTIPART_MIXED);
+    let json_quality = sse::JSON_MEDIA_TYPES
+        .iter()
+        .map(|media_type| sse::media_type_quality(&media_ranges, media_type))
+        .fold(0.0, f32::max);
+    if multipart_quality <= 0.0 {
+        return false;
+    }
+    match multipart_quality.partial_cmp(&json_quality) {
+        Some(Ordering::Greater) => true,
+        Some(Ordering::Equal) => {
+            let position = |media_types: &[&str]| {
+                media_ranges
+                    .iter()
+                    .position(|(media_range, _)| media_types.contains(&media_range.as_str()))
+            };
+            match (
+                position(&[MULTIPART_MIXED]),
+                position(&sse::JSON_MEDIA_TYPES),
+            ) {
+                (Some(multipart_position), Some(json_position)) => {
+                    multipart_position < json_position
+                }
+                (Some(_), None) => true,
+                (None, _) => false,
+            }
+        }
+        Some(Ordering::Less) | None => false,
+    }
+}
+
+/// Returns whether the operation of a request has fragments deferred with `@defer`. Only those
+/// operations are delivered incrementally, all other requests get a single JSON response even if
+/// they accept `multipart/mixed`. A request that can't be parsed has no deferred fragments, so
+/// that its errors are returned as JSON.
+pub fn has_deferred_fragments(request: &lang_graphql::http::RawRequest) -> bool {
+    let Ok(document) =
+        lang_graphql::parser::Parser::new(&request.query).parse_executable_document()
+    else {
+        return false;
+    };
+    let mut operations = Vec::new();
+    let mut fragments = HashMap::new();
+    for definition in &document.items {
+        match &definition.item {
+            executable::ExecutableDefinition::Operation(operation) => operations.push(operation),
+            executable::ExecutableDefinition::Fragment(fragment) => {
+                fragments.insert(&fragment.name.item, fragment);
+            }
+        }
+    }
+    let operation = match &request.operation_name {
+        Some(operation_name) => operations.into_iter().find(|operation| {
+            operation
+                .name
+                .as_ref()
+                .is_some_and(|name| name.item == *operation_name)
+        }),
+        None => operations.into_iter().next(),
+    };
+    operation.is_some_and(|operation| {
+        selection_set_has_deferred_fragments(
+            &operation.selection_set.item,
+            &fragments,
+            &mut HashSet::new(),
+        )
+    })
+}
+
+/// Whether a selection set, or one of its nested selection sets or fragments, has a fragment
+/// deferred with `@defer`. Each named fragment is only visited once.
+fn selection_set_has_deferred_fragments<'q>(
+    selection_set: &'q executable::SelectionSet,
+    fragments: &HashMap<&'q Name, &'q executable::FragmentDefinition>,
+    visited_fragments: &mut HashSet<&'q Name>,
+) -> bool {
+    let is_deferred = |directives: &[Spanning<executable::Directive>]| {
+        directives
+            .iter()
+            .any(|directive| directive.item.name.item.as_str() == "defer")
+    };
+    selection_set
+        .items
+        .iter()
+        .any(|selection| match &selection.item {
+            executable::Selection::Field(field) => {
+                field.selection_set.as_ref().is_some_and(|selection_set| {
+                    selection_set_has_deferred_fragments(
+                        &selection_set.item,
+                        fragments,
+                        visited_fragments,
+                    )
+                })
+            }
+            executable::Selection::InlineFragment(fragment) => {
+                is_deferred(&fragment.directives)
+                    || selection_set_has_deferred_fragments(
+                        &fragment.selection_set.item,
+                        fragments,
+                        visited_fragments,
+                    )
+            }
+            executable::Selection::FragmentSpread(spread) => {
+                is_deferred(&spread.directives)
+                    || (visited_fragments.insert(&spread.fragment_name.item)
+                        && fragments
+                            .get(&spread.fragment_name.item)
+                            .is_some_and(|fragment| {
+                                selection_set_has_deferred_fragments(
+                                    &fragment.selection_set.item,
+                                    fragments,
+                                    visited_fragments,
+                                )
+                            }))
+            }
+        })
+}
+
+impl<M: WebSocketMetrics> EventStreamServer<M> {
+    /// Runs an operation for a client accepting `multipart/mixed` responses. The initial
+    /// payload is sent as soon as it is executed, followed by the deferred fragments.
+    pub async fn handle_multipart_request(
+        &self,
+        client_address: std::net::SocketAddr,
+        session: Session,
+        headers: HeaderMap,
+        request: lang_graphql::http::RawRequest,
+        context: types::Context<M>,
+    ) -> Response {
+        let (connection, receiver) = self.new_connection(session, headers, context).await;
+        // There is a single operation on the connection, so it is named after the connection
+        let operation_id = OperationId(connection.id.to_string());
+        protocol::subscribe::handle_subscribe(
+            client_address,
+            connection.clone(),
+            operation_id,
+            request,
+        )
+        .await;
+        multipart_response(receiver, connection, self.connections.clone())
+    }
+}
+
+/// Sends the messages of a connection as the parts of a `multipart/mixed` response.
+fn multipart_response<M: WebSocketMetrics>(
+    receiver: mpsc::Receiver<types::Message>,
+    connection: types::Connection<M>,
+    connections: types::Connections<M>,
+) -> Response {
+    let guard = ConnectionGuard::new(connections, connection.id.clone());
+    let parts =
+        futures_util::stream::unfold(Some((receiver, connection, guard)), |state| async move {
+            let (mut receiver, connection, guard) = state?;
+            let (part, end_of_stream) = match receiver.recv().await {
+                Some(message) => message_to_part(&connection, message).await,
+                None => (None, true),
+            };
+            let mut body = part
+                .map(|part| [PART_DELIMITER.as_bytes(), &part].concat())
+                .unwrap_or_default();
+            let state = if end_of_stream {
+                body.extend_from_slice(FINAL_DELIMITER.as_bytes());
+                None
+            } else {
+                Some((receiver, connection, guard))
+            };
+            Some((Ok::<_, std::convert::Infallible>(body), state))
+        });
+    (
+        [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(MULTIPART_MIXED_CONTENT_TYPE),
+        )],
+        axum::body::Body::from_stream(parts),
+    )
+        .into_response()
+}
+
+/// Converts a connection message into a part of the response. Also returns whether the
+/// response should end.
+async fn message_to_part<M: WebSocketMetrics>(
+    connection: &types::Connection<M>,
+    message: types::Message,
+) -> (Option<Vec<u8>>, bool) {
+    match message {
+        types::Message::Protocol(message) => {
+            // Stop the poller if the operation is complete or an error occurred
+            if let Some(operation_id) = message.is_complete_or_error() {
+                connection.stop_poller(operation_id).await;
+            }
+            match *message {
+                ServerMessage::Next { payload, .. } => (serde_json::to_vec(&payload).ok(), false),
+                ServerMessage::Error { payload, .. } => (
+                    serde_json::to_vec(&lang_graphql::http::Response::errors(payload)).ok(),
+                    true,
+                ),
+                ServerMessage::Complete { .. } => (None, true),
+                ServerMessage::ConnectionAck | ServerMessage::Ping { .. } | ServerMessage::Pong => {
+                    (None, false)
+                }
+            }
+        }
+        // Close messages end the response, eg. on shutdown
+        types::Message::Raw(ws::Message::Close(_)) => (None, true),
+        // Responses returned by pre-parse plugins are sent in place of the operation result
+        types::Message::Raw(message) => (Some(message.into_data()), true),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accepts_multipart_mixed() {
+        let accepts = |accept_headers: &[&'static str]| {
+            let mut headers = HeaderMap::new();
+            for accept_header in accept_headers {
+                headers.append(header::ACCEPT, HeaderValue::from_static(accept_header));
+            }
+            accepts_multipart_mixed(&headers)
+        };
+        assert!(!accepts(&[]));
+        assert!(!accepts(&["application/json"]));
+        assert!(!accepts(&["*/*"]));
+        assert!(accepts(&["multipart/mixed"]));
+        assert!(accepts(&[
+            "multipart/mixed;deferSpec=20220824, application/json"
+        ]));
+        assert!(accepts(&["application/json;q=0.5, multipart/*"]));
+        assert!(accepts(&["multipart/mixed, */*"]));
+        // JSON is preferred, or listed first with the same quality value
+        assert!(!accepts(&["application/json, multipart/mixed"]));
+        assert!(!accepts(&["application/json, multipart/mixed;q=0.9"]));
+        assert!(!accepts(&[
+            "multipart/mixed;q=0.8",
+            "application/graphql-response+json"
+        ]));
+        assert!(!accepts(&["multipart/mixed;q=0"]));
+        assert!(!accepts(&["multipart/mixed;q=0, application/json;q=0"]));
+    }
+
+    #[test]
+    fn test_has_deferred_fragments() {
+        let deferred = |query: &str, operation_name: Option<&str>| {
+            has_deferred_fragments(&lang_graphql::http::RawRequest {
+                operation_name: operation_name
+                    .map(|operation_name| Name::new(operation_name).unwrap()),
+                query: query.to_string(),
+                variables: None,
+            })
+        };
+        assert!(!deferred("{ a { b } }", None));
+        assert!(!deferred("{ a {", None));
+        assert!(deferred("{ a { ... @defer { b } } }", None));
+        assert!(deferred(
+            "query Q { a { ...F } } fragment F on A { ... @defer { b } }",
+            None
+        ));
+        assert!(deferred(
+            "query Q { a { ...F @defer(label: \"f\") } } fragment F on A { b }",
+            None
+        ));
+        // Only the selected operation counts
+        let query = "query P { a { b } } query Q { a { ... @defer { b } } }";
+        assert!(!deferred(query, Some("P")));
+        assert!(deferred(query, Some("Q")));
+        // Fragments spreading each other are only visited once
+        assert!(!deferred(
+            "{ a { ...F } } fragment F on A { b ...G } fragment G on A { c ...F }",
+            None
+        ));
+    }
+}
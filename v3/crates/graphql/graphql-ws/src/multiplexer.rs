@@ -0,0 +1,326 @@
+//! Multiplexing of subscriptions that share the same shape.
+//!
+//! Subscriptions whose NDC queries differ only in literal values (eg: arguments, or session
+//! variables used in permission filters) are grouped into a cohort. Each cohort is polled by a
+//! single task, which sends one NDC query request carrying one variable set per subscriber,
+//! and dispatches each of the resulting rowsets back to its subscriber.
+use blake2::{Blake2b, Digest};
+use engine_types::{HttpContext, ProjectId};
+use plan_types::{QueryExecutionPlan, VariableName};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, Notify};
+
+/// The number of consecutive failed polls after which a cohort gives up, and the error is sent
+/// to all of its subscribers. Failed polls before that are retried at the next polling interval,
+/// so that a transient error from the data connector doesn't end every subscription in the
+/// cohort.
+const MAX_CONSECUTIVE_FAILED_POLLS: usize = 3;
+
+/// The minimum time between the start of a poll and a poll triggered by subscribers joining the
+/// cohort. Subscribers joining within this time of each other get their first result from a
+/// single poll, rather than each of them re-polling the whole cohort.
+const SUBSCRIBER_JOINED_DEBOUNCE: tokio::time::Duration = tokio::time::Duration::from_millis(100);
+
+/// The result of polling a cohort, as received by each of its subscribers.
+pub(crate) type PollResult = Result<ndc_models::RowSet, Arc<execute::FieldError>>;
+
+/// A subscriber's variable set, and the channel to send its rowset through.
+type SubscriberSnapshot = (
+    BTreeMap<VariableName, serde_json::Value>,
+    mpsc::Sender<PollResult>,
+);
+
+/// Identifies a cohort: the hash of the project, the data connector, the polling interval and
+/// the parameterized NDC query request shared by all of its subscribers.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct CohortKey([u8; 64]);
+
+/// Identifies a subscriber within a cohort.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct SubscriberId(u64);
+
+/// A subscriber of a cohort, with its own variable set for the shared query.
+struct Subscriber {
+    variables: BTreeMap<VariableName, serde_json::Value>,
+    sender: mpsc::Sender<PollResult>,
+}
+
+/// A group of subscribers polled together with a single NDC query request.
+/// A cohort exists for as long as its poller task is running.
+#[derive(Default)]
+struct Cohort {
+    subscribers: HashMap<SubscriberId, Subscriber>,
+    /// Wakes up the poller when a subscriber joins, so that it gets its first result without
+    /// waiting for the rest of the polling interval. Joins are debounced by the poller.
+    subscriber_joined: Arc<Notify>,
+}
+
+#[derive(Default)]
+struct Cohorts {
+    cohorts: HashMap<CohortKey, Cohort>,
+    next_subscriber_id: u64,
+}
+
+/// A shared registry of subscription cohorts, across all WebSocket connections.
+/// Cheap to clone, as the registry is wrapped in an `Arc`.
+#[derive(Clone, Default)]
+pub struct Multiplexer(Arc<Mutex<Cohorts>>);
+
+impl Multiplexer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a subscriber to the cohort for the given query, starting a poller for the cohort
+    /// if it doesn't exist yet. The subscriber is removed from the cohort when the returned
+    /// `CohortSubscription` is dropped.
+    pub(crate) fn subscribe(
+        &self,
+        http_context: &HttpContext,
+        project_id: Option<&ProjectId>,
+        subscription: execute::NDCMultiplexedSubscriptionQuery,
+    ) -> Result<CohortSubscription, execute::FieldError> {
+        let key = cohort_key(
+            project_id,
+            &subscription.query_execution_plan,
+            subscription.polling_interval_ms,
+        )?;
+        // A single pending result per subscriber is enough; if the subscriber has not
+        // consumed it by the next poll, that poll's result is skipped for the subscriber.
+        let (sender, receiver) = mpsc::channel(1);
+        let subscriber = Subscriber {
+            variables: subscription.variables,
+            sender,
+        };
+
+        let mut cohorts = self.0.lock().unwrap();
+        let subscriber_id = SubscriberId(cohorts.next_subscriber_id);
+        cohorts.next_subscriber_id += 1;
+        let is_new_cohort = !cohorts.cohorts.contains_key(&key);
+        let cohort = cohorts.cohorts.entry(key.clone()).or_default();
+        cohort.subscribers.insert(subscriber_id, subscriber);
+        let subscriber_joined = cohort.subscriber_joined.clone();
+        drop(cohorts);
+
+        if is_new_cohort {
+            let multiplexer = self.clone();
+            let key = key.clone();
+            let http_context = http_context.clone();
+            let project_id = project_id.cloned();
+            let span_link = tracing_util::SpanLink::from_current_span();
+            tokio::spawn(async move {
+                multiplexer
+                    .poll_cohort(
+                        key,
+                        http_context,
+                        project_id,
+                        subscription.query_execution_plan,
+                        subscription.polling_interval_ms,
+                        subscriber_joined,
+                        span_link,
+                    )
+                    .await;
+            });
+        } else {
+            // Poll the existing cohort right away for the new subscriber
+            subscriber_joined.notify_one();
+        }
+
+        Ok(CohortSubscription {
+            multiplexer: self.clone(),
+            key,
+            subscriber_id,
+            receiver,
+        })
+    }
+
+    /// Returns the number of subscribers across all cohorts.
+    pub fn subscriber_count(&self) -> usize {
+        let cohorts = self.0.lock().unwrap();
+        cohorts
+            .cohorts
+            .values()
+            .map(|cohort| cohort.subscribers.len())
+            .sum()
+    }
+
+    /// Returns the number of cohorts being polled.
+    pub fn cohort_count(&self) -> usize {
+        self.0.lock().unwrap().cohorts.len()
+    }
+
+    fn unsubscribe(&self, key: &CohortKey, subscriber_id: SubscriberId) {
+        let mut cohorts = self.0.lock().unwrap();
+        if let Some(cohort) = cohorts.cohorts.get_mut(key) {
+            cohort.subscribers.remove(&subscriber_id);
+        }
+    }
+
+    /// Returns a snapshot of the current subscribers of a cohort. If the cohort has no
+    /// subscribers left, it is removed and `None` is returned, so that the poller exits.
+    fn snapshot_subscribers(&self, key: &CohortKey) -> Option<Vec<SubscriberSnapshot>> {
+        let mut cohorts = self.0.lock().unwrap();
+        let is_empty = cohorts
+            .cohorts
+            .get(key)
+            .map_or(true, |cohort| cohort.subscribers.is_empty());
+        if is_empty {
+            cohorts.cohorts.remove(key);
+            return None;
+        }
+        let cohort = cohorts.cohorts.get(key)?;
+        Some(
+            cohort
+                .subscribers
+                .values()
+                .map(|subscriber| (subscriber.variables.clone(), subscriber.sender.clone()))
+                .collect(),
+        )
+    }
+
+    /// Polls a cohort until it has no subscribers left.
+    async fn poll_cohort(
+        &self,
+        key: CohortKey,
+        http_context: HttpContext,
+        project_id: Option<ProjectId>,
+        query_execution_plan: QueryExecutionPlan,
+        polling_interval_ms: u64,
+        subscriber_joined: Arc<Notify>,
+        parent_span_link: tracing_util::SpanLink,
+    ) {
+        let polling_interval_duration = tokio::time::Duration::from_millis(polling_interval_ms);
+        let tracer = tracing_util::global_tracer();
+        let mut consecutive_failed_polls = 0;
+        while let Some(subscribers) = self.snapshot_subscribers(&key) {
+            let poll_started = tokio::time::Instant::now();
+            let (variable_sets, senders): (Vec<_>, Vec<_>) = subscribers.into_iter().unzip();
+            let subscriber_count = senders.len();
+            let result: Result<_, execute::FieldError> = tracer
+                .new_trace_async_with_link(
+                    "websocket_poll_multiplexed_subscription",
+                    "Polling a multiplexed subscription query",
+                    tracing_util::SpanVisibility::User,
+                    parent_span_link.clone(),
+                    || {
+                        tracing_util::set_attribute_on_active_span(
+                            tracing_util::AttributeVisibility::Default,
+                            "subscription.cohort.subscribers",
+                            subscriber_count.to_string(),
+                        );
+                        Box::pin(async {
+                            let query_request = execute::make_multiplexed_ndc_query_request(
+                                &query_execution_plan,
+                                variable_sets,
+                            )?;
+                            let response = execute::fetch_from_data_connector(
+                                &http_context,
+                                &query_request,
+                                &query_execution_plan.data_connector,
+                                project_id.as_ref(),
+                            )
+                            .await?;
+                            let rowsets = response.as_latest_rowsets();
+                            if rowsets.len() != subscriber_count {
+                                Err(execute::NDCUnexpectedError::BadNDCResponse {
+                                    summary: format!(
+                                        "expected {subscriber_count} rowsets, one for each variable set, but got {}",
+                                        rowsets.len()
+                                    ),
+                                })?;
+                            }
+                            Ok(rowsets)
+                        })
+                    },
+                )
+                .await;
+
+            match result {
+                Ok(rowsets) => {
+                    consecutive_failed_polls = 0;
+                    for (sender, rowset) in senders.iter().zip(rowsets) {
+                        // A full channel means the subscriber is still processing the previous
+                        // result, and a closed one that it has gone away; skip it either way.
+                        let _ = sender.try_send(Ok(rowset));
+                    }
+                }
+                Err(err) => {
+                    consecutive_failed_polls += 1;
+                    // Retry at the next polling interval, unless the cohort keeps failing
+                    if consecutive_failed_polls >= MAX_CONSECUTIVE_FAILED_POLLS {
+                        consecutive_failed_polls = 0;
+                        let err = Arc::new(err);
+                        for sender in &senders {
+                            let _ = sender.try_send(Err(err.clone()));
+                        }
+                    }
+                }
+            }
+            // Wait for the polling interval, or until a new subscriber joins. A joining
+            // subscriber is not polled for before the debounce time since the start of the
+            // previous poll, so that subscribers joining one after another share a poll.
+            let next_poll = tokio::time::Instant::now() + polling_interval_duration;
+            tokio::select! {
+                () = tokio::time::sleep_until(next_poll) => {}
+                () = subscriber_joined.notified() => {
+                    tokio::time::sleep_until(
+                        next_poll.min(poll_started + SUBSCRIBER_JOINED_DEBOUNCE),
+                    )
+                    .await;
+                }
+            }
+        }
+    }
+}
+
+/// A subscriber's handle on its cohort. Results of each poll are received through it, and
+/// dropping it removes the subscriber from the cohort.
+pub(crate) struct CohortSubscription {
+    multiplexer: Multiplexer,
+    key: CohortKey,
+    subscriber_id: SubscriberId,
+    receiver: mpsc::Receiver<PollResult>,
+}
+
+impl CohortSubscription {
+    /// Waits for the result of the next poll of the cohort.
+    pub(crate) async fn next(&mut self) -> Option<PollResult> {
+        self.receiver.recv().await
+    }
+}
+
+impl Drop for CohortSubscription {
+    fn drop(&mut self) {
+        self.multiplexer.unsubscribe(&self.key, self.subscriber_id);
+    }
+}
+
+/// Subscriptions are grouped by their project, the data connector they query, their polling
+/// interval and the parameterized NDC query request (without any variable sets). The multiplexer
+/// is shared by the whole server, so that subscriptions of different projects are never polled
+/// together, even if their data connectors have the same name.
+fn cohort_key(
+    project_id: Option<&ProjectId>,
+    query_execution_plan: &QueryExecutionPlan,
+    polling_interval_ms: u64,
+) -> Result<CohortKey, execute::FieldError> {
+    let query_request = execute::make_ndc_query_request(query_execution_plan.clone())?;
+    let serialized_request = serde_json::to_vec(&query_request).unwrap_or_default();
+    let mut hasher = Blake2b::new();
+    // The project id is prefixed with its length, so that it can't run into the data connector
+    // name
+    match project_id {
+        Some(project_id) => {
+            hasher.update([1]);
+            hasher.update((project_id.0.len() as u64).to_be_bytes());
+            hasher.update(project_id.0.as_bytes());
+        }
+        None => hasher.update([0]),
+    }
+    let data_connector_name = query_execution_plan.data_connector.name.to_string();
+    hasher.update(data_connector_name.as_bytes());
+    hasher.update(polling_interval_ms.to_be_bytes());
+    hasher.update(&serialized_request);
+    Ok(CohortKey(hasher.finalize().into()))
+}
@@ -4,7 +4,8 @@ use hasura_authn::{authenticate, AuthConfig, AuthError};
 use hasura_authn_core::{authorize_identity, Session, SessionError};
 use std::collections::HashMap;
 
-use super::types::{ConnectionInitState, InitPayload, ServerMessage};
+use super::legacy;
+use super::types::{ConnectionInitState, InitPayload, Protocol, ServerMessage};
 use crate::metrics::WebSocketMetrics;
 use crate::websocket::types as ws;
 
@@ -42,6 +43,13 @@ pub async fn handle_connection_init<M: WebSocketMetrics>(
                             connection.send(ws::Message::too_many_init_requests()).await;
                         }
                         Err(_e) => {
+                            // The legacy protocol reports the failure with a `connection_error`
+                            // message before the connection is closed
+                            if connection.protocol == Protocol::SubscriptionsTransportWs {
+                                connection
+                                    .send(ws::Message::Raw(legacy::connection_error("Forbidden")))
+                                    .await;
+                            }
                             // Initialization failed, send a forbidden message
                             connection.send(ws::Message::forbidden()).await;
                         }
@@ -0,0 +1,116 @@
+//! Messages of the legacy `graphql-ws` protocol of `subscriptions-transport-ws`.
+//! ref: <https://github.com/apollographql/subscriptions-transport-ws/blob/master/PROTOCOL.md>
+//!
+//! The messages are converted from and to those of `graphql-transport-ws`, so that both
+//! protocols share the same initialization, authentication and subscription handling.
+
+use axum::extract::ws;
+use nonempty::NonEmpty;
+use serde::{Deserialize, Serialize};
+
+use super::types::{self, InitPayload, OperationId};
+
+/// Messages that the client can send to the server
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+pub(crate) enum ClientMessage {
+    /// The client sends this message to the server to initiate the connection.
+    #[serde(rename = "connection_init")]
+    ConnectionInit { payload: Option<InitPayload> },
+
+    /// The client sends this message to the server to execute a GraphQL operation.
+    #[serde(rename = "start")]
+    Start {
+        id: OperationId,
+        payload: lang_graphql::http::RawRequest,
+    },
+
+    /// The client sends this message to the server to stop a running GraphQL operation.
+    #[serde(rename = "stop")]
+    Stop { id: OperationId },
+
+    /// The client sends this message to the server to terminate the connection.
+    #[serde(rename = "connection_terminate")]
+    ConnectionTerminate,
+}
+
+impl ClientMessage {
+    /// Converts the message into its `graphql-transport-ws` equivalent.
+    /// Returns `None` if the message terminates the connection.
+    pub(crate) fn into_client_message(self) -> Option<types::ClientMessage> {
+        match self {
+            Self::ConnectionInit { payload } => {
+                Some(types::ClientMessage::ConnectionInit { payload })
+            }
+            Self::Start { id, payload } => Some(types::ClientMessage::Subscribe { id, payload }),
+            Self::Stop { id } => Some(types::ClientMessage::Complete { id }),
+            Self::ConnectionTerminate => None,
+        }
+    }
+}
+
+/// Messages that the server can send to the client
+#[derive(Serialize)]
+#[serde(tag = "type")]
+pub(crate) enum ServerMessage<'a> {
+    /// The server sends this message to the client to acknowledge the connection.
+    #[serde(rename = "connection_ack")]
+    ConnectionAck,
+
+    /// The server sends this message to the client to reject the connection initialization.
+    #[serde(rename = "connection_error")]
+    ConnectionError { payload: ConnectionErrorPayload<'a> },
+
+    /// Server sends the result of a requested operation.
+    #[serde(rename = "data")]
+    Data {
+        id: &'a OperationId,
+        payload: &'a lang_graphql::http::Response,
+    },
+
+    /// Server sends errors resulting from a requested operation.
+    #[serde(rename = "error")]
+    Error {
+        id: &'a OperationId,
+        payload: &'a NonEmpty<lang_graphql::http::GraphQLError>,
+    },
+
+    /// Server sends this message to the client to indicate that the operation has been completed.
+    #[serde(rename = "complete")]
+    Complete { id: &'a OperationId },
+
+    /// Server sends this message periodically to keep the connection alive.
+    #[serde(rename = "ka")]
+    ConnectionKeepAlive,
+}
+
+impl<'a> ServerMessage<'a> {
+    /// Converts a `graphql-transport-ws` message into its equivalent.
+    /// Returns `None` if the protocol has no equivalent of the message.
+    pub(crate) fn from_server_message(message: &'a types::ServerMessage) -> Option<Self> {
+        match message {
+            types::ServerMessage::ConnectionAck => Some(Self::ConnectionAck),
+            types::ServerMessage::Next { id, payload } => Some(Self::Data { id, payload }),
+            types::ServerMessage::Error { id, payload } => Some(Self::Error { id, payload }),
+            types::ServerMessage::Complete { id } => Some(Self::Complete { id }),
+            // Pings are only sent to keep the connection alive
+            types::ServerMessage::Ping { .. } => Some(Self::ConnectionKeepAlive),
+            // Clients of this protocol don't send pings, so there is nothing to respond to
+            types::ServerMessage::Pong => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct ConnectionErrorPayload<'a> {
+    message: &'a str,
+}
+
+/// Returns the `connection_error` message rejecting the initialization of a connection, which
+/// has no equivalent in `graphql-transport-ws`, where the connection is closed right away.
+pub(crate) fn connection_error(message: &str) -> ws::Message {
+    let message = ServerMessage::ConnectionError {
+        payload: ConnectionErrorPayload { message },
+    };
+    ws::Message::Text(serde_json::to_string(&message).unwrap_or_default())
+}
@@ -1,4 +1,5 @@
 pub mod init;
+pub(crate) mod legacy;
 pub mod subscribe;
 pub mod types;
 
@@ -10,6 +11,10 @@ use types::{ClientMessage, ServerMessage};
 /// ref: <https://github.com/enisdenjo/graphql-ws/blob/master/PROTOCOL.md#communication>
 pub static GRAPHQL_WS_PROTOCOL: &str = "graphql-transport-ws";
 
+/// Protocol name for the legacy GraphQL over WebSocket of `subscriptions-transport-ws`.
+/// ref: <https://github.com/apollographql/subscriptions-transport-ws/blob/master/PROTOCOL.md>
+pub static SUBSCRIPTIONS_TRANSPORT_WS_PROTOCOL: &str = "graphql-ws";
+
 /// Timeout for the connection initialization process.
 pub static CONNECTION_INIT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(3);
 
@@ -5,11 +5,15 @@ use crate::websocket::types as ws;
 use axum::http;
 use blake2::{Blake2b, Digest};
 use engine_types::ExposeInternalErrors;
+use futures_util::StreamExt;
 use graphql_frontend::{process_response, ExecuteQueryResult, RootFieldResult};
 use graphql_ir::RequestPlan;
+use graphql_schema::GDS;
 use hasura_authn_core::Session;
 use indexmap::IndexMap;
+use lang_graphql::{ast::common as ast, normalized_ast};
 use nonempty::NonEmpty;
+use plan_types::{NDCSubscriptionExecution, ProcessResponseAs};
 use pre_parse_plugin::execute as pre_parse_plugin;
 use pre_response_plugin::execute as pre_response_plugin;
 
@@ -341,25 +345,13 @@ async fn execute<M: WebSocketMetrics>(
     match request_plan {
         // Handle mutations.
         RequestPlan::MutationPlan(mutation_plan) => {
-            let execute_query_result =
-                graphql_frontend::execute_mutation_plan(http_context, mutation_plan, project_id)
-                    .await;
-            send_single_result_operation_response(
-                client_address,
-                operation_id,
-                &raw_request,
-                session,
-                headers,
-                execute_query_result,
-                expose_internal_errors,
-                connection,
+            let execute_query_result = graphql_frontend::execute_mutation_plan(
+                http_context,
+                mutation_plan,
+                project_id,
+                &session.variables,
             )
             .await;
-        }
-        // Handle queries.
-        RequestPlan::QueryPlan(query_plan) => {
-            let execute_query_result =
-                graphql_frontend::execute_query_plan(http_context, query_plan, project_id).await;
             send_single_result_operation_response(
                 client_address,
                 operation_id,
@@ -372,124 +364,465 @@ async fn execute<M: WebSocketMetrics>(
             )
             .await;
         }
+        // Handle queries. Fragments deferred with `@defer` are sent after the initial result.
+        RequestPlan::QueryPlan(query_plan) => {
+            let (execute_query_result, deferred_execution) =
+                graphql_frontend::execute_query_plan_incremental(
+                    http_context,
+                    query_plan,
+                    project_id,
+                    &session.variables,
+                )
+                .await;
+            if deferred_execution.is_empty() {
+                send_single_result_operation_response(
+                    client_address,
+                    operation_id,
+                    &raw_request,
+                    session,
+                    headers,
+                    execute_query_result,
+                    expose_internal_errors,
+                    connection,
+                )
+                .await;
+            } else {
+                send_incremental_operation_response(
+                    client_address,
+                    operation_id,
+                    &raw_request,
+                    &session,
+                    &headers,
+                    execute_query_result,
+                    deferred_execution,
+                    connection,
+                )
+                .await;
+            }
+        }
         // Handle subscriptions by starting a polling loop to repeatedly fetch data.
         RequestPlan::SubscriptionPlan(alias, plan) => {
-            match execute::resolve_ndc_subscription_execution(plan.subscription_execution).await {
-                Ok(ndc_subscription) => {
-                    let query_request = ndc_subscription.query_request;
-                    let data_connector = ndc_subscription.data_connector;
-                    let selection_set = plan.selection_set;
-                    let process_response_as = ndc_subscription.process_response_as;
-                    let is_nullable = process_response_as.is_nullable();
-                    let polling_interval_duration =
-                        tokio::time::Duration::from_millis(ndc_subscription.polling_interval_ms);
-
-                    // Initialize a response hash to track changes in the response.
-                    let mut response_hash = ResponseHash::new();
-
-                    let tracer = tracing_util::global_tracer();
-                    let this_span_link = tracing_util::SpanLink::from_current_span();
-
-                    // A loop to periodically wait for the polling interval, then fetch data from NDC.
-                    loop {
-                        let result: Result<_, execute::FieldError> = tracer
-                            .new_trace_async_with_link(
-                                "websocket_poll_subscription",
-                                "Polling a subscription query",
-                                tracing_util::SpanVisibility::User,
-                                this_span_link.clone(),
-                                || {
-                                    tracing_util::set_attribute_on_active_span(
-                                        tracing_util::AttributeVisibility::Default,
-                                        "graphql.operation.id",
-                                        operation_id.0.clone(),
-                                    );
-                                    Box::pin(async {
-                                        // Fetch response from the connector
-                                        let response = execute::fetch_from_data_connector(
-                                            http_context,
-                                            &query_request,
-                                            &data_connector,
-                                            None,
-                                        )
-                                        .await?;
-                                        // Process response
-                                        let response_rowsets = response.as_latest_rowsets();
-                                        let processed_response = process_response(
-                                            selection_set,
-                                            response_rowsets,
-                                            &process_response_as,
-                                        );
-                                        let root_fields = IndexMap::from([(
-                                            alias.clone(),
-                                            RootFieldResult::from_processed_response(
-                                                is_nullable,
-                                                processed_response,
-                                            ),
-                                        )]);
-                                        // Generate a single root field query response
-                                        let query_result = ExecuteQueryResult { root_fields };
-
-                                        let graphql_response =
-                                            graphql_frontend::GraphQLResponse::from_result(
-                                                query_result,
-                                                expose_internal_errors,
-                                            )
-                                            .inner();
-                                        // Send the response
-                                        let stop_subscription =
-                                            send_subscription_operation_response(
-                                                client_address,
-                                                &mut response_hash,
-                                                operation_id.clone(),
-                                                &raw_request,
-                                                &session,
-                                                &headers,
-                                                graphql_response,
-                                                connection,
-                                            )
-                                            .await;
-                                        Ok(stop_subscription)
-                                    })
-                                },
-                            )
-                            .await;
+            let selection_set = plan.selection_set;
+            // Streaming subscriptions keep a cursor of their own, so they are always polled on their own.
+            let subscription_execution = match execute::resolve_stream_ndc_subscription_execution(
+                plan.subscription_execution,
+            ) {
+                Ok(stream_subscription) => {
+                    poll_stream_subscription(
+                        client_address,
+                        operation_id,
+                        connection,
+                        &session,
+                        &headers,
+                        &raw_request,
+                        &alias,
+                        selection_set,
+                        stream_subscription,
+                    )
+                    .await;
+                    return;
+                }
+                Err(subscription_execution) => subscription_execution,
+            };
+            match execute::resolve_multiplexed_ndc_subscription_execution(subscription_execution) {
+                // Poll the subscription together with the other subscriptions of its cohort,
+                // ie. those that differ from it only in variables.
+                Ok(multiplexed_subscription) => {
+                    poll_multiplexed_subscription(
+                        client_address,
+                        operation_id,
+                        connection,
+                        &session,
+                        &headers,
+                        &raw_request,
+                        &alias,
+                        selection_set,
+                        multiplexed_subscription,
+                    )
+                    .await;
+                }
+                // The data connector can't run the subscription with variables, so poll it on its own.
+                Err(subscription_execution) => {
+                    poll_subscription(
+                        client_address,
+                        operation_id,
+                        connection,
+                        &session,
+                        &headers,
+                        &raw_request,
+                        &alias,
+                        selection_set,
+                        subscription_execution,
+                    )
+                    .await;
+                }
+            }
+        }
+    }
+}
 
-                        match result {
-                            Ok(stop_subscription) => {
-                                // Stop the subscription, if only errors sent in the current response
-                                if stop_subscription {
-                                    break;
-                                }
-                            }
-                            Err(err) => {
-                                // Send the exception as a GraphQL error and stop polling
-                                let graphql_error =
-                                    err.to_graphql_error(expose_internal_errors, None);
-                                send_graphql_errors(
-                                    operation_id,
-                                    NonEmpty::new(graphql_error),
+/// Polls a subscription on its own, in a loop which periodically fetches data from the NDC.
+async fn poll_subscription<M: WebSocketMetrics>(
+    client_address: std::net::SocketAddr,
+    operation_id: OperationId,
+    connection: &ws::Connection<M>,
+    session: &Session,
+    headers: &http::HeaderMap,
+    raw_request: &lang_graphql::http::RawRequest,
+    alias: &ast::Alias,
+    selection_set: &normalized_ast::SelectionSet<'_, GDS>,
+    subscription_execution: NDCSubscriptionExecution,
+) {
+    let project_id = connection.context.project_id.as_ref();
+    let http_context = &connection.context.http_context;
+    let expose_internal_errors = connection.context.expose_internal_errors;
+    match execute::resolve_ndc_subscription_execution(subscription_execution).await {
+        Ok(ndc_subscription) => {
+            let query_request = ndc_subscription.query_request;
+            let data_connector = ndc_subscription.data_connector;
+            let process_response_as = ndc_subscription.process_response_as;
+            let polling_interval_duration =
+                tokio::time::Duration::from_millis(ndc_subscription.polling_interval_ms);
+
+            // Initialize a response hash to track changes in the response.
+            let mut response_hash = ResponseHash::new();
+
+            let tracer = tracing_util::global_tracer();
+            let this_span_link = tracing_util::SpanLink::from_current_span();
+
+            // A loop to periodically wait for the polling interval, then fetch data from NDC.
+            loop {
+                let result: Result<_, execute::FieldError> = tracer
+                    .new_trace_async_with_link(
+                        "websocket_poll_subscription",
+                        "Polling a subscription query",
+                        tracing_util::SpanVisibility::User,
+                        this_span_link.clone(),
+                        || {
+                            tracing_util::set_attribute_on_active_span(
+                                tracing_util::AttributeVisibility::Default,
+                                "graphql.operation.id",
+                                operation_id.0.clone(),
+                            );
+                            Box::pin(async {
+                                // Fetch response from the connector
+                                let response = execute::fetch_from_data_connector(
+                                    http_context,
+                                    &query_request,
+                                    &data_connector,
+                                    project_id,
+                                )
+                                .await?;
+                                // Process and send the response
+                                let stop_subscription = process_subscription_response(
+                                    client_address,
+                                    &mut response_hash,
+                                    &operation_id,
+                                    raw_request,
+                                    session,
+                                    headers,
+                                    alias,
+                                    selection_set,
+                                    &process_response_as,
+                                    response.as_latest_rowsets(),
                                     connection,
                                 )
                                 .await;
-                                break;
-                            }
+                                Ok(stop_subscription)
+                            })
+                        },
+                    )
+                    .await;
+
+                match result {
+                    Ok(stop_subscription) => {
+                        // Stop the subscription, if only errors sent in the current response
+                        if stop_subscription {
+                            break;
                         }
-                        // Wait for the polling interval
-                        tokio::time::sleep(polling_interval_duration).await;
+                    }
+                    Err(err) => {
+                        // Send the exception as a GraphQL error and stop polling
+                        let graphql_error = err.to_graphql_error(expose_internal_errors, None);
+                        send_graphql_errors(operation_id, NonEmpty::new(graphql_error), connection)
+                            .await;
+                        break;
                     }
                 }
-                // Send an error message if the subscription fails to resolve.
-                Err(e) => {
-                    let graphql_error = e.to_graphql_error(expose_internal_errors, None);
-                    send_graphql_errors(operation_id, NonEmpty::new(graphql_error), connection)
+                // Wait for the polling interval
+                tokio::time::sleep(polling_interval_duration).await;
+            }
+        }
+        // Send an error message if the subscription fails to resolve.
+        Err(e) => {
+            let graphql_error = e.to_graphql_error(expose_internal_errors, None);
+            send_graphql_errors(operation_id, NonEmpty::new(graphql_error), connection).await;
+        }
+    }
+}
+
+/// Polls a streaming subscription, in a loop which fetches the batch of rows after the cursor
+/// from the NDC and advances the cursor to the last row of the batch. Only new rows are sent
+/// to the client, and empty batches are not sent at all.
+async fn poll_stream_subscription<M: WebSocketMetrics>(
+    client_address: std::net::SocketAddr,
+    operation_id: OperationId,
+    connection: &ws::Connection<M>,
+    session: &Session,
+    headers: &http::HeaderMap,
+    raw_request: &lang_graphql::http::RawRequest,
+    alias: &ast::Alias,
+    selection_set: &normalized_ast::SelectionSet<'_, GDS>,
+    stream_subscription: execute::NDCStreamSubscriptionQuery,
+) {
+    let project_id = connection.context.project_id.as_ref();
+    let http_context = &connection.context.http_context;
+    let expose_internal_errors = connection.context.expose_internal_errors;
+    let execute::NDCStreamSubscriptionQuery {
+        query_execution_plan,
+        cursor,
+        process_response_as,
+        polling_interval_ms,
+    } = stream_subscription;
+    let data_connector = query_execution_plan.data_connector.clone();
+    let batch_size = query_execution_plan.query_node.limit;
+    let polling_interval_duration = tokio::time::Duration::from_millis(polling_interval_ms);
+
+    let mut cursor_value = cursor.initial_value.clone();
+    // Consecutive batches never contain the same rows, so this only serves to send errors once.
+    let mut response_hash = ResponseHash::new();
+
+    let tracer = tracing_util::global_tracer();
+    let this_span_link = tracing_util::SpanLink::from_current_span();
+
+    // A loop to fetch batches from NDC, waiting for the polling interval whenever the
+    // subscriber has caught up with the rows in the data source.
+    loop {
+        let result: Result<_, execute::FieldError> = tracer
+            .new_trace_async_with_link(
+                "websocket_poll_stream_subscription",
+                "Polling a streaming subscription query",
+                tracing_util::SpanVisibility::User,
+                this_span_link.clone(),
+                || {
+                    tracing_util::set_attribute_on_active_span(
+                        tracing_util::AttributeVisibility::Default,
+                        "graphql.operation.id",
+                        operation_id.0.clone(),
+                    );
+                    Box::pin(async {
+                        // Fetch the batch after the cursor from the connector
+                        let query_request = execute::make_stream_ndc_query_request(
+                            &query_execution_plan,
+                            &cursor,
+                            &cursor_value,
+                        )?;
+                        let response = execute::fetch_from_data_connector(
+                            http_context,
+                            &query_request,
+                            &data_connector,
+                            project_id,
+                        )
+                        .await?;
+                        let rowsets = response.as_latest_rowsets();
+                        let Some(next_cursor_value) = rowsets
+                            .first()
+                            .and_then(|rowset| execute::get_stream_cursor_value(&cursor, rowset))
+                        else {
+                            // Nothing new since the last batch
+                            return Ok((false, false));
+                        };
+                        let batch_is_full = rowsets
+                            .first()
+                            .and_then(|rowset| rowset.rows.as_ref())
+                            .is_some_and(|rows| {
+                                batch_size
+                                    .is_some_and(|batch_size| rows.len() >= batch_size as usize)
+                            });
+                        cursor_value = next_cursor_value;
+                        // Process and send the batch
+                        let stop_subscription = process_subscription_response(
+                            client_address,
+                            &mut response_hash,
+                            &operation_id,
+                            raw_request,
+                            session,
+                            headers,
+                            alias,
+                            selection_set,
+                            &process_response_as,
+                            rowsets,
+                            connection,
+                        )
                         .await;
+                        Ok((stop_subscription, batch_is_full))
+                    })
+                },
+            )
+            .await;
+
+        match result {
+            Ok((stop_subscription, batch_is_full)) => {
+                // Stop the subscription, if only errors sent in the current response
+                if stop_subscription {
+                    break;
+                }
+                // More rows may be waiting after a full batch, so fetch them right away
+                if batch_is_full {
+                    continue;
+                }
+            }
+            Err(err) => {
+                // Send the exception as a GraphQL error and stop polling
+                let graphql_error = err.to_graphql_error(expose_internal_errors, None);
+                send_graphql_errors(operation_id, NonEmpty::new(graphql_error), connection).await;
+                break;
+            }
+        }
+        // Wait for the polling interval
+        tokio::time::sleep(polling_interval_duration).await;
+    }
+}
+
+/// Polls a subscription as a member of its cohort. The cohort is polled by the multiplexer
+/// with a single NDC request for all of its subscribers, and this subscriber receives its
+/// own rowset from each poll.
+async fn poll_multiplexed_subscription<M: WebSocketMetrics>(
+    client_address: std::net::SocketAddr,
+    operation_id: OperationId,
+    connection: &ws::Connection<M>,
+    session: &Session,
+    headers: &http::HeaderMap,
+    raw_request: &lang_graphql::http::RawRequest,
+    alias: &ast::Alias,
+    selection_set: &normalized_ast::SelectionSet<'_, GDS>,
+    multiplexed_subscription: execute::NDCMultiplexedSubscriptionQuery,
+) {
+    let expose_internal_errors = connection.context.expose_internal_errors;
+    let process_response_as = multiplexed_subscription.process_response_as.clone();
+    // Join the cohort. The subscriber leaves the cohort when `cohort_subscription` is dropped,
+    // ie. when this function returns or the poller running it is stopped.
+    let mut cohort_subscription = match connection.multiplexer.subscribe(
+        &connection.context.http_context,
+        connection.context.project_id.as_ref(),
+        multiplexed_subscription,
+    ) {
+        Ok(cohort_subscription) => cohort_subscription,
+        Err(e) => {
+            let graphql_error = e.to_graphql_error(expose_internal_errors, None);
+            send_graphql_errors(operation_id, NonEmpty::new(graphql_error), connection).await;
+            return;
+        }
+    };
+
+    // Initialize a response hash to track changes in the response.
+    let mut response_hash = ResponseHash::new();
+
+    let tracer = tracing_util::global_tracer();
+    let this_span_link = tracing_util::SpanLink::from_current_span();
+
+    // Wait for the result of each poll of the cohort
+    while let Some(poll_result) = cohort_subscription.next().await {
+        match poll_result {
+            Ok(rowset) => {
+                let stop_subscription = tracer
+                    .new_trace_async_with_link(
+                        "websocket_process_multiplexed_subscription",
+                        "Processing a multiplexed subscription result",
+                        tracing_util::SpanVisibility::User,
+                        this_span_link.clone(),
+                        || {
+                            tracing_util::set_attribute_on_active_span(
+                                tracing_util::AttributeVisibility::Default,
+                                "graphql.operation.id",
+                                operation_id.0.clone(),
+                            );
+                            Box::pin(async {
+                                let stop_subscription = process_subscription_response(
+                                    client_address,
+                                    &mut response_hash,
+                                    &operation_id,
+                                    raw_request,
+                                    session,
+                                    headers,
+                                    alias,
+                                    selection_set,
+                                    &process_response_as,
+                                    vec![rowset],
+                                    connection,
+                                )
+                                .await;
+                                tracing_util::Successful::new(stop_subscription)
+                            })
+                        },
+                    )
+                    .await
+                    .into_inner();
+                // Stop the subscription, if only errors sent in the current response
+                if stop_subscription {
+                    break;
                 }
             }
+            Err(err) => {
+                // Send the exception as a GraphQL error and stop polling
+                let graphql_error = err.to_graphql_error(expose_internal_errors, None);
+                send_graphql_errors(operation_id, NonEmpty::new(graphql_error), connection).await;
+                break;
+            }
         }
     }
 }
 
+/// Processes the rowsets fetched for a subscription into a GraphQL response, and sends it if
+/// it changed since the last poll. Returns whether the subscription should be stopped.
+async fn process_subscription_response<M: WebSocketMetrics>(
+    client_address: std::net::SocketAddr,
+    response_hash: &mut ResponseHash,
+    operation_id: &OperationId,
+    raw_request: &lang_graphql::http::RawRequest,
+    session: &Session,
+    headers: &http::HeaderMap,
+    alias: &ast::Alias,
+    selection_set: &normalized_ast::SelectionSet<'_, GDS>,
+    process_response_as: &ProcessResponseAs,
+    response_rowsets: Vec<ndc_models::RowSet>,
+    connection: &ws::Connection<M>,
+) -> bool {
+    let processed_response = process_response(
+        selection_set,
+        response_rowsets,
+        process_response_as,
+        &session.variables,
+    );
+    let root_fields = IndexMap::from([(
+        alias.clone(),
+        RootFieldResult::from_processed_response(
+            process_response_as.is_nullable(),
+            processed_response,
+        ),
+    )]);
+    // Generate a single root field query response
+    let query_result = ExecuteQueryResult { root_fields };
+
+    let graphql_response = graphql_frontend::GraphQLResponse::from_result(
+        query_result,
+        connection.context.expose_internal_errors,
+    )
+    .inner();
+    // Send the response
+    send_subscription_operation_response(
+        client_address,
+        response_hash,
+        operation_id.clone(),
+        raw_request,
+        session,
+        headers,
+        graphql_response,
+        connection,
+    )
+    .await
+}
+
 #[derive(PartialEq, Eq)]
 struct ResponseHash(Option<[u8; 64]>);
 
@@ -557,23 +890,16 @@ enum GraphQLResponse {
 
 impl GraphQLResponse {
     fn new(response: lang_graphql::http::Response) -> Self {
-        // If any error exist
-        if let Some(errors) = response.errors {
-            // If some data present
-            if let Some(data) = response.data {
-                // It is a partial response
-                Self::Ok(lang_graphql::http::Response::partial(
-                    data,
-                    errors.into(),
-                    response.headers,
-                ))
-            } else {
-                // If no data present, it is an error
-                Self::Error(errors)
-            }
-        } else {
-            // No errors, Ok response
-            Self::Ok(response)
+        match response {
+            // If errors exist but no data is present, it is an error
+            lang_graphql::http::Response {
+                data: None,
+                errors: Some(errors),
+                incremental: None,
+                ..
+            } => Self::Error(errors),
+            // Otherwise it is an Ok response, possibly a partial one
+            response => Self::Ok(response),
         }
     }
 }
@@ -615,6 +941,62 @@ async fn send_single_result_operation_response<M: WebSocketMetrics>(
     }
 }
 
+/// Sends the initial result of a query with deferred fragments, followed by a result for the
+/// deferred fragments of each root field as they are executed, and a completion message.
+#[allow(clippy::too_many_arguments)]
+async fn send_incremental_operation_response<M: WebSocketMetrics>(
+    client_address: std::net::SocketAddr,
+    operation_id: OperationId,
+    raw_request: &lang_graphql::http::RawRequest,
+    session: &Session,
+    headers: &http::HeaderMap,
+    result: ExecuteQueryResult,
+    deferred_execution: graphql_frontend::DeferredQueryExecution<'_, '_>,
+    connection: &ws::Connection<M>,
+) {
+    let expose_internal_errors = connection.context.expose_internal_errors;
+    let graphql_response =
+        graphql_frontend::GraphQLResponse::from_result(result, expose_internal_errors)
+            .inner()
+            .with_has_next(true);
+    run_pre_response_plugins(
+        client_address,
+        raw_request,
+        session.clone(),
+        headers.clone(),
+        &graphql_response,
+        connection,
+    );
+    match GraphQLResponse::new(graphql_response) {
+        GraphQLResponse::Ok(response) => {
+            send_graphql_ok(operation_id.clone(), response, connection).await;
+        }
+        GraphQLResponse::Error(errors) => {
+            // There is no data to complete with the deferred fragments
+            send_graphql_errors(operation_id, errors, connection).await;
+            return;
+        }
+    }
+    let mut subsequent_responses = std::pin::pin!(deferred_execution.execute(
+        &connection.context.http_context,
+        connection.context.project_id.as_ref(),
+        expose_internal_errors,
+        &session.variables,
+    ));
+    while let Some(response) = subsequent_responses.next().await {
+        run_pre_response_plugins(
+            client_address,
+            raw_request,
+            session.clone(),
+            headers.clone(),
+            &response,
+            connection,
+        );
+        send_graphql_ok(operation_id.clone(), response, connection).await;
+    }
+    send_complete(operation_id, connection).await;
+}
+
 /// Sends a subscription operation response.
 async fn send_subscription_operation_response<M: WebSocketMetrics>(
     client_address: std::net::SocketAddr,
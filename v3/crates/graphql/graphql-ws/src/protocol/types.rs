@@ -4,6 +4,59 @@ use nonempty::NonEmpty;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// The WebSocket sub-protocols GraphQL operations can be served over, negotiated with the
+/// `Sec-WebSocket-Protocol` header. Operations are handled in terms of the messages of
+/// `graphql-transport-ws`, which are converted from and to those of the legacy protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Protocol {
+    /// ref: <https://github.com/enisdenjo/graphql-ws/blob/master/PROTOCOL.md>
+    GraphqlTransportWs,
+    /// The legacy `graphql-ws` protocol of `subscriptions-transport-ws`.
+    /// ref: <https://github.com/apollographql/subscriptions-transport-ws/blob/master/PROTOCOL.md>
+    SubscriptionsTransportWs,
+}
+
+impl Protocol {
+    /// Returns the name of the protocol in the `Sec-WebSocket-Protocol` header.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::GraphqlTransportWs => super::GRAPHQL_WS_PROTOCOL,
+            Self::SubscriptionsTransportWs => super::SUBSCRIPTIONS_TRANSPORT_WS_PROTOCOL,
+        }
+    }
+
+    /// Parses a message from the client.
+    /// Returns `None` if the message terminates the connection.
+    pub fn parse_client_message(
+        self,
+        data: &[u8],
+    ) -> Result<Option<ClientMessage>, serde_json::Error> {
+        match self {
+            Self::GraphqlTransportWs => serde_json::from_slice::<ClientMessage>(data).map(Some),
+            Self::SubscriptionsTransportWs => {
+                serde_json::from_slice::<super::legacy::ClientMessage>(data)
+                    .map(super::legacy::ClientMessage::into_client_message)
+            }
+        }
+    }
+
+    /// Serializes a message to the client.
+    /// Returns `None` if the protocol has no equivalent of the message.
+    pub fn serialize_server_message(
+        self,
+        message: &ServerMessage,
+    ) -> Result<Option<String>, serde_json::Error> {
+        match self {
+            Self::GraphqlTransportWs => serde_json::to_string(message).map(Some),
+            Self::SubscriptionsTransportWs => {
+                super::legacy::ServerMessage::from_server_message(message)
+                    .map(|message| serde_json::to_string(&message))
+                    .transpose()
+            }
+        }
+    }
+}
+
 /// A unique identifier for a GraphQL operation.
 /// Sent by the client with the `subscribe` message and used by the server to identify the operation.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
@@ -0,0 +1,649 @@
+//! GraphQL over Server-Sent Events, implementing the `graphql-sse` protocol in both the
+//! distinct connections and the single connection modes.
+//! ref: <https://github.com/enisdenjo/graphql-sse/blob/master/PROTOCOL.md>
+//!
+//! An event stream is served by a `Connection`, just like a WebSocket, so that operations are
+//! executed, polled and multiplexed by the same code as over `graphql-transport-ws`. The
+//! connection is initialized with the session of the HTTP request instead of a
+//! `connection_init` message, and its messages are sent as events instead of WebSocket frames.
+//!
+//! In the single connection mode, the token of a reserved event stream is bound to the session
+//! that reserved it: requests using the token with any other session are rejected.
+
+use axum::{
+    extract::ws,
+    http::{header, HeaderMap, StatusCode},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
+};
+use futures_util::StreamExt;
+use hasura_authn_core::Session;
+use nonempty::NonEmpty;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, Mutex};
+
+use crate::metrics::WebSocketMetrics;
+use crate::multiplexer::Multiplexer;
+use crate::protocol;
+use crate::protocol::types::{ConnectionInitState, OperationId, Protocol, ServerMessage};
+use crate::websocket::{self, types};
+
+/// Header carrying the token of a reserved event stream in the single connection mode.
+pub static EVENT_STREAM_TOKEN_HEADER: &str = "x-graphql-event-stream-token";
+static TEXT_EVENT_STREAM: &str = "text/event-stream";
+pub(crate) static JSON_MEDIA_TYPES: [&str; 2] =
+    ["application/json", "application/graphql-response+json"];
+static EVENT_STREAM_CHANNEL_SIZE: usize = 50;
+/// How long a reserved event stream waits to be opened before it is dropped.
+static DEFAULT_RESERVATION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Returns whether the request prefers its response as an event stream, ie. the quality value
+/// of `text/event-stream` in its `Accept` headers is strictly higher than that of JSON.
+pub fn accepts_event_stream(headers: &HeaderMap) -> bool {
+    let media_ranges = parse_accept_headers(headers);
+    let event_stream_quality = media_type_quality(&media_ranges, TEXT_EVENT_STREAM);
+    let json_quality = JSON_MEDIA_TYPES
+        .iter()
+        .map(|media_type| media_type_quality(&media_ranges, media_type))
+        .fold(0.0, f32::max);
+    event_stream_quality > 0.0 && event_stream_quality > json_quality
+}
+
+/// The media ranges of the `Accept` headers, along with their quality values.
+pub(crate) fn parse_accept_headers(headers: &HeaderMap) -> Vec<(String, f32)> {
+    headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(websocket::parse_comma_separated_header_values)
+        .filter_map(|media_range| {
+            let mut parts = media_range.split(';').map(str::trim);
+            let media_type = parts.next()?.to_ascii_lowercase();
+            let quality = parts
+                .find_map(|parameter| parameter.strip_prefix("q="))
+                .map_or(Some(1.0), |quality| quality.parse().ok())?;
+            Some((media_type, quality))
+        })
+        .collect()
+}
+
+/// The quality value of a media type, from the most specific media range matching it.
+pub(crate) fn media_type_quality(media_ranges: &[(String, f32)], media_type: &str) -> f32 {
+    let media_type_wildcard = media_type
+        .split_once('/')
+        .map(|(type_, _)| format!("{type_}/*"));
+    [
+        Some(media_type.to_string()),
+        media_type_wildcard,
+        Some("*/*".to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .find_map(|range| {
+        media_ranges
+            .iter()
+            .find(|(media_range, _)| *media_range == range)
+            .map(|(_, quality)| *quality)
+    })
+    .unwrap_or(0.0)
+}
+
+/// The body of an operation request.
+#[derive(Debug, Deserialize)]
+pub struct EventStreamRequest {
+    #[serde(flatten)]
+    pub request: lang_graphql::http::RawRequest,
+    #[serde(default)]
+    pub extensions: EventStreamRequestExtensions,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventStreamRequestExtensions {
+    /// Identifies the operation on a reserved event stream, in the single connection mode.
+    pub operation_id: Option<OperationId>,
+}
+
+/// The query parameters of event stream requests.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventStreamParams {
+    /// The token of a reserved event stream, for clients which cannot set headers.
+    pub token: Option<String>,
+    /// The operation to stop on a reserved event stream.
+    pub operation_id: Option<OperationId>,
+}
+
+/// The modes of the `graphql-sse` protocol.
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    /// Each operation has an event stream of its own, which ends when the operation completes.
+    DistinctConnections,
+    /// Operations are sent over a single reserved event stream, identified by a token.
+    SingleConnection,
+}
+
+/// An event stream reserved in the single connection mode.
+struct Reservation<M> {
+    connection: types::Connection<M>,
+    /// The session which reserved the event stream. Only requests with this session can use
+    /// the token of the stream.
+    session: Session,
+    /// The messages of the connection, until the event stream is opened.
+    receiver: Option<mpsc::Receiver<types::Message>>,
+}
+
+type Reservations<M> = Arc<Mutex<HashMap<types::WebSocketId, Reservation<M>>>>;
+
+/// GraphQL over Server-Sent Events server.
+/// It shares its multiplexer with the WebSocket server, so subscriptions are multiplexed across
+/// both transports. Its connections are kept apart from the WebSocket connections, so that a
+/// WebSocket connection can never be used as a reserved event stream.
+pub struct EventStreamServer<M> {
+    pub(crate) connections: types::Connections<M>,
+    multiplexer: Multiplexer,
+    /// Event streams reserved in the single connection mode, by their token.
+    reservations: Reservations<M>,
+    reservation_timeout: Duration,
+}
+
+impl<M: WebSocketMetrics> EventStreamServer<M> {
+    pub fn new(websocket_server: &websocket::WebSocketServer<M>) -> Self {
+        Self {
+            connections: types::Connections::new(),
+            multiplexer: websocket_server.multiplexer.clone(),
+            reservations: Arc::new(Mutex::new(HashMap::new())),
+            reservation_timeout: DEFAULT_RESERVATION_TIMEOUT,
+        }
+    }
+
+    /// Sets how long a reserved event stream waits to be opened before it is dropped.
+    #[must_use]
+    pub fn with_reservation_timeout(mut self, reservation_timeout: Duration) -> Self {
+        self.reservation_timeout = reservation_timeout;
+        self
+    }
+
+    /// Ends all active event streams.
+    pub async fn shutdown(&self, reason: &'static str) {
+        self.reservations.lock().await.clear();
+        let mut map = self.connections.0.write().await;
+        for (_, connection) in map.drain() {
+            // Sending a close message through the channel ends the event stream
+            connection
+                .send(types::Message::force_reconnect(reason))
+                .await;
+        }
+    }
+
+    /// Handles an operation request. The operation is sent over the reserved event stream if
+    /// the request carries a token, otherwise over an event stream of its own.
+    pub async fn handle_operation_request(
+        &self,
+        client_address: std::net::SocketAddr,
+        session: Session,
+        headers: HeaderMap,
+        params: &EventStreamParams,
+        request: EventStreamRequest,
+        context: types::Context<M>,
+    ) -> Response {
+        match get_token(&headers, params) {
+            None => {
+                self.handle_distinct_connection(
+                    client_address,
+                    session,
+                    headers,
+                    request.request,
+                    context,
+                )
+                .await
+            }
+            Some(token) => self
+                .handle_single_connection_operation(client_address, &session, &token, request)
+                .await
+                .map_or_else(IntoResponse::into_response, IntoResponse::into_response),
+        }
+    }
+
+    /// Runs an operation over an event stream of its own (distinct connections mode).
+    pub async fn handle_distinct_connection(
+        &self,
+        client_address: std::net::SocketAddr,
+        session: Session,
+        headers: HeaderMap,
+        request: lang_graphql::http::RawRequest,
+        context: types::Context<M>,
+    ) -> Response {
+        let (connection, receiver) = self.new_connection(session, headers, context).await;
+        // There is a single operation on the stream, so it is named after the stream itself
+        let operation_id = OperationId(connection.id.to_string());
+        protocol::subscribe::handle_subscribe(
+            client_address,
+            connection.clone(),
+            operation_id,
+            request,
+        )
+        .await;
+        let guard = ConnectionGuard::new(self.connections.clone(), connection.id.clone());
+        event_stream_response(Mode::DistinctConnections, receiver, connection, guard)
+    }
+
+    /// Reserves an event stream for the single connection mode. The response is the token
+    /// identifying the stream, to be sent with the subsequent requests. The reservation is
+    /// dropped if the stream is not opened within the reservation timeout.
+    pub async fn handle_reservation(
+        &self,
+        session: Session,
+        headers: HeaderMap,
+        context: types::Context<M>,
+    ) -> Response {
+        let (connection, receiver) = self.new_connection(session.clone(), headers, context).await;
+        let token = connection.id.clone();
+        self.reservations.lock().await.insert(
+            token.clone(),
+            Reservation {
+                connection,
+                session,
+                receiver: Some(receiver),
+            },
+        );
+
+        let reservations = self.reservations.clone();
+        let connections = self.connections.clone();
+        let reservation_timeout = self.reservation_timeout;
+        let reserved_token = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(reservation_timeout).await;
+            let mut reservations = reservations.lock().await;
+            // Drop the reservation if its event stream was never opened
+            if reservations
+                .get(&reserved_token)
+                .is_some_and(|reservation| reservation.receiver.is_some())
+            {
+                reservations.remove(&reserved_token);
+                drop(reservations);
+                connections.drop(&reserved_token).await;
+            }
+        });
+
+        (StatusCode::CREATED, token.to_string()).into_response()
+    }
+
+    /// Opens a reserved event stream.
+    pub async fn handle_event_stream(
+        &self,
+        session: &Session,
+        headers: &HeaderMap,
+        params: &EventStreamParams,
+    ) -> Result<Response, EventStreamError> {
+        let token = get_token(headers, params).ok_or(EventStreamError::MissingToken)?;
+        let mut reservations = self.reservations.lock().await;
+        let reservation = get_reservation(&mut reservations, &token, session)?;
+        // A reserved event stream can only be opened once
+        let receiver = reservation
+            .receiver
+            .take()
+            .ok_or(EventStreamError::StreamAlreadyOpen)?;
+        let connection = reservation.connection.clone();
+        drop(reservations);
+        let guard = ConnectionGuard {
+            connections: self.connections.clone(),
+            id: connection.id.clone(),
+            reservations: Some(self.reservations.clone()),
+        };
+        Ok(event_stream_response(
+            Mode::SingleConnection,
+            receiver,
+            connection,
+            guard,
+        ))
+    }
+
+    /// Stops an operation running on a reserved event stream.
+    pub async fn handle_operation_complete(
+        &self,
+        session: &Session,
+        headers: &HeaderMap,
+        params: &EventStreamParams,
+    ) -> Result<StatusCode, EventStreamError> {
+        let token = get_token(headers, params).ok_or(EventStreamError::MissingToken)?;
+        let operation_id = params
+            .operation_id
+            .as_ref()
+            .ok_or(EventStreamError::MissingOperationId)?;
+        let connection = self.get_reserved_connection(&token, session).await?;
+        connection.stop_poller(operation_id).await;
+        Ok(StatusCode::OK)
+    }
+
+    /// Starts an operation on a reserved event stream. The results are sent over the stream,
+    /// so the request is only accepted here.
+    async fn handle_single_connection_operation(
+        &self,
+        client_address: std::net::SocketAddr,
+        session: &Session,
+        token: &types::WebSocketId,
+        request: EventStreamRequest,
+    ) -> Result<StatusCode, EventStreamError> {
+        let connection = self.get_reserved_connection(token, session).await?;
+        let operation_id = request
+            .extensions
+            .operation_id
+            .ok_or(EventStreamError::MissingOperationId)?;
+        protocol::subscribe::handle_subscribe(
+            client_address,
+            connection,
+            operation_id,
+            request.request,
+        )
+        .await;
+        Ok(StatusCode::ACCEPTED)
+    }
+
+    /// Creates a connection for an event stream, initialized with the session of the request.
+    pub(crate) async fn new_connection(
+        &self,
+        session: Session,
+        headers: HeaderMap,
+        context: types::Context<M>,
+    ) -> (types::Connection<M>, mpsc::Receiver<types::Message>) {
+        let (channel_sender, channel_receiver) =
+            mpsc::channel::<types::Message>(EVENT_STREAM_CHANNEL_SIZE);
+        let connection = self
+            .connections
+            .new_connection(
+                types::WebSocketId::new(),
+                // Events are converted from the messages of this protocol
+                Protocol::GraphqlTransportWs,
+                context,
+                channel_sender,
+                self.multiplexer.clone(),
+            )
+            .await;
+        *connection.protocol_init_state.write().await =
+            ConnectionInitState::Initialized { session, headers };
+        (connection, channel_receiver)
+    }
+
+    /// Returns the connection of a reserved event stream, if it was reserved by the session.
+    async fn get_reserved_connection(
+        &self,
+        token: &types::WebSocketId,
+        session: &Session,
+    ) -> Result<types::Connection<M>, EventStreamError> {
+        let mut reservations = self.reservations.lock().await;
+        let reservation = get_reservation(&mut reservations, token, session)?;
+        Ok(reservation.connection.clone())
+    }
+}
+
+fn get_reservation<'a, M>(
+    reservations: &'a mut HashMap<types::WebSocketId, Reservation<M>>,
+    token: &types::WebSocketId,
+    session: &Session,
+) -> Result<&'a mut Reservation<M>, EventStreamError> {
+    let reservation = reservations
+        .get_mut(token)
+        .ok_or(EventStreamError::StreamNotFound)?;
+    if reservation.session != *session {
+        return Err(EventStreamError::SessionMismatch);
+    }
+    Ok(reservation)
+}
+
+/// Error types for event stream requests.
+#[derive(Debug, thiserror::Error)]
+pub enum EventStreamError {
+    #[error("Missing {EVENT_STREAM_TOKEN_HEADER} header or token query parameter")]
+    MissingToken,
+
+    #[error("Missing operation id")]
+    MissingOperationId,
+
+    #[error("Event stream not found")]
+    StreamNotFound,
+
+    #[error("Event stream is already open")]
+    StreamAlreadyOpen,
+
+    #[error("Event stream was reserved by another session")]
+    SessionMismatch,
+}
+
+impl tracing_util::TraceableError for EventStreamError {
+    fn visibility(&self) -> tracing_util::ErrorVisibility {
+        tracing_util::ErrorVisibility::User
+    }
+}
+
+impl IntoResponse for EventStreamError {
+    fn into_response(self) -> Response {
+        let status_code = match self {
+            Self::MissingToken | Self::MissingOperationId => StatusCode::BAD_REQUEST,
+            Self::StreamNotFound => StatusCode::NOT_FOUND,
+            Self::StreamAlreadyOpen => StatusCode::CONFLICT,
+            Self::SessionMismatch => StatusCode::FORBIDDEN,
+        };
+        (status_code, self.to_string()).into_response()
+    }
+}
+
+fn get_token(headers: &HeaderMap, params: &EventStreamParams) -> Option<types::WebSocketId> {
+    headers
+        .get(EVENT_STREAM_TOKEN_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .or(params.token.as_deref())
+        .map(types::WebSocketId::parse)
+}
+
+/// Drops the connection of an event stream along with the stream, ie. when the stream ends
+/// or the client goes away, which stops the pollers of its operations. The reservation of the
+/// stream, if any, is dropped too.
+pub(crate) struct ConnectionGuard<M: WebSocketMetrics> {
+    connections: types::Connections<M>,
+    id: types::WebSocketId,
+    reservations: Option<Reservations<M>>,
+}
+
+impl<M: WebSocketMetrics> ConnectionGuard<M> {
+    pub(crate) fn new(connections: types::Connections<M>, id: types::WebSocketId) -> Self {
+        Self {
+            connections,
+            id,
+            reservations: None,
+        }
+    }
+}
+
+impl<M: WebSocketMetrics> Drop for ConnectionGuard<M> {
+    fn drop(&mut self) {
+        let connections = self.connections.clone();
+        let reservations = self.reservations.take();
+        let id = self.id.clone();
+        tokio::spawn(async move {
+            if let Some(reservations) = reservations {
+                reservations.lock().await.remove(&id);
+            }
+            connections.drop(&id).await;
+        });
+    }
+}
+
+/// Sends the messages of a connection as an event stream.
+fn event_stream_response<M: WebSocketMetrics>(
+    mode: Mode,
+    receiver: mpsc::Receiver<types::Message>,
+    connection: types::Connection<M>,
+    guard: ConnectionGuard<M>,
+) -> Response {
+    let events = futures_util::stream::unfold(
+        Some((receiver, connection, guard)),
+        move |state| async move {
+            let (mut receiver, connection, guard) = state?;
+            let message = receiver.recv().await?;
+            let (events, end_of_stream) = message_to_events(mode, &connection, message).await;
+            let state = if end_of_stream {
+                None
+            } else {
+                Some((receiver, connection, guard))
+            };
+            Some((events, state))
+        },
+    )
+    .flat_map(futures_util::stream::iter);
+    // Keep-alive is done with comments in the event stream, rather than with ping messages
+    Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+#[derive(Serialize)]
+struct SingleConnectionNext<'a, T> {
+    id: &'a OperationId,
+    payload: &'a T,
+}
+
+#[derive(Serialize)]
+struct SingleConnectionComplete<'a> {
+    id: &'a OperationId,
+}
+
+#[derive(Serialize)]
+struct ErrorsPayload<'a> {
+    errors: &'a NonEmpty<lang_graphql::http::GraphQLError>,
+}
+
+/// Converts a connection message into events. Also returns whether the stream should end.
+async fn message_to_events<M: WebSocketMetrics>(
+    mode: Mode,
+    connection: &types::Connection<M>,
+    message: types::Message,
+) -> (Vec<Result<Event, axum::Error>>, bool) {
+    let ends_with_operation = mode == Mode::DistinctConnections;
+    match message {
+        types::Message::Protocol(message) => {
+            // Stop the poller if the operation is complete or an error occurred
+            if let Some(operation_id) = message.is_complete_or_error() {
+                connection.stop_poller(operation_id).await;
+            }
+            match *message {
+                ServerMessage::Next { id, payload } => {
+                    (vec![next_event(mode, &id, &payload)], false)
+                }
+                // There is no error event in the protocol, so errors are sent as a result,
+                // followed by the completion of the operation.
+                ServerMessage::Error { id, payload } => {
+                    let errors = ErrorsPayload { errors: &payload };
+                    (
+                        vec![next_event(mode, &id, &errors), complete_event(mode, &id)],
+                        ends_with_operation,
+                    )
+                }
+                ServerMessage::Complete { id } => {
+                    (vec![complete_event(mode, &id)], ends_with_operation)
+                }
+                ServerMessage::ConnectionAck | ServerMessage::Ping { .. } | ServerMessage::Pong => {
+                    (vec![], false)
+                }
+            }
+        }
+        // Close messages end the stream, eg. on shutdown
+        types::Message::Raw(ws::Message::Close(_)) => (vec![], true),
+        // Responses returned by pre-parse plugins are sent in place of the operation result.
+        // They carry no operation id, so they can only be sent in the distinct connections mode.
+        types::Message::Raw(message) => match mode {
+            Mode::DistinctConnections => {
+                let data = String::from_utf8_lossy(&message.into_data()).into_owned();
+                (
+                    vec![
+                        Ok(Event::default().event("next").data(data)),
+                        Ok(Event::default().event("complete").data("")),
+                    ],
+                    true,
+                )
+            }
+            Mode::SingleConnection => (vec![], false),
+        },
+    }
+}
+
+fn next_event<T: Serialize>(
+    mode: Mode,
+    operation_id: &OperationId,
+    payload: &T,
+) -> Result<Event, axum::Error> {
+    let event = Event::default().event("next");
+    match mode {
+        Mode::DistinctConnections => event.json_data(payload),
+        Mode::SingleConnection => event.json_data(SingleConnectionNext {
+            id: operation_id,
+            payload,
+        }),
+    }
+}
+
+fn complete_event(mode: Mode, operation_id: &OperationId) -> Result<Event, axum::Error> {
+    let event = Event::default().event("complete");
+    match mode {
+        Mode::DistinctConnections => Ok(event.data("")),
+        Mode::SingleConnection => event.json_data(SingleConnectionComplete { id: operation_id }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    #[test]
+    fn test_accepts_event_stream() {
+        let accepts = |accept_headers: &[&'static str]| {
+            let mut headers = HeaderMap::new();
+            for accept_header in accept_headers {
+                headers.append(header::ACCEPT, HeaderValue::from_static(accept_header));
+            }
+            accepts_event_stream(&headers)
+        };
+        assert!(!accepts(&[]));
+        assert!(!accepts(&["application/json"]));
+        assert!(accepts(&["text/event-stream"]));
+        assert!(accepts(&["text/event-stream", "application/json;q=0.5"]));
+        assert!(accepts(&["application/json;q=0.5, text/*"]));
+        // JSON is preferred, or as good as an event stream
+        assert!(!accepts(&["application/json, text/event-stream;q=0.9"]));
+        assert!(!accepts(&["text/event-stream, application/json"]));
+        assert!(!accepts(&["text/event-stream, */*"]));
+        assert!(!accepts(&[
+            "text/event-stream;q=0.8",
+            "application/graphql-response+json"
+        ]));
+        assert!(!accepts(&["text/event-stream;q=0"]));
+    }
+
+    #[test]
+    fn test_get_token() {
+        let params = EventStreamParams {
+            token: Some("from-params".to_string()),
+            operation_id: None,
+        };
+        assert_eq!(
+            get_token(&HeaderMap::new(), &params).map(|token| token.to_string()),
+            Some("from-params".to_string())
+        );
+        let mut headers = HeaderMap::new();
+        headers.append(
+            EVENT_STREAM_TOKEN_HEADER,
+            HeaderValue::from_static("from-header"),
+        );
+        assert_eq!(
+            get_token(&headers, &params).map(|token| token.to_string()),
+            Some("from-header".to_string())
+        );
+        assert!(get_token(&HeaderMap::new(), &EventStreamParams::default()).is_none());
+    }
+}
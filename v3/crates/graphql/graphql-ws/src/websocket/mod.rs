@@ -12,6 +12,7 @@ use axum::{
 use futures_util::StreamExt;
 
 use crate::metrics::WebSocketMetrics;
+use crate::multiplexer::Multiplexer;
 use crate::protocol;
 
 pub static SEC_WEBSOCKET_PROTOCOL: &str = "Sec-WebSocket-Protocol";
@@ -21,6 +22,8 @@ static WEBSOCKET_CHANNEL_SIZE: usize = 50;
 /// GraphQL WebSocket server implementation.
 pub struct WebSocketServer<M> {
     pub connections: types::Connections<M>,
+    /// Subscriptions that differ only in variables are polled together, across connections.
+    pub multiplexer: Multiplexer,
 }
 
 impl<M> WebSocketServer<M> {
@@ -28,6 +31,7 @@ impl<M> WebSocketServer<M> {
     pub fn new() -> Self {
         Self {
             connections: types::Connections::new(), // Initialize an empty map of active connections
+            multiplexer: Multiplexer::new(), // Initialize an empty registry of subscription cohorts
         }
     }
 
@@ -76,25 +80,29 @@ impl<M> WebSocketServer<M> {
                     websocket_id.to_string(),
                 )];
                 tracing_util::run_with_baggage(trace_baggage, || {
-                    // Check if headers contain graphql-transport-ws protocol
-                    check_protocol_in_headers(headers)?;
+                    // Negotiate the GraphQL WebSocket protocol from the headers
+                    let protocol = check_protocol_in_headers(headers)?;
                     let connections = self.connections.clone();
+                    let multiplexer = self.multiplexer.clone();
                     // Upgrade the WebSocket connection and handle it
                     let span_link = tracing_util::SpanLink::from_current_span();
                     // // Clone the websocket_id to move it into the closure
                     let websocket_id_cloned = websocket_id.clone();
-                    let mut response = ws_upgrade
-                        .protocols(vec![protocol::GRAPHQL_WS_PROTOCOL])
-                        .on_upgrade(move |socket| {
-                            start_websocket_session(
-                                client_address,
-                                socket,
-                                websocket_id_cloned,
-                                context,
-                                connections,
-                                span_link,
-                            )
-                        });
+                    let mut response =
+                        ws_upgrade
+                            .protocols(vec![protocol.name()])
+                            .on_upgrade(move |socket| {
+                                start_websocket_session(
+                                    client_address,
+                                    socket,
+                                    websocket_id_cloned,
+                                    protocol,
+                                    context,
+                                    connections,
+                                    multiplexer,
+                                    span_link,
+                                )
+                            });
                     // Set the WebSocket id response header
                     response
                         .headers_mut()
@@ -119,8 +127,12 @@ pub enum WebSocketError {
     #[error("{SEC_WEBSOCKET_PROTOCOL} header: {0}")]
     InvalidHeaderValue(#[from] ToStrError),
 
-    /// Error when the GraphQL WebSocket protocol is not included
-    #[error("Expecting {} protocol", protocol::GRAPHQL_WS_PROTOCOL)]
+    /// Error when none of the GraphQL WebSocket protocols is included
+    #[error(
+        "Expecting {} or {} protocol",
+        protocol::GRAPHQL_WS_PROTOCOL,
+        protocol::SUBSCRIPTIONS_TRANSPORT_WS_PROTOCOL
+    )]
     ExpectingGraphqlWsProtocol,
 
     /// Error when setting the WebSocket ID header value fails in response
@@ -160,8 +172,10 @@ async fn start_websocket_session<M: WebSocketMetrics>(
     client_address: std::net::SocketAddr,
     socket: ws::WebSocket,
     websocket_id: types::WebSocketId,
+    protocol: protocol::types::Protocol,
     context: types::Context<M>,
     connections: types::Connections<M>,
+    multiplexer: Multiplexer,
     parent_span_link: tracing_util::SpanLink,
 ) {
     let tracer = tracing_util::global_tracer();
@@ -183,7 +197,13 @@ async fn start_websocket_session<M: WebSocketMetrics>(
 
                     // Create a new WebSocket connection instance
                     let connection = connections
-                        .new_connection(websocket_id, context, channel_sender)
+                        .new_connection(
+                            websocket_id,
+                            protocol,
+                            context,
+                            channel_sender,
+                            multiplexer,
+                        )
                         .await;
 
                     let this_span_link = tracing_util::SpanLink::from_current_span();
@@ -276,12 +296,16 @@ async fn start_websocket_session<M: WebSocketMetrics>(
         .into_inner();
 }
 
-/// Validates that the required WebSocket protocol is present in the connection headers.
+/// Validates that a supported WebSocket protocol is present in the connection headers, and
+/// returns the protocol to use for the connection.
 ///
 /// This function checks that:
 /// 1. The Sec-WebSocket-Protocol header exists
-/// 2. The header contains the GraphQL WebSocket ("graphql-transport-ws") protocol
-pub(crate) fn check_protocol_in_headers(headers: &HeaderMap) -> Result<(), WebSocketError> {
+/// 2. The header contains the GraphQL WebSocket ("graphql-transport-ws") protocol, or the
+///    legacy ("graphql-ws") one. The former is preferred if the client offers both.
+pub(crate) fn check_protocol_in_headers(
+    headers: &HeaderMap,
+) -> Result<protocol::types::Protocol, WebSocketError> {
     let protocol_header_values = headers.get_all(SEC_WEBSOCKET_PROTOCOL).iter();
     let mut provided_protocols = Vec::new();
     for protocol in protocol_header_values {
@@ -289,15 +313,18 @@ pub(crate) fn check_protocol_in_headers(headers: &HeaderMap) -> Result<(), WebSo
         provided_protocols.extend_from_slice(&parse_comma_separated_header_values(protocol_str));
     }
     if provided_protocols.is_empty() {
-        Err(WebSocketError::MissingProtocolHeader)?;
-    } else if !provided_protocols.contains(&protocol::GRAPHQL_WS_PROTOCOL) {
-        Err(WebSocketError::ExpectingGraphqlWsProtocol)?;
+        Err(WebSocketError::MissingProtocolHeader)
+    } else if provided_protocols.contains(&protocol::GRAPHQL_WS_PROTOCOL) {
+        Ok(protocol::types::Protocol::GraphqlTransportWs)
+    } else if provided_protocols.contains(&protocol::SUBSCRIPTIONS_TRANSPORT_WS_PROTOCOL) {
+        Ok(protocol::types::Protocol::SubscriptionsTransportWs)
+    } else {
+        Err(WebSocketError::ExpectingGraphqlWsProtocol)
     }
-    Ok(())
 }
 
 /// Parses a comma-separated header value into a vector of trimmed strings.
-fn parse_comma_separated_header_values(header: &str) -> Vec<&str> {
+pub(crate) fn parse_comma_separated_header_values(header: &str) -> Vec<&str> {
     header
         .split(',')
         .map(str::trim)
@@ -339,7 +366,38 @@ mod tests {
             HeaderValue::from_static(protocol::GRAPHQL_WS_PROTOCOL),
         );
         let result = check_protocol_in_headers(&headers);
-        assert!(result.is_ok());
+        assert!(matches!(
+            result,
+            Ok(protocol::types::Protocol::GraphqlTransportWs)
+        ));
+    }
+
+    #[test]
+    fn test_check_protocol_legacy() {
+        let mut headers = HeaderMap::new();
+        headers.append(
+            SEC_WEBSOCKET_PROTOCOL,
+            HeaderValue::from_static(protocol::SUBSCRIPTIONS_TRANSPORT_WS_PROTOCOL),
+        );
+        let result = check_protocol_in_headers(&headers);
+        assert!(matches!(
+            result,
+            Ok(protocol::types::Protocol::SubscriptionsTransportWs)
+        ));
+    }
+
+    #[test]
+    fn test_check_protocol_prefers_graphql_transport_ws() {
+        let mut headers = HeaderMap::new();
+        headers.append(
+            SEC_WEBSOCKET_PROTOCOL,
+            HeaderValue::from_static("graphql-ws, graphql-transport-ws"),
+        );
+        let result = check_protocol_in_headers(&headers);
+        assert!(matches!(
+            result,
+            Ok(protocol::types::Protocol::GraphqlTransportWs)
+        ));
     }
 
     #[test]
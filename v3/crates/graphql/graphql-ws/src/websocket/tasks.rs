@@ -99,7 +99,8 @@ pub(crate) async fn process_incoming_message<M: WebSocketMetrics>(
                 || {
                     Box::pin(async {
                         // Parse message
-                        let break_loop = match parse_incoming_message(message) {
+                        let break_loop = match parse_incoming_message(connection.protocol, message)
+                        {
                             // Handle a close message from the client
                             Ok(ParsedClientMessage::Close) => {
                                 // Add this event
@@ -172,6 +173,7 @@ impl tracing_util::TraceableError for ParseError {
 }
 
 fn parse_incoming_message(
+    protocol: protocol::types::Protocol,
     message: Result<ws::Message, axum::Error>,
 ) -> Result<ParsedClientMessage, ParseError> {
     let tracer = tracing_util::global_tracer();
@@ -183,12 +185,11 @@ fn parse_incoming_message(
             let message = message?;
             match message {
                 ws::Message::Close(_) => Ok(ParsedClientMessage::Close),
-                message => {
-                    let client_message = serde_json::from_slice::<protocol::types::ClientMessage>(
-                        &message.into_data(),
-                    )?;
-                    Ok(ParsedClientMessage::Protocol(client_message))
-                }
+                message => match protocol.parse_client_message(&message.into_data())? {
+                    Some(client_message) => Ok(ParsedClientMessage::Protocol(client_message)),
+                    // The client terminated the connection through the protocol
+                    None => Ok(ParsedClientMessage::Close),
+                },
             }
         },
     )
@@ -239,10 +240,12 @@ pub(crate) async fn manage_outgoing_messages<M: WebSocketMetrics + Sync>(
                             }
                             // Handle protocol messages by serializing them into JSON
                             types::Message::Protocol(msg) => {
-                                match serde_json::to_string(&msg) {
-                                    Ok(json_text) => {
+                                match connection.protocol.serialize_server_message(&msg) {
+                                    Ok(Some(json_text)) => {
                                         websocket_sender.send(ws::Message::Text(json_text)).await?;
                                     }
+                                    // The protocol has no equivalent of the message
+                                    Ok(None) => {}
                                     Err(err) => {
                                         // Send internal server error message if serialization fails
                                         websocket_sender
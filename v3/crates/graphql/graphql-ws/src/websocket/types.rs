@@ -11,6 +11,7 @@ use std::sync::Arc;
 use tokio::sync::{mpsc::Sender, RwLock};
 
 use crate::metrics::WebSocketMetrics;
+use crate::multiplexer::Multiplexer;
 use crate::poller;
 use crate::protocol::types as protocol;
 
@@ -43,6 +44,11 @@ impl WebSocketId {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Parses a connection ID, eg. the token of an event stream.
+    pub(crate) fn parse(id: &str) -> Self {
+        Self(SmolStr::new(id))
+    }
 }
 
 impl Default for WebSocketId {
@@ -70,15 +76,17 @@ impl<M> Connections<M> {
     pub(crate) async fn new_connection(
         &self,
         id: WebSocketId,
+        protocol: protocol::Protocol,
         context: Context<M>,
         channel: Sender<Message>,
+        multiplexer: Multiplexer,
     ) -> Connection<M>
     where
         M: WebSocketMetrics,
     {
         // Record this new connection in metrics
         context.metrics.record_connection_init();
-        let new_connection = Connection::new(id, context, channel);
+        let new_connection = Connection::new(id, protocol, context, channel, multiplexer);
         let mut map = self.0.write().await;
         map.insert(new_connection.id.clone(), new_connection.clone());
         new_connection
@@ -114,6 +122,8 @@ impl<M> Default for Connections<M> {
 pub struct Connection<M> {
     // Unique WebSocket connection ID
     pub id: WebSocketId,
+    // The protocol negotiated for the connection, which messages are parsed and serialized with
+    pub protocol: protocol::Protocol,
     // Manages the WebSocket protocol state
     pub protocol_init_state: Arc<RwLock<protocol::ConnectionInitState>>,
     // Shared connection context
@@ -122,20 +132,30 @@ pub struct Connection<M> {
     pub send_channel: Sender<Message>,
     // Active pollers associated with operations. A web socket connection can have multiple active subscriptions.
     pub pollers: Arc<RwLock<HashMap<protocol::OperationId, poller::Poller>>>,
+    // Cohorts of subscriptions polled together, shared across all connections of the server
+    pub multiplexer: Multiplexer,
 }
 
 impl<M> Connection<M> {
     /// Creates a new WebSocket connection with the given context and message sender channel.
     /// To actually create a WebSocket connection, use the `Connections::new_connection` method.
-    pub fn new(id: WebSocketId, context: Context<M>, channel: Sender<Message>) -> Self {
+    pub fn new(
+        id: WebSocketId,
+        protocol: protocol::Protocol,
+        context: Context<M>,
+        channel: Sender<Message>,
+        multiplexer: Multiplexer,
+    ) -> Self {
         Self {
             id,
+            protocol,
             protocol_init_state: Arc::new(RwLock::new(
                 protocol::ConnectionInitState::NotInitialized,
             )), // Initial protocol state
             context,                                        // Shared connection context
             send_channel: channel, // Channel for sending messages over the WebSocket
             pollers: Arc::new(RwLock::new(HashMap::new())), // A map of active pollers
+            multiplexer,           // Cohorts of subscriptions shared across connections
         }
     }
 
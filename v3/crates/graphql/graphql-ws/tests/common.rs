@@ -1,9 +1,13 @@
-use axum::{extract::State, response::IntoResponse, routing::get};
+use axum::{
+    extract::State,
+    response::IntoResponse,
+    routing::{get, post},
+};
 use engine_types::{ExposeInternalErrors, HttpContext};
 use futures_util::{SinkExt, StreamExt};
 use graphql_ir::GraphqlRequestPipeline;
 use graphql_ws::Context;
-use graphql_ws::GRAPHQL_WS_PROTOCOL;
+use graphql_ws::{GRAPHQL_WS_PROTOCOL, SUBSCRIPTIONS_TRANSPORT_WS_PROTOCOL};
 use std::{net, path::PathBuf, sync::Arc};
 use tokio::{net::TcpStream, task::JoinHandle};
 use tokio_tungstenite::{
@@ -52,11 +56,68 @@ pub(crate) async fn start_websocket_server() -> TestServer {
 #[allow(dead_code)]
 pub(crate) async fn start_websocket_server_expiry(
     expiry: graphql_ws::ConnectionExpiry,
+) -> TestServer {
+    start_websocket_server_with(expiry, GRAPHQL_WS_PROTOCOL).await
+}
+
+#[allow(dead_code)]
+pub(crate) async fn start_legacy_websocket_server() -> TestServer {
+    start_websocket_server_with(
+        graphql_ws::ConnectionExpiry::Never,
+        SUBSCRIPTIONS_TRANSPORT_WS_PROTOCOL,
+    )
+    .await
+}
+
+#[allow(dead_code)]
+pub(crate) async fn start_websocket_server_with(
+    expiry: graphql_ws::ConnectionExpiry,
+    protocol: &str,
 ) -> TestServer {
     // Create a TCP listener
     let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
     let addr = listener.local_addr().unwrap();
 
+    let context = make_context(expiry);
+    let connections = graphql_ws::Connections::new();
+    let ws_server = graphql_ws::WebSocketServer {
+        connections: connections.clone(),
+        multiplexer: graphql_ws::Multiplexer::new(),
+    };
+    // Spawn a server
+    let state = ServerState { ws_server, context };
+    let server_handle = tokio::spawn(async move {
+        let app = axum::Router::new()
+            .route("/ws", get(ws_handler))
+            .with_state(Arc::new(state));
+
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<net::SocketAddr>(),
+        )
+        .await
+        .unwrap();
+    });
+
+    let url = format!("ws://{addr}/ws");
+    let mut request = url.into_client_request().unwrap();
+    request.headers_mut().insert(
+        graphql_ws::SEC_WEBSOCKET_PROTOCOL,
+        protocol.parse().unwrap(),
+    );
+    let (socket, _response) = connect_async(request)
+        .await
+        .expect("Failed to connect to WebSocket server");
+
+    TestServer {
+        connections,
+        socket,
+        server_handle,
+    }
+}
+
+#[allow(dead_code)]
+fn make_context(expiry: graphql_ws::ConnectionExpiry) -> Context<graphql_ws::NoOpWebSocketMetrics> {
     // Auth Config
     let auth_config_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(AUTH_CONFIG_PATH);
     let raw_auth_config = std::fs::read_to_string(auth_config_path).unwrap();
@@ -87,7 +148,7 @@ pub(crate) async fn start_websocket_server_expiry(
         pre_response_plugins: Vec::new(),
         pre_route_plugins: Vec::new(),
     };
-    let context = Context {
+    Context {
         connection_expiry: expiry,
         http_context,
         metadata: resolved_metadata.into(),
@@ -98,41 +159,6 @@ pub(crate) async fn start_websocket_server_expiry(
         auth_config: Arc::new(auth_config),
         plugin_configs: Arc::new(plugin_configs),
         metrics: graphql_ws::NoOpWebSocketMetrics,
-    };
-
-    let connections = graphql_ws::Connections::new();
-    let ws_server = graphql_ws::WebSocketServer {
-        connections: connections.clone(),
-    };
-    // Spawn a server
-    let state = ServerState { ws_server, context };
-    let server_handle = tokio::spawn(async move {
-        let app = axum::Router::new()
-            .route("/ws", get(ws_handler))
-            .with_state(Arc::new(state));
-
-        axum::serve(
-            listener,
-            app.into_make_service_with_connect_info::<net::SocketAddr>(),
-        )
-        .await
-        .unwrap();
-    });
-
-    let url = format!("ws://{addr}/ws");
-    let mut request = url.into_client_request().unwrap();
-    request.headers_mut().insert(
-        graphql_ws::SEC_WEBSOCKET_PROTOCOL,
-        GRAPHQL_WS_PROTOCOL.parse().unwrap(),
-    );
-    let (socket, _response) = connect_async(request)
-        .await
-        .expect("Failed to connect to WebSocket server");
-
-    TestServer {
-        connections,
-        socket,
-        server_handle,
     }
 }
 
@@ -250,6 +276,73 @@ pub(crate) fn subscribe_article_by_id(operation_id: &str) -> serde_json::Value {
     })
 }
 
+#[allow(dead_code)]
+pub(crate) fn subscribe_article_title_by_id(
+    operation_id: &str,
+    article_id: i64,
+) -> serde_json::Value {
+    let query = format!(
+        r"
+          subscription {{
+            ArticleByID(article_id: {article_id}) {{
+              article_id
+              title
+            }}
+          }}
+    "
+    );
+    serde_json::json!({
+        "type": "subscribe",
+        "id": operation_id,
+        "payload": {
+            "operationName": null,
+            "query": query
+        }
+    })
+}
+
+/// The body of an HTTP request selecting the title of an article, as a query or a subscription.
+#[allow(dead_code)]
+pub(crate) fn article_title_by_id_request(
+    operation_type: &str,
+    article_id: i64,
+) -> serde_json::Value {
+    let query = format!(
+        r"
+          {operation_type} {{
+            ArticleByID(article_id: {article_id}) {{
+              article_id
+              title
+            }}
+          }}
+    "
+    );
+    serde_json::json!({
+        "operationName": null,
+        "query": query
+    })
+}
+
+#[allow(dead_code)]
+pub(crate) fn subscribe_article_stream(operation_id: &str) -> serde_json::Value {
+    let query = r"
+          subscription {
+            ArticleStream(batch_size: 2, cursor: { article_id: 0 }) {
+              article_id
+              title
+            }
+          }
+    ";
+    serde_json::json!({
+        "type": "subscribe",
+        "id": operation_id,
+        "payload": {
+            "operationName": null,
+            "query": query
+        }
+    })
+}
+
 #[allow(dead_code)]
 pub(crate) async fn graphql_ws_connection_init(
     socket: &mut WebSocketStream<MaybeTlsStream<TcpStream>>,
@@ -281,3 +374,296 @@ pub(crate) async fn check_operation_id<M>(
     let (_, connection) = connections.iter().next().unwrap();
     assert!(connection.pollers.read().await.contains_key(&operation_id));
 }
+
+#[allow(dead_code)]
+pub(crate) struct EventStreamServerState<M> {
+    pub(crate) event_stream_server: graphql_ws::EventStreamServer<M>,
+    pub(crate) context: Context<M>,
+}
+
+#[allow(dead_code)]
+pub(crate) struct EventStreamTestServer {
+    pub(crate) url: String,
+    pub(crate) multiplexer: graphql_ws::Multiplexer,
+    pub(crate) server_handle: JoinHandle<()>,
+}
+
+#[allow(dead_code)]
+type EventStreamState = State<Arc<EventStreamServerState<graphql_ws::NoOpWebSocketMetrics>>>;
+
+/// Resolves the session of a request, as the engine's authentication middleware does.
+#[allow(dead_code)]
+async fn resolve_session(
+    headers: &axum::http::HeaderMap,
+    context: &Context<graphql_ws::NoOpWebSocketMetrics>,
+) -> hasura_authn_core::Session {
+    let identity =
+        hasura_authn::authenticate(headers, &context.http_context.client, &context.auth_config)
+            .await
+            .unwrap();
+    hasura_authn_core::authorize_identity(&identity, headers).unwrap()
+}
+
+#[allow(dead_code)]
+pub(crate) async fn graphql_handler(
+    headers: axum::http::HeaderMap,
+    State(state): EventStreamState,
+    axum::Json(request): axum::Json<lang_graphql::http::RawRequest>,
+) -> axum::response::Response {
+    let session = resolve_session(&headers, &state.context).await;
+    let client_address = "127.0.0.1:8080".parse().unwrap();
+    let context = state.context.clone();
+    if graphql_ws::accepts_event_stream(&headers) {
+        state
+            .event_stream_server
+            .handle_distinct_connection(client_address, session, headers, request, context)
+            .await
+    } else if graphql_ws::accepts_multipart_mixed(&headers)
+        && graphql_ws::has_deferred_fragments(&request)
+    {
+        state
+            .event_stream_server
+            .handle_multipart_request(client_address, session, headers, request, context)
+            .await
+    } else {
+        axum::http::StatusCode::NOT_ACCEPTABLE.into_response()
+    }
+}
+
+#[allow(dead_code)]
+pub(crate) async fn event_stream_request_handler(
+    headers: axum::http::HeaderMap,
+    State(state): EventStreamState,
+    axum::extract::Query(params): axum::extract::Query<graphql_ws::EventStreamParams>,
+    axum::Json(request): axum::Json<graphql_ws::EventStreamRequest>,
+) -> axum::response::Response {
+    let session = resolve_session(&headers, &state.context).await;
+    state
+        .event_stream_server
+        .handle_operation_request(
+            "127.0.0.1:8080".parse().unwrap(),
+            session,
+            headers,
+            &params,
+            request,
+            state.context.clone(),
+        )
+        .await
+}
+
+#[allow(dead_code)]
+pub(crate) async fn event_stream_reservation_handler(
+    headers: axum::http::HeaderMap,
+    State(state): EventStreamState,
+) -> axum::response::Response {
+    let session = resolve_session(&headers, &state.context).await;
+    state
+        .event_stream_server
+        .handle_reservation(session, headers, state.context.clone())
+        .await
+}
+
+#[allow(dead_code)]
+pub(crate) async fn event_stream_handler(
+    headers: axum::http::HeaderMap,
+    State(state): EventStreamState,
+    axum::extract::Query(params): axum::extract::Query<graphql_ws::EventStreamParams>,
+) -> axum::response::Response {
+    let session = resolve_session(&headers, &state.context).await;
+    state
+        .event_stream_server
+        .handle_event_stream(&session, &headers, &params)
+        .await
+        .into_response()
+}
+
+#[allow(dead_code)]
+pub(crate) async fn event_stream_operation_complete_handler(
+    headers: axum::http::HeaderMap,
+    State(state): EventStreamState,
+    axum::extract::Query(params): axum::extract::Query<graphql_ws::EventStreamParams>,
+) -> axum::response::Response {
+    let session = resolve_session(&headers, &state.context).await;
+    state
+        .event_stream_server
+        .handle_operation_complete(&session, &headers, &params)
+        .await
+        .into_response()
+}
+
+#[allow(dead_code)]
+pub(crate) async fn start_event_stream_server() -> EventStreamTestServer {
+    start_event_stream_server_with(std::time::Duration::from_secs(30)).await
+}
+
+#[allow(dead_code)]
+pub(crate) async fn start_event_stream_server_with(
+    reservation_timeout: std::time::Duration,
+) -> EventStreamTestServer {
+    // Create a TCP listener
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let context = make_context(graphql_ws::ConnectionExpiry::Never);
+    let multiplexer = graphql_ws::Multiplexer::new();
+    let ws_server = graphql_ws::WebSocketServer {
+        connections: graphql_ws::Connections::new(),
+        multiplexer: multiplexer.clone(),
+    };
+    let event_stream_server = graphql_ws::EventStreamServer::new(&ws_server)
+        .with_reservation_timeout(reservation_timeout);
+    // Spawn a server
+    let state = EventStreamServerState {
+        event_stream_server,
+        context,
+    };
+    let server_handle = tokio::spawn(async move {
+        let app = axum::Router::new()
+            .route("/graphql", post(graphql_handler))
+            .route(
+                "/graphql/stream",
+                post(event_stream_request_handler)
+                    .put(event_stream_reservation_handler)
+                    .get(event_stream_handler)
+                    .delete(event_stream_operation_complete_handler),
+            )
+            .with_state(Arc::new(state));
+
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<net::SocketAddr>(),
+        )
+        .await
+        .unwrap();
+    });
+
+    EventStreamTestServer {
+        url: format!("http://{addr}"),
+        multiplexer,
+        server_handle,
+    }
+}
+
+/// Reads the events of an event stream response.
+#[allow(dead_code)]
+pub(crate) struct EventStreamReader {
+    response: reqwest::Response,
+    buffer: String,
+}
+
+#[allow(dead_code)]
+impl EventStreamReader {
+    pub(crate) fn new(response: reqwest::Response) -> Self {
+        Self {
+            response,
+            buffer: String::new(),
+        }
+    }
+
+    /// Returns the next event as its name and data, skipping keep-alive comments, or `None`
+    /// when the stream ends.
+    pub(crate) async fn next_event(&mut self) -> Option<(String, String)> {
+        loop {
+            while let Some(end) = self.buffer.find("\n\n") {
+                let event = self.buffer[..end].to_string();
+                self.buffer.drain(..end + 2);
+                let mut name = String::new();
+                let mut data = String::new();
+                for line in event.lines() {
+                    if let Some(value) = line.strip_prefix("event:") {
+                        value.trim_start().clone_into(&mut name);
+                    } else if let Some(value) = line.strip_prefix("data:") {
+                        data.push_str(value.trim_start());
+                    }
+                }
+                if !name.is_empty() {
+                    return Some((name, data));
+                }
+            }
+            let chunk = self.response.chunk().await.unwrap()?;
+            self.buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+    }
+
+    /// Returns the next event, which must be a `next` event, parsed as JSON.
+    pub(crate) async fn expect_next_event(&mut self) -> serde_json::Value {
+        let (name, data) = self.next_event().await.expect("Expected an event");
+        assert_eq!(name, "next");
+        serde_json::from_str(&data).expect("Expected a valid JSON")
+    }
+
+    /// Returns the data of the next event, which must be a `complete` event.
+    pub(crate) async fn expect_complete_event(&mut self) -> String {
+        let (name, data) = self.next_event().await.expect("Expected an event");
+        assert_eq!(name, "complete");
+        data
+    }
+}
+
+#[allow(dead_code)]
+pub(crate) fn admin_headers() -> reqwest::header::HeaderMap {
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-hasura-role", "admin".parse().unwrap());
+    headers
+}
+
+#[allow(dead_code)]
+pub(crate) fn user_1_id_2_headers() -> reqwest::header::HeaderMap {
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-hasura-role", "user_1".parse().unwrap());
+    headers.insert("x-hasura-user-id", "2".parse().unwrap());
+    headers
+}
+
+#[allow(dead_code)]
+pub(crate) async fn assert_zero_subscribers_timeout(multiplexer: &graphql_ws::Multiplexer) {
+    // Unsubscribing from a cohort is not immediate. So, we keep checking zero subscribers
+    // for at most 5 seconds.
+    let result = tokio::time::timeout(tokio::time::Duration::from_secs(5), async {
+        loop {
+            if multiplexer.subscriber_count() == 0 {
+                break;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+        }
+    })
+    .await;
+    assert!(result.is_ok(), "Subscribers are not empty");
+}
+
+/// A query selecting the title of an article in a labelled deferred fragment, and the name of
+/// its author in an unlabelled one.
+#[allow(dead_code)]
+pub(crate) fn deferred_article_by_id_query(defer: bool) -> String {
+    format!(
+        r#"
+          query {{
+            ArticleByID(article_id: 1) {{
+              article_id
+              ... @defer(if: {defer}, label: "title") {{
+                title
+              }}
+              Author {{
+                author_id
+                ... @defer(if: {defer}) {{
+                  first_name
+                }}
+              }}
+            }}
+          }}
+    "#
+    )
+}
+
+/// Splits a `multipart/mixed` response body into its JSON parts.
+#[allow(dead_code)]
+pub(crate) fn multipart_json_parts(body: &str) -> Vec<serde_json::Value> {
+    body.split("\r\n---")
+        // The final delimiter is `-----`, leaving `--` after the split
+        .filter(|part| !part.is_empty() && !part.starts_with("--"))
+        .map(|part| {
+            let (_headers, json) = part.split_once("\r\n\r\n").expect("Expected part headers");
+            serde_json::from_str(json).expect("Expected a valid JSON")
+        })
+        .collect()
+}
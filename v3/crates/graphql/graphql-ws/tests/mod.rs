@@ -310,6 +310,354 @@ async fn test_graphql_ws_subscribe_user_1() {
     server_handle.abort();
 }
 
+#[tokio::test]
+async fn test_graphql_ws_subscribe_multiplexed() {
+    let TestServer {
+        connections,
+        mut socket,
+        server_handle,
+    } = start_websocket_server().await;
+    // Send connection_init and check ack
+    graphql_ws_connection_init(&mut socket, connection_init_admin()).await;
+
+    // Send two subscriptions which differ only in the article id
+    for (operation_id, article_id) in [("operation-1", 1), ("operation-2", 2)] {
+        let json_message =
+            serde_json::to_string(&subscribe_article_title_by_id(operation_id, article_id))
+                .unwrap();
+        socket
+            .send(tungstenite::Message::Text(json_message))
+            .await
+            .unwrap();
+    }
+
+    // Each subscription receives its own data, in any order
+    let mut messages = std::collections::BTreeMap::new();
+    for _ in 0..2 {
+        let message = expect_text_message(&mut socket).await;
+        let message_json: serde_json::Value =
+            serde_json::from_str(message.as_str()).expect("Expected a valid JSON");
+        messages.insert(
+            message_json["id"].as_str().unwrap().to_string(),
+            message_json,
+        );
+    }
+    assert_eq!(
+        messages["operation-1"],
+        serde_json::json!({
+            "type": "next",
+            "id": "operation-1",
+            "payload": {
+                "data": {
+                    "ArticleByID": {
+                        "article_id": 1,
+                        "title": "The Next 700 Programming Languages"
+                    }
+                }
+            }
+        })
+    );
+    assert_eq!(
+        messages["operation-2"],
+        serde_json::json!({
+            "type": "next",
+            "id": "operation-2",
+            "payload": {
+                "data": {
+                    "ArticleByID": {
+                        "article_id": 2,
+                        "title": "Why Functional Programming Matters"
+                    }
+                }
+            }
+        })
+    );
+
+    // Both subscriptions are polled together in a single cohort
+    {
+        let connections = connections.0.read().await;
+        let (_, connection) = connections.iter().next().unwrap();
+        assert_eq!(connection.multiplexer.cohort_count(), 1);
+        assert_eq!(connection.multiplexer.subscriber_count(), 2);
+    }
+
+    // Send close frame from client
+    socket
+        .send(tungstenite::Message::Close(None))
+        .await
+        .unwrap();
+    // Assert zero connections
+    assert_zero_connections_timeout(connections).await;
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_graphql_ws_subscribe_multiplexed_join_existing_cohort() {
+    let TestServer {
+        connections,
+        mut socket,
+        server_handle,
+    } = start_websocket_server().await;
+    // Send connection_init and check ack
+    graphql_ws_connection_init(&mut socket, connection_init_admin()).await;
+
+    // Start a cohort with a first subscription, and wait for its first result
+    let json_message =
+        serde_json::to_string(&subscribe_article_title_by_id("operation-1", 1)).unwrap();
+    socket
+        .send(tungstenite::Message::Text(json_message))
+        .await
+        .unwrap();
+    let message = expect_text_message(&mut socket).await;
+    let message_json: serde_json::Value =
+        serde_json::from_str(message.as_str()).expect("Expected a valid JSON");
+    assert_eq!(message_json["id"], "operation-1");
+
+    // A subscription joining the cohort gets its first result right away, rather than after
+    // the 10 seconds polling interval of the cohort
+    let json_message =
+        serde_json::to_string(&subscribe_article_title_by_id("operation-2", 2)).unwrap();
+    socket
+        .send(tungstenite::Message::Text(json_message))
+        .await
+        .unwrap();
+    let message = tokio::time::timeout(
+        tokio::time::Duration::from_secs(3),
+        expect_text_message(&mut socket),
+    )
+    .await
+    .expect("Expected the first result of the joining subscription before the next poll");
+    let message_json: serde_json::Value =
+        serde_json::from_str(message.as_str()).expect("Expected a valid JSON");
+    assert_eq!(
+        message_json,
+        serde_json::json!({
+            "type": "next",
+            "id": "operation-2",
+            "payload": {
+                "data": {
+                    "ArticleByID": {
+                        "article_id": 2,
+                        "title": "Why Functional Programming Matters"
+                    }
+                }
+            }
+        })
+    );
+
+    // Both subscriptions are polled together in a single cohort
+    {
+        let connections = connections.0.read().await;
+        let (_, connection) = connections.iter().next().unwrap();
+        assert_eq!(connection.multiplexer.cohort_count(), 1);
+        assert_eq!(connection.multiplexer.subscriber_count(), 2);
+    }
+
+    // Send close frame from client
+    socket
+        .send(tungstenite::Message::Close(None))
+        .await
+        .unwrap();
+    // Assert zero connections
+    assert_zero_connections_timeout(connections).await;
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_graphql_ws_subscribe_stream() {
+    let TestServer {
+        connections,
+        mut socket,
+        server_handle,
+    } = start_websocket_server().await;
+    // Send connection_init and check ack
+    graphql_ws_connection_init(&mut socket, connection_init_admin()).await;
+
+    let operation_id = "1";
+    let json_message = serde_json::to_string(&subscribe_article_stream(operation_id)).unwrap();
+    socket
+        .send(tungstenite::Message::Text(json_message))
+        .await
+        .unwrap();
+
+    // The first batch contains the rows right after the initial cursor value
+    let message = expect_text_message(&mut socket).await;
+    let message_json: serde_json::Value =
+        serde_json::from_str(message.as_str()).expect("Expected a valid JSON");
+    assert_eq!(
+        message_json,
+        serde_json::json!({
+            "type": "next",
+            "id": operation_id,
+            "payload": {
+                "data": {
+                    "ArticleStream": [
+                        {
+                            "article_id": 1,
+                            "title": "The Next 700 Programming Languages"
+                        },
+                        {
+                            "article_id": 2,
+                            "title": "Why Functional Programming Matters"
+                        }
+                    ]
+                }
+            }
+        })
+    );
+
+    // The batch was full, so the next batch is sent right away, starting after the cursor
+    let message = expect_text_message(&mut socket).await;
+    let message_json: serde_json::Value =
+        serde_json::from_str(message.as_str()).expect("Expected a valid JSON");
+    let next_batch = message_json["payload"]["data"]["ArticleStream"]
+        .as_array()
+        .expect("Expected a batch of articles");
+    assert!(!next_batch.is_empty());
+    assert!(next_batch
+        .iter()
+        .all(|article| article["article_id"].as_i64().unwrap() > 2));
+
+    // Send close frame from client
+    socket
+        .send(tungstenite::Message::Close(None))
+        .await
+        .unwrap();
+    // Assert zero connections
+    assert_zero_connections_timeout(connections).await;
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_legacy_graphql_ws_subscribe_admin() {
+    let TestServer {
+        connections,
+        mut socket,
+        server_handle,
+    } = start_legacy_websocket_server().await;
+    // Send connection_init and check ack
+    graphql_ws_connection_init(&mut socket, connection_init_admin()).await;
+
+    // Send a subscription with a `start` message
+    let operation_id = "some-operation-id";
+    let start_message = serde_json::json!({
+        "type": "start",
+        "id": operation_id,
+        "payload": subscribe_article_title_by_id(operation_id, 1)["payload"]
+    });
+    socket
+        .send(tungstenite::Message::Text(start_message.to_string()))
+        .await
+        .unwrap();
+
+    // The result is sent with a `data` message
+    let message = expect_text_message(&mut socket).await;
+    let message_json: serde_json::Value =
+        serde_json::from_str(message.as_str()).expect("Expected a valid JSON");
+    assert_eq!(
+        message_json,
+        serde_json::json!({
+            "type": "data",
+            "id": operation_id,
+            "payload": {
+                "data": {
+                    "ArticleByID": {
+                        "article_id": 1,
+                        "title": "The Next 700 Programming Languages"
+                    }
+                }
+            }
+        })
+    );
+    check_operation_id(operation_id, &connections).await;
+
+    // Stop the subscription with a `stop` message
+    let stop_message = serde_json::json!({"type": "stop", "id": operation_id});
+    socket
+        .send(tungstenite::Message::Text(stop_message.to_string()))
+        .await
+        .unwrap();
+    assert_zero_operations_timeout(&connections).await;
+
+    // Terminate the connection with a `connection_terminate` message
+    let terminate_message = serde_json::json!({"type": "connection_terminate"});
+    socket
+        .send(tungstenite::Message::Text(terminate_message.to_string()))
+        .await
+        .unwrap();
+    // Assert zero connections
+    assert_zero_connections_timeout(connections).await;
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_legacy_graphql_ws_connection_init_no_headers() {
+    let TestServer {
+        connections,
+        mut socket,
+        server_handle,
+    } = start_legacy_websocket_server().await;
+    // Send connection init without headers. Connection initialization fails.
+    let connection_init_no_headers = serde_json::json!({
+        "type": "connection_init",
+        "payload": {
+            "headers": {}
+        }
+    });
+    socket
+        .send(tungstenite::Message::Text(
+            connection_init_no_headers.to_string(),
+        ))
+        .await
+        .unwrap();
+
+    // The failure is reported with a `connection_error` message
+    let message = expect_text_message(&mut socket).await;
+    let message_json: serde_json::Value =
+        serde_json::from_str(message.as_str()).expect("Expected a valid JSON");
+    assert_eq!(
+        message_json,
+        serde_json::json!({
+            "type": "connection_error",
+            "payload": {
+                "message": "Forbidden"
+            }
+        })
+    );
+
+    // Followed by a close message
+    let message = expect_close_message(&mut socket).await;
+    let close_code = tungstenite::protocol::frame::coding::CloseCode::from(4403);
+    if let tungstenite::Message::Close(Some(close_frame)) = message {
+        assert_eq!(close_frame.code, close_code);
+        assert_eq!(close_frame.reason, "Forbidden");
+    }
+    // Assert zero connections
+    assert_zero_connections_timeout(connections).await;
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_legacy_graphql_ws_connection_terminate() {
+    let TestServer {
+        connections,
+        mut socket,
+        server_handle,
+    } = start_legacy_websocket_server().await;
+    // Send connection_init and check ack
+    graphql_ws_connection_init(&mut socket, connection_init_admin()).await;
+
+    // Terminate the connection with a `connection_terminate` message, without any operation
+    let terminate_message = serde_json::json!({"type": "connection_terminate"});
+    socket
+        .send(tungstenite::Message::Text(terminate_message.to_string()))
+        .await
+        .unwrap();
+    // Assert zero connections
+    assert_zero_connections_timeout(connections).await;
+    server_handle.abort();
+}
+
 #[tokio::test]
 async fn test_graphql_ws_subscribe_user_1_validation_error() {
     let TestServer {
@@ -430,3 +778,514 @@ async fn test_graphql_ws_keepalive() {
     assert_zero_connections_timeout(connections).await;
     server_handle.abort();
 }
+
+#[tokio::test]
+async fn test_event_stream_distinct_connection() {
+    let EventStreamTestServer {
+        url,
+        multiplexer,
+        server_handle,
+    } = start_event_stream_server().await;
+    let client = reqwest::Client::new();
+
+    // A query is sent as a `next` event, and its event stream ends after `complete`
+    let response = client
+        .post(format!("{url}/graphql"))
+        .headers(admin_headers())
+        .header("accept", "text/event-stream")
+        .json(&article_title_by_id_request("query", 1))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"].to_str().unwrap(),
+        "text/event-stream"
+    );
+    let mut events = EventStreamReader::new(response);
+    assert_eq!(
+        events.expect_next_event().await,
+        serde_json::json!({
+            "data": {
+                "ArticleByID": {
+                    "article_id": 1,
+                    "title": "The Next 700 Programming Languages"
+                }
+            }
+        })
+    );
+    assert_eq!(events.expect_complete_event().await, "");
+    assert!(events.next_event().await.is_none());
+
+    // A subscription is polled until its event stream is closed by the client
+    let response = client
+        .post(format!("{url}/graphql/stream"))
+        .headers(admin_headers())
+        .header("accept", "text/event-stream")
+        .json(&article_title_by_id_request("subscription", 2))
+        .send()
+        .await
+        .unwrap();
+    let mut events = EventStreamReader::new(response);
+    assert_eq!(
+        events.expect_next_event().await,
+        serde_json::json!({
+            "data": {
+                "ArticleByID": {
+                    "article_id": 2,
+                    "title": "Why Functional Programming Matters"
+                }
+            }
+        })
+    );
+    assert_eq!(multiplexer.subscriber_count(), 1);
+    drop(events);
+    assert_zero_subscribers_timeout(&multiplexer).await;
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_event_stream_single_connection() {
+    let EventStreamTestServer {
+        url,
+        multiplexer,
+        server_handle,
+    } = start_event_stream_server().await;
+    let client = reqwest::Client::new();
+
+    // Reserve an event stream
+    let response = client
+        .put(format!("{url}/graphql/stream"))
+        .headers(admin_headers())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::CREATED);
+    let token = response.text().await.unwrap();
+
+    // Open the reserved event stream
+    let response = client
+        .get(format!("{url}/graphql/stream"))
+        .headers(admin_headers())
+        .header("accept", "text/event-stream")
+        .header(graphql_ws::EVENT_STREAM_TOKEN_HEADER, &token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let mut events = EventStreamReader::new(response);
+
+    // A reserved event stream can only be opened once
+    let response = client
+        .get(format!("{url}/graphql/stream?token={token}"))
+        .headers(admin_headers())
+        .header("accept", "text/event-stream")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::CONFLICT);
+
+    // Start a subscription on the event stream
+    let mut request = article_title_by_id_request("subscription", 1);
+    request["extensions"] = serde_json::json!({"operationId": "operation-1"});
+    let response = client
+        .post(format!("{url}/graphql/stream"))
+        .headers(admin_headers())
+        .header(graphql_ws::EVENT_STREAM_TOKEN_HEADER, &token)
+        .json(&request)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::ACCEPTED);
+
+    // Its results are sent over the event stream, along with the operation id
+    assert_eq!(
+        events.expect_next_event().await,
+        serde_json::json!({
+            "id": "operation-1",
+            "payload": {
+                "data": {
+                    "ArticleByID": {
+                        "article_id": 1,
+                        "title": "The Next 700 Programming Languages"
+                    }
+                }
+            }
+        })
+    );
+    assert_eq!(multiplexer.subscriber_count(), 1);
+
+    // Stop the subscription
+    let response = client
+        .delete(format!("{url}/graphql/stream?operationId=operation-1"))
+        .headers(admin_headers())
+        .header(graphql_ws::EVENT_STREAM_TOKEN_HEADER, &token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    assert_zero_subscribers_timeout(&multiplexer).await;
+
+    // The event stream stays open for further operations
+    let mut request = article_title_by_id_request("query", 2);
+    request["extensions"] = serde_json::json!({"operationId": "operation-2"});
+    let response = client
+        .post(format!("{url}/graphql/stream"))
+        .headers(admin_headers())
+        .header(graphql_ws::EVENT_STREAM_TOKEN_HEADER, &token)
+        .json(&request)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::ACCEPTED);
+    assert_eq!(
+        events.expect_next_event().await,
+        serde_json::json!({
+            "id": "operation-2",
+            "payload": {
+                "data": {
+                    "ArticleByID": {
+                        "article_id": 2,
+                        "title": "Why Functional Programming Matters"
+                    }
+                }
+            }
+        })
+    );
+    let complete: serde_json::Value =
+        serde_json::from_str(&events.expect_complete_event().await).unwrap();
+    assert_eq!(complete, serde_json::json!({"id": "operation-2"}));
+
+    // Closing the event stream drops its reservation
+    drop(events);
+    let result = tokio::time::timeout(tokio::time::Duration::from_secs(5), async {
+        loop {
+            let response = client
+                .post(format!("{url}/graphql/stream"))
+                .headers(admin_headers())
+                .header(graphql_ws::EVENT_STREAM_TOKEN_HEADER, &token)
+                .json(&request)
+                .send()
+                .await
+                .unwrap();
+            if response.status() == reqwest::StatusCode::NOT_FOUND {
+                break;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(200)).await;
+        }
+    })
+    .await;
+    assert!(result.is_ok(), "Reservation was not dropped");
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_event_stream_single_connection_session_mismatch() {
+    let EventStreamTestServer {
+        url, server_handle, ..
+    } = start_event_stream_server().await;
+    let client = reqwest::Client::new();
+
+    // Reserve an event stream as admin
+    let response = client
+        .put(format!("{url}/graphql/stream"))
+        .headers(admin_headers())
+        .send()
+        .await
+        .unwrap();
+    let token = response.text().await.unwrap();
+
+    // The token can't be used with another session
+    let response = client
+        .get(format!("{url}/graphql/stream"))
+        .headers(user_1_id_2_headers())
+        .header("accept", "text/event-stream")
+        .header(graphql_ws::EVENT_STREAM_TOKEN_HEADER, &token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::FORBIDDEN);
+    assert_eq!(
+        response.text().await.unwrap(),
+        "Event stream was reserved by another session"
+    );
+
+    let mut request = article_title_by_id_request("subscription", 1);
+    request["extensions"] = serde_json::json!({"operationId": "operation-1"});
+    let response = client
+        .post(format!("{url}/graphql/stream"))
+        .headers(user_1_id_2_headers())
+        .header(graphql_ws::EVENT_STREAM_TOKEN_HEADER, &token)
+        .json(&request)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::FORBIDDEN);
+
+    let response = client
+        .delete(format!("{url}/graphql/stream?operationId=operation-1"))
+        .headers(user_1_id_2_headers())
+        .header(graphql_ws::EVENT_STREAM_TOKEN_HEADER, &token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::FORBIDDEN);
+
+    // Unknown tokens are not found
+    let response = client
+        .get(format!("{url}/graphql/stream"))
+        .headers(admin_headers())
+        .header("accept", "text/event-stream")
+        .header(
+            graphql_ws::EVENT_STREAM_TOKEN_HEADER,
+            "00000000-0000-0000-0000-000000000000",
+        )
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_event_stream_reservation_timeout() {
+    let EventStreamTestServer {
+        url, server_handle, ..
+    } = start_event_stream_server_with(std::time::Duration::from_millis(100)).await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .put(format!("{url}/graphql/stream"))
+        .headers(admin_headers())
+        .send()
+        .await
+        .unwrap();
+    let token = response.text().await.unwrap();
+
+    // The reservation is dropped as its event stream is not opened in time
+    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+    let response = client
+        .get(format!("{url}/graphql/stream"))
+        .headers(admin_headers())
+        .header("accept", "text/event-stream")
+        .header(graphql_ws::EVENT_STREAM_TOKEN_HEADER, &token)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_graphql_ws_query_defer() {
+    let TestServer {
+        connections,
+        mut socket,
+        server_handle,
+    } = start_websocket_server().await;
+    graphql_ws_connection_init(&mut socket, connection_init_admin()).await;
+
+    let operation_id = "some-operation-id";
+    let subscribe_message = serde_json::json!({
+        "type": "subscribe",
+        "id": operation_id,
+        "payload": {
+            "query": deferred_article_by_id_query(true)
+        }
+    });
+    socket
+        .send(tungstenite::Message::Text(subscribe_message.to_string()))
+        .await
+        .unwrap();
+
+    // The initial result leaves out the deferred fields
+    let message = expect_text_message(&mut socket).await;
+    let message_json: serde_json::Value =
+        serde_json::from_str(message.as_str()).expect("Expected a valid JSON");
+    assert_eq!(
+        message_json,
+        serde_json::json!({
+            "type": "next",
+            "id": operation_id,
+            "payload": {
+                "data": {
+                    "ArticleByID": {
+                        "article_id": 1,
+                        "Author": {
+                            "author_id": 1
+                        }
+                    }
+                },
+                "hasNext": true
+            }
+        })
+    );
+
+    // The deferred fragments are sent in a subsequent `next` message, at their paths
+    let message = expect_text_message(&mut socket).await;
+    let message_json: serde_json::Value =
+        serde_json::from_str(message.as_str()).expect("Expected a valid JSON");
+    assert_eq!(
+        message_json,
+        serde_json::json!({
+            "type": "next",
+            "id": operation_id,
+            "payload": {
+                "incremental": [
+                    {
+                        "data": {
+                            "first_name": "Peter"
+                        },
+                        "path": ["ArticleByID", "Author"]
+                    },
+                    {
+                        "data": {
+                            "title": "The Next 700 Programming Languages"
+                        },
+                        "path": ["ArticleByID"],
+                        "label": "title"
+                    }
+                ],
+                "hasNext": false
+            }
+        })
+    );
+
+    let message = expect_text_message(&mut socket).await;
+    let message_json: serde_json::Value =
+        serde_json::from_str(message.as_str()).expect("Expected a valid JSON");
+    assert_eq!(
+        message_json,
+        serde_json::json!({"type": "complete", "id": operation_id})
+    );
+
+    socket.close(None).await.unwrap();
+    assert_zero_connections_timeout(connections).await;
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_graphql_ws_query_defer_if_false() {
+    let TestServer {
+        connections,
+        mut socket,
+        server_handle,
+    } = start_websocket_server().await;
+    graphql_ws_connection_init(&mut socket, connection_init_admin()).await;
+
+    let operation_id = "some-operation-id";
+    let subscribe_message = serde_json::json!({
+        "type": "subscribe",
+        "id": operation_id,
+        "payload": {
+            "query": deferred_article_by_id_query(false)
+        }
+    });
+    socket
+        .send(tungstenite::Message::Text(subscribe_message.to_string()))
+        .await
+        .unwrap();
+
+    // Nothing is deferred, so the result is sent at once
+    let message = expect_text_message(&mut socket).await;
+    let message_json: serde_json::Value =
+        serde_json::from_str(message.as_str()).expect("Expected a valid JSON");
+    assert_eq!(
+        message_json,
+        serde_json::json!({
+            "type": "next",
+            "id": operation_id,
+            "payload": {
+                "data": {
+                    "ArticleByID": {
+                        "article_id": 1,
+                        "title": "The Next 700 Programming Languages",
+                        "Author": {
+                            "author_id": 1,
+                            "first_name": "Peter"
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    let message = expect_text_message(&mut socket).await;
+    let message_json: serde_json::Value =
+        serde_json::from_str(message.as_str()).expect("Expected a valid JSON");
+    assert_eq!(
+        message_json,
+        serde_json::json!({"type": "complete", "id": operation_id})
+    );
+
+    socket.close(None).await.unwrap();
+    assert_zero_connections_timeout(connections).await;
+    server_handle.abort();
+}
+
+#[tokio::test]
+async fn test_multipart_mixed_defer() {
+    let EventStreamTestServer {
+        url, server_handle, ..
+    } = start_event_stream_server().await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{url}/graphql"))
+        .headers(admin_headers())
+        .header(
+            "accept",
+            "multipart/mixed;deferSpec=20220824, application/json",
+        )
+        .json(&serde_json::json!({"query": deferred_article_by_id_query(true)}))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"].to_str().unwrap(),
+        "multipart/mixed; boundary=\"-\"; deferSpec=20220824"
+    );
+
+    // The initial payload is followed by a part with the deferred fragments
+    let body = response.text().await.unwrap();
+    assert!(
+        body.ends_with("\r\n-----\r\n"),
+        "Expected a final delimiter"
+    );
+    assert_eq!(
+        multipart_json_parts(&body),
+        vec![
+            serde_json::json!({
+                "data": {
+                    "ArticleByID": {
+                        "article_id": 1,
+                        "Author": {
+                            "author_id": 1
+                        }
+                    }
+                },
+                "hasNext": true
+            }),
+            serde_json::json!({
+                "incremental": [
+                    {
+                        "data": {
+                            "first_name": "Peter"
+                        },
+                        "path": ["ArticleByID", "Author"]
+                    },
+                    {
+                        "data": {
+                            "title": "The Next 700 Programming Languages"
+                        },
+                        "path": ["ArticleByID"],
+                        "label": "title"
+                    }
+                ],
+                "hasNext": false
+            })
+        ]
+    );
+    server_handle.abort();
+}
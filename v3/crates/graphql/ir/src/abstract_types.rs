@@ -0,0 +1,12 @@
+use lang_graphql::ast::common::TypeName;
+
+const ABSTRACT_MEMBER_NDC_PREFIX: &str = "hasura_member_col";
+
+/// The alias of the discriminator field fetched to pick the member object type
+/// of a union or interface typed value
+pub const DISCRIMINATOR_COL: &str = "hasura_discriminator_col";
+
+/// The alias of a field selected on a member object type of a union or interface typed value
+pub fn abstract_member_col_format(member_type_name: &TypeName, alias: &str) -> String {
+    format!("{ABSTRACT_MEMBER_NDC_PREFIX}_{member_type_name}_{alias}")
+}
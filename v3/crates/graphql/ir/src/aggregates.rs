@@ -40,7 +40,7 @@ pub fn generate_aggregate_selection_set_ir<'s>(
     })
 }
 
-fn add_aggregate_selections<'s>(
+pub(crate) fn add_aggregate_selections<'s>(
     aggregate_field_selections: &mut IndexMap<NdcFieldAlias, AggregateFieldSelection>,
     selection_set: &normalized_ast::SelectionSet<'s, GDS>,
     aggregate_operand_type: &QualifiedTypeName,
@@ -61,63 +61,16 @@ fn add_aggregate_selections<'s>(
         match field_call.info.generic {
             Annotation::Output(OutputAnnotation::Aggregate(
                 AggregateOutputAnnotation::AggregationFunctionField(aggregate_function),
-            )) => match aggregate_function {
-                AggregationFunctionAnnotation::Count => {
-                    let selection_field_name =
-                        mk_alias_from_graphql_field_path(&graphql_field_path);
-                    let selection = AggregateFieldSelection::Count {
-                        column_path: column_path.iter().map(|m| m.column.clone()).collect(),
-                    };
-                    aggregate_field_selections.insert(selection_field_name, selection);
-                }
-
-                AggregationFunctionAnnotation::CountDistinct => {
-                    let selection_field_name =
-                        mk_alias_from_graphql_field_path(&graphql_field_path);
-                    let selection = AggregateFieldSelection::CountDistinct {
-                        column_path: column_path.iter().map(|m| m.column.clone()).collect(),
-                    };
-                    aggregate_field_selections.insert(selection_field_name, selection);
-                }
-
-                AggregationFunctionAnnotation::Function {
-                    function_name,
-                    aggregate_expression: _,
-                    data_connector_functions,
-                } => {
-                    let selection_field_name =
-                        mk_alias_from_graphql_field_path(&graphql_field_path);
-
-                    let column_path = nonempty::NonEmpty::from_slice(column_path)
-                        .ok_or_else(|| error::InternalDeveloperError::ColumnAggregationFunctionUsedOnModelObjectType {
-                            aggregate_operand_type: aggregate_operand_type.clone(),
-                            aggregation_function: function_name.clone(),
-                        })?;
-
-                    let column_scalar_type =
-                        get_ndc_underlying_type_name(&column_path.last().column_type);
-
-                    let data_connector_function_info = data_connector_functions
-                        .iter()
-                        .find(|fn_info| {
-                            fn_info.data_connector_name == *data_connector_name &&
-                            fn_info.operand_scalar_type.as_str() == column_scalar_type.as_str()
-                        })
-                        .ok_or_else(|| {
-                            error::InternalDeveloperError::DataConnectorAggregationFunctionNotFound {
-                                aggregate_operand_type: aggregate_operand_type.clone(),
-                                aggregation_function: function_name.clone(),
-                                data_connector_name: data_connector_name.clone(),
-                            }
-                        })?;
-
-                    let selection = AggregateFieldSelection::AggregationFunction {
-                        function_name: data_connector_function_info.function_name.clone(),
-                        column_path: column_path.map(|m| m.column.clone()),
-                    };
-                    aggregate_field_selections.insert(selection_field_name, selection);
-                }
-            },
+            )) => {
+                let selection_field_name = mk_alias_from_graphql_field_path(&graphql_field_path);
+                let selection = make_aggregation_function_selection(
+                    aggregate_function,
+                    aggregate_operand_type,
+                    data_connector_name,
+                    column_path,
+                )?;
+                aggregate_field_selections.insert(selection_field_name, selection);
+            }
 
             Annotation::Output(OutputAnnotation::Aggregate(
                 AggregateOutputAnnotation::AggregatableField {
@@ -178,6 +131,61 @@ fn add_aggregate_selections<'s>(
     Ok(())
 }
 
+/// Resolves an aggregation function to the NDC aggregate to perform over the column at the
+/// end of the column path (or over the rows themselves, if the column path is empty)
+pub(crate) fn make_aggregation_function_selection(
+    aggregate_function: &AggregationFunctionAnnotation,
+    aggregate_operand_type: &QualifiedTypeName,
+    data_connector_name: &Qualified<DataConnectorName>,
+    column_path: &[&metadata_resolve::FieldMapping],
+) -> Result<AggregateFieldSelection, error::Error> {
+    match aggregate_function {
+        AggregationFunctionAnnotation::Count => Ok(AggregateFieldSelection::Count {
+            column_path: column_path.iter().map(|m| m.column.clone()).collect(),
+        }),
+
+        AggregationFunctionAnnotation::CountDistinct => {
+            Ok(AggregateFieldSelection::CountDistinct {
+                column_path: column_path.iter().map(|m| m.column.clone()).collect(),
+            })
+        }
+
+        AggregationFunctionAnnotation::Function {
+            function_name,
+            aggregate_expression: _,
+            data_connector_functions,
+        } => {
+            let column_path = nonempty::NonEmpty::from_slice(column_path).ok_or_else(|| {
+                error::InternalDeveloperError::ColumnAggregationFunctionUsedOnModelObjectType {
+                    aggregate_operand_type: aggregate_operand_type.clone(),
+                    aggregation_function: function_name.clone(),
+                }
+            })?;
+
+            let column_scalar_type = get_ndc_underlying_type_name(&column_path.last().column_type);
+
+            let data_connector_function_info = data_connector_functions
+                .iter()
+                .find(|fn_info| {
+                    fn_info.data_connector_name == *data_connector_name
+                        && fn_info.operand_scalar_type.as_str() == column_scalar_type.as_str()
+                })
+                .ok_or_else(|| {
+                    error::InternalDeveloperError::DataConnectorAggregationFunctionNotFound {
+                        aggregate_operand_type: aggregate_operand_type.clone(),
+                        aggregation_function: function_name.clone(),
+                        data_connector_name: data_connector_name.clone(),
+                    }
+                })?;
+
+            Ok(AggregateFieldSelection::AggregationFunction {
+                function_name: data_connector_function_info.function_name.clone(),
+                column_path: column_path.map(|m| m.column.clone()),
+            })
+        }
+    }
+}
+
 pub fn mk_alias_from_graphql_field_path(graphql_field_path: &[&Alias]) -> NdcFieldAlias {
     NdcFieldAlias::from(
         graphql_field_path
@@ -18,6 +18,9 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use super::arguments;
+use super::filter::{FilterExpression, QueryFilter};
+use super::model_selection::ModelSelection;
+use super::relationship::{RemoteModelRelationshipInfo, SourceField};
 use super::selection_set;
 use super::selection_set::FieldSelection;
 use super::selection_set::NestedSelection;
@@ -27,9 +30,15 @@ use graphql_schema::TypeKind;
 use graphql_schema::GDS;
 use metadata_resolve::{Qualified, QualifiedTypeReference};
 use plan::UnresolvedArgument;
-use plan::{count_command, process_argument_presets_for_command};
-use plan_types::NdcFieldAlias;
+use plan::{
+    check_command_argument_predicate, count_command, process_argument_presets_for_command,
+    process_model_predicate,
+};
 use plan_types::UsagesCounts;
+use plan_types::{
+    make_model_field_variable_name, ComparisonTarget, ComparisonValue, Expression,
+    LocalFieldComparison, NdcFieldAlias, COMMAND_OUTPUT_FILTER_ALIAS,
+};
 
 #[derive(Serialize, Debug)]
 pub enum CommandSelection<'s> {
@@ -66,6 +75,14 @@ pub struct CommandInfo<'s> {
 
     // All the models/commands used in the 'command' operation.
     pub usage_counts: UsagesCounts,
+
+    /// The output filter of the command permission for the role, checked on each
+    /// object returned by the command.
+    pub output_filter: Option<&'s metadata_resolve::CommandOutputFilter>,
+
+    /// How the member object type of each returned value is decided, when the
+    /// output type of the command is a union or interface type.
+    pub type_discrimination: Option<metadata_resolve::TypeDiscrimination>,
 }
 
 /// IR for the 'function based command' operations
@@ -101,6 +118,7 @@ pub fn generate_command_info<'n, 's>(
     result_base_type_kind: TypeKind,
     command: &'s metadata_resolve::CommandWithPermissions,
     command_source: &'s CommandSource,
+    fixed_arguments: BTreeMap<DataConnectorArgumentName, UnresolvedArgument<'s>>,
     models: &'s IndexMap<
         metadata_resolve::Qualified<open_dds::models::ModelName>,
         metadata_resolve::ModelWithPermissions,
@@ -117,7 +135,9 @@ pub fn generate_command_info<'n, 's>(
     request_headers: &reqwest::header::HeaderMap,
     usage_counts: &mut UsagesCounts,
 ) -> Result<CommandInfo<'s>, error::Error> {
-    let mut command_arguments = BTreeMap::new();
+    // arguments fixed by the caller, such as those of a command relationship
+    // that are mapped from values, are not exposed as field arguments
+    let mut command_arguments = fixed_arguments;
 
     for argument in field_call.arguments.values() {
         let (ndc_arg_name, ndc_val) = arguments::build_ndc_argument_as_value(
@@ -143,11 +163,24 @@ pub fn generate_command_info<'n, 's>(
         usage_counts,
     )?;
 
+    if !check_command_argument_predicate(&command_arguments, command, session)? {
+        return Err(error::Error::CommandArgumentPredicateNotSatisfied {
+            role: session.role.clone(),
+            command_name: command_name.clone(),
+        });
+    }
+
+    let output_filter = command
+        .permissions
+        .get(&session.role)
+        .and_then(|permission| permission.output_filter.as_ref());
+
     // Add the name of the root command
     let mut usage_counts = UsagesCounts::new();
     count_command(command_name, &mut usage_counts);
 
-    let selection = selection_set::generate_nested_selection(
+    let type_discrimination = get_type_discrimination(&result_base_type_kind);
+    let mut selection = selection_set::generate_nested_selection(
         result_type,
         result_base_type_kind,
         metadata_resolve::FieldNestedness::NotNested,
@@ -163,6 +196,17 @@ pub fn generate_command_info<'n, 's>(
         &mut usage_counts,
     )?;
 
+    if let (Some(output_filter), Some(selection)) = (output_filter, selection.as_mut()) {
+        build_output_filter_fields(
+            output_filter,
+            result_type,
+            &command_source.type_mappings,
+            selection,
+            session,
+            &mut usage_counts,
+        )?;
+    }
+
     // always generate classic IR for now
     let selection = CommandSelection::Ir {
         selection: wrap_selection_in_response_config(command_source, selection),
@@ -176,9 +220,148 @@ pub fn generate_command_info<'n, 's>(
         selection,
         type_container: field.type_container.clone(),
         usage_counts,
+        output_filter,
+        type_discrimination,
     })
 }
 
+fn get_type_discrimination(
+    result_base_type_kind: &TypeKind,
+) -> Option<metadata_resolve::TypeDiscrimination> {
+    match result_base_type_kind {
+        TypeKind::Abstract(type_discrimination) => Some(type_discrimination.clone()),
+        TypeKind::Scalar | TypeKind::Object => None,
+    }
+}
+
+/// Join the objects returned by a command with the objects selected from the model of
+/// their type by their unique identifier, with the output filter of the command permission
+/// applied, so that the objects that do not satisfy the filter are found while processing
+/// the response.
+fn build_output_filter_fields<'s>(
+    output_filter: &'s metadata_resolve::CommandOutputFilter,
+    result_type: &QualifiedTypeReference,
+    type_mappings: &BTreeMap<
+        Qualified<open_dds::types::CustomTypeName>,
+        metadata_resolve::TypeMapping,
+    >,
+    selection: &mut NestedSelection<'s>,
+    session: &Session,
+    usage_counts: &mut UsagesCounts,
+) -> Result<(), error::Error> {
+    match selection {
+        NestedSelection::Array(element_selection) => build_output_filter_fields(
+            output_filter,
+            result_type,
+            type_mappings,
+            element_selection,
+            session,
+            usage_counts,
+        ),
+        NestedSelection::Object(result_selection_set) => {
+            let output_type_name = metadata_resolve::unwrap_custom_type_name(result_type)
+                .ok_or_else(|| error::InternalEngineError::InternalGeneric {
+                    description:
+                        "output filter defined for a command that does not return an object type"
+                            .to_string(),
+                })?;
+            let Some(metadata_resolve::TypeMapping::Object { field_mappings, .. }) =
+                type_mappings.get(output_type_name)
+            else {
+                return Err(error::Error::InternalTypeMappingNotFound {
+                    type_name: output_type_name.clone(),
+                });
+            };
+
+            let mut join_mapping = Vec::new();
+            let mut relationship_join_filter_expressions = Vec::new();
+            let mut key_fields = IndexMap::new();
+            for (field_name, target_column) in &output_filter.unique_identifier {
+                let field_mapping = field_mappings.get(field_name).ok_or_else(|| {
+                    error::InternalEngineError::InternalGeneric {
+                        description: format!(
+                            "invalid field in command output filter: {field_name:}"
+                        ),
+                    }
+                })?;
+                join_mapping.push((
+                    SourceField {
+                        field_path: vec![field_name.clone()],
+                        field_mapping: field_mapping.clone(),
+                        nested_columns: vec![],
+                    },
+                    (field_name.clone(), target_column.clone()),
+                ));
+                relationship_join_filter_expressions.push(Expression::LocalField(
+                    LocalFieldComparison::BinaryComparison {
+                        column: ComparisonTarget::Column {
+                            name: target_column.column.clone(),
+                            field_path: target_column.field_path.clone(),
+                        },
+                        operator: target_column.equal_operator.clone(),
+                        value: ComparisonValue::Variable {
+                            name: make_model_field_variable_name(target_column),
+                        },
+                    },
+                ));
+                key_fields.insert(
+                    NdcFieldAlias::from(target_column.column.as_str()),
+                    FieldSelection::Column {
+                        column: target_column.column.clone(),
+                        nested_selection: None,
+                        arguments: BTreeMap::new(),
+                    },
+                );
+            }
+
+            let model_source = &output_filter.model_source;
+            let permission_filter = process_model_predicate(
+                &model_source.data_connector,
+                &model_source.type_mappings,
+                &output_filter.predicate,
+                &session.variables,
+                usage_counts,
+            )?;
+
+            let output_filter_selection = ModelSelection {
+                data_connector: model_source.data_connector.clone(),
+                collection: &model_source.collection,
+                arguments: BTreeMap::new(),
+                filter_clause: FilterExpression {
+                    query_filter: QueryFilter {
+                        where_clause: None,
+                        additional_filter: None,
+                    },
+                    permission_filter: Some(permission_filter),
+                    relationship_join_filter: Some(Expression::mk_and(
+                        relationship_join_filter_expressions,
+                    )),
+                },
+                limit: None,
+                offset: None,
+                order_by: None,
+                selection: Some(ResultSelectionSet { fields: key_fields }),
+                aggregate_selection: None,
+                cursor_pagination: None,
+                distinct_on: None,
+                group_by: None,
+            };
+
+            result_selection_set.fields.insert(
+                NdcFieldAlias::from(COMMAND_OUTPUT_FILTER_ALIAS),
+                FieldSelection::ModelRelationshipRemote {
+                    ir: output_filter_selection,
+                    relationship_info: RemoteModelRelationshipInfo {
+                        join_mapping,
+                        argument_join_mapping: vec![],
+                    },
+                },
+            );
+            Ok(())
+        }
+    }
+}
+
 /// Generates the OpenDD IR for a 'command' operation
 #[allow(irrefutable_let_patterns)]
 pub fn generate_command_info_open_dd<'n, 's>(
@@ -192,6 +375,7 @@ pub fn generate_command_info_open_dd<'n, 's>(
     result_type: &QualifiedTypeReference,
     result_base_type_kind: TypeKind,
     command_source: &'s CommandSource,
+    output_filter: Option<&'s metadata_resolve::CommandOutputFilter>,
     session_variables: &SessionVariables,
     request_headers: &reqwest::header::HeaderMap,
     usage_counts: &mut UsagesCounts,
@@ -218,6 +402,8 @@ pub fn generate_command_info_open_dd<'n, 's>(
         subgraph: command_name.subgraph.clone(),
     };
 
+    let type_discrimination = get_type_discrimination(&result_base_type_kind);
+    // the output filter is applied when the OpenDD IR is planned
     let nested_selection = selection_set::generate_nested_selection_open_dd_ir(
         result_type,
         result_base_type_kind,
@@ -245,6 +431,8 @@ pub fn generate_command_info_open_dd<'n, 's>(
         selection,
         type_container: field.type_container.clone(),
         usage_counts,
+        output_filter,
+        type_discrimination,
     })
 }
 
@@ -300,6 +488,7 @@ pub fn generate_function_based_command<'n, 's>(
     result_base_type_kind: TypeKind,
     command: &'s metadata_resolve::CommandWithPermissions,
     command_source: &'s CommandSource,
+    fixed_arguments: BTreeMap<DataConnectorArgumentName, UnresolvedArgument<'s>>,
     models: &'s IndexMap<
         metadata_resolve::Qualified<open_dds::models::ModelName>,
         metadata_resolve::ModelWithPermissions,
@@ -324,6 +513,7 @@ pub fn generate_function_based_command<'n, 's>(
         result_base_type_kind,
         command,
         command_source,
+        fixed_arguments,
         models,
         commands,
         object_types,
@@ -352,6 +542,7 @@ pub fn generate_function_based_command_open_dd<'n, 's>(
     result_type: &QualifiedTypeReference,
     result_base_type_kind: TypeKind,
     command_source: &'s CommandSource,
+    output_filter: Option<&'s metadata_resolve::CommandOutputFilter>,
     session_variables: &SessionVariables,
     request_headers: &reqwest::header::HeaderMap,
     usage_counts: &mut UsagesCounts,
@@ -364,6 +555,7 @@ pub fn generate_function_based_command_open_dd<'n, 's>(
         result_type,
         result_base_type_kind,
         command_source,
+        output_filter,
         session_variables,
         request_headers,
         usage_counts,
@@ -411,6 +603,7 @@ pub fn generate_procedure_based_command<'n, 's>(
         result_base_type_kind,
         command,
         command_source,
+        BTreeMap::new(),
         models,
         commands,
         object_types,
@@ -438,6 +631,7 @@ pub fn generate_procedure_based_command_open_dd<'n, 's>(
     result_type: &QualifiedTypeReference,
     result_base_type_kind: TypeKind,
     command_source: &'s CommandSource,
+    output_filter: Option<&'s metadata_resolve::CommandOutputFilter>,
     session_variables: &SessionVariables,
     request_headers: &reqwest::header::HeaderMap,
 ) -> Result<ProcedureBasedCommand<'s>, error::Error> {
@@ -451,6 +645,7 @@ pub fn generate_procedure_based_command_open_dd<'n, 's>(
         result_type,
         result_base_type_kind,
         command_source,
+        output_filter,
         session_variables,
         request_headers,
         &mut usage_counts,
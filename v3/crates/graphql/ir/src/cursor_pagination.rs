@@ -0,0 +1,97 @@
+//! IR for the cursor pagination of a model selection, requested with the `first`/`after` and
+//! `last`/`before` arguments
+
+use lang_graphql::normalized_ast;
+use serde::Serialize;
+
+use crate::error;
+use crate::global_id::decode_pagination_cursor;
+use graphql_schema::{ModelInputAnnotation, GDS};
+
+/// The page of rows requested with the cursor pagination arguments
+#[derive(Debug, Serialize)]
+pub struct CursorPaginationInput<'s> {
+    pub cursor_pagination: &'s metadata_resolve::CursorPaginationGraphQlDefinition,
+    // Whether the page ends before the cursor (`last`/`before`) rather than starting after it
+    pub backwards: bool,
+    // The values of the ordering fields of the row the page starts after, or ends before
+    pub cursor: Option<Vec<serde_json::Value>>,
+}
+
+/// The values of the cursor pagination arguments of a field
+#[derive(Default)]
+pub(crate) struct CursorPaginationArguments {
+    // The `first` and `after` arguments
+    forward_page: (Option<u32>, Option<Vec<serde_json::Value>>),
+    // The `last` and `before` arguments
+    backward_page: (Option<u32>, Option<Vec<serde_json::Value>>),
+}
+
+/// The page requested with the cursor pagination arguments
+pub(crate) struct RequestedPage {
+    pub page_size: Option<u32>,
+    pub backwards: bool,
+    pub cursor: Option<Vec<serde_json::Value>>,
+}
+
+impl CursorPaginationArguments {
+    /// Reads the value of an argument if it is a cursor pagination argument, and returns whether
+    /// it is one
+    pub(crate) fn read_argument(
+        &mut self,
+        annotation: &ModelInputAnnotation,
+        value: &normalized_ast::Value<'_, GDS>,
+    ) -> Result<bool, error::Error> {
+        match annotation {
+            // An explicit `null` requests the same page as an omitted argument
+            ModelInputAnnotation::ModelFirstArgument
+            | ModelInputAnnotation::ModelAfterArgument
+            | ModelInputAnnotation::ModelLastArgument
+            | ModelInputAnnotation::ModelBeforeArgument
+                if value.is_null() => {}
+            ModelInputAnnotation::ModelFirstArgument => {
+                self.forward_page.0 = Some(
+                    value
+                        .as_int_u32()
+                        .map_err(error::Error::map_unexpected_value_to_external_error)?,
+                );
+            }
+            ModelInputAnnotation::ModelAfterArgument => {
+                self.forward_page.1 =
+                    Some(decode_pagination_cursor(value.as_string().map_err(
+                        error::Error::map_unexpected_value_to_external_error,
+                    )?)?);
+            }
+            ModelInputAnnotation::ModelLastArgument => {
+                self.backward_page.0 = Some(
+                    value
+                        .as_int_u32()
+                        .map_err(error::Error::map_unexpected_value_to_external_error)?,
+                );
+            }
+            ModelInputAnnotation::ModelBeforeArgument => {
+                self.backward_page.1 =
+                    Some(decode_pagination_cursor(value.as_string().map_err(
+                        error::Error::map_unexpected_value_to_external_error,
+                    )?)?);
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// The requested page, or `None` if none of the arguments were given
+    pub(crate) fn requested_page(self) -> Result<Option<RequestedPage>, error::Error> {
+        let (backwards, (page_size, cursor)) = match (self.forward_page, self.backward_page) {
+            ((None, None), (None, None)) => return Ok(None),
+            (forward_page, (None, None)) => (false, forward_page),
+            ((None, None), backward_page) => (true, backward_page),
+            _ => return Err(error::Error::CursorPaginationInBothDirections),
+        };
+        Ok(Some(RequestedPage {
+            page_size,
+            backwards,
+            cursor,
+        }))
+    }
+}
@@ -0,0 +1,52 @@
+//! IR for the distinct rows of a model selection, requested with the `distinct_on` argument
+
+use lang_graphql::normalized_ast;
+use open_dds::data_connector::DataConnectorColumnName;
+use serde::Serialize;
+
+use crate::error;
+use graphql_schema::{Annotation, InputAnnotation, ModelInputAnnotation, GDS};
+
+/// The columns the selected rows must be distinct on
+#[derive(Debug, Serialize)]
+pub struct DistinctOnInput<'s> {
+    pub distinct_on: &'s metadata_resolve::DistinctOnGraphQlDefinition,
+    pub columns: Vec<DataConnectorColumnName>,
+}
+
+/// Reads the value of the `distinct_on` argument. Returns `None` if no field is given, as all
+/// the rows are then selected.
+pub(crate) fn read_distinct_on_argument<'s>(
+    distinct_on: Option<&'s metadata_resolve::DistinctOnGraphQlDefinition>,
+    value: &normalized_ast::Value<'s, GDS>,
+) -> Result<Option<DistinctOnInput<'s>>, error::Error> {
+    if value.is_null() {
+        return Ok(None);
+    }
+    let distinct_on = distinct_on.ok_or_else(|| error::InternalEngineError::InternalGeneric {
+        description: "distinct_on argument found for a model without distinct_on".to_string(),
+    })?;
+    let mut columns = Vec::new();
+    for field_value in value.as_list()? {
+        match &field_value.as_enum()?.info.generic {
+            Annotation::Input(InputAnnotation::Model(
+                ModelInputAnnotation::ModelDistinctOnField { ndc_column, .. },
+            )) => {
+                // A field given more than once does not change the distinct rows
+                if !columns.contains(ndc_column) {
+                    columns.push(ndc_column.clone());
+                }
+            }
+            &annotation => Err(error::InternalEngineError::UnexpectedAnnotation {
+                annotation: annotation.clone(),
+            })?,
+        }
+    }
+    if columns.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(DistinctOnInput {
+        distinct_on,
+        columns,
+    }))
+}
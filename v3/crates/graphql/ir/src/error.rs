@@ -3,6 +3,7 @@ use lang_graphql as gql;
 use open_dds::{
     aggregates::AggregationFunctionName,
     arguments::ArgumentName,
+    commands::CommandName,
     data_connector::{DataConnectorColumnName, DataConnectorName},
     relationships::RelationshipName,
     types::{CustomTypeName, FieldName},
@@ -13,6 +14,7 @@ use tracing_util::{ErrorVisibility, TraceableError};
 use transitive::Transitive;
 
 use graphql_schema::Annotation;
+use hasura_authn_core::Role;
 use metadata_resolve::{Qualified, QualifiedTypeName};
 
 impl From<plan::InternalError> for Error {
@@ -43,6 +45,12 @@ pub enum Error {
         decoding_error: String,
     },
 
+    #[error("The cursor {encoded_value:} couldn't be decoded due to {decoding_error:}")]
+    FailureDecodingCursor {
+        encoded_value: String,
+        decoding_error: String,
+    },
+
     #[error("Unexpected value: expecting {expected_kind:}, but found: {found:}")]
     UnexpectedValue {
         expected_kind: &'static str,
@@ -61,12 +69,44 @@ pub enum Error {
     #[error("{value} is not a valid offset value")]
     InvalidOffsetValue { value: u32 },
 
+    #[error("{value} is not a valid batch size value")]
+    InvalidBatchSizeValue { value: u32 },
+
     #[error("field '{field_name:} not found in entity representation")]
     FieldNotFoundInEntityRepresentation { field_name: FieldName },
 
     #[error("order_by expects a list of input objects with exactly one key-value pair per input object. Please split the input object with multiple key-value pairs into a list of single key-value pair objects.")]
     OrderByObjectShouldExactlyHaveOneKeyValuePair,
 
+    #[error("cursor expects an input object with exactly one key-value pair: the field to stream the rows by, and the value after which to start streaming.")]
+    StreamCursorShouldExactlyHaveOneKeyValuePair,
+
+    #[error("the arguments to paginate forwards (first, after) cannot be used together with the arguments to paginate backwards (last, before)")]
+    CursorPaginationInBothDirections,
+
+    #[error("cursor pagination cannot be used together with limit or offset")]
+    CursorPaginationWithLimitOrOffset,
+
+    #[error("distinct_on cannot be used together with cursor pagination")]
+    DistinctOnWithCursorPagination,
+
+    #[error(
+        "distinct_on on the field {field_name:} is not supported by the OpenDD request pipeline"
+    )]
+    DistinctOnNotSupportedInOpenDdPipeline { field_name: ast::Name },
+
+    #[error("the nodes of the connection field {field_name:} must be selected with the same fields wherever they are selected")]
+    DifferentConnectionNodeSelections { field_name: ast::Name },
+
+    #[error("the connection field {field_name:} is not supported by the OpenDD request pipeline")]
+    ConnectionNotSupportedInOpenDdPipeline { field_name: ast::Name },
+
+    #[error("the group by field {field_name:} is not supported by the OpenDD request pipeline")]
+    GroupByNotSupportedInOpenDdPipeline { field_name: ast::Name },
+
+    #[error("the groups of the field {field_name:} can only be ordered by the dimensions selected in the same field")]
+    GroupOrderByDimensionNotSelected { field_name: ast::Name },
+
     #[error("missing non-nullable argument {argument_name:} for field {field_name:}")]
     MissingNonNullableArgument {
         argument_name: String,
@@ -76,6 +116,18 @@ pub enum Error {
     #[error("Only one subscription root field is allowed")]
     NoneOrMoreSubscriptionRootFields,
 
+    #[error("role {role:} does not have permission to execute command {command_name:} with the given arguments")]
+    CommandArgumentPredicateNotSatisfied {
+        role: Role,
+        command_name: Qualified<CommandName>,
+    },
+
+    #[error("the field {field_name:} of union or interface type {type_name:} is not supported by the OpenDD request pipeline")]
+    AbstractTypeNotSupportedInOpenDdPipeline {
+        field_name: ast::Name,
+        type_name: Qualified<CustomTypeName>,
+    },
+
     #[error("internal error: type mapping not found for type {type_name:}")]
     InternalTypeMappingNotFound {
         type_name: Qualified<CustomTypeName>,
@@ -235,6 +287,9 @@ pub enum InternalEngineError {
     #[error("Subscription is restricted by select permission")]
     SubscriptionNotAllowed,
 
+    #[error("Aggregation is restricted by select permission")]
+    AggregationNotAllowed,
+
     #[error("{0}")]
     PlanInternalEngineError(plan::InternalEngineError),
 
@@ -0,0 +1,14 @@
+use lang_graphql::ast::common::Alias;
+use open_dds::types::FieldName;
+
+const FIELD_CONDITION_NDC_PREFIX: &str = "hasura_condition_col";
+
+/// The alias of a field fetched to evaluate the condition of a conditionally allowed field
+pub fn condition_col_format(alias: &Alias, field_name: &FieldName) -> String {
+    format!(
+        "{}_{}_{}",
+        FIELD_CONDITION_NDC_PREFIX.to_owned(),
+        alias,
+        field_name.as_str()
+    )
+}
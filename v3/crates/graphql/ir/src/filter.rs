@@ -2,17 +2,18 @@ use hasura_authn_core::SessionVariables;
 use indexmap::IndexMap;
 use lang_graphql::ast::common as ast;
 use lang_graphql::normalized_ast;
-use metadata_resolve::{DataConnectorLink, FieldMapping, Qualified};
+use metadata_resolve::{DataConnectorLink, FieldMapping, Qualified, QualifiedTypeName};
 use serde::Serialize;
 use std::collections::BTreeMap;
 use std::ops::Deref;
 
-use crate::{error, permissions};
+use crate::{aggregates, error, permissions};
 use graphql_schema::{self};
-use graphql_schema::{BooleanExpressionAnnotation, InputAnnotation, ObjectFieldKind};
 use graphql_schema::{
-    FilterRelationshipAnnotation, ObjectBooleanExpressionField, ScalarBooleanExpressionField,
+    AggregateBooleanExpressionField, FilterRelationshipAnnotation, ObjectBooleanExpressionField,
+    ScalarBooleanExpressionField,
 };
+use graphql_schema::{BooleanExpressionAnnotation, InputAnnotation, ObjectFieldKind};
 use graphql_schema::{LogicalOperatorField, GDS};
 use open_dds::{
     data_connector::{DataConnectorColumnName, DataConnectorOperatorName},
@@ -20,8 +21,8 @@ use open_dds::{
 };
 use plan::count_model;
 use plan_types::{
-    ComparisonTarget, ComparisonValue, Expression, LocalFieldComparison, UsagesCounts,
-    EXPRESSION_SCALAR_VALUE_VIRTUAL_COLUMN_NAME,
+    AggregateComparison, AggregateFieldSelection, ComparisonTarget, ComparisonValue, Expression,
+    LocalFieldComparison, UsagesCounts, EXPRESSION_SCALAR_VALUE_VIRTUAL_COLUMN_NAME,
 };
 
 /// Filter expression to be applied on a model/command selection set
@@ -263,6 +264,80 @@ fn resolve_object_boolean_expression_open_dd(
                         predicate,
                     )?*/
                 }
+                ObjectBooleanExpressionField::RelationshipAggregateField(
+                    FilterRelationshipAnnotation {
+                        relationship_name,
+                        target_model_name,
+                        ..
+                    },
+                ) => {
+                    // Add the target model being used in the usage counts
+                    count_model(target_model_name, usage_counts);
+
+                    // The target model's permission filter is applied when the query is planned
+                    let (filter, aggregate_expressions) =
+                        split_relationship_aggregate_predicate(field.value.as_object()?)?;
+
+                    let relationship_filter = filter
+                        .map(|filter_object| {
+                            resolve_object_boolean_expression_open_dd(
+                                filter_object,
+                                &[], // We're traversing across the relationship, so we reset the field path
+                                session_variables,
+                                usage_counts,
+                            )
+                        })
+                        .transpose()?;
+
+                    let build_comparison =
+                        |aggregate: open_dds::query::Aggregate,
+                         comparison: AggregateComparisonOpenDd|
+                         -> open_dds::query::BooleanExpression {
+                            let operand = open_dds::query::Operand::RelationshipAggregate(
+                                open_dds::query::RelationshipAggregateOperand {
+                                    target: Box::new(open_dds::query::RelationshipTarget {
+                                        relationship_name: relationship_name.clone(),
+                                        arguments: IndexMap::new(),
+                                        filter: relationship_filter.clone(),
+                                        order_by: vec![],
+                                        limit: None,
+                                        offset: None,
+                                    }),
+                                    aggregate: Box::new(aggregate),
+                                },
+                            );
+                            // The relationship may be nested inside object fields
+                            let operand = field_path.iter().rev().fold(operand, |nested, field| {
+                                open_dds::query::Operand::Field(
+                                    open_dds::query::ObjectFieldOperand {
+                                        target: Box::new(open_dds::query::ObjectFieldTarget {
+                                            arguments: IndexMap::new(),
+                                            field_name: (*field).clone(),
+                                        }),
+                                        nested: Some(Box::new(nested)),
+                                    },
+                                )
+                            });
+                            match comparison {
+                                AggregateComparisonOpenDd::IsNull => {
+                                    open_dds::query::BooleanExpression::IsNull(operand)
+                                }
+                                AggregateComparisonOpenDd::Comparison { operator, argument } => {
+                                    open_dds::query::BooleanExpression::Comparison {
+                                        operand,
+                                        operator,
+                                        argument: Box::new(argument),
+                                    }
+                                }
+                            }
+                        };
+
+                    resolve_aggregate_boolean_expression_open_dd(
+                        &aggregate_expressions,
+                        &[],
+                        &build_comparison,
+                    )?
+                }
             };
 
             Ok(field_expression)
@@ -512,6 +587,209 @@ fn resolve_object_boolean_expression<'s>(
                         predicate,
                     )?
                 }
+                ObjectBooleanExpressionField::RelationshipAggregateField(
+                    FilterRelationshipAnnotation {
+                        relationship_name,
+                        relationship_type,
+                        source_type,
+                        target_source,
+                        target_type,
+                        target_model_name,
+                        mappings,
+                        deprecated: _,
+                    },
+                ) => {
+                    // Add the target model being used in the usage counts
+                    count_model(target_model_name, usage_counts);
+
+                    // Get the filter permissions for the target model
+                    let filter_permission = permissions::get_select_filter_predicate(&field.info)?;
+                    let permission_predicate =
+                        permissions::build_model_permissions_filter_predicate(
+                            &target_source.model.data_connector,
+                            &target_source.model.type_mappings,
+                            filter_permission,
+                            session_variables,
+                            usage_counts,
+                        )?;
+
+                    let (filter, aggregate_expressions) =
+                        split_relationship_aggregate_predicate(field.value.as_object()?)?;
+
+                    // The rows of the relationship are filtered before they are aggregated
+                    let relationship_filter = filter
+                        .map(|filter_object| {
+                            resolve_object_boolean_expression(
+                                filter_object,
+                                &target_source.model.data_connector,
+                                &target_source.model.type_mappings,
+                                &[], // We're traversing across the relationship, so we reset the field path
+                                session_variables,
+                                usage_counts,
+                            )
+                        })
+                        .transpose()?;
+
+                    // Combine the permission filter and the relationship filter
+                    let filter_predicate = match (permission_predicate, relationship_filter) {
+                        (Some(permission_predicate), Some(relationship_filter)) => {
+                            Some(Expression::mk_and(vec![
+                                permission_predicate,
+                                relationship_filter,
+                            ]))
+                        }
+                        (predicate, None) | (None, predicate) => predicate,
+                    };
+
+                    let build_comparison =
+                        |aggregate: AggregateFieldSelection,
+                         comparison: AggregateComparison|
+                         -> Result<Expression<'s>, error::Error> {
+                            Ok(plan::build_relationship_aggregate_comparison_expression(
+                                type_mappings,
+                                column_path,
+                                data_connector_link,
+                                relationship_name,
+                                relationship_type,
+                                source_type,
+                                target_source,
+                                target_type,
+                                mappings,
+                                filter_predicate.clone(),
+                                aggregate,
+                                comparison,
+                            )?)
+                        };
+
+                    resolve_aggregate_boolean_expression(
+                        &aggregate_expressions,
+                        &target_source.model.data_connector,
+                        &target_source.model.type_mappings,
+                        &QualifiedTypeName::Custom(target_type.clone()),
+                        &[],
+                        &build_comparison,
+                    )?
+                }
+            };
+
+            Ok(field_expression)
+        })
+        .collect::<Result<Vec<Expression>, error::Error>>()?;
+
+    Ok(Expression::mk_and(field_expressions))
+}
+
+/// The input fields of a relationship aggregate predicate: the filter applied to the related
+/// rows before they are aggregated, and the comparisons against the aggregates
+type RelationshipAggregatePredicate<'a, 's> = (
+    Option<&'a IndexMap<ast::Name, normalized_ast::InputField<'s, GDS>>>,
+    IndexMap<ast::Name, normalized_ast::InputField<'s, GDS>>,
+);
+
+fn split_relationship_aggregate_predicate<'a, 's>(
+    fields: &'a IndexMap<ast::Name, normalized_ast::InputField<'s, GDS>>,
+) -> Result<RelationshipAggregatePredicate<'a, 's>, error::Error> {
+    let mut filter = None;
+    let mut aggregate_expressions = IndexMap::new();
+    for (field_name, field) in fields {
+        match extract_aggregate_boolean_expression_field_annotation(field.info.generic)? {
+            AggregateBooleanExpressionField::FilterInputField => {
+                filter = Some(field.value.as_object()?);
+            }
+            _ => {
+                aggregate_expressions.insert(field_name.clone(), field.clone());
+            }
+        }
+    }
+    Ok((filter, aggregate_expressions))
+}
+
+/// Resolve the comparisons against the aggregates of the rows of an array relationship.
+/// `column_path` is the path to the (possibly nested) field whose values are aggregated,
+/// and is empty when aggregating the rows themselves.
+fn resolve_aggregate_boolean_expression<'s>(
+    fields: &IndexMap<ast::Name, normalized_ast::InputField<'s, GDS>>,
+    data_connector_link: &'s DataConnectorLink,
+    type_mappings: &'s BTreeMap<Qualified<CustomTypeName>, metadata_resolve::TypeMapping>,
+    aggregate_operand_type: &QualifiedTypeName,
+    column_path: &[&'s FieldMapping],
+    build_comparison: &dyn Fn(
+        AggregateFieldSelection,
+        AggregateComparison,
+    ) -> Result<Expression<'s>, error::Error>,
+) -> Result<Expression<'s>, error::Error> {
+    let field_expressions = fields
+        .values()
+        .map(|field| {
+            let field_annotation =
+                extract_aggregate_boolean_expression_field_annotation(field.info.generic)?;
+
+            let field_expression = match field_annotation {
+                AggregateBooleanExpressionField::LogicalOperatorField(logical_operator) => {
+                    resolve_logical_operator(field, logical_operator, |value_object| {
+                        resolve_aggregate_boolean_expression(
+                            value_object,
+                            data_connector_link,
+                            type_mappings,
+                            aggregate_operand_type,
+                            column_path,
+                            build_comparison,
+                        )
+                    })?
+                }
+                AggregateBooleanExpressionField::AggregatableField {
+                    field_name,
+                    aggregate_operand_type: field_aggregate_operand_type,
+                } => {
+                    let QualifiedTypeName::Custom(object_type) = aggregate_operand_type else {
+                        return Err(
+                            error::InternalDeveloperError::AggregatableFieldFoundOnScalarTypedOperand {
+                                field_name: field_name.clone(),
+                                aggregate_operand_type: aggregate_operand_type.clone(),
+                            }
+                            .into(),
+                        );
+                    };
+                    let field_mapping = plan::get_field_mapping_of_field_name(
+                        type_mappings,
+                        object_type,
+                        field_name,
+                    )?;
+                    let column_path = column_path
+                        .iter()
+                        .copied()
+                        .chain([field_mapping])
+                        .collect::<Vec<_>>();
+
+                    resolve_aggregate_boolean_expression(
+                        field.value.as_object()?,
+                        data_connector_link,
+                        type_mappings,
+                        field_aggregate_operand_type,
+                        &column_path,
+                        build_comparison,
+                    )?
+                }
+                AggregateBooleanExpressionField::AggregationFunctionField(aggregate_function) => {
+                    let aggregate = aggregates::make_aggregation_function_selection(
+                        aggregate_function,
+                        aggregate_operand_type,
+                        &data_connector_link.name,
+                        column_path,
+                    )?;
+
+                    resolve_aggregate_comparison(
+                        field.value.as_object()?,
+                        data_connector_link,
+                        &aggregate,
+                        build_comparison,
+                    )?
+                }
+                AggregateBooleanExpressionField::FilterInputField => {
+                    Err(error::InternalEngineError::UnexpectedAnnotation {
+                        annotation: field.info.generic.clone(),
+                    })?
+                }
             };
 
             Ok(field_expression)
@@ -521,6 +799,304 @@ fn resolve_object_boolean_expression<'s>(
     Ok(Expression::mk_and(field_expressions))
 }
 
+/// Resolve the scalar comparisons against the result of an aggregate
+fn resolve_aggregate_comparison<'s>(
+    fields: &IndexMap<ast::Name, normalized_ast::InputField<'s, GDS>>,
+    data_connector_link: &'s DataConnectorLink,
+    aggregate: &AggregateFieldSelection,
+    build_comparison: &dyn Fn(
+        AggregateFieldSelection,
+        AggregateComparison,
+    ) -> Result<Expression<'s>, error::Error>,
+) -> Result<Expression<'s>, error::Error> {
+    let field_expressions = fields
+        .values()
+        .map(|field| {
+            let field_annotation =
+                extract_scalar_boolean_expression_field_annotation(field.info.generic)?;
+
+            let field_expression = match field_annotation {
+                ScalarBooleanExpressionField::LogicalOperatorField(logical_operator) => {
+                    resolve_logical_operator(field, logical_operator, |value_object| {
+                        resolve_aggregate_comparison(
+                            value_object,
+                            data_connector_link,
+                            aggregate,
+                            build_comparison,
+                        )
+                    })?
+                }
+                ScalarBooleanExpressionField::IsNullOperation => {
+                    let is_null_expression = build_comparison(
+                        aggregate.clone(),
+                        AggregateComparison::UnaryComparison {
+                            operator: metadata_resolve::UnaryComparisonOperator::IsNull,
+                        },
+                    )?;
+                    if field.value.as_boolean()? {
+                        is_null_expression
+                    } else {
+                        Expression::mk_not(is_null_expression)
+                    }
+                }
+                ScalarBooleanExpressionField::ComparisonOperation {
+                    operator_mapping,
+                    operator_name,
+                } => {
+                    let operator =
+                        operator_mapping
+                            .get(&data_connector_link.name)
+                            .ok_or_else(|| error::InternalEngineError::InternalGeneric {
+                                description: format!(
+                                    "could not find a mapping for the operator {operator_name} in data connector {}",
+                                    data_connector_link.name
+                                ),
+                            })?;
+
+                    build_comparison(
+                        aggregate.clone(),
+                        AggregateComparison::BinaryComparison {
+                            operator: operator.clone(),
+                            value: ComparisonValue::Scalar {
+                                value: field.value.as_json(),
+                            },
+                        },
+                    )?
+                }
+            };
+
+            Ok(field_expression)
+        })
+        .collect::<Result<Vec<Expression>, error::Error>>()?;
+
+    Ok(Expression::mk_and(field_expressions))
+}
+
+/// A comparison against an aggregate in the OpenDD IR
+enum AggregateComparisonOpenDd {
+    IsNull,
+    Comparison {
+        operator: open_dds::query::ComparisonOperator,
+        argument: open_dds::query::Value,
+    },
+}
+
+/// Generate the OpenDD IR for the comparisons against the aggregates of the rows of an
+/// array relationship. `field_path` is the path to the (possibly nested) field whose values
+/// are aggregated, and is empty when aggregating the rows themselves.
+fn resolve_aggregate_boolean_expression_open_dd(
+    fields: &IndexMap<ast::Name, normalized_ast::InputField<'_, GDS>>,
+    field_path: &[FieldName],
+    build_comparison: &dyn Fn(
+        open_dds::query::Aggregate,
+        AggregateComparisonOpenDd,
+    ) -> open_dds::query::BooleanExpression,
+) -> Result<open_dds::query::BooleanExpression, error::Error> {
+    let field_expressions = fields
+        .values()
+        .map(|field| {
+            let field_annotation =
+                extract_aggregate_boolean_expression_field_annotation(field.info.generic)?;
+
+            let field_expression = match field_annotation {
+                AggregateBooleanExpressionField::LogicalOperatorField(logical_operator) => {
+                    resolve_logical_operator_open_dd(field, logical_operator, |value_object| {
+                        resolve_aggregate_boolean_expression_open_dd(
+                            value_object,
+                            field_path,
+                            build_comparison,
+                        )
+                    })?
+                }
+                AggregateBooleanExpressionField::AggregatableField { field_name, .. } => {
+                    let field_path = field_path
+                        .iter()
+                        .chain([field_name])
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    resolve_aggregate_boolean_expression_open_dd(
+                        field.value.as_object()?,
+                        &field_path,
+                        build_comparison,
+                    )?
+                }
+                AggregateBooleanExpressionField::AggregationFunctionField(aggregate_function) => {
+                    let function = match aggregate_function {
+                        graphql_schema::AggregationFunctionAnnotation::Count => {
+                            open_dds::query::AggregationFunction::Count {}
+                        }
+                        graphql_schema::AggregationFunctionAnnotation::CountDistinct => {
+                            open_dds::query::AggregationFunction::CountDistinct {}
+                        }
+                        graphql_schema::AggregationFunctionAnnotation::Function {
+                            function_name,
+                            aggregate_expression,
+                            data_connector_functions: _,
+                        } => open_dds::query::AggregationFunction::Custom {
+                            name: function_name.clone(),
+                            expression: aggregate_expression.clone(),
+                        },
+                    };
+                    let aggregate = open_dds::query::Aggregate {
+                        function,
+                        operand: build_nested_field_path(field_path).map(|operand| *operand),
+                    };
+
+                    resolve_aggregate_comparison_open_dd(
+                        field.value.as_object()?,
+                        &aggregate,
+                        build_comparison,
+                    )?
+                }
+                AggregateBooleanExpressionField::FilterInputField => {
+                    Err(error::InternalEngineError::UnexpectedAnnotation {
+                        annotation: field.info.generic.clone(),
+                    })?
+                }
+            };
+
+            Ok(field_expression)
+        })
+        .collect::<Result<Vec<open_dds::query::BooleanExpression>, error::Error>>()?;
+
+    Ok(open_dds::query::BooleanExpression::And(field_expressions))
+}
+
+/// Generate the OpenDD IR for the scalar comparisons against the result of an aggregate
+fn resolve_aggregate_comparison_open_dd(
+    fields: &IndexMap<ast::Name, normalized_ast::InputField<'_, GDS>>,
+    aggregate: &open_dds::query::Aggregate,
+    build_comparison: &dyn Fn(
+        open_dds::query::Aggregate,
+        AggregateComparisonOpenDd,
+    ) -> open_dds::query::BooleanExpression,
+) -> Result<open_dds::query::BooleanExpression, error::Error> {
+    let field_expressions = fields
+        .values()
+        .map(|field| {
+            let field_annotation =
+                extract_scalar_boolean_expression_field_annotation(field.info.generic)?;
+
+            let field_expression = match field_annotation {
+                ScalarBooleanExpressionField::LogicalOperatorField(logical_operator) => {
+                    resolve_logical_operator_open_dd(field, logical_operator, |value_object| {
+                        resolve_aggregate_comparison_open_dd(
+                            value_object,
+                            aggregate,
+                            build_comparison,
+                        )
+                    })?
+                }
+                ScalarBooleanExpressionField::IsNullOperation => {
+                    let is_null_expression =
+                        build_comparison(aggregate.clone(), AggregateComparisonOpenDd::IsNull);
+                    if field.value.as_boolean()? {
+                        is_null_expression
+                    } else {
+                        open_dds::query::BooleanExpression::Not(Box::new(is_null_expression))
+                    }
+                }
+                ScalarBooleanExpressionField::ComparisonOperation { operator_name, .. } => {
+                    build_comparison(
+                        aggregate.clone(),
+                        AggregateComparisonOpenDd::Comparison {
+                            operator: open_dds::query::ComparisonOperator::Custom(
+                                operator_name.clone(),
+                            ),
+                            argument: open_dds::query::Value::Literal(field.value.as_json()),
+                        },
+                    )
+                }
+            };
+
+            Ok(field_expression)
+        })
+        .collect::<Result<Vec<open_dds::query::BooleanExpression>, error::Error>>()?;
+
+    Ok(open_dds::query::BooleanExpression::And(field_expressions))
+}
+
+// Resolve `_and`, `_or` and `_not` fields, resolving their operands with `resolve`
+fn resolve_logical_operator<'s>(
+    field: &normalized_ast::InputField<'s, GDS>,
+    logical_operator: &LogicalOperatorField,
+    mut resolve: impl FnMut(
+        &IndexMap<ast::Name, normalized_ast::InputField<'s, GDS>>,
+    ) -> Result<Expression<'s>, error::Error>,
+) -> Result<Expression<'s>, error::Error> {
+    match logical_operator {
+        // The "_and" and "_or" field values should be lists
+        LogicalOperatorField::AndOp => Ok(Expression::mk_and(
+            field
+                .value
+                .as_list()?
+                .iter()
+                .map(|value| resolve(value.as_object()?))
+                .collect::<Result<Vec<_>, _>>()?,
+        )),
+        LogicalOperatorField::OrOp => Ok(Expression::mk_or(
+            field
+                .value
+                .as_list()?
+                .iter()
+                .map(|value| resolve(value.as_object()?))
+                .collect::<Result<Vec<_>, _>>()?,
+        )),
+        // The "_not" field value should be an object
+        LogicalOperatorField::NotOp => Ok(Expression::mk_not(resolve(field.value.as_object()?)?)),
+    }
+}
+
+// Resolve `_and`, `_or` and `_not` fields into the OpenDD IR, resolving their operands with
+// `resolve`
+fn resolve_logical_operator_open_dd(
+    field: &normalized_ast::InputField<'_, GDS>,
+    logical_operator: &LogicalOperatorField,
+    mut resolve: impl FnMut(
+        &IndexMap<ast::Name, normalized_ast::InputField<'_, GDS>>,
+    ) -> Result<open_dds::query::BooleanExpression, error::Error>,
+) -> Result<open_dds::query::BooleanExpression, error::Error> {
+    match logical_operator {
+        // The "_and" and "_or" field values should be lists
+        LogicalOperatorField::AndOp => Ok(open_dds::query::BooleanExpression::And(
+            field
+                .value
+                .as_list()?
+                .iter()
+                .map(|value| resolve(value.as_object()?))
+                .collect::<Result<Vec<_>, _>>()?,
+        )),
+        LogicalOperatorField::OrOp => Ok(open_dds::query::BooleanExpression::Or(
+            field
+                .value
+                .as_list()?
+                .iter()
+                .map(|value| resolve(value.as_object()?))
+                .collect::<Result<Vec<_>, _>>()?,
+        )),
+        // The "_not" field value should be an object
+        LogicalOperatorField::NotOp => Ok(open_dds::query::BooleanExpression::Not(Box::new(
+            resolve(field.value.as_object()?)?,
+        ))),
+    }
+}
+
+fn extract_aggregate_boolean_expression_field_annotation(
+    annotation: &graphql_schema::Annotation,
+) -> Result<&AggregateBooleanExpressionField, error::Error> {
+    match annotation {
+        graphql_schema::Annotation::Input(InputAnnotation::BooleanExpression(
+            BooleanExpressionAnnotation::AggregateBooleanExpressionField(
+                aggregate_boolean_expression_field,
+            ),
+        )) => Ok(aggregate_boolean_expression_field),
+        _ => Err(error::InternalEngineError::UnexpectedAnnotation {
+            annotation: annotation.clone(),
+        }
+        .into()),
+    }
+}
+
 fn extract_object_boolean_expression_field_annotation(
     annotation: &graphql_schema::Annotation,
 ) -> Result<&ObjectBooleanExpressionField, error::Error> {
@@ -1,8 +1,13 @@
+use base64::{engine::general_purpose, Engine};
 use lang_graphql::ast::common::Alias;
 use open_dds::types::FieldName;
+use serde::{Deserialize, Serialize};
+
+use crate::error;
 
 const GLOBAL_ID_NDC_PREFIX: &str = "hasura_global_id_col";
 pub const GLOBAL_ID_VERSION: u16 = 1;
+pub const PAGINATION_CURSOR_VERSION: u16 = 1;
 
 pub fn global_id_col_format(alias: &Alias, field_name: &FieldName) -> String {
     format!(
@@ -12,3 +17,45 @@ pub fn global_id_col_format(alias: &Alias, field_name: &FieldName) -> String {
         field_name.as_str()
     )
 }
+
+/// The cursor of a row in a cursor paginated query. Like global IDs, cursors are opaque to
+/// clients: they are base64 encoded JSON objects.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct PaginationCursor {
+    version: u16,
+    /// The values of the fields the rows are ordered by, in the order of the ordering
+    values: Vec<serde_json::Value>,
+}
+
+/// Encodes the values of the ordering fields of a row into a pagination cursor
+pub fn encode_pagination_cursor(
+    values: Vec<serde_json::Value>,
+) -> Result<String, serde_json::Error> {
+    let cursor = serde_json::to_string(&PaginationCursor {
+        version: PAGINATION_CURSOR_VERSION,
+        values,
+    })?;
+    Ok(general_purpose::STANDARD.encode(cursor))
+}
+
+/// Decodes a pagination cursor into the values of the ordering fields of a row
+pub(crate) fn decode_pagination_cursor(
+    encoded_value: &str,
+) -> Result<Vec<serde_json::Value>, error::Error> {
+    let decoding_error = |decoding_error: String| error::Error::FailureDecodingCursor {
+        encoded_value: encoded_value.to_string(),
+        decoding_error,
+    };
+    let decoded_value = general_purpose::STANDARD
+        .decode(encoded_value)
+        .map_err(|e| decoding_error(e.to_string()))?;
+    let cursor: PaginationCursor =
+        serde_json::from_slice(&decoded_value).map_err(|e| decoding_error(e.to_string()))?;
+    if cursor.version != PAGINATION_CURSOR_VERSION {
+        return Err(decoding_error(format!(
+            "unsupported cursor version {}",
+            cursor.version
+        )));
+    }
+    Ok(cursor.values)
+}
@@ -2,10 +2,14 @@ use indexmap::IndexMap;
 use lang_graphql::ast::common as ast;
 use serde::Serialize;
 
+mod abstract_types;
 mod aggregates;
 mod arguments;
 mod commands;
+mod cursor_pagination;
+mod distinct_on;
 mod error;
+mod field_condition;
 mod filter;
 mod global_id;
 mod model_selection;
@@ -20,18 +24,21 @@ mod root_field;
 mod selection_set;
 mod subscription_root;
 
+pub use abstract_types::{abstract_member_col_format, DISCRIMINATOR_COL};
 pub use aggregates::mk_alias_from_graphql_field_path;
 pub use commands::{CommandInfo, CommandSelection, FunctionBasedCommand, ProcedureBasedCommand};
 pub use error::{Error, InternalDeveloperError, InternalEngineError};
+pub use field_condition::condition_col_format;
 pub use filter::FilterExpression;
-pub use global_id::{global_id_col_format, GLOBAL_ID_VERSION};
+pub use global_id::{encode_pagination_cursor, global_id_col_format, GLOBAL_ID_VERSION};
 pub use model_selection::ModelSelection;
 pub use model_tracking::get_all_usage_counts_in_query;
 pub use mutation_root::generate_ir as generate_mutation_ir;
+pub use mutation_root::model_mutation::ModelMutation;
 pub use order_by::OrderBy;
 pub use plan::{
-    generate_request_plan, ApolloFederationSelect, Error as PlanError, MutationPlan, NodeQueryPlan,
-    QueryPlan, RequestPlan,
+    generate_request_plan, pagination_cursor_field_alias, ApolloFederationSelect,
+    Error as PlanError, MutationPlan, NodeQueryPlan, QueryPlan, RequestPlan,
 };
 pub use query_root::generate_ir as generate_query_ir;
 pub use relationship::{build_remote_command_relationship, build_remote_relationship};
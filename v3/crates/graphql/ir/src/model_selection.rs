@@ -1,5 +1,7 @@
 //! IR for the 'model_selection' type - selecting fields from a model
 use super::{aggregates, arguments, filter, order_by, permissions, selection_set};
+use crate::cursor_pagination::CursorPaginationInput;
+use crate::distinct_on::DistinctOnInput;
 use crate::error;
 use graphql_schema::GDS;
 use graphql_schema::{
@@ -52,6 +54,15 @@ pub struct ModelSelection<'s> {
 
     // Aggregates requested of the model
     pub aggregate_selection: Option<plan_types::AggregateSelectionSet>,
+
+    // The page of rows requested of a relationship connection
+    pub cursor_pagination: Option<CursorPaginationInput<'s>>,
+
+    // The columns the selected rows must be distinct on
+    pub distinct_on: Option<DistinctOnInput<'s>>,
+
+    // The groups of the rows requested of the model, with the aggregates over each group
+    pub group_by: Option<plan_types::Grouping>,
 }
 
 struct ModelSelectAggregateArguments<'s> {
@@ -239,6 +250,9 @@ pub fn model_selection_ir<'s>(
         order_by,
         selection: Some(selection),
         aggregate_selection: None,
+        cursor_pagination: None,
+        distinct_on: None,
+        group_by: None,
     })
 }
 
@@ -249,6 +263,7 @@ pub fn generate_aggregate_model_selection_ir<'s>(
     model: &'s metadata_resolve::ModelWithPermissions,
     model_source: &'s metadata_resolve::ModelSource,
     model_name: &Qualified<open_dds::models::ModelName>,
+    additional_filter: Option<Expression<'s>>,
     object_types: &'s BTreeMap<
         Qualified<open_dds::types::CustomTypeName>,
         metadata_resolve::ObjectTypeWithRelationships,
@@ -257,6 +272,7 @@ pub fn generate_aggregate_model_selection_ir<'s>(
     request_headers: &reqwest::header::HeaderMap,
     usage_counts: &mut UsagesCounts,
 ) -> Result<ModelSelection<'s>, error::Error> {
+    permissions::check_aggregation_permission(&field_call.info)?;
     count_model(model_name, usage_counts);
 
     let mut arguments = read_model_select_aggregate_arguments(
@@ -278,7 +294,7 @@ pub fn generate_aggregate_model_selection_ir<'s>(
 
     let query_filter = filter::QueryFilter {
         where_clause: arguments.filter_input_arguments.filter_clause,
-        additional_filter: None,
+        additional_filter,
     };
 
     model_aggregate_selection_ir(
@@ -288,7 +304,10 @@ pub fn generate_aggregate_model_selection_ir<'s>(
         arguments.model_arguments,
         query_filter,
         permissions::get_select_filter_predicate(&field_call.info)?,
-        arguments.filter_input_arguments.limit,
+        permissions::apply_select_permission_limit(
+            &field_call.info,
+            arguments.filter_input_arguments.limit,
+        )?,
         arguments.filter_input_arguments.offset,
         arguments.filter_input_arguments.order_by,
         &session.variables,
@@ -538,10 +557,55 @@ fn model_aggregate_selection_ir<'s>(
         order_by,
         selection: None,
         aggregate_selection: Some(aggregate_selection),
+        cursor_pagination: None,
+        distinct_on: None,
+        group_by: None,
+    })
+}
+
+/// Generates the IR fragment for selecting the groups of the rows of a model.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn model_group_by_selection_ir<'s>(
+    model_source: &'s metadata_resolve::ModelSource,
+    arguments: BTreeMap<DataConnectorArgumentName, UnresolvedArgument<'s>>,
+    query_filter: filter::QueryFilter<'s>,
+    permissions_predicate: &'s metadata_resolve::FilterPermission,
+    limit: Option<u32>,
+    grouping: plan_types::Grouping,
+    session_variables: &SessionVariables,
+    usage_counts: &mut UsagesCounts,
+) -> Result<ModelSelection<'s>, error::Error> {
+    let permission_filter = permissions::build_model_permissions_filter_predicate(
+        &model_source.data_connector,
+        &model_source.type_mappings,
+        permissions_predicate,
+        session_variables,
+        usage_counts,
+    )?;
+
+    let filter_clause = filter::FilterExpression {
+        query_filter,
+        permission_filter,
+        relationship_join_filter: None,
+    };
+
+    Ok(ModelSelection {
+        data_connector: model_source.data_connector.clone(),
+        collection: &model_source.collection,
+        arguments,
+        filter_clause,
+        limit,
+        offset: None,
+        order_by: None,
+        selection: None,
+        aggregate_selection: None,
+        cursor_pagination: None,
+        distinct_on: None,
+        group_by: Some(grouping),
     })
 }
 
-fn get_field_mappings_for_object_type<'s>(
+pub(crate) fn get_field_mappings_for_object_type<'s>(
     model_source: &'s metadata_resolve::ModelSource,
     data_type: &Qualified<CustomTypeName>,
 ) -> Result<&'s BTreeMap<open_dds::types::FieldName, metadata_resolve::FieldMapping>, error::Error>
@@ -36,6 +36,14 @@ pub fn get_all_usage_counts_in_query(ir: &IR<'_, '_>) -> UsagesCounts {
                         let usage_counts = ir.usage_counts.clone();
                         extend_usage_count(usage_counts, &mut all_usage_counts);
                     }
+                    root_field::QueryRootField::ModelSelectConnection { ir, .. } => {
+                        let usage_counts = ir.usage_counts.clone();
+                        extend_usage_count(usage_counts, &mut all_usage_counts);
+                    }
+                    root_field::QueryRootField::ModelSelectGroupBy { ir, .. } => {
+                        let usage_counts = ir.usage_counts.clone();
+                        extend_usage_count(usage_counts, &mut all_usage_counts);
+                    }
                     root_field::QueryRootField::NodeSelect(ir1) => match ir1 {
                         None => {}
                         Some(ir2) => {
@@ -66,6 +74,10 @@ pub fn get_all_usage_counts_in_query(ir: &IR<'_, '_>) -> UsagesCounts {
                         let usage_counts = ir.command_info.usage_counts.clone();
                         extend_usage_count(usage_counts, &mut all_usage_counts);
                     }
+                    root_field::MutationRootField::ModelMutation { ir, .. } => {
+                        let usage_counts = ir.usage_counts.clone();
+                        extend_usage_count(usage_counts, &mut all_usage_counts);
+                    }
                 }
             }
         }
@@ -82,6 +94,10 @@ pub fn get_all_usage_counts_in_query(ir: &IR<'_, '_>) -> UsagesCounts {
                 let usage_counts = ir.usage_counts.clone();
                 extend_usage_count(usage_counts, &mut all_usage_counts);
             }
+            root_field::SubscriptionRootField::ModelSelectStream { ir, .. } => {
+                let usage_counts = ir.usage_counts.clone();
+                extend_usage_count(usage_counts, &mut all_usage_counts);
+            }
         },
     }
     all_usage_counts
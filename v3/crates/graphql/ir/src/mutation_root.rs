@@ -1,5 +1,7 @@
 //! IR of the mutation root type
 
+pub mod model_mutation;
+
 use hasura_authn_core::Session;
 use indexmap::IndexMap;
 use lang_graphql as gql;
@@ -77,7 +79,7 @@ pub fn generate_ir<'n, 's>(
                                             field,
                                             field_call,
                                             result_type,
-                                            *result_base_type_kind,
+                                            result_base_type_kind.clone(),
                                             command,
                                             source,
                                             &metadata.models,
@@ -95,8 +97,11 @@ pub fn generate_ir<'n, 's>(
                                             field,
                                             field_call,
                                             result_type,
-                                            *result_base_type_kind,
+                                            result_base_type_kind.clone(),
                                             source,
+                                            command.permissions.get(&session.role).and_then(
+                                                |permission| permission.output_filter.as_ref(),
+                                            ),
                                             &session.variables,
                                             request_headers,
                                         )?
@@ -104,6 +109,28 @@ pub fn generate_ir<'n, 's>(
                                 },
                             })
                         }
+                        Annotation::Output(OutputAnnotation::RootField(
+                            RootFieldAnnotation::ModelMutation {
+                                data_type,
+                                kind,
+                                name,
+                                procedure_name,
+                            },
+                        )) => Ok(root_field::MutationRootField::ModelMutation {
+                            selection_set: &field.selection_set,
+                            ir: model_mutation::generate_model_mutation_ir(
+                                &type_name,
+                                field,
+                                field_call,
+                                *kind,
+                                data_type,
+                                name,
+                                procedure_name,
+                                metadata,
+                                session,
+                                request_headers,
+                            )?,
+                        }),
                         annotation => Err(error::InternalEngineError::UnexpectedAnnotation {
                             annotation: annotation.clone(),
                         }),
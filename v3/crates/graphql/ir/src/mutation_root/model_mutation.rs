@@ -0,0 +1,329 @@
+//! IR for the generated mutations of a model
+//!
+//! The insert, update and delete mutations of a model call procedures of its data connector.
+//! The select permission of the model acts as the check on the affected rows: the update and
+//! delete mutations only affect the rows it allows, and the inserted or updated rows must
+//! satisfy it.
+
+use hasura_authn_core::Session;
+use indexmap::IndexMap;
+use lang_graphql::ast::common as ast;
+use lang_graphql::normalized_ast;
+use metadata_resolve::{Qualified, QualifiedBaseType, QualifiedTypeName, QualifiedTypeReference};
+use open_dds::commands::ProcedureName;
+use open_dds::data_connector::DataConnectorColumnName;
+use open_dds::types::{CustomTypeName, DataConnectorArgumentName};
+use plan::{
+    apply_input_field_presets_to_partial_object, apply_input_field_presets_to_value, count_model,
+    UnresolvedArgument,
+};
+use plan_types::{
+    ComparisonTarget, ComparisonValue, Expression, LocalFieldComparison, NdcFieldAlias,
+    UsagesCounts,
+};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::arguments;
+use crate::error;
+use crate::filter;
+use crate::model_selection;
+use crate::permissions;
+use crate::selection_set::{self, FieldSelection, NestedSelection, ResultSelectionSet};
+use graphql_schema::{
+    Annotation, BooleanExpressionAnnotation, InputAnnotation, ModelInputAnnotation,
+    ModelMutationKind, ModelMutationResponseOutputAnnotation, OutputAnnotation,
+    RootFieldAnnotation, GDS,
+};
+
+/// IR for a generated mutation of a model
+#[derive(Serialize, Debug)]
+pub struct ModelMutation<'s> {
+    /// The name of the field as published in the schema
+    pub field_name: ast::Name,
+
+    /// The model whose objects are affected by the mutation
+    pub model_name: Arc<Qualified<open_dds::models::ModelName>>,
+
+    /// The procedure of the data connector that performs the mutation
+    pub procedure_name: &'s ProcedureName,
+
+    /// The data connector backing the model
+    pub data_connector: Arc<metadata_resolve::DataConnectorLink>,
+
+    /// The arguments of the procedure
+    pub arguments: BTreeMap<DataConnectorArgumentName, UnresolvedArgument<'s>>,
+
+    /// The selection of the result of the procedure, with the number of affected rows and the
+    /// affected rows
+    pub selection: NestedSelection<'s>,
+
+    /// All the models/commands used in the mutation, including the ones used via
+    /// relationships in the selection of the affected rows
+    pub usage_counts: UsagesCounts,
+}
+
+/// Generates the IR for a generated mutation of a model
+pub fn generate_model_mutation_ir<'n, 's>(
+    type_name: &ast::TypeName,
+    field: &'n normalized_ast::Field<'s, GDS>,
+    field_call: &'n normalized_ast::FieldCall<'s, GDS>,
+    kind: ModelMutationKind,
+    data_type: &Qualified<CustomTypeName>,
+    model_name: &Qualified<open_dds::models::ModelName>,
+    procedure_name: &'s ProcedureName,
+    metadata: &'s metadata_resolve::Metadata,
+    session: &Session,
+    request_headers: &reqwest::header::HeaderMap,
+) -> Result<ModelMutation<'s>, error::Error> {
+    let model = metadata.models.get(model_name).ok_or_else(|| {
+        error::InternalEngineError::InternalGeneric {
+            description: format!("Model {model_name} not found"),
+        }
+    })?;
+    let model_source = model.model.source.as_deref().ok_or_else(|| {
+        error::InternalDeveloperError::NoSourceDataConnector {
+            type_name: type_name.clone(),
+            field_name: field_call.name.clone(),
+        }
+    })?;
+
+    let mut usage_counts = UsagesCounts::new();
+    count_model(model_name, &mut usage_counts);
+
+    let mut arguments = BTreeMap::new();
+    let mut pre_check = Vec::new();
+
+    for argument in field_call.arguments.values() {
+        match argument.info.generic {
+            Annotation::Input(InputAnnotation::Model(
+                ModelInputAnnotation::ModelInsertObjectsArgument,
+            )) => {
+                let objects_type = QualifiedTypeReference {
+                    underlying_type: QualifiedBaseType::List(Box::new(QualifiedTypeReference {
+                        underlying_type: QualifiedBaseType::Named(QualifiedTypeName::Custom(
+                            data_type.clone(),
+                        )),
+                        nullable: false,
+                    })),
+                    nullable: false,
+                };
+                let mut objects = arguments::map_argument_value_to_ndc_type(
+                    &objects_type,
+                    &argument.value,
+                    &model_source.type_mappings,
+                )?;
+                apply_input_field_presets_to_value(
+                    &mut objects,
+                    &objects_type,
+                    &model_source.type_mappings,
+                    &metadata.object_types,
+                    session,
+                )?;
+                arguments.insert(
+                    DataConnectorArgumentName::from(metadata_resolve::MUTATION_OBJECTS_ARGUMENT),
+                    UnresolvedArgument::Literal { value: objects },
+                );
+            }
+            Annotation::Input(InputAnnotation::Model(ModelInputAnnotation::ModelSetArgument)) => {
+                let set_type = QualifiedTypeReference {
+                    underlying_type: QualifiedBaseType::Named(QualifiedTypeName::Custom(
+                        data_type.clone(),
+                    )),
+                    nullable: false,
+                };
+                let mut update_columns = arguments::map_argument_value_to_ndc_type(
+                    &set_type,
+                    &argument.value,
+                    &model_source.type_mappings,
+                )?;
+                let update_columns_object = update_columns.as_object_mut().ok_or_else(|| {
+                    error::InternalEngineError::InternalGeneric {
+                        description: "the new values of the fields of an update are not an object"
+                            .to_string(),
+                    }
+                })?;
+                apply_input_field_presets_to_partial_object(
+                    update_columns_object,
+                    data_type,
+                    &model_source.type_mappings,
+                    &metadata.object_types,
+                    session,
+                )?;
+                arguments.insert(
+                    DataConnectorArgumentName::from(
+                        metadata_resolve::MUTATION_UPDATE_COLUMNS_ARGUMENT,
+                    ),
+                    UnresolvedArgument::Literal {
+                        value: update_columns,
+                    },
+                );
+            }
+            Annotation::Input(InputAnnotation::Model(
+                ModelInputAnnotation::ModelUniqueIdentifierArgument {
+                    field_name,
+                    ndc_column,
+                },
+            )) => {
+                let ndc_column = ndc_column.as_ref().ok_or_else(|| {
+                    error::InternalEngineError::InternalGeneric {
+                        description: format!(
+                            "Missing NDC column mapping for unique identifier argument {} on field {}",
+                            argument.name, field_call.name
+                        ),
+                    }
+                })?;
+                let field_type = &model
+                    .model
+                    .type_fields
+                    .get(field_name)
+                    .ok_or_else(|| error::InternalEngineError::InternalGeneric {
+                        description: format!(
+                            "Field {field_name} of unique identifier argument {} not found in model {model_name}",
+                            argument.name
+                        ),
+                    })?
+                    .field_type;
+                let value = arguments::map_argument_value_to_ndc_type(
+                    field_type,
+                    &argument.value,
+                    &model_source.type_mappings,
+                )?;
+                pre_check.push(Expression::LocalField(
+                    LocalFieldComparison::BinaryComparison {
+                        column: ComparisonTarget::Column {
+                            name: ndc_column.column.clone(),
+                            field_path: vec![],
+                        },
+                        operator: ndc_column.equal_operator.clone(),
+                        value: ComparisonValue::Scalar { value },
+                    },
+                ));
+            }
+            Annotation::Input(InputAnnotation::BooleanExpression(
+                BooleanExpressionAnnotation::BooleanExpressionRootField,
+            )) => {
+                if !argument.value.is_null() {
+                    pre_check.push(filter::resolve_filter_expression(
+                        argument.value.as_object()?,
+                        &model_source.data_connector,
+                        &model_source.type_mappings,
+                        &session.variables,
+                        &mut usage_counts,
+                    )?);
+                }
+            }
+            annotation => Err(error::InternalEngineError::UnexpectedAnnotation {
+                annotation: annotation.clone(),
+            })?,
+        }
+    }
+
+    // The select permission of the model is the row check of the mutation
+    let permission_filter = permissions::build_model_permissions_filter_predicate(
+        &model_source.data_connector,
+        &model_source.type_mappings,
+        permissions::get_select_filter_predicate(&field_call.info)?,
+        &session.variables,
+        &mut usage_counts,
+    )?;
+
+    match kind {
+        ModelMutationKind::Insert => {}
+        ModelMutationKind::UpdateByUniqueKey
+        | ModelMutationKind::Update
+        | ModelMutationKind::Delete => {
+            pre_check.extend(permission_filter.clone());
+            arguments.insert(
+                DataConnectorArgumentName::from(metadata_resolve::MUTATION_PRE_CHECK_ARGUMENT),
+                UnresolvedArgument::BooleanExpression {
+                    predicate: Expression::mk_and(pre_check),
+                },
+            );
+        }
+    }
+    match kind {
+        ModelMutationKind::Insert
+        | ModelMutationKind::UpdateByUniqueKey
+        | ModelMutationKind::Update => {
+            arguments.insert(
+                DataConnectorArgumentName::from(metadata_resolve::MUTATION_POST_CHECK_ARGUMENT),
+                UnresolvedArgument::BooleanExpression {
+                    predicate: permission_filter.unwrap_or_else(|| Expression::mk_and(vec![])),
+                },
+            );
+        }
+        ModelMutationKind::Delete => {}
+    }
+
+    let field_mappings =
+        model_selection::get_field_mappings_for_object_type(model_source, data_type)?;
+    let mut response_fields = IndexMap::new();
+    for response_field in field.selection_set.fields.values() {
+        let response_field_call = response_field.field_call()?;
+        match response_field_call.info.generic {
+            Annotation::Output(OutputAnnotation::ModelMutationResponse(
+                ModelMutationResponseOutputAnnotation::AffectedRows,
+            )) => {
+                response_fields.insert(
+                    NdcFieldAlias::from(response_field.alias.0.as_str()),
+                    FieldSelection::Column {
+                        column: DataConnectorColumnName::from(
+                            metadata_resolve::MUTATION_AFFECTED_ROWS_FIELD,
+                        ),
+                        nested_selection: None,
+                        arguments: BTreeMap::new(),
+                    },
+                );
+            }
+            Annotation::Output(OutputAnnotation::ModelMutationResponse(
+                ModelMutationResponseOutputAnnotation::Returning,
+            )) => {
+                let returning_selection = selection_set::generate_selection_set_ir(
+                    &response_field.selection_set,
+                    metadata_resolve::FieldNestedness::NotNested,
+                    &model_source.data_connector,
+                    &model_source.type_mappings,
+                    field_mappings,
+                    &metadata.models,
+                    &metadata.commands,
+                    &metadata.object_types,
+                    session,
+                    request_headers,
+                    &mut usage_counts,
+                )?;
+                response_fields.insert(
+                    NdcFieldAlias::from(response_field.alias.0.as_str()),
+                    FieldSelection::Column {
+                        column: DataConnectorColumnName::from(
+                            metadata_resolve::MUTATION_RETURNING_FIELD,
+                        ),
+                        nested_selection: Some(NestedSelection::Array(Box::new(
+                            NestedSelection::Object(returning_selection),
+                        ))),
+                        arguments: BTreeMap::new(),
+                    },
+                );
+            }
+            Annotation::Output(OutputAnnotation::RootField(RootFieldAnnotation::Introspection)) => {
+                // `__typename` is added while processing the response
+            }
+            annotation => Err(error::InternalEngineError::UnexpectedAnnotation {
+                annotation: annotation.clone(),
+            })?,
+        }
+    }
+
+    Ok(ModelMutation {
+        field_name: field_call.name.clone(),
+        model_name: Arc::new(model_name.clone()),
+        procedure_name,
+        data_connector: model_source.data_connector.clone(),
+        arguments,
+        selection: NestedSelection::Object(ResultSelectionSet {
+            fields: response_fields,
+        }),
+        usage_counts,
+    })
+}
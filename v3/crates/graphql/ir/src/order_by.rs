@@ -6,15 +6,18 @@ use graphql_schema::{Annotation, InputAnnotation, ModelInputAnnotation};
 use hasura_authn_core::SessionVariables;
 use indexmap::IndexMap;
 use lang_graphql::normalized_ast::{self as normalized_ast, InputField, Value};
-use open_dds::data_connector::DataConnectorColumnName;
+use metadata_resolve::{FieldMapping, Qualified, QualifiedTypeName, TypeMapping};
+use open_dds::data_connector::{DataConnectorColumnName, DataConnectorName};
+use open_dds::relationships::RelationshipType;
+use open_dds::types::{CustomTypeName, FieldName};
 use plan::count_model;
 use plan_types::{
-    Expression, LocalModelRelationshipInfo, NdcRelationshipName, OrderByDirection, OrderByElement,
-    OrderByTarget, RelationshipPathElement, UsagesCounts,
+    AggregateFieldSelection, Expression, LocalModelRelationshipInfo, NdcRelationshipName,
+    OrderByDirection, OrderByElement, OrderByTarget, RelationshipPathElement, UsagesCounts,
 };
 use serde::Serialize;
 
-use crate::{error, permissions};
+use crate::{aggregates, error, permissions};
 use graphql_schema::GDS;
 
 #[derive(Debug, Serialize, Clone, PartialEq)]
@@ -166,10 +169,7 @@ pub fn build_ndc_order_by_element<'s>(
                     })?;
 
                 let order_element = OrderByElement {
-                    order_direction: match order_direction {
-                        graphql_schema::ModelOrderByDirection::Asc => OrderByDirection::Asc,
-                        graphql_schema::ModelOrderByDirection::Desc => OrderByDirection::Desc,
-                    },
+                    order_direction: to_order_by_direction(order_direction),
                     target: OrderByTarget::Column {
                         relationship_path: relationship_path.iter().copied().cloned().collect(),
                         // The column name is the root column
@@ -234,6 +234,8 @@ pub fn build_ndc_order_by_element<'s>(
                             target_source: &target_source.model,
                             target_type,
                             mappings,
+                            // relationships with argument mappings can't be orderable
+                            argument_mappings: &[],
                         },
                     );
 
@@ -262,19 +264,45 @@ pub fn build_ndc_order_by_element<'s>(
                         .chain([&relationship_path_element])
                         .collect::<Vec<_>>();
 
-                    let new_order_by_elements = build_ndc_order_by_element(
-                        &object_field.value,
-                        *multiple_input_properties,
-                        &[], // Field path resets as we pass through a relationship
-                        &new_relationship_path,
-                        relationships,
-                        session_variables,
-                        usage_counts,
-                        &target_source.model.type_mappings,
-                        data_connector_link,
-                        data_type,
-                    )?;
-                    order_by_elements.extend(new_order_by_elements);
+                    match relationship_type {
+                        // Array relationships are ordered by an aggregate of the related rows
+                        RelationshipType::Array => {
+                            let target_type_mappings = &target_source.model.type_mappings;
+                            let (order_direction, aggregate) = build_ndc_aggregate_order_by(
+                                &object_field.value,
+                                &QualifiedTypeName::Custom(target_type.clone()),
+                                &target_source.model.data_connector.name,
+                                &[],
+                                target_type_mappings,
+                                get_field_mappings(target_type_mappings, target_type),
+                            )?;
+                            order_by_elements.push(OrderByElement {
+                                order_direction,
+                                target: OrderByTarget::Aggregate {
+                                    relationship_path: new_relationship_path
+                                        .into_iter()
+                                        .cloned()
+                                        .collect(),
+                                    aggregate,
+                                },
+                            });
+                        }
+                        RelationshipType::Object => {
+                            let new_order_by_elements = build_ndc_order_by_element(
+                                &object_field.value,
+                                *multiple_input_properties,
+                                &[], // Field path resets as we pass through a relationship
+                                &new_relationship_path,
+                                relationships,
+                                session_variables,
+                                usage_counts,
+                                &target_source.model.type_mappings,
+                                data_connector_link,
+                                data_type,
+                            )?;
+                            order_by_elements.extend(new_order_by_elements);
+                        }
+                    }
                 } else {
                     Err(error::InternalEngineError::InternalGeneric {
                         description: "Remote relationships are not supported in order_by".into(),
@@ -507,14 +535,7 @@ pub fn build_order_by_element_open_dd_ir<'s>(
                         }),
                         nested: None,
                     });
-                let direction = match order_direction {
-                    graphql_schema::ModelOrderByDirection::Asc => {
-                        open_dds::models::OrderByDirection::Asc
-                    }
-                    graphql_schema::ModelOrderByDirection::Desc => {
-                        open_dds::models::OrderByDirection::Desc
-                    }
-                };
+                let direction = to_open_dd_order_by_direction(order_direction);
 
                 let order_element = open_dds::query::OrderByElement { direction, operand };
 
@@ -526,7 +547,7 @@ pub fn build_order_by_element_open_dd_ir<'s>(
                 graphql_schema::ModelInputAnnotation::ModelOrderByRelationshipArgument(
                     OrderByRelationshipAnnotation {
                         relationship_name,
-                        relationship_type: _,
+                        relationship_type,
                         source_type: _,
                         object_type_name,
                         target_source,
@@ -564,25 +585,45 @@ pub fn build_order_by_element_open_dd_ir<'s>(
                         offset: None,
                     };
 
-                    let new_order_by_elements = build_order_by_element_open_dd_ir(
-                        &object_field.value,
-                        *multiple_input_properties,
-                        usage_counts,
-                        data_connector_link,
-                        data_type,
-                    )?;
+                    match relationship_type {
+                        // Array relationships are ordered by an aggregate of the related rows
+                        RelationshipType::Array => {
+                            let (direction, function, operand) =
+                                build_aggregate_order_by_open_dd_ir(&object_field.value)?;
+                            let operand = open_dds::query::Operand::RelationshipAggregate(
+                                open_dds::query::RelationshipAggregateOperand {
+                                    target: Box::new(relationship_target),
+                                    aggregate: Box::new(open_dds::query::Aggregate {
+                                        function,
+                                        operand,
+                                    }),
+                                },
+                            );
+                            order_by_elements
+                                .push(open_dds::query::OrderByElement { direction, operand });
+                        }
+                        RelationshipType::Object => {
+                            let new_order_by_elements = build_order_by_element_open_dd_ir(
+                                &object_field.value,
+                                *multiple_input_properties,
+                                usage_counts,
+                                data_connector_link,
+                                data_type,
+                            )?;
 
-                    for element in new_order_by_elements {
-                        let operand = open_dds::query::Operand::Relationship(
-                            open_dds::query::RelationshipOperand {
-                                target: Box::new(relationship_target.clone()),
-                                nested: Some(Box::new(element.operand)),
-                            },
-                        );
-                        order_by_elements.push(open_dds::query::OrderByElement {
-                            direction: element.direction,
-                            operand,
-                        });
+                            for element in new_order_by_elements {
+                                let operand = open_dds::query::Operand::Relationship(
+                                    open_dds::query::RelationshipOperand {
+                                        target: Box::new(relationship_target.clone()),
+                                        nested: Some(Box::new(element.operand)),
+                                    },
+                                );
+                                order_by_elements.push(open_dds::query::OrderByElement {
+                                    direction: element.direction,
+                                    operand,
+                                });
+                            }
+                        }
                     }
                 } else {
                     Err(error::InternalEngineError::InternalGeneric {
@@ -661,3 +702,211 @@ pub fn build_order_by_element_open_dd_ir<'s>(
     }
     Ok(order_by_elements)
 }
+
+pub(crate) fn get_order_by_direction(
+    value: &Value<'_, GDS>,
+) -> Result<graphql_schema::ModelOrderByDirection, error::Error> {
+    match &value.as_enum()?.info.generic {
+        Annotation::Input(InputAnnotation::Model(
+            ModelInputAnnotation::ModelOrderByDirection { direction },
+        )) => Ok(direction.clone()),
+        &annotation => Err(error::InternalEngineError::UnexpectedAnnotation {
+            annotation: annotation.clone(),
+        })?,
+    }
+}
+
+pub(crate) fn to_order_by_direction(
+    direction: &graphql_schema::ModelOrderByDirection,
+) -> OrderByDirection {
+    match direction {
+        graphql_schema::ModelOrderByDirection::Asc => OrderByDirection::Asc,
+        graphql_schema::ModelOrderByDirection::Desc => OrderByDirection::Desc,
+        graphql_schema::ModelOrderByDirection::AscNullsFirst => OrderByDirection::AscNullsFirst,
+        graphql_schema::ModelOrderByDirection::AscNullsLast => OrderByDirection::AscNullsLast,
+        graphql_schema::ModelOrderByDirection::DescNullsFirst => OrderByDirection::DescNullsFirst,
+        graphql_schema::ModelOrderByDirection::DescNullsLast => OrderByDirection::DescNullsLast,
+    }
+}
+
+fn to_open_dd_order_by_direction(
+    direction: &graphql_schema::ModelOrderByDirection,
+) -> open_dds::models::OrderByDirection {
+    match direction {
+        graphql_schema::ModelOrderByDirection::Asc => open_dds::models::OrderByDirection::Asc,
+        graphql_schema::ModelOrderByDirection::Desc => open_dds::models::OrderByDirection::Desc,
+        graphql_schema::ModelOrderByDirection::AscNullsFirst => {
+            open_dds::models::OrderByDirection::AscNullsFirst
+        }
+        graphql_schema::ModelOrderByDirection::AscNullsLast => {
+            open_dds::models::OrderByDirection::AscNullsLast
+        }
+        graphql_schema::ModelOrderByDirection::DescNullsFirst => {
+            open_dds::models::OrderByDirection::DescNullsFirst
+        }
+        graphql_schema::ModelOrderByDirection::DescNullsLast => {
+            open_dds::models::OrderByDirection::DescNullsLast
+        }
+    }
+}
+
+fn get_field_mappings<'s>(
+    type_mappings: &'s BTreeMap<Qualified<CustomTypeName>, TypeMapping>,
+    type_name: &Qualified<CustomTypeName>,
+) -> Option<&'s BTreeMap<FieldName, FieldMapping>> {
+    type_mappings
+        .get(type_name)
+        .map(|TypeMapping::Object { field_mappings, .. }| field_mappings)
+}
+
+// Build the aggregate to order by from the aggregate ordering of an array relationship.
+// For eg: '{Tracks: {Milliseconds: {_max: Desc}}}' will order by the `max` aggregation
+// function of the data connector over the `Milliseconds` column of the related tracks.
+pub(crate) fn build_ndc_aggregate_order_by<'s>(
+    input_field_value: &Value<'s, GDS>,
+    aggregate_operand_type: &QualifiedTypeName,
+    data_connector_name: &Qualified<DataConnectorName>,
+    column_path: &[&'s FieldMapping],
+    type_mappings: &'s BTreeMap<Qualified<CustomTypeName>, TypeMapping>,
+    field_mappings: Option<&'s BTreeMap<FieldName, FieldMapping>>,
+) -> Result<(OrderByDirection, AggregateFieldSelection), error::Error> {
+    let input_object_fields = input_field_value.as_object()?;
+
+    // An aggregate ordering must pick exactly one aggregation to order by
+    let (Some(object_field), 1) = (
+        input_object_fields.values().next(),
+        input_object_fields.len(),
+    ) else {
+        return Err(error::Error::OrderByObjectShouldExactlyHaveOneKeyValuePair);
+    };
+
+    match object_field.info.generic {
+        Annotation::Input(InputAnnotation::Model(
+            ModelInputAnnotation::ModelOrderByAggregationFunction(aggregate_function),
+        )) => {
+            let order_direction =
+                to_order_by_direction(&get_order_by_direction(&object_field.value)?);
+            let aggregate = aggregates::make_aggregation_function_selection(
+                aggregate_function,
+                aggregate_operand_type,
+                data_connector_name,
+                column_path,
+            )?;
+            Ok((order_direction, aggregate))
+        }
+        Annotation::Input(InputAnnotation::Model(
+            ModelInputAnnotation::ModelOrderByAggregatableField {
+                field_name,
+                aggregate_operand_type: field_aggregate_operand_type,
+            },
+        )) => {
+            let field_mapping = field_mappings
+                .ok_or_else(|| {
+                    error::InternalDeveloperError::AggregatableFieldFoundOnScalarTypedOperand {
+                        field_name: field_name.clone(),
+                        aggregate_operand_type: aggregate_operand_type.clone(),
+                    }
+                })?
+                .get(field_name)
+                .ok_or_else(|| error::InternalEngineError::InternalGeneric {
+                    description: format!("invalid field in annotation: {field_name}"),
+                })?;
+            let column_path = column_path
+                .iter()
+                .copied()
+                .chain(std::iter::once(field_mapping))
+                .collect::<Vec<_>>();
+
+            // If the type name is not in the object type mappings or is inbuilt, it is a scalar type
+            // and therefore does not have field mappings
+            let field_operand_field_mappings = match field_aggregate_operand_type {
+                QualifiedTypeName::Custom(custom_type_name) => {
+                    get_field_mappings(type_mappings, custom_type_name)
+                }
+                QualifiedTypeName::Inbuilt(_) => None,
+            };
+
+            build_ndc_aggregate_order_by(
+                &object_field.value,
+                aggregate_operand_type,
+                data_connector_name,
+                &column_path,
+                type_mappings,
+                field_operand_field_mappings,
+            )
+        }
+        annotation => Err(error::InternalEngineError::UnexpectedAnnotation {
+            annotation: annotation.clone(),
+        })?,
+    }
+}
+
+// Build the OpenDD aggregate to order by from the aggregate ordering of an array relationship,
+// returning the order direction, the aggregation function and the (possibly nested) field
+// whose values are aggregated
+fn build_aggregate_order_by_open_dd_ir(
+    input_field_value: &Value<'_, GDS>,
+) -> Result<
+    (
+        open_dds::models::OrderByDirection,
+        open_dds::query::AggregationFunction,
+        Option<open_dds::query::Operand>,
+    ),
+    error::Error,
+> {
+    let input_object_fields = input_field_value.as_object()?;
+
+    // An aggregate ordering must pick exactly one aggregation to order by
+    let (Some(object_field), 1) = (
+        input_object_fields.values().next(),
+        input_object_fields.len(),
+    ) else {
+        return Err(error::Error::OrderByObjectShouldExactlyHaveOneKeyValuePair);
+    };
+
+    match object_field.info.generic {
+        Annotation::Input(InputAnnotation::Model(
+            ModelInputAnnotation::ModelOrderByAggregationFunction(aggregate_function),
+        )) => {
+            let direction =
+                to_open_dd_order_by_direction(&get_order_by_direction(&object_field.value)?);
+            let function = match aggregate_function {
+                graphql_schema::AggregationFunctionAnnotation::Count => {
+                    open_dds::query::AggregationFunction::Count {}
+                }
+                graphql_schema::AggregationFunctionAnnotation::CountDistinct => {
+                    open_dds::query::AggregationFunction::CountDistinct {}
+                }
+                graphql_schema::AggregationFunctionAnnotation::Function {
+                    function_name,
+                    aggregate_expression,
+                    data_connector_functions: _,
+                } => open_dds::query::AggregationFunction::Custom {
+                    name: function_name.clone(),
+                    expression: aggregate_expression.clone(),
+                },
+            };
+            Ok((direction, function, None))
+        }
+        Annotation::Input(InputAnnotation::Model(
+            ModelInputAnnotation::ModelOrderByAggregatableField {
+                field_name,
+                aggregate_operand_type: _,
+            },
+        )) => {
+            let (direction, function, nested) =
+                build_aggregate_order_by_open_dd_ir(&object_field.value)?;
+            let operand = open_dds::query::Operand::Field(open_dds::query::ObjectFieldOperand {
+                target: Box::new(open_dds::query::ObjectFieldTarget {
+                    field_name: field_name.clone(),
+                    arguments: IndexMap::new(),
+                }),
+                nested: nested.map(Box::new),
+            });
+            Ok((direction, function, Some(operand)))
+        }
+        annotation => Err(error::InternalEngineError::UnexpectedAnnotation {
+            annotation: annotation.clone(),
+        })?,
+    }
+}
@@ -2,6 +2,7 @@ use hasura_authn_core::SessionVariables;
 use lang_graphql::schema;
 use std::collections::BTreeMap;
 
+use open_dds::arguments::ArgumentName;
 use open_dds::types::CustomTypeName;
 
 use crate::error;
@@ -23,7 +24,8 @@ pub(crate) fn get_select_filter_predicate<'s>(
             graphql_schema::NamespaceAnnotation::Model { filter, .. } => Some(filter),
             graphql_schema::NamespaceAnnotation::NodeFieldTypeMappings(_)
             | graphql_schema::NamespaceAnnotation::EntityTypeMappings(_)
-            | graphql_schema::NamespaceAnnotation::Command(_)
+            | graphql_schema::NamespaceAnnotation::Command { .. }
+            | graphql_schema::NamespaceAnnotation::ObjectField { .. }
             | graphql_schema::NamespaceAnnotation::InputFieldPresets { .. } => None,
         })
         // If we're hitting this case, it means that the caller of this
@@ -36,6 +38,100 @@ pub(crate) fn get_select_filter_predicate<'s>(
         )))
 }
 
+/// Cap the requested limit with the limit of the select permission found in the
+/// namespace annotation of the field call. The limit of the permission also applies
+/// when no limit is requested.
+pub(crate) fn apply_select_permission_limit(
+    node_info: &schema::NodeInfo<'_, GDS>,
+    limit: Option<u32>,
+) -> Result<Option<u32>, error::Error> {
+    let permission_limit = node_info
+        .namespaced
+        .as_ref()
+        .and_then(|annotation| match annotation {
+            graphql_schema::NamespaceAnnotation::Model { limit, .. } => Some(*limit),
+            graphql_schema::NamespaceAnnotation::NodeFieldTypeMappings(_)
+            | graphql_schema::NamespaceAnnotation::EntityTypeMappings(_)
+            | graphql_schema::NamespaceAnnotation::Command { .. }
+            | graphql_schema::NamespaceAnnotation::ObjectField { .. }
+            | graphql_schema::NamespaceAnnotation::InputFieldPresets { .. } => None,
+        })
+        .ok_or(error::Error::Internal(error::InternalError::Engine(
+            error::InternalEngineError::ExpectedNamespaceAnnotationNotFound {
+                namespace_annotation_type: "Limit".to_string(),
+            },
+        )))?;
+    Ok(match (limit, permission_limit) {
+        (Some(limit), Some(permission_limit)) => Some(limit.min(permission_limit)),
+        (limit, None) | (None, limit) => limit,
+    })
+}
+
+/// Check that the select permission found in the namespace annotation of the
+/// field call allows aggregating the model.
+pub(crate) fn check_aggregation_permission(
+    node_info: &schema::NodeInfo<'_, GDS>,
+) -> Result<(), error::Error> {
+    // The aggregate fields are not generated in the schema for roles that are not allowed
+    // to aggregate the model. This is just a double check.
+    if let Some(graphql_schema::NamespaceAnnotation::Model {
+        allow_aggregations: false,
+        ..
+    }) = node_info.namespaced
+    {
+        Err(error::InternalEngineError::AggregationNotAllowed)?;
+    }
+    Ok(())
+}
+
+/// Fetch the presets of a field's arguments from the namespace annotation
+/// of the field call, if the role has any.
+pub(crate) fn get_field_argument_presets<'s>(
+    node_info: &schema::NodeInfo<'s, GDS>,
+) -> Option<
+    &'s BTreeMap<
+        ArgumentName,
+        (
+            metadata_resolve::QualifiedTypeReference,
+            metadata_resolve::ValueExpression,
+        ),
+    >,
+> {
+    node_info
+        .namespaced
+        .as_ref()
+        .and_then(|annotation| match annotation {
+            graphql_schema::NamespaceAnnotation::ObjectField {
+                argument_presets, ..
+            } => Some(argument_presets),
+            graphql_schema::NamespaceAnnotation::Model { .. }
+            | graphql_schema::NamespaceAnnotation::NodeFieldTypeMappings(_)
+            | graphql_schema::NamespaceAnnotation::EntityTypeMappings(_)
+            | graphql_schema::NamespaceAnnotation::Command { .. }
+            | graphql_schema::NamespaceAnnotation::InputFieldPresets { .. } => None,
+        })
+}
+
+/// Fetch the condition under which a field is accessible from the namespace annotation
+/// of the field call, if the field is only accessible on some objects.
+pub(crate) fn get_field_condition<'s>(
+    node_info: &schema::NodeInfo<'s, GDS>,
+) -> Option<&'s metadata_resolve::FieldCondition> {
+    node_info
+        .namespaced
+        .as_ref()
+        .and_then(|annotation| match annotation {
+            graphql_schema::NamespaceAnnotation::ObjectField { condition, .. } => {
+                condition.as_ref()
+            }
+            graphql_schema::NamespaceAnnotation::Model { .. }
+            | graphql_schema::NamespaceAnnotation::NodeFieldTypeMappings(_)
+            | graphql_schema::NamespaceAnnotation::EntityTypeMappings(_)
+            | graphql_schema::NamespaceAnnotation::Command { .. }
+            | graphql_schema::NamespaceAnnotation::InputFieldPresets { .. } => None,
+        })
+}
+
 pub fn build_model_permissions_filter_predicate<'s>(
     model_data_connector_link: &'s metadata_resolve::DataConnectorLink,
     model_type_mappings: &'s BTreeMap<Qualified<CustomTypeName>, metadata_resolve::TypeMapping>,
@@ -7,14 +7,17 @@ mod order_by;
 mod relationships;
 mod selection_set;
 mod types;
+use crate::cursor_pagination::CursorPaginationInput;
+use crate::distinct_on::DistinctOnInput;
 use crate::query_root::apollo_federation::ModelEntitySelection;
 use crate::query_root::node_field::ModelNodeSelection;
 use crate::query_root::select_aggregate::ModelSelectAggregateSelection;
 use crate::query_root::select_many::ModelSelectManySelection;
 use crate::query_root::select_one::ModelSelectOneSelection;
+use crate::subscription_root::select_stream::StreamCursorInput;
 use crate::{
-    ApolloFederationRootFields, MutationRootField, ProcedureBasedCommand, QueryRootField,
-    SubscriptionRootField, IR,
+    ApolloFederationRootFields, ModelMutation, MutationRootField, ProcedureBasedCommand,
+    QueryRootField, SubscriptionRootField, IR,
 };
 pub use error::Error;
 use graphql_schema::{GDSRoleNamespaceGetter, GDS};
@@ -23,14 +26,28 @@ use indexmap::IndexMap;
 use lang_graphql as gql;
 pub use metadata_resolve::Metadata;
 use plan_types::{
-    CommandReturnKind, ExecutionTree, NDCMutationExecution, NDCQueryExecution,
-    NDCSubscriptionExecution, ProcessResponseAs, QueryExecutionPlan, UniqueNumber,
+    AggregateFieldSelection, CommandReturnKind, ComparisonTarget, ComparisonValue,
+    CursorPagination, Dimension, ExecutionTree, Field, FieldsSelection, GroupOrderByElement,
+    GroupOrderByTarget, Grouping, LocalFieldComparison, NDCMutationExecution, NDCQueryExecution,
+    NDCSubscriptionExecution, NdcFieldAlias, OrderByDirection, OrderByElement, OrderByTarget,
+    ProcessResponseAs, QueryExecutionPlan, QueryNodeNew, ResolvedFilterExpression, StreamCursor,
+    UniqueNumber,
 };
+use std::collections::BTreeMap;
 pub use types::{
     ApolloFederationSelect, MutationPlan, MutationSelect, NodeQueryPlan, Plan, QueryPlan,
     RequestPlan, SubscriptionSelect,
 };
 
+/// The alias the cursor column of a streaming subscription is fetched with
+const STREAM_CURSOR_NDC_ALIAS: &str = "hasura_stream_cursor";
+
+/// The prefix of the aliases the ordering columns of a cursor paginated query are fetched with
+const PAGINATION_CURSOR_NDC_ALIAS_PREFIX: &str = "hasura_pagination_cursor";
+
+/// The prefix of the aliases of the dimensions the distinct rows of a query are grouped by
+const DISTINCT_ON_NDC_ALIAS_PREFIX: &str = "hasura_distinct_on";
+
 /// Build a plan to handle a given GraphQL request. This plan will either be a mutation plan or a query
 /// plan, but currently can't be both.
 pub fn generate_request_plan<'n, 's, 'ir>(
@@ -70,6 +87,21 @@ pub fn generate_request_plan<'n, 's, 'ir>(
                             .type_names
                             .insert(alias.clone(), type_name.clone());
                     }
+                    MutationRootField::ModelMutation { selection_set, ir } => {
+                        let plan = plan_model_mutation(
+                            selection_set,
+                            ir,
+                            metadata,
+                            session,
+                            request_headers,
+                            &mut unique_number,
+                        )?;
+                        mutation_plan
+                            .nodes
+                            .entry(plan.mutation_execution.data_connector.clone())
+                            .or_default()
+                            .insert(alias.clone(), plan);
+                    }
                     MutationRootField::ProcedureBasedCommand { selection_set, ir } => {
                         let plan = plan_mutation(
                             selection_set,
@@ -140,11 +172,52 @@ fn plan_mutation<'n, 's>(
                     CommandReturnKind::Object
                 },
                 response_config: ir.command_info.data_connector.response_config.clone(),
+                check_output_filter: ir.command_info.output_filter.is_some(),
+                type_discrimination: ir.command_info.type_discrimination.clone(),
             },
         },
     })
 }
 
+// Plan the execution of a generated mutation of a model
+fn plan_model_mutation<'n, 's>(
+    selection_set: &'n gql::normalized_ast::SelectionSet<'s, GDS>,
+    ir: &ModelMutation<'s>,
+    metadata: &'s Metadata,
+    session: &Session,
+    request_headers: &reqwest::header::HeaderMap,
+    unique_number: &mut UniqueNumber,
+) -> Result<MutationSelect<'n, 's>, error::Error> {
+    let Plan {
+        inner: ndc_ir,
+        join_locations,
+        remote_predicates,
+    } = commands::plan_model_mutation_execution(
+        ir,
+        metadata,
+        session,
+        request_headers,
+        unique_number,
+    )?;
+
+    // _should not_ happen but let's fail rather than do a query with missing filters
+    if !remote_predicates.0.is_empty() {
+        return Err(error::Error::RemotePredicatesAreNotSupportedInMutations);
+    }
+
+    Ok(MutationSelect {
+        selection_set,
+        mutation_execution: NDCMutationExecution {
+            execution_node: ndc_ir,
+            join_locations,
+            data_connector: ir.data_connector.clone(),
+            execution_span_attribute: "execute_model_mutation".into(),
+            field_span_attribute: ir.field_name.to_string(),
+            process_response_as: ProcessResponseAs::ModelMutationResponse,
+        },
+    })
+}
+
 fn plan_subscription<'s, 'ir>(
     root_field: &'ir SubscriptionRootField<'_, 's>,
     metadata: &'s Metadata,
@@ -198,6 +271,7 @@ fn plan_subscription<'s, 'ir>(
                     process_response_as: ProcessResponseAs::Object {
                         is_nullable: ir.type_container.nullable.to_owned(),
                     },
+                    stream_cursor: None,
                 },
             })
         }
@@ -207,35 +281,13 @@ fn plan_subscription<'s, 'ir>(
             selection_set,
             polling_interval_ms,
         } => {
-            let execution_tree = match ir.model_selection {
-                ModelSelectManySelection::Ir(ref model_selection) => {
-                    model_selection::plan_query_execution(
-                        model_selection,
-                        metadata,
-                        session,
-                        request_headers,
-                        unique_number,
-                    )
-                }
-                ModelSelectManySelection::OpenDd(ref model_selection) => {
-                    // TODO: expose more specific function in `plan` for just model selections
-                    let single_node_execution_plan = plan::query_to_plan(
-                        &open_dds::query::Query::Model(model_selection.clone()),
-                        metadata,
-                        session,
-                        request_headers,
-                        unique_number,
-                    )?;
-                    match single_node_execution_plan {
-                        plan::SingleNodeExecutionPlan::Query(execution_tree) => Ok(execution_tree),
-                        plan::SingleNodeExecutionPlan::Mutation(_) => {
-                            // we should use a more specific planning function to avoid
-                            // this as it _should not_ happen
-                            Err(error::Error::PlanExpectedQueryGotMutation)
-                        }
-                    }
-                }
-            }?;
+            let execution_tree = plan_select_many_selection(
+                &ir.model_selection,
+                metadata,
+                session,
+                request_headers,
+                unique_number,
+            )?;
             let query_execution_plan = reject_remote_joins(execution_tree)?;
             Ok(SubscriptionSelect {
                 selection_set,
@@ -247,6 +299,7 @@ fn plan_subscription<'s, 'ir>(
                     process_response_as: ProcessResponseAs::Array {
                         is_nullable: ir.type_container.nullable.to_owned(),
                     },
+                    stream_cursor: None,
                 },
             })
         }
@@ -294,10 +347,366 @@ fn plan_subscription<'s, 'ir>(
                     execution_span_attribute: "execute_model_select_aggregate",
                     field_span_attribute: ir.field_name.to_string(),
                     process_response_as: ProcessResponseAs::Aggregates,
+                    stream_cursor: None,
                 },
             })
         }
+
+        SubscriptionRootField::ModelSelectStream {
+            ir,
+            selection_set,
+            polling_interval_ms,
+        } => {
+            let execution_tree = plan_select_many_selection(
+                &ir.model_selection,
+                metadata,
+                session,
+                request_headers,
+                unique_number,
+            )?;
+            let mut query_execution_plan = reject_remote_joins(execution_tree)?;
+            let stream_cursor =
+                plan_stream_cursor(&mut query_execution_plan.query_node, &ir.cursor);
+            Ok(SubscriptionSelect {
+                selection_set,
+                subscription_execution: NDCSubscriptionExecution {
+                    query_execution_plan,
+                    polling_interval_ms: *polling_interval_ms,
+                    execution_span_attribute: "execute_model_select_stream",
+                    field_span_attribute: ir.field_name.to_string(),
+                    process_response_as: ProcessResponseAs::Array {
+                        is_nullable: ir.type_container.nullable.to_owned(),
+                    },
+                    stream_cursor: Some(stream_cursor),
+                },
+            })
+        }
+    }
+}
+
+fn plan_select_many_selection(
+    model_selection: &ModelSelectManySelection<'_>,
+    metadata: &Metadata,
+    session: &Session,
+    request_headers: &reqwest::header::HeaderMap,
+    unique_number: &mut UniqueNumber,
+) -> Result<ExecutionTree, error::Error> {
+    match model_selection {
+        ModelSelectManySelection::Ir(model_selection) => model_selection::plan_query_execution(
+            model_selection,
+            metadata,
+            session,
+            request_headers,
+            unique_number,
+        ),
+        ModelSelectManySelection::OpenDd(model_selection) => {
+            // TODO: expose more specific function in `plan` for just model selections
+            let single_node_execution_plan = plan::query_to_plan(
+                &open_dds::query::Query::Model(model_selection.clone()),
+                metadata,
+                session,
+                request_headers,
+                unique_number,
+            )?;
+            match single_node_execution_plan {
+                plan::SingleNodeExecutionPlan::Query(execution_tree) => Ok(execution_tree),
+                plan::SingleNodeExecutionPlan::Mutation(_) => {
+                    // we should use a more specific planning function to avoid
+                    // this as it _should not_ happen
+                    Err(error::Error::PlanExpectedQueryGotMutation)
+                }
+            }
+        }
+    }
+}
+
+/// Orders the rows of a streaming subscription by its cursor column, and fetches the cursor
+/// column along with the selected fields, so that the cursor can be advanced after each batch.
+fn plan_stream_cursor(query_node: &mut QueryNodeNew, cursor: &StreamCursorInput) -> StreamCursor {
+    let column = cursor.cursor_field.ndc_column.clone();
+    query_node.order_by = Some(vec![OrderByElement {
+        order_direction: OrderByDirection::Asc,
+        target: OrderByTarget::Column {
+            relationship_path: vec![],
+            name: column.clone(),
+            field_path: vec![],
+        },
+    }]);
+    let field_alias = NdcFieldAlias::from(STREAM_CURSOR_NDC_ALIAS);
+    query_node
+        .fields
+        .get_or_insert_with(|| FieldsSelection {
+            fields: IndexMap::new(),
+        })
+        .fields
+        .insert(
+            field_alias.clone(),
+            Field::Column {
+                column: column.clone(),
+                fields: None,
+                arguments: BTreeMap::new(),
+            },
+        );
+    StreamCursor {
+        column,
+        greater_than_operator: cursor.cursor_field.greater_than_operator.clone(),
+        field_alias,
+        initial_value: cursor.initial_value.clone(),
+    }
+}
+
+/// The alias the ordering column at the given position is fetched with in a cursor paginated
+/// query, to build the cursors of its rows
+pub fn pagination_cursor_field_alias(index: usize) -> NdcFieldAlias {
+    NdcFieldAlias::from(format!("{PAGINATION_CURSOR_NDC_ALIAS_PREFIX}_{index}").as_str())
+}
+
+/// Selects the distinct rows of a query by grouping its rows on the distinct columns. Each
+/// selected column is fetched with the row aggregation function, which returns its value in the
+/// first row of each group, so that the groups are the distinct rows. The groups are ordered by
+/// the requested ordering on the distinct columns, and the limit and offset apply to the groups.
+fn plan_distinct_on(
+    query_node: &mut QueryNodeNew,
+    input: &DistinctOnInput,
+) -> Result<(), error::Error> {
+    let mut aggregates = IndexMap::new();
+    for (alias, field) in query_node
+        .fields
+        .take()
+        .map_or_else(IndexMap::new, |f| f.fields)
+    {
+        match field {
+            Field::Column {
+                column,
+                fields: None,
+                arguments,
+            } if arguments.is_empty() => {
+                aggregates.insert(
+                    alias,
+                    AggregateFieldSelection::AggregationFunction {
+                        function_name: input.distinct_on.row_aggregation_function.clone(),
+                        column_path: nonempty::NonEmpty::new(column),
+                    },
+                );
+            }
+            Field::Column { .. } | Field::Relationship { .. } => {
+                return Err(error::Error::UnsupportedFieldForDistinctOn)
+            }
+        }
+    }
+    let dimensions = input
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            (
+                NdcFieldAlias::from(format!("{DISTINCT_ON_NDC_ALIAS_PREFIX}_{index}").as_str()),
+                Dimension::Column {
+                    column_path: nonempty::NonEmpty::new(column.clone()),
+                    extraction: None,
+                },
+            )
+        })
+        .collect();
+    // The rows are still ordered by the requested ordering, which decides the first row of each
+    // group, while the groups can only be ordered by the distinct columns
+    let order_by = query_node
+        .order_by
+        .iter()
+        .flatten()
+        .filter_map(|element| match &element.target {
+            OrderByTarget::Column {
+                relationship_path,
+                name,
+                field_path,
+            } if relationship_path.is_empty() && field_path.is_empty() => input
+                .columns
+                .iter()
+                .position(|column| column == name)
+                .map(|index| GroupOrderByElement {
+                    order_direction: element.order_direction.clone(),
+                    target: GroupOrderByTarget::Dimension { index },
+                }),
+            OrderByTarget::Column { .. } | OrderByTarget::Aggregate { .. } => None,
+        })
+        .collect();
+    query_node.group_by = Some(Grouping {
+        aggregates,
+        dimensions,
+        limit: query_node.limit.take(),
+        offset: query_node.offset.take(),
+        order_by,
+    });
+    Ok(())
+}
+
+/// Orders the rows of a cursor paginated query by the requested ordering followed by the unique
+/// identifier, fetches only the rows after (or before) the cursor, and fetches the ordering
+/// columns along with the selected fields, so that the cursors of the page can be built.
+fn plan_cursor_pagination(
+    query_node: &mut QueryNodeNew,
+    input: &CursorPaginationInput,
+) -> Result<CursorPagination, error::Error> {
+    let cursor_fields = &input.cursor_pagination.cursor_fields;
+    // The cursor fields the rows are ordered by, along with whether they are in ascending order
+    let mut ordering: Vec<(&metadata_resolve::CursorField, bool)> = Vec::new();
+    for element in query_node.order_by.take().unwrap_or_default() {
+        let is_ascending = match element.order_direction {
+            OrderByDirection::Asc => true,
+            OrderByDirection::Desc => false,
+            OrderByDirection::AscNullsFirst
+            | OrderByDirection::AscNullsLast
+            | OrderByDirection::DescNullsFirst
+            | OrderByDirection::DescNullsLast => {
+                return Err(error::Error::UnsupportedOrderingForCursorPagination)
+            }
+        };
+        let cursor_field = match &element.target {
+            OrderByTarget::Column {
+                relationship_path,
+                name,
+                field_path,
+            } if relationship_path.is_empty() && field_path.is_empty() => cursor_fields
+                .values()
+                .find(|cursor_field| cursor_field.ndc_column == *name),
+            OrderByTarget::Column { .. } | OrderByTarget::Aggregate { .. } => None,
+        }
+        .ok_or(error::Error::UnsupportedOrderingForCursorPagination)?;
+        // Ordering by a column again has no effect on the order of the rows
+        if !ordering.iter().any(|(field, _)| field == &cursor_field) {
+            ordering.push((cursor_field, is_ascending));
+        }
+    }
+    for field_name in &input.cursor_pagination.unique_identifier {
+        let cursor_field =
+            cursor_fields
+                .get(field_name)
+                .ok_or_else(|| error::InternalError::InternalGeneric {
+                    description: format!(
+                        "cursor field {field_name} of the unique identifier not found"
+                    ),
+                })?;
+        if !ordering.iter().any(|(field, _)| field == &cursor_field) {
+            ordering.push((cursor_field, true));
+        }
+    }
+    // A backwards page is fetched in the reverse order, starting from the cursor
+    if input.backwards {
+        for (_, is_ascending) in &mut ordering {
+            *is_ascending = !*is_ascending;
+        }
+    }
+
+    if let Some(cursor) = &input.cursor {
+        if cursor.len() != ordering.len() {
+            return Err(error::Error::CursorDoesNotMatchOrdering {
+                expected: ordering.len(),
+                found: cursor.len(),
+            });
+        }
+        let cursor_predicate = make_cursor_predicate(&ordering, cursor);
+        query_node.predicate = Some(match query_node.predicate.take() {
+            None => cursor_predicate,
+            Some(predicate) => ResolvedFilterExpression::mk_and(vec![predicate, cursor_predicate]),
+        });
+    }
+
+    query_node.order_by = Some(
+        ordering
+            .iter()
+            .map(|(cursor_field, is_ascending)| OrderByElement {
+                order_direction: if *is_ascending {
+                    OrderByDirection::Asc
+                } else {
+                    OrderByDirection::Desc
+                },
+                target: OrderByTarget::Column {
+                    relationship_path: vec![],
+                    name: cursor_field.ndc_column.clone(),
+                    field_path: vec![],
+                },
+            })
+            .collect(),
+    );
+
+    // One row more than the page size is fetched, to tell whether there are more rows
+    let page_size = query_node.limit;
+    query_node.limit = page_size.map(|page_size| page_size.saturating_add(1));
+
+    let fields = &mut query_node
+        .fields
+        .get_or_insert_with(|| FieldsSelection {
+            fields: IndexMap::new(),
+        })
+        .fields;
+    let cursor_field_aliases = ordering
+        .iter()
+        .enumerate()
+        .map(|(index, (cursor_field, _))| {
+            let field_alias = pagination_cursor_field_alias(index);
+            fields.insert(
+                field_alias.clone(),
+                Field::Column {
+                    column: cursor_field.ndc_column.clone(),
+                    fields: None,
+                    arguments: BTreeMap::new(),
+                },
+            );
+            field_alias
+        })
+        .collect();
+
+    Ok(CursorPagination {
+        page_size,
+        backwards: input.backwards,
+        cursor_field_aliases,
+    })
+}
+
+/// The predicate selecting the rows that come after the cursor in the given ordering. A row
+/// comes after the cursor if, for some ordering column, the row is past the cursor in that
+/// column, and equal to the cursor in all of the preceding columns.
+fn make_cursor_predicate(
+    ordering: &[(&metadata_resolve::CursorField, bool)],
+    cursor: &[serde_json::Value],
+) -> ResolvedFilterExpression {
+    let comparison = |cursor_field: &metadata_resolve::CursorField,
+                      operator: &open_dds::data_connector::DataConnectorOperatorName,
+                      value: &serde_json::Value| {
+        ResolvedFilterExpression::LocalFieldComparison(LocalFieldComparison::BinaryComparison {
+            column: ComparisonTarget::Column {
+                name: cursor_field.ndc_column.clone(),
+                field_path: vec![],
+            },
+            operator: operator.clone(),
+            value: ComparisonValue::Scalar {
+                value: value.clone(),
+            },
+        })
+    };
+    let mut alternatives = Vec::new();
+    for (index, ((cursor_field, is_ascending), value)) in ordering.iter().zip(cursor).enumerate() {
+        let mut conditions = ordering
+            .iter()
+            .zip(cursor)
+            .take(index)
+            .map(|((preceding_field, _), preceding_value)| {
+                comparison(
+                    preceding_field,
+                    &preceding_field.equal_operator,
+                    preceding_value,
+                )
+            })
+            .collect::<Vec<_>>();
+        let past_operator = if *is_ascending {
+            &cursor_field.greater_than_operator
+        } else {
+            &cursor_field.less_than_operator
+        };
+        conditions.push(comparison(cursor_field, past_operator, value));
+        alternatives.push(ResolvedFilterExpression::mk_and(conditions));
     }
+    ResolvedFilterExpression::mk_or(alternatives)
 }
 
 fn reject_remote_joins(tree: ExecutionTree) -> Result<QueryExecutionPlan, error::Error> {
@@ -379,12 +788,13 @@ fn plan_query<'n, 's, 'ir>(
                     process_response_as: ProcessResponseAs::Object {
                         is_nullable: ir.type_container.nullable.to_owned(),
                     },
+                    cursor_pagination: None,
                 },
             }
         }
 
         QueryRootField::ModelSelectMany { ir, selection_set } => {
-            let execution_tree = match ir.model_selection {
+            let mut execution_tree = match ir.model_selection {
                 ModelSelectManySelection::Ir(ref model_selection) => {
                     model_selection::plan_query_execution(
                         model_selection,
@@ -414,6 +824,17 @@ fn plan_query<'n, 's, 'ir>(
                 }
             }?;
 
+            let cursor_pagination = ir
+                .cursor_pagination
+                .as_ref()
+                .map(|cursor_pagination| {
+                    plan_cursor_pagination(
+                        &mut execution_tree.query_execution_plan.query_node,
+                        cursor_pagination,
+                    )
+                })
+                .transpose()?;
+
             NodeQueryPlan::NDCQueryExecution {
                 selection_set,
                 query_execution: NDCQueryExecution {
@@ -423,9 +844,57 @@ fn plan_query<'n, 's, 'ir>(
                     process_response_as: ProcessResponseAs::Array {
                         is_nullable: ir.type_container.nullable.to_owned(),
                     },
+                    cursor_pagination,
                 },
             }
         }
+        QueryRootField::ModelSelectConnection { ir, selection_set } => {
+            let mut execution_tree = model_selection::plan_query_execution(
+                &ir.model_selection,
+                metadata,
+                session,
+                request_headers,
+                unique_number,
+            )?;
+
+            let cursor_pagination = plan_cursor_pagination(
+                &mut execution_tree.query_execution_plan.query_node,
+                &ir.cursor_pagination,
+            )?;
+
+            NodeQueryPlan::NDCQueryExecution {
+                selection_set,
+                query_execution: NDCQueryExecution {
+                    execution_tree,
+                    execution_span_attribute: "execute_model_select_connection",
+                    field_span_attribute: ir.field_name.to_string(),
+                    // The edges and page info of the connection are built from the page of rows
+                    process_response_as: ProcessResponseAs::Connection { cursor_pagination },
+                    cursor_pagination: None,
+                },
+            }
+        }
+        QueryRootField::ModelSelectGroupBy { ir, selection_set } => {
+            let execution_tree = model_selection::plan_query_execution(
+                &ir.model_selection,
+                metadata,
+                session,
+                request_headers,
+                unique_number,
+            )?;
+            NodeQueryPlan::NDCQueryExecution {
+                query_execution: NDCQueryExecution {
+                    execution_tree,
+                    execution_span_attribute: "execute_model_select_group_by",
+                    field_span_attribute: ir.field_name.to_string(),
+                    process_response_as: ProcessResponseAs::Groups {
+                        dimensions: ir.dimension_aliases(),
+                    },
+                    cursor_pagination: None,
+                },
+                selection_set,
+            }
+        }
         QueryRootField::ModelSelectAggregate { ir, selection_set } => {
             let execution_tree = {
                 match &ir.model_selection {
@@ -468,6 +937,7 @@ fn plan_query<'n, 's, 'ir>(
                     execution_span_attribute: "execute_model_select_aggregate",
                     field_span_attribute: ir.field_name.to_string(),
                     process_response_as: ProcessResponseAs::Aggregates,
+                    cursor_pagination: None,
                 },
                 selection_set,
             }
@@ -512,6 +982,7 @@ fn plan_query<'n, 's, 'ir>(
                         execution_span_attribute: "execute_node",
                         field_span_attribute: "node".into(),
                         process_response_as: ProcessResponseAs::Object { is_nullable: true }, // node(id: ID!): Node; the node field is nullable,
+                        cursor_pagination: None,
                     },
                     &ir.selection_set,
                 )))
@@ -542,7 +1013,10 @@ fn plan_query<'n, 's, 'ir>(
                             CommandReturnKind::Object
                         },
                         response_config: ir.command_info.data_connector.response_config.clone(),
+                        check_output_filter: ir.command_info.output_filter.is_some(),
+                        type_discrimination: ir.command_info.type_discrimination.clone(),
                     },
+                    cursor_pagination: None,
                 },
             }
         }
@@ -569,6 +1043,7 @@ fn plan_query<'n, 's, 'ir>(
                         execution_span_attribute: "execute_entity",
                         field_span_attribute: "entity".into(),
                         process_response_as: ProcessResponseAs::Object { is_nullable: true },
+                        cursor_pagination: None,
                     },
                     &ir.selection_set,
                 ));
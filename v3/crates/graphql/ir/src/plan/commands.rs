@@ -7,7 +7,9 @@ use super::arguments;
 use super::error;
 use super::selection_set;
 use crate::plan::Plan;
-use crate::{CommandInfo, CommandSelection, FunctionBasedCommand, ProcedureBasedCommand};
+use crate::{
+    CommandInfo, CommandSelection, FunctionBasedCommand, ModelMutation, ProcedureBasedCommand,
+};
 use hasura_authn_core::Session;
 use metadata_resolve::Metadata;
 use open_dds::commands::ProcedureName;
@@ -185,9 +187,13 @@ pub(crate) fn plan_mutation_execution(
                     // invoking yet
                     Err(error::Error::PlanExpectedMutationGotQuery)
                 }
-                plan::SingleNodeExecutionPlan::Mutation(mutation_execution_plan) => Ok(Plan {
+                plan::SingleNodeExecutionPlan::Mutation(plan_types::MutationExecutionTree {
+                    remote_predicates,
+                    mutation_execution_plan,
+                    remote_join_executions,
+                }) => Ok(Plan {
                     inner: mutation_execution_plan,
-                    join_locations,
+                    join_locations: remote_join_executions,
                     remote_predicates,
                 }),
             }
@@ -237,3 +243,43 @@ pub(crate) fn plan_mutation_execution(
         }
     }
 }
+
+/// Plan the call of the procedure of a generated mutation of a model
+pub(crate) fn plan_model_mutation_execution(
+    ir: &ModelMutation<'_>,
+    metadata: &'_ Metadata,
+    session: &Session,
+    request_headers: &reqwest::header::HeaderMap,
+    unique_number: &mut UniqueNumber,
+) -> Result<Plan<MutationExecutionPlan>, error::Error> {
+    let mut collection_relationships = BTreeMap::new();
+    let Plan {
+        inner: fields,
+        join_locations,
+        remote_predicates,
+    } = selection_set::plan_nested_selection(
+        &ir.selection,
+        ir.data_connector.capabilities.supported_ndc_version,
+        &mut collection_relationships,
+        metadata,
+        session,
+        request_headers,
+        unique_number,
+    )?;
+    let mutation_request = MutationExecutionPlan {
+        procedure_name: ir.procedure_name.clone(),
+        procedure_arguments: arguments::plan_mutation_arguments(
+            &ir.arguments,
+            &mut collection_relationships,
+            unique_number,
+        )?,
+        procedure_fields: Some(fields),
+        collection_relationships,
+        data_connector: ir.data_connector.clone(),
+    };
+    Ok(Plan {
+        inner: mutation_request,
+        join_locations,
+        remote_predicates,
+    })
+}
@@ -8,6 +8,15 @@ pub enum Error {
     #[error("remote joins are not supported in subscriptions")]
     RemoteJoinsAreNotSupportedSubscriptions,
 
+    #[error("cursor pagination only supports ordering by non-nullable scalar fields of the model mapped to columns with 'equal', 'greater than' and 'less than' operators, in ascending or descending order")]
+    UnsupportedOrderingForCursorPagination,
+
+    #[error("the cursor does not match the ordering of the query: it has {found} values, but the rows are ordered by {expected} fields. A cursor can only be used with the ordering it was returned for")]
+    CursorDoesNotMatchOrdering { expected: usize, found: usize },
+
+    #[error("distinct_on only supports selecting scalar fields mapped to columns, and remote relationships")]
+    UnsupportedFieldForDistinctOn,
+
     #[error("remote predicates are not supported in mutations")]
     RemotePredicatesAreNotSupportedInMutations,
 
@@ -32,7 +41,10 @@ impl TraceableError for Error {
         match self {
             Self::Internal(_internal) => tracing_util::ErrorVisibility::Internal,
             Self::OpenDdPlanError(error) => error.visibility(),
-            Self::RemoteJoinsAreNotSupportedSubscriptions => tracing_util::ErrorVisibility::User,
+            Self::RemoteJoinsAreNotSupportedSubscriptions
+            | Self::UnsupportedOrderingForCursorPagination
+            | Self::CursorDoesNotMatchOrdering { .. }
+            | Self::UnsupportedFieldForDistinctOn => tracing_util::ErrorVisibility::User,
             Self::RemotePredicatesAreNotSupportedInMutations
             | Self::PlanExpectedMutationGotQuery
             | Self::PlanExpectedQueryGotMutation => tracing_util::ErrorVisibility::Internal,
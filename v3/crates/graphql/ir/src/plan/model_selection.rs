@@ -66,16 +66,26 @@ pub(crate) fn plan_query_node(
 
     remote_predicates.0.extend(order_by_remote_predicates.0);
 
-    let query_node = QueryNodeNew {
+    let mut query_node = QueryNodeNew {
         limit: ir.limit,
         offset: ir.offset,
         order_by,
         predicate,
         aggregates: ir.aggregate_selection.clone(),
         fields: query_fields.map(|fields| FieldsSelection { fields }),
-        group_by: None,
+        group_by: ir.group_by.clone(),
     };
 
+    // The page of a relationship connection is trimmed and turned into edges while processing
+    // the response, from the ordering fields fetched here
+    if let Some(cursor_pagination) = &ir.cursor_pagination {
+        super::plan_cursor_pagination(&mut query_node, cursor_pagination)?;
+    }
+
+    if let Some(distinct_on) = &ir.distinct_on {
+        super::plan_distinct_on(&mut query_node, distinct_on)?;
+    }
+
     Ok(Plan {
         inner: query_node,
         join_locations,
@@ -60,38 +60,62 @@ fn plan_order_by_target(
             field_path,
             relationship_path,
         } => plan_types::OrderByTarget::Column {
-            relationship_path: relationship_path
-                .iter()
-                .map(|element| {
-                    Ok(plan_types::RelationshipPathElement {
-                        field_path: element.field_path.clone(),
-                        relationship_name: element.relationship_name.clone(),
-                        filter_predicate: element
-                            .filter_predicate
-                            .as_ref()
-                            .map(|pred| {
-                                plan_expression(
-                                    pred,
-                                    relationships,
-                                    remote_predicates,
-                                    unique_number,
-                                )
-                                .map_err(|plan_error| {
-                                    plan_error::Error::Internal(
-                                        plan_error::InternalError::InternalGeneric {
-                                            description: plan_error.to_string(),
-                                        },
-                                    )
-                                })
-                            })
-                            .transpose()?,
-                    })
-                })
-                .collect::<Result<Vec<_>, plan_error::Error>>()?,
+            relationship_path: plan_relationship_path(
+                relationship_path,
+                relationships,
+                unique_number,
+                remote_predicates,
+            )?,
             name: name.clone(),
             field_path: field_path.clone(),
         },
+        plan_types::OrderByTarget::Aggregate {
+            relationship_path,
+            aggregate,
+        } => plan_types::OrderByTarget::Aggregate {
+            relationship_path: plan_relationship_path(
+                relationship_path,
+                relationships,
+                unique_number,
+                remote_predicates,
+            )?,
+            aggregate: aggregate.clone(),
+        },
     };
 
     Ok(target)
 }
+
+fn plan_relationship_path(
+    relationship_path: &[plan_types::RelationshipPathElement<plan_types::Expression<'_>>],
+    relationships: &mut BTreeMap<NdcRelationshipName, Relationship>,
+    unique_number: &mut UniqueNumber,
+    remote_predicates: &mut PredicateQueryTrees,
+) -> Result<
+    Vec<plan_types::RelationshipPathElement<plan_types::ResolvedFilterExpression>>,
+    plan_error::Error,
+> {
+    relationship_path
+        .iter()
+        .map(|element| {
+            Ok(plan_types::RelationshipPathElement {
+                field_path: element.field_path.clone(),
+                relationship_name: element.relationship_name.clone(),
+                filter_predicate: element
+                    .filter_predicate
+                    .as_ref()
+                    .map(|pred| {
+                        plan_expression(pred, relationships, remote_predicates, unique_number)
+                            .map_err(|plan_error| {
+                                plan_error::Error::Internal(
+                                    plan_error::InternalError::InternalGeneric {
+                                        description: plan_error.to_string(),
+                                    },
+                                )
+                            })
+                    })
+                    .transpose()?,
+            })
+        })
+        .collect()
+}
@@ -12,9 +12,9 @@ use metadata_resolve::{FieldMapping, Metadata};
 use open_dds::data_connector::DataConnectorColumnName;
 use plan::{process_command_relationship_definition, process_model_relationship_definition};
 use plan_types::{
-    CommandReturnKind, ExecutionTree, Field, JoinLocations, JoinNode, Location, LocationKind,
-    NestedArray, NestedField, NestedObject, PredicateQueryTrees, RemoteJoin, RemoteJoinType,
-    SourceFieldAlias, TargetField,
+    make_model_argument_variable_name, Argument, CommandReturnKind, ExecutionTree, Field,
+    JoinLocations, JoinNode, Location, LocationKind, NestedArray, NestedField, NestedObject,
+    PredicateQueryTrees, RemoteJoin, RemoteJoinType, SourceFieldAlias, TargetField,
 };
 use plan_types::{NdcFieldAlias, NdcRelationshipName, Relationship, UniqueNumber};
 use std::collections::{BTreeMap, HashMap};
@@ -174,10 +174,15 @@ pub(crate) fn plan_selection_set(
 
                 remote_predicates.0.extend(relationship_remote_predicates.0);
 
+                let (relationship_arguments, argument_remote_predicates) =
+                    arguments::plan_arguments(&query.arguments, relationships, unique_number)?;
+
+                remote_predicates.0.extend(argument_remote_predicates.0);
+
                 let ndc_field = Field::Relationship {
                     query_node: Box::new(relationship_query),
                     relationship: name.clone(),
-                    arguments: BTreeMap::new(),
+                    arguments: relationship_arguments,
                 };
                 if !jl.locations.is_empty() {
                     join_locations.locations.insert(
@@ -257,15 +262,15 @@ pub(crate) fn plan_selection_set(
                 relationship_info,
             } => {
                 let mut join_mapping = HashMap::new();
-                for ((src_field_alias, src_field), target_field) in &relationship_info.join_mapping
-                {
+                for (src_field, target_field) in &relationship_info.join_mapping {
                     let ndc_field_alias = process_remote_relationship_field_mapping(
                         model_selection,
-                        src_field,
+                        &src_field.field_mapping,
+                        &src_field.nested_columns,
                         &mut fields,
                     );
                     join_mapping.insert(
-                        src_field_alias.clone(),
+                        src_field.field_path.clone(),
                         (
                             ndc_field_alias,
                             TargetField::ModelField(target_field.clone()),
@@ -274,7 +279,7 @@ pub(crate) fn plan_selection_set(
                 }
                 // Construct the `JoinLocations` tree
                 let ExecutionTree {
-                    query_execution_plan: query_execution,
+                    query_execution_plan: mut query_execution,
                     remote_join_executions: sub_join_locations,
                     remote_predicates: model_remote_predicates,
                 } = model_selection::plan_query_execution(
@@ -285,6 +290,31 @@ pub(crate) fn plan_selection_set(
                     unique_number,
                 )?;
 
+                // Add the arguments on which the join is done to the model arguments
+                for (src_field, (argument_name, ndc_argument_name)) in
+                    &relationship_info.argument_join_mapping
+                {
+                    let ndc_field_alias = process_remote_relationship_field_mapping(
+                        model_selection,
+                        &src_field.field_mapping,
+                        &src_field.nested_columns,
+                        &mut fields,
+                    );
+                    join_mapping.insert(
+                        src_field.field_path.clone(),
+                        (
+                            ndc_field_alias,
+                            TargetField::ModelArgument(argument_name.clone()),
+                        ),
+                    );
+                    query_execution.arguments.insert(
+                        ndc_argument_name.clone(),
+                        Argument::Variable {
+                            name: make_model_argument_variable_name(argument_name),
+                        },
+                    );
+                }
+
                 // push any remote predicates to the outer list
                 remote_predicates.0.extend(model_remote_predicates.0);
 
@@ -309,15 +339,15 @@ pub(crate) fn plan_selection_set(
             } => {
                 let mut join_mapping = HashMap::new();
 
-                for ((src_field_alias, src_field), target_field) in &relationship_info.join_mapping
-                {
+                for (src_field, target_field) in &relationship_info.join_mapping {
                     let ndc_field_alias = process_remote_relationship_field_mapping(
                         model_selection,
-                        src_field,
+                        &src_field.field_mapping,
+                        &src_field.nested_columns,
                         &mut fields,
                     );
                     join_mapping.insert(
-                        src_field_alias.clone(),
+                        src_field.field_path.clone(),
                         (
                             ndc_field_alias,
                             TargetField::CommandField(target_field.clone()),
@@ -353,6 +383,10 @@ pub(crate) fn plan_selection_set(
                             CommandReturnKind::Object
                         },
                         response_config: ir.command_info.data_connector.response_config.clone(),
+                        // the output filter and the member types of a command relationship
+                        // are resolved when processing the relationship field of the source object
+                        check_output_filter: false,
+                        type_discrimination: None,
                     },
                     remote_join_type: RemoteJoinType::ToCommand,
                 };
@@ -380,12 +414,22 @@ pub(crate) fn plan_selection_set(
 ///   (with an internal alias), and return the alias
 /// - if the selection set already contains the field, do not insert the field
 ///   in NDC IR, and return the existing alias
+///
+/// When the join value is a field of nested objects in the column, the whole
+/// column is always selected under the internal alias, as the selection set
+/// may not select the nested field.
 fn process_remote_relationship_field_mapping(
     selection: &ResultSelectionSet<'_>,
     field_mapping: &FieldMapping,
+    nested_columns: &[DataConnectorColumnName],
     fields: &mut IndexMap<NdcFieldAlias, Field>,
 ) -> SourceFieldAlias {
-    match selection.contains(field_mapping) {
+    let existing_field_alias = if nested_columns.is_empty() {
+        selection.contains(field_mapping)
+    } else {
+        None
+    };
+    match existing_field_alias {
         None => {
             let internal_alias = make_hasura_phantom_field(&field_mapping.column);
             fields.insert(
@@ -396,9 +440,15 @@ fn process_remote_relationship_field_mapping(
                     arguments: BTreeMap::new(),
                 },
             );
-            SourceFieldAlias(internal_alias)
+            SourceFieldAlias {
+                alias: internal_alias,
+                nested_path: nested_columns.to_vec(),
+            }
         }
-        Some(field_alias) => SourceFieldAlias(field_alias.as_str().to_owned()),
+        Some(field_alias) => SourceFieldAlias {
+            alias: field_alias.as_str().to_owned(),
+            nested_path: vec![],
+        },
     }
 }
 
@@ -25,6 +25,8 @@ use graphql_schema::{Annotation, NodeFieldTypeNameMapping, OutputAnnotation, Roo
 pub mod apollo_federation;
 pub mod node_field;
 pub mod select_aggregate;
+pub mod select_connection;
+pub mod select_group_by;
 pub mod select_many;
 pub mod select_one;
 
@@ -281,6 +283,38 @@ pub fn generate_model_rootfield_ir<'n, 's>(
                 model_name,
             )?,
         },
+        RootFieldKind::SelectConnection => root_field::QueryRootField::ModelSelectConnection {
+            selection_set: &field.selection_set,
+            ir: select_connection::select_connection_generate_ir(
+                request_pipeline,
+                field,
+                field_call,
+                data_type,
+                model,
+                source,
+                models,
+                commands,
+                object_types,
+                session,
+                request_headers,
+                model_name,
+            )?,
+        },
+        RootFieldKind::SelectGroupBy => root_field::QueryRootField::ModelSelectGroupBy {
+            selection_set: &field.selection_set,
+            ir: select_group_by::select_group_by_generate_ir(
+                request_pipeline,
+                field,
+                field_call,
+                data_type,
+                model,
+                source,
+                object_types,
+                session,
+                request_headers,
+                model_name,
+            )?,
+        },
     };
     Ok(ir)
 }
@@ -334,9 +368,10 @@ fn generate_command_rootfield_ir<'n, 's>(
                 field,
                 field_call,
                 result_type,
-                *result_base_type_kind,
+                result_base_type_kind.clone(),
                 command,
                 source,
+                BTreeMap::new(),
                 models,
                 commands,
                 object_types,
@@ -351,8 +386,12 @@ fn generate_command_rootfield_ir<'n, 's>(
                 field,
                 field_call,
                 result_type,
-                *result_base_type_kind,
+                result_base_type_kind.clone(),
                 source,
+                command
+                    .permissions
+                    .get(&session.role)
+                    .and_then(|permission| permission.output_filter.as_ref()),
                 &session.variables,
                 request_headers,
                 &mut usage_counts,
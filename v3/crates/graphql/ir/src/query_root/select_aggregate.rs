@@ -73,6 +73,7 @@ pub(crate) fn select_aggregate_generate_ir<'n, 's>(
                     model,
                     model_source,
                     model_name,
+                    None,
                     object_types,
                     session,
                     request_headers,
@@ -0,0 +1,269 @@
+//! model_source IR for 'select_connection' operation
+//!
+//! A 'select_connection' operation paginates through the rows of a model with a Relay connection
+
+use hasura_authn_core::Session;
+use indexmap::IndexMap;
+use lang_graphql::ast::common as ast;
+use lang_graphql::normalized_ast;
+
+use open_dds;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::arguments;
+use crate::cursor_pagination::{CursorPaginationArguments, CursorPaginationInput, RequestedPage};
+use crate::error;
+use crate::filter;
+use crate::model_selection;
+use crate::order_by::build_ndc_order_by;
+use crate::permissions;
+use crate::GraphqlRequestPipeline;
+use graphql_schema::GDS;
+use graphql_schema::{
+    self, Annotation, BooleanExpressionAnnotation, ConnectionOutputAnnotation,
+    ModelInputAnnotation, OutputAnnotation,
+};
+use metadata_resolve;
+use metadata_resolve::Qualified;
+use plan::{count_model, process_argument_presets_for_model};
+use plan_types::UsagesCounts;
+
+/// IR for the 'select_connection' operation on a model
+#[derive(Debug, Serialize)]
+pub struct ModelSelectConnection<'s> {
+    // The name of the field as published in the schema
+    pub field_name: ast::Name,
+
+    // The selection of the nodes of the connection. The page size is the limit of the
+    // model selection.
+    pub model_selection: model_selection::ModelSelection<'s>,
+
+    // The page of the connection requested with the `first`/`after` or `last`/`before`
+    // arguments. All the rows are in a single page when no page size is requested.
+    pub cursor_pagination: CursorPaginationInput<'s>,
+
+    // All the models/commands used in this operation. This includes the models/commands
+    // used via relationships. And in future, the models/commands used in the filter clause
+    pub usage_counts: UsagesCounts,
+}
+
+/// Generates the IR for a 'select_connection' operation
+pub fn select_connection_generate_ir<'s>(
+    request_pipeline: GraphqlRequestPipeline,
+    field: &normalized_ast::Field<'s, GDS>,
+    field_call: &normalized_ast::FieldCall<'s, GDS>,
+    data_type: &Qualified<open_dds::types::CustomTypeName>,
+    model: &'s metadata_resolve::ModelWithPermissions,
+    model_source: &'s metadata_resolve::ModelSource,
+    models: &'s IndexMap<
+        metadata_resolve::Qualified<open_dds::models::ModelName>,
+        metadata_resolve::ModelWithPermissions,
+    >,
+    commands: &'s IndexMap<
+        metadata_resolve::Qualified<open_dds::commands::CommandName>,
+        metadata_resolve::CommandWithPermissions,
+    >,
+    object_types: &'s BTreeMap<
+        Qualified<open_dds::types::CustomTypeName>,
+        metadata_resolve::ObjectTypeWithRelationships,
+    >,
+    session: &Session,
+    request_headers: &reqwest::header::HeaderMap,
+    model_name: &'s Qualified<open_dds::models::ModelName>,
+) -> Result<ModelSelectConnection<'s>, error::Error> {
+    if request_pipeline == GraphqlRequestPipeline::OpenDd {
+        return Err(error::Error::ConnectionNotSupportedInOpenDdPipeline {
+            field_name: field_call.name.clone(),
+        });
+    }
+    let select_connection = model
+        .graphql_api
+        .select_connection
+        .as_ref()
+        .ok_or_else(|| error::InternalEngineError::InternalGeneric {
+            description: format!("select connection not found for model {model_name}"),
+        })?;
+
+    let mut where_input = None;
+    let mut order_by = None;
+    let mut model_arguments = BTreeMap::new();
+    let mut cursor_pagination_arguments = CursorPaginationArguments::default();
+
+    // Add the name of the root model
+    let mut usage_counts = UsagesCounts::new();
+    count_model(model_name, &mut usage_counts);
+
+    for argument in field_call.arguments.values() {
+        match argument.info.generic {
+            annotation @ Annotation::Input(graphql_schema::InputAnnotation::Model(
+                model_argument_annotation,
+            )) => match model_argument_annotation {
+                ModelInputAnnotation::ModelFirstArgument
+                | ModelInputAnnotation::ModelAfterArgument
+                | ModelInputAnnotation::ModelLastArgument
+                | ModelInputAnnotation::ModelBeforeArgument => {
+                    cursor_pagination_arguments
+                        .read_argument(model_argument_annotation, &argument.value)?;
+                }
+                ModelInputAnnotation::ModelArgumentsExpression => {
+                    for argument in argument.value.as_object()?.values() {
+                        let (ndc_arg_name, ndc_val) = arguments::build_ndc_argument_as_value(
+                            &field_call.name,
+                            argument,
+                            &model_source.type_mappings,
+                            &model_source.data_connector,
+                            &session.variables,
+                            &mut usage_counts,
+                        )?;
+                        model_arguments.insert(ndc_arg_name, ndc_val);
+                    }
+                }
+                ModelInputAnnotation::ModelOrderByExpression => {
+                    order_by = Some(build_ndc_order_by(
+                        argument,
+                        &session.variables,
+                        &mut usage_counts,
+                        &model_source.type_mappings,
+                        &model_source.data_connector,
+                        data_type,
+                    )?);
+                }
+                _ => {
+                    return Err(error::InternalEngineError::UnexpectedAnnotation {
+                        annotation: annotation.clone(),
+                    })?
+                }
+            },
+
+            Annotation::Input(graphql_schema::InputAnnotation::BooleanExpression(
+                BooleanExpressionAnnotation::BooleanExpressionRootField,
+            )) => {
+                where_input = Some(argument.value.as_object()?);
+            }
+
+            annotation => {
+                return Err(error::InternalEngineError::UnexpectedAnnotation {
+                    annotation: annotation.clone(),
+                })?
+            }
+        }
+    }
+
+    let RequestedPage {
+        page_size,
+        backwards,
+        cursor,
+    } = cursor_pagination_arguments
+        .requested_page()?
+        .unwrap_or(RequestedPage {
+            page_size: None,
+            backwards: false,
+            cursor: None,
+        });
+
+    // add any preset arguments from model permissions
+    model_arguments = process_argument_presets_for_model(
+        model_arguments,
+        model,
+        object_types,
+        session,
+        request_headers,
+        &mut usage_counts,
+    )?;
+
+    let where_clause = match where_input {
+        Some(where_input) => Some(filter::resolve_filter_expression(
+            where_input,
+            &model_source.data_connector,
+            &model_source.type_mappings,
+            &session.variables,
+            &mut usage_counts,
+        )?),
+        None => None,
+    };
+
+    let query_filter = filter::QueryFilter {
+        where_clause,
+        additional_filter: None,
+    };
+
+    let empty_selection_set = normalized_ast::SelectionSet {
+        fields: IndexMap::new(),
+        type_name: None,
+    };
+    let node_selection_set = connection_node_selection_set(&field_call.name, &field.selection_set)?
+        .unwrap_or(&empty_selection_set);
+
+    let model_selection = model_selection::model_selection_ir(
+        node_selection_set,
+        data_type,
+        model_source,
+        model_arguments,
+        query_filter,
+        permissions::get_select_filter_predicate(&field_call.info)?,
+        // The page size limits the rows, with the permission limit still applied on top
+        permissions::apply_select_permission_limit(&field_call.info, page_size)?,
+        None,
+        order_by,
+        models,
+        commands,
+        object_types,
+        session,
+        request_headers,
+        // Get all the models/commands that were used as relationships
+        &mut usage_counts,
+    )?;
+
+    Ok(ModelSelectConnection {
+        field_name: field_call.name.clone(),
+        model_selection,
+        cursor_pagination: CursorPaginationInput {
+            cursor_pagination: &select_connection.cursor_pagination,
+            backwards,
+            cursor,
+        },
+        usage_counts,
+    })
+}
+
+/// The selection set of the nodes of a connection, which are selected through its edges.
+/// Returns `None` if no node is selected.
+pub(crate) fn connection_node_selection_set<'a, 's>(
+    field_name: &ast::Name,
+    selection_set: &'a normalized_ast::SelectionSet<'s, GDS>,
+) -> Result<Option<&'a normalized_ast::SelectionSet<'s, GDS>>, error::Error> {
+    let mut node_selection_set = None;
+    for edges_field in selection_set.fields.values() {
+        if !matches!(
+            edges_field.field_call()?.info.generic,
+            Annotation::Output(OutputAnnotation::Connection(
+                ConnectionOutputAnnotation::Edges
+            ))
+        ) {
+            continue;
+        }
+        for node_field in edges_field.selection_set.fields.values() {
+            if !matches!(
+                node_field.field_call()?.info.generic,
+                Annotation::Output(OutputAnnotation::Connection(
+                    ConnectionOutputAnnotation::EdgeNode
+                ))
+            ) {
+                continue;
+            }
+            // All the edges are built from the same rows, so the nodes are only fetched once
+            match node_selection_set {
+                None => node_selection_set = Some(&node_field.selection_set),
+                Some(existing_selection_set)
+                    if existing_selection_set == &node_field.selection_set => {}
+                Some(_) => {
+                    return Err(error::Error::DifferentConnectionNodeSelections {
+                        field_name: field_name.clone(),
+                    })
+                }
+            }
+        }
+    }
+    Ok(node_selection_set)
+}
@@ -0,0 +1,343 @@
+//! model_source IR for 'select_group_by' operation
+//!
+//! A 'select_group_by' operation groups the rows of a model by the requested dimensions, and
+//! fetches the aggregates over each group
+
+use hasura_authn_core::Session;
+use indexmap::IndexMap;
+use lang_graphql::ast::common::{self as ast, Alias};
+use lang_graphql::normalized_ast;
+
+use open_dds;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::aggregates::{add_aggregate_selections, mk_alias_from_graphql_field_path};
+use crate::arguments;
+use crate::error;
+use crate::filter;
+use crate::model_selection;
+use crate::order_by::{
+    build_ndc_aggregate_order_by, get_order_by_direction, to_order_by_direction,
+};
+use crate::permissions;
+use crate::GraphqlRequestPipeline;
+use graphql_schema::GDS;
+use graphql_schema::{
+    self, Annotation, BooleanExpressionAnnotation, GroupByOutputAnnotation, ModelInputAnnotation,
+    OutputAnnotation,
+};
+use metadata_resolve;
+use metadata_resolve::{Qualified, QualifiedTypeName};
+use plan::{count_model, process_argument_presets_for_model};
+use plan_types::{
+    Dimension, GroupOrderByElement, GroupOrderByTarget, Grouping, NdcFieldAlias, UsagesCounts,
+};
+
+/// IR for the 'select_group_by' operation on a model
+#[derive(Debug, Serialize)]
+pub struct ModelSelectGroupBy<'s> {
+    // The name of the field as published in the schema
+    pub field_name: ast::Name,
+
+    // The selection of the rows to group. The dimensions, aggregates and ordering of the
+    // groups are in its grouping.
+    pub model_selection: model_selection::ModelSelection<'s>,
+
+    // All the models/commands used in this operation. This includes the models/commands
+    // used via relationships. And in future, the models/commands used in the filter clause
+    pub usage_counts: UsagesCounts,
+}
+
+impl ModelSelectGroupBy<'_> {
+    /// The aliases of the selected dimensions, in the order their values are returned in each
+    /// group
+    pub fn dimension_aliases(&self) -> Vec<NdcFieldAlias> {
+        self.model_selection
+            .group_by
+            .as_ref()
+            .map(|grouping| grouping.dimensions.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Generates the IR for a 'select_group_by' operation
+pub fn select_group_by_generate_ir<'s>(
+    request_pipeline: GraphqlRequestPipeline,
+    field: &normalized_ast::Field<'s, GDS>,
+    field_call: &normalized_ast::FieldCall<'s, GDS>,
+    data_type: &Qualified<open_dds::types::CustomTypeName>,
+    model: &'s metadata_resolve::ModelWithPermissions,
+    model_source: &'s metadata_resolve::ModelSource,
+    object_types: &'s BTreeMap<
+        Qualified<open_dds::types::CustomTypeName>,
+        metadata_resolve::ObjectTypeWithRelationships,
+    >,
+    session: &Session,
+    request_headers: &reqwest::header::HeaderMap,
+    model_name: &'s Qualified<open_dds::models::ModelName>,
+) -> Result<ModelSelectGroupBy<'s>, error::Error> {
+    if request_pipeline == GraphqlRequestPipeline::OpenDd {
+        return Err(error::Error::GroupByNotSupportedInOpenDdPipeline {
+            field_name: field_call.name.clone(),
+        });
+    }
+    permissions::check_aggregation_permission(&field_call.info)?;
+
+    // Add the name of the root model
+    let mut usage_counts = UsagesCounts::new();
+    count_model(model_name, &mut usage_counts);
+
+    let field_mappings =
+        model_selection::get_field_mappings_for_object_type(model_source, data_type)?;
+    let mut grouping = build_grouping(field, model_source, data_type, field_mappings)?;
+
+    let mut where_input = None;
+    let mut order_by_input = None;
+    let mut model_arguments = BTreeMap::new();
+
+    for argument in field_call.arguments.values() {
+        match argument.info.generic {
+            annotation @ Annotation::Input(graphql_schema::InputAnnotation::Model(
+                model_argument_annotation,
+            )) => match model_argument_annotation {
+                ModelInputAnnotation::ModelArgumentsExpression => {
+                    for argument in argument.value.as_object()?.values() {
+                        let (ndc_arg_name, ndc_val) = arguments::build_ndc_argument_as_value(
+                            &field_call.name,
+                            argument,
+                            &model_source.type_mappings,
+                            &model_source.data_connector,
+                            &session.variables,
+                            &mut usage_counts,
+                        )?;
+                        model_arguments.insert(ndc_arg_name, ndc_val);
+                    }
+                }
+                ModelInputAnnotation::ModelLimitArgument => {
+                    grouping.limit = Some(
+                        argument
+                            .value
+                            .as_int_u32()
+                            .map_err(error::Error::map_unexpected_value_to_external_error)?,
+                    );
+                }
+                ModelInputAnnotation::ModelOffsetArgument => {
+                    grouping.offset = Some(
+                        argument
+                            .value
+                            .as_int_u32()
+                            .map_err(error::Error::map_unexpected_value_to_external_error)?,
+                    );
+                }
+                ModelInputAnnotation::ModelGroupOrderByExpression => {
+                    order_by_input = Some(&argument.value);
+                }
+                _ => {
+                    return Err(error::InternalEngineError::UnexpectedAnnotation {
+                        annotation: annotation.clone(),
+                    })?
+                }
+            },
+
+            Annotation::Input(graphql_schema::InputAnnotation::BooleanExpression(
+                BooleanExpressionAnnotation::BooleanExpressionRootField,
+            )) => {
+                where_input = Some(argument.value.as_object()?);
+            }
+
+            annotation => {
+                return Err(error::InternalEngineError::UnexpectedAnnotation {
+                    annotation: annotation.clone(),
+                })?
+            }
+        }
+    }
+
+    if let Some(order_by_input) = order_by_input {
+        grouping.order_by = build_group_order_by(
+            &field_call.name,
+            order_by_input,
+            &grouping.dimensions,
+            model_source,
+            data_type,
+            field_mappings,
+        )?;
+    }
+
+    // add any preset arguments from model permissions
+    model_arguments = process_argument_presets_for_model(
+        model_arguments,
+        model,
+        object_types,
+        session,
+        request_headers,
+        &mut usage_counts,
+    )?;
+
+    // The where clause filters the rows that are grouped
+    let where_clause = match where_input {
+        Some(where_input) => Some(filter::resolve_filter_expression(
+            where_input,
+            &model_source.data_connector,
+            &model_source.type_mappings,
+            &session.variables,
+            &mut usage_counts,
+        )?),
+        None => None,
+    };
+
+    let query_filter = filter::QueryFilter {
+        where_clause,
+        additional_filter: None,
+    };
+
+    let model_selection = model_selection::model_group_by_selection_ir(
+        model_source,
+        model_arguments,
+        query_filter,
+        permissions::get_select_filter_predicate(&field_call.info)?,
+        // The permission limit still applies to the rows that are grouped
+        permissions::apply_select_permission_limit(&field_call.info, None)?,
+        grouping,
+        &session.variables,
+        &mut usage_counts,
+    )?;
+
+    Ok(ModelSelectGroupBy {
+        field_name: field_call.name.clone(),
+        model_selection,
+        usage_counts,
+    })
+}
+
+/// Builds the dimensions and aggregates of the groups from the fields selected of them
+fn build_grouping<'s>(
+    field: &normalized_ast::Field<'s, GDS>,
+    model_source: &'s metadata_resolve::ModelSource,
+    data_type: &Qualified<open_dds::types::CustomTypeName>,
+    field_mappings: &'s BTreeMap<open_dds::types::FieldName, metadata_resolve::FieldMapping>,
+) -> Result<Grouping, error::Error> {
+    let mut grouping = Grouping::default();
+    for group_field in field.selection_set.fields.values() {
+        match group_field.field_call()?.info.generic {
+            Annotation::Output(OutputAnnotation::GroupBy(GroupByOutputAnnotation::Dimensions)) => {
+                for dimension_field in group_field.selection_set.fields.values() {
+                    match dimension_field.field_call()?.info.generic {
+                        Annotation::Output(OutputAnnotation::GroupBy(
+                            GroupByOutputAnnotation::Dimension { dimension, .. },
+                        )) => {
+                            grouping.dimensions.insert(
+                                mk_alias_from_graphql_field_path(&[
+                                    &group_field.alias,
+                                    &dimension_field.alias,
+                                ]),
+                                Dimension::Column {
+                                    column_path: nonempty::NonEmpty::new(dimension.column.clone()),
+                                    extraction: dimension.extraction.clone(),
+                                },
+                            );
+                        }
+                        Annotation::Output(OutputAnnotation::RootField(
+                            graphql_schema::RootFieldAnnotation::Introspection,
+                        )) => {}
+                        annotation => Err(error::InternalEngineError::UnexpectedAnnotation {
+                            annotation: annotation.clone(),
+                        })?,
+                    }
+                }
+            }
+            Annotation::Output(OutputAnnotation::GroupBy(GroupByOutputAnnotation::Aggregates)) => {
+                let graphql_field_path: [&Alias; 1] = [&group_field.alias];
+                add_aggregate_selections(
+                    &mut grouping.aggregates,
+                    &group_field.selection_set,
+                    &QualifiedTypeName::Custom(data_type.clone()),
+                    &model_source.data_connector.name,
+                    &[], // column_path
+                    &graphql_field_path,
+                    &model_source.type_mappings,
+                    Some(field_mappings),
+                )?;
+            }
+            Annotation::Output(OutputAnnotation::RootField(
+                graphql_schema::RootFieldAnnotation::Introspection,
+            )) => {}
+            annotation => Err(error::InternalEngineError::UnexpectedAnnotation {
+                annotation: annotation.clone(),
+            })?,
+        }
+    }
+    Ok(grouping)
+}
+
+/// Builds the ordering of the groups. Each element of the ordering orders by either a selected
+/// dimension or an aggregate over the rows of the groups.
+fn build_group_order_by<'s>(
+    field_name: &ast::Name,
+    order_by_input: &normalized_ast::Value<'s, GDS>,
+    dimensions: &IndexMap<NdcFieldAlias, Dimension>,
+    model_source: &'s metadata_resolve::ModelSource,
+    data_type: &Qualified<open_dds::types::CustomTypeName>,
+    field_mappings: &'s BTreeMap<open_dds::types::FieldName, metadata_resolve::FieldMapping>,
+) -> Result<Vec<GroupOrderByElement>, error::Error> {
+    let normalized_ast::Value::List(order_by_elements) = order_by_input else {
+        return Err(error::InternalEngineError::InternalGeneric {
+            description: "Expected list of input objects value for order_by".into(),
+        })?;
+    };
+
+    let mut group_order_by = Vec::new();
+    for order_by_element in order_by_elements {
+        let input_object_fields = order_by_element.as_object()?;
+        let (Some(object_field), 1) = (
+            input_object_fields.values().next(),
+            input_object_fields.len(),
+        ) else {
+            return Err(error::Error::OrderByObjectShouldExactlyHaveOneKeyValuePair);
+        };
+
+        let (order_direction, target) = match object_field.info.generic {
+            Annotation::Input(graphql_schema::InputAnnotation::Model(
+                ModelInputAnnotation::ModelGroupOrderByDimension { dimension, .. },
+            )) => {
+                let order_dimension = Dimension::Column {
+                    column_path: nonempty::NonEmpty::new(dimension.column.clone()),
+                    extraction: dimension.extraction.clone(),
+                };
+                // ordering by a dimension that is not selected would split the groups
+                let index = dimensions
+                    .values()
+                    .position(|dimension| *dimension == order_dimension)
+                    .ok_or_else(|| error::Error::GroupOrderByDimensionNotSelected {
+                        field_name: field_name.clone(),
+                    })?;
+                (
+                    to_order_by_direction(&get_order_by_direction(&object_field.value)?),
+                    GroupOrderByTarget::Dimension { index },
+                )
+            }
+            Annotation::Input(graphql_schema::InputAnnotation::Model(
+                ModelInputAnnotation::ModelGroupOrderByAggregates,
+            )) => {
+                let (order_direction, aggregate) = build_ndc_aggregate_order_by(
+                    &object_field.value,
+                    &QualifiedTypeName::Custom(data_type.clone()),
+                    &model_source.data_connector.name,
+                    &[], // column_path
+                    &model_source.type_mappings,
+                    Some(field_mappings),
+                )?;
+                (order_direction, GroupOrderByTarget::Aggregate { aggregate })
+            }
+            annotation => Err(error::InternalEngineError::UnexpectedAnnotation {
+                annotation: annotation.clone(),
+            })?,
+        };
+        group_order_by.push(GroupOrderByElement {
+            order_direction,
+            target,
+        });
+    }
+    Ok(group_order_by)
+}
@@ -13,6 +13,8 @@ use serde::Serialize;
 use std::collections::BTreeMap;
 
 use crate::arguments;
+use crate::cursor_pagination::{CursorPaginationArguments, CursorPaginationInput, RequestedPage};
+use crate::distinct_on::read_distinct_on_argument;
 use crate::error;
 use crate::filter;
 use crate::model_selection;
@@ -40,6 +42,10 @@ pub struct ModelSelectMany<'n, 's> {
 
     pub model_selection: ModelSelectManySelection<'s>,
 
+    // The cursor pagination of the operation, if the page is requested with the `first`/`after`
+    // or `last`/`before` arguments. The page size is the limit of the model selection.
+    pub cursor_pagination: Option<CursorPaginationInput<'s>>,
+
     // The Graphql output type of the operation
     pub type_container: &'n ast::TypeContainer<ast::TypeName>,
 
@@ -78,6 +84,8 @@ pub fn select_many_generate_ir<'n, 's>(
     let mut where_input = None;
     let mut order_by = None;
     let mut model_arguments = BTreeMap::new();
+    let mut cursor_pagination_arguments = CursorPaginationArguments::default();
+    let mut distinct_on = None;
 
     // For opendd execution pipeline
     let mut model_arguments_input = None;
@@ -108,6 +116,23 @@ pub fn select_many_generate_ir<'n, 's>(
                             .map_err(error::Error::map_unexpected_value_to_external_error)?,
                     );
                 }
+                ModelInputAnnotation::ModelFirstArgument
+                | ModelInputAnnotation::ModelAfterArgument
+                | ModelInputAnnotation::ModelLastArgument
+                | ModelInputAnnotation::ModelBeforeArgument => {
+                    cursor_pagination_arguments
+                        .read_argument(model_argument_annotation, &argument.value)?;
+                }
+                ModelInputAnnotation::ModelDistinctOnArgument => {
+                    distinct_on = read_distinct_on_argument(
+                        model
+                            .graphql_api
+                            .select_many
+                            .as_ref()
+                            .and_then(|select_many| select_many.distinct_on.as_ref()),
+                        &argument.value,
+                    )?;
+                }
                 ModelInputAnnotation::ModelArgumentsExpression => match &argument.value {
                     normalized_ast::Value::Object(arguments) => {
                         for argument in arguments.values() {
@@ -161,6 +186,40 @@ pub fn select_many_generate_ir<'n, 's>(
         }
     }
 
+    let cursor_pagination = model
+        .graphql_api
+        .select_many
+        .as_ref()
+        .and_then(|select_many| select_many.cursor_pagination.as_ref());
+    let cursor_pagination = match (
+        cursor_pagination,
+        cursor_pagination_arguments.requested_page()?,
+    ) {
+        (None, _) | (_, None) => None,
+        (
+            Some(cursor_pagination),
+            Some(RequestedPage {
+                page_size,
+                backwards,
+                cursor,
+            }),
+        ) => {
+            if limit.is_some() || offset.is_some() {
+                return Err(error::Error::CursorPaginationWithLimitOrOffset);
+            }
+            if distinct_on.is_some() {
+                return Err(error::Error::DistinctOnWithCursorPagination);
+            }
+            // The page size limits the rows, with the permission limit still applied on top
+            limit = page_size;
+            Some(CursorPaginationInput {
+                cursor_pagination,
+                backwards,
+                cursor,
+            })
+        }
+    };
+
     // add any preset arguments from model permissions
     model_arguments = process_argument_presets_for_model(
         model_arguments,
@@ -173,6 +232,11 @@ pub fn select_many_generate_ir<'n, 's>(
 
     let model_selection = match request_pipeline {
         GraphqlRequestPipeline::OpenDd => {
+            if distinct_on.is_some() {
+                return Err(error::Error::DistinctOnNotSupportedInOpenDdPipeline {
+                    field_name: field_call.name.clone(),
+                });
+            }
             let where_clause = match where_input {
                 Some(where_input) => Some(filter::resolve_filter_expression_open_dd(
                     where_input,
@@ -236,14 +300,14 @@ pub fn select_many_generate_ir<'n, 's>(
                 additional_filter: None,
             };
 
-            ModelSelectManySelection::Ir(model_selection::model_selection_ir(
+            let mut selection_ir = model_selection::model_selection_ir(
                 &field.selection_set,
                 data_type,
                 model_source,
                 model_arguments,
                 query_filter,
                 permissions::get_select_filter_predicate(&field_call.info)?,
-                limit,
+                permissions::apply_select_permission_limit(&field_call.info, limit)?,
                 offset,
                 order_by,
                 models,
@@ -253,13 +317,16 @@ pub fn select_many_generate_ir<'n, 's>(
                 request_headers,
                 // Get all the models/commands that were used as relationships
                 &mut usage_counts,
-            )?)
+            )?;
+            selection_ir.distinct_on = distinct_on;
+            ModelSelectManySelection::Ir(selection_ir)
         }
     };
 
     Ok(ModelSelectMany {
         field_name: field_call.name.clone(),
         model_selection,
+        cursor_pagination,
         type_container: &field.type_container,
         usage_counts,
     })
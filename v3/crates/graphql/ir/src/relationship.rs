@@ -4,14 +4,16 @@ use lang_graphql::normalized_ast::{self, Field};
 use open_dds::{
     arguments::ArgumentName,
     commands::FunctionName,
+    data_connector::DataConnectorColumnName,
     relationships::{RelationshipName, RelationshipType},
-    types::{CustomTypeName, FieldName},
+    types::{CustomTypeName, DataConnectorArgumentName, FieldName},
 };
 use std::collections::BTreeMap;
 
 use serde::Serialize;
 
 use super::{
+    arguments,
     commands::generate_function_based_command,
     filter,
     model_selection::{self, model_selection_ir},
@@ -19,18 +21,21 @@ use super::{
     permissions,
     selection_set::{self, generate_selection_set_open_dd_ir, FieldSelection},
 };
+use crate::cursor_pagination::{CursorPaginationArguments, CursorPaginationInput, RequestedPage};
+use crate::distinct_on::read_distinct_on_argument;
 use crate::error;
 use crate::order_by;
+use crate::query_root::select_connection::connection_node_selection_set;
 use graphql_schema::{
     Annotation, BooleanExpressionAnnotation, CommandRelationshipAnnotation, InputAnnotation,
     ModelAggregateRelationshipAnnotation, ModelInputAnnotation, ModelRelationshipAnnotation, GDS,
 };
 use metadata_resolve::{self, CommandSource, Qualified, RelationshipModelMapping};
-use plan::{count_command, count_model};
+use plan::{count_command, count_model, process_argument_presets_for_model, UnresolvedArgument};
 use plan_types::{
-    ComparisonTarget, ComparisonValue, Expression, LocalCommandRelationshipInfo,
-    LocalFieldComparison, LocalModelRelationshipInfo, NdcRelationshipName, UsagesCounts,
-    VariableName,
+    make_model_field_variable_name, ComparisonTarget, ComparisonValue, Expression,
+    LocalCommandRelationshipInfo, LocalFieldComparison, LocalModelRelationshipInfo,
+    NdcRelationshipName, UsagesCounts,
 };
 
 #[derive(Debug, Clone, Serialize)]
@@ -40,6 +45,9 @@ pub struct RemoteModelRelationshipInfo {
     /// contains mapping of field names and `metadata_resolve::FieldMapping`.
     /// Also see `build_remote_relationship`.
     pub join_mapping: Vec<(SourceField, TargetField)>,
+    /// Mappings from source fields to arguments of the target model, along with
+    /// the names of the arguments in the data connector.
+    pub argument_join_mapping: Vec<(SourceField, (ArgumentName, DataConnectorArgumentName))>,
 }
 
 #[derive(Debug, Serialize)]
@@ -48,7 +56,16 @@ pub struct RemoteCommandRelationshipInfo<'s> {
     pub join_mapping: Vec<(SourceField, ArgumentName)>,
 }
 
-pub type SourceField = (FieldName, metadata_resolve::FieldMapping);
+/// The source field of a remote relationship mapping
+#[derive(Debug, Clone, Serialize)]
+pub struct SourceField {
+    /// Path of the field, starting from a field of the source type
+    pub field_path: Vec<FieldName>,
+    /// Mapping of the first field on the path
+    pub field_mapping: metadata_resolve::FieldMapping,
+    /// Columns of the nested object fields on the rest of the path
+    pub nested_columns: Vec<DataConnectorColumnName>,
+}
 pub type TargetField = (FieldName, metadata_resolve::NdcColumnForComparison);
 
 pub fn generate_model_relationship_open_dd_ir<'s>(
@@ -67,10 +84,28 @@ pub fn generate_model_relationship_open_dd_ir<'s>(
     count_model(&relationship_annotation.model_name, usage_counts);
     let field_call = field.field_call()?;
 
+    let target_model = models
+        .get(&relationship_annotation.model_name)
+        .ok_or_else(|| {
+            error::InternalError::Developer(
+                error::InternalDeveloperError::TargetModelNotFoundForRelationship {
+                    model_name: relationship_annotation.model_name.clone(),
+                    relationship_name: relationship_annotation.relationship_name.clone(),
+                },
+            )
+        })?;
+    let target_model_source = target_model.model.source.as_ref().ok_or_else(|| {
+        error::Error::InternalMissingTargetModelSourceForRelationship {
+            relationship_name: relationship_annotation.relationship_name.clone(),
+            type_name: relationship_annotation.source_type.clone(),
+        }
+    })?;
+
     let mut limit = None;
     let mut offset = None;
     let mut where_input = None;
     let mut order_by = Vec::new();
+    let mut model_arguments = IndexMap::new();
 
     for argument in field_call.arguments.values() {
         match argument.info.generic {
@@ -89,26 +124,30 @@ pub fn generate_model_relationship_open_dd_ir<'s>(
                                     error::Error::map_unexpected_value_to_external_error,
                                 )?);
                         }
+                        ModelInputAnnotation::ModelArgumentsExpression => {
+                            model_arguments = arguments::resolve_model_arguments_input_opendd(
+                                argument.value.as_object()?,
+                                &target_model_source.type_mappings,
+                                session_variables,
+                                usage_counts,
+                            )?;
+                        }
+                        ModelInputAnnotation::ModelDistinctOnArgument => {
+                            let distinct_on = read_distinct_on_argument(
+                                target_model
+                                    .graphql_api
+                                    .select_many
+                                    .as_ref()
+                                    .and_then(|select_many| select_many.distinct_on.as_ref()),
+                                &argument.value,
+                            )?;
+                            if distinct_on.is_some() {
+                                return Err(error::Error::DistinctOnNotSupportedInOpenDdPipeline {
+                                    field_name: field_call.name.clone(),
+                                });
+                            }
+                        }
                         ModelInputAnnotation::ModelOrderByExpression => {
-                            let target_model = models
-                                    .get(&relationship_annotation.model_name)
-                                    .ok_or_else(|| {
-                                        error::InternalError::Developer(
-                                            error::InternalDeveloperError::TargetModelNotFoundForRelationship {
-                                                model_name: relationship_annotation.model_name.clone(),
-                                                relationship_name: relationship_annotation.relationship_name.clone(),
-                                            },
-                                        )
-                                    })?;
-                            let target_model_source =
-                                target_model.model.source.as_ref().ok_or_else(|| {
-                                    error::Error::InternalMissingTargetModelSourceForRelationship {
-                                        relationship_name: relationship_annotation
-                                            .relationship_name
-                                            .clone(),
-                                        type_name: relationship_annotation.source_type.clone(),
-                                    }
-                                })?;
                             order_by.extend(order_by::build_order_by_open_dd_ir(
                                 &argument.value,
                                 usage_counts,
@@ -183,7 +222,7 @@ pub fn generate_model_relationship_open_dd_ir<'s>(
 
     let target = open_dds::query::RelationshipTarget {
         relationship_name: relationship_annotation.relationship_name.clone(),
-        arguments: IndexMap::new(),
+        arguments: model_arguments,
         filter,
         limit,
         offset,
@@ -198,9 +237,13 @@ pub fn generate_model_relationship_open_dd_ir<'s>(
     Ok(relationship_selection)
 }
 
+/// Generates the IR of a relationship field to a model. The rows of a connection field are
+/// paginated with the cursor pagination of the target model's select connection, and its nodes
+/// are selected through its edges.
 pub fn generate_model_relationship_ir<'s>(
     field: &Field<'s, GDS>,
     relationship_annotation: &'s ModelRelationshipAnnotation,
+    is_connection: bool,
     relationship_field_nestedness: metadata_resolve::FieldNestedness,
     source_data_connector: &'s metadata_resolve::DataConnectorLink,
     source_type_mappings: &'s BTreeMap<Qualified<CustomTypeName>, metadata_resolve::TypeMapping>,
@@ -251,6 +294,9 @@ pub fn generate_model_relationship_ir<'s>(
     let mut offset = None;
     let mut where_clause = None;
     let mut order_by = None;
+    let mut model_arguments = BTreeMap::new();
+    let mut cursor_pagination_arguments = CursorPaginationArguments::default();
+    let mut distinct_on = None;
 
     for argument in field_call.arguments.values() {
         match argument.info.generic {
@@ -258,6 +304,22 @@ pub fn generate_model_relationship_ir<'s>(
                 match argument_annotation {
                     InputAnnotation::Model(model_argument_annotation) => {
                         match model_argument_annotation {
+                            ModelInputAnnotation::ModelFirstArgument
+                            | ModelInputAnnotation::ModelAfterArgument
+                            | ModelInputAnnotation::ModelLastArgument
+                            | ModelInputAnnotation::ModelBeforeArgument => {
+                                cursor_pagination_arguments
+                                    .read_argument(model_argument_annotation, &argument.value)?;
+                            }
+                            ModelInputAnnotation::ModelDistinctOnArgument => {
+                                distinct_on =
+                                    read_distinct_on_argument(
+                                        target_model.graphql_api.select_many.as_ref().and_then(
+                                            |select_many| select_many.distinct_on.as_ref(),
+                                        ),
+                                        &argument.value,
+                                    )?;
+                            }
                             ModelInputAnnotation::ModelLimitArgument => {
                                 limit = Some(argument.value.as_int_u32().map_err(
                                     error::Error::map_unexpected_value_to_external_error,
@@ -268,6 +330,20 @@ pub fn generate_model_relationship_ir<'s>(
                                     error::Error::map_unexpected_value_to_external_error,
                                 )?);
                             }
+                            ModelInputAnnotation::ModelArgumentsExpression => {
+                                for model_argument in argument.value.as_object()?.values() {
+                                    let (ndc_arg_name, ndc_val) =
+                                        arguments::build_ndc_argument_as_value(
+                                            &field_call.name,
+                                            model_argument,
+                                            &target_source.type_mappings,
+                                            &target_source.data_connector,
+                                            &session.variables,
+                                            usage_counts,
+                                        )?;
+                                    model_arguments.insert(ndc_arg_name, ndc_val);
+                                }
+                            }
                             ModelInputAnnotation::ModelOrderByExpression => {
                                 order_by = Some(build_ndc_order_by(
                                     argument,
@@ -313,19 +389,77 @@ pub fn generate_model_relationship_ir<'s>(
         }
     }
 
+    // add any preset arguments from model permissions
+    let model_arguments = process_argument_presets_for_model(
+        model_arguments,
+        target_model,
+        object_types,
+        session,
+        request_headers,
+        usage_counts,
+    )?;
+
     let query_filter = filter::QueryFilter {
         where_clause,
-        additional_filter: None,
+        additional_filter: build_value_mappings_filter(
+            &relationship_annotation.value_mappings,
+            &relationship_annotation.relationship_name,
+            &relationship_annotation.source_type,
+            &session.variables,
+        )?,
     };
 
-    let selection_ir = model_selection_ir(
-        &field.selection_set,
+    let empty_selection_set = normalized_ast::SelectionSet {
+        fields: IndexMap::new(),
+        type_name: None,
+    };
+    let (selection_set, cursor_pagination) = if is_connection {
+        let select_connection = target_model
+            .graphql_api
+            .select_connection
+            .as_ref()
+            .ok_or_else(|| error::InternalEngineError::InternalGeneric {
+                description: format!(
+                    "select connection not found for model {}",
+                    relationship_annotation.model_name
+                ),
+            })?;
+        // All the rows are in a single page when no page is requested
+        let RequestedPage {
+            page_size,
+            backwards,
+            cursor,
+        } = cursor_pagination_arguments
+            .requested_page()?
+            .unwrap_or(RequestedPage {
+                page_size: None,
+                backwards: false,
+                cursor: None,
+            });
+        limit = page_size;
+        let node_selection_set =
+            connection_node_selection_set(&field_call.name, &field.selection_set)?
+                .unwrap_or(&empty_selection_set);
+        (
+            node_selection_set,
+            Some(CursorPaginationInput {
+                cursor_pagination: &select_connection.cursor_pagination,
+                backwards,
+                cursor,
+            }),
+        )
+    } else {
+        (&field.selection_set, None)
+    };
+
+    let mut selection_ir = model_selection_ir(
+        selection_set,
         &relationship_annotation.target_type,
         target_source,
-        BTreeMap::new(),
+        model_arguments,
         query_filter,
         permissions::get_select_filter_predicate(&field_call.info)?,
-        limit,
+        permissions::apply_select_permission_limit(&field_call.info, limit)?,
         offset,
         order_by,
         models,
@@ -335,6 +469,8 @@ pub fn generate_model_relationship_ir<'s>(
         request_headers,
         usage_counts,
     )?;
+    selection_ir.cursor_pagination = cursor_pagination;
+    selection_ir.distinct_on = distinct_on;
 
     match metadata_resolve::relationship_execution_category(
         relationship_field_nestedness,
@@ -353,6 +489,7 @@ pub fn generate_model_relationship_ir<'s>(
                 &relationship_annotation.target_type,
                 target_source,
                 &relationship_annotation.mappings,
+                &relationship_annotation.argument_mappings,
             ))
         }
         metadata_resolve::RelationshipExecutionCategory::RemoteForEach => {
@@ -362,6 +499,7 @@ pub fn generate_model_relationship_ir<'s>(
                 &relationship_annotation.source_type,
                 source_type_mappings,
                 &relationship_annotation.mappings,
+                &relationship_annotation.argument_mappings,
             )
         }
     }
@@ -446,6 +584,12 @@ pub fn generate_model_aggregate_relationship_ir<'s>(
         model,
         target_source,
         &relationship_annotation.model_name,
+        build_value_mappings_filter(
+            &relationship_annotation.value_mappings,
+            &relationship_annotation.relationship_name,
+            &relationship_annotation.source_type,
+            &session.variables,
+        )?,
         object_types,
         session,
         request_headers,
@@ -469,6 +613,7 @@ pub fn generate_model_aggregate_relationship_ir<'s>(
                 &relationship_annotation.target_type,
                 target_source,
                 &relationship_annotation.mappings,
+                &relationship_annotation.argument_mappings,
             ))
         }
         metadata_resolve::RelationshipExecutionCategory::RemoteForEach => {
@@ -478,6 +623,7 @@ pub fn generate_model_aggregate_relationship_ir<'s>(
                 &relationship_annotation.source_type,
                 source_type_mappings,
                 &relationship_annotation.mappings,
+                &relationship_annotation.argument_mappings,
             )
         }
     }
@@ -500,6 +646,7 @@ pub fn generate_command_relationship_open_dd_ir<'s>(
 ) -> Result<open_dds::query::RelationshipSelection, error::Error> {
     count_command(&relationship_annotation.command_name, usage_counts);
 
+    // the output filter of the command is applied when the OpenDD IR is planned
     let selection = generate_selection_set_open_dd_ir(
         &field.selection_set,
         metadata_resolve::FieldNestedness::NotNested,
@@ -628,6 +775,73 @@ pub fn generate_command_relationship_ir<'s>(
     }
 }
 
+/// Build the filter that restricts the target model of a relationship to the rows
+/// whose fields are equal to the values of the relationship's value mappings
+fn build_value_mappings_filter<'s>(
+    value_mappings: &[metadata_resolve::RelationshipModelValueMapping],
+    relationship_name: &RelationshipName,
+    source_type: &Qualified<CustomTypeName>,
+    session_variables: &SessionVariables,
+) -> Result<Option<Expression<'s>>, error::Error> {
+    let mut expressions = Vec::new();
+    for value_mapping in value_mappings {
+        let target_column = value_mapping.target_ndc_column.as_ref().ok_or_else(|| {
+            error::InternalEngineError::InternalGeneric {
+                description: format!(
+                    "No column mapping for relationship {relationship_name} on {source_type}"
+                ),
+            }
+        })?;
+        let value = plan::make_argument_from_value_expression(
+            &value_mapping.source_value,
+            &value_mapping.target_field_type,
+            session_variables,
+        )?;
+        expressions.push(Expression::LocalField(
+            LocalFieldComparison::BinaryComparison {
+                column: ComparisonTarget::Column {
+                    name: target_column.column.clone(),
+                    field_path: target_column.field_path.clone(),
+                },
+                operator: target_column.equal_operator.clone(),
+                value: ComparisonValue::Scalar { value },
+            },
+        ));
+    }
+    Ok((!expressions.is_empty()).then(|| Expression::mk_and(expressions)))
+}
+
+/// Build the arguments of the target command of a relationship that are mapped
+/// from values rather than from fields of the source object
+fn build_value_mapping_arguments<'s>(
+    annotation: &CommandRelationshipAnnotation,
+    target_source: &CommandSource,
+    session_variables: &SessionVariables,
+) -> Result<BTreeMap<DataConnectorArgumentName, UnresolvedArgument<'s>>, error::Error> {
+    let mut arguments = BTreeMap::new();
+    for value_mapping in &annotation.value_mappings {
+        let ndc_argument_name = target_source
+            .argument_mappings
+            .get(&value_mapping.argument_name)
+            .ok_or_else(|| {
+                error::InternalDeveloperError::ArgumentMappingNotFoundForRelationship {
+                    relationship_name: annotation.relationship_name.clone(),
+                    argument_name: value_mapping.argument_name.clone(),
+                }
+            })?;
+        let value = plan::make_argument_from_value_expression(
+            &value_mapping.source_value,
+            &value_mapping.argument_type,
+            session_variables,
+        )?;
+        arguments.insert(
+            ndc_argument_name.clone(),
+            UnresolvedArgument::Literal { value },
+        );
+    }
+    Ok(arguments)
+}
+
 pub fn build_local_model_relationship<'s>(
     relationships_ir: model_selection::ModelSelection<'s>,
     relationship_name: &'s RelationshipName,
@@ -638,6 +852,7 @@ pub fn build_local_model_relationship<'s>(
     target_type: &'s Qualified<CustomTypeName>,
     target_source: &'s metadata_resolve::ModelSource,
     target_mappings: &'s Vec<RelationshipModelMapping>,
+    target_argument_mappings: &'s [metadata_resolve::RelationshipModelArgumentMapping],
 ) -> FieldSelection<'s> {
     let rel_info = LocalModelRelationshipInfo {
         relationship_name,
@@ -648,6 +863,7 @@ pub fn build_local_model_relationship<'s>(
         target_source,
         target_type,
         mappings: target_mappings,
+        argument_mappings: target_argument_mappings,
     };
 
     FieldSelection::ModelRelationshipLocal {
@@ -687,9 +903,10 @@ pub fn build_local_command_relationship<'s>(
         field,
         field_call,
         &annotation.target_type,
-        annotation.target_base_type_kind,
+        annotation.target_base_type_kind.clone(),
         target_command,
         target_source,
+        build_value_mapping_arguments(annotation, target_source, &session.variables)?,
         models,
         commands,
         object_types,
@@ -728,14 +945,18 @@ pub fn build_remote_relationship<'s>(
     source_type: &'s Qualified<CustomTypeName>,
     source_type_mappings: &'s BTreeMap<Qualified<CustomTypeName>, metadata_resolve::TypeMapping>,
     target_mappings: &'s Vec<RelationshipModelMapping>,
+    target_argument_mappings: &'s [metadata_resolve::RelationshipModelArgumentMapping],
 ) -> Result<FieldSelection<'s>, error::Error> {
     let mut join_mapping: Vec<(SourceField, TargetField)> = vec![];
-    for metadata_resolve::RelationshipModelMapping {
-        source_field: source_field_path,
-        target_field: target_field_path,
-        target_ndc_column,
-    } in target_mappings
-    {
+    for mapping in target_mappings {
+        let metadata_resolve::RelationshipModelMapping {
+            source_field: source_field_path,
+            source_nested_fields,
+            target_field: target_field_path,
+            target_nested_fields: _,
+            target_ndc_column,
+        } = mapping;
+
         let source_column = metadata_resolve::get_field_mapping_of_field_name(
             source_type_mappings,
             source_type,
@@ -746,6 +967,15 @@ pub fn build_remote_relationship<'s>(
             error::Error::from(error::InternalDeveloperError::RelationshipFieldMappingError(err))
         })?;
 
+        let source_nested_columns = metadata_resolve::get_nested_field_columns(
+            source_type_mappings,
+            relationship_name,
+            source_nested_fields,
+        )
+        .map_err(|err| {
+            error::Error::from(error::InternalDeveloperError::RelationshipFieldMappingError(err))
+        })?;
+
         let target_column = target_ndc_column.as_ref().ok_or_else(|| {
             error::InternalEngineError::InternalGeneric {
                 description: format!(
@@ -754,7 +984,11 @@ pub fn build_remote_relationship<'s>(
             }
         })?;
 
-        let source_field = (source_field_path.field_name.clone(), source_column);
+        let source_field = SourceField {
+            field_path: mapping.source_field_path(),
+            field_mapping: source_column,
+            nested_columns: source_nested_columns,
+        };
         let target_field = (target_field_path.field_name.clone(), target_column.clone());
         join_mapping.push((source_field, target_field));
     }
@@ -763,15 +997,14 @@ pub fn build_remote_relationship<'s>(
 
     // Generate the join condition expressions for the remote relationship
     for (_source, (_field_name, target_column)) in &join_mapping {
-        let target_value_variable = format!("${}", &target_column.column);
         let comparison_exp = LocalFieldComparison::BinaryComparison {
             column: ComparisonTarget::Column {
                 name: target_column.column.clone(),
-                field_path: vec![],
+                field_path: target_column.field_path.clone(),
             },
             operator: target_column.equal_operator.clone(),
             value: ComparisonValue::Variable {
-                name: VariableName(target_value_variable),
+                name: make_model_field_variable_name(target_column),
             },
         };
         relationship_join_filter_expressions.push(Expression::LocalField(comparison_exp));
@@ -781,7 +1014,52 @@ pub fn build_remote_relationship<'s>(
         .filter_clause
         .relationship_join_filter = Some(Expression::mk_and(relationship_join_filter_expressions));
 
-    let rel_info = RemoteModelRelationshipInfo { join_mapping };
+    let mut argument_join_mapping = vec![];
+    for argument_mapping in target_argument_mappings {
+        let source_column = metadata_resolve::get_field_mapping_of_field_name(
+            source_type_mappings,
+            source_type,
+            relationship_name,
+            &argument_mapping.source_field.field_name,
+        )
+        .map_err(|err| {
+            error::Error::from(error::InternalDeveloperError::RelationshipFieldMappingError(err))
+        })?;
+
+        let source_nested_columns = metadata_resolve::get_nested_field_columns(
+            source_type_mappings,
+            relationship_name,
+            &argument_mapping.source_nested_fields,
+        )
+        .map_err(|err| {
+            error::Error::from(error::InternalDeveloperError::RelationshipFieldMappingError(err))
+        })?;
+
+        let ndc_argument_name = argument_mapping.ndc_argument_name.as_ref().ok_or_else(|| {
+            error::InternalDeveloperError::ArgumentMappingNotFoundForRelationship {
+                relationship_name: relationship_name.clone(),
+                argument_name: argument_mapping.argument_name.clone(),
+            }
+        })?;
+
+        let source_field = SourceField {
+            field_path: argument_mapping.source_field_path(),
+            field_mapping: source_column,
+            nested_columns: source_nested_columns,
+        };
+        argument_join_mapping.push((
+            source_field,
+            (
+                argument_mapping.argument_name.clone(),
+                ndc_argument_name.clone(),
+            ),
+        ));
+    }
+
+    let rel_info = RemoteModelRelationshipInfo {
+        join_mapping,
+        argument_join_mapping,
+    };
     Ok(FieldSelection::ModelRelationshipRemote {
         ir: remote_relationships_ir,
         relationship_info: rel_info,
@@ -813,11 +1091,13 @@ pub fn build_remote_command_relationship<'n, 's>(
     usage_counts: &mut UsagesCounts,
 ) -> Result<FieldSelection<'s>, error::Error> {
     let mut join_mapping: Vec<(SourceField, ArgumentName)> = vec![];
-    for metadata_resolve::RelationshipCommandMapping {
-        source_field: source_field_path,
-        argument_name: target_argument_name,
-    } in &annotation.mappings
-    {
+    for mapping in &annotation.mappings {
+        let metadata_resolve::RelationshipCommandMapping {
+            source_field: source_field_path,
+            source_nested_fields,
+            argument_name: target_argument_name,
+        } = mapping;
+
         let source_column = metadata_resolve::get_field_mapping_of_field_name(
             type_mappings,
             &annotation.source_type,
@@ -828,7 +1108,20 @@ pub fn build_remote_command_relationship<'n, 's>(
             error::Error::from(error::InternalDeveloperError::RelationshipFieldMappingError(err))
         })?;
 
-        let source_field = (source_field_path.field_name.clone(), source_column);
+        let source_nested_columns = metadata_resolve::get_nested_field_columns(
+            type_mappings,
+            &annotation.relationship_name,
+            source_nested_fields,
+        )
+        .map_err(|err| {
+            error::Error::from(error::InternalDeveloperError::RelationshipFieldMappingError(err))
+        })?;
+
+        let source_field = SourceField {
+            field_path: mapping.source_field_path(),
+            field_mapping: source_column,
+            nested_columns: source_nested_columns,
+        };
         join_mapping.push((source_field, target_argument_name.clone()));
     }
     let mut remote_relationships_ir = generate_function_based_command(
@@ -837,9 +1130,10 @@ pub fn build_remote_command_relationship<'n, 's>(
         field,
         field_call,
         &annotation.target_type,
-        annotation.target_base_type_kind,
+        annotation.target_base_type_kind.clone(),
         target_command,
         target_source,
+        build_value_mapping_arguments(annotation, target_source, &session.variables)?,
         models,
         commands,
         object_types,
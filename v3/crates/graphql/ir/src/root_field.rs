@@ -6,7 +6,12 @@ use serde::Serialize;
 
 use super::{
     commands,
-    query_root::{apollo_federation, node_field, select_aggregate, select_many, select_one},
+    mutation_root::model_mutation,
+    query_root::{
+        apollo_federation, node_field, select_aggregate, select_connection, select_group_by,
+        select_many, select_one,
+    },
+    subscription_root::select_stream,
 };
 use graphql_schema::GDS;
 
@@ -45,6 +50,17 @@ pub enum QueryRootField<'n, 's> {
         selection_set: &'n gql::normalized_ast::SelectionSet<'s, GDS>,
         ir: select_aggregate::ModelSelectAggregate<'n, 's>,
     },
+    // Operation that selects a page of rows from a model as a Relay connection
+    ModelSelectConnection {
+        selection_set: &'n gql::normalized_ast::SelectionSet<'s, GDS>,
+        ir: select_connection::ModelSelectConnection<'s>,
+    },
+    // Operation that selects the groups of the rows of a model, with the aggregates over each
+    // group
+    ModelSelectGroupBy {
+        selection_set: &'n gql::normalized_ast::SelectionSet<'s, GDS>,
+        ir: select_group_by::ModelSelectGroupBy<'s>,
+    },
     // Operation that selects a single row from the model corresponding
     // to the Global Id input.
     NodeSelect(Option<node_field::NodeSelect<'n, 's>>),
@@ -79,6 +95,11 @@ pub enum MutationRootField<'n, 's> {
         selection_set: &'n gql::normalized_ast::SelectionSet<'s, GDS>,
         ir: commands::ProcedureBasedCommand<'s>,
     },
+    // Generated insert, update or delete mutation of a model
+    ModelMutation {
+        selection_set: &'n gql::normalized_ast::SelectionSet<'s, GDS>,
+        ir: model_mutation::ModelMutation<'s>,
+    },
 }
 
 /// IR of a subscription root field
@@ -102,4 +123,10 @@ pub enum SubscriptionRootField<'n, 's> {
         ir: select_aggregate::ModelSelectAggregate<'n, 's>,
         polling_interval_ms: u64,
     },
+    // Operation that streams the rows of a model, in batches ordered by a cursor field
+    ModelSelectStream {
+        selection_set: &'n gql::normalized_ast::SelectionSet<'s, GDS>,
+        ir: select_stream::ModelSelectStream<'n, 's>,
+        polling_interval_ms: u64,
+    },
 }
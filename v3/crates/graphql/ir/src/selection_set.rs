@@ -13,9 +13,12 @@ use super::arguments;
 use super::commands::FunctionBasedCommand;
 use super::model_selection::ModelSelection;
 use super::relationship::{self, RemoteCommandRelationshipInfo, RemoteModelRelationshipInfo};
+use crate::abstract_types;
 use crate::aggregates::mk_alias_from_graphql_field_path;
 use crate::error;
+use crate::field_condition;
 use crate::global_id;
+use crate::permissions;
 use graphql_schema::{
     AggregateOutputAnnotation, AggregationFunctionAnnotation, InputAnnotation, TypeKind,
 };
@@ -146,6 +149,32 @@ fn build_global_id_fields(
     Ok(())
 }
 
+fn build_condition_fields(
+    condition: &metadata_resolve::FieldCondition,
+    field_mappings: &BTreeMap<FieldName, metadata_resolve::FieldMapping>,
+    field_alias: &Alias,
+    fields: &mut IndexMap<NdcFieldAlias, FieldSelection>,
+) -> Result<(), error::Error> {
+    for field_name in condition.fields() {
+        let field_mapping = field_mappings.get(field_name).ok_or_else(|| {
+            error::InternalEngineError::InternalGeneric {
+                description: format!("invalid field condition in annotation: {field_name:}"),
+            }
+        })?;
+        let condition_col_alias = field_condition::condition_col_format(field_alias, field_name);
+
+        fields.insert(
+            NdcFieldAlias::from(condition_col_alias.as_str()),
+            FieldSelection::Column {
+                column: field_mapping.column.clone(),
+                nested_selection: None,
+                arguments: BTreeMap::new(),
+            },
+        );
+    }
+    Ok(())
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum NestedSelectionType {
     /// The nested selection is selecting the root of a command.
@@ -206,9 +235,15 @@ pub fn generate_nested_selection_open_dd_ir(
         metadata_resolve::QualifiedBaseType::Named(qualified_type_name) => {
             match qualified_type_name {
                 metadata_resolve::QualifiedTypeName::Inbuilt(_) => Ok(None), // Inbuilt types are all scalars so there should be no subselections.
-                metadata_resolve::QualifiedTypeName::Custom(_data_type) => {
+                metadata_resolve::QualifiedTypeName::Custom(data_type) => {
                     match field_base_type_kind {
                         TypeKind::Scalar => Ok(None),
+                        TypeKind::Abstract(_) => {
+                            Err(error::Error::AbstractTypeNotSupportedInOpenDdPipeline {
+                                field_name: field.field_call()?.name.clone(),
+                                type_name: data_type.clone(),
+                            })
+                        }
                         TypeKind::Object => {
                             let nested_selection = generate_selection_set_open_dd_ir(
                                 &field.selection_set,
@@ -315,6 +350,22 @@ pub fn generate_nested_selection<'s>(
                             )?;
                             Ok(Some(NestedSelection::Object(nested_selection)))
                         }
+                        TypeKind::Abstract(type_discrimination) => {
+                            let nested_selection = generate_abstract_selection_set_ir(
+                                &field.selection_set,
+                                &type_discrimination,
+                                selection_set_field_nestedness,
+                                data_connector,
+                                type_mappings,
+                                models,
+                                commands,
+                                object_types,
+                                session,
+                                request_headers,
+                                usage_counts,
+                            )?;
+                            Ok(Some(NestedSelection::Object(nested_selection)))
+                        }
                     }
                 }
             }
@@ -322,6 +373,92 @@ pub fn generate_nested_selection<'s>(
     }
 }
 
+/// Builds the IR of a selection set on a union or interface type. The fields
+/// selected on each member object type are fetched under an alias prefixed with
+/// the member's name, along with the discriminator field that decides which
+/// member the value belongs to.
+fn generate_abstract_selection_set_ir<'s>(
+    selection_set: &normalized_ast::SelectionSet<'s, GDS>,
+    type_discrimination: &metadata_resolve::TypeDiscrimination,
+    selection_set_field_nestedness: metadata_resolve::FieldNestedness,
+    data_connector: &'s metadata_resolve::DataConnectorLink,
+    type_mappings: &'s BTreeMap<
+        metadata_resolve::Qualified<CustomTypeName>,
+        metadata_resolve::TypeMapping,
+    >,
+    models: &'s IndexMap<
+        metadata_resolve::Qualified<open_dds::models::ModelName>,
+        metadata_resolve::ModelWithPermissions,
+    >,
+    commands: &'s IndexMap<
+        metadata_resolve::Qualified<open_dds::commands::CommandName>,
+        metadata_resolve::CommandWithPermissions,
+    >,
+    object_types: &'s BTreeMap<
+        metadata_resolve::Qualified<open_dds::types::CustomTypeName>,
+        metadata_resolve::ObjectTypeWithRelationships,
+    >,
+    session: &Session,
+    request_headers: &reqwest::header::HeaderMap,
+    usage_counts: &mut UsagesCounts,
+) -> Result<ResultSelectionSet<'s>, error::Error> {
+    let mut fields = IndexMap::new();
+    for member in &type_discrimination.members {
+        // members without a GraphQL type name can never be selected
+        let Some(member_graphql_type_name) = &member.graphql_type_name else {
+            continue;
+        };
+        let metadata_resolve::TypeMapping::Object { field_mappings, .. } = type_mappings
+            .get(&member.object_type)
+            .ok_or_else(|| error::Error::InternalTypeMappingNotFound {
+                type_name: member.object_type.clone(),
+            })?;
+        if fields.is_empty() {
+            // the discriminator field is mapped to the same column in every member
+            let discriminator_field_mapping = field_mappings
+                .get(&type_discrimination.discriminator_field)
+                .ok_or_else(|| error::Error::InternalMappingNotFound {
+                    type_name: member.object_type.clone(),
+                    field_name: type_discrimination.discriminator_field.clone(),
+                })?;
+            fields.insert(
+                NdcFieldAlias::from(abstract_types::DISCRIMINATOR_COL),
+                FieldSelection::Column {
+                    column: discriminator_field_mapping.column.clone(),
+                    nested_selection: None,
+                    arguments: BTreeMap::new(),
+                },
+            );
+        }
+        let member_selection_set = generate_selection_set_ir(
+            &selection_set.filter_field_calls_by_typename(member_graphql_type_name.clone()),
+            selection_set_field_nestedness,
+            data_connector,
+            type_mappings,
+            field_mappings,
+            models,
+            commands,
+            object_types,
+            session,
+            request_headers,
+            usage_counts,
+        )?;
+        for (alias, field_selection) in member_selection_set.fields {
+            fields.insert(
+                NdcFieldAlias::from(
+                    abstract_types::abstract_member_col_format(
+                        member_graphql_type_name,
+                        alias.as_str(),
+                    )
+                    .as_str(),
+                ),
+                field_selection,
+            );
+        }
+    }
+    Ok(ResultSelectionSet { fields })
+}
+
 /// Builds the OpenDD IR from a normalized selection set
 pub fn generate_selection_set_open_dd_ir(
     selection_set: &normalized_ast::SelectionSet<'_, GDS>,
@@ -353,7 +490,7 @@ pub fn generate_selection_set_open_dd_ir(
                     } => {
                         let nested_selection = generate_nested_selection_open_dd_ir(
                             field_type,
-                            *field_base_type_kind,
+                            field_base_type_kind.clone(),
                             selection_set_field_nestedness
                                 .max(metadata_resolve::FieldNestedness::ObjectNested),
                             models,
@@ -364,11 +501,18 @@ pub fn generate_selection_set_open_dd_ir(
                             request_headers,
                             usage_counts,
                         )?;
+                        // Preset arguments are not in the schema of the role, and are
+                        // applied by the planner from the type permissions.
+                        let argument_presets =
+                            permissions::get_field_argument_presets(&field_call.info);
                         let mut field_arguments = IndexMap::new();
                         for (argument_name, argument_type) in argument_types {
                             match field_call.arguments.get(argument_name) {
                                 None => {
-                                    if !argument_type.nullable {
+                                    let has_preset = argument_presets.is_some_and(|presets| {
+                                        presets.contains_key(argument_name.as_str())
+                                    });
+                                    if !argument_type.nullable && !has_preset {
                                         Err(error::Error::MissingNonNullableArgument {
                                             argument_name: argument_name.to_string(),
                                             field_name: name.to_string(),
@@ -458,6 +602,11 @@ pub fn generate_selection_set_open_dd_ir(
                             ),
                         );
                     }
+                    OutputAnnotation::RelationshipToModelConnection(_) => {
+                        Err(error::Error::ConnectionNotSupportedInOpenDdPipeline {
+                            field_name: field_call.name.clone(),
+                        })?;
+                    }
                     OutputAnnotation::RelationshipToModelAggregate(relationship_annotation) => {
                         fields.insert(
                             make_field_alias(field.alias.0.as_str())?,
@@ -643,7 +792,7 @@ pub fn generate_selection_set_ir<'s>(
                     })?;
                     let nested_selection = generate_nested_selection(
                         field_type,
-                        *field_base_type_kind,
+                        field_base_type_kind.clone(),
                         selection_set_field_nestedness
                             .max(metadata_resolve::FieldNestedness::ObjectNested),
                         NestedSelectionType::NestedSelection,
@@ -657,11 +806,16 @@ pub fn generate_selection_set_ir<'s>(
                         request_headers,
                         usage_counts,
                     )?;
+                    let argument_presets =
+                        permissions::get_field_argument_presets(&field_call.info);
                     let mut field_arguments = BTreeMap::new();
                     for (argument_name, argument_type) in argument_types {
                         let argument_value = match field_call.arguments.get(argument_name) {
                             None => {
-                                if argument_type.nullable {
+                                let has_preset = argument_presets.is_some_and(|presets| {
+                                    presets.contains_key(argument_name.as_str())
+                                });
+                                if argument_type.nullable || has_preset {
                                     Ok(None)
                                 } else {
                                     Err(error::Error::MissingNonNullableArgument {
@@ -692,6 +846,25 @@ pub fn generate_selection_set_ir<'s>(
                             field_arguments.insert(ndc_argument_name, argument);
                         }
                     }
+                    // Preset arguments from TypeOutputPermission field argument presets
+                    for (argument_name, (argument_type, argument_value)) in
+                        argument_presets.into_iter().flatten()
+                    {
+                        let value = plan::make_argument_from_value_expression(
+                            argument_value,
+                            argument_type,
+                            &session.variables,
+                        )?;
+                        let ndc_argument_name = field_mapping
+                            .argument_mappings
+                            .get(argument_name)
+                            .map_or_else(
+                                || DataConnectorArgumentName::from(argument_name.as_str()),
+                                Clone::clone,
+                            );
+                        field_arguments
+                            .insert(ndc_argument_name, UnresolvedArgument::Literal { value });
+                    }
 
                     fields.insert(
                         NdcFieldAlias::from(field.alias.0.as_str()),
@@ -701,6 +874,14 @@ pub fn generate_selection_set_ir<'s>(
                             arguments: field_arguments,
                         },
                     );
+                    if let Some(condition) = permissions::get_field_condition(&field_call.info) {
+                        build_condition_fields(
+                            condition,
+                            field_mappings,
+                            &field.alias,
+                            &mut fields,
+                        )?;
+                    }
                 }
                 OutputAnnotation::RootField(RootFieldAnnotation::Introspection) => {}
                 OutputAnnotation::GlobalIDField { global_id_fields } => {
@@ -744,6 +925,26 @@ pub fn generate_selection_set_ir<'s>(
                         relationship::generate_model_relationship_ir(
                             field,
                             relationship_annotation,
+                            false,
+                            selection_set_field_nestedness,
+                            data_connector,
+                            type_mappings,
+                            models,
+                            commands,
+                            object_types,
+                            session,
+                            request_headers,
+                            usage_counts,
+                        )?,
+                    );
+                }
+                OutputAnnotation::RelationshipToModelConnection(relationship_annotation) => {
+                    fields.insert(
+                        NdcFieldAlias::from(field.alias.0.as_str()),
+                        relationship::generate_model_relationship_ir(
+                            field,
+                            relationship_annotation,
+                            true,
                             selection_set_field_nestedness,
                             data_connector,
                             type_mappings,
@@ -16,6 +16,8 @@ use graphql_schema::RootFieldKind;
 use graphql_schema::GDS;
 use graphql_schema::{Annotation, NamespaceAnnotation, OutputAnnotation, RootFieldAnnotation};
 
+pub mod select_stream;
+
 pub fn generate_ir<'n, 's>(
     request_pipeline: GraphqlRequestPipeline,
     session: &Session,
@@ -63,6 +65,33 @@ pub fn generate_ir<'n, 's>(
                             )?;
                             Ok((alias.clone(), ir))
                         }
+                        RootFieldAnnotation::ModelStreamSubscription {
+                            data_type,
+                            name: model_name,
+                            polling_interval_ms,
+                        } => {
+                            let model = metadata.models.get(model_name).ok_or_else(|| {
+                                error::InternalEngineError::InternalGeneric {
+                                    description: format!("Model {model_name} not found"),
+                                }
+                            })?;
+                            let ir = generate_model_stream_rootfield_ir(
+                                request_pipeline,
+                                &type_name,
+                                model,
+                                &metadata.models,
+                                &metadata.commands,
+                                &metadata.object_types,
+                                data_type,
+                                field,
+                                field_call,
+                                session,
+                                request_headers,
+                                model_name,
+                                polling_interval_ms,
+                            )?;
+                            Ok((alias.clone(), ir))
+                        }
                         _ => Err(error::Error::from(
                             error::InternalEngineError::UnexpectedAnnotation {
                                 annotation: annotation.clone(),
@@ -178,6 +207,81 @@ fn generate_model_rootfield_ir<'n, 's>(
             )?,
             polling_interval_ms: *polling_interval_ms,
         },
+        // Connections are not published in the subscription root
+        RootFieldKind::SelectConnection => Err(error::InternalEngineError::InternalGeneric {
+            description: format!(
+                "select connection is not supported in subscriptions for model {model_name}"
+            ),
+        })?,
+        // Group by fields are not published in the subscription root
+        RootFieldKind::SelectGroupBy => Err(error::InternalEngineError::InternalGeneric {
+            description: format!(
+                "select group by is not supported in subscriptions for model {model_name}"
+            ),
+        })?,
     };
     Ok(ir)
 }
+
+#[allow(clippy::too_many_arguments)]
+fn generate_model_stream_rootfield_ir<'n, 's>(
+    request_pipeline: GraphqlRequestPipeline,
+    type_name: &ast::TypeName,
+    model: &'s metadata_resolve::ModelWithPermissions,
+    models: &'s IndexMap<
+        metadata_resolve::Qualified<open_dds::models::ModelName>,
+        metadata_resolve::ModelWithPermissions,
+    >,
+    commands: &'s IndexMap<
+        metadata_resolve::Qualified<open_dds::commands::CommandName>,
+        metadata_resolve::CommandWithPermissions,
+    >,
+    object_types: &'s BTreeMap<
+        metadata_resolve::Qualified<open_dds::types::CustomTypeName>,
+        metadata_resolve::ObjectTypeWithRelationships,
+    >,
+    data_type: &metadata_resolve::Qualified<CustomTypeName>,
+    field: &'n gql::normalized_ast::Field<'s, GDS>,
+    field_call: &'s gql::normalized_ast::FieldCall<'s, GDS>,
+    session: &Session,
+    request_headers: &reqwest::header::HeaderMap,
+    model_name: &'s metadata_resolve::Qualified<models::ModelName>,
+    polling_interval_ms: &u64,
+) -> Result<root_field::SubscriptionRootField<'n, 's>, error::Error> {
+    let source = model.model.source.as_deref().ok_or_else(|| {
+        error::InternalDeveloperError::NoSourceDataConnector {
+            type_name: type_name.clone(),
+            field_name: field_call.name.clone(),
+        }
+    })?;
+    // Check if subscription is allowed
+    // We won't be generating graphql schema any way if subscription is not allowed in permission.
+    // This is just a double check, if in case we missed something.
+    if let Some(NamespaceAnnotation::Model {
+        allow_subscriptions,
+        ..
+    }) = field_call.info.namespaced
+    {
+        if !allow_subscriptions {
+            Err(error::InternalEngineError::SubscriptionNotAllowed)?;
+        }
+    }
+    Ok(root_field::SubscriptionRootField::ModelSelectStream {
+        selection_set: &field.selection_set,
+        ir: select_stream::select_stream_generate_ir(
+            request_pipeline,
+            field,
+            field_call,
+            data_type,
+            model,
+            source,
+            models,
+            commands,
+            object_types,
+            session,
+            request_headers,
+            model_name,
+        )?,
+        polling_interval_ms: *polling_interval_ms,
+    })
+}
@@ -0,0 +1,284 @@
+//! model_source IR for a streaming subscription
+//!
+//! A streaming subscription fetches the rows of a model in batches, in the order of a cursor
+//! field, starting after the initial value of the cursor.
+
+use hasura_authn_core::Session;
+use indexmap::IndexMap;
+use lang_graphql::ast::common as ast;
+use lang_graphql::normalized_ast;
+
+use open_dds;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::arguments;
+use crate::error;
+use crate::filter;
+use crate::model_selection;
+use crate::permissions;
+use crate::query_root::select_many::ModelSelectManySelection;
+use crate::GraphqlRequestPipeline;
+use graphql_schema::GDS;
+use graphql_schema::{self, Annotation, BooleanExpressionAnnotation, ModelInputAnnotation};
+use metadata_resolve;
+use metadata_resolve::Qualified;
+use plan::{count_model, process_argument_presets_for_model};
+use plan_types::UsagesCounts;
+
+/// IR for a streaming subscription on a model
+#[derive(Debug, Serialize)]
+pub struct ModelSelectStream<'n, 's> {
+    // The name of the field as published in the schema
+    pub field_name: ast::Name,
+
+    // Selects the first batch of rows of the stream. The rows are ordered by the cursor
+    // field, and limited to the batch size.
+    pub model_selection: ModelSelectManySelection<'s>,
+
+    // The field the rows are streamed by, and the value after which to start streaming
+    pub cursor: StreamCursorInput,
+
+    // The Graphql output type of the operation
+    pub type_container: &'n ast::TypeContainer<ast::TypeName>,
+
+    // All the models/commands used in this operation. This includes the models/commands
+    // used via relationships. And in future, the models/commands used in the filter clause
+    pub usage_counts: UsagesCounts,
+}
+
+/// The cursor of a streaming subscription, as provided in the `cursor` argument
+#[derive(Debug, Serialize)]
+pub struct StreamCursorInput {
+    pub cursor_field: metadata_resolve::StreamCursorField,
+    pub initial_value: serde_json::Value,
+}
+
+/// Generates the IR for a streaming subscription
+pub fn select_stream_generate_ir<'n, 's>(
+    request_pipeline: GraphqlRequestPipeline,
+    field: &'n normalized_ast::Field<'s, GDS>,
+    field_call: &'n normalized_ast::FieldCall<'s, GDS>,
+    data_type: &Qualified<open_dds::types::CustomTypeName>,
+    model: &'s metadata_resolve::ModelWithPermissions,
+    model_source: &'s metadata_resolve::ModelSource,
+    models: &'s IndexMap<
+        metadata_resolve::Qualified<open_dds::models::ModelName>,
+        metadata_resolve::ModelWithPermissions,
+    >,
+    commands: &'s IndexMap<
+        metadata_resolve::Qualified<open_dds::commands::CommandName>,
+        metadata_resolve::CommandWithPermissions,
+    >,
+    object_types: &'s BTreeMap<
+        Qualified<open_dds::types::CustomTypeName>,
+        metadata_resolve::ObjectTypeWithRelationships,
+    >,
+    session: &Session,
+    request_headers: &reqwest::header::HeaderMap,
+    model_name: &'s Qualified<open_dds::models::ModelName>,
+) -> Result<ModelSelectStream<'n, 's>, error::Error> {
+    let mut batch_size = None;
+    let mut cursor = None;
+    let mut where_input = None;
+    let mut model_arguments = BTreeMap::new();
+
+    // For opendd execution pipeline
+    let mut model_arguments_input = None;
+
+    // Add the name of the root model
+    let mut usage_counts = UsagesCounts::new();
+    count_model(model_name, &mut usage_counts);
+
+    for argument in field_call.arguments.values() {
+        match argument.info.generic {
+            annotation @ Annotation::Input(graphql_schema::InputAnnotation::Model(
+                model_argument_annotation,
+            )) => match model_argument_annotation {
+                ModelInputAnnotation::ModelStreamBatchSizeArgument => {
+                    let value = argument
+                        .value
+                        .as_int_u32()
+                        .map_err(error::Error::map_unexpected_value_to_external_error)?;
+                    // A stream with an empty batch size would never advance
+                    if value == 0 {
+                        return Err(error::Error::InvalidBatchSizeValue { value });
+                    }
+                    batch_size = Some(value);
+                }
+                ModelInputAnnotation::ModelStreamCursorArgument => {
+                    cursor = Some(build_stream_cursor_input(&argument.value)?);
+                }
+                ModelInputAnnotation::ModelArgumentsExpression => match &argument.value {
+                    normalized_ast::Value::Object(arguments) => {
+                        for argument in arguments.values() {
+                            let (ndc_arg_name, ndc_val) = arguments::build_ndc_argument_as_value(
+                                &field_call.name,
+                                argument,
+                                &model_source.type_mappings,
+                                &model_source.data_connector,
+                                &session.variables,
+                                &mut usage_counts,
+                            )?;
+
+                            model_arguments.insert(ndc_arg_name, ndc_val);
+                        }
+                        model_arguments_input = Some(arguments);
+                    }
+                    _ => Err(error::InternalEngineError::InternalGeneric {
+                        description: "Expected object value for model arguments".into(),
+                    })?,
+                },
+                _ => {
+                    return Err(error::InternalEngineError::UnexpectedAnnotation {
+                        annotation: annotation.clone(),
+                    })?
+                }
+            },
+
+            Annotation::Input(graphql_schema::InputAnnotation::BooleanExpression(
+                BooleanExpressionAnnotation::BooleanExpressionRootField,
+            )) => {
+                where_input = Some(argument.value.as_object()?);
+            }
+
+            annotation => {
+                return Err(error::InternalEngineError::UnexpectedAnnotation {
+                    annotation: annotation.clone(),
+                })?
+            }
+        }
+    }
+
+    let cursor = cursor.ok_or_else(|| error::Error::MissingNonNullableArgument {
+        argument_name: "cursor".to_string(),
+        field_name: field_call.name.to_string(),
+    })?;
+    let batch_size = batch_size.ok_or_else(|| error::Error::MissingNonNullableArgument {
+        argument_name: "batch_size".to_string(),
+        field_name: field_call.name.to_string(),
+    })?;
+
+    // add any preset arguments from model permissions
+    model_arguments = process_argument_presets_for_model(
+        model_arguments,
+        model,
+        object_types,
+        session,
+        request_headers,
+        &mut usage_counts,
+    )?;
+
+    // The rows are ordered by the cursor when the query is planned, so no ordering is
+    // requested here
+    let model_selection = match request_pipeline {
+        GraphqlRequestPipeline::OpenDd => {
+            let where_clause = match where_input {
+                Some(where_input) => Some(filter::resolve_filter_expression_open_dd(
+                    where_input,
+                    &session.variables,
+                    &mut usage_counts,
+                )?),
+                None => None,
+            };
+
+            let model_arguments = model_arguments_input
+                .map(|arguments_input| {
+                    arguments::resolve_model_arguments_input_opendd(
+                        arguments_input,
+                        &model_source.type_mappings,
+                        &session.variables,
+                        &mut usage_counts,
+                    )
+                })
+                .transpose()?;
+
+            ModelSelectManySelection::OpenDd(model_selection::model_selection_open_dd_ir(
+                &field.selection_set,
+                model_name,
+                models,
+                &model_source.type_mappings,
+                model_arguments,
+                where_clause,
+                vec![],
+                Some(batch_size),
+                None,
+                &session.variables,
+                request_headers,
+                // Get all the models/commands that were used as relationships
+                &mut usage_counts,
+            )?)
+        }
+        GraphqlRequestPipeline::Old => {
+            let where_clause = match where_input {
+                Some(where_input) => Some(filter::resolve_filter_expression(
+                    where_input,
+                    &model_source.data_connector,
+                    &model_source.type_mappings,
+                    &session.variables,
+                    &mut usage_counts,
+                )?),
+                None => None,
+            };
+
+            let query_filter = filter::QueryFilter {
+                where_clause,
+                additional_filter: None,
+            };
+
+            ModelSelectManySelection::Ir(model_selection::model_selection_ir(
+                &field.selection_set,
+                data_type,
+                model_source,
+                model_arguments,
+                query_filter,
+                permissions::get_select_filter_predicate(&field_call.info)?,
+                permissions::apply_select_permission_limit(&field_call.info, Some(batch_size))?,
+                None,
+                None,
+                models,
+                commands,
+                object_types,
+                session,
+                request_headers,
+                // Get all the models/commands that were used as relationships
+                &mut usage_counts,
+            )?)
+        }
+    };
+
+    Ok(ModelSelectStream {
+        field_name: field_call.name.clone(),
+        model_selection,
+        cursor,
+        type_container: &field.type_container,
+        usage_counts,
+    })
+}
+
+/// Reads the `cursor` argument, which must provide exactly one field along with its initial value
+fn build_stream_cursor_input(
+    value: &normalized_ast::Value<'_, GDS>,
+) -> Result<StreamCursorInput, error::Error> {
+    let mut cursor_fields = value
+        .as_object()?
+        .values()
+        .filter(|cursor_field| !cursor_field.value.is_null());
+    match (cursor_fields.next(), cursor_fields.next()) {
+        (Some(cursor_field), None) => match cursor_field.info.generic {
+            Annotation::Input(graphql_schema::InputAnnotation::Model(
+                ModelInputAnnotation::ModelStreamCursorField {
+                    cursor_field: field,
+                    ..
+                },
+            )) => Ok(StreamCursorInput {
+                cursor_field: field.clone(),
+                initial_value: cursor_field.value.as_json(),
+            }),
+            annotation => Err(error::InternalEngineError::UnexpectedAnnotation {
+                annotation: annotation.clone(),
+            })?,
+        },
+        _ => Err(error::Error::StreamCursorShouldExactlyHaveOneKeyValuePair),
+    }
+}
@@ -2,6 +2,7 @@ use std::collections::BTreeMap;
 
 use indexmap::IndexMap;
 use nonempty::{nonempty, NonEmpty};
+use serde::ser::SerializeMap;
 use serde::{Deserialize, Serialize};
 
 use crate::ast::common as ast;
@@ -32,7 +33,7 @@ pub type Path = Vec<PathSegment>;
 
 /// A path segment is either a field name or an index into a list.
 /// <https://spec.graphql.org/October2021/#sel-HAPHRPJABABEyoB>
-#[derive(Serialize, Debug, PartialEq)]
+#[derive(Serialize, Clone, Debug, PartialEq)]
 #[serde(untagged)]
 pub enum PathSegment {
     /// Path segment that represent a field.
@@ -76,17 +77,60 @@ pub struct GraphQLError {
     pub is_internal: bool,
 }
 
-#[derive(Serialize)]
+/// The result of a fragment deferred with `@defer`, delivered in a subsequent payload of an
+/// incremental response.
+/// <https://github.com/graphql/graphql-wg/blob/main/rfcs/DeferStream.md>
+#[derive(Serialize, Debug, PartialEq)]
+pub struct IncrementalResult {
+    /// The fields of the deferred fragment, `null` if they could not be resolved.
+    pub data: Option<IndexMap<ast::Alias, serde_json::Value>>,
+    /// The path of the object the deferred fragment was spread on.
+    pub path: Path,
+    /// The label of the `@defer` directive, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<NonEmpty<GraphQLError>>,
+}
+
 pub struct Response {
-    #[serde(skip_serializing)]
     pub status_code: http::status::StatusCode,
-    #[serde(skip_serializing)]
     pub headers: http::HeaderMap,
     pub data: Option<IndexMap<ast::Alias, serde_json::Value>>,
     /// Errors entry shouldn't be present if no errors raised
     /// <https://spec.graphql.org/October2021/#sel-FAPHFCBUBpEm7G>
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub errors: Option<NonEmpty<GraphQLError>>,
+    /// The results of deferred fragments, only present in the subsequent payloads of an
+    /// incremental response.
+    pub incremental: Option<Vec<IncrementalResult>>,
+    /// Whether more payloads follow, only present in the payloads of an incremental response.
+    pub has_next: Option<bool>,
+    /// Additional information about the response, outside of the data and errors
+    /// <https://spec.graphql.org/October2021/#sel-EAPHJCAACCoGu9J>
+    pub extensions: Option<IndexMap<String, serde_json::Value>>,
+}
+
+impl Serialize for Response {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        // The subsequent payloads of an incremental response don't carry any data of their own
+        if self.incremental.is_none() {
+            map.serialize_entry("data", &self.data)?;
+        }
+        if let Some(errors) = &self.errors {
+            map.serialize_entry("errors", errors)?;
+        }
+        if let Some(incremental) = &self.incremental {
+            map.serialize_entry("incremental", incremental)?;
+        }
+        if let Some(has_next) = &self.has_next {
+            map.serialize_entry("hasNext", has_next)?;
+        }
+        if let Some(extensions) = &self.extensions {
+            map.serialize_entry("extensions", extensions)?;
+        }
+        map.end()
+    }
 }
 
 impl Response {
@@ -96,6 +140,9 @@ impl Response {
             headers: http::HeaderMap::default(),
             data: Some(data),
             errors: None,
+            incremental: None,
+            has_next: None,
+            extensions: None,
         }
     }
     pub fn partial(
@@ -108,6 +155,9 @@ impl Response {
             headers,
             data: Some(data),
             errors: NonEmpty::from_vec(errors),
+            incremental: None,
+            has_next: None,
+            extensions: None,
         }
     }
 
@@ -117,6 +167,9 @@ impl Response {
             headers: http::HeaderMap::default(),
             data: None,
             errors: Some(nonempty![error]),
+            incremental: None,
+            has_next: None,
+            extensions: None,
         }
     }
 
@@ -135,6 +188,9 @@ impl Response {
                 path: None,
                 extensions: None,
             }]),
+            incremental: None,
+            has_next: None,
+            extensions: None,
         }
     }
 
@@ -154,6 +210,9 @@ impl Response {
                 path: None,
                 extensions: Some(Extensions { details }),
             }]),
+            incremental: None,
+            has_next: None,
+            extensions: None,
         }
     }
 
@@ -163,6 +222,9 @@ impl Response {
             headers,
             data: None,
             errors: Some(nonempty![error]),
+            incremental: None,
+            has_next: None,
+            extensions: None,
         }
     }
 
@@ -175,6 +237,9 @@ impl Response {
             headers: http::HeaderMap::default(),
             data: None,
             errors: Some(errors),
+            incremental: None,
+            has_next: None,
+            extensions: None,
         }
     }
 
@@ -184,9 +249,41 @@ impl Response {
             headers: http::HeaderMap::default(),
             data: None,
             errors: Some(errors),
+            incremental: None,
+            has_next: None,
+            extensions: None,
+        }
+    }
+
+    /// A subsequent payload of an incremental response, with the results of deferred fragments.
+    pub fn subsequent(incremental: Vec<IncrementalResult>, has_next: bool) -> Self {
+        Self {
+            status_code: http::status::StatusCode::OK,
+            headers: http::HeaderMap::default(),
+            data: None,
+            errors: None,
+            incremental: Some(incremental),
+            has_next: Some(has_next),
+            extensions: None,
         }
     }
 
+    /// Marks the response as a payload of an incremental response.
+    #[must_use]
+    pub fn with_has_next(mut self, has_next: bool) -> Self {
+        self.has_next = Some(has_next);
+        self
+    }
+
+    /// Adds an entry to the extensions of the response.
+    #[must_use]
+    pub fn with_extension(mut self, key: String, value: serde_json::Value) -> Self {
+        self.extensions
+            .get_or_insert_with(IndexMap::new)
+            .insert(key, value);
+        self
+    }
+
     pub fn does_contains_error(&self) -> bool {
         self.errors.is_some()
     }
@@ -209,8 +306,9 @@ impl axum::response::IntoResponse for Response {
 
 #[cfg(test)]
 mod tests {
-    use super::PathSegment;
-    use crate::ast::common::Name;
+    use super::{IncrementalResult, PathSegment, Response};
+    use crate::ast::common::{Alias, Name};
+    use indexmap::IndexMap;
     use serde_json;
 
     #[test]
@@ -224,4 +322,39 @@ mod tests {
         let serialized_value = serde_json::value::to_value(path).unwrap();
         assert_eq!(serialized_value, serde_json::json!(["one", 2, 3, "four"]));
     }
+
+    #[test]
+    fn test_incremental_response_serializing() {
+        let initial = Response::ok(IndexMap::from([(
+            Alias(Name::new("article").unwrap()),
+            serde_json::json!({"id": 1}),
+        )]))
+        .with_has_next(true);
+        assert_eq!(
+            serde_json::to_value(initial).unwrap(),
+            serde_json::json!({"data": {"article": {"id": 1}}, "hasNext": true})
+        );
+
+        let subsequent = Response::subsequent(
+            vec![IncrementalResult {
+                data: Some(IndexMap::from([(
+                    Alias(Name::new("author").unwrap()),
+                    serde_json::json!({"name": "Jane"}),
+                )])),
+                path: vec![PathSegment::field(Name::new("article").unwrap())],
+                label: Some("author".to_owned()),
+                errors: None,
+            }],
+            false,
+        );
+        assert_eq!(
+            serde_json::to_value(subsequent).unwrap(),
+            serde_json::json!({
+                "incremental": [
+                    {"data": {"author": {"name": "Jane"}}, "path": ["article"], "label": "author"}
+                ],
+                "hasNext": false
+            })
+        );
+    }
 }
@@ -249,6 +249,16 @@ pub struct Field<'s, S: SchemaContext> {
     pub field_calls: FieldCalls<'s, S>,
     pub selection_set: SelectionSet<'s, S>,
     pub type_container: TypeContainer<TypeName>,
+    /// Set if the field is only selected in fragments deferred with `@defer`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deferred: Option<DeferredFragment>,
+}
+
+/// A fragment deferred with `@defer`, whose fields are delivered after the initial
+/// payload of the response.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DeferredFragment {
+    pub label: Option<String>,
 }
 
 impl<'s, S: SchemaContext> Field<'s, S> {
@@ -318,6 +328,7 @@ impl<'s, S: SchemaContext> SelectionSet<'s, S> {
                         .selection_set
                         .filter_field_calls_by_typename(type_name.clone()),
                     type_container: field.type_container.clone(),
+                    deferred: field.deferred.clone(),
                 };
                 filtered_selection_set_fields.insert(alias.clone(), new_field);
             }
@@ -328,6 +339,37 @@ impl<'s, S: SchemaContext> SelectionSet<'s, S> {
         }
     }
 
+    /// Whether any field of the selection set, or of the nested selection sets, is deferred.
+    pub fn has_deferred_fields(&self) -> bool {
+        self.fields
+            .values()
+            .any(|field| field.deferred.is_some() || field.selection_set.has_deferred_fields())
+    }
+
+    /// Returns the selection set without its deferred fields, nor those of the nested selection
+    /// sets, ie. the fields of the initial payload of the response.
+    pub fn without_deferred_fields(&self) -> SelectionSet<'s, S> {
+        let fields = self
+            .fields
+            .iter()
+            .filter(|(_, field)| field.deferred.is_none())
+            .map(|(alias, field)| {
+                let new_field = Field {
+                    alias: alias.clone(),
+                    field_calls: field.field_calls.clone(),
+                    selection_set: field.selection_set.without_deferred_fields(),
+                    type_container: field.type_container.clone(),
+                    deferred: None,
+                };
+                (alias.clone(), new_field)
+            })
+            .collect();
+        SelectionSet {
+            fields,
+            type_name: self.type_name.clone(),
+        }
+    }
+
     /// `as_object_selection_set` provides a way to iterate over the fields in the selection set
     /// and convert them into a JSON object.
     /// This also handles the special case of the `__typename` field.
@@ -122,6 +122,18 @@ pub(super) struct CollectedField<'q, 's, S: schema::SchemaContext> {
     pub field_path: Vec<&'s ast::TypeName>,
     pub reachable: bool,
     pub field: &'q executable::Field,
+    /// The `@defer` directive of the innermost deferred fragment the field was collected from
+    pub defer: Option<&'q executable::Directive>,
+}
+
+/// Returns the `@defer` directive among the directives of a fragment, if any.
+fn find_defer_directive(
+    directives: &[spanning::Spanning<executable::Directive>],
+) -> Option<&executable::Directive> {
+    directives
+        .iter()
+        .map(|directive| &directive.item)
+        .find(|directive| directive.name.item.as_str() == "defer")
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -141,6 +153,7 @@ fn collect_fields_from_fragment<
     selection_set_reachability: &HashSet<&'s ast::TypeName>,
     fragment_selection_type: &SelectableType<'s, S>,
     fragment_selection_set: &'q executable::SelectionSet,
+    defer: Option<&'q executable::Directive>,
     fields: &mut Vec<CollectedField<'q, 's, S>>,
 ) -> Result<()> {
     let common_types: HashSet<&ast::TypeName> = selection_type
@@ -205,6 +218,7 @@ fn collect_fields_from_fragment<
         &fragment_reachability,
         fragment_to_be_coerced_as,
         &fragment_selection_set.items,
+        defer,
         fields,
     )?;
     Ok(())
@@ -238,6 +252,7 @@ pub(super) fn collect_fields<
         &selection_type.possible_types,
         None,
         selection_set,
+        None,
         fields,
     )
 }
@@ -252,6 +267,7 @@ fn collect_fields_internal<'q, 's, S: schema::SchemaContext, NSGet: schema::Name
     selection_set_reachability: &HashSet<&'s ast::TypeName>,
     selection_sub_type: Option<&SelectableType<'s, S>>,
     selection_set: &'q [spanning::Spanning<executable::Selection>],
+    defer: Option<&'q executable::Directive>,
     fields: &mut Vec<CollectedField<'q, 's, S>>,
 ) -> Result<()> {
     for selection in selection_set {
@@ -285,6 +301,7 @@ fn collect_fields_internal<'q, 's, S: schema::SchemaContext, NSGet: schema::Name
                     info: refined_field_info,
                     field,
                     reachable: !selection_set_reachability.is_empty(),
+                    defer,
                 });
             }
             executable::Selection::FragmentSpread(spread) => {
@@ -311,6 +328,7 @@ fn collect_fields_internal<'q, 's, S: schema::SchemaContext, NSGet: schema::Name
                     selection_set_reachability,
                     &fragment_selection_type,
                     &fragment_definition.selection_set.item,
+                    find_defer_directive(&spread.directives).or(defer),
                     fields,
                 )?;
             }
@@ -338,6 +356,7 @@ fn collect_fields_internal<'q, 's, S: schema::SchemaContext, NSGet: schema::Name
                     selection_set_reachability,
                     fragment_selection_type.as_ref().unwrap_or(selection_type),
                     &spread.selection_set.item,
+                    find_defer_directive(&spread.directives).or(defer),
                     fields,
                 )?;
             }
@@ -161,4 +161,15 @@ pub enum Error {
     },
     #[error("no fields are selected")]
     FieldSelectionSetIsEmpty,
+    #[error("unknown argument {argument_name} on directive @{directive_name}")]
+    UnknownDirectiveArgument {
+        directive_name: ast::Name,
+        argument_name: ast::Name,
+    },
+    #[error("expected a value of type {expected_type} for argument {argument_name} on directive @{directive_name}")]
+    InvalidDirectiveArgument {
+        directive_name: ast::Name,
+        argument_name: ast::Name,
+        expected_type: &'static str,
+    },
 }
@@ -10,6 +10,7 @@ use crate::ast::common as ast;
 use crate::ast::executable;
 use crate::ast::spanning;
 use crate::ast::spanning::Spanning;
+use crate::ast::value as gql;
 use crate::normalized_ast as normalized;
 use crate::schema;
 
@@ -95,8 +96,26 @@ where
             });
         acc
     });
+    // A field is only deferred if all of its selections are in deferred fragments, in which
+    // case it is delivered with the first of them.
+    let mut deferred_fields = HashMap::new();
+    for field in &fields {
+        let deferred = match field.defer {
+            Some(directive) => normalize_defer_directive(variables, directive)?,
+            None => None,
+        };
+        deferred_fields
+            .entry(field.alias)
+            .and_modify(|existing: &mut Option<normalized::DeferredFragment>| {
+                if deferred.is_none() {
+                    *existing = None;
+                }
+            })
+            .or_insert(deferred);
+    }
     let mut normalized_fields = IndexMap::new();
     for (alias, (alias_type, typed_fields)) in field_map {
+        let deferred = deferred_fields.remove(alias).flatten();
         let alias = ast::Alias(alias.clone());
         let (field_calls, selection_set) = merge_fields(
             namespaced_getter,
@@ -115,6 +134,7 @@ where
                 field_calls,
                 selection_set,
                 type_container: alias_type.clone(),
+                deferred,
             };
             normalized_fields.insert(alias, normalized_field);
         }
@@ -258,6 +278,63 @@ where
     ))
 }
 
+/// Normalizes the `@defer` directive of a fragment. Returns `None` if the fragment is not
+/// deferred, ie. when the `if` argument is `false`.
+fn normalize_defer_directive<'q, 's, S: schema::SchemaContext>(
+    variables: &input::value::Variables<'q, 's, S>,
+    directive: &'q executable::Directive,
+) -> Result<Option<normalized::DeferredFragment>> {
+    let mut label = None;
+    if let Some(arguments) = &directive.arguments {
+        for argument in &arguments.item {
+            let argument_name = &argument.item.key.item;
+            let value = directive_argument_value(variables, &argument.item.value.item);
+            let invalid_argument = |expected_type| Error::InvalidDirectiveArgument {
+                directive_name: directive.name.item.clone(),
+                argument_name: argument_name.clone(),
+                expected_type,
+            };
+            match argument_name.as_str() {
+                "if" => match value {
+                    Some(serde_json::Value::Bool(true)) => {}
+                    Some(serde_json::Value::Bool(false)) => return Ok(None),
+                    _ => return Err(invalid_argument("Boolean!")),
+                },
+                "label" => match value {
+                    Some(serde_json::Value::String(value)) => label = Some(value),
+                    Some(serde_json::Value::Null) | None => {}
+                    _ => return Err(invalid_argument("String")),
+                },
+                _ => {
+                    return Err(Error::UnknownDirectiveArgument {
+                        directive_name: directive.name.item.clone(),
+                        argument_name: argument_name.clone(),
+                    })
+                }
+            }
+        }
+    }
+    Ok(Some(normalized::DeferredFragment { label }))
+}
+
+/// Resolves the value of a directive argument, which can only be a scalar or a variable.
+fn directive_argument_value<'q, 's, S: schema::SchemaContext>(
+    variables: &input::value::Variables<'q, 's, S>,
+    value: &gql::Value,
+) -> Option<serde_json::Value> {
+    match value {
+        gql::Value::Variable(variable) => variables.values.get(variable).cloned().or_else(|| {
+            let (definition, _) = variables.definitions.get(variable)?;
+            definition
+                .default_value
+                .as_ref()
+                .map(|default_value| default_value.item.to_json())
+        }),
+        gql::Value::SimpleValue(value) => Some(value.to_json()),
+        gql::Value::List(_) | gql::Value::Object(_) => None,
+    }
+}
+
 fn normalize_arguments<'q, 's, S: schema::SchemaContext, NSGet: schema::NamespacedGetter<S>>(
     namespaced_getter: &NSGet,
     schema: &'s schema::Schema<S>,
@@ -163,13 +163,12 @@ fn add_aggregatable_fields(
         );
 
         // Only allow access to aggregations of the field if the type permissions allow it
+        // on all objects
         let allowed_roles = object_type
             .type_output_permissions
             .iter()
             .filter(|(_role, perms)| {
-                perms
-                    .allowed_fields
-                    .contains(&aggregatable_field_info.field_name)
+                perms.is_unconditionally_allowed(&aggregatable_field_info.field_name)
             })
             .map(|(role, _perms)| (role.clone(), None))
             .collect::<HashMap<Role, Option<NamespaceAnnotation>>>();
@@ -329,7 +328,7 @@ fn add_aggregation_functions(
     Ok(())
 }
 
-fn get_object_type<'a>(
+pub(crate) fn get_object_type<'a>(
     gds: &'a GDS,
     type_name: &'a QualifiedTypeName,
 ) -> Option<(
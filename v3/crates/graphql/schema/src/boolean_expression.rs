@@ -15,15 +15,18 @@ use super::types::output_type::get_object_type_representation;
 use super::types::output_type::relationship::FilterRelationshipAnnotation;
 use super::types::{ObjectFieldKind, TypeId};
 use metadata_resolve::{
-    mk_name, BooleanExpressionComparableRelationship, ComparisonExpressionInfo,
-    GlobalGraphqlConfig, IncludeLogicalOperators, ModelWithPermissions,
-    ObjectBooleanExpressionGraphqlConfig, ObjectComparisonExpressionInfo, ObjectComparisonKind,
-    ObjectTypeWithRelationships, OperatorMapping, Qualified, QualifiedTypeReference,
+    mk_name, BooleanExpressionComparableRelationship, BooleanExpressionTypeIdentifier,
+    ComparableCountAggregation, ComparisonExpressionInfo, FilterInputDefinition,
+    FromModelFilterInputDefinition, GlobalGraphqlConfig, IncludeLogicalOperators, IsNullOperator,
+    ModelWithPermissions, ObjectAggregateBooleanExpression, ObjectBooleanExpressionGraphqlConfig,
+    ObjectComparisonExpressionInfo, ObjectComparisonKind, ObjectTypeWithRelationships,
+    OperatorMapping, Qualified, QualifiedTypeName, QualifiedTypeReference,
     RelationshipCapabilities, RelationshipField, RelationshipModelMapping,
     ResolvedObjectBooleanExpressionType, ScalarBooleanExpressionGraphqlConfig,
     ScalarComparisonKind,
 };
 
+use crate::aggregates::AggregationFunctionAnnotation;
 use crate::mk_deprecation_status;
 use crate::permissions;
 use crate::types;
@@ -324,7 +327,10 @@ fn build_new_comparable_relationships_schema(
                 relationship_type,
                 target_typename: _,
                 mappings,
-                relationship_aggregate: _,
+                value_mappings: _,
+                argument_mappings: _,
+                relationship_aggregate,
+                connection_field_name: _,
             } = model_relationship_target.as_ref();
             // lookup target model for relationship
             let target_model = gds.metadata.models.get(model_name).ok_or_else(|| {
@@ -389,6 +395,34 @@ fn build_new_comparable_relationships_schema(
 
                 input_fields.insert(name, schema);
             }
+
+            // if the relationship can be compared using aggregates over its target rows,
+            // add the `<relationship>_aggregate` predicate field
+            if let (
+                Some(aggregate_boolean_expression_type_name),
+                Some(relationship_aggregate),
+                Some(target_source),
+            ) = (
+                &comparable_relationship.aggregate_boolean_expression_type,
+                relationship_aggregate,
+                &target_model.model.source,
+            ) {
+                if let Some((name, schema)) = build_model_relationship_aggregate_schema(
+                    object_type_representation,
+                    target_object_type_representation,
+                    aggregate_boolean_expression_type_name,
+                    relationship_aggregate,
+                    target_model,
+                    target_source,
+                    relationship,
+                    relationship_type,
+                    mappings,
+                    gds,
+                    builder,
+                )? {
+                    input_fields.insert(name, schema);
+                }
+            }
         }
     }
     Ok(input_fields)
@@ -435,6 +469,10 @@ fn build_model_relationship_schema(
         source_object_type_representation,
         target_object_type_representation,
         relationship_model_mappings,
+        // relationships with value or argument mappings can't be used in predicates
+        &[],
+        &[],
+        &gds.metadata.object_types,
     );
 
     Ok((
@@ -467,6 +505,105 @@ fn build_model_relationship_schema(
     ))
 }
 
+// build the `<relationship>_aggregate` input field, which compares the results of aggregating
+// the rows of an array relationship
+fn build_model_relationship_aggregate_schema(
+    source_object_type_representation: &ObjectTypeWithRelationships,
+    target_object_type_representation: &ObjectTypeWithRelationships,
+    aggregate_boolean_expression_type_name: &Qualified<CustomTypeName>,
+    relationship_aggregate: &metadata_resolve::AggregateRelationship,
+    target_model: &ModelWithPermissions,
+    target_source: &Arc<metadata_resolve::ModelSource>,
+    relationship: &RelationshipField,
+    relationship_type: &RelationshipType,
+    relationship_model_mappings: &[RelationshipModelMapping],
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+) -> Result<Option<InputField>, Error> {
+    let object_aggregate_boolean_expression = gds
+        .metadata
+        .boolean_expression_types
+        .object_aggregates
+        .get(aggregate_boolean_expression_type_name)
+        .ok_or_else(|| Error::InternalBooleanExpressionNotFound {
+            type_name: aggregate_boolean_expression_type_name.clone(),
+        })?;
+
+    let Some(predicate_graphql) = &object_aggregate_boolean_expression.predicate_graphql else {
+        return Ok(None);
+    };
+
+    // Aggregate comparisons are always pushed down to the data connector, so we only
+    // include them when the data connector can evaluate them
+    if !target_source
+        .data_connector
+        .capabilities
+        .supports_aggregates
+        .as_ref()
+        .is_some_and(|aggregates| aggregates.supports_filtering_by_aggregates)
+    {
+        return Ok(None);
+    }
+
+    let target_model_source =
+        metadata_resolve::ModelTargetSource::from_model_source(target_source, relationship)
+            .map_err(metadata_resolve::WithContext::from)?;
+
+    let annotation = FilterRelationshipAnnotation {
+        source_type: relationship.source.clone(),
+        relationship_name: relationship.relationship_name.clone(),
+        target_source: target_model_source,
+        target_type: target_model.model.data_type.clone(),
+        target_model_name: target_model.model.name.clone(),
+        relationship_type: relationship_type.clone(),
+        mappings: relationship_model_mappings.to_vec(),
+        deprecated: relationship.deprecated.clone(),
+    };
+
+    let namespace_annotations = permissions::get_model_relationship_namespace_annotations(
+        target_model,
+        source_object_type_representation,
+        target_object_type_representation,
+        relationship_model_mappings,
+        // relationships with value or argument mappings can't be used in predicates
+        &[],
+        &[],
+        &gds.metadata.object_types,
+    );
+
+    let registered_type_name = builder.register_type(TypeId::RelationshipAggregatePredicateType {
+        gds_type_name: aggregate_boolean_expression_type_name.clone(),
+        graphql_type_name: predicate_graphql.type_name.clone(),
+    });
+
+    Ok(Some((
+        relationship_aggregate.field_name.clone(),
+        builder.conditional_namespaced(
+            gql_schema::InputField::<GDS>::new(
+                relationship_aggregate.field_name.clone(),
+                relationship_aggregate.description.clone(),
+                types::Annotation::Input(types::InputAnnotation::BooleanExpression(
+                    types::BooleanExpressionAnnotation::ObjectBooleanExpressionField(
+                        types::ObjectBooleanExpressionField::RelationshipAggregateField(annotation),
+                    ),
+                )),
+                ast::TypeContainer::named_null(registered_type_name),
+                None,
+                if gds
+                    .metadata
+                    .graphql_config
+                    .propagate_boolean_expression_deprecation_status
+                {
+                    mk_deprecation_status(relationship.deprecated.as_ref())
+                } else {
+                    gql_schema::DeprecationStatus::NotDeprecated
+                },
+            ),
+            namespace_annotations,
+        ),
+    )))
+}
+
 // build the schema using the new `BooleanExpressionType` metadata kind
 fn build_schema_with_boolean_expression_type(
     boolean_expression_object_type: &ResolvedObjectBooleanExpressionType,
@@ -670,3 +807,474 @@ pub fn build_scalar_boolean_expression_input(
         gql_schema::InputObject::new(type_name.clone(), None, input_fields, Vec::new()),
     ))
 }
+
+/// Build the type of the `<relationship>_aggregate` input field, which filters the rows of the
+/// relationship and then compares the results of aggregating them
+pub fn build_relationship_aggregate_predicate_input(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    type_name: &ast::TypeName,
+    gds_type_name: &Qualified<CustomTypeName>,
+) -> Result<gql_schema::TypeInfo<GDS>, Error> {
+    let object_aggregate_boolean_expression =
+        get_object_aggregate_boolean_expression(gds, gds_type_name)?;
+
+    let mut input_fields = BTreeMap::new();
+
+    // add the field used to filter the rows before they are aggregated
+    if let (
+        Some(filter_input_field_name),
+        Some(FilterInputDefinition::FromModel(FromModelFilterInputDefinition { model_name })),
+    ) = (
+        object_aggregate_boolean_expression
+            .predicate_graphql
+            .as_ref()
+            .and_then(|graphql| graphql.filter_input_field_name.as_ref()),
+        &object_aggregate_boolean_expression.filter_input,
+    ) {
+        let model =
+            gds.metadata
+                .models
+                .get(model_name)
+                .ok_or_else(|| Error::InternalModelNotFound {
+                    model_name: model_name.clone(),
+                })?;
+
+        if let Some((filter_expression_type, filter_expression_graphql)) = model
+            .filter_expression_type
+            .as_ref()
+            .and_then(|filter_expression_type| {
+                filter_expression_type
+                    .graphql
+                    .as_ref()
+                    .map(|graphql| (filter_expression_type, graphql))
+            })
+        {
+            let registered_type_name =
+                builder.register_type(TypeId::InputObjectBooleanExpressionType {
+                    graphql_type_name: filter_expression_graphql.type_name.clone(),
+                    gds_type_name: filter_expression_type.name.clone(),
+                });
+
+            input_fields.insert(
+                filter_input_field_name.clone(),
+                builder.allow_all_namespaced(gql_schema::InputField::<GDS>::new(
+                    filter_input_field_name.clone(),
+                    None,
+                    types::Annotation::Input(types::InputAnnotation::BooleanExpression(
+                        types::BooleanExpressionAnnotation::AggregateBooleanExpressionField(
+                            types::AggregateBooleanExpressionField::FilterInputField,
+                        ),
+                    )),
+                    ast::TypeContainer::named_null(registered_type_name),
+                    None,
+                    gql_schema::DeprecationStatus::NotDeprecated,
+                )),
+            );
+        }
+    }
+
+    // add the comparisons against the aggregates of the filtered rows
+    input_fields.extend(build_count_aggregation_comparisons_schema(
+        gds,
+        builder,
+        object_aggregate_boolean_expression
+            .count_aggregation
+            .as_ref(),
+        object_aggregate_boolean_expression
+            .count_distinct_aggregation
+            .as_ref(),
+    )?);
+
+    input_fields.extend(build_comparable_aggregatable_fields_schema(
+        gds,
+        builder,
+        object_aggregate_boolean_expression,
+    )?);
+
+    Ok(gql_schema::TypeInfo::InputObject(
+        gql_schema::InputObject::new(type_name.clone(), None, input_fields, Vec::new()),
+    ))
+}
+
+/// Build the input type that compares the aggregates of an object type,
+/// using an `objectAggregate` boolean expression type
+pub fn build_object_aggregate_boolean_expression_input(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    type_name: &ast::TypeName,
+    gds_type_name: &Qualified<CustomTypeName>,
+) -> Result<gql_schema::TypeInfo<GDS>, Error> {
+    let object_aggregate_boolean_expression =
+        get_object_aggregate_boolean_expression(gds, gds_type_name)?;
+
+    let mut input_fields = build_aggregate_logical_operators_schema(
+        &object_aggregate_boolean_expression.logical_operators,
+        type_name,
+        builder,
+    );
+
+    input_fields.extend(build_count_aggregation_comparisons_schema(
+        gds,
+        builder,
+        object_aggregate_boolean_expression
+            .count_aggregation
+            .as_ref(),
+        object_aggregate_boolean_expression
+            .count_distinct_aggregation
+            .as_ref(),
+    )?);
+
+    input_fields.extend(build_comparable_aggregatable_fields_schema(
+        gds,
+        builder,
+        object_aggregate_boolean_expression,
+    )?);
+
+    Ok(gql_schema::TypeInfo::InputObject(
+        gql_schema::InputObject::new(type_name.clone(), None, input_fields, Vec::new()),
+    ))
+}
+
+/// Build the input type that compares the aggregates of a scalar type,
+/// using a `scalarAggregate` boolean expression type
+pub fn build_scalar_aggregate_boolean_expression_input(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    type_name: &ast::TypeName,
+    gds_type_name: &Qualified<CustomTypeName>,
+) -> Result<gql_schema::TypeInfo<GDS>, Error> {
+    let scalar_aggregate_boolean_expression = gds
+        .metadata
+        .boolean_expression_types
+        .scalar_aggregates
+        .get(gds_type_name)
+        .ok_or_else(|| Error::InternalBooleanExpressionNotFound {
+            type_name: gds_type_name.clone(),
+        })?;
+
+    let aggregate_expression = gds
+        .metadata
+        .aggregate_expressions
+        .get(&scalar_aggregate_boolean_expression.aggregate_expression)
+        .ok_or_else(|| Error::InternalAggregateExpressionNotFound {
+            aggregate_expression: scalar_aggregate_boolean_expression
+                .aggregate_expression
+                .clone(),
+        })?;
+
+    let mut input_fields = build_aggregate_logical_operators_schema(
+        &scalar_aggregate_boolean_expression.logical_operators,
+        type_name,
+        builder,
+    );
+
+    input_fields.extend(build_count_aggregation_comparisons_schema(
+        gds,
+        builder,
+        scalar_aggregate_boolean_expression
+            .count_aggregation
+            .as_ref(),
+        scalar_aggregate_boolean_expression
+            .count_distinct_aggregation
+            .as_ref(),
+    )?);
+
+    for comparable_aggregation_function in
+        &scalar_aggregate_boolean_expression.aggregation_functions
+    {
+        let aggregation_function_info = aggregate_expression
+            .operand
+            .aggregation_functions
+            .iter()
+            .find(|aggregation_function_info| {
+                aggregation_function_info.name
+                    == comparable_aggregation_function.aggregate_function_name
+            })
+            .ok_or_else(|| Error::InternalAggregateExpressionNotFound {
+                aggregate_expression: aggregate_expression.name.clone(),
+            })?;
+
+        let Some(comparison_type_name) = get_aggregate_comparison_type(
+            gds,
+            builder,
+            &comparable_aggregation_function.boolean_expression_type,
+        )?
+        else {
+            continue;
+        };
+
+        let field_graphql_name = mk_name(
+            comparable_aggregation_function
+                .aggregate_function_name
+                .as_str(),
+        )
+        .map_err(metadata_resolve::Error::from)
+        .map_err(metadata_resolve::WithContext::from)?;
+
+        input_fields.insert(
+            field_graphql_name.clone(),
+            builder.allow_all_namespaced(gql_schema::InputField::<GDS>::new(
+                field_graphql_name,
+                comparable_aggregation_function.description.clone(),
+                types::Annotation::Input(types::InputAnnotation::BooleanExpression(
+                    types::BooleanExpressionAnnotation::AggregateBooleanExpressionField(
+                        types::AggregateBooleanExpressionField::AggregationFunctionField(
+                            AggregationFunctionAnnotation::Function {
+                                function_name: aggregation_function_info.name.clone(),
+                                aggregate_expression: aggregate_expression.name.name.clone(),
+                                data_connector_functions: aggregation_function_info
+                                    .data_connector_functions
+                                    .clone(),
+                            },
+                        ),
+                    ),
+                )),
+                ast::TypeContainer::named_null(comparison_type_name),
+                None,
+                gql_schema::DeprecationStatus::NotDeprecated,
+            )),
+        );
+    }
+
+    Ok(gql_schema::TypeInfo::InputObject(
+        gql_schema::InputObject::new(type_name.clone(), None, input_fields, Vec::new()),
+    ))
+}
+
+fn get_object_aggregate_boolean_expression<'a>(
+    gds: &'a GDS,
+    gds_type_name: &Qualified<CustomTypeName>,
+) -> Result<&'a ObjectAggregateBooleanExpression, Error> {
+    gds.metadata
+        .boolean_expression_types
+        .object_aggregates
+        .get(gds_type_name)
+        .ok_or_else(|| Error::InternalBooleanExpressionNotFound {
+            type_name: gds_type_name.clone(),
+        })
+}
+
+// add `_and`, `_or` and `_not` fields to an aggregate boolean expression, if enabled
+fn build_aggregate_logical_operators_schema(
+    logical_operators: &metadata_resolve::LogicalOperators,
+    type_name: &ast::TypeName,
+    builder: &mut gql_schema::Builder<GDS>,
+) -> BTreeMap<ast::Name, gql_schema::Namespaced<GDS, gql_schema::InputField<GDS>>> {
+    match logical_operators {
+        metadata_resolve::LogicalOperators::Include {
+            graphql: Some(graphql_config),
+        } => build_logical_operators_schema(graphql_config, type_name, builder, |ann| {
+            types::BooleanExpressionAnnotation::AggregateBooleanExpressionField(
+                types::AggregateBooleanExpressionField::LogicalOperatorField(ann),
+            )
+        }),
+        metadata_resolve::LogicalOperators::Include { graphql: None }
+        | metadata_resolve::LogicalOperators::Exclude => BTreeMap::new(),
+    }
+}
+
+// add the `_count` and `_count_distinct` comparison fields, if they are enabled
+fn build_count_aggregation_comparisons_schema(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    count_aggregation: Option<&ComparableCountAggregation>,
+    count_distinct_aggregation: Option<&ComparableCountAggregation>,
+) -> Result<BTreeMap<ast::Name, gql_schema::Namespaced<GDS, gql_schema::InputField<GDS>>>, Error> {
+    let mut input_fields = BTreeMap::new();
+
+    for (comparable_count, aggregation_function) in [
+        (count_aggregation, AggregationFunctionAnnotation::Count),
+        (
+            count_distinct_aggregation,
+            AggregationFunctionAnnotation::CountDistinct,
+        ),
+    ] {
+        let Some((comparable_count, count_graphql)) = comparable_count
+            .and_then(|count| count.graphql.as_ref().map(|graphql| (count, graphql)))
+        else {
+            continue;
+        };
+
+        let Some(comparison_type_name) =
+            get_aggregate_comparison_type(gds, builder, &comparable_count.boolean_expression_type)?
+        else {
+            continue;
+        };
+
+        // All roles can compare the number of rows
+        input_fields.insert(
+            count_graphql.field_name.clone(),
+            builder.allow_all_namespaced(gql_schema::InputField::<GDS>::new(
+                count_graphql.field_name.clone(),
+                None,
+                types::Annotation::Input(types::InputAnnotation::BooleanExpression(
+                    types::BooleanExpressionAnnotation::AggregateBooleanExpressionField(
+                        types::AggregateBooleanExpressionField::AggregationFunctionField(
+                            aggregation_function,
+                        ),
+                    ),
+                )),
+                ast::TypeContainer::named_null(comparison_type_name),
+                None,
+                gql_schema::DeprecationStatus::NotDeprecated,
+            )),
+        );
+    }
+
+    Ok(input_fields)
+}
+
+// add the fields of the aggregated object type, whose values can be aggregated and compared
+fn build_comparable_aggregatable_fields_schema(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    object_aggregate_boolean_expression: &ObjectAggregateBooleanExpression,
+) -> Result<BTreeMap<ast::Name, gql_schema::Namespaced<GDS, gql_schema::InputField<GDS>>>, Error> {
+    let mut input_fields = BTreeMap::new();
+
+    let object_type_representation =
+        get_object_type_representation(gds, &object_aggregate_boolean_expression.operand_type)?;
+
+    for comparable_field in &object_aggregate_boolean_expression.comparable_fields {
+        let aggregate_boolean_expression_type_name =
+            &comparable_field.aggregate_boolean_expression_type;
+
+        let (registered_type_name, aggregate_operand_type) = if let Some(scalar_aggregate) = gds
+            .metadata
+            .boolean_expression_types
+            .scalar_aggregates
+            .get(aggregate_boolean_expression_type_name)
+        {
+            let Some(graphql) = &scalar_aggregate.graphql else {
+                continue;
+            };
+            (
+                builder.register_type(TypeId::InputScalarAggregateBooleanExpressionType {
+                    gds_type_name: aggregate_boolean_expression_type_name.clone(),
+                    graphql_type_name: graphql.type_name.clone(),
+                }),
+                scalar_aggregate.operand_type.clone(),
+            )
+        } else {
+            let object_aggregate = get_object_aggregate_boolean_expression(
+                gds,
+                aggregate_boolean_expression_type_name,
+            )?;
+            let Some(graphql) = &object_aggregate.graphql else {
+                continue;
+            };
+            (
+                builder.register_type(TypeId::InputObjectAggregateBooleanExpressionType {
+                    gds_type_name: aggregate_boolean_expression_type_name.clone(),
+                    graphql_type_name: graphql.type_name.clone(),
+                }),
+                QualifiedTypeName::Custom(object_aggregate.operand_type.clone()),
+            )
+        };
+
+        let field_graphql_name = mk_name(comparable_field.field_name.as_str())
+            .map_err(metadata_resolve::Error::from)
+            .map_err(metadata_resolve::WithContext::from)?;
+
+        // Only allow comparing aggregations of the field if the type permissions allow it
+        // on all objects
+        let field_permissions: HashMap<Role, Option<types::NamespaceAnnotation>> =
+            object_type_representation
+                .type_output_permissions
+                .iter()
+                .filter(|(_role, perms)| {
+                    perms.is_unconditionally_allowed(&comparable_field.field_name)
+                })
+                .map(|(role, _perms)| (role.clone(), None))
+                .collect();
+
+        input_fields.insert(
+            field_graphql_name.clone(),
+            builder.conditional_namespaced(
+                gql_schema::InputField::<GDS>::new(
+                    field_graphql_name,
+                    comparable_field.description.clone(),
+                    types::Annotation::Input(types::InputAnnotation::BooleanExpression(
+                        types::BooleanExpressionAnnotation::AggregateBooleanExpressionField(
+                            types::AggregateBooleanExpressionField::AggregatableField {
+                                field_name: comparable_field.field_name.clone(),
+                                aggregate_operand_type,
+                            },
+                        ),
+                    )),
+                    ast::TypeContainer::named_null(registered_type_name),
+                    None,
+                    gql_schema::DeprecationStatus::NotDeprecated,
+                ),
+                field_permissions,
+            ),
+        );
+    }
+
+    Ok(input_fields)
+}
+
+// get the scalar comparison type used to compare the result of an aggregation. This is
+// registered exactly as it is for comparable fields of object boolean expressions, so that
+// both uses share the same GraphQL type
+fn get_aggregate_comparison_type(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    boolean_expression_type: &BooleanExpressionTypeIdentifier,
+) -> Result<Option<gql_schema::RegisteredTypeName>, Error> {
+    let scalar_boolean_expression = gds
+        .metadata
+        .boolean_expression_types
+        .scalars
+        .get(boolean_expression_type)
+        .ok_or_else(|| Error::InternalScalarBooleanExpressionNotFound {
+            boolean_expression_type: boolean_expression_type.clone(),
+        })?;
+
+    let Some(graphql_name) = &scalar_boolean_expression.graphql_name else {
+        return Ok(None);
+    };
+
+    let graphql_type_name = mk_name(graphql_name.as_str())
+        .map(ast::TypeName)
+        .map_err(metadata_resolve::Error::from)
+        .map_err(metadata_resolve::WithContext::from)?;
+
+    let mut operators = Vec::new();
+    for (op_name, input_type) in &scalar_boolean_expression.comparison_operators {
+        let op_name = mk_name(op_name.as_str())
+            .map_err(metadata_resolve::Error::from)
+            .map_err(metadata_resolve::WithContext::from)?;
+
+        operators.push((op_name, input_type.clone()));
+    }
+
+    let operator_mapping = scalar_boolean_expression
+        .data_connector_operator_mappings
+        .iter()
+        .map(|(data_connector_name, mappings)| {
+            (
+                data_connector_name.clone(),
+                OperatorMapping(mappings.operator_mapping.clone()),
+            )
+        })
+        .collect();
+
+    let is_null_operator_name = match &scalar_boolean_expression.is_null_operator {
+        IsNullOperator::Include {
+            graphql: Some(graphql),
+        } => Some(graphql.is_null_operator_name.clone()),
+        IsNullOperator::Include { graphql: None } | IsNullOperator::Exclude => None,
+    };
+
+    Ok(Some(builder.register_type(
+        TypeId::InputScalarBooleanExpressionType {
+            graphql_type_name,
+            operators,
+            operator_mapping,
+            is_null_operator_name,
+            logical_operators: scalar_boolean_expression.logical_operators.clone(),
+        },
+    )))
+}
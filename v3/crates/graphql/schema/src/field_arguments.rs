@@ -1,11 +1,14 @@
 use lang_graphql::{ast::common as ast, schema as gql_schema};
-use open_dds::arguments::ArgumentName;
+use open_dds::{arguments::ArgumentName, types::FieldName};
+use std::collections::HashMap;
 
-use crate::{types, Annotation, GDS};
+use crate::{types, Annotation, Role, GDS};
 
 pub fn generate_field_argument(
     gds: &GDS,
     builder: &mut gql_schema::Builder<GDS>,
+    object_type_representation: &metadata_resolve::ObjectTypeWithRelationships,
+    field_name: &FieldName,
     argument_name: &ArgumentName,
     argument_type: &metadata_resolve::FieldArgumentInfo,
 ) -> Result<
@@ -15,11 +18,11 @@ pub fn generate_field_argument(
     ),
     crate::Error,
 > {
-    let field_name = ast::Name::new(argument_name.as_str())?;
+    let graphql_argument_name = ast::Name::new(argument_name.as_str())?;
     let input_type = types::input_type::get_input_type(gds, builder, &argument_type.argument_type)?;
 
     let input_field = gql_schema::InputField::new(
-        field_name.clone(),
+        graphql_argument_name.clone(),
         argument_type.description.clone(),
         Annotation::Input(types::InputAnnotation::FieldArgument {
             argument_name: argument_name.clone(),
@@ -29,5 +32,20 @@ pub fn generate_field_argument(
         gql_schema::DeprecationStatus::NotDeprecated,
     );
 
-    Ok((field_name, builder.allow_all_namespaced(input_field)))
+    // a role is "allowed" to use this argument if it DOESN'T have a preset argument defined
+    let mut role_map = HashMap::new();
+    for (role, permission) in &object_type_representation.type_output_permissions {
+        let has_preset = permission
+            .field_argument_presets
+            .get(field_name)
+            .is_some_and(|argument_presets| argument_presets.contains_key(argument_name));
+        if !has_preset {
+            role_map.insert(Role(role.0.clone()), None);
+        }
+    }
+
+    Ok((
+        graphql_argument_name,
+        builder.conditional_namespaced(input_field, role_map),
+    ))
 }
@@ -15,6 +15,7 @@ mod relay;
 mod subscription_root;
 mod types;
 
+use std::collections::BTreeSet;
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -33,17 +34,20 @@ use open_dds::{
 };
 
 pub use aggregates::{AggregateOutputAnnotation, AggregationFunctionAnnotation};
+pub use mutation_root::model_mutations::ModelMutationResponseOutputAnnotation;
+pub use query_root::select_group_by::GroupByOutputAnnotation;
+pub use relay::ConnectionOutputAnnotation;
 pub use types::output_type::relationship::{
     CommandRelationshipAnnotation, CommandTargetSource, FilterRelationshipAnnotation,
     ModelAggregateRelationshipAnnotation, ModelRelationshipAnnotation,
     OrderByRelationshipAnnotation,
 };
 pub use types::{
-    Annotation, ApolloFederationRootFields, BooleanExpressionAnnotation,
-    EntityFieldTypeNameMapping, GlobalID, InputAnnotation, LogicalOperatorField,
-    ModelInputAnnotation, ModelOrderByDirection, NamespaceAnnotation, NodeFieldTypeNameMapping,
-    ObjectBooleanExpressionField, ObjectFieldKind, OutputAnnotation, RootFieldAnnotation,
-    RootFieldKind, ScalarBooleanExpressionField, TypeKind,
+    AggregateBooleanExpressionField, Annotation, ApolloFederationRootFields,
+    BooleanExpressionAnnotation, EntityFieldTypeNameMapping, GlobalID, InputAnnotation,
+    LogicalOperatorField, ModelInputAnnotation, ModelMutationKind, ModelOrderByDirection,
+    NamespaceAnnotation, NodeFieldTypeNameMapping, ObjectBooleanExpressionField, ObjectFieldKind,
+    OutputAnnotation, RootFieldAnnotation, RootFieldKind, ScalarBooleanExpressionField, TypeKind,
 };
 
 /// This 'NamespacedGetter' looks up 'NamespacedNodeInfo's according to actual roles.
@@ -163,7 +167,21 @@ impl gql_schema::SchemaContext for GDS {
                 gds_type_name,
                 graphql_type_name,
                 ..
-            } => types::scalar_type::scalar_type_schema(self, gds_type_name, graphql_type_name),
+            } => types::scalar_type::scalar_type_schema(
+                self,
+                builder,
+                gds_type_name,
+                graphql_type_name,
+            ),
+            types::TypeId::AbstractType {
+                gds_type_name,
+                graphql_type_name,
+            } => types::abstract_type::abstract_type_schema(
+                self,
+                builder,
+                gds_type_name,
+                graphql_type_name,
+            ),
             types::TypeId::InputObjectType {
                 gds_type_name,
                 graphql_type_name,
@@ -189,7 +207,49 @@ impl gql_schema::SchemaContext for GDS {
                 model_name,
                 type_name,
             } => model_arguments::build_model_arguments_input_schema(
-                self, builder, type_name, model_name,
+                self,
+                builder,
+                type_name,
+                model_name,
+                &BTreeSet::new(),
+            ),
+            types::TypeId::ModelRelationshipArgumentsInput {
+                model_name,
+                mapped_arguments,
+                type_name,
+            } => model_arguments::build_model_arguments_input_schema(
+                self,
+                builder,
+                type_name,
+                model_name,
+                mapped_arguments,
+            ),
+            types::TypeId::InputObjectAggregateBooleanExpressionType {
+                gds_type_name,
+                graphql_type_name,
+            } => boolean_expression::build_object_aggregate_boolean_expression_input(
+                self,
+                builder,
+                graphql_type_name,
+                gds_type_name,
+            ),
+            types::TypeId::InputScalarAggregateBooleanExpressionType {
+                gds_type_name,
+                graphql_type_name,
+            } => boolean_expression::build_scalar_aggregate_boolean_expression_input(
+                self,
+                builder,
+                graphql_type_name,
+                gds_type_name,
+            ),
+            types::TypeId::RelationshipAggregatePredicateType {
+                gds_type_name,
+                graphql_type_name,
+            } => boolean_expression::build_relationship_aggregate_predicate_input(
+                self,
+                builder,
+                graphql_type_name,
+                gds_type_name,
             ),
             types::TypeId::InputScalarBooleanExpressionType {
                 graphql_type_name,
@@ -218,6 +278,15 @@ impl gql_schema::SchemaContext for GDS {
             types::TypeId::OrderByEnumType { graphql_type_name } => {
                 model_order_by::build_order_by_enum_type_schema(self, builder, graphql_type_name)
             }
+            types::TypeId::OrderByAggregateExpression {
+                aggregate_expression_name,
+                graphql_type_name,
+            } => model_order_by::build_aggregate_order_by_input_schema(
+                self,
+                builder,
+                graphql_type_name,
+                aggregate_expression_name,
+            ),
             types::TypeId::ApolloFederationType(types::PossibleApolloFederationTypes::Entity) => {
                 Ok(gql_schema::TypeInfo::Union(
                     apollo_federation::apollo_federation_entities_schema(builder, self)?,
@@ -253,6 +322,78 @@ impl gql_schema::SchemaContext for GDS {
                 model_name,
                 graphql_type_name,
             ),
+            types::TypeId::ModelStreamCursorInputType {
+                model_name,
+                graphql_type_name,
+            } => subscription_root::build_stream_cursor_input_type(
+                self,
+                builder,
+                model_name,
+                graphql_type_name,
+            ),
+            types::TypeId::ModelDistinctOnEnumType {
+                model_name,
+                graphql_type_name,
+            } => query_root::select_many::build_distinct_on_enum_type(
+                self,
+                builder,
+                model_name,
+                graphql_type_name,
+            ),
+            types::TypeId::ModelConnectionType {
+                model_name,
+                graphql_type_name,
+            } => relay::build_model_connection_type(self, builder, model_name, graphql_type_name),
+            types::TypeId::ModelEdgeType {
+                model_name,
+                graphql_type_name,
+            } => relay::build_model_edge_type(self, builder, model_name, graphql_type_name),
+            types::TypeId::PageInfoType => relay::build_page_info_type(builder),
+            types::TypeId::ModelGroupType {
+                model_name,
+                graphql_type_name,
+            } => query_root::select_group_by::build_model_group_type(
+                self,
+                builder,
+                model_name,
+                graphql_type_name,
+            ),
+            types::TypeId::ModelGroupDimensionsType {
+                model_name,
+                graphql_type_name,
+            } => query_root::select_group_by::build_model_group_dimensions_type(
+                self,
+                builder,
+                model_name,
+                graphql_type_name,
+            ),
+            types::TypeId::ModelGroupOrderByInputType {
+                model_name,
+                graphql_type_name,
+            } => query_root::select_group_by::build_model_group_order_by_input_type(
+                self,
+                builder,
+                model_name,
+                graphql_type_name,
+            ),
+            types::TypeId::ModelMutationResponseType {
+                model_name,
+                graphql_type_name,
+            } => mutation_root::model_mutations::build_model_mutation_response_type(
+                self,
+                builder,
+                model_name,
+                graphql_type_name,
+            ),
+            types::TypeId::ModelSetInputType {
+                model_name,
+                graphql_type_name,
+            } => mutation_root::model_mutations::build_model_set_input_type(
+                self,
+                builder,
+                model_name,
+                graphql_type_name,
+            ),
         }
     }
 
@@ -348,6 +489,20 @@ pub enum Error {
     InternalErrorDuplicateEntitySourceFound { type_name: ast::TypeName },
     #[error("internal error while building schema, model not found: {model_name}")]
     InternalModelNotFound { model_name: Qualified<ModelName> },
+    #[error(
+        "internal error while building schema, streaming subscription not found for model: {model_name}"
+    )]
+    InternalStreamSubscriptionNotFound { model_name: Qualified<ModelName> },
+    #[error("internal error while building schema, distinct_on not found for model: {model_name}")]
+    InternalDistinctOnNotFound { model_name: Qualified<ModelName> },
+    #[error(
+        "internal error while building schema, select connection not found for model: {model_name}"
+    )]
+    InternalSelectConnectionNotFound { model_name: Qualified<ModelName> },
+    #[error("internal error while building schema, mutations not found for model: {model_name}")]
+    InternalModelMutationsNotFound { model_name: Qualified<ModelName> },
+    #[error("internal error while building schema, group by not found for model: {model_name}")]
+    InternalGroupByNotFound { model_name: Qualified<ModelName> },
     #[error("internal error while building schema, order by expression not found: {order_by_expression_identifier}")]
     InternalOrderByExpressionNotFound {
         order_by_expression_identifier: Qualified<OrderByExpressionIdentifier>,
@@ -360,6 +515,10 @@ pub enum Error {
     InternalBooleanExpressionNotFound {
         type_name: Qualified<CustomTypeName>,
     },
+    #[error("internal error while building schema, scalar boolean expression not found: {boolean_expression_type}")]
+    InternalScalarBooleanExpressionNotFound {
+        boolean_expression_type: metadata_resolve::BooleanExpressionTypeIdentifier,
+    },
     #[error(
         "Conflicting argument names {argument_name} for field {field_name} of type {type_name}"
     )]
@@ -382,6 +541,10 @@ pub enum Error {
     NoGraphQlTypeNameForScalar {
         type_name: Qualified<CustomTypeName>,
     },
+    #[error("No graphql type name has been defined for union or interface type: {type_name}")]
+    NoGraphQlTypeNameForAbstractType {
+        type_name: Qualified<CustomTypeName>,
+    },
     #[error("No graphql output type name has been defined for object type: {type_name}")]
     NoGraphQlOutputTypeNameForObject {
         type_name: Qualified<CustomTypeName>,
@@ -9,14 +9,22 @@ use crate::{
 };
 use lang_graphql::schema as gql_schema;
 use metadata_resolve::Qualified;
-use open_dds::models::ModelName;
-use std::collections::{BTreeMap, HashMap};
+use open_dds::{arguments::ArgumentName, models::ModelName};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// The `args` input object of a relationship to a model, which leaves out the
+/// model arguments whose values are provided by the relationship mappings
+pub struct RelationshipArguments {
+    pub mapped_arguments: BTreeSet<ArgumentName>,
+    pub type_name: ast::TypeName,
+}
 
 /// Creates the `args` input object within which the model
 /// arguments fields will live.
 pub fn get_model_arguments_input_field(
     builder: &mut gql_schema::Builder<GDS>,
     model: &metadata_resolve::ModelWithPermissions,
+    relationship_arguments: Option<RelationshipArguments>,
     include_empty_default: bool,
 ) -> Result<gql_schema::InputField<GDS>, crate::Error> {
     model
@@ -29,10 +37,20 @@ pub fn get_model_arguments_input_field(
         .map(|arguments_input_config| {
             // This function call adds the model arguments to the
             // `args` input object
-            builder.register_type(TypeId::ModelArgumentsInput {
-                model_name: model.model.name.clone(),
-                type_name: arguments_input_config.type_name.clone(),
-            });
+            let type_name = match relationship_arguments {
+                None => builder.register_type(TypeId::ModelArgumentsInput {
+                    model_name: model.model.name.clone(),
+                    type_name: arguments_input_config.type_name.clone(),
+                }),
+                Some(RelationshipArguments {
+                    mapped_arguments,
+                    type_name,
+                }) => builder.register_type(TypeId::ModelRelationshipArgumentsInput {
+                    model_name: model.model.name.clone(),
+                    mapped_arguments,
+                    type_name,
+                }),
+            };
 
             // if there are no possible arguments, provide a default of `{}`
             // so that `args` can be omitted if the user chooses
@@ -48,19 +66,20 @@ pub fn get_model_arguments_input_field(
                 info: Annotation::Input(InputAnnotation::Model(
                     ModelInputAnnotation::ModelArgumentsExpression,
                 )),
-                field_type: ast::TypeContainer::named_non_null(
-                    arguments_input_config.type_name.clone(),
-                ),
+                field_type: ast::TypeContainer::named_non_null(type_name.type_name().clone()),
                 default_value,
                 deprecation_status: gql_schema::DeprecationStatus::NotDeprecated,
             }
         })
 }
 
+/// Build the input fields of the model arguments, leaving out the arguments in
+/// `mapped_arguments` as their values are provided by a relationship mapping.
 pub fn build_model_argument_fields(
     gds: &GDS,
     builder: &mut gql_schema::Builder<GDS>,
     model: &metadata_resolve::ModelWithPermissions,
+    mapped_arguments: &BTreeSet<ArgumentName>,
 ) -> Result<
     BTreeMap<ast::Name, gql_schema::Namespaced<GDS, gql_schema::InputField<GDS>>>,
     crate::Error,
@@ -69,6 +88,7 @@ pub fn build_model_argument_fields(
         .model
         .arguments
         .iter()
+        .filter(|(argument_name, _)| !mapped_arguments.contains(*argument_name))
         .map(|(argument_name, argument_type)| {
             let field_name = ast::Name::new(argument_name.as_str())?;
             let input_type = get_input_type(gds, builder, &argument_type.argument_type)?;
@@ -119,6 +139,7 @@ pub fn build_model_arguments_input_schema(
     builder: &mut gql_schema::Builder<GDS>,
     type_name: &ast::TypeName,
     model_name: &Qualified<ModelName>,
+    mapped_arguments: &BTreeSet<ArgumentName>,
 ) -> Result<gql_schema::TypeInfo<GDS>, crate::Error> {
     let model =
         gds.metadata
@@ -132,7 +153,7 @@ pub fn build_model_arguments_input_schema(
         gql_schema::InputObject::new(
             type_name.clone(),
             None,
-            build_model_argument_fields(gds, builder, model)?,
+            build_model_argument_fields(gds, builder, model, mapped_arguments)?,
             Vec::new(),
         ),
     ))
@@ -145,6 +166,80 @@ pub fn add_model_arguments_field(
     model: &metadata_resolve::ModelWithPermissions,
     parent_field_name: &ast::Name,
     parent_type: &ast::TypeName,
+) -> Result<(), crate::Error> {
+    add_arguments_field(
+        arguments,
+        builder,
+        model,
+        None,
+        parent_field_name,
+        parent_type,
+    )
+}
+
+/// Generate the `args` input object of a relationship field to a model. Model
+/// arguments that are mapped by the relationship are left out, and the `args`
+/// input object is omitted entirely when all of the model arguments are mapped.
+pub fn add_model_relationship_arguments_field(
+    arguments: &mut BTreeMap<ast::Name, gql_schema::Namespaced<GDS, gql_schema::InputField<GDS>>>,
+    builder: &mut gql_schema::Builder<GDS>,
+    model: &metadata_resolve::ModelWithPermissions,
+    mapped_arguments: BTreeSet<ArgumentName>,
+    parent_field_name: &ast::Name,
+    parent_type: &ast::TypeName,
+) -> Result<(), crate::Error> {
+    if mapped_arguments.is_empty() {
+        return add_model_arguments_field(
+            arguments,
+            builder,
+            model,
+            parent_field_name,
+            parent_type,
+        );
+    }
+
+    if model
+        .model
+        .arguments
+        .keys()
+        .all(|argument_name| mapped_arguments.contains(argument_name))
+    {
+        return Ok(());
+    }
+
+    let arguments_input_config = model
+        .graphql_api
+        .arguments_input_config
+        .as_ref()
+        .ok_or_else(|| crate::Error::NoArgumentsInputConfigForSelectMany {
+            model_name: model.model.name.clone(),
+        })?;
+
+    let type_name = ast::TypeName(ast::Name::new(&format!(
+        "{parent_type}_{parent_field_name}_{}",
+        arguments_input_config.type_name
+    ))?);
+
+    add_arguments_field(
+        arguments,
+        builder,
+        model,
+        Some(RelationshipArguments {
+            mapped_arguments,
+            type_name,
+        }),
+        parent_field_name,
+        parent_type,
+    )
+}
+
+fn add_arguments_field(
+    arguments: &mut BTreeMap<ast::Name, gql_schema::Namespaced<GDS, gql_schema::InputField<GDS>>>,
+    builder: &mut gql_schema::Builder<GDS>,
+    model: &metadata_resolve::ModelWithPermissions,
+    relationship_arguments: Option<RelationshipArguments>,
+    parent_field_name: &ast::Name,
+    parent_type: &ast::TypeName,
 ) -> Result<(), crate::Error> {
     // which arguments are actually available for the user to provide?
     let user_arguments: Vec<_> = model
@@ -152,6 +247,17 @@ pub fn add_model_arguments_field(
         .arguments
         .keys()
         .filter(|argument_name| {
+            // mapped arguments are provided by the relationship
+            if relationship_arguments
+                .as_ref()
+                .is_some_and(|relationship_arguments| {
+                    relationship_arguments
+                        .mapped_arguments
+                        .contains(*argument_name)
+                })
+            {
+                return false;
+            }
             for permission in model.select_permissions.values() {
                 // if there is a preset for this argument, it will not be included in the schema
                 if permission.argument_presets.contains_key(*argument_name) {
@@ -169,8 +275,12 @@ pub fn add_model_arguments_field(
 
     if !model.model.arguments.is_empty() {
         let include_empty_default = user_arguments.is_empty();
-        let model_arguments_input =
-            get_model_arguments_input_field(builder, model, include_empty_default)?;
+        let model_arguments_input = get_model_arguments_input_field(
+            builder,
+            model,
+            relationship_arguments,
+            include_empty_default,
+        )?;
 
         let name = model_arguments_input.name.clone();
 
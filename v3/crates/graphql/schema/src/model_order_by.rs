@@ -1,24 +1,29 @@
 use hasura_authn_core::Role;
 use lang_graphql::ast::common as ast;
 use lang_graphql::schema as gql_schema;
+use open_dds::aggregates::AggregateExpressionName;
+use open_dds::models::OrderByDirection;
 use open_dds::relationships::{RelationshipName, RelationshipType};
 use open_dds::types::{CustomTypeName, Deprecated};
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 use super::types::output_type::relationship::OrderByRelationshipAnnotation;
 use super::types::{output_type::get_object_type_representation, Annotation, TypeId};
+use crate::aggregates::{self, AggregationFunctionAnnotation};
 use crate::types::{self};
 use crate::{mk_deprecation_status, GDS};
 use crate::{permissions, ModelInputAnnotation};
 use metadata_resolve::Qualified;
 use metadata_resolve::{
-    mk_name, ObjectTypeWithRelationships, OrderByExpressionGraphqlConfig,
+    mk_name, AggregateExpression, ObjectTypeWithRelationships, OrderByExpressionGraphqlConfig,
     OrderByExpressionIdentifier, OrderableField, OrderableObjectField, OrderableRelationship,
+    OrderableScalarField,
 };
 
 use crate::Error;
 
-// Generates the schema for 'order_by' arguments: Asc/Desc
+// Generates the schema for 'order_by' arguments: Asc/Desc, and the directions that place nulls
+// first or last if the enum type includes them
 pub fn build_order_by_enum_type_schema(
     gds: &GDS,
     builder: &mut gql_schema::Builder<GDS>,
@@ -34,35 +39,65 @@ pub fn build_order_by_enum_type_schema(
             type_name: order_by_type_name.clone(),
         })?;
 
-    let asc_ast_name = &order_by_input_config.asc_direction_field_value;
-    order_by_values.insert(
-        asc_ast_name.clone(),
-        builder.allow_all_namespaced(gql_schema::EnumValue {
-            value: asc_ast_name.clone(),
-            description: Some("Sorts the data in ascending order".to_string()),
-            deprecation_status: gql_schema::DeprecationStatus::NotDeprecated,
-            info: types::Annotation::Input(types::InputAnnotation::Model(
-                types::ModelInputAnnotation::ModelOrderByDirection {
-                    direction: types::ModelOrderByDirection::Asc,
-                },
-            )),
-        }),
-    );
-
-    let desc_ast_name = &order_by_input_config.desc_direction_field_value;
-    order_by_values.insert(
-        desc_ast_name.clone(),
-        builder.allow_all_namespaced(gql_schema::EnumValue {
-            value: desc_ast_name.clone(),
-            description: Some("Sorts the data in descending order".to_string()),
-            deprecation_status: gql_schema::DeprecationStatus::NotDeprecated,
-            info: types::Annotation::Input(types::InputAnnotation::Model(
-                types::ModelInputAnnotation::ModelOrderByDirection {
-                    direction: types::ModelOrderByDirection::Desc,
-                },
-            )),
-        }),
-    );
+    let directions = if *order_by_type_name == order_by_input_config.enum_type_name {
+        BTreeSet::from([OrderByDirection::Asc, OrderByDirection::Desc])
+    } else {
+        order_by_input_config
+            .nulls_enum_types
+            .iter()
+            .find(|enum_type| enum_type.type_name == *order_by_type_name)
+            .map(|enum_type| enum_type.directions.clone())
+            .ok_or_else(|| Error::InternalNoOrderByGraphqlConfigOrderByEnumType {
+                type_name: order_by_type_name.clone(),
+            })?
+    };
+
+    for direction in directions {
+        let (description, direction_annotation) = match direction {
+            OrderByDirection::Asc => (
+                "Sorts the data in ascending order",
+                types::ModelOrderByDirection::Asc,
+            ),
+            OrderByDirection::Desc => (
+                "Sorts the data in descending order",
+                types::ModelOrderByDirection::Desc,
+            ),
+            OrderByDirection::AscNullsFirst => (
+                "Sorts the data in ascending order, with nulls first",
+                types::ModelOrderByDirection::AscNullsFirst,
+            ),
+            OrderByDirection::AscNullsLast => (
+                "Sorts the data in ascending order, with nulls last",
+                types::ModelOrderByDirection::AscNullsLast,
+            ),
+            OrderByDirection::DescNullsFirst => (
+                "Sorts the data in descending order, with nulls first",
+                types::ModelOrderByDirection::DescNullsFirst,
+            ),
+            OrderByDirection::DescNullsLast => (
+                "Sorts the data in descending order, with nulls last",
+                types::ModelOrderByDirection::DescNullsLast,
+            ),
+        };
+        let direction_ast_name = order_by_input_config
+            .direction_field_value(&direction)
+            .ok_or_else(|| Error::InternalNoOrderByGraphqlConfigOrderByEnumType {
+                type_name: order_by_type_name.clone(),
+            })?;
+        order_by_values.insert(
+            direction_ast_name.clone(),
+            builder.allow_all_namespaced(gql_schema::EnumValue {
+                value: direction_ast_name.clone(),
+                description: Some(description.to_string()),
+                deprecation_status: gql_schema::DeprecationStatus::NotDeprecated,
+                info: types::Annotation::Input(types::InputAnnotation::Model(
+                    types::ModelInputAnnotation::ModelOrderByDirection {
+                        direction: direction_annotation,
+                    },
+                )),
+            }),
+        );
+    }
 
     Ok(gql_schema::TypeInfo::Enum(gql_schema::Enum {
         name: order_by_type_name.clone(),
@@ -195,10 +230,25 @@ pub fn build_model_order_by_input_schema(
                 .collect();
 
         let input_field = match orderable_field {
-            OrderableField::Scalar(_) => {
+            OrderableField::Scalar(OrderableScalarField {
+                order_by_expression_identifier,
+            }) => {
+                // fields that can place nulls first or last use the enum type with
+                // those directions
+                let enum_type_name = gds
+                    .metadata
+                    .order_by_expressions
+                    .scalars
+                    .get(order_by_expression_identifier)
+                    .and_then(|scalar_order_by_expression| {
+                        order_by_input_config.enum_type_name_for_directions(
+                            &scalar_order_by_expression.enable_order_by_directions,
+                        )
+                    })
+                    .unwrap_or(&order_by_input_config.enum_type_name);
                 let input_type = ast::TypeContainer::named_null(builder.register_type(
                     TypeId::OrderByEnumType {
-                        graphql_type_name: order_by_input_config.enum_type_name.clone(),
+                        graphql_type_name: enum_type_name.clone(),
                     },
                 ));
                 builder.conditional_namespaced(
@@ -299,7 +349,10 @@ fn build_orderable_relationships(
                 relationship_type,
                 target_typename,
                 mappings,
+                value_mappings,
+                argument_mappings: _,
                 relationship_aggregate: _,
+                connection_field_name: _,
             } = model_relationship_target.as_ref();
             let target_model = gds.metadata.models.get(model_name).ok_or_else(|| {
                 crate::Error::InternalModelNotFound {
@@ -320,12 +373,27 @@ fn build_orderable_relationships(
                 .map_err(metadata_resolve::Error::from)
                 .map_err(metadata_resolve::WithContext::from)?;
 
-                // TODO(naveen): Support Array relationships in order_by when the support for aggregates is implemented
-                if let RelationshipType::Object = relationship_type {
-                    // which type to use for the inner ordering?
-                    // if there is one designated by the orderable relationship, use that
-                    // otherwise use whatever the target models feels like
-                    if let Some(target_model_order_by_expression_type_name) =
+                // which type to use for the inner ordering?
+                let target_order_by_type_name = match relationship_type {
+                    // array relationships can only be ordered by aggregates of the related rows
+                    RelationshipType::Array => orderable_relationship
+                        .aggregate_expression
+                        .as_ref()
+                        .map(|aggregate_expression_name| {
+                            let aggregate_expression = gds
+                                .metadata
+                                .aggregate_expressions
+                                .get(aggregate_expression_name)
+                                .ok_or_else(|| Error::InternalAggregateExpressionNotFound {
+                                    aggregate_expression: aggregate_expression_name.clone(),
+                                })?;
+                            get_aggregate_order_by_input_type(builder, aggregate_expression)
+                        })
+                        .transpose()?,
+                    // for object relationships, if there is an order by expression designated
+                    // by the orderable relationship, use that, otherwise use whatever the target
+                    // model feels like
+                    RelationshipType::Object => {
                         match &orderable_relationship.order_by_expression {
                             Some(target_model_order_by_expression_name) => {
                                 let qualified_target_order_by_identifier = Qualified::new(
@@ -345,76 +413,272 @@ fn build_orderable_relationships(
                                             qualified_target_order_by_identifier.clone(),
                                     })?;
 
-                                // lookup graphql type name if it has one defined
-                                let maybe_graphql_type = target_order_by_expression
-                                    .graphql
-                                    .as_ref()
-                                    .map(|graphql| &graphql.expression_type_name);
-
-                                // we add the target order by expression to the schema
-                                // as it might not be attached to anything else
-                                if let Some(graphql_type) = maybe_graphql_type {
+                                // lookup graphql type name if it has one defined, and add the
+                                // target order by expression to the schema as it might not be
+                                // attached to anything else
+                                target_order_by_expression.graphql.as_ref().map(|graphql| {
                                     builder.register_type(types::TypeId::OrderByExpression {
                                         order_by_expression_identifier:
                                             qualified_target_order_by_identifier.clone(),
-                                        graphql_type_name: graphql_type.clone(),
-                                    });
-                                }
-
-                                maybe_graphql_type
+                                        graphql_type_name: graphql.expression_type_name.clone(),
+                                    })
+                                })
                             }
-                            None => target_model
-                                .graphql_api
-                                .order_by_expression
-                                .as_ref()
-                                .map(|graphql| &graphql.order_by_type_name),
+                            None => target_model.graphql_api.order_by_expression.as_ref().map(
+                                |graphql| {
+                                    gql_schema::RegisteredTypeName::new(
+                                        graphql.order_by_type_name.0.clone(),
+                                    )
+                                },
+                            ),
                         }
-                    {
-                        let annotation = OrderByRelationshipAnnotation {
-                            source_type: relationship.source.clone(),
-                            relationship_name: relationship.relationship_name.clone(),
-                            target_model_name: model_name.clone(),
-                            target_source: target_model_source.clone(),
-                            target_type: target_typename.clone(),
-                            relationship_type: relationship_type.clone(),
-                            mappings: mappings.clone(),
-                            object_type_name: object_type_name.clone(),
-                            deprecated: relationship.deprecated.clone(),
-                            multiple_input_properties: gds
-                                .metadata
-                                .graphql_config
-                                .multiple_order_by_input_object_fields,
-                        };
-
-                        fields.insert(
-                                    relationship.field_name.clone(),
-                                    builder.conditional_namespaced(
-                                        gql_schema::InputField::new(
-                                            relationship.field_name.clone(),
-                                            None,
-                                            types::Annotation::Input(types::InputAnnotation::Model(
-                                                types::ModelInputAnnotation::ModelOrderByRelationshipArgument(annotation),
-                                            )),
-                                            ast::TypeContainer::named_null(
-                                                gql_schema::RegisteredTypeName::new(
-                                                    target_model_order_by_expression_type_name.0.clone(),
-                                                ),
-                                            ),
-                                            None,
-                                            gql_schema::DeprecationStatus::NotDeprecated,
-                                        ),
-                                        permissions::get_model_relationship_namespace_annotations(
-                                            target_model,
-                                            object_type_representation,
-                                            target_object_type_representation,
-                                            mappings,
-                                        ),
-                                    ),
-                                );
+                    }
+                };
+
+                if let Some(target_order_by_type_name) = target_order_by_type_name {
+                    let annotation = OrderByRelationshipAnnotation {
+                        source_type: relationship.source.clone(),
+                        relationship_name: relationship.relationship_name.clone(),
+                        target_model_name: model_name.clone(),
+                        target_source: target_model_source.clone(),
+                        target_type: target_typename.clone(),
+                        relationship_type: relationship_type.clone(),
+                        mappings: mappings.clone(),
+                        object_type_name: object_type_name.clone(),
+                        deprecated: relationship.deprecated.clone(),
+                        multiple_input_properties: gds
+                            .metadata
+                            .graphql_config
+                            .multiple_order_by_input_object_fields,
                     };
+
+                    fields.insert(
+                        relationship.field_name.clone(),
+                        builder.conditional_namespaced(
+                            gql_schema::InputField::new(
+                                relationship.field_name.clone(),
+                                None,
+                                types::Annotation::Input(types::InputAnnotation::Model(
+                                    types::ModelInputAnnotation::ModelOrderByRelationshipArgument(
+                                        annotation,
+                                    ),
+                                )),
+                                ast::TypeContainer::named_null(target_order_by_type_name),
+                                None,
+                                gql_schema::DeprecationStatus::NotDeprecated,
+                            ),
+                            permissions::get_model_relationship_namespace_annotations(
+                                target_model,
+                                object_type_representation,
+                                target_object_type_representation,
+                                mappings,
+                                value_mappings,
+                                // relationships with argument mappings can't be used in order by
+                                &[],
+                                &gds.metadata.object_types,
+                            ),
+                        ),
+                    );
                 }
             }
         }
     }
     Ok(())
 }
+
+pub(crate) fn get_aggregate_order_by_input_type(
+    builder: &mut gql_schema::Builder<GDS>,
+    aggregate_expression: &AggregateExpression,
+) -> Result<gql_schema::RegisteredTypeName, Error> {
+    // the input type is named after the aggregate expression's select output type
+    let select_output_type_name = aggregate_expression
+        .graphql
+        .as_ref()
+        .map(|graphql| &graphql.select_output_type_name)
+        .ok_or_else(|| Error::NoGraphQlSelectTypeNameForAggregateExpression {
+            aggregate_expression: aggregate_expression.name.clone(),
+        })?;
+    let graphql_type_name = mk_name(&format!("{select_output_type_name}_order_by"))
+        .map(ast::TypeName)
+        .map_err(metadata_resolve::Error::from)
+        .map_err(metadata_resolve::WithContext::from)?;
+
+    Ok(builder.register_type(TypeId::OrderByAggregateExpression {
+        aggregate_expression_name: aggregate_expression.name.clone(),
+        graphql_type_name,
+    }))
+}
+
+// Generates the input type used to order by the aggregations of an aggregate expression.
+// This mirrors the aggregate expression's select output type, but every aggregation
+// takes an order by direction instead.
+pub fn build_aggregate_order_by_input_schema(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    type_name: &ast::TypeName,
+    aggregate_expression_name: &Qualified<AggregateExpressionName>,
+) -> Result<gql_schema::TypeInfo<GDS>, Error> {
+    let aggregate_expression = gds
+        .metadata
+        .aggregate_expressions
+        .get(aggregate_expression_name)
+        .ok_or_else(|| Error::InternalAggregateExpressionNotFound {
+            aggregate_expression: aggregate_expression_name.clone(),
+        })?;
+
+    let order_by_input_config = gds
+        .metadata
+        .graphql_config
+        .order_by_input
+        .as_ref()
+        .ok_or_else(|| Error::InternalNoOrderByGraphqlConfigOrderByEnumType {
+            type_name: type_name.clone(),
+        })?;
+
+    let order_by_direction_field =
+        |builder: &mut gql_schema::Builder<GDS>,
+         field_name: &ast::Name,
+         description: Option<String>,
+         aggregation_function: AggregationFunctionAnnotation| {
+            let input_type =
+                ast::TypeContainer::named_null(builder.register_type(TypeId::OrderByEnumType {
+                    graphql_type_name: order_by_input_config.enum_type_name.clone(),
+                }));
+            // All roles can order by all aggregation functions
+            builder.allow_all_namespaced(gql_schema::InputField::new(
+                field_name.clone(),
+                description,
+                Annotation::Input(types::InputAnnotation::Model(
+                    types::ModelInputAnnotation::ModelOrderByAggregationFunction(
+                        aggregation_function,
+                    ),
+                )),
+                input_type,
+                None,
+                gql_schema::DeprecationStatus::NotDeprecated,
+            ))
+        };
+
+    let mut fields = BTreeMap::new();
+
+    if let Some((object_type_name, object_type)) =
+        aggregates::get_object_type(gds, &aggregate_expression.operand.aggregated_type)
+    {
+        for aggregatable_field_info in &aggregate_expression.operand.aggregatable_fields {
+            let field_def = object_type
+                .object_type
+                .fields
+                .get(&aggregatable_field_info.field_name)
+                .ok_or_else(|| Error::InternalObjectTypeFieldNotFound {
+                    type_name: object_type_name.clone(),
+                    field_name: aggregatable_field_info.field_name.clone(),
+                })?;
+
+            let field_aggregate_expression = gds
+                .metadata
+                .aggregate_expressions
+                .get(&aggregatable_field_info.aggregate_expression)
+                .ok_or_else(|| Error::InternalAggregateExpressionNotFound {
+                    aggregate_expression: aggregatable_field_info.aggregate_expression.clone(),
+                })?;
+
+            let field_graphql_name = mk_name(aggregatable_field_info.field_name.as_str())
+                .map_err(metadata_resolve::Error::from)
+                .map_err(metadata_resolve::WithContext::from)?;
+
+            let input_field = gql_schema::InputField::new(
+                field_graphql_name.clone(),
+                aggregatable_field_info.description.clone(),
+                Annotation::Input(types::InputAnnotation::Model(
+                    types::ModelInputAnnotation::ModelOrderByAggregatableField {
+                        field_name: aggregatable_field_info.field_name.clone(),
+                        aggregate_operand_type: field_aggregate_expression
+                            .operand
+                            .aggregated_type
+                            .clone(),
+                    },
+                )),
+                ast::TypeContainer::named_null(get_aggregate_order_by_input_type(
+                    builder,
+                    field_aggregate_expression,
+                )?),
+                None,
+                mk_deprecation_status(field_def.deprecated.as_ref()),
+            );
+
+            // Only allow ordering by aggregations of the field if the type permissions allow it
+            // on all objects
+            let allowed_roles = object_type
+                .type_output_permissions
+                .iter()
+                .filter(|(_role, perms)| {
+                    perms.is_unconditionally_allowed(&aggregatable_field_info.field_name)
+                })
+                .map(|(role, _perms)| (role.clone(), None))
+                .collect::<HashMap<Role, Option<types::NamespaceAnnotation>>>();
+
+            fields.insert(
+                field_graphql_name,
+                builder.conditional_namespaced(input_field, allowed_roles),
+            );
+        }
+    }
+
+    if let Some(graphql) = &aggregate_expression.graphql {
+        if aggregate_expression.count.enable {
+            fields.insert(
+                graphql.count_field_name.clone(),
+                order_by_direction_field(
+                    builder,
+                    &graphql.count_field_name,
+                    aggregate_expression.count.description.clone(),
+                    AggregationFunctionAnnotation::Count,
+                ),
+            );
+        }
+        if aggregate_expression.count_distinct.enable {
+            fields.insert(
+                graphql.count_distinct_field_name.clone(),
+                order_by_direction_field(
+                    builder,
+                    &graphql.count_distinct_field_name,
+                    aggregate_expression.count_distinct.description.clone(),
+                    AggregationFunctionAnnotation::CountDistinct,
+                ),
+            );
+        }
+    }
+
+    for aggregation_function_info in &aggregate_expression.operand.aggregation_functions {
+        let field_graphql_name = mk_name(aggregation_function_info.name.as_str())
+            .map_err(metadata_resolve::Error::from)
+            .map_err(metadata_resolve::WithContext::from)?;
+
+        let input_field = order_by_direction_field(
+            builder,
+            &field_graphql_name,
+            aggregation_function_info.description.clone(),
+            AggregationFunctionAnnotation::Function {
+                function_name: aggregation_function_info.name.clone(),
+                aggregate_expression: aggregate_expression.name.name.clone(),
+                data_connector_functions: aggregation_function_info
+                    .data_connector_functions
+                    .clone(),
+            },
+        );
+
+        if fields
+            .insert(field_graphql_name.clone(), input_field)
+            .is_some()
+        {
+            return Err(Error::AggregationFunctionFieldNameConflict {
+                aggregate_expression: aggregate_expression.name.clone(),
+                field_name: field_graphql_name,
+            });
+        }
+    }
+
+    Ok(gql_schema::TypeInfo::InputObject(
+        gql_schema::InputObject::new(type_name.clone(), None, fields, Vec::new()),
+    ))
+}
@@ -1,5 +1,7 @@
 //! Schema for the mutation root type
 
+pub mod model_mutations;
+
 use lang_graphql::ast::common::{self as ast, TypeName};
 use lang_graphql::schema as gql_schema;
 use std::collections::BTreeMap;
@@ -37,6 +39,21 @@ pub fn mutation_root_schema(
         }
     }
 
+    // Add the generated mutations of the models
+    for model in gds.metadata.models.values() {
+        if let Some(mutations) = &model.graphql_api.mutations {
+            for (field_name, field) in model_mutations::model_mutation_fields(
+                gds,
+                builder,
+                model,
+                mutations,
+                mutation_root_type_name,
+            )? {
+                fields.insert(field_name, field);
+            }
+        }
+    }
+
     Ok(gql_schema::Object::new(
         builder,
         mutation_root_type_name.clone(),
@@ -0,0 +1,350 @@
+//! Schema for the generated mutations of a model
+//!
+//! The insert, update and delete mutations of a model call procedures of its data connector,
+//! and return the number of affected objects along with the affected objects.
+
+use lang_graphql::ast::common as ast;
+use lang_graphql::schema as gql_schema;
+use metadata_resolve::{Qualified, QualifiedBaseType, QualifiedTypeName, QualifiedTypeReference};
+use open_dds::models::ModelName;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use strum_macros::Display;
+
+use crate::types::output_type::{get_custom_output_type, get_object_type_representation};
+use crate::types::{
+    self, input_type::get_input_type, input_type::input_object_type_input_fields, Annotation,
+    ModelInputAnnotation, ModelMutationKind, OutputAnnotation, RootFieldAnnotation, TypeId,
+};
+use crate::{mk_deprecation_status, model_filter_input, permissions, query_root, Role, GDS};
+
+/// Annotations of the fields of the response type of the mutations of a model.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Display)]
+pub enum ModelMutationResponseOutputAnnotation {
+    /// The number of objects affected by the mutation
+    AffectedRows,
+    /// The objects affected by the mutation
+    Returning,
+}
+
+/// Generates the mutation root fields of a model
+pub(crate) fn model_mutation_fields(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    model: &metadata_resolve::ModelWithPermissions,
+    mutations: &metadata_resolve::ModelMutationsGraphQlDefinition,
+    parent_type: &ast::TypeName,
+) -> Result<
+    Vec<(
+        ast::Name,
+        gql_schema::Namespaced<GDS, gql_schema::Field<GDS>>,
+    )>,
+    crate::Error,
+> {
+    let mut fields = Vec::new();
+
+    if let Some(insert) = &mutations.insert {
+        let objects_type = get_input_type(
+            gds,
+            builder,
+            &QualifiedTypeReference {
+                underlying_type: QualifiedBaseType::List(Box::new(QualifiedTypeReference {
+                    underlying_type: QualifiedBaseType::Named(QualifiedTypeName::Custom(
+                        model.model.data_type.clone(),
+                    )),
+                    nullable: false,
+                })),
+                nullable: false,
+            },
+        )?;
+        let objects_argument = gql_schema::InputField::new(
+            lang_graphql::mk_name!("objects"),
+            Some("The objects to insert".to_string()),
+            Annotation::Input(types::InputAnnotation::Model(
+                ModelInputAnnotation::ModelInsertObjectsArgument,
+            )),
+            objects_type,
+            None,
+            gql_schema::DeprecationStatus::NotDeprecated,
+        );
+        let arguments = BTreeMap::from([(
+            objects_argument.name.clone(),
+            builder.allow_all_namespaced(objects_argument),
+        )]);
+        let field_annotations = permissions::get_select_permissions_namespace_annotations(model);
+        fields.push(model_mutation_field(
+            builder,
+            model,
+            mutations,
+            insert,
+            ModelMutationKind::Insert,
+            arguments,
+            field_annotations,
+        ));
+    }
+
+    if let Some(update_by_unique_key) = &mutations.update_by_unique_key {
+        let mut arguments = query_root::select_one::generate_unique_identifier_arguments(
+            gds,
+            builder,
+            &update_by_unique_key.unique_identifier,
+        )?;
+        let set_argument = set_input_field(builder, model, mutations)?;
+        if arguments
+            .insert(set_argument.0.clone(), set_argument.1)
+            .is_some()
+        {
+            return Err(crate::Error::GraphQlArgumentConflict {
+                argument_name: set_argument.0,
+                field_name: update_by_unique_key.mutation_root_field.clone(),
+                type_name: parent_type.clone(),
+            });
+        }
+        let object_type_representation =
+            get_object_type_representation(gds, &model.model.data_type)?;
+        let field_annotations = permissions::get_select_one_namespace_annotations(
+            model,
+            object_type_representation,
+            &update_by_unique_key.unique_identifier,
+        );
+        let output_typename = builder.register_type(TypeId::ModelMutationResponseType {
+            model_name: model.model.name.clone(),
+            graphql_type_name: mutations.response_type_name.clone(),
+        });
+        let field = builder.conditional_namespaced(
+            gql_schema::Field::new(
+                update_by_unique_key.mutation_root_field.clone(),
+                update_by_unique_key.description.clone(),
+                Annotation::Output(OutputAnnotation::RootField(
+                    RootFieldAnnotation::ModelMutation {
+                        data_type: model.model.data_type.clone(),
+                        kind: ModelMutationKind::UpdateByUniqueKey,
+                        name: model.model.name.clone(),
+                        procedure_name: update_by_unique_key.procedure_name.clone(),
+                    },
+                )),
+                ast::TypeContainer::named_non_null(output_typename),
+                arguments,
+                mk_deprecation_status(update_by_unique_key.deprecated.as_ref()),
+            ),
+            field_annotations,
+        );
+        fields.push((update_by_unique_key.mutation_root_field.clone(), field));
+    }
+
+    if let Some(update) = &mutations.update {
+        let mut arguments = BTreeMap::new();
+        model_filter_input::add_where_input_field(&mut arguments, builder, model);
+        let set_argument = set_input_field(builder, model, mutations)?;
+        if arguments
+            .insert(set_argument.0.clone(), set_argument.1)
+            .is_some()
+        {
+            return Err(crate::Error::GraphQlArgumentConflict {
+                argument_name: set_argument.0,
+                field_name: update.mutation_root_field.clone(),
+                type_name: parent_type.clone(),
+            });
+        }
+        let field_annotations = permissions::get_select_permissions_namespace_annotations(model);
+        fields.push(model_mutation_field(
+            builder,
+            model,
+            mutations,
+            update,
+            ModelMutationKind::Update,
+            arguments,
+            field_annotations,
+        ));
+    }
+
+    if let Some(delete) = &mutations.delete {
+        let mut arguments = BTreeMap::new();
+        model_filter_input::add_where_input_field(&mut arguments, builder, model);
+        let field_annotations = permissions::get_select_permissions_namespace_annotations(model);
+        fields.push(model_mutation_field(
+            builder,
+            model,
+            mutations,
+            delete,
+            ModelMutationKind::Delete,
+            arguments,
+            field_annotations,
+        ));
+    }
+
+    Ok(fields)
+}
+
+fn model_mutation_field(
+    builder: &mut gql_schema::Builder<GDS>,
+    model: &metadata_resolve::ModelWithPermissions,
+    mutations: &metadata_resolve::ModelMutationsGraphQlDefinition,
+    mutation: &metadata_resolve::ModelMutationGraphQlDefinition,
+    kind: ModelMutationKind,
+    arguments: BTreeMap<ast::Name, gql_schema::Namespaced<GDS, gql_schema::InputField<GDS>>>,
+    field_annotations: HashMap<Role, Option<types::NamespaceAnnotation>>,
+) -> (
+    ast::Name,
+    gql_schema::Namespaced<GDS, gql_schema::Field<GDS>>,
+) {
+    let output_typename = builder.register_type(TypeId::ModelMutationResponseType {
+        model_name: model.model.name.clone(),
+        graphql_type_name: mutations.response_type_name.clone(),
+    });
+    let field = builder.conditional_namespaced(
+        gql_schema::Field::new(
+            mutation.mutation_root_field.clone(),
+            mutation.description.clone(),
+            Annotation::Output(OutputAnnotation::RootField(
+                RootFieldAnnotation::ModelMutation {
+                    data_type: model.model.data_type.clone(),
+                    kind,
+                    name: model.model.name.clone(),
+                    procedure_name: mutation.procedure_name.clone(),
+                },
+            )),
+            ast::TypeContainer::named_non_null(output_typename),
+            arguments,
+            mk_deprecation_status(mutation.deprecated.as_ref()),
+        ),
+        field_annotations,
+    );
+    (mutation.mutation_root_field.clone(), field)
+}
+
+/// The `_set` argument of the update mutations, with the new values of the fields
+fn set_input_field(
+    builder: &mut gql_schema::Builder<GDS>,
+    model: &metadata_resolve::ModelWithPermissions,
+    mutations: &metadata_resolve::ModelMutationsGraphQlDefinition,
+) -> Result<
+    (
+        ast::Name,
+        gql_schema::Namespaced<GDS, gql_schema::InputField<GDS>>,
+    ),
+    crate::Error,
+> {
+    let set_input_type_name = mutations.set_input_type_name.as_ref().ok_or_else(|| {
+        crate::Error::InternalModelMutationsNotFound {
+            model_name: model.model.name.clone(),
+        }
+    })?;
+    let set_type = builder.register_type(TypeId::ModelSetInputType {
+        model_name: model.model.name.clone(),
+        graphql_type_name: set_input_type_name.clone(),
+    });
+    let set_argument = gql_schema::InputField::new(
+        lang_graphql::mk_name!("_set"),
+        Some("The new values of the fields of the updated objects".to_string()),
+        Annotation::Input(types::InputAnnotation::Model(
+            ModelInputAnnotation::ModelSetArgument,
+        )),
+        ast::TypeContainer::named_non_null(set_type),
+        None,
+        gql_schema::DeprecationStatus::NotDeprecated,
+    );
+    Ok((
+        set_argument.name.clone(),
+        builder.allow_all_namespaced(set_argument),
+    ))
+}
+
+fn get_model_mutations<'s>(
+    gds: &'s GDS,
+    model_name: &Qualified<ModelName>,
+) -> Result<&'s metadata_resolve::ModelWithPermissions, crate::Error> {
+    let model =
+        gds.metadata
+            .models
+            .get(model_name)
+            .ok_or_else(|| crate::Error::InternalModelNotFound {
+                model_name: model_name.clone(),
+            })?;
+    if model.graphql_api.mutations.is_none() {
+        return Err(crate::Error::InternalModelMutationsNotFound {
+            model_name: model_name.clone(),
+        });
+    }
+    Ok(model)
+}
+
+/// Builds the response type of the mutations of a model, with the number of affected objects
+/// and the affected objects.
+pub(crate) fn build_model_mutation_response_type(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    model_name: &Qualified<ModelName>,
+    graphql_type_name: &ast::TypeName,
+) -> Result<gql_schema::TypeInfo<GDS>, crate::Error> {
+    let model = get_model_mutations(gds, model_name)?;
+    let object_type = get_custom_output_type(gds, builder, &model.model.data_type)?;
+    let fields = BTreeMap::from([
+        response_field(
+            builder,
+            lang_graphql::mk_name!("affected_rows"),
+            "The number of objects affected by the mutation",
+            ModelMutationResponseOutputAnnotation::AffectedRows,
+            ast::TypeContainer::named_non_null(gql_schema::RegisteredTypeName::int()),
+        ),
+        response_field(
+            builder,
+            lang_graphql::mk_name!("returning"),
+            "The objects affected by the mutation",
+            ModelMutationResponseOutputAnnotation::Returning,
+            ast::TypeContainer::list_non_null(ast::TypeContainer::named_non_null(object_type)),
+        ),
+    ]);
+    Ok(gql_schema::TypeInfo::Object(gql_schema::Object::new(
+        builder,
+        graphql_type_name.clone(),
+        None,
+        fields,
+        BTreeMap::new(),
+        Vec::new(),
+    )))
+}
+
+fn response_field(
+    builder: &mut gql_schema::Builder<GDS>,
+    name: ast::Name,
+    description: &str,
+    annotation: ModelMutationResponseOutputAnnotation,
+    field_type: ast::TypeContainer<gql_schema::RegisteredTypeName>,
+) -> (
+    ast::Name,
+    gql_schema::Namespaced<GDS, gql_schema::Field<GDS>>,
+) {
+    let field = gql_schema::Field::new(
+        name.clone(),
+        Some(description.to_string()),
+        Annotation::Output(OutputAnnotation::ModelMutationResponse(annotation)),
+        field_type,
+        BTreeMap::new(),
+        gql_schema::DeprecationStatus::NotDeprecated,
+    );
+    (name, builder.allow_all_namespaced(field))
+}
+
+/// Builds the input type of the new values of the fields of a model, for its update
+/// mutations. Every field can be left out, and the fields preset by the input permissions of
+/// a role are left out for that role.
+pub(crate) fn build_model_set_input_type(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    model_name: &Qualified<ModelName>,
+    graphql_type_name: &ast::TypeName,
+) -> Result<gql_schema::TypeInfo<GDS>, crate::Error> {
+    let model = get_model_mutations(gds, model_name)?;
+    let object_type_representation = get_object_type_representation(gds, &model.model.data_type)?;
+    let input_fields = input_object_type_input_fields(
+        gds,
+        builder,
+        &model.model.data_type,
+        object_type_representation,
+        true,
+    )?;
+    Ok(gql_schema::TypeInfo::InputObject(
+        gql_schema::InputObject::new(graphql_type_name.clone(), None, input_fields, Vec::new()),
+    ))
+}
@@ -1,10 +1,10 @@
 use indexmap::IndexMap;
-use open_dds::types::FieldName;
-use std::collections::HashMap;
+use open_dds::types::{CustomTypeName, FieldName};
+use std::collections::{BTreeMap, HashMap};
 
 use crate::types;
 use crate::Role;
-use metadata_resolve::{self};
+use metadata_resolve::{self, Qualified};
 
 /// Build namespace annotation for select permissions
 pub(crate) fn get_select_permissions_namespace_annotations(
@@ -19,6 +19,8 @@ pub(crate) fn get_select_permissions_namespace_annotations(
                 filter: select_permission.filter.clone(),
                 argument_presets: select_permission.argument_presets.clone(),
                 allow_subscriptions: select_permission.allow_subscriptions,
+                limit: select_permission.limit,
+                allow_aggregations: select_permission.allow_aggregations,
             }),
         );
     }
@@ -26,6 +28,25 @@ pub(crate) fn get_select_permissions_namespace_annotations(
     namespace_annotations
 }
 
+/// Filters a HashMap of role-to-annotation mappings, retaining only those
+/// where aggregations are allowed.
+pub(crate) fn apply_aggregation_permissions_model(
+    annotations: HashMap<Role, Option<types::NamespaceAnnotation>>,
+) -> HashMap<Role, Option<types::NamespaceAnnotation>> {
+    annotations
+        .into_iter()
+        .filter(|(_, annotation)| {
+            matches!(
+                annotation,
+                Some(types::NamespaceAnnotation::Model {
+                    allow_aggregations: true,
+                    ..
+                })
+            )
+        })
+        .collect()
+}
+
 /// Build namespace annotation for select one permissions.
 /// This is different from generating permissions for select_many etc,
 /// as we need to check the permissions of the arguments used in the selection.
@@ -50,31 +71,88 @@ pub(crate) fn get_select_one_namespace_annotations(
 
 /// Build namespace annotation for model relationship permissions.
 /// We need to check the permissions of the source and target fields
-/// in the relationship mappings.
+/// in the relationship mappings (including any fields of nested objects
+/// on their paths), and of the target fields that are compared against
+/// values.
 pub(crate) fn get_model_relationship_namespace_annotations(
     target_model: &metadata_resolve::ModelWithPermissions,
     source_object_type_representation: &metadata_resolve::ObjectTypeWithRelationships,
     target_object_type_representation: &metadata_resolve::ObjectTypeWithRelationships,
     mappings: &[metadata_resolve::RelationshipModelMapping],
+    value_mappings: &[metadata_resolve::RelationshipModelValueMapping],
+    argument_mappings: &[metadata_resolve::RelationshipModelArgumentMapping],
+    object_types: &BTreeMap<
+        Qualified<CustomTypeName>,
+        metadata_resolve::ObjectTypeWithRelationships,
+    >,
 ) -> HashMap<Role, Option<types::NamespaceAnnotation>> {
     let select_permissions = get_select_permissions_namespace_annotations(target_model);
     let permissions = select_permissions
         .into_iter()
         .filter(|(role, _)| {
             mappings.iter().all(|mapping| {
-                let source_name = mapping.source_field.field_name.clone();
-                let target_name = mapping.target_field.field_name.clone();
-
-                get_allowed_roles_for_field(source_object_type_representation, &source_name)
-                    .any(|allowed_role| role == allowed_role)
-                    && get_allowed_roles_for_field(target_object_type_representation, &target_name)
-                        .any(|allowed_role| role == allowed_role)
+                is_relationship_field_path_allowed(
+                    role,
+                    source_object_type_representation,
+                    &mapping.source_field.field_name,
+                    &mapping.source_nested_fields,
+                    object_types,
+                ) && is_relationship_field_path_allowed(
+                    role,
+                    target_object_type_representation,
+                    &mapping.target_field.field_name,
+                    &mapping.target_nested_fields,
+                    object_types,
+                )
+            }) && value_mappings.iter().all(|value_mapping| {
+                is_relationship_field_path_allowed(
+                    role,
+                    target_object_type_representation,
+                    &value_mapping.target_field.field_name,
+                    &value_mapping.target_nested_fields,
+                    object_types,
+                )
+            }) && argument_mappings.iter().all(|argument_mapping| {
+                is_relationship_field_path_allowed(
+                    role,
+                    source_object_type_representation,
+                    &argument_mapping.source_field.field_name,
+                    &argument_mapping.source_nested_fields,
+                    object_types,
+                )
             })
         })
         .collect();
     permissions
 }
 
+/// Whether the role is allowed to access a field used in a relationship
+/// mapping, along with every field of the nested objects on its path.
+fn is_relationship_field_path_allowed(
+    role: &Role,
+    object_type_representation: &metadata_resolve::ObjectTypeWithRelationships,
+    field_name: &FieldName,
+    nested_fields: &[metadata_resolve::RelationshipNestedField],
+    object_types: &BTreeMap<
+        Qualified<CustomTypeName>,
+        metadata_resolve::ObjectTypeWithRelationships,
+    >,
+) -> bool {
+    get_allowed_roles_for_field(object_type_representation, field_name)
+        .any(|allowed_role| role == allowed_role)
+        && nested_fields.iter().all(|nested_field| {
+            object_types.get(&nested_field.object_type).is_some_and(
+                |nested_object_type_representation| {
+                    get_allowed_roles_for_field(
+                        nested_object_type_representation,
+                        &nested_field.field_name,
+                    )
+                    .any(|allowed_role| role == allowed_role)
+                },
+            )
+        })
+}
+
 /// Build namespace annotation for commands
 pub(crate) fn get_command_namespace_annotations(
     command: &metadata_resolve::CommandWithPermissions,
@@ -86,9 +164,10 @@ pub(crate) fn get_command_namespace_annotations(
         if permission.allow_execution {
             permissions.insert(
                 role.clone(),
-                Some(types::NamespaceAnnotation::Command(
-                    permission.argument_presets.clone(),
-                )),
+                Some(types::NamespaceAnnotation::Command {
+                    argument_presets: permission.argument_presets.clone(),
+                    output_filter: permission.output_filter.clone(),
+                }),
             );
         }
     }
@@ -103,6 +182,10 @@ pub(crate) fn get_command_relationship_namespace_annotations(
     command: &metadata_resolve::CommandWithPermissions,
     source_object_type_representation: &metadata_resolve::ObjectTypeWithRelationships,
     mappings: &[metadata_resolve::RelationshipCommandMapping],
+    object_types: &BTreeMap<
+        Qualified<CustomTypeName>,
+        metadata_resolve::ObjectTypeWithRelationships,
+    >,
 ) -> HashMap<Role, Option<types::NamespaceAnnotation>> {
     let select_permissions = get_command_namespace_annotations(command);
 
@@ -110,11 +193,13 @@ pub(crate) fn get_command_relationship_namespace_annotations(
         .into_iter()
         .filter(|(role, _)| {
             mappings.iter().all(|mapping| {
-                get_allowed_roles_for_field(
+                is_relationship_field_path_allowed(
+                    role,
                     source_object_type_representation,
                     &mapping.source_field.field_name,
+                    &mapping.source_nested_fields,
+                    object_types,
                 )
-                .any(|allowed_role| role == allowed_role)
             })
         })
         .collect()
@@ -133,7 +218,7 @@ pub(crate) fn get_node_interface_annotations(
             .object_type
             .global_id_fields
             .iter()
-            .all(|field_name| type_output_permission.allowed_fields.contains(field_name));
+            .all(|field_name| type_output_permission.is_unconditionally_allowed(field_name));
         if is_permitted {
             permissions.insert(role.clone(), None);
         }
@@ -154,7 +239,7 @@ pub(crate) fn get_entity_union_permissions(
             .object_type
             .global_id_fields
             .iter()
-            .all(|field_name| type_output_permission.allowed_fields.contains(field_name));
+            .all(|field_name| type_output_permission.is_unconditionally_allowed(field_name));
         if is_permitted {
             permissions.insert(role.clone(), None);
         }
@@ -162,6 +247,41 @@ pub(crate) fn get_entity_union_permissions(
     permissions
 }
 
+/// Build namespace annotations for the membership of an object type in a
+/// union or interface type. The object type is only a possible type of the
+/// abstract type for roles that can access the object type at all.
+pub(crate) fn get_abstract_type_member_permissions(
+    object_type_representation: &metadata_resolve::ObjectTypeWithRelationships,
+) -> HashMap<Role, Option<types::NamespaceAnnotation>> {
+    get_allowed_roles_for_type(object_type_representation)
+        .map(|role| (role.clone(), None))
+        .collect()
+}
+
+/// Build namespace annotations for a field of an interface type.
+/// The field is only exposed for a role if the role has unconditional access
+/// to the field in every implementation of the interface.
+pub(crate) fn get_interface_field_permissions(
+    implementations: &[&metadata_resolve::ObjectTypeWithRelationships],
+    field_name: &FieldName,
+) -> HashMap<Role, Option<types::NamespaceAnnotation>> {
+    let mut permissions = HashMap::new();
+    if let Some((first, rest)) = implementations.split_first() {
+        for role in get_allowed_roles_for_field(first, field_name) {
+            let is_permitted = rest.iter().all(|implementation| {
+                implementation
+                    .type_output_permissions
+                    .get(role)
+                    .is_some_and(|permission| permission.is_unconditionally_allowed(field_name))
+            });
+            if is_permitted {
+                permissions.insert(role.clone(), None);
+            }
+        }
+    }
+    permissions
+}
+
 /// Are we allowed to access a given type at all?
 /// If we are allowed to access at least one field, yes
 pub(crate) fn get_allowed_roles_for_type(
@@ -174,7 +294,9 @@ pub(crate) fn get_allowed_roles_for_type(
         .flat_map(|field_name| get_allowed_roles_for_field(object_type_representation, field_name))
 }
 
-/// Build namespace annotations for each field based on the type permissions
+/// Build namespace annotations for each field based on the type permissions.
+/// Fields that are only accessible on some objects are left out, so that they can't
+/// be used to filter, order or join objects.
 pub(crate) fn get_allowed_roles_for_field<'a>(
     object_type_representation: &'a metadata_resolve::ObjectTypeWithRelationships,
     field_name: &'a FieldName,
@@ -183,7 +305,7 @@ pub(crate) fn get_allowed_roles_for_field<'a>(
         .type_output_permissions
         .iter()
         .filter_map(|(role, type_output_permission)| {
-            if type_output_permission.allowed_fields.contains(field_name) {
+            if type_output_permission.is_unconditionally_allowed(field_name) {
                 Some(role)
             } else {
                 None
@@ -203,7 +325,7 @@ pub(crate) fn get_node_field_namespace_permissions(
             .object_type
             .global_id_fields
             .iter()
-            .all(|field_name| type_output_permission.allowed_fields.contains(field_name));
+            .all(|field_name| type_output_permission.is_unconditionally_allowed(field_name));
 
         if is_global_id_field_accessible {
             let select_permission = model.select_permissions.get(role).map(|s| s.filter.clone());
@@ -237,7 +359,7 @@ pub(crate) fn get_entities_field_namespace_permissions(
             let is_all_keys_field_accessible =
                 apollo_federation_config.keys.iter().all(|key_fields| {
                     key_fields.fields.iter().all(|field_name| {
-                        type_output_permission.allowed_fields.contains(field_name)
+                        type_output_permission.is_unconditionally_allowed(field_name)
                     })
                 });
 
@@ -14,6 +14,8 @@ use self::node_field::RelayNodeFieldOutput;
 pub mod apollo_federation;
 pub mod node_field;
 pub mod select_aggregate;
+pub mod select_connection;
+pub mod select_group_by;
 pub mod select_many;
 pub mod select_one;
 
@@ -54,6 +56,26 @@ pub fn query_root_schema(
                 query_root_type_name,
             )?;
             fields.insert(field_name, field);
+            if let Some(group_by) = &select_aggregate.group_by {
+                let (field_name, field) = select_group_by::select_group_by_field(
+                    gds,
+                    builder,
+                    model,
+                    select_aggregate,
+                    group_by,
+                    query_root_type_name,
+                )?;
+                fields.insert(field_name, field);
+            }
+        }
+        if let Some(select_connection) = &model.graphql_api.select_connection {
+            let (field_name, field) = select_connection::select_connection_field(
+                builder,
+                model,
+                select_connection,
+                query_root_type_name,
+            )?;
+            fields.insert(field_name, field);
         }
     }
 
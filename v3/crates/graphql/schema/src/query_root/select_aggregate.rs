@@ -45,7 +45,9 @@ pub(crate) fn select_aggregate_field(
         parent_type,
     )?;
 
-    let field_permissions = permissions::get_select_permissions_namespace_annotations(model);
+    let field_permissions = permissions::apply_aggregation_permissions_model(
+        permissions::get_select_permissions_namespace_annotations(model),
+    );
 
     let output_typename = get_aggregate_select_output_type(builder, aggregate_expression)?;
 
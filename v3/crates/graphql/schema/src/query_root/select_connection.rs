@@ -0,0 +1,71 @@
+//! Schema for 'select_connection' operation
+//!
+//! A 'select_connection' operation paginates through the rows of a model with a Relay connection
+
+use lang_graphql::ast::common as ast;
+use lang_graphql::schema as gql_schema;
+use std::collections::BTreeMap;
+
+use crate::mk_deprecation_status;
+use crate::model_arguments::add_model_arguments_field;
+use crate::model_filter_input::{add_order_by_input_field, add_where_input_field};
+use crate::query_root::select_many::add_cursor_pagination_arguments;
+use crate::{permissions, relay, types, types::Annotation, GDS};
+use metadata_resolve;
+
+/// Generates schema for a 'select_connection' operation
+pub(crate) fn select_connection_field(
+    builder: &mut gql_schema::Builder<GDS>,
+    model: &metadata_resolve::ModelWithPermissions,
+    select_connection: &metadata_resolve::SelectConnectionGraphQlDefinition,
+    parent_type: &ast::TypeName,
+) -> Result<
+    (
+        ast::Name,
+        gql_schema::Namespaced<GDS, gql_schema::Field<GDS>>,
+    ),
+    crate::Error,
+> {
+    let query_root_field = select_connection.query_root_field.clone();
+    let mut arguments = BTreeMap::new();
+
+    add_order_by_input_field(&mut arguments, builder, model);
+    add_where_input_field(&mut arguments, builder, model);
+    add_model_arguments_field(
+        &mut arguments,
+        builder,
+        model,
+        &select_connection.query_root_field,
+        parent_type,
+    )?;
+    add_cursor_pagination_arguments(
+        &mut arguments,
+        builder,
+        &select_connection.cursor_pagination,
+    );
+
+    let field_type = ast::TypeContainer::named_non_null(relay::get_model_connection_type(
+        builder,
+        &model.model.name,
+        select_connection,
+    ));
+
+    let field = builder.conditional_namespaced(
+        gql_schema::Field::new(
+            query_root_field.clone(),
+            select_connection.description.clone(),
+            Annotation::Output(types::OutputAnnotation::RootField(
+                types::RootFieldAnnotation::Model {
+                    data_type: model.model.data_type.clone(),
+                    kind: types::RootFieldKind::SelectConnection,
+                    name: model.model.name.clone(),
+                },
+            )),
+            field_type,
+            arguments,
+            mk_deprecation_status(select_connection.deprecated.as_ref()),
+        ),
+        permissions::get_select_permissions_namespace_annotations(model),
+    );
+    Ok((query_root_field, field))
+}
@@ -0,0 +1,394 @@
+//! Schema for 'select_group_by' operation
+//!
+//! A 'select_group_by' operation groups the rows of a model by the requested dimensions, and
+//! fetches the aggregates over each group
+
+use lang_graphql::ast::common as ast;
+use lang_graphql::schema as gql_schema;
+use metadata_resolve::{Qualified, QualifiedTypeReference};
+use open_dds::models::ModelName;
+use open_dds::types::{CustomTypeName, Deprecated, FieldName};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use strum_macros::Display;
+
+use crate::aggregates::get_aggregate_select_output_type;
+use crate::model_arguments::add_model_arguments_field;
+use crate::model_filter_input::{
+    add_limit_input_field, add_offset_input_field, add_where_input_field,
+};
+use crate::model_order_by::get_aggregate_order_by_input_type;
+use crate::types::output_type::{get_object_type_representation, get_output_type};
+use crate::types::{self, Annotation, ModelInputAnnotation, OutputAnnotation, TypeId};
+use crate::{mk_deprecation_status, permissions, Error, GDS};
+
+/// Annotations of the fields of the group and dimensions types of a group by root field
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Display)]
+pub enum GroupByOutputAnnotation {
+    /// The `dimensions` field of a group
+    Dimensions,
+    /// The `aggregates` field of a group
+    Aggregates,
+    /// A field of the dimensions of a group, which groups the rows by its value
+    Dimension {
+        field_name: FieldName,
+        /// The parent type is required to report field usage while analyzing query usage.
+        parent_type: Qualified<CustomTypeName>,
+        dimension: metadata_resolve::GroupByDimension,
+        /// To mark a field as deprecated in the field usage while reporting query usage analytics.
+        deprecated: Option<Deprecated>,
+    },
+}
+
+/// Generates schema for a 'select_group_by' operation
+pub(crate) fn select_group_by_field(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    model: &metadata_resolve::ModelWithPermissions,
+    select_aggregate: &metadata_resolve::SelectAggregateGraphQlDefinition,
+    group_by: &metadata_resolve::GroupByGraphQlDefinition,
+    parent_type: &ast::TypeName,
+) -> Result<
+    (
+        ast::Name,
+        gql_schema::Namespaced<GDS, gql_schema::Field<GDS>>,
+    ),
+    Error,
+> {
+    // the aggregate expression must exist for the aggregates of the groups
+    gds.metadata
+        .aggregate_expressions
+        .get(&select_aggregate.aggregate_expression_name)
+        .ok_or_else(|| Error::InternalAggregateExpressionNotFound {
+            aggregate_expression: select_aggregate.aggregate_expression_name.clone(),
+        })?;
+
+    let query_root_field = group_by.query_root_field.clone();
+    let mut arguments = BTreeMap::new();
+
+    add_model_arguments_field(
+        &mut arguments,
+        builder,
+        model,
+        &group_by.query_root_field,
+        parent_type,
+    )?;
+    add_where_input_field(&mut arguments, builder, model);
+    add_limit_input_field(&mut arguments, builder, model)?;
+    add_offset_input_field(&mut arguments, builder, model)?;
+    if let Some(order_by_argument_name) = &group_by.order_by_argument_name {
+        if gds.metadata.graphql_config.order_by_input.is_some() {
+            let order_by_type = builder.register_type(TypeId::ModelGroupOrderByInputType {
+                model_name: model.model.name.clone(),
+                graphql_type_name: group_by.order_by_type_name.clone(),
+            });
+            let order_by_argument = gql_schema::InputField::new(
+                order_by_argument_name.clone(),
+                None,
+                Annotation::Input(types::InputAnnotation::Model(
+                    ModelInputAnnotation::ModelGroupOrderByExpression,
+                )),
+                ast::TypeContainer::list_null(ast::TypeContainer::named_non_null(order_by_type)),
+                None,
+                gql_schema::DeprecationStatus::NotDeprecated,
+            );
+            arguments.insert(
+                order_by_argument.name.clone(),
+                builder.allow_all_namespaced(order_by_argument),
+            );
+        }
+    }
+
+    let group_type = builder.register_type(TypeId::ModelGroupType {
+        model_name: model.model.name.clone(),
+        graphql_type_name: group_by.group_type_name.clone(),
+    });
+
+    let field_permissions = permissions::apply_aggregation_permissions_model(
+        permissions::get_select_permissions_namespace_annotations(model),
+    );
+
+    let field = builder.conditional_namespaced(
+        gql_schema::Field::new(
+            query_root_field.clone(),
+            group_by.description.clone(),
+            Annotation::Output(OutputAnnotation::RootField(
+                types::RootFieldAnnotation::Model {
+                    data_type: model.model.data_type.clone(),
+                    kind: types::RootFieldKind::SelectGroupBy,
+                    name: model.model.name.clone(),
+                },
+            )),
+            ast::TypeContainer::list_non_null(ast::TypeContainer::named_non_null(group_type)),
+            arguments,
+            mk_deprecation_status(group_by.deprecated.as_ref()),
+        ),
+        field_permissions,
+    );
+    Ok((query_root_field, field))
+}
+
+fn get_model_group_by<'s>(
+    gds: &'s GDS,
+    model_name: &Qualified<ModelName>,
+) -> Result<
+    (
+        &'s metadata_resolve::ModelWithPermissions,
+        &'s metadata_resolve::SelectAggregateGraphQlDefinition,
+        &'s metadata_resolve::GroupByGraphQlDefinition,
+    ),
+    Error,
+> {
+    let model =
+        gds.metadata
+            .models
+            .get(model_name)
+            .ok_or_else(|| Error::InternalModelNotFound {
+                model_name: model_name.clone(),
+            })?;
+    let (select_aggregate, group_by) = model
+        .graphql_api
+        .select_aggregate
+        .as_ref()
+        .and_then(|select_aggregate| {
+            select_aggregate
+                .group_by
+                .as_ref()
+                .map(|group_by| (select_aggregate, group_by))
+        })
+        .ok_or_else(|| Error::InternalGroupByNotFound {
+            model_name: model_name.clone(),
+        })?;
+    Ok((model, select_aggregate, group_by))
+}
+
+fn group_field(
+    builder: &mut gql_schema::Builder<GDS>,
+    name: ast::Name,
+    description: &str,
+    annotation: GroupByOutputAnnotation,
+    field_type: ast::TypeContainer<gql_schema::RegisteredTypeName>,
+) -> (
+    ast::Name,
+    gql_schema::Namespaced<GDS, gql_schema::Field<GDS>>,
+) {
+    let field = gql_schema::Field::new(
+        name.clone(),
+        Some(description.to_string()),
+        Annotation::Output(OutputAnnotation::GroupBy(annotation)),
+        field_type,
+        BTreeMap::new(),
+        gql_schema::DeprecationStatus::NotDeprecated,
+    );
+    (name, builder.allow_all_namespaced(field))
+}
+
+/// Builds the group type of a model, which contains the dimensions of a group and the aggregates
+/// over its rows
+pub(crate) fn build_model_group_type(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    model_name: &Qualified<ModelName>,
+    graphql_type_name: &ast::TypeName,
+) -> Result<gql_schema::TypeInfo<GDS>, Error> {
+    let (_, select_aggregate, group_by) = get_model_group_by(gds, model_name)?;
+    let aggregate_expression = gds
+        .metadata
+        .aggregate_expressions
+        .get(&select_aggregate.aggregate_expression_name)
+        .ok_or_else(|| Error::InternalAggregateExpressionNotFound {
+            aggregate_expression: select_aggregate.aggregate_expression_name.clone(),
+        })?;
+    let dimensions_type = builder.register_type(TypeId::ModelGroupDimensionsType {
+        model_name: model_name.clone(),
+        graphql_type_name: group_by.dimensions_type_name.clone(),
+    });
+    let aggregates_type = get_aggregate_select_output_type(builder, aggregate_expression)?;
+    let fields = BTreeMap::from([
+        group_field(
+            builder,
+            lang_graphql::mk_name!("dimensions"),
+            "The values the rows of the group share",
+            GroupByOutputAnnotation::Dimensions,
+            ast::TypeContainer::named_non_null(dimensions_type),
+        ),
+        group_field(
+            builder,
+            lang_graphql::mk_name!("aggregates"),
+            "The aggregates over the rows of the group",
+            GroupByOutputAnnotation::Aggregates,
+            ast::TypeContainer::named_non_null(aggregates_type),
+        ),
+    ]);
+    Ok(gql_schema::TypeInfo::Object(gql_schema::Object::new(
+        builder,
+        graphql_type_name.clone(),
+        None,
+        fields,
+        BTreeMap::new(),
+        Vec::new(),
+    )))
+}
+
+/// Builds the dimensions type of a model, whose selected fields are the values the rows are
+/// grouped by
+pub(crate) fn build_model_group_dimensions_type(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    model_name: &Qualified<ModelName>,
+    graphql_type_name: &ast::TypeName,
+) -> Result<gql_schema::TypeInfo<GDS>, Error> {
+    let (model, _, group_by) = get_model_group_by(gds, model_name)?;
+    let object_type_representation = get_object_type_representation(gds, &model.model.data_type)?;
+
+    let mut fields = BTreeMap::new();
+    for (graphql_field_name, dimension) in &group_by.dimensions {
+        let field_definition = object_type_representation
+            .object_type
+            .fields
+            .get(&dimension.field_name)
+            .ok_or_else(|| Error::InternalObjectTypeFieldNotFound {
+                field_name: dimension.field_name.clone(),
+                type_name: model.model.data_type.clone(),
+            })?;
+        // the rows of a group may not have a value for the field, and the extracted parts of a
+        // field are integers
+        let field_type = match dimension.extraction {
+            None => get_output_type(
+                gds,
+                builder,
+                &QualifiedTypeReference {
+                    underlying_type: field_definition.field_type.underlying_type.clone(),
+                    nullable: true,
+                },
+            )?,
+            Some(_) => ast::TypeContainer::named_null(gql_schema::RegisteredTypeName::int()),
+        };
+        let field_permissions = permissions::get_allowed_roles_for_field(
+            object_type_representation,
+            &dimension.field_name,
+        )
+        .map(|role| (role.clone(), None))
+        .collect();
+        let field = builder.conditional_namespaced(
+            gql_schema::Field::new(
+                graphql_field_name.clone(),
+                field_definition.description.clone(),
+                Annotation::Output(OutputAnnotation::GroupBy(
+                    GroupByOutputAnnotation::Dimension {
+                        field_name: dimension.field_name.clone(),
+                        parent_type: model.model.data_type.clone(),
+                        dimension: dimension.clone(),
+                        deprecated: field_definition.deprecated.clone(),
+                    },
+                )),
+                field_type,
+                BTreeMap::new(),
+                mk_deprecation_status(field_definition.deprecated.as_ref()),
+            ),
+            field_permissions,
+        );
+        fields.insert(graphql_field_name.clone(), field);
+    }
+
+    Ok(gql_schema::TypeInfo::Object(gql_schema::Object::new(
+        builder,
+        graphql_type_name.clone(),
+        None,
+        fields,
+        BTreeMap::new(),
+        Vec::new(),
+    )))
+}
+
+/// Builds the input type to order the groups of a model, by their dimensions or by the aggregates
+/// over their rows
+pub(crate) fn build_model_group_order_by_input_type(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    model_name: &Qualified<ModelName>,
+    graphql_type_name: &ast::TypeName,
+) -> Result<gql_schema::TypeInfo<GDS>, Error> {
+    let (model, select_aggregate, group_by) = get_model_group_by(gds, model_name)?;
+    let object_type_representation = get_object_type_representation(gds, &model.model.data_type)?;
+    let order_by_input_config = gds
+        .metadata
+        .graphql_config
+        .order_by_input
+        .as_ref()
+        .ok_or_else(|| Error::InternalNoOrderByGraphqlConfigOrderByEnumType {
+            type_name: graphql_type_name.clone(),
+        })?;
+    let enum_type = builder.register_type(TypeId::OrderByEnumType {
+        graphql_type_name: order_by_input_config.enum_type_name.clone(),
+    });
+
+    let aggregates_field_name = lang_graphql::mk_name!("aggregates");
+    let mut fields = BTreeMap::new();
+    for (graphql_field_name, dimension) in &group_by.dimensions {
+        // the aggregates are ordered by through their own field
+        if *graphql_field_name == aggregates_field_name {
+            continue;
+        }
+        let field_definition = object_type_representation
+            .object_type
+            .fields
+            .get(&dimension.field_name)
+            .ok_or_else(|| Error::InternalObjectTypeFieldNotFound {
+                field_name: dimension.field_name.clone(),
+                type_name: model.model.data_type.clone(),
+            })?;
+        let field_permissions = permissions::get_allowed_roles_for_field(
+            object_type_representation,
+            &dimension.field_name,
+        )
+        .map(|role| (role.clone(), None))
+        .collect();
+        let input_field = builder.conditional_namespaced(
+            gql_schema::InputField::new(
+                graphql_field_name.clone(),
+                None,
+                Annotation::Input(types::InputAnnotation::Model(
+                    ModelInputAnnotation::ModelGroupOrderByDimension {
+                        field_name: dimension.field_name.clone(),
+                        parent_type: model.model.data_type.clone(),
+                        dimension: dimension.clone(),
+                        deprecated: field_definition.deprecated.clone(),
+                    },
+                )),
+                ast::TypeContainer::named_null(enum_type.clone()),
+                None,
+                gql_schema::DeprecationStatus::NotDeprecated,
+            ),
+            field_permissions,
+        );
+        fields.insert(graphql_field_name.clone(), input_field);
+    }
+
+    let aggregate_expression = gds
+        .metadata
+        .aggregate_expressions
+        .get(&select_aggregate.aggregate_expression_name)
+        .ok_or_else(|| Error::InternalAggregateExpressionNotFound {
+            aggregate_expression: select_aggregate.aggregate_expression_name.clone(),
+        })?;
+    let aggregates_order_by_type =
+        get_aggregate_order_by_input_type(builder, aggregate_expression)?;
+    fields.insert(
+        aggregates_field_name.clone(),
+        builder.allow_all_namespaced(gql_schema::InputField::new(
+            aggregates_field_name,
+            Some("Orders the groups by the aggregates over their rows".to_string()),
+            Annotation::Input(types::InputAnnotation::Model(
+                ModelInputAnnotation::ModelGroupOrderByAggregates,
+            )),
+            ast::TypeContainer::named_null(aggregates_order_by_type),
+            None,
+            gql_schema::DeprecationStatus::NotDeprecated,
+        )),
+    );
+
+    Ok(gql_schema::TypeInfo::InputObject(
+        gql_schema::InputObject::new(graphql_type_name.clone(), None, fields, Vec::new()),
+    ))
+}
@@ -2,10 +2,12 @@
 //!
 //! A 'select_many' operation fetches zero or one row from a model
 
+use hasura_authn_core::Role;
 use lang_graphql::ast::common as ast;
 use lang_graphql::ast::common::Name;
 use lang_graphql::schema as gql_schema;
-use std::collections::BTreeMap;
+use open_dds::models::ModelName;
+use std::collections::{BTreeMap, HashMap};
 
 use crate::mk_deprecation_status;
 use crate::model_arguments::add_model_arguments_field;
@@ -14,13 +16,17 @@ use crate::model_filter_input::{
 };
 use crate::{
     permissions,
-    types::{self, output_type::get_custom_output_type, Annotation},
+    types::{
+        self,
+        output_type::{get_custom_output_type, get_object_type_representation},
+        Annotation,
+    },
     GDS,
 };
-use metadata_resolve;
+use metadata_resolve::{self, Qualified};
 
 /// Generates the schema for the arguments of a model selection, which includes
-/// limit, offset, order_by and where.
+/// limit, offset, order_by, where and distinct_on.
 pub(crate) fn generate_select_many_arguments(
     builder: &mut gql_schema::Builder<GDS>,
     model: &metadata_resolve::ModelWithPermissions,
@@ -32,10 +38,167 @@ pub(crate) fn generate_select_many_arguments(
     add_offset_input_field(&mut arguments, builder, model)?;
     add_order_by_input_field(&mut arguments, builder, model);
     add_where_input_field(&mut arguments, builder, model);
+    add_distinct_on_argument(&mut arguments, builder, model);
 
     Ok(arguments)
 }
 
+/// Adds the `distinct_on` argument, if the model allows selecting distinct rows.
+fn add_distinct_on_argument(
+    arguments: &mut BTreeMap<Name, gql_schema::Namespaced<GDS, gql_schema::InputField<GDS>>>,
+    builder: &mut gql_schema::Builder<GDS>,
+    model: &metadata_resolve::ModelWithPermissions,
+) {
+    let Some(distinct_on) = model
+        .graphql_api
+        .select_many
+        .as_ref()
+        .and_then(|select_many| select_many.distinct_on.as_ref())
+    else {
+        return;
+    };
+    let distinct_on_enum_type = builder.register_type(types::TypeId::ModelDistinctOnEnumType {
+        model_name: model.model.name.clone(),
+        graphql_type_name: distinct_on.enum_type_name.clone(),
+    });
+    let argument = gql_schema::InputField::new(
+        distinct_on.argument_name.clone(),
+        Some(
+            "Selects only the first row of each distinct combination of values of the given fields"
+                .to_string(),
+        ),
+        Annotation::Input(types::InputAnnotation::Model(
+            types::ModelInputAnnotation::ModelDistinctOnArgument,
+        )),
+        ast::TypeContainer::list_null(ast::TypeContainer::named_non_null(distinct_on_enum_type)),
+        None,
+        gql_schema::DeprecationStatus::NotDeprecated,
+    );
+    arguments.insert(
+        argument.name.clone(),
+        builder.allow_all_namespaced(argument),
+    );
+}
+
+/// Builds the enum of the fields the rows of a model can be distinct on. A field is only
+/// available to the roles that can select it.
+pub(crate) fn build_distinct_on_enum_type(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    model_name: &Qualified<ModelName>,
+    graphql_type_name: &ast::TypeName,
+) -> Result<gql_schema::TypeInfo<GDS>, crate::Error> {
+    let model =
+        gds.metadata
+            .models
+            .get(model_name)
+            .ok_or_else(|| crate::Error::InternalModelNotFound {
+                model_name: model_name.clone(),
+            })?;
+    let distinct_on = model
+        .graphql_api
+        .select_many
+        .as_ref()
+        .and_then(|select_many| select_many.distinct_on.as_ref())
+        .ok_or_else(|| crate::Error::InternalDistinctOnNotFound {
+            model_name: model_name.clone(),
+        })?;
+    let object_type_representation = get_object_type_representation(gds, &model.model.data_type)?;
+
+    let mut values = BTreeMap::new();
+    for (field_name, ndc_column) in &distinct_on.fields {
+        let graphql_field_name = metadata_resolve::mk_name(field_name.as_str())
+            .map_err(metadata_resolve::Error::from)
+            .map_err(metadata_resolve::WithContext::from)?;
+        let field_definition = object_type_representation
+            .object_type
+            .fields
+            .get(field_name)
+            .ok_or_else(|| crate::Error::InternalObjectTypeFieldNotFound {
+                field_name: field_name.clone(),
+                type_name: model.model.data_type.clone(),
+            })?;
+        let field_permissions: HashMap<Role, Option<types::NamespaceAnnotation>> =
+            permissions::get_allowed_roles_for_field(object_type_representation, field_name)
+                .map(|role| (role.clone(), None))
+                .collect();
+        values.insert(
+            graphql_field_name.clone(),
+            builder.conditional_namespaced(
+                gql_schema::EnumValue {
+                    value: graphql_field_name,
+                    description: field_definition.description.clone(),
+                    deprecation_status: mk_deprecation_status(field_definition.deprecated.as_ref()),
+                    info: Annotation::Input(types::InputAnnotation::Model(
+                        types::ModelInputAnnotation::ModelDistinctOnField {
+                            field_name: field_name.clone(),
+                            parent_type: model.model.data_type.clone(),
+                            ndc_column: ndc_column.clone(),
+                            deprecated: field_definition.deprecated.clone(),
+                        },
+                    )),
+                },
+                field_permissions,
+            ),
+        );
+    }
+
+    Ok(gql_schema::TypeInfo::Enum(gql_schema::Enum {
+        name: graphql_type_name.clone(),
+        description: None,
+        values,
+        directives: Vec::new(),
+    }))
+}
+
+/// Adds the `first`/`after` and `last`/`before` arguments of cursor pagination.
+pub(crate) fn add_cursor_pagination_arguments(
+    arguments: &mut BTreeMap<Name, gql_schema::Namespaced<GDS, gql_schema::InputField<GDS>>>,
+    builder: &mut gql_schema::Builder<GDS>,
+    cursor_pagination: &metadata_resolve::CursorPaginationGraphQlDefinition,
+) {
+    let cursor_pagination_arguments = [
+        (
+            &cursor_pagination.first_argument_name,
+            "The number of rows to fetch after the `after` cursor",
+            types::ModelInputAnnotation::ModelFirstArgument,
+            gql_schema::RegisteredTypeName::int(),
+        ),
+        (
+            &cursor_pagination.after_argument_name,
+            "The cursor after which to fetch rows",
+            types::ModelInputAnnotation::ModelAfterArgument,
+            gql_schema::RegisteredTypeName::string(),
+        ),
+        (
+            &cursor_pagination.last_argument_name,
+            "The number of rows to fetch before the `before` cursor",
+            types::ModelInputAnnotation::ModelLastArgument,
+            gql_schema::RegisteredTypeName::int(),
+        ),
+        (
+            &cursor_pagination.before_argument_name,
+            "The cursor before which to fetch rows",
+            types::ModelInputAnnotation::ModelBeforeArgument,
+            gql_schema::RegisteredTypeName::string(),
+        ),
+    ];
+    for (argument_name, description, annotation, argument_type) in cursor_pagination_arguments {
+        let argument = gql_schema::InputField::new(
+            argument_name.clone(),
+            Some(description.to_string()),
+            Annotation::Input(types::InputAnnotation::Model(annotation)),
+            ast::TypeContainer::named_null(argument_type),
+            None,
+            gql_schema::DeprecationStatus::NotDeprecated,
+        );
+        arguments.insert(
+            argument.name.clone(),
+            builder.allow_all_namespaced(argument),
+        );
+    }
+}
+
 /// Generates schema for a 'select_many' operation
 pub(crate) fn select_many_field(
     gds: &GDS,
@@ -61,6 +224,10 @@ pub(crate) fn select_many_field(
         parent_type,
     )?;
 
+    if let Some(cursor_pagination) = &select_many.cursor_pagination {
+        add_cursor_pagination_arguments(&mut arguments, builder, cursor_pagination);
+    }
+
     let field_type = ast::TypeContainer::list_null(ast::TypeContainer::named_non_null(
         get_custom_output_type(gds, builder, &model.model.data_type)?,
     ));
@@ -5,7 +5,7 @@
 use indexmap::IndexMap;
 use lang_graphql::{ast::common as ast, schema as gql_schema};
 use open_dds::types::FieldName;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::types::output_type::get_object_type_representation;
 use crate::{mk_deprecation_status, GDS};
@@ -83,6 +83,34 @@ pub(crate) fn generate_select_one_arguments(
 ) -> Result<
     BTreeMap<ast::Name, gql_schema::Namespaced<GDS, gql_schema::InputField<GDS>>>,
     crate::Error,
+> {
+    let mut arguments = generate_unique_identifier_arguments(gds, builder, unique_identifier)?;
+
+    for (argument_field_name, argument_field) in
+        model_arguments::build_model_argument_fields(gds, builder, model, &BTreeSet::new())?
+    {
+        if arguments
+            .insert(argument_field_name.clone(), argument_field)
+            .is_some()
+        {
+            return Err(crate::Error::GraphQlArgumentConflict {
+                argument_name: argument_field_name,
+                field_name: root_field,
+                type_name: parent_type.clone(),
+            });
+        }
+    }
+    Ok(arguments)
+}
+
+/// The arguments for the fields of a unique identifier of a model
+pub(crate) fn generate_unique_identifier_arguments(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    unique_identifier: &IndexMap<FieldName, metadata_resolve::UniqueIdentifierField>,
+) -> Result<
+    BTreeMap<ast::Name, gql_schema::Namespaced<GDS, gql_schema::InputField<GDS>>>,
+    crate::Error,
 > {
     let mut arguments = BTreeMap::new();
     for (field_name, field) in unique_identifier {
@@ -109,20 +137,5 @@ pub(crate) fn generate_select_one_arguments(
             builder.allow_all_namespaced(argument),
         );
     }
-
-    for (argument_field_name, argument_field) in
-        model_arguments::build_model_argument_fields(gds, builder, model)?
-    {
-        if arguments
-            .insert(argument_field_name.clone(), argument_field)
-            .is_some()
-        {
-            return Err(crate::Error::GraphQlArgumentConflict {
-                argument_name: argument_field_name,
-                field_name: root_field,
-                type_name: parent_type.clone(),
-            });
-        }
-    }
     Ok(arguments)
 }
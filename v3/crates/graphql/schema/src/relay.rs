@@ -1,7 +1,13 @@
 //! Schema of the relay according to <https://relay.dev/graphql/objectidentification.htm>
+//! and <https://relay.dev/graphql/connections.htm>
 
+use lang_graphql::ast::common as ast;
 use lang_graphql::schema as gql_schema;
+use metadata_resolve::Qualified;
+use open_dds::models::ModelName;
+use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap};
+use strum_macros::Display;
 
 use super::permissions;
 use crate::types::{
@@ -9,7 +15,7 @@ use crate::types::{
     output_type::{
         get_custom_output_type, get_object_type_representation, get_output_type, ID_TYPE_REFERENCE,
     },
-    Annotation, OutputAnnotation,
+    Annotation, OutputAnnotation, TypeId,
 };
 use crate::{mk_typename, Role, GDS};
 
@@ -73,3 +79,199 @@ pub fn node_interface_schema(
         Vec::new(),
     ))
 }
+
+/// Annotations of the fields of the Relay connection, edge and `PageInfo` types.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Display)]
+pub enum ConnectionOutputAnnotation {
+    /// The `edges` field of a connection
+    Edges,
+    /// The `pageInfo` field of a connection
+    PageInfo,
+    /// The `cursor` field of an edge
+    EdgeCursor,
+    /// The `node` field of an edge
+    EdgeNode,
+    HasNextPage,
+    HasPreviousPage,
+    StartCursor,
+    EndCursor,
+}
+
+/// Registers the connection type of a model with a select connection
+pub(crate) fn get_model_connection_type(
+    builder: &mut gql_schema::Builder<GDS>,
+    model_name: &Qualified<ModelName>,
+    select_connection: &metadata_resolve::SelectConnectionGraphQlDefinition,
+) -> gql_schema::RegisteredTypeName {
+    builder.register_type(TypeId::ModelConnectionType {
+        model_name: model_name.clone(),
+        graphql_type_name: select_connection.connection_type_name.clone(),
+    })
+}
+
+fn get_model_select_connection<'s>(
+    gds: &'s GDS,
+    model_name: &Qualified<ModelName>,
+) -> Result<
+    (
+        &'s metadata_resolve::ModelWithPermissions,
+        &'s metadata_resolve::SelectConnectionGraphQlDefinition,
+    ),
+    crate::Error,
+> {
+    let model =
+        gds.metadata
+            .models
+            .get(model_name)
+            .ok_or_else(|| crate::Error::InternalModelNotFound {
+                model_name: model_name.clone(),
+            })?;
+    let select_connection = model
+        .graphql_api
+        .select_connection
+        .as_ref()
+        .ok_or_else(|| crate::Error::InternalSelectConnectionNotFound {
+            model_name: model_name.clone(),
+        })?;
+    Ok((model, select_connection))
+}
+
+fn connection_field(
+    builder: &mut gql_schema::Builder<GDS>,
+    name: ast::Name,
+    description: &str,
+    annotation: ConnectionOutputAnnotation,
+    field_type: ast::TypeContainer<gql_schema::RegisteredTypeName>,
+) -> (
+    ast::Name,
+    gql_schema::Namespaced<GDS, gql_schema::Field<GDS>>,
+) {
+    let field = gql_schema::Field::new(
+        name.clone(),
+        Some(description.to_string()),
+        Annotation::Output(OutputAnnotation::Connection(annotation)),
+        field_type,
+        BTreeMap::new(),
+        gql_schema::DeprecationStatus::NotDeprecated,
+    );
+    (name, builder.allow_all_namespaced(field))
+}
+
+/// Builds the connection type of a model, which contains the edges of the requested page
+/// and the information about the page.
+pub(crate) fn build_model_connection_type(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    model_name: &Qualified<ModelName>,
+    graphql_type_name: &ast::TypeName,
+) -> Result<gql_schema::TypeInfo<GDS>, crate::Error> {
+    let (_, select_connection) = get_model_select_connection(gds, model_name)?;
+    let edge_type = builder.register_type(TypeId::ModelEdgeType {
+        model_name: model_name.clone(),
+        graphql_type_name: select_connection.edge_type_name.clone(),
+    });
+    let page_info_type = builder.register_type(TypeId::PageInfoType);
+    let fields = BTreeMap::from([
+        connection_field(
+            builder,
+            lang_graphql::mk_name!("edges"),
+            "The edges of the requested page",
+            ConnectionOutputAnnotation::Edges,
+            ast::TypeContainer::list_non_null(ast::TypeContainer::named_non_null(edge_type)),
+        ),
+        connection_field(
+            builder,
+            lang_graphql::mk_name!("pageInfo"),
+            "Information about the requested page",
+            ConnectionOutputAnnotation::PageInfo,
+            ast::TypeContainer::named_non_null(page_info_type),
+        ),
+    ]);
+    Ok(gql_schema::TypeInfo::Object(gql_schema::Object::new(
+        builder,
+        graphql_type_name.clone(),
+        None,
+        fields,
+        BTreeMap::new(),
+        Vec::new(),
+    )))
+}
+
+/// Builds the edge type of a model, which contains an object of the model along with its cursor
+pub(crate) fn build_model_edge_type(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    model_name: &Qualified<ModelName>,
+    graphql_type_name: &ast::TypeName,
+) -> Result<gql_schema::TypeInfo<GDS>, crate::Error> {
+    let (model, _) = get_model_select_connection(gds, model_name)?;
+    let node_type = get_custom_output_type(gds, builder, &model.model.data_type)?;
+    let fields = BTreeMap::from([
+        connection_field(
+            builder,
+            lang_graphql::mk_name!("cursor"),
+            "The cursor of the edge, to paginate from it",
+            ConnectionOutputAnnotation::EdgeCursor,
+            ast::TypeContainer::named_non_null(gql_schema::RegisteredTypeName::string()),
+        ),
+        connection_field(
+            builder,
+            lang_graphql::mk_name!("node"),
+            "The object at the end of the edge",
+            ConnectionOutputAnnotation::EdgeNode,
+            ast::TypeContainer::named_non_null(node_type),
+        ),
+    ]);
+    Ok(gql_schema::TypeInfo::Object(gql_schema::Object::new(
+        builder,
+        graphql_type_name.clone(),
+        None,
+        fields,
+        BTreeMap::new(),
+        Vec::new(),
+    )))
+}
+
+/// Builds the `PageInfo` type, which is shared by the connections of all models
+pub(crate) fn build_page_info_type(
+    builder: &mut gql_schema::Builder<GDS>,
+) -> Result<gql_schema::TypeInfo<GDS>, crate::Error> {
+    let fields = BTreeMap::from([
+        connection_field(
+            builder,
+            lang_graphql::mk_name!("hasNextPage"),
+            "Whether there are more edges after the requested page",
+            ConnectionOutputAnnotation::HasNextPage,
+            ast::TypeContainer::named_non_null(gql_schema::RegisteredTypeName::boolean()),
+        ),
+        connection_field(
+            builder,
+            lang_graphql::mk_name!("hasPreviousPage"),
+            "Whether there are more edges before the requested page",
+            ConnectionOutputAnnotation::HasPreviousPage,
+            ast::TypeContainer::named_non_null(gql_schema::RegisteredTypeName::boolean()),
+        ),
+        connection_field(
+            builder,
+            lang_graphql::mk_name!("startCursor"),
+            "The cursor of the first edge of the requested page",
+            ConnectionOutputAnnotation::StartCursor,
+            ast::TypeContainer::named_null(gql_schema::RegisteredTypeName::string()),
+        ),
+        connection_field(
+            builder,
+            lang_graphql::mk_name!("endCursor"),
+            "The cursor of the last edge of the requested page",
+            ConnectionOutputAnnotation::EndCursor,
+            ast::TypeContainer::named_null(gql_schema::RegisteredTypeName::string()),
+        ),
+    ]);
+    Ok(gql_schema::TypeInfo::Object(gql_schema::Object::new(
+        builder,
+        ast::TypeName(lang_graphql::mk_name!("PageInfo")),
+        None,
+        fields,
+        BTreeMap::new(),
+        Vec::new(),
+    )))
+}
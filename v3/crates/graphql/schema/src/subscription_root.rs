@@ -4,8 +4,11 @@ use hasura_authn_core::Role;
 use indexmap::IndexMap;
 use lang_graphql::ast::common as ast;
 use lang_graphql::ast::common::TypeName;
+use lang_graphql::mk_name;
 use lang_graphql::schema as gql_schema;
+use metadata_resolve::{Qualified, QualifiedTypeReference};
 use open_dds::aggregates::AggregateExpressionName;
+use open_dds::models::ModelName;
 use open_dds::types::FieldName;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
@@ -13,7 +16,10 @@ use std::collections::HashMap;
 use crate::aggregates::get_aggregate_select_output_type;
 use crate::mk_deprecation_status;
 use crate::model_arguments;
+use crate::model_filter_input::add_where_input_field;
+use crate::permissions;
 use crate::types;
+use crate::types::input_type::get_input_type;
 use crate::types::output_type::get_custom_output_type;
 use crate::types::output_type::get_object_type_representation;
 use crate::Annotation;
@@ -55,6 +61,18 @@ pub fn subscription_root_schema(
                 )?;
                 fields.insert(field_name, field);
             }
+
+            // Add the streaming subscription field to the subscription root
+            if let Some(stream_subscription) = &select_many.stream_subscription {
+                let (field_name, field) = select_stream_field(
+                    gds,
+                    builder,
+                    model,
+                    stream_subscription,
+                    subscription_root_type_name,
+                )?;
+                fields.insert(field_name, field);
+            }
         }
 
         // Add select_aggregate fields to the subscription root
@@ -217,7 +235,9 @@ fn select_aggregate_field(
         parent_type,
     )?;
 
-    let field_permissions = get_select_permissions_namespace_annotations(model);
+    let field_permissions = permissions::apply_aggregation_permissions_model(
+        get_select_permissions_namespace_annotations(model),
+    );
 
     let output_typename = get_aggregate_select_output_type(builder, aggregate_expression)?;
 
@@ -242,6 +262,172 @@ fn select_aggregate_field(
     Ok((subscription_field_name, field))
 }
 
+/// Generates schema for a streaming subscription on a model
+fn select_stream_field(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    model: &metadata_resolve::ModelWithPermissions,
+    stream_subscription: &metadata_resolve::StreamSubscriptionGraphQlDefinition,
+    parent_type: &ast::TypeName,
+) -> Result<
+    (
+        ast::Name,
+        gql_schema::Namespaced<GDS, gql_schema::Field<GDS>>,
+    ),
+    crate::Error,
+> {
+    let subscription_root_field = stream_subscription.root_field.clone();
+    let mut arguments = BTreeMap::new();
+
+    let batch_size_argument = gql_schema::InputField::new(
+        mk_name!("batch_size"),
+        Some("The maximum number of rows to send in each batch".to_string()),
+        Annotation::Input(types::InputAnnotation::Model(
+            types::ModelInputAnnotation::ModelStreamBatchSizeArgument,
+        )),
+        ast::TypeContainer::named_non_null(gql_schema::RegisteredTypeName::int()),
+        None,
+        gql_schema::DeprecationStatus::NotDeprecated,
+    );
+    arguments.insert(
+        batch_size_argument.name.clone(),
+        builder.allow_all_namespaced(batch_size_argument),
+    );
+
+    let cursor_input_type = builder.register_type(types::TypeId::ModelStreamCursorInputType {
+        model_name: model.model.name.clone(),
+        graphql_type_name: stream_subscription.cursor_input_type_name.clone(),
+    });
+    let cursor_argument = gql_schema::InputField::new(
+        mk_name!("cursor"),
+        Some(
+            "The field to stream the rows by, and the value after which to start streaming"
+                .to_string(),
+        ),
+        Annotation::Input(types::InputAnnotation::Model(
+            types::ModelInputAnnotation::ModelStreamCursorArgument,
+        )),
+        ast::TypeContainer::named_non_null(cursor_input_type),
+        None,
+        gql_schema::DeprecationStatus::NotDeprecated,
+    );
+    arguments.insert(
+        cursor_argument.name.clone(),
+        builder.allow_all_namespaced(cursor_argument),
+    );
+
+    add_where_input_field(&mut arguments, builder, model);
+
+    model_arguments::add_model_arguments_field(
+        &mut arguments,
+        builder,
+        model,
+        &stream_subscription.root_field,
+        parent_type,
+    )?;
+
+    let field_type = ast::TypeContainer::list_null(ast::TypeContainer::named_non_null(
+        get_custom_output_type(gds, builder, &model.model.data_type)?,
+    ));
+
+    let field = builder.conditional_namespaced(
+        gql_schema::Field::new(
+            subscription_root_field.clone(),
+            stream_subscription.description.clone(),
+            Annotation::Output(types::OutputAnnotation::RootField(
+                types::RootFieldAnnotation::ModelStreamSubscription {
+                    data_type: model.model.data_type.clone(),
+                    name: model.model.name.clone(),
+                    polling_interval_ms: stream_subscription.polling_interval_ms,
+                },
+            )),
+            field_type,
+            arguments,
+            mk_deprecation_status(stream_subscription.deprecated.as_ref()),
+        ),
+        get_select_permissions_namespace_annotations(model),
+    );
+    Ok((subscription_root_field, field))
+}
+
+/// Builds the input type of the `cursor` argument of a streaming subscription. Each of its
+/// fields is a field of the model that can be used as the cursor, and exactly one of them
+/// must be provided along with the value after which to start streaming.
+pub(crate) fn build_stream_cursor_input_type(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    model_name: &Qualified<ModelName>,
+    graphql_type_name: &ast::TypeName,
+) -> Result<gql_schema::TypeInfo<GDS>, crate::Error> {
+    let model =
+        gds.metadata
+            .models
+            .get(model_name)
+            .ok_or_else(|| crate::Error::InternalModelNotFound {
+                model_name: model_name.clone(),
+            })?;
+    let stream_subscription = model
+        .graphql_api
+        .select_many
+        .as_ref()
+        .and_then(|select_many| select_many.stream_subscription.as_ref())
+        .ok_or_else(|| crate::Error::InternalStreamSubscriptionNotFound {
+            model_name: model_name.clone(),
+        })?;
+    let object_type_representation = get_object_type_representation(gds, &model.model.data_type)?;
+
+    let mut fields = BTreeMap::new();
+    for (field_name, cursor_field) in &stream_subscription.cursor_fields {
+        let graphql_field_name = metadata_resolve::mk_name(field_name.as_str())
+            .map_err(metadata_resolve::Error::from)
+            .map_err(metadata_resolve::WithContext::from)?;
+        let field_definition = object_type_representation
+            .object_type
+            .fields
+            .get(field_name)
+            .ok_or_else(|| crate::Error::InternalObjectTypeFieldNotFound {
+                field_name: field_name.clone(),
+                type_name: model.model.data_type.clone(),
+            })?;
+        // Only one of the cursor fields is provided, so all of them are nullable
+        let input_type = get_input_type(
+            gds,
+            builder,
+            &QualifiedTypeReference {
+                underlying_type: cursor_field.field_type.underlying_type.clone(),
+                nullable: true,
+            },
+        )?;
+        let field_permissions: HashMap<Role, Option<types::NamespaceAnnotation>> =
+            permissions::get_allowed_roles_for_field(object_type_representation, field_name)
+                .map(|role| (role.clone(), None))
+                .collect();
+        let input_field = gql_schema::InputField::new(
+            graphql_field_name.clone(),
+            field_definition.description.clone(),
+            Annotation::Input(types::InputAnnotation::Model(
+                types::ModelInputAnnotation::ModelStreamCursorField {
+                    field_name: field_name.clone(),
+                    parent_type: model.model.data_type.clone(),
+                    cursor_field: cursor_field.clone(),
+                    deprecated: field_definition.deprecated.clone(),
+                },
+            )),
+            input_type,
+            None,
+            mk_deprecation_status(field_definition.deprecated.as_ref()),
+        );
+        fields.insert(
+            graphql_field_name,
+            builder.conditional_namespaced(input_field, field_permissions),
+        );
+    }
+
+    Ok(gql_schema::TypeInfo::InputObject(
+        gql_schema::InputObject::new(graphql_type_name.clone(), None, fields, Vec::new()),
+    ))
+}
+
 /// Build namespace annotations for subscription select one root field.
 ///
 /// This wrapper function combines the process of generating annotations and
@@ -5,7 +5,7 @@ use lang_graphql::{
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     fmt::Display,
     sync::Arc,
 };
@@ -14,7 +14,7 @@ use open_dds::{
     aggregates,
     arguments::ArgumentName,
     commands,
-    data_connector::{DataConnectorName, DataConnectorOperatorName},
+    data_connector::{DataConnectorColumnName, DataConnectorName, DataConnectorOperatorName},
     models,
     types::{self, DataConnectorArgumentName, Deprecated},
 };
@@ -22,7 +22,7 @@ use open_dds::{
 use metadata_resolve::{
     self, deserialize_non_string_key_btreemap, serialize_non_string_key_btreemap, FieldPresetInfo,
     LogicalOperators, NdcColumnForComparison, OperatorMapping, OrderByExpressionIdentifier,
-    Qualified, QualifiedTypeReference,
+    Qualified, QualifiedTypeName, QualifiedTypeReference,
 };
 
 use json_ext::HashMapWithJsonKey;
@@ -32,6 +32,7 @@ use self::output_type::relationship::{
     FilterRelationshipAnnotation, OrderByRelationshipAnnotation,
 };
 
+pub mod abstract_type;
 pub mod inbuilt_type;
 pub mod input_type;
 pub mod output_type;
@@ -77,6 +78,8 @@ pub enum RootFieldKind {
     SelectOne,
     SelectMany,
     SelectAggregate,
+    SelectConnection,
+    SelectGroupBy,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -91,6 +94,10 @@ pub enum ObjectFieldKind {
 pub enum ModelOrderByDirection {
     Asc,
     Desc,
+    AscNullsFirst,
+    AscNullsLast,
+    DescNullsFirst,
+    DescNullsLast,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Display)]
@@ -120,6 +127,11 @@ pub enum RootFieldAnnotation {
         name: Qualified<models::ModelName>,
         polling_interval_ms: u64,
     },
+    ModelStreamSubscription {
+        data_type: Qualified<types::CustomTypeName>,
+        name: Qualified<models::ModelName>,
+        polling_interval_ms: u64,
+    },
     FunctionCommand {
         name: Qualified<commands::CommandName>,
         result_type: QualifiedTypeReference,
@@ -132,9 +144,25 @@ pub enum RootFieldAnnotation {
         result_base_type_kind: TypeKind,
         procedure_name: Option<commands::ProcedureName>,
     },
+    /// A generated mutation of a model, which calls a procedure of its data connector
+    ModelMutation {
+        data_type: Qualified<types::CustomTypeName>,
+        kind: ModelMutationKind,
+        name: Qualified<models::ModelName>,
+        procedure_name: commands::ProcedureName,
+    },
     ApolloFederation(ApolloFederationRootFields),
 }
 
+/// The kinds of the generated mutations of a model
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Display)]
+pub enum ModelMutationKind {
+    Insert,
+    UpdateByUniqueKey,
+    Update,
+    Delete,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Display)]
 pub enum ApolloFederationRootFields {
     Entities {
@@ -143,10 +171,12 @@ pub enum ApolloFederationRootFields {
     Service,
 }
 
-#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Display)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Display)]
 pub enum TypeKind {
     Scalar,
     Object,
+    /// A union or interface type, whose object type is selected by a discriminator field
+    Abstract(metadata_resolve::TypeDiscrimination),
 }
 
 /// Annotations of the GraphQL output fields/types.
@@ -177,12 +207,19 @@ pub enum OutputAnnotation {
     },
     RelationshipToModel(output_type::relationship::ModelRelationshipAnnotation),
     RelationshipToModelAggregate(output_type::relationship::ModelAggregateRelationshipAnnotation),
+    /// A Relay connection over an array relationship to a model
+    RelationshipToModelConnection(output_type::relationship::ModelRelationshipAnnotation),
     RelationshipToCommand(output_type::relationship::CommandRelationshipAnnotation),
     RelayNodeInterfaceID {
         typename_mappings: HashMap<ast::TypeName, Vec<types::FieldName>>,
     },
     SDL,
     Aggregate(crate::aggregates::AggregateOutputAnnotation),
+    Connection(crate::relay::ConnectionOutputAnnotation),
+    GroupBy(crate::query_root::select_group_by::GroupByOutputAnnotation),
+    ModelMutationResponse(
+        crate::mutation_root::model_mutations::ModelMutationResponseOutputAnnotation,
+    ),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Display)]
@@ -210,6 +247,14 @@ pub enum ModelInputAnnotation {
         deprecated: Option<Deprecated>,
     },
     ModelOrderByRelationshipArgument(OrderByRelationshipAnnotation),
+    /// An aggregation to order by, inside the aggregate ordering of an array relationship
+    ModelOrderByAggregationFunction(crate::aggregates::AggregationFunctionAnnotation),
+    /// A field of the aggregated type whose values are aggregated, inside the aggregate
+    /// ordering of an array relationship
+    ModelOrderByAggregatableField {
+        field_name: types::FieldName,
+        aggregate_operand_type: QualifiedTypeName,
+    },
 
     ModelOrderByDirection {
         direction: ModelOrderByDirection,
@@ -223,6 +268,55 @@ pub enum ModelInputAnnotation {
         ndc_column: Option<NdcColumnForComparison>,
     },
     ModelFilterInputArgument,
+    /// The number of rows to fetch after the `after` cursor
+    ModelFirstArgument,
+    /// The cursor after which rows are fetched
+    ModelAfterArgument,
+    /// The number of rows to fetch before the `before` cursor
+    ModelLastArgument,
+    /// The cursor before which rows are fetched
+    ModelBeforeArgument,
+    ModelStreamBatchSizeArgument,
+    ModelStreamCursorArgument,
+    ModelStreamCursorField {
+        field_name: types::FieldName,
+        /// The parent type is required to report field usage while analyzing query usage.
+        /// Field usage is reported with the name of object type where the field is defined.
+        parent_type: Qualified<types::CustomTypeName>,
+        cursor_field: metadata_resolve::StreamCursorField,
+        /// To mark a field as deprecated in the field usage while reporting query usage analytics.
+        deprecated: Option<Deprecated>,
+    },
+    /// The list of fields the selected rows must be distinct on
+    ModelDistinctOnArgument,
+    /// A field the selected rows can be distinct on
+    ModelDistinctOnField {
+        field_name: types::FieldName,
+        /// The parent type is required to report field usage while analyzing query usage.
+        /// Field usage is reported with the name of object type where the field is defined.
+        parent_type: Qualified<types::CustomTypeName>,
+        ndc_column: DataConnectorColumnName,
+        /// To mark a field as deprecated in the field usage while reporting query usage analytics.
+        deprecated: Option<Deprecated>,
+    },
+    /// The ordering of the groups of a group by root field
+    ModelGroupOrderByExpression,
+    /// A dimension to order the groups by
+    ModelGroupOrderByDimension {
+        field_name: types::FieldName,
+        /// The parent type is required to report field usage while analyzing query usage.
+        /// Field usage is reported with the name of object type where the field is defined.
+        parent_type: Qualified<types::CustomTypeName>,
+        dimension: metadata_resolve::GroupByDimension,
+        /// To mark a field as deprecated in the field usage while reporting query usage analytics.
+        deprecated: Option<Deprecated>,
+    },
+    /// The aggregations to order the groups by
+    ModelGroupOrderByAggregates,
+    /// The objects to insert with an insert mutation
+    ModelInsertObjectsArgument,
+    /// The new values of the fields of the objects updated by an update mutation
+    ModelSetArgument,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Display)]
@@ -236,6 +330,9 @@ pub enum BooleanExpressionAnnotation {
 
     /// Marks a field inside an scalar boolean expression
     ScalarBooleanExpressionField(ScalarBooleanExpressionField),
+
+    /// Marks a field inside an aggregate boolean expression
+    AggregateBooleanExpressionField(AggregateBooleanExpressionField),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -249,6 +346,8 @@ pub enum ObjectBooleanExpressionField {
         deprecated: Option<Deprecated>,
     },
     RelationshipField(FilterRelationshipAnnotation),
+    /// Compares the aggregations of the rows of an array relationship
+    RelationshipAggregateField(FilterRelationshipAnnotation),
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -267,6 +366,20 @@ pub enum ScalarBooleanExpressionField {
     IsNullOperation,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum AggregateBooleanExpressionField {
+    LogicalOperatorField(LogicalOperatorField),
+    /// Filters the rows before they are aggregated
+    FilterInputField,
+    /// An aggregation whose result is compared
+    AggregationFunctionField(crate::aggregates::AggregationFunctionAnnotation),
+    /// A field of the aggregated type whose values are aggregated
+    AggregatableField {
+        field_name: types::FieldName,
+        aggregate_operand_type: QualifiedTypeName,
+    },
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub enum LogicalOperatorField {
     AndOp,
@@ -309,6 +422,9 @@ pub enum InputAnnotation {
     FieldArgument {
         argument_name: ArgumentName, // OpenDd argument name
     },
+    EnumValue {
+        type_name: Qualified<types::CustomTypeName>,
+    },
 }
 
 /// Contains the different possible entities that can be used to generate
@@ -330,16 +446,21 @@ pub enum Annotation {
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Display)]
 pub enum NamespaceAnnotation {
-    /// any arguments that we should prefill for a command or type
-    Command(
-        BTreeMap<
+    /// Permissions for executing a command.
+    Command {
+        /// any arguments that we should prefill for a command
+        argument_presets: BTreeMap<
             ArgumentName,
             (
                 QualifiedTypeReference,
                 metadata_resolve::ValueExpressionOrPredicate,
             ),
         >,
-    ),
+        /// The filter that each object returned by the command must match. The objects are
+        /// selected again from the model of their object type with the filter applied, and a
+        /// permission error is raised while processing the response when one is not found.
+        output_filter: Option<metadata_resolve::CommandOutputFilter>,
+    },
     /// any filter and arguments for selecting from a model
     Model {
         filter: metadata_resolve::FilterPermission,
@@ -351,6 +472,19 @@ pub enum NamespaceAnnotation {
             ),
         >,
         allow_subscriptions: bool,
+        limit: Option<u32>,
+        allow_aggregations: bool,
+    },
+    /// Permissions of an object type field.
+    ObjectField {
+        /// Presets for the arguments of the field. The preset arguments are removed from
+        /// the schema, and their values are injected during IR generation.
+        argument_presets:
+            BTreeMap<ArgumentName, (QualifiedTypeReference, metadata_resolve::ValueExpression)>,
+        /// The condition that an object must match for the field to be accessible. The
+        /// fields used by the condition are fetched along with the field, and the field is
+        /// set to null while processing the response when the condition doesn't hold.
+        condition: Option<metadata_resolve::FieldCondition>,
     },
     /// Field presets for an input field.
     ///
@@ -396,6 +530,10 @@ pub enum TypeId {
         gds_type_name: Qualified<types::CustomTypeName>,
         graphql_type_name: ast::TypeName,
     },
+    AbstractType {
+        gds_type_name: Qualified<types::CustomTypeName>,
+        graphql_type_name: ast::TypeName,
+    },
     InputObjectType {
         gds_type_name: Qualified<types::CustomTypeName>,
         graphql_type_name: ast::TypeName,
@@ -411,11 +549,33 @@ pub enum TypeId {
         is_null_operator_name: Option<ast::Name>,
         logical_operators: LogicalOperators,
     },
+    /// Compares the aggregations of an object type, using an objectAggregate boolean expression
+    InputObjectAggregateBooleanExpressionType {
+        gds_type_name: Qualified<types::CustomTypeName>,
+        graphql_type_name: ast::TypeName,
+    },
+    /// Compares the aggregations of a scalar type, using a scalarAggregate boolean expression
+    InputScalarAggregateBooleanExpressionType {
+        gds_type_name: Qualified<types::CustomTypeName>,
+        graphql_type_name: ast::TypeName,
+    },
+    /// Compares the aggregations of the rows of an array relationship, after filtering them
+    RelationshipAggregatePredicateType {
+        gds_type_name: Qualified<types::CustomTypeName>,
+        graphql_type_name: ast::TypeName,
+    },
     NodeRoot,
     ModelArgumentsInput {
         model_name: Qualified<models::ModelName>,
         type_name: ast::TypeName,
     },
+    /// The `args` input object of a relationship to a model, which leaves out
+    /// the model arguments that are mapped by the relationship
+    ModelRelationshipArgumentsInput {
+        model_name: Qualified<models::ModelName>,
+        mapped_arguments: BTreeSet<ArgumentName>,
+        type_name: ast::TypeName,
+    },
     OrderByExpression {
         order_by_expression_identifier: Qualified<OrderByExpressionIdentifier>,
         graphql_type_name: ast::TypeName,
@@ -423,6 +583,12 @@ pub enum TypeId {
     OrderByEnumType {
         graphql_type_name: ast::TypeName,
     },
+    /// Orders by the aggregations of an aggregate expression, which is used to
+    /// order by an array relationship
+    OrderByAggregateExpression {
+        aggregate_expression_name: Qualified<aggregates::AggregateExpressionName>,
+        graphql_type_name: ast::TypeName,
+    },
     ApolloFederationType(PossibleApolloFederationTypes),
     AggregateSelectOutputType {
         aggregate_expression_name: Qualified<aggregates::AggregateExpressionName>,
@@ -432,6 +598,52 @@ pub enum TypeId {
         model_name: Qualified<models::ModelName>,
         graphql_type_name: ast::TypeName,
     },
+    ModelStreamCursorInputType {
+        model_name: Qualified<models::ModelName>,
+        graphql_type_name: ast::TypeName,
+    },
+    /// The enum of the fields the rows of a model can be distinct on
+    ModelDistinctOnEnumType {
+        model_name: Qualified<models::ModelName>,
+        graphql_type_name: ast::TypeName,
+    },
+    /// The Relay connection type of a model with a select connection
+    ModelConnectionType {
+        model_name: Qualified<models::ModelName>,
+        graphql_type_name: ast::TypeName,
+    },
+    /// The Relay edge type of a model with a select connection
+    ModelEdgeType {
+        model_name: Qualified<models::ModelName>,
+        graphql_type_name: ast::TypeName,
+    },
+    /// The Relay `PageInfo` type shared by all connections
+    PageInfoType,
+    /// The type of a group of the rows of a model with a group by root field
+    ModelGroupType {
+        model_name: Qualified<models::ModelName>,
+        graphql_type_name: ast::TypeName,
+    },
+    /// The type of the dimensions of a group of the rows of a model
+    ModelGroupDimensionsType {
+        model_name: Qualified<models::ModelName>,
+        graphql_type_name: ast::TypeName,
+    },
+    /// The input type to order the groups of the rows of a model
+    ModelGroupOrderByInputType {
+        model_name: Qualified<models::ModelName>,
+        graphql_type_name: ast::TypeName,
+    },
+    /// The response type of the mutations of a model
+    ModelMutationResponseType {
+        model_name: Qualified<models::ModelName>,
+        graphql_type_name: ast::TypeName,
+    },
+    /// The input type of the new values of the fields of a model, for its update mutations
+    ModelSetInputType {
+        model_name: Qualified<models::ModelName>,
+        graphql_type_name: ast::TypeName,
+    },
 }
 
 #[derive(Serialize, Clone, Debug, Hash, PartialEq, Eq)]
@@ -460,6 +672,9 @@ impl TypeId {
             | TypeId::ScalarType {
                 graphql_type_name, ..
             }
+            | TypeId::AbstractType {
+                graphql_type_name, ..
+            }
             | TypeId::InputObjectType {
                 graphql_type_name, ..
             }
@@ -469,20 +684,61 @@ impl TypeId {
             | TypeId::InputScalarBooleanExpressionType {
                 graphql_type_name, ..
             }
+            | TypeId::InputObjectAggregateBooleanExpressionType {
+                graphql_type_name, ..
+            }
+            | TypeId::InputScalarAggregateBooleanExpressionType {
+                graphql_type_name, ..
+            }
+            | TypeId::RelationshipAggregatePredicateType {
+                graphql_type_name, ..
+            }
             | TypeId::OrderByExpression {
                 graphql_type_name, ..
             }
             | TypeId::OrderByEnumType {
                 graphql_type_name, ..
             }
+            | TypeId::OrderByAggregateExpression {
+                graphql_type_name, ..
+            }
             | TypeId::AggregateSelectOutputType {
                 graphql_type_name, ..
             }
             | TypeId::ModelFilterInputType {
                 graphql_type_name, ..
+            }
+            | TypeId::ModelStreamCursorInputType {
+                graphql_type_name, ..
+            }
+            | TypeId::ModelDistinctOnEnumType {
+                graphql_type_name, ..
+            }
+            | TypeId::ModelConnectionType {
+                graphql_type_name, ..
+            }
+            | TypeId::ModelEdgeType {
+                graphql_type_name, ..
+            }
+            | TypeId::ModelGroupType {
+                graphql_type_name, ..
+            }
+            | TypeId::ModelGroupDimensionsType {
+                graphql_type_name, ..
+            }
+            | TypeId::ModelGroupOrderByInputType {
+                graphql_type_name, ..
+            }
+            | TypeId::ModelMutationResponseType {
+                graphql_type_name, ..
+            }
+            | TypeId::ModelSetInputType {
+                graphql_type_name, ..
             } => graphql_type_name.clone(),
             TypeId::NodeRoot => ast::TypeName(mk_name!("Node")),
-            TypeId::ModelArgumentsInput { type_name, .. } => type_name.clone(),
+            TypeId::PageInfoType => ast::TypeName(mk_name!("PageInfo")),
+            TypeId::ModelArgumentsInput { type_name, .. }
+            | TypeId::ModelRelationshipArgumentsInput { type_name, .. } => type_name.clone(),
             TypeId::ApolloFederationType(PossibleApolloFederationTypes::Entity) => {
                 ast::TypeName(mk_name!("_Entity"))
             }
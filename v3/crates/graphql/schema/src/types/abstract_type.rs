@@ -0,0 +1,127 @@
+//! Schema of union and interface types, whose values are objects of one of their member object types
+
+use lang_graphql::ast::common as ast;
+use lang_graphql::schema::{self as gql_schema, RegisteredTypeName};
+use open_dds::types::CustomTypeName;
+use std::collections::BTreeMap;
+
+use super::output_type::{
+    get_custom_output_type, get_object_type_representation, get_output_type, get_type_kind,
+};
+use super::{Annotation, OutputAnnotation};
+use crate::{mk_deprecation_status, permissions, Error, GDS};
+use metadata_resolve::{mk_name, AbstractTypeKind, Qualified};
+
+pub fn abstract_type_schema(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    type_name: &Qualified<CustomTypeName>,
+    graphql_type_name: &ast::TypeName,
+) -> Result<gql_schema::TypeInfo<GDS>, Error> {
+    let abstract_type =
+        gds.metadata
+            .abstract_types
+            .get(type_name)
+            .ok_or_else(|| Error::InternalTypeNotFound {
+                type_name: type_name.clone(),
+            })?;
+
+    let mut members = BTreeMap::new();
+    let mut member_representations = Vec::new();
+    for member in &abstract_type.discrimination.members {
+        let object_type_representation = get_object_type_representation(gds, &member.object_type)?;
+        let member_type_name = get_custom_output_type(gds, builder, &member.object_type)?;
+        members.insert(
+            member_type_name,
+            builder.conditional_namespaced(
+                (),
+                permissions::get_abstract_type_member_permissions(object_type_representation),
+            ),
+        );
+        member_representations.push(object_type_representation);
+    }
+
+    match &abstract_type.kind {
+        AbstractTypeKind::Union => Ok(gql_schema::TypeInfo::Union(gql_schema::Union::new(
+            builder,
+            graphql_type_name.clone(),
+            abstract_type.description.clone(),
+            members,
+            Vec::new(),
+        ))),
+        AbstractTypeKind::Interface { fields } => {
+            let mut interface_fields = BTreeMap::new();
+            for (field_name, field_definition) in fields {
+                let graphql_field_name = mk_name(field_name.as_str())
+                    .map_err(metadata_resolve::Error::from)
+                    .map_err(metadata_resolve::WithContext::from)?;
+                let field = gql_schema::Field::<GDS>::new(
+                    graphql_field_name.clone(),
+                    field_definition.description.clone(),
+                    Annotation::Output(OutputAnnotation::Field {
+                        name: field_name.clone(),
+                        field_type: field_definition.field_type.clone(),
+                        field_base_type_kind: get_type_kind(gds, &field_definition.field_type)?,
+                        parent_type: type_name.clone(),
+                        argument_types: BTreeMap::new(),
+                        deprecated: field_definition.deprecated.clone(),
+                    }),
+                    get_output_type(gds, builder, &field_definition.field_type)?,
+                    BTreeMap::new(),
+                    mk_deprecation_status(field_definition.deprecated.as_ref()),
+                );
+                // an interface field is only exposed for a role if every
+                // implementation exposes it unconditionally
+                interface_fields.insert(
+                    graphql_field_name,
+                    builder.conditional_namespaced(
+                        field,
+                        permissions::get_interface_field_permissions(
+                            &member_representations,
+                            field_name,
+                        ),
+                    ),
+                );
+            }
+            Ok(gql_schema::TypeInfo::Interface(gql_schema::Interface::new(
+                builder,
+                graphql_type_name.clone(),
+                abstract_type.description.clone(),
+                interface_fields,
+                BTreeMap::new(),
+                members,
+                Vec::new(),
+            )))
+        }
+    }
+}
+
+/// The interface types implemented by an object type, to be listed in the
+/// `implements` clause of the object type.
+pub(crate) fn implemented_interfaces(
+    gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
+    type_name: &Qualified<CustomTypeName>,
+    object_type_representation: &metadata_resolve::ObjectTypeWithRelationships,
+) -> Result<BTreeMap<RegisteredTypeName, gql_schema::Namespaced<GDS, ()>>, Error> {
+    let mut interfaces = BTreeMap::new();
+    for (abstract_type_name, abstract_type) in &gds.metadata.abstract_types {
+        let is_implemented = matches!(abstract_type.kind, AbstractTypeKind::Interface { .. })
+            && abstract_type.graphql_type_name.is_some()
+            && abstract_type
+                .discrimination
+                .members
+                .iter()
+                .any(|member| &member.object_type == type_name);
+        if is_implemented {
+            interfaces.insert(
+                get_custom_output_type(gds, builder, abstract_type_name)?,
+                builder.conditional_namespaced(
+                    (),
+                    permissions::get_abstract_type_member_permissions(object_type_representation),
+                ),
+            );
+        }
+    }
+    Ok(interfaces)
+}
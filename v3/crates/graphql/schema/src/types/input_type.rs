@@ -123,11 +123,15 @@ fn get_custom_input_type(
     .map(|type_id| builder.register_type(type_id))
 }
 
-fn input_object_type_input_fields(
+/// The input fields of an object type. The fields preset by the input permissions of a role
+/// are left out for that role. When `all_fields_nullable` is set, every field can be left
+/// out, like in the new values of the fields of an update.
+pub(crate) fn input_object_type_input_fields(
     gds: &GDS,
     builder: &mut gql_schema::Builder<GDS>,
     type_name: &Qualified<CustomTypeName>,
     object_type_representation: &metadata_resolve::ObjectTypeWithRelationships,
+    all_fields_nullable: bool,
 ) -> Result<BTreeMap<ast::Name, gql_schema::Namespaced<GDS, gql_schema::InputField<GDS>>>, Error> {
     object_type_representation
         .object_type
@@ -147,7 +151,14 @@ fn input_object_type_input_fields(
                     parent_type: type_name.to_owned(),
                     deprecated: field_definition.deprecated.clone(),
                 }),
-                get_input_type(gds, builder, &field_definition.field_type)?,
+                get_input_type(
+                    gds,
+                    builder,
+                    &QualifiedTypeReference {
+                        nullable: all_fields_nullable || field_definition.field_type.nullable,
+                        ..field_definition.field_type.clone()
+                    },
+                )?,
                 None, // Default value
                 gql_schema::DeprecationStatus::NotDeprecated,
             );
@@ -246,7 +257,7 @@ pub fn input_object_type_schema(
     let graphql_type_name = graphql_type_name.clone();
 
     let input_fields =
-        input_object_type_input_fields(gds, builder, type_name, object_type_representation)?;
+        input_object_type_input_fields(gds, builder, type_name, object_type_representation, false)?;
 
     Ok(gql_schema::TypeInfo::InputObject(
         gql_schema::InputObject::new(
@@ -1,13 +1,14 @@
 use lang_graphql::ast::common::{self as ast, TypeContainer};
 use lang_graphql::mk_name;
 use lang_graphql::schema::{self as gql_schema, Directive, RegisteredType};
+use open_dds::arguments::ArgumentName;
 use open_dds::commands::DataConnectorCommand;
 use open_dds::{
     relationships,
     types::{CustomTypeName, FieldName, InbuiltType},
 };
 use relationship::ModelAggregateRelationshipAnnotation;
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 use self::relationship::{
     CommandRelationshipAnnotation, CommandTargetSource, ModelRelationshipAnnotation,
@@ -16,9 +17,12 @@ use super::inbuilt_type::base_type_container_for_inbuilt_type;
 use super::{Annotation, PossibleApolloFederationTypes, TypeId};
 use crate::commands::generate_command_argument;
 use crate::field_arguments::generate_field_argument;
-use crate::query_root::select_aggregate;
-use crate::query_root::select_many::generate_select_many_arguments;
-use crate::{aggregates, mk_deprecation_status, permissions};
+use crate::query_root::select_many::{
+    add_cursor_pagination_arguments, generate_select_many_arguments,
+};
+use crate::{
+    aggregates, mk_deprecation_status, model_arguments, model_filter_input, permissions, relay,
+};
 use crate::{Role, GDS};
 use metadata_resolve::{self, mk_name};
 use metadata_resolve::{get_type_representation, TypeRepresentation};
@@ -111,6 +115,18 @@ pub fn get_custom_output_type(
     builder: &mut gql_schema::Builder<GDS>,
     gds_type: &Qualified<CustomTypeName>,
 ) -> Result<gql_schema::RegisteredTypeName, Error> {
+    if let Some(abstract_type) = gds.metadata.abstract_types.get(gds_type) {
+        return Ok(builder.register_type(super::TypeId::AbstractType {
+            gds_type_name: gds_type.clone(),
+            graphql_type_name: abstract_type
+                .graphql_type_name
+                .as_ref()
+                .ok_or_else(|| Error::NoGraphQlTypeNameForAbstractType {
+                    type_name: gds_type.clone(),
+                })?
+                .clone(),
+        }));
+    }
     match get_type_representation(
         gds_type,
         &gds.metadata.object_types,
@@ -160,6 +176,11 @@ pub(crate) fn get_type_kind(
         QualifiedBaseType::Named(qualified_type_name) => match qualified_type_name {
             QualifiedTypeName::Inbuilt(_) => Ok(super::TypeKind::Scalar), // Inbuilt types are all scalars
             QualifiedTypeName::Custom(type_name) => {
+                if let Some(abstract_type) = gds.metadata.abstract_types.get(type_name) {
+                    return Ok(super::TypeKind::Abstract(
+                        abstract_type.discrimination.clone(),
+                    ));
+                }
                 match get_type_representation(
                     type_name,
                     &gds.metadata.object_types,
@@ -201,7 +222,14 @@ fn object_type_fields(
                 .field_arguments
                 .iter()
                 .map(|(argument_name, argument_type)| {
-                    generate_field_argument(gds, builder, argument_name, argument_type)
+                    generate_field_argument(
+                        gds,
+                        builder,
+                        object_type_representation,
+                        field_name,
+                        argument_name,
+                        argument_type,
+                    )
                 })
                 .collect::<Result<BTreeMap<_, _>, _>>()?;
             let field_argument_types =
@@ -237,7 +265,9 @@ fn object_type_fields(
                 let mut role_map = HashMap::new();
                 for (role, perms) in &object_type_representation.type_output_permissions {
                     if perms.allowed_fields.contains(field_name) {
-                        role_map.insert(Role(role.0.clone()), None);
+                        let annotation =
+                            object_field_annotation(perms, field_name, field_definition);
+                        role_map.insert(Role(role.0.clone()), annotation);
                     }
                 }
                 builder.conditional_namespaced(field, role_map)
@@ -258,6 +288,37 @@ fn object_type_fields(
     Ok(graphql_fields)
 }
 
+/// Annotate the presets of a field's arguments for a role, along with the types of the
+/// arguments, and the condition under which the field is accessible
+fn object_field_annotation(
+    type_output_permission: &metadata_resolve::TypeOutputPermission,
+    field_name: &FieldName,
+    field_definition: &metadata_resolve::FieldDefinition,
+) -> Option<super::NamespaceAnnotation> {
+    let argument_presets = type_output_permission
+        .field_argument_presets
+        .get(field_name);
+    let condition = type_output_permission.field_conditions.get(field_name);
+    if argument_presets.is_none() && condition.is_none() {
+        return None;
+    }
+    Some(super::NamespaceAnnotation::ObjectField {
+        argument_presets: argument_presets
+            .into_iter()
+            .flatten()
+            .filter_map(|(argument_name, value)| {
+                // the presets are validated against the field arguments during resolution
+                let argument_info = field_definition.field_arguments.get(argument_name)?;
+                Some((
+                    argument_name.clone(),
+                    (argument_info.argument_type.clone(), value.clone()),
+                ))
+            })
+            .collect(),
+        condition: condition.cloned(),
+    })
+}
+
 /// Add the relationship fields to the `graphql_fields` map
 fn add_relationship_fields(
     graphql_fields: &mut BTreeMap<ast::Name, gql_schema::Namespaced<GDS, gql_schema::Field<GDS>>>,
@@ -284,6 +345,7 @@ fn add_relationship_fields(
                 let ModelRelationshipFields {
                     field,
                     aggregate_field,
+                    connection_field,
                 } = model_relationship_fields(
                     model_relationship_target,
                     builder,
@@ -296,6 +358,9 @@ fn add_relationship_fields(
                 if let Some(aggregate_field) = aggregate_field {
                     relationship_fields.push(aggregate_field);
                 }
+                if let Some(connection_field) = connection_field {
+                    relationship_fields.push(connection_field);
+                }
             }
         }
         for relationship_field in relationship_fields {
@@ -338,6 +403,12 @@ fn command_relationship_field(
         .mappings
         .iter()
         .map(|mapping| &mapping.argument_name)
+        .chain(
+            command_relationship_target
+                .value_mappings
+                .iter()
+                .map(|value_mapping| &value_mapping.argument_name),
+        )
         .collect::<HashSet<_>>();
     let arguments = command
         .command
@@ -365,6 +436,7 @@ fn command_relationship_field(
                         &command_relationship_target.target_type,
                     )?,
                     mappings: command_relationship_target.mappings.clone(),
+                    value_mappings: command_relationship_target.value_mappings.clone(),
                     deprecated: relationship.deprecated.clone(),
                 },
             )),
@@ -376,6 +448,7 @@ fn command_relationship_field(
             command,
             object_type_representation,
             &command_relationship_target.mappings,
+            &gds.metadata.object_types,
         ),
     );
     Ok(field)
@@ -386,6 +459,8 @@ struct ModelRelationshipFields {
     field: gql_schema::Namespaced<GDS, gql_schema::Field<GDS>>,
     // aggregate relationship field applicable only for an array relationship
     aggregate_field: Option<gql_schema::Namespaced<GDS, gql_schema::Field<GDS>>>,
+    // connection relationship field applicable only for an array relationship
+    connection_field: Option<gql_schema::Namespaced<GDS, gql_schema::Field<GDS>>>,
 }
 
 /// Create a model relationship field
@@ -417,46 +492,101 @@ fn model_relationship_fields(
         .ok_or_else(|| Error::InternalModelNotFound {
             model_name: model_relationship_target.model_name.clone(),
         })?;
-    if !model.model.arguments.is_empty() {
-        return Err(Error::InternalUnsupported {
-            summary: "Relationships to models with arguments aren't supported".into(),
-        });
-    }
 
-    let arguments = match model_relationship_target.relationship_type {
+    let mut arguments = match model_relationship_target.relationship_type {
         relationships::RelationshipType::Array => generate_select_many_arguments(builder, model)?,
         relationships::RelationshipType::Object => BTreeMap::new(),
     };
 
+    // Model arguments that are not mapped by the relationship are provided by the user
+    model_arguments::add_model_relationship_arguments_field(
+        &mut arguments,
+        builder,
+        model,
+        mapped_model_arguments(model_relationship_target),
+        &relationship.field_name,
+        parent_graphql_type_name,
+    )?;
+
     let target_object_type_representation =
         get_object_type_representation(gds, &model.model.data_type)?;
 
+    let relationship_annotation = ModelRelationshipAnnotation {
+        source_type: relationship.source.clone(),
+        relationship_name: relationship.relationship_name.clone(),
+        model_name: model_relationship_target.model_name.clone(),
+        target_capabilities: relationship.target_capabilities.clone(),
+        target_type: model_relationship_target.target_typename.clone(),
+        relationship_type: model_relationship_target.relationship_type.clone(),
+        mappings: model_relationship_target.mappings.clone(),
+        value_mappings: model_relationship_target.value_mappings.clone(),
+        argument_mappings: model_relationship_target.argument_mappings.clone(),
+        deprecated: relationship.deprecated.clone(),
+    };
+    let relationship_permissions = permissions::get_model_relationship_namespace_annotations(
+        model,
+        object_type_representation,
+        target_object_type_representation,
+        &model_relationship_target.mappings,
+        &model_relationship_target.value_mappings,
+        &model_relationship_target.argument_mappings,
+        &gds.metadata.object_types,
+    );
+
+    let connection_field = match (
+        &model_relationship_target.connection_field_name,
+        &model.graphql_api.select_connection,
+    ) {
+        (Some(connection_field_name), Some(select_connection)) => {
+            let mut arguments = BTreeMap::new();
+            model_filter_input::add_order_by_input_field(&mut arguments, builder, model);
+            model_filter_input::add_where_input_field(&mut arguments, builder, model);
+            model_arguments::add_model_relationship_arguments_field(
+                &mut arguments,
+                builder,
+                model,
+                mapped_model_arguments(model_relationship_target),
+                connection_field_name,
+                parent_graphql_type_name,
+            )?;
+            add_cursor_pagination_arguments(
+                &mut arguments,
+                builder,
+                &select_connection.cursor_pagination,
+            );
+            Some(builder.conditional_namespaced(
+                gql_schema::Field::<GDS>::new(
+                    connection_field_name.clone(),
+                    relationship.description.clone(),
+                    Annotation::Output(super::OutputAnnotation::RelationshipToModelConnection(
+                        relationship_annotation.clone(),
+                    )),
+                    ast::TypeContainer::named_non_null(relay::get_model_connection_type(
+                        builder,
+                        &model.model.name,
+                        select_connection,
+                    )),
+                    arguments,
+                    mk_deprecation_status(relationship.deprecated.as_ref()),
+                ),
+                relationship_permissions.clone(),
+            ))
+        }
+        _ => None,
+    };
+
     let field = builder.conditional_namespaced(
         gql_schema::Field::<GDS>::new(
             relationship.field_name.clone(),
             relationship.description.clone(),
             Annotation::Output(super::OutputAnnotation::RelationshipToModel(
-                ModelRelationshipAnnotation {
-                    source_type: relationship.source.clone(),
-                    relationship_name: relationship.relationship_name.clone(),
-                    model_name: model_relationship_target.model_name.clone(),
-                    target_capabilities: relationship.target_capabilities.clone(),
-                    target_type: model_relationship_target.target_typename.clone(),
-                    relationship_type: model_relationship_target.relationship_type.clone(),
-                    mappings: model_relationship_target.mappings.clone(),
-                    deprecated: relationship.deprecated.clone(),
-                },
+                relationship_annotation,
             )),
             relationship_output_type,
             arguments,
             mk_deprecation_status(relationship.deprecated.as_ref()),
         ),
-        permissions::get_model_relationship_namespace_annotations(
-            model,
-            object_type_representation,
-            target_object_type_representation,
-            &model_relationship_target.mappings,
-        ),
+        relationship_permissions,
     );
     let aggregate_field = model_relationship_target
         .relationship_aggregate
@@ -470,6 +600,8 @@ fn model_relationship_fields(
                 &aggregate.field_name,
                 &model_relationship_target.target_typename,
                 &model_relationship_target.mappings,
+                &model_relationship_target.value_mappings,
+                &model_relationship_target.argument_mappings,
                 relationship,
                 object_type_representation,
                 parent_graphql_type_name,
@@ -479,9 +611,21 @@ fn model_relationship_fields(
     Ok(ModelRelationshipFields {
         field,
         aggregate_field,
+        connection_field,
     })
 }
 
+/// The arguments of the target model that are mapped by a relationship
+fn mapped_model_arguments(
+    model_relationship_target: &metadata_resolve::ModelRelationshipTarget,
+) -> BTreeSet<ArgumentName> {
+    model_relationship_target
+        .argument_mappings
+        .iter()
+        .map(|argument_mapping| argument_mapping.argument_name.clone())
+        .collect()
+}
+
 /// Create a model relationship field
 fn model_aggregate_relationship_field(
     aggregate_relationship: &metadata_resolve::AggregateRelationship,
@@ -491,6 +635,8 @@ fn model_aggregate_relationship_field(
     aggregate_field_name: &ast::Name,
     target_typename: &Qualified<CustomTypeName>,
     mappings: &[metadata_resolve::RelationshipModelMapping],
+    value_mappings: &[metadata_resolve::RelationshipModelValueMapping],
+    argument_mappings: &[metadata_resolve::RelationshipModelArgumentMapping],
     relationship: &metadata_resolve::RelationshipField,
     object_type_representation: &metadata_resolve::ObjectTypeWithRelationships,
     parent_graphql_type_name: &ast::TypeName,
@@ -507,13 +653,24 @@ fn model_aggregate_relationship_field(
     let aggregate_select_output_type =
         aggregates::get_aggregate_select_output_type(builder, aggregate_expression)?;
 
-    let arguments = select_aggregate::generate_select_aggregate_arguments(
+    let mut arguments = BTreeMap::new();
+    model_arguments::add_model_relationship_arguments_field(
+        &mut arguments,
         builder,
         target_model,
-        &aggregate_relationship.filter_input_field_name,
+        argument_mappings
+            .iter()
+            .map(|argument_mapping| argument_mapping.argument_name.clone())
+            .collect(),
         aggregate_field_name,
         parent_graphql_type_name,
     )?;
+    model_filter_input::add_filter_input_argument_field(
+        &mut arguments,
+        &aggregate_relationship.filter_input_field_name,
+        builder,
+        target_model,
+    )?;
 
     let target_object_type_representation =
         get_object_type_representation(gds, &target_model.model.data_type)?;
@@ -530,6 +687,8 @@ fn model_aggregate_relationship_field(
                     target_capabilities: relationship.target_capabilities.clone(),
                     target_type: target_typename.clone(),
                     mappings: mappings.to_vec(),
+                    value_mappings: value_mappings.to_vec(),
+                    argument_mappings: argument_mappings.to_vec(),
                     deprecated: relationship.deprecated.clone(),
                 },
             )),
@@ -537,11 +696,16 @@ fn model_aggregate_relationship_field(
             arguments,
             mk_deprecation_status(relationship.deprecated.as_ref()),
         ),
-        permissions::get_model_relationship_namespace_annotations(
-            target_model,
-            object_type_representation,
-            target_object_type_representation,
-            mappings,
+        permissions::apply_aggregation_permissions_model(
+            permissions::get_model_relationship_namespace_annotations(
+                target_model,
+                object_type_representation,
+                target_object_type_representation,
+                mappings,
+                value_mappings,
+                argument_mappings,
+                &gds.metadata.object_types,
+            ),
         ),
     );
     Ok(field)
@@ -606,6 +770,12 @@ pub fn output_type_schema(
         }
         None => Vec::new(),
     };
+    let mut interfaces = super::abstract_type::implemented_interfaces(
+        gds,
+        builder,
+        type_name,
+        object_type_representation,
+    )?;
     if object_type_representation
         .object_type
         .global_id_fields
@@ -616,13 +786,12 @@ pub fn output_type_schema(
             graphql_type_name,
             object_type_representation.object_type.description.clone(),
             object_type_fields,
-            BTreeMap::new(),
+            interfaces,
             directives,
         )))
     } else {
         // Generate the Global object `id` field and insert it
         // into the `object_type_fields`.
-        let mut interfaces = BTreeMap::new();
         let global_id_field_name = lang_graphql::mk_name!("id");
         let global_id_field = gql_schema::Field::<GDS>::new(
             global_id_field_name.clone(),
@@ -20,6 +20,8 @@ pub struct ModelRelationshipAnnotation {
     pub target_type: Qualified<CustomTypeName>,
     pub relationship_type: RelationshipType,
     pub mappings: Vec<metadata_resolve::RelationshipModelMapping>,
+    pub value_mappings: Vec<metadata_resolve::RelationshipModelValueMapping>,
+    pub argument_mappings: Vec<metadata_resolve::RelationshipModelArgumentMapping>,
     pub deprecated: Option<Deprecated>,
 }
 
@@ -31,6 +33,8 @@ pub struct ModelAggregateRelationshipAnnotation {
     pub target_capabilities: Option<RelationshipCapabilities>,
     pub target_type: Qualified<CustomTypeName>,
     pub mappings: Vec<metadata_resolve::RelationshipModelMapping>,
+    pub value_mappings: Vec<metadata_resolve::RelationshipModelValueMapping>,
+    pub argument_mappings: Vec<metadata_resolve::RelationshipModelArgumentMapping>,
     pub deprecated: Option<Deprecated>,
 }
 
@@ -69,6 +73,7 @@ pub struct CommandRelationshipAnnotation {
     pub target_type: QualifiedTypeReference,
     pub target_base_type_kind: TypeKind,
     pub mappings: Vec<metadata_resolve::RelationshipCommandMapping>,
+    pub value_mappings: Vec<metadata_resolve::RelationshipCommandValueMapping>,
     pub deprecated: Option<Deprecated>,
 }
 
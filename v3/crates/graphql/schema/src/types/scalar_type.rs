@@ -1,13 +1,16 @@
+use crate::types;
 use crate::GDS;
 use lang_graphql::ast::common as ast;
 use lang_graphql::schema as gql_schema;
-use metadata_resolve::Qualified;
+use metadata_resolve::{mk_name, Qualified};
 use open_dds::types::CustomTypeName;
+use std::collections::BTreeMap;
 
-use crate::Error;
+use crate::{mk_deprecation_status, Error};
 
 pub fn scalar_type_schema(
     gds: &GDS,
+    builder: &mut gql_schema::Builder<GDS>,
     type_name: &Qualified<CustomTypeName>,
     graphql_type_name: &ast::TypeName,
 ) -> Result<gql_schema::TypeInfo<GDS>, Error> {
@@ -21,6 +24,33 @@ pub fn scalar_type_schema(
 
     let graphql_type_name = graphql_type_name.clone();
 
+    // enum types are scalar types with a fixed set of values
+    if let Some(enum_values) = &scalar_type_representation.enum_values {
+        let mut values = BTreeMap::new();
+        for enum_value in enum_values {
+            let value_name = mk_name(&enum_value.value)
+                .map_err(metadata_resolve::Error::from)
+                .map_err(metadata_resolve::WithContext::from)?;
+            values.insert(
+                value_name.clone(),
+                builder.allow_all_namespaced(gql_schema::EnumValue {
+                    value: value_name,
+                    description: enum_value.description.clone(),
+                    deprecation_status: mk_deprecation_status(enum_value.deprecated.as_ref()),
+                    info: types::Annotation::Input(types::InputAnnotation::EnumValue {
+                        type_name: type_name.clone(),
+                    }),
+                }),
+            );
+        }
+        return Ok(gql_schema::TypeInfo::Enum(gql_schema::Enum {
+            name: graphql_type_name,
+            description: scalar_type_representation.description.clone(),
+            values,
+            directives: Vec::new(),
+        }));
+    }
+
     Ok(gql_schema::TypeInfo::Scalar(gql_schema::Scalar {
         name: graphql_type_name,
         description: scalar_type_representation.description.clone(),
@@ -13,9 +13,10 @@ pub fn build_model(
     object_types: &BTreeMap<Qualified<CustomTypeName>, ObjectTypeWithRelationships>,
 ) -> Result<Model, ModelWarning> {
     // if we have no select permission for the model, ignore it
-    if !model.select_permissions.contains_key(role) {
-        return Err(ModelWarning::NoSelectPermission);
-    }
+    let select_permission = model
+        .select_permissions
+        .get(role)
+        .ok_or(ModelWarning::NoSelectPermission)?;
     object_types
         .get(&model.model.data_type)
         .ok_or_else(|| ModelWarning::NoObjectTypeFound {
@@ -36,5 +37,6 @@ pub fn build_model(
         data_type: model.model.data_type.clone(),
         data_connector_name,
         filter_expression_type: model.filter_expression_type.clone(),
+        limit: select_permission.limit,
     })
 }
@@ -32,9 +32,9 @@ pub fn build_object_type(
             .fields
             .iter()
             .filter(|(field_name, _field_info)| {
-                output_permissions_for_role
-                    .allowed_fields
-                    .contains(*field_name)
+                // conditions of conditionally allowed fields are not evaluated by JSON:API,
+                // so those fields are left out
+                output_permissions_for_role.is_unconditionally_allowed(field_name)
             })
     {
         let field_type =
@@ -117,6 +117,17 @@ fn type_from_type_representation(
             })),
             QualifiedTypeName::Custom(custom_type_name) => {
                 match scalar_types.get(custom_type_name) {
+                    // enum types are represented by their values, whichever data connector
+                    // they come from
+                    Some(ScalarTypeRepresentation {
+                        enum_values: Some(enum_values),
+                        ..
+                    }) => Ok(Type::Scalar(ndc_models::TypeRepresentation::Enum {
+                        one_of: enum_values
+                            .iter()
+                            .map(|enum_value| enum_value.value.clone())
+                            .collect(),
+                    })),
                     Some(scalar_type) => {
                         Ok(Type::ScalarForDataConnector(ScalarTypeForDataConnector {
                             type_representations: scalar_type
@@ -163,4 +163,6 @@ pub struct Model {
     pub data_type: Qualified<CustomTypeName>,
     pub data_connector_name: Qualified<DataConnectorName>,
     pub filter_expression_type: Option<ResolvedObjectBooleanExpressionType>,
+    // the maximum number of rows the role can fetch, if any
+    pub limit: Option<u32>,
 }
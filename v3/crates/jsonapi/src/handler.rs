@@ -116,6 +116,7 @@ async fn query_engine_execute(
                     execution_tree: execution_tree.clone(),
                     field_span_attribute: "REST".into(),
                     process_response_as: ProcessResponseAs::Array { is_nullable: false },
+                    cursor_pagination: None,
                 };
                 Ok(
                     execute::resolve_ndc_query_execution(http_context, ndc_query_execution, None)
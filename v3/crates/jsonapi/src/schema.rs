@@ -61,7 +61,7 @@ fn get_route_for_model(
     >,
 ) -> oas3::spec::Operation {
     let mut parameters = vec![
-        oas3::spec::ObjectOrReference::Object(parameters::page_limit_parameter()),
+        oas3::spec::ObjectOrReference::Object(parameters::page_limit_parameter(model)),
         oas3::spec::ObjectOrReference::Object(parameters::page_offset_parameter()),
         oas3::spec::ObjectOrReference::Object(parameters::ordering_parameter(model, object_type)),
         oas3::spec::ObjectOrReference::Object(parameters::include_parameter(model, object_type)),
@@ -27,8 +27,12 @@ pub fn page_offset_parameter() -> oas3::spec::Parameter {
     }
 }
 
-pub fn page_limit_parameter() -> oas3::spec::Parameter {
-    let schema = oas3::spec::ObjectOrReference::Object(int_schema());
+pub fn page_limit_parameter(model: &Model) -> oas3::spec::Parameter {
+    // the limit of the role's select permission caps the requested limit
+    let schema = oas3::spec::ObjectOrReference::Object(oas3::spec::ObjectSchema {
+        maximum: model.limit.map(serde_json::Number::from),
+        ..int_schema()
+    });
     oas3::spec::Parameter {
         name: "page[limit]".into(),
         allow_empty_value: None,
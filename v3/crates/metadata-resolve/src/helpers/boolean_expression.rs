@@ -268,6 +268,60 @@ fn validate_data_connector_with_comparable_relationship(
                 },
             )?;
 
+        // Value mappings are applied as constant filters on the target model, which can't be
+        // expressed in a relationship predicate
+        if !relationship_target_model.value_mappings.is_empty() {
+            return Err(
+                TypePredicateError::RelationshipWithValueMappingsInTypePredicate {
+                    relationship_name: comparable_relationship.relationship_name.clone(),
+                    type_name: object_boolean_expression_type.object_type.clone(),
+                }
+                .into(),
+            );
+        }
+
+        // Argument mappings take their values from each row of the source, which can't be
+        // expressed in a relationship predicate
+        if !relationship_target_model.argument_mappings.is_empty() {
+            return Err(
+                TypePredicateError::RelationshipWithArgumentMappingsInTypePredicate {
+                    relationship_name: comparable_relationship.relationship_name.clone(),
+                    type_name: object_boolean_expression_type.object_type.clone(),
+                }
+                .into(),
+            );
+        }
+
+        // Predicates compare against top-level columns only, so mappings through nested
+        // objects can't be used in them
+        if relationship_target_model.mappings.iter().any(|mapping| {
+            !mapping.source_nested_fields.is_empty() || !mapping.target_nested_fields.is_empty()
+        }) {
+            return Err(
+                TypePredicateError::RelationshipWithNestedFieldPathsInTypePredicate {
+                    relationship_name: comparable_relationship.relationship_name.clone(),
+                    type_name: object_boolean_expression_type.object_type.clone(),
+                }
+                .into(),
+            );
+        }
+
+        // Filtering by aggregates is only part of NDC v0.2
+        if comparable_relationship
+            .aggregate_boolean_expression_type
+            .is_some()
+            && data_connector.capabilities.supported_ndc_version == data_connectors::NdcVersion::V01
+        {
+            return Err(
+                boolean_expressions::BooleanExpressionError::DataConnectorDoesNotSupportAggregateComparisons {
+                    data_connector_name: data_connector.name.clone(),
+                    ndc_version: data_connector.capabilities.supported_ndc_version,
+                    boolean_expression_type_name: object_boolean_expression_type.name.clone(),
+                    relationship_name: comparable_relationship.relationship_name.clone(),
+                },
+            );
+        }
+
         match &target_model.source {
             Some(target_model_source) => {
                 // If relationship is a not a local relationship.
@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 
 use crate::{
     data_connectors::CommandsResponseConfig,
-    stages::{commands, data_connectors, models, object_types},
+    stages::{abstract_types, commands, data_connectors, models, object_types},
 };
 use ndc_models;
 use open_dds::{
@@ -273,6 +273,10 @@ pub fn validate_ndc_command(
     command_name: &Qualified<CommandName>,
     command_source: &commands::CommandSource,
     command_output_type: &QualifiedTypeReference,
+    abstract_types: &BTreeMap<
+        Qualified<CustomTypeName>,
+        abstract_types::AbstractTypeRepresentation,
+    >,
     schema: &data_connectors::DataConnectorSchema,
     commands_response_config: Option<&CommandsResponseConfig>,
 ) -> Result<bool, NDCValidationError> {
@@ -368,28 +372,42 @@ pub fn validate_ndc_command(
                     )?;
                     source_type_open_dd_type_same =
                         actual_command_source_type == command_source_ndc_type;
-                    // Check if the command.output_type has typeMappings
-                    let object_types::TypeMapping::Object { field_mappings, .. } = command_source
-                        .type_mappings
-                        .get(custom_type)
-                        .ok_or_else(|| NDCValidationError::UnknownCommandTypeMapping {
-                            command_name: command_name.clone(),
-                            type_name: custom_type.clone(),
-                        })?;
-                    // Check if the field mappings for the output_type is valid
-                    for (field_name, field_mapping) in field_mappings {
-                        let column_name = &field_mapping.column;
-                        if !actual_command_source_type
-                            .fields
-                            .contains_key(column_name.as_str())
-                        {
-                            return Err(NDCValidationError::NoSuchColumnForCommand {
-                                db_name: db.name.clone(),
-                                command_name: command_name.clone(),
-                                field_name: field_name.clone(),
-                                func_proc_name: command_source_func_proc_name.to_owned(),
-                                column_name: column_name.clone(),
-                            });
+                    // A union or interface output type is made of its member object types,
+                    // which are all read from the same ndc object type
+                    let output_object_types = match abstract_types.get(custom_type) {
+                        Some(abstract_type) => abstract_type
+                            .discrimination
+                            .members
+                            .iter()
+                            .map(|member| &member.object_type)
+                            .collect(),
+                        None => vec![custom_type],
+                    };
+                    for output_object_type in output_object_types {
+                        // Check if the command.output_type has typeMappings
+                        let object_types::TypeMapping::Object { field_mappings, .. } =
+                            command_source
+                                .type_mappings
+                                .get(output_object_type)
+                                .ok_or_else(|| NDCValidationError::UnknownCommandTypeMapping {
+                                    command_name: command_name.clone(),
+                                    type_name: output_object_type.clone(),
+                                })?;
+                        // Check if the field mappings for the output_type is valid
+                        for (field_name, field_mapping) in field_mappings {
+                            let column_name = &field_mapping.column;
+                            if !actual_command_source_type
+                                .fields
+                                .contains_key(column_name.as_str())
+                            {
+                                return Err(NDCValidationError::NoSuchColumnForCommand {
+                                    db_name: db.name.clone(),
+                                    command_name: command_name.clone(),
+                                    field_name: field_name.clone(),
+                                    func_proc_name: command_source_func_proc_name.to_owned(),
+                                    column_name: column_name.clone(),
+                                });
+                            }
                         }
                     }
                 }
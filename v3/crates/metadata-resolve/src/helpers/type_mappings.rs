@@ -1,11 +1,13 @@
-use crate::stages::{object_types, scalar_types, type_permissions};
+use crate::stages::{object_relationships, object_types, scalar_types, type_permissions};
 
 use crate::data_connectors::CommandsResponseConfig;
 use crate::helpers::ndc_validation::{get_underlying_named_type, NDCValidationError};
 use crate::helpers::types::{object_type_exists, unwrap_custom_type_name};
 use crate::types::subgraph::Qualified;
 
-use open_dds::data_connector::{DataConnectorName, DataConnectorObjectType};
+use open_dds::data_connector::{
+    DataConnectorColumnName, DataConnectorName, DataConnectorObjectType,
+};
 use open_dds::relationships::RelationshipName;
 use open_dds::types::{CustomTypeName, FieldName};
 
@@ -272,3 +274,24 @@ pub fn get_field_mapping_of_field_name(
             .clone()),
     }
 }
+
+/// Get the NDC columns of the nested object fields on the field path of a
+/// relationship mapping
+pub fn get_nested_field_columns(
+    type_mappings: &BTreeMap<Qualified<CustomTypeName>, object_types::TypeMapping>,
+    relationship_name: &RelationshipName,
+    nested_fields: &[object_relationships::RelationshipNestedField],
+) -> Result<Vec<DataConnectorColumnName>, RelationshipFieldMappingError> {
+    nested_fields
+        .iter()
+        .map(|nested_field| {
+            get_field_mapping_of_field_name(
+                type_mappings,
+                &nested_field.object_type,
+                relationship_name,
+                &nested_field.field_name,
+            )
+            .map(|field_mapping| field_mapping.column)
+        })
+        .collect()
+}
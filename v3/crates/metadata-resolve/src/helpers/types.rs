@@ -19,6 +19,11 @@ use std::str::FromStr;
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct NdcColumnForComparison {
     pub column: DataConnectorColumnName,
+    /// Path to a nested field within the object `column`, when comparing
+    /// against a field of a nested object rather than the column itself
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub field_path: Vec<DataConnectorColumnName>,
     pub equal_operator: DataConnectorOperatorName,
 }
 
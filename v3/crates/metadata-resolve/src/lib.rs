@@ -14,21 +14,33 @@ mod types;
 
 pub use helpers::http;
 pub use helpers::ndc_validation::NDCValidationError;
-pub use helpers::type_mappings::{get_field_mapping_of_field_name, RelationshipFieldMappingError};
+pub use helpers::type_mappings::{
+    get_field_mapping_of_field_name, get_nested_field_columns, RelationshipFieldMappingError,
+};
 pub use helpers::types::{
     get_type_representation, mk_name, object_type_exists, unwrap_custom_type_name,
     NdcColumnForComparison, TypeRepresentation,
 };
+pub use stages::abstract_types::{
+    AbstractTypeKind, AbstractTypeRepresentation, DiscriminatedMember, InterfaceFieldDefinition,
+    TypeDiscrimination,
+};
+pub use stages::aggregate_boolean_expressions::{
+    ComparableAggregatableField, ComparableAggregationFunction, ComparableCountAggregation,
+    FilterInputDefinition, FromModelFilterInputDefinition, ObjectAggregateBooleanExpression,
+    ScalarAggregateBooleanExpression,
+};
 pub use stages::aggregates::{
     AggregatableFieldInfo, AggregateExpression, AggregateExpressionGraphqlConfig, AggregateOperand,
     AggregationFunctionInfo, DataConnectorAggregationFunctionInfo,
 };
 pub use stages::boolean_expressions::{
     BooleanExpressionComparableRelationship, BooleanExpressionError,
-    BooleanExpressionGraphqlConfig, BooleanExpressionGraphqlFieldConfig, ComparisonExpressionInfo,
-    IncludeLogicalOperators, ObjectBooleanExpressionGraphqlConfig, ObjectComparisonExpressionInfo,
-    ObjectComparisonKind, OperatorMapping, ResolvedObjectBooleanExpressionType,
-    ScalarBooleanExpressionGraphqlConfig, ScalarComparisonKind,
+    BooleanExpressionGraphqlConfig, BooleanExpressionGraphqlFieldConfig,
+    BooleanExpressionTypeIdentifier, ComparisonExpressionInfo, IncludeLogicalOperators,
+    ObjectBooleanExpressionGraphqlConfig, ObjectComparisonExpressionInfo, ObjectComparisonKind,
+    OperatorMapping, ResolvedObjectBooleanExpressionType, ScalarBooleanExpressionGraphqlConfig,
+    ScalarComparisonKind,
 };
 pub use stages::data_connectors::{
     ArgumentPresetValue, DataConnectorLink, HttpHeadersPreset, NdcVersion,
@@ -40,18 +52,27 @@ pub use stages::model_permissions::{
 };
 pub use stages::models::{Model, ModelSource, ModelsError};
 pub use stages::models_graphql::{
-    ModelOrderByExpression, SelectAggregateGraphQlDefinition, SelectManyGraphQlDefinition,
-    SelectUniqueGraphQlDefinition, SubscriptionGraphQlDefinition, UniqueIdentifierField,
+    CursorField, CursorPaginationGraphQlDefinition, DistinctOnGraphQlDefinition, GroupByDimension,
+    GroupByGraphQlDefinition, ModelMutationGraphQlDefinition, ModelMutationsGraphQlDefinition,
+    ModelOrderByExpression, SelectAggregateGraphQlDefinition, SelectConnectionGraphQlDefinition,
+    SelectManyGraphQlDefinition, SelectUniqueGraphQlDefinition, StreamCursorField,
+    StreamSubscriptionGraphQlDefinition, SubscriptionGraphQlDefinition, UniqueIdentifierField,
+    UpdateByUniqueKeyGraphQlDefinition, MUTATION_AFFECTED_ROWS_FIELD, MUTATION_OBJECTS_ARGUMENT,
+    MUTATION_POST_CHECK_ARGUMENT, MUTATION_PRE_CHECK_ARGUMENT, MUTATION_RETURNING_FIELD,
+    MUTATION_UPDATE_COLUMNS_ARGUMENT,
 };
 pub use stages::object_relationships::{
     relationship_execution_category, AggregateRelationship, CommandRelationshipTarget,
     FieldNestedness, ModelRelationshipTarget, ObjectTypeWithRelationships,
-    RelationshipCapabilities, RelationshipCommandMapping, RelationshipExecutionCategory,
-    RelationshipField, RelationshipModelMapping, RelationshipTarget,
+    RelationshipCapabilities, RelationshipCommandMapping, RelationshipCommandValueMapping,
+    RelationshipExecutionCategory, RelationshipField, RelationshipModelArgumentMapping,
+    RelationshipModelMapping, RelationshipModelValueMapping, RelationshipNestedField,
+    RelationshipTarget,
 };
 pub use stages::object_types::{
-    AggregateFunctions, ComparisonOperators, ExtractionFunctions, FieldArgumentInfo, FieldMapping,
-    ObjectTypeRepresentation, ResolvedObjectApolloFederationConfig, TypeMapping,
+    AggregateFunctions, ComparisonOperators, ExtractionFunctions, FieldArgumentInfo,
+    FieldDefinition, FieldMapping, ObjectTypeRepresentation, ResolvedObjectApolloFederationConfig,
+    TypeMapping,
 };
 pub use stages::order_by_expressions::{
     ObjectOrderByExpression, OrderByExpressionGraphqlConfig, OrderByExpressionIdentifier,
@@ -60,12 +81,17 @@ pub use stages::order_by_expressions::{
 };
 pub use stages::plugins::LifecyclePluginConfigs;
 pub use stages::scalar_boolean_expressions::{
-    LogicalOperators, LogicalOperatorsGraphqlConfig, ResolvedScalarBooleanExpressionType,
+    IsNullOperator, IsNullOperatorGraphqlConfig, LogicalOperators, LogicalOperatorsGraphqlConfig,
+    ResolvedScalarBooleanExpressionType,
 };
 pub use stages::scalar_type_representations::ScalarTypeRepresentation;
-pub use stages::type_permissions::{FieldPresetInfo, TypeInputPermission};
+pub use stages::scalar_types::EnumTypeValue;
+pub use stages::type_permissions::{
+    FieldCondition, FieldConditionOperator, FieldPresetInfo, TypeInputPermission,
+    TypeOutputPermission,
+};
 pub use stages::{
-    command_permissions::CommandWithPermissions,
+    command_permissions::{ArgumentPredicate, CommandOutputFilter, CommandWithPermissions},
     commands::{Command, CommandSource},
     data_connectors,
 };
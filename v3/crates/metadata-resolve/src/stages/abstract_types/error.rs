@@ -0,0 +1,55 @@
+use crate::stages::graphql_config;
+use crate::types::subgraph::{Qualified, QualifiedTypeReference};
+use open_dds::types::{CustomTypeName, FieldName};
+
+#[derive(Debug, thiserror::Error)]
+pub enum AbstractTypesError {
+    #[error("{0}")]
+    GraphqlConfigError(#[from] graphql_config::GraphqlConfigError),
+    #[error("the following type is defined more than once: {name:}")]
+    DuplicateTypeDefinition { name: Qualified<CustomTypeName> },
+    #[error("the type {type_name} must have at least one member object type")]
+    NoMemberTypes {
+        type_name: Qualified<CustomTypeName>,
+    },
+    #[error("the member {member_type} of the type {type_name} is not an object type")]
+    MemberIsNotAnObjectType {
+        type_name: Qualified<CustomTypeName>,
+        member_type: Qualified<CustomTypeName>,
+    },
+    #[error("the object type {member_type} is a member of the type {type_name} more than once")]
+    DuplicateMemberType {
+        type_name: Qualified<CustomTypeName>,
+        member_type: Qualified<CustomTypeName>,
+    },
+    #[error("the discriminator value {discriminator_value} is used by more than one member of the type {type_name}")]
+    DuplicateDiscriminatorValue {
+        type_name: Qualified<CustomTypeName>,
+        discriminator_value: String,
+    },
+    #[error("the discriminator field {discriminator_field} of the type {type_name} is not defined in its member {member_type}")]
+    MissingDiscriminatorField {
+        type_name: Qualified<CustomTypeName>,
+        member_type: Qualified<CustomTypeName>,
+        discriminator_field: FieldName,
+    },
+    #[error("the field {field_name} of the interface type {type_name} is not defined in its implementation {member_type}")]
+    MissingInterfaceField {
+        type_name: Qualified<CustomTypeName>,
+        member_type: Qualified<CustomTypeName>,
+        field_name: FieldName,
+    },
+    #[error("the field {field_name} of the interface type {type_name} has type {interface_field_type}, but it has type {member_field_type} in its implementation {member_type}")]
+    InterfaceFieldTypeMismatch {
+        type_name: Qualified<CustomTypeName>,
+        member_type: Qualified<CustomTypeName>,
+        field_name: FieldName,
+        interface_field_type: QualifiedTypeReference,
+        member_field_type: QualifiedTypeReference,
+    },
+    #[error("the field {field_name} is defined more than once in the interface type {type_name}")]
+    DuplicateInterfaceField {
+        type_name: Qualified<CustomTypeName>,
+        field_name: FieldName,
+    },
+}
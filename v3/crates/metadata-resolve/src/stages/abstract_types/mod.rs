@@ -0,0 +1,224 @@
+use crate::helpers::types::mk_name;
+use crate::stages::{graphql_config, object_types, scalar_types};
+use crate::types::subgraph::{mk_qualified_type_reference, Qualified};
+use indexmap::IndexMap;
+use lang_graphql::ast::common as ast;
+use open_dds::identifier::SubgraphName;
+use open_dds::types::{CustomTypeName, DiscriminatedObjectType, FieldName, GraphQlTypeName};
+use std::collections::{BTreeMap, BTreeSet};
+
+mod error;
+pub mod types;
+pub use error::AbstractTypesError;
+pub use types::{
+    AbstractTypeKind, AbstractTypeRepresentation, AbstractTypesOutput, DiscriminatedMember,
+    InterfaceFieldDefinition, TypeDiscrimination,
+};
+
+/// resolve union and interface types
+pub fn resolve(
+    metadata_accessor: &open_dds::accessor::MetadataAccessor,
+    object_types: &object_types::ObjectTypesWithTypeMappings,
+    scalar_types: &BTreeMap<Qualified<CustomTypeName>, scalar_types::ScalarTypeRepresentation>,
+    graphql_types: &mut graphql_config::GraphqlTypeNames,
+) -> Result<AbstractTypesOutput, AbstractTypesError> {
+    let mut abstract_types = BTreeMap::new();
+
+    for open_dds::accessor::QualifiedObject {
+        path: _,
+        subgraph,
+        object: union_type,
+    } in &metadata_accessor.union_types
+    {
+        let qualified_type_name = Qualified::new(subgraph.clone(), union_type.name.clone());
+        let discrimination = resolve_type_discrimination(
+            &qualified_type_name,
+            subgraph,
+            &union_type.discriminator_field,
+            &union_type.members,
+            object_types,
+        )?;
+        let graphql_type_name = resolve_graphql_type_name(
+            union_type
+                .graphql
+                .as_ref()
+                .map(|graphql| &graphql.type_name),
+            graphql_types,
+        )?;
+        insert_abstract_type(
+            &mut abstract_types,
+            qualified_type_name,
+            AbstractTypeRepresentation {
+                kind: AbstractTypeKind::Union,
+                graphql_type_name,
+                description: union_type.description.clone(),
+                discrimination,
+            },
+            object_types,
+            scalar_types,
+        )?;
+    }
+
+    for open_dds::accessor::QualifiedObject {
+        path: _,
+        subgraph,
+        object: interface_type,
+    } in &metadata_accessor.interface_types
+    {
+        let qualified_type_name = Qualified::new(subgraph.clone(), interface_type.name.clone());
+        let discrimination = resolve_type_discrimination(
+            &qualified_type_name,
+            subgraph,
+            &interface_type.discriminator_field,
+            &interface_type.implementations,
+            object_types,
+        )?;
+
+        let mut fields = IndexMap::new();
+        for field in &interface_type.fields {
+            let interface_field = InterfaceFieldDefinition {
+                field_type: mk_qualified_type_reference(&field.field_type, subgraph),
+                description: field.description.clone(),
+                deprecated: field.deprecated.clone(),
+            };
+            // every implementation must have the field, with the same type
+            for member in &discrimination.members {
+                let member_field = object_types
+                    .get(&member.object_type)
+                    .and_then(|object_type| object_type.object_type.fields.get(&field.name))
+                    .ok_or_else(|| AbstractTypesError::MissingInterfaceField {
+                        type_name: qualified_type_name.clone(),
+                        member_type: member.object_type.clone(),
+                        field_name: field.name.clone(),
+                    })?;
+                if member_field.field_type != interface_field.field_type {
+                    return Err(AbstractTypesError::InterfaceFieldTypeMismatch {
+                        type_name: qualified_type_name.clone(),
+                        member_type: member.object_type.clone(),
+                        field_name: field.name.clone(),
+                        interface_field_type: interface_field.field_type,
+                        member_field_type: member_field.field_type.clone(),
+                    });
+                }
+            }
+            if fields.insert(field.name.clone(), interface_field).is_some() {
+                return Err(AbstractTypesError::DuplicateInterfaceField {
+                    type_name: qualified_type_name,
+                    field_name: field.name.clone(),
+                });
+            }
+        }
+
+        let graphql_type_name = resolve_graphql_type_name(
+            interface_type
+                .graphql
+                .as_ref()
+                .map(|graphql| &graphql.type_name),
+            graphql_types,
+        )?;
+        insert_abstract_type(
+            &mut abstract_types,
+            qualified_type_name,
+            AbstractTypeRepresentation {
+                kind: AbstractTypeKind::Interface { fields },
+                graphql_type_name,
+                description: interface_type.description.clone(),
+                discrimination,
+            },
+            object_types,
+            scalar_types,
+        )?;
+    }
+
+    Ok(AbstractTypesOutput { abstract_types })
+}
+
+/// Check that the members of a union or interface type are distinct object types which
+/// all define the discriminator field, and are selected by distinct discriminator values.
+fn resolve_type_discrimination(
+    type_name: &Qualified<CustomTypeName>,
+    subgraph: &SubgraphName,
+    discriminator_field: &FieldName,
+    members: &[DiscriminatedObjectType],
+    object_types: &object_types::ObjectTypesWithTypeMappings,
+) -> Result<TypeDiscrimination, AbstractTypesError> {
+    if members.is_empty() {
+        return Err(AbstractTypesError::NoMemberTypes {
+            type_name: type_name.clone(),
+        });
+    }
+
+    let mut member_types = BTreeSet::new();
+    let mut discriminator_values = BTreeSet::new();
+    let mut resolved_members = Vec::new();
+    for member in members {
+        let member_type = Qualified::new(subgraph.clone(), member.object_type.clone());
+        let object_type = object_types.get(&member_type).ok_or_else(|| {
+            AbstractTypesError::MemberIsNotAnObjectType {
+                type_name: type_name.clone(),
+                member_type: member_type.clone(),
+            }
+        })?;
+        if !member_types.insert(member_type.clone()) {
+            return Err(AbstractTypesError::DuplicateMemberType {
+                type_name: type_name.clone(),
+                member_type,
+            });
+        }
+        if !discriminator_values.insert(member.discriminator_value.as_str()) {
+            return Err(AbstractTypesError::DuplicateDiscriminatorValue {
+                type_name: type_name.clone(),
+                discriminator_value: member.discriminator_value.clone(),
+            });
+        }
+        if !object_type
+            .object_type
+            .fields
+            .contains_key(discriminator_field)
+        {
+            return Err(AbstractTypesError::MissingDiscriminatorField {
+                type_name: type_name.clone(),
+                member_type,
+                discriminator_field: discriminator_field.clone(),
+            });
+        }
+        resolved_members.push(DiscriminatedMember {
+            graphql_type_name: object_type.object_type.graphql_output_type_name.clone(),
+            object_type: member_type,
+            discriminator_value: member.discriminator_value.clone(),
+        });
+    }
+
+    Ok(TypeDiscrimination {
+        discriminator_field: discriminator_field.clone(),
+        members: resolved_members,
+    })
+}
+
+fn resolve_graphql_type_name(
+    graphql_type_name: Option<&GraphQlTypeName>,
+    graphql_types: &mut graphql_config::GraphqlTypeNames,
+) -> Result<Option<ast::TypeName>, AbstractTypesError> {
+    let graphql_type_name = graphql_type_name
+        .map(|type_name| mk_name(type_name.as_ref()).map(ast::TypeName))
+        .transpose()?;
+    graphql_types.store(graphql_type_name.as_ref())?;
+    Ok(graphql_type_name)
+}
+
+fn insert_abstract_type(
+    abstract_types: &mut BTreeMap<Qualified<CustomTypeName>, AbstractTypeRepresentation>,
+    type_name: Qualified<CustomTypeName>,
+    abstract_type: AbstractTypeRepresentation,
+    object_types: &object_types::ObjectTypesWithTypeMappings,
+    scalar_types: &BTreeMap<Qualified<CustomTypeName>, scalar_types::ScalarTypeRepresentation>,
+) -> Result<(), AbstractTypesError> {
+    if object_types.contains_key(&type_name)
+        || scalar_types.contains_key(&type_name)
+        || abstract_types.contains_key(&type_name)
+    {
+        return Err(AbstractTypesError::DuplicateTypeDefinition { name: type_name });
+    }
+    abstract_types.insert(type_name, abstract_type);
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use crate::types::subgraph::{Qualified, QualifiedTypeReference};
+use indexmap::IndexMap;
+use lang_graphql::ast::common as ast;
+use open_dds::types::{CustomTypeName, Deprecated, FieldName};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// output of `abstract_types` step
+pub struct AbstractTypesOutput {
+    pub abstract_types: BTreeMap<Qualified<CustomTypeName>, AbstractTypeRepresentation>,
+}
+
+/// A union or interface type, whose values are objects of one of several object types
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AbstractTypeRepresentation {
+    pub kind: AbstractTypeKind,
+    pub graphql_type_name: Option<ast::TypeName>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub description: Option<String>,
+    pub discrimination: TypeDiscrimination,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum AbstractTypeKind {
+    Union,
+    Interface {
+        fields: IndexMap<FieldName, InterfaceFieldDefinition>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct InterfaceFieldDefinition {
+    pub field_type: QualifiedTypeReference,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub description: Option<String>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub deprecated: Option<Deprecated>,
+}
+
+/// How the object type of a value of an abstract type is selected: each object type
+/// is identified by the value of the discriminator field.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TypeDiscrimination {
+    pub discriminator_field: FieldName,
+    pub members: Vec<DiscriminatedMember>,
+}
+
+impl TypeDiscrimination {
+    /// The member selected by a value of the discriminator field
+    pub fn member_for_value(&self, discriminator_value: &str) -> Option<&DiscriminatedMember> {
+        self.members
+            .iter()
+            .find(|member| member.discriminator_value == discriminator_value)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DiscriminatedMember {
+    pub object_type: Qualified<CustomTypeName>,
+    pub graphql_type_name: Option<ast::TypeName>,
+    pub discriminator_value: String,
+}
@@ -10,9 +10,8 @@ use super::{
     type_permissions::ObjectTypeWithPermissions,
 };
 use crate::{
-    configuration::UnstableFeatures, helpers::check_for_duplicates, mk_name,
-    types::subgraph::mk_qualified_type_name, Qualified, QualifiedBaseType, QualifiedTypeName,
-    ResolvedScalarBooleanExpressionType,
+    helpers::check_for_duplicates, mk_name, types::subgraph::mk_qualified_type_name, Qualified,
+    QualifiedBaseType, QualifiedTypeName, ResolvedScalarBooleanExpressionType,
 };
 use lang_graphql::ast::common as ast;
 use open_dds::{
@@ -27,7 +26,6 @@ use open_dds::{
 pub use types::*;
 
 pub fn resolve(
-    unstable_features: &UnstableFeatures,
     metadata_accessor: &open_dds::accessor::MetadataAccessor,
     scalar_boolean_expression_types: &BTreeMap<
         boolean_expressions::BooleanExpressionTypeIdentifier,
@@ -58,11 +56,8 @@ pub fn resolve(
             ) => {
                 let mut issues = vec![];
 
-                let qualified_name = resolve_common_aggregate_boolean_expression(
-                    unstable_features,
-                    subgraph,
-                    boolean_expression,
-                )?;
+                let qualified_name =
+                    resolve_common_aggregate_boolean_expression(subgraph, boolean_expression)?;
                 let object_aggregate = resolve_object_aggregate_boolean_expression(
                     subgraph,
                     boolean_expression,
@@ -99,11 +94,8 @@ pub fn resolve(
             ) => {
                 let mut issues = vec![];
 
-                let qualified_name = resolve_common_aggregate_boolean_expression(
-                    unstable_features,
-                    subgraph,
-                    boolean_expression,
-                )?;
+                let qualified_name =
+                    resolve_common_aggregate_boolean_expression(subgraph, boolean_expression)?;
 
                 let scalar_aggregate = resolve_scalar_aggregate_boolean_expression(
                     subgraph,
@@ -140,19 +132,11 @@ pub fn resolve(
 }
 
 fn resolve_common_aggregate_boolean_expression(
-    unstable_features: &UnstableFeatures,
     subgraph: &SubgraphName,
     boolean_expression: &open_dds::boolean_expression::BooleanExpressionTypeV1,
 ) -> Result<Qualified<CustomTypeName>, NamedAggregateBooleanExpressionError> {
     let qualified_name = Qualified::new(subgraph.clone(), boolean_expression.name.clone());
 
-    if !unstable_features.enable_aggregation_predicates {
-        return Err(NamedAggregateBooleanExpressionError {
-            type_name: qualified_name,
-            error: AggregateBooleanExpressionError::AggregateBooleanExpressionsNotSupported,
-        });
-    }
-
     // Check if isNull is enabled; isNull is not supported for aggregates boolexps because
     // one applies isNull to the result of an aggregate (ie. the final scalar-operand comparison boolexp)
     // not the aggregate boolexp itself
@@ -338,6 +322,7 @@ fn resolve_aggregate_bool_exp_graphql_config(
 
 fn resolve_aggregate_predicate_bool_exp_graphql_config(
     object_aggregate_operand: &open_dds::boolean_expression::BooleanExpressionObjectAggregateOperand,
+    graphql_config: &graphql_config::GraphqlConfig,
     graphql_types: &mut graphql_config::GraphqlTypeNames,
 ) -> Result<Option<AggregatePredicateGraphqlConfig>, AggregateBooleanExpressionError> {
     object_aggregate_operand
@@ -351,6 +336,11 @@ fn resolve_aggregate_predicate_bool_exp_graphql_config(
 
             Ok(AggregatePredicateGraphqlConfig {
                 type_name: predicate_graphql_name,
+                filter_input_field_name: graphql_config
+                    .query
+                    .aggregate_config
+                    .as_ref()
+                    .map(|config| config.filter_input_field_name.clone()),
             })
         })
         .transpose()
@@ -609,6 +599,7 @@ fn resolve_object_aggregate_boolean_expression(
     // Resolve aggregate predicate graphql configuration
     let predicate_graphql = resolve_aggregate_predicate_bool_exp_graphql_config(
         object_aggregate_operand,
+        graphql_config,
         graphql_types,
     )?;
 
@@ -119,6 +119,9 @@ pub struct FromModelFilterInputDefinition {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct AggregatePredicateGraphqlConfig {
     pub type_name: ast::TypeName,
+    /// The name of the field used to filter the objects before they are aggregated,
+    /// taken from the aggregate settings in the GraphqlConfig
+    pub filter_input_field_name: Option<ast::Name>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -130,9 +133,6 @@ pub struct NamedAggregateBooleanExpressionError {
 
 #[derive(Debug, thiserror::Error)]
 pub enum AggregateBooleanExpressionError {
-    #[error("boolean expressions with aggregate operands are not supported")]
-    AggregateBooleanExpressionsNotSupported,
-
     #[error("boolean expressions with aggregate operands do not support isNull comparisons")]
     IsNullComparisonsNotSupported,
 
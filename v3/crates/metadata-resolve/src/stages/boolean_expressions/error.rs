@@ -97,6 +97,13 @@ pub enum BooleanExpressionError {
         parent_boolean_expression_type_name: Qualified<CustomTypeName>,
         nested_boolean_expression_type_name: Qualified<CustomTypeName>,
     },
+    #[error("The data connector '{data_connector_name}' does not support filtering by aggregates, as it implements NDC {ndc_version}. The relationship '{relationship_name}' within '{boolean_expression_type_name}' defines an aggregate boolean expression type, which requires NDC v0.2.x.")]
+    DataConnectorDoesNotSupportAggregateComparisons {
+        data_connector_name: Qualified<DataConnectorName>,
+        ndc_version: data_connectors::NdcVersion,
+        boolean_expression_type_name: Qualified<CustomTypeName>,
+        relationship_name: RelationshipName,
+    },
     #[error("The field {field_name:} has type {field_type:} but the field's boolean expression type {field_boolean_expression_type_name:} has type {underlying_type:}")]
     FieldTypeMismatch {
         field_name: FieldName,
@@ -128,6 +135,28 @@ pub enum BooleanExpressionError {
         model_name: Qualified<ModelName>,
     },
 
+    #[error("could not find objectAggregate boolean expression type {aggregate_boolean_expression:} referenced by relationship {relationship_name:} within boolean expression {parent_boolean_expression:}")]
+    ObjectAggregateBooleanExpressionCouldNotBeFound {
+        parent_boolean_expression: Qualified<CustomTypeName>,
+        relationship_name: RelationshipName,
+        aggregate_boolean_expression: Qualified<CustomTypeName>,
+    },
+
+    #[error("the relationship {relationship_name:} in boolean expression {boolean_expression_type_name:} defines an aggregate boolean expression type, but aggregate comparisons are only supported on array relationships to models")]
+    AggregateComparisonRequiresArrayModelRelationship {
+        boolean_expression_type_name: Qualified<CustomTypeName>,
+        relationship_name: RelationshipName,
+    },
+
+    #[error("the aggregate boolean expression type {aggregate_boolean_expression:} used by relationship {relationship_name:} in boolean expression {boolean_expression_type_name:} aggregates over object type {operand_type:} whereas the target model's object type is {model_object_type:}")]
+    AggregateBooleanExpressionTypeMismatchInRelationship {
+        boolean_expression_type_name: Qualified<CustomTypeName>,
+        relationship_name: RelationshipName,
+        aggregate_boolean_expression: Qualified<CustomTypeName>,
+        operand_type: Qualified<CustomTypeName>,
+        model_object_type: Qualified<CustomTypeName>,
+    },
+
     #[error("{0}")]
     GraphqlConfigError(#[from] graphql_config::GraphqlConfigError),
 
@@ -225,6 +225,7 @@ pub(crate) fn resolve_object_boolean_expression_type(
         relationships,
         &boolean_expression_type_name.subgraph,
         raw_boolean_expression_types,
+        &BTreeMap::new(),
         raw_models,
         object_boolean_expression_type_names,
         &mut issues,
@@ -155,6 +155,7 @@ pub fn resolve(
                     object_types,
                     &boolean_expression_scalar_types,
                     &raw_boolean_expression_types,
+                    &boolean_expression_object_aggregate_types,
                     relationships,
                     &raw_models,
                     &object_boolean_expression_type_names,
@@ -6,7 +6,8 @@ use super::{
     ResolvedObjectBooleanExpressionTypeFields, ScalarComparisonKind,
 };
 use crate::stages::{
-    graphql_config, object_types, relationships, scalar_boolean_expressions, type_permissions,
+    aggregate_boolean_expressions, graphql_config, object_types, relationships,
+    scalar_boolean_expressions, type_permissions,
 };
 use crate::types::subgraph::mk_qualified_type_name;
 use crate::{Qualified, QualifiedBaseType};
@@ -46,6 +47,10 @@ pub(crate) fn resolve_object_boolean_expression_type(
         scalar_boolean_expressions::ResolvedScalarBooleanExpressionType,
     >,
     raw_boolean_expression_types: &RawBooleanExpressionTypes,
+    object_aggregate_boolean_expression_types: &BTreeMap<
+        Qualified<CustomTypeName>,
+        aggregate_boolean_expressions::ObjectAggregateBooleanExpression,
+    >,
     relationships: &relationships::Relationships,
     raw_models: &BTreeMap<Qualified<ModelName>, &open_dds::models::Model>,
     object_boolean_expression_type_names: &BTreeSet<Qualified<CustomTypeName>>,
@@ -120,6 +125,7 @@ pub(crate) fn resolve_object_boolean_expression_type(
         relationships,
         subgraph,
         raw_boolean_expression_types,
+        object_aggregate_boolean_expression_types,
         raw_models,
         object_boolean_expression_type_names,
         &mut issues,
@@ -174,6 +180,10 @@ pub fn resolve_comparable_relationships(
     relationships: &relationships::Relationships,
     subgraph: &SubgraphName,
     raw_boolean_expression_types: &RawBooleanExpressionTypes,
+    object_aggregate_boolean_expression_types: &BTreeMap<
+        Qualified<CustomTypeName>,
+        aggregate_boolean_expressions::ObjectAggregateBooleanExpression,
+    >,
     raw_models: &BTreeMap<Qualified<ModelName>, &open_dds::models::Model>,
     object_boolean_expression_type_names: &BTreeSet<Qualified<CustomTypeName>>,
     issues: &mut Vec<BooleanExpressionIssue>,
@@ -257,11 +267,27 @@ pub fn resolve_comparable_relationships(
                         }
                     }?;
 
+                    let aggregate_boolean_expression_type = comparable_relationship
+                        .aggregate_boolean_expression_type
+                        .as_ref()
+                        .map(|aggregate_boolean_expression_type_name| {
+                            resolve_comparable_relationship_aggregate(
+                                boolean_expression_type_name,
+                                relationship,
+                                &target_subgraph,
+                                aggregate_boolean_expression_type_name,
+                                object_aggregate_boolean_expression_types,
+                                raw_models,
+                            )
+                        })
+                        .transpose()?;
+
                     if let Some(_duplicate_relationship) = resolved_comparable_relationships.insert(
                         FieldName::new(comparable_relationship.relationship_name.inner().clone()),
                         BooleanExpressionComparableRelationship {
                             relationship_name: comparable_relationship.relationship_name.clone(),
                             boolean_expression_type: target_boolean_expression_type,
+                            aggregate_boolean_expression_type,
                         },
                     ) {
                         issues.push(
@@ -283,6 +309,74 @@ pub fn resolve_comparable_relationships(
     Ok(resolved_comparable_relationships)
 }
 
+// an aggregate comparison is only possible across an array relationship to a model,
+// using an objectAggregate boolean expression over the object type of that model
+fn resolve_comparable_relationship_aggregate(
+    boolean_expression_type_name: &Qualified<CustomTypeName>,
+    relationship: &open_dds::relationships::RelationshipV1,
+    target_subgraph: &SubgraphName,
+    aggregate_boolean_expression_type_name: &CustomTypeName,
+    object_aggregate_boolean_expression_types: &BTreeMap<
+        Qualified<CustomTypeName>,
+        aggregate_boolean_expressions::ObjectAggregateBooleanExpression,
+    >,
+    raw_models: &BTreeMap<Qualified<ModelName>, &open_dds::models::Model>,
+) -> Result<Qualified<CustomTypeName>, BooleanExpressionError> {
+    let aggregate_boolean_expression_type = Qualified::new(
+        target_subgraph.clone(),
+        aggregate_boolean_expression_type_name.clone(),
+    );
+
+    let object_aggregate = object_aggregate_boolean_expression_types
+        .get(&aggregate_boolean_expression_type)
+        .ok_or_else(
+            || BooleanExpressionError::ObjectAggregateBooleanExpressionCouldNotBeFound {
+                parent_boolean_expression: boolean_expression_type_name.clone(),
+                relationship_name: relationship.name.clone(),
+                aggregate_boolean_expression: aggregate_boolean_expression_type.clone(),
+            },
+        )?;
+
+    let model_target = match &relationship.target {
+        open_dds::relationships::RelationshipTarget::Model(model_target)
+            if model_target.relationship_type
+                == open_dds::relationships::RelationshipType::Array =>
+        {
+            Ok(model_target)
+        }
+        _ => Err(
+            BooleanExpressionError::AggregateComparisonRequiresArrayModelRelationship {
+                boolean_expression_type_name: boolean_expression_type_name.clone(),
+                relationship_name: relationship.name.clone(),
+            },
+        ),
+    }?;
+
+    let target_model_name = Qualified::new(target_subgraph.clone(), model_target.name.clone());
+    let raw_model = raw_models.get(&target_model_name).ok_or_else(|| {
+        BooleanExpressionError::TargetModelNotFound {
+            relationship_name: relationship.name.clone(),
+            model_name: target_model_name.clone(),
+        }
+    })?;
+
+    let model_object_type =
+        Qualified::new(target_subgraph.clone(), raw_model.object_type().clone());
+    if object_aggregate.operand_type != model_object_type {
+        return Err(
+            BooleanExpressionError::AggregateBooleanExpressionTypeMismatchInRelationship {
+                boolean_expression_type_name: boolean_expression_type_name.clone(),
+                relationship_name: relationship.name.clone(),
+                aggregate_boolean_expression: aggregate_boolean_expression_type,
+                operand_type: object_aggregate.operand_type.clone(),
+                model_object_type,
+            },
+        );
+    }
+
+    Ok(aggregate_boolean_expression_type)
+}
+
 pub struct ComparableFieldsOutput {
     pub comparable_fields:
         BTreeMap<FieldName, (ComparableFieldKind, BooleanExpressionTypeIdentifier)>,
@@ -270,6 +270,12 @@ pub struct BooleanExpressionComparableRelationship {
     /// The boolean expression type to use for comparison. This is optional for relationships to
     /// models, and defaults to the filterExpressionType of the model
     pub boolean_expression_type: Qualified<CustomTypeName>,
+
+    /// The objectAggregate boolean expression type to use for comparisons against aggregates
+    /// of the target of the relationship
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub aggregate_boolean_expression_type: Option<Qualified<CustomTypeName>>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -5,18 +5,24 @@ use open_dds::identifier::SubgraphName;
 use open_dds::{data_connector::DataConnectorName, models::ModelName, types::CustomTypeName};
 
 use crate::stages::{
-    boolean_expressions, commands, data_connector_scalar_types, models_graphql,
-    object_relationships, scalar_types,
+    boolean_expressions, commands, data_connector_scalar_types, model_permissions, models_graphql,
+    object_relationships, object_types, scalar_types, type_permissions,
 };
 use crate::types::error::Error;
 use crate::types::subgraph::Qualified;
 use open_dds::arguments::ArgumentName;
 
 use crate::helpers::argument::resolve_value_expression_for_argument;
+use crate::helpers::typecheck;
+use crate::helpers::types::unwrap_custom_type_name;
+use crate::FieldConditionOperator;
 
-use open_dds::permissions::CommandPermissionsV1;
+use open_dds::permissions::{CommandPermissionsV1, ModelPredicate};
 
-use super::types::{CommandPermission, CommandPermissionIssue};
+use super::types::{
+    ArgumentPredicate, CommandOutputFilter, CommandPermission, CommandPermissionError,
+    CommandPermissionIssue,
+};
 use std::collections::BTreeMap;
 
 // get the ndc_models::Type for an argument if it is available
@@ -128,11 +134,255 @@ pub fn resolve_command_permissions(
             }
         }
 
+        let object_type_representations = object_types
+            .iter()
+            .map(|(type_name, object_type)| (type_name, &object_type.object_type))
+            .collect();
+
+        let argument_predicate = command_permission
+            .argument_predicate
+            .as_ref()
+            .map(|predicate| {
+                resolve_argument_predicate(
+                    flags,
+                    &object_type_representations,
+                    &command_permission.role,
+                    command,
+                    predicate,
+                    issues,
+                )
+            })
+            .transpose()?;
+
+        let output_filter = command_permission
+            .output_filter
+            .as_ref()
+            .map(|predicate| {
+                resolve_output_filter(
+                    flags,
+                    &command_permission.role,
+                    command,
+                    predicate,
+                    object_types,
+                    scalar_types,
+                    boolean_expression_types,
+                    models,
+                    data_connector_scalars,
+                    subgraph,
+                )
+            })
+            .transpose()?;
+
         let resolved_permission = CommandPermission {
             allow_execution: command_permission.allow_execution,
             argument_presets,
+            argument_predicate,
+            output_filter,
         };
         validated_permissions.insert(command_permission.role.clone(), resolved_permission);
     }
     Ok(validated_permissions)
 }
+
+fn resolve_condition_operator(
+    operator: &open_dds::types::OperatorName,
+) -> Option<FieldConditionOperator> {
+    match operator.as_str() {
+        "_eq" => Some(FieldConditionOperator::Equal),
+        "_neq" => Some(FieldConditionOperator::NotEqual),
+        _ => None,
+    }
+}
+
+fn resolve_argument_predicate(
+    flags: &open_dds::flags::OpenDdFlags,
+    object_types: &BTreeMap<&Qualified<CustomTypeName>, &object_types::ObjectTypeRepresentation>,
+    role: &Role,
+    command: &commands::Command,
+    predicate: &open_dds::permissions::ArgumentPredicate,
+    issues: &mut Vec<CommandPermissionIssue>,
+) -> Result<ArgumentPredicate, CommandPermissionError> {
+    let resolve_argument = |argument_name: &ArgumentName| {
+        command.arguments.get(argument_name).ok_or_else(|| {
+            CommandPermissionError::UnknownArgumentInArgumentPredicate {
+                role: role.clone(),
+                command_name: command.name.clone(),
+                argument_name: argument_name.clone(),
+            }
+        })
+    };
+    let resolve_nested = |predicate: &open_dds::permissions::ArgumentPredicate,
+                          issues: &mut Vec<CommandPermissionIssue>| {
+        resolve_argument_predicate(flags, object_types, role, command, predicate, issues)
+    };
+    match predicate {
+        open_dds::permissions::ArgumentPredicate::ArgumentComparison(
+            open_dds::permissions::ArgumentComparisonPredicate {
+                argument,
+                operator,
+                value,
+            },
+        ) => {
+            let argument_info = resolve_argument(argument)?;
+            let resolved_operator = resolve_condition_operator(operator).ok_or_else(|| {
+                CommandPermissionError::UnsupportedArgumentPredicateOperator {
+                    role: role.clone(),
+                    command_name: command.name.clone(),
+                    operator: operator.clone(),
+                }
+            })?;
+            let new_issues = typecheck::typecheck_value_expression(
+                object_types,
+                &argument_info.argument_type,
+                value,
+            )
+            .map_err(|type_error| {
+                CommandPermissionError::ArgumentPredicateTypeError {
+                    role: role.clone(),
+                    command_name: command.name.clone(),
+                    argument_name: argument.clone(),
+                    type_error,
+                }
+            })?;
+            for issue in new_issues {
+                issues.push(CommandPermissionIssue::ArgumentPredicateTypecheckIssue {
+                    role: role.clone(),
+                    command_name: command.name.clone(),
+                    argument_name: argument.clone(),
+                    typecheck_issue: issue,
+                });
+            }
+            Ok(ArgumentPredicate::ArgumentComparison {
+                argument: argument.clone(),
+                argument_type: argument_info.argument_type.clone(),
+                operator: resolved_operator,
+                value: type_permissions::resolve_value_expression(flags, value),
+            })
+        }
+        open_dds::permissions::ArgumentPredicate::ArgumentIsNull(
+            open_dds::permissions::ArgumentIsNullPredicate { argument },
+        ) => {
+            resolve_argument(argument)?;
+            Ok(ArgumentPredicate::ArgumentIsNull {
+                argument: argument.clone(),
+            })
+        }
+        open_dds::permissions::ArgumentPredicate::And(predicates) => Ok(ArgumentPredicate::And(
+            predicates
+                .iter()
+                .map(|predicate| resolve_nested(predicate, issues))
+                .collect::<Result<_, _>>()?,
+        )),
+        open_dds::permissions::ArgumentPredicate::Or(predicates) => Ok(ArgumentPredicate::Or(
+            predicates
+                .iter()
+                .map(|predicate| resolve_nested(predicate, issues))
+                .collect::<Result<_, _>>()?,
+        )),
+        open_dds::permissions::ArgumentPredicate::Not(predicate) => Ok(ArgumentPredicate::Not(
+            Box::new(resolve_nested(predicate, issues)?),
+        )),
+    }
+}
+
+/// Resolve the output filter of a command permission against the model whose object type
+/// the command returns, in the same way as the filter of a model select permission.
+fn resolve_output_filter(
+    flags: &open_dds::flags::OpenDdFlags,
+    role: &Role,
+    command: &commands::Command,
+    predicate: &ModelPredicate,
+    object_types: &BTreeMap<
+        Qualified<CustomTypeName>,
+        object_relationships::ObjectTypeWithRelationships,
+    >,
+    scalar_types: &BTreeMap<Qualified<CustomTypeName>, scalar_types::ScalarTypeRepresentation>,
+    boolean_expression_types: &boolean_expressions::BooleanExpressionTypes,
+    models: &IndexMap<Qualified<ModelName>, models_graphql::ModelWithGraphql>,
+    data_connector_scalars: &BTreeMap<
+        Qualified<DataConnectorName>,
+        data_connector_scalar_types::DataConnectorScalars,
+    >,
+    subgraph: &SubgraphName,
+) -> Result<CommandOutputFilter, Error> {
+    // the returned objects are selected from a model with the object type of the command
+    let output_type_name = unwrap_custom_type_name(&command.output_type);
+    let mut output_models = models.values().filter(|model| {
+        Some(&model.inner.data_type) == output_type_name
+            && model.inner.source.is_some()
+            && model.inner.arguments.is_empty()
+    });
+    let model = output_models.next().ok_or_else(|| {
+        CommandPermissionError::OutputFilterForNonModelOutputType {
+            role: role.clone(),
+            command_name: command.name.clone(),
+        }
+    })?;
+    if output_models.next().is_some() {
+        return Err(CommandPermissionError::AmbiguousOutputFilterModel {
+            role: role.clone(),
+            command_name: command.name.clone(),
+            type_name: model.inner.data_type.clone(),
+        }
+        .into());
+    }
+    let model_source =
+        model
+            .inner
+            .source
+            .as_ref()
+            .ok_or_else(|| Error::ModelSourceRequiredForPredicate {
+                model_name: model.inner.name.clone(),
+            })?;
+
+    // the returned objects are identified by the fields of the first select unique of the model
+    let unique_identifier = model
+        .graphql_api
+        .select_uniques
+        .first()
+        .and_then(|select_unique| {
+            select_unique
+                .unique_identifier
+                .iter()
+                .map(|(field_name, field)| {
+                    field
+                        .ndc_column
+                        .clone()
+                        .map(|ndc_column| (field_name.clone(), ndc_column))
+                })
+                .collect::<Option<IndexMap<_, _>>>()
+        })
+        .ok_or_else(
+            || CommandPermissionError::OutputFilterModelWithoutUniqueIdentifier {
+                role: role.clone(),
+                command_name: command.name.clone(),
+                model_name: model.inner.name.clone(),
+            },
+        )?;
+
+    let boolean_expression_fields = model
+        .filter_expression_type
+        .as_ref()
+        .and_then(|filter| filter.get_fields(flags));
+
+    let predicate = model_permissions::resolve_model_predicate_with_model(
+        flags,
+        predicate,
+        &model.inner,
+        subgraph,
+        boolean_expression_fields,
+        data_connector_scalars,
+        &model.inner.type_fields,
+        object_types,
+        scalar_types,
+        boolean_expression_types,
+        models,
+    )?;
+
+    Ok(CommandOutputFilter {
+        model_name: model.inner.name.clone(),
+        model_source: model_source.clone(),
+        unique_identifier,
+        predicate,
+    })
+}
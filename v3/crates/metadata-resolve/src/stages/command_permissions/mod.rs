@@ -15,7 +15,10 @@ use crate::types::subgraph::Qualified;
 
 use std::collections::BTreeMap;
 mod types;
-pub use types::{CommandPermissionIssue, CommandPermissionsOutput, CommandWithPermissions};
+pub use types::{
+    ArgumentPredicate, CommandOutputFilter, CommandPermissionError, CommandPermissionIssue,
+    CommandPermissionsOutput, CommandWithPermissions,
+};
 
 /// resolve command permissions
 pub fn resolve(
@@ -1,17 +1,20 @@
 use hasura_authn_core::Role;
 use indexmap::IndexMap;
 use open_dds::commands::CommandName;
+use open_dds::models::ModelName;
 use serde::{Deserialize, Serialize};
 
 use crate::helpers::typecheck;
-use crate::stages::commands;
+use crate::stages::{commands, model_permissions::ModelPredicate, models};
 use crate::types::error::ShouldBeAnError;
-use crate::types::permission::ValueExpressionOrPredicate;
+use crate::types::permission::{ValueExpression, ValueExpressionOrPredicate};
 use crate::types::subgraph::QualifiedTypeReference;
-use crate::Qualified;
+use crate::{FieldConditionOperator, NdcColumnForComparison, Qualified};
 use open_dds::arguments::ArgumentName;
+use open_dds::types::{CustomTypeName, FieldName, OperatorName};
 
 use std::collections::BTreeMap;
+use std::sync::Arc;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct CommandWithPermissions {
@@ -24,6 +27,81 @@ pub struct CommandPermission {
     pub allow_execution: bool,
     pub argument_presets:
         BTreeMap<ArgumentName, (QualifiedTypeReference, ValueExpressionOrPredicate)>,
+    /// Checked on the arguments (after presets are applied) before the command is executed.
+    pub argument_predicate: Option<ArgumentPredicate>,
+    /// Checked on each object returned by the command.
+    pub output_filter: Option<CommandOutputFilter>,
+}
+
+/// A filter on the objects returned by a command. The objects are checked by selecting
+/// them from the model whose object type the command returns, by the unique identifier of
+/// the model, with the filter applied in the same way as a model select permission filter.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CommandOutputFilter {
+    pub model_name: Qualified<ModelName>,
+    pub model_source: Arc<models::ModelSource>,
+    /// The fields that identify an object of the model, with the columns they are
+    /// compared against in the model's collection
+    pub unique_identifier: IndexMap<FieldName, NdcColumnForComparison>,
+    pub predicate: ModelPredicate,
+}
+
+/// A predicate over the arguments of a command, evaluated by the engine before
+/// the command is executed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ArgumentPredicate {
+    ArgumentComparison {
+        argument: ArgumentName,
+        argument_type: QualifiedTypeReference,
+        operator: FieldConditionOperator,
+        value: ValueExpression,
+    },
+    ArgumentIsNull {
+        argument: ArgumentName,
+    },
+    And(Vec<ArgumentPredicate>),
+    Or(Vec<ArgumentPredicate>),
+    Not(Box<ArgumentPredicate>),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CommandPermissionError {
+    #[error("the argument predicate for role {role:} in command {command_name:} refers to an unknown argument {argument_name:}")]
+    UnknownArgumentInArgumentPredicate {
+        role: Role,
+        command_name: Qualified<CommandName>,
+        argument_name: ArgumentName,
+    },
+    #[error("unsupported operator {operator:} in the argument predicate for role {role:} in command {command_name:}; only _eq and _neq are supported")]
+    UnsupportedArgumentPredicateOperator {
+        role: Role,
+        command_name: Qualified<CommandName>,
+        operator: OperatorName,
+    },
+    #[error("Type error in the argument predicate for argument {argument_name:} for role {role:} in command {command_name:}: {type_error:}")]
+    ArgumentPredicateTypeError {
+        role: Role,
+        command_name: Qualified<CommandName>,
+        argument_name: ArgumentName,
+        type_error: typecheck::TypecheckError,
+    },
+    #[error("an output filter is defined for role {role:} in command {command_name:}, but the command does not return the object type of a model without arguments")]
+    OutputFilterForNonModelOutputType {
+        role: Role,
+        command_name: Qualified<CommandName>,
+    },
+    #[error("an output filter is defined for role {role:} in command {command_name:}, but more than one model without arguments has the object type {type_name:} returned by the command")]
+    AmbiguousOutputFilterModel {
+        role: Role,
+        command_name: Qualified<CommandName>,
+        type_name: Qualified<CustomTypeName>,
+    },
+    #[error("an output filter is defined for role {role:} in command {command_name:}, but the model {model_name:} has no select unique to identify the returned objects by")]
+    OutputFilterModelWithoutUniqueIdentifier {
+        role: Role,
+        command_name: Qualified<CommandName>,
+        model_name: Qualified<ModelName>,
+    },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -35,6 +113,13 @@ pub enum CommandPermissionIssue {
         argument_name: ArgumentName,
         typecheck_issue: typecheck::TypecheckIssue,
     },
+    #[error("Type error in the argument predicate for argument {argument_name:} for role {role:} in command {command_name:}: {typecheck_issue:}")]
+    ArgumentPredicateTypecheckIssue {
+        role: Role,
+        command_name: Qualified<CommandName>,
+        argument_name: ArgumentName,
+        typecheck_issue: typecheck::TypecheckIssue,
+    },
 }
 
 impl ShouldBeAnError for CommandPermissionIssue {
@@ -42,6 +127,9 @@ impl ShouldBeAnError for CommandPermissionIssue {
         match self {
             CommandPermissionIssue::CommandArgumentPresetTypecheckIssue {
                 typecheck_issue, ..
+            }
+            | CommandPermissionIssue::ArgumentPredicateTypecheckIssue {
+                typecheck_issue, ..
             } => typecheck_issue.should_be_an_error(flags),
         }
     }
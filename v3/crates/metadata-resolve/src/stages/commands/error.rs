@@ -8,7 +8,7 @@ use open_dds::{
     arguments::ArgumentName,
     commands::{CommandName, FunctionName, ProcedureName},
     data_connector::DataConnectorName,
-    types::{CustomTypeName, TypeReference},
+    types::{CustomTypeName, FieldName, TypeReference},
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -98,6 +98,11 @@ pub enum CommandsError {
         command_name: Qualified<CommandName>,
         error: TypeMappingCollectionError,
     },
+    #[error("the members of the output type of command {command_name:} must all map the discriminator field {discriminator_field:} to the same column")]
+    DiscriminatorColumnMismatch {
+        command_name: Qualified<CommandName>,
+        discriminator_field: FieldName,
+    },
 
     #[error("{0}")]
     DataConnectorError(#[from] data_connectors::NamedDataConnectorError),
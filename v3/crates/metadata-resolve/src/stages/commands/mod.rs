@@ -7,7 +7,9 @@ mod types;
 pub use error::CommandsError;
 
 use crate::helpers::types::TrackGraphQLRootFields;
-use crate::stages::{boolean_expressions, data_connectors, scalar_types, type_permissions};
+use crate::stages::{
+    abstract_types, boolean_expressions, data_connectors, scalar_types, type_permissions,
+};
 use crate::types::subgraph::Qualified;
 use indexmap::IndexMap;
 
@@ -25,6 +27,10 @@ pub fn resolve(
     object_types: &type_permissions::ObjectTypesWithPermissions,
     track_root_fields: &mut TrackGraphQLRootFields,
     scalar_types: &BTreeMap<Qualified<CustomTypeName>, scalar_types::ScalarTypeRepresentation>,
+    abstract_types: &BTreeMap<
+        Qualified<CustomTypeName>,
+        abstract_types::AbstractTypeRepresentation,
+    >,
     boolean_expression_types: &boolean_expressions::BooleanExpressionTypes,
 ) -> Result<CommandsOutput, CommandsError> {
     let mut commands: IndexMap<Qualified<CommandName>, Command> = IndexMap::new();
@@ -52,6 +58,7 @@ pub fn resolve(
                 data_connectors,
                 object_types,
                 scalar_types,
+                abstract_types,
                 boolean_expression_types,
             )?;
             resolved_command.source = Some(Arc::new(command_source));
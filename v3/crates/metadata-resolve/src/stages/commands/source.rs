@@ -3,7 +3,10 @@ use std::sync::Arc;
 use crate::helpers::argument::{get_argument_mappings, ArgumentMappingResults};
 use crate::helpers::ndc_validation::{self};
 use crate::helpers::types::{object_type_exists, unwrap_custom_type_name};
-use crate::stages::{boolean_expressions, data_connectors, scalar_types, type_permissions};
+use crate::stages::{
+    abstract_types, boolean_expressions, data_connectors, object_types, scalar_types,
+    type_permissions,
+};
 use crate::types::subgraph::Qualified;
 
 use super::types::CommandsIssue;
@@ -30,6 +33,10 @@ pub fn resolve_command_source(
     data_connectors: &data_connectors::DataConnectors,
     object_types: &type_permissions::ObjectTypesWithPermissions,
     scalar_types: &BTreeMap<Qualified<CustomTypeName>, scalar_types::ScalarTypeRepresentation>,
+    abstract_types: &BTreeMap<
+        Qualified<CustomTypeName>,
+        abstract_types::AbstractTypeRepresentation,
+    >,
     boolean_expression_types: &boolean_expressions::BooleanExpressionTypes,
 ) -> Result<(CommandSource, Vec<CommandsIssue>), CommandsError> {
     if command.source.is_some() {
@@ -161,46 +168,60 @@ pub fn resolve_command_source(
         })
         .collect();
 
-    // get object type name if it exists for the output type, and refers to a valid object
-    let command_result_base_object_type_name = unwrap_custom_type_name(&command.output_type)
-        .and_then(|custom_type_name| object_type_exists(custom_type_name, object_types).ok());
+    // get the union or interface type of the output type, if it refers to one
+    let command_result_abstract_type = unwrap_custom_type_name(&command.output_type)
+        .and_then(|custom_type_name| abstract_types.get(custom_type_name));
 
-    let mut type_mappings = BTreeMap::new();
+    // get object type names of the output type: either the output type itself, if it refers to
+    // a valid object, or the members of the union or interface type it refers to
+    let command_result_base_object_type_names = match command_result_abstract_type {
+        Some(abstract_type) => abstract_type
+            .discrimination
+            .members
+            .iter()
+            .map(|member| member.object_type.clone())
+            .collect(),
+        None => unwrap_custom_type_name(&command.output_type)
+            .and_then(|custom_type_name| object_type_exists(custom_type_name, object_types).ok())
+            .into_iter()
+            .collect::<Vec<_>>(),
+    };
 
-    // Get the type mapping to resolve for the result type
-    let source_result_type_mapping_to_resolve = command_result_base_object_type_name
-        .as_ref()
-        .map(|custom_type_name| {
-            // Get the corresponding object_type (data_connector.object_type) associated with the result_type for the source
-            let source_result_type_name =
-                ndc_validation::get_underlying_named_type(&command_source_response.result_type);
+    let mut type_mappings = BTreeMap::new();
 
-            let source_result_type_mapping_to_resolve = type_mappings::TypeMappingToCollect {
-                type_name: custom_type_name,
-                ndc_object_type_name: source_result_type_name,
-            };
+    // Get the corresponding object_type (data_connector.object_type) associated with the result_type for the source
+    let source_result_type_name =
+        ndc_validation::get_underlying_named_type(&command_source_response.result_type);
 
-            Ok::<_, CommandsError>(source_result_type_mapping_to_resolve)
+    // Get the type mappings to resolve for the result type. All the members of a union or
+    // interface type are read from the same ndc object type.
+    let source_result_type_mappings_to_resolve = command_result_base_object_type_names
+        .iter()
+        .map(|custom_type_name| type_mappings::TypeMappingToCollect {
+            type_name: custom_type_name,
+            ndc_object_type_name: source_result_type_name,
         })
-        .transpose()?;
+        .collect::<Vec<_>>();
 
     // Get the ndc object type from the source result type name
-    let ndc_object_type = source_result_type_mapping_to_resolve
-        .as_ref()
-        .map(|type_mapping_to_resolve| {
-            let ndc_type_name = type_mapping_to_resolve.ndc_object_type_name;
+    let ndc_object_type = if source_result_type_mappings_to_resolve.is_empty() {
+        None
+    } else {
+        Some(
             data_connector_context
                 .schema
                 .object_types
-                .get(ndc_type_name)
+                .get(source_result_type_name)
                 .ok_or_else(|| CommandsError::CommandTypeMappingCollectionError {
                     command_name: command.name.clone(),
                     error: type_mappings::TypeMappingCollectionError::NDCValidationError(
-                        crate::NDCValidationError::NoSuchType(ndc_type_name.as_str().to_owned()),
+                        crate::NDCValidationError::NoSuchType(
+                            source_result_type_name.as_str().to_owned(),
+                        ),
                     ),
-                })
-        })
-        .transpose()?;
+                })?,
+        )
+    };
 
     let special_case = data_connector_context
         .response_headers
@@ -213,7 +234,7 @@ pub fn resolve_command_source(
             },
         );
 
-    for type_mapping_to_collect in source_result_type_mapping_to_resolve
+    for type_mapping_to_collect in source_result_type_mappings_to_resolve
         .iter()
         .chain(argument_type_mappings_to_resolve.iter())
     {
@@ -231,6 +252,10 @@ pub fn resolve_command_source(
         })?;
     }
 
+    if let Some(abstract_type) = command_result_abstract_type {
+        validate_discriminator_column(command, abstract_type, &type_mappings)?;
+    }
+
     let mut command_source = CommandSource {
         data_connector: data_connectors::DataConnectorLink::new(
             qualified_data_connector_name,
@@ -250,6 +275,7 @@ pub fn resolve_command_source(
         &command.name,
         &command_source,
         &command.output_type,
+        abstract_types,
         &data_connector_context.schema,
         commands_response_config,
     )?;
@@ -258,3 +284,34 @@ pub fn resolve_command_source(
 
     Ok((command_source, issues))
 }
+
+/// The members of a union or interface type output by a command are selected by the value of
+/// the discriminator field, so they must all read it from the same column.
+fn validate_discriminator_column(
+    command: &Command,
+    abstract_type: &abstract_types::AbstractTypeRepresentation,
+    type_mappings: &BTreeMap<Qualified<CustomTypeName>, object_types::TypeMapping>,
+) -> Result<(), CommandsError> {
+    let discrimination = &abstract_type.discrimination;
+    let mut discriminator_column = None;
+    for member in &discrimination.members {
+        let Some(object_types::TypeMapping::Object { field_mappings, .. }) =
+            type_mappings.get(&member.object_type)
+        else {
+            continue;
+        };
+        if let Some(field_mapping) = field_mappings.get(&discrimination.discriminator_field) {
+            match discriminator_column {
+                None => discriminator_column = Some(&field_mapping.column),
+                Some(column) if column == &field_mapping.column => {}
+                Some(_) => {
+                    return Err(CommandsError::DiscriminatorColumnMismatch {
+                        command_name: command.name.clone(),
+                        discriminator_field: discrimination.discriminator_field.clone(),
+                    })
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -18,6 +18,13 @@ pub enum DataConnectorScalarTypesError {
         scalar_type: DataConnectorScalarType,
         type_name: Qualified<CustomTypeName>,
     },
+    #[error("the value {value} of the enum type {type_name} is not one of the values of scalar {scalar_type} from data connector {data_connector}")]
+    EnumValueNotInDataConnectorScalar {
+        type_name: Qualified<CustomTypeName>,
+        value: String,
+        data_connector: Qualified<DataConnectorName>,
+        scalar_type: DataConnectorScalarType,
+    },
     #[error("{0}")]
     GraphqlConfigError(#[from] graphql_config::GraphqlConfigError),
     #[error("{0}")]
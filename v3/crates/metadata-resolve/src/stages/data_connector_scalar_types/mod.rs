@@ -91,8 +91,19 @@ pub fn resolve<'a>(
                     .get(&ndc_scalar_name)
                     .unwrap();
 
+                let qualified_custom_type_name =
+                    Qualified::new(subgraph.clone(), custom_type_name.clone());
+
+                validate_enum_values(
+                    &qualified_custom_type_name,
+                    scalar_types,
+                    &ndc_scalar_type.representation,
+                    &qualified_data_connector_name,
+                    scalar_type_name,
+                )?;
+
                 scalars.by_custom_type_name.insert(
-                    Qualified::new(subgraph.clone(), custom_type_name.clone()),
+                    qualified_custom_type_name,
                     ndc_scalar_type.representation.clone(),
                 );
             }
@@ -144,6 +155,39 @@ fn validate_type_name(
     Ok(())
 }
 
+// an enum type can only represent a data connector scalar that is itself an enum if every value of
+// the enum type is one of the values of the data connector scalar
+fn validate_enum_values(
+    type_name: &Qualified<CustomTypeName>,
+    scalar_types: &BTreeMap<Qualified<CustomTypeName>, scalar_types::ScalarTypeRepresentation>,
+    ndc_representation: &ndc_models::TypeRepresentation,
+    data_connector_name: &Qualified<DataConnectorName>,
+    scalar_type_name: &DataConnectorScalarType,
+) -> Result<(), DataConnectorScalarTypesError> {
+    let Some(enum_values) = scalar_types
+        .get(type_name)
+        .and_then(|scalar_type| scalar_type.enum_values.as_ref())
+    else {
+        return Ok(());
+    };
+    if let ndc_models::TypeRepresentation::Enum { one_of } = ndc_representation {
+        if let Some(enum_value) = enum_values
+            .iter()
+            .find(|enum_value| !one_of.contains(&enum_value.value))
+        {
+            return Err(
+                DataConnectorScalarTypesError::EnumValueNotInDataConnectorScalar {
+                    type_name: type_name.clone(),
+                    value: enum_value.value.clone(),
+                    data_connector: data_connector_name.clone(),
+                    scalar_type: scalar_type_name.clone(),
+                },
+            );
+        }
+    }
+    Ok(())
+}
+
 // convert from types in previous stage to this stage
 fn convert_data_connectors_contexts<'a>(
     old_data_connectors: &'a data_connectors::DataConnectors<'a>,
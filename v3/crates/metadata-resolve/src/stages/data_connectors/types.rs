@@ -463,6 +463,11 @@ pub struct DataConnectorCapabilities {
     #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
     pub supports_nested_object_ordering: bool,
 
+    /// Whether or not ordering with nulls placed first or last is supported
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub supports_order_by_nulls: bool,
+
     /// Whether not filtering using 'exists' over nested object arrays is supported
     #[serde(default = "serde_ext::ser_default")]
     #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
@@ -506,6 +511,11 @@ pub struct DataConnectorAggregateCapabilities {
     #[serde(default = "serde_ext::ser_default")]
     #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
     pub supports_grouping: Option<DataConnectorGroupingCapabilities>,
+
+    /// Whether filtering by the results of aggregates over array relationships is supported
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub supports_filtering_by_aggregates: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -523,6 +533,11 @@ pub struct DataConnectorRelationshipCapabilities {
     #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
     pub supports_relation_comparisons: bool,
 
+    /// Whether or not ordering by an aggregate over an array relationship is supported
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub supports_order_by_aggregate: bool,
+
     /// Whether or not relationships can start from or end with columns in nested objects. Implies support in field selection.
     #[serde(default = "serde_ext::ser_default")]
     #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
@@ -556,6 +571,8 @@ fn mk_ndc_01_capabilities(
         supports_explaining_mutations: capabilities.mutation.explain.is_some(),
         supports_nested_object_filtering: capabilities.query.nested_fields.filter_by.is_some(),
         supports_nested_object_ordering: capabilities.query.nested_fields.order_by.is_some(),
+        // Placing nulls first or last cannot be expressed in NDC order by directions
+        supports_order_by_nulls: false,
         supports_nested_object_array_filtering: capabilities
             .query
             .exists
@@ -572,12 +589,14 @@ fn mk_ndc_01_capabilities(
                     .is_some(),
                 aggregate_count_scalar_type: None,
                 supports_grouping: None,
+                supports_filtering_by_aggregates: false,
             }
         }),
         supports_query_variables: capabilities.query.variables.is_some(),
         supports_relationships: capabilities.relationships.as_ref().map(|rel| {
             DataConnectorRelationshipCapabilities {
                 supports_relation_comparisons: rel.relation_comparisons.is_some(),
+                supports_order_by_aggregate: rel.order_by_aggregate.is_some(),
                 // Selection of nested relationships is assumed supported in NDC 0.1.x
                 supports_nested_relationships: Some(DataConnectorNestedRelationshipCapabilities {
                     supports_nested_array_selection: true,
@@ -600,6 +619,8 @@ fn mk_ndc_02_capabilities(
         supports_explaining_mutations: capabilities.mutation.explain.is_some(),
         supports_nested_object_filtering: capabilities.query.nested_fields.filter_by.is_some(),
         supports_nested_object_ordering: capabilities.query.nested_fields.order_by.is_some(),
+        // Placing nulls first or last cannot be expressed in NDC order by directions
+        supports_order_by_nulls: false,
         supports_nested_object_array_filtering: capabilities
             .query
             .exists
@@ -632,12 +653,14 @@ fn mk_ndc_02_capabilities(
                         supports_pagination: groupby_capabilities.paginate.is_some(),
                     }
                 }),
+                supports_filtering_by_aggregates: aggregates.filter_by.is_some(),
             }
         }),
         supports_query_variables: capabilities.query.variables.is_some(),
         supports_relationships: capabilities.relationships.as_ref().map(|rel| {
             DataConnectorRelationshipCapabilities {
                 supports_relation_comparisons: rel.relation_comparisons.is_some(),
+                supports_order_by_aggregate: rel.order_by_aggregate.is_some(),
                 supports_nested_relationships: rel.nested.as_ref().map(|n| {
                     DataConnectorNestedRelationshipCapabilities {
                         supports_nested_array_selection: n.array.is_some(),
@@ -709,6 +732,7 @@ mod tests {
             supports_explaining_mutations: false,
             supports_nested_object_filtering: false,
             supports_nested_object_ordering: false,
+            supports_order_by_nulls: false,
             supports_nested_object_array_filtering: false,
             supports_nested_scalar_array_filtering: false,
             supports_aggregates: None,
@@ -760,6 +784,7 @@ mod tests {
             supports_explaining_mutations: false,
             supports_nested_object_filtering: false,
             supports_nested_object_ordering: false,
+            supports_order_by_nulls: false,
             supports_nested_object_array_filtering: false,
             supports_nested_scalar_array_filtering: false,
             supports_aggregates: None,
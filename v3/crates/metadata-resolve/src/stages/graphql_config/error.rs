@@ -18,6 +18,16 @@ pub enum GraphqlConfigError {
     MissingOrderByEnumTypeNamesInGraphqlConfig,
     #[error("only one enumTypeNames can be defined in GraphqlConfig, whose direction values are both 'asc' and 'desc'.")]
     MultipleOrderByEnumTypeNamesInGraphqlConfig,
+    #[error("one of the enumTypeNames in orderByInput of GraphqlConfig must have only the 'asc' and 'desc' directions")]
+    MissingDefaultOrderByEnumTypeNameInGraphqlConfig,
+    #[error(
+        "multiple enumTypeNames in orderByInput of GraphqlConfig have the directions: {directions}"
+    )]
+    DuplicateOrderByEnumTypeDirections { directions: String },
+    #[error("the direction {direction} is used in the enumTypeNames of orderByInput in GraphqlConfig, but has no name in enumDirectionValues")]
+    MissingOrderByDirectionValue {
+        direction: open_dds::graphql_config::OrderByDirection,
+    },
     #[error(
             "invalid directions: {directions} defined in orderByInput of GraphqlConfig , currently there is no support for partial directions. Please specify a type which has both 'asc' and 'desc' directions"
         )]
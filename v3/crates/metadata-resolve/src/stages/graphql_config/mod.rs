@@ -3,12 +3,13 @@
 mod error;
 mod types;
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::OnceLock;
 
 use lang_graphql::ast::common as ast;
 use open_dds::accessor::QualifiedObject;
 use open_dds::graphql_config::{self, OrderByDirection};
+use open_dds::models;
 use open_dds::types::{GraphQlFieldName, GraphQlTypeName};
 
 use crate::helpers::types::mk_name;
@@ -16,7 +17,7 @@ pub use error::GraphqlConfigError;
 pub use types::{
     AggregateGraphqlConfig, FilterInputGraphqlConfig, FilterInputOperatorNames,
     GlobalGraphqlConfig, GraphqlConfig, GraphqlTypeNames, MultipleOrderByInputObjectFields,
-    OrderByInputGraphqlConfig, QueryGraphqlConfig,
+    OrderByInputGraphqlConfig, OrderByNullsEnumType, QueryGraphqlConfig,
 };
 
 /// Resolve and validate the GraphQL configuration.
@@ -124,50 +125,12 @@ pub fn resolve_graphql_config(
                 })
                 .transpose()?;
 
-            let order_by_input = match &graphql_config_metadata.query.order_by_input {
-                None => None,
-                Some(order_by_input) => {
-                    let order_by_enum_type_name = match order_by_input.enum_type_names.as_slice() {
-                        [] => Err(GraphqlConfigError::MissingOrderByEnumTypeNamesInGraphqlConfig),
-                        [order_by_enum_type] => Ok({
-                            // TODO: Naveen: Currently we do not allow enabling a specific direction
-                            // for orderableField. In future when we support this, we would like to
-                            // build different enum types for different variations of directions.
-                            let input_directions = order_by_enum_type
-                                .directions
-                                .iter()
-                                .copied()
-                                .collect::<HashSet<_>>();
-                            if input_directions
-                                == HashSet::from([OrderByDirection::Asc, OrderByDirection::Desc])
-                            {
-                                mk_name(order_by_enum_type.type_name.as_str())
-                            } else {
-                                let invalid_directions = order_by_enum_type
-                                    .directions
-                                    .iter()
-                                    .map(std::string::ToString::to_string)
-                                    .collect::<Vec<_>>()
-                                    .join(",");
-                                Err(GraphqlConfigError::InvalidOrderByDirection {
-                                    directions: invalid_directions,
-                                })
-                            }
-                        }),
-                        _ => Err(GraphqlConfigError::MultipleOrderByEnumTypeNamesInGraphqlConfig),
-                    }?;
-
-                    Some(OrderByInputGraphqlConfig {
-                        asc_direction_field_value: mk_name(
-                            order_by_input.enum_direction_values.asc.as_str(),
-                        )?,
-                        desc_direction_field_value: mk_name(
-                            order_by_input.enum_direction_values.desc.as_str(),
-                        )?,
-                        enum_type_name: ast::TypeName(order_by_enum_type_name?),
-                    })
-                }
-            };
+            let order_by_input = graphql_config_metadata
+                .query
+                .order_by_input
+                .as_ref()
+                .map(resolve_order_by_input)
+                .transpose()?;
 
             let aggregate_config = graphql_config_metadata
                 .query
@@ -224,6 +187,124 @@ pub fn resolve_graphql_config(
     }
 }
 
+fn resolve_order_by_input(
+    order_by_input: &graphql_config::OrderByInputGraphqlConfig,
+) -> Result<OrderByInputGraphqlConfig, GraphqlConfigError> {
+    let direction_values = &order_by_input.enum_direction_values;
+    let mut nulls_direction_field_values = BTreeMap::new();
+    for (direction, field_value) in [
+        (
+            models::OrderByDirection::AscNullsFirst,
+            &direction_values.asc_nulls_first,
+        ),
+        (
+            models::OrderByDirection::AscNullsLast,
+            &direction_values.asc_nulls_last,
+        ),
+        (
+            models::OrderByDirection::DescNullsFirst,
+            &direction_values.desc_nulls_first,
+        ),
+        (
+            models::OrderByDirection::DescNullsLast,
+            &direction_values.desc_nulls_last,
+        ),
+    ] {
+        if let Some(field_value) = field_value {
+            nulls_direction_field_values.insert(direction, mk_name(field_value.as_str())?);
+        }
+    }
+
+    if order_by_input.enum_type_names.is_empty() {
+        return Err(GraphqlConfigError::MissingOrderByEnumTypeNamesInGraphqlConfig);
+    }
+
+    // Each enum type must have both the 'Asc' and 'Desc' directions. The type with only those
+    // is used by default, and those that also place nulls first or last are used for fields
+    // that enable the same set of directions
+    let mut enum_type_name = None;
+    let mut nulls_enum_types = Vec::<OrderByNullsEnumType>::new();
+    for order_by_enum_type in &order_by_input.enum_type_names {
+        let directions = order_by_enum_type
+            .directions
+            .iter()
+            .map(resolve_order_by_direction)
+            .collect::<BTreeSet<_>>();
+
+        if !(directions.contains(&models::OrderByDirection::Asc)
+            && directions.contains(&models::OrderByDirection::Desc))
+        {
+            let invalid_directions = order_by_enum_type
+                .directions
+                .iter()
+                .map(std::string::ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+            return Err(GraphqlConfigError::InvalidOrderByDirection {
+                directions: invalid_directions,
+            });
+        }
+
+        // Directions that place nulls first or last have no default name
+        for direction in &order_by_enum_type.directions {
+            let resolved_direction = resolve_order_by_direction(direction);
+            if resolved_direction.specifies_nulls_order()
+                && !nulls_direction_field_values.contains_key(&resolved_direction)
+            {
+                return Err(GraphqlConfigError::MissingOrderByDirectionValue {
+                    direction: *direction,
+                });
+            }
+        }
+
+        let type_name = ast::TypeName(mk_name(order_by_enum_type.type_name.as_str())?);
+        if directions
+            .iter()
+            .any(models::OrderByDirection::specifies_nulls_order)
+        {
+            if nulls_enum_types
+                .iter()
+                .any(|enum_type| enum_type.directions == directions)
+            {
+                return Err(GraphqlConfigError::DuplicateOrderByEnumTypeDirections {
+                    directions: order_by_enum_type
+                        .directions
+                        .iter()
+                        .map(std::string::ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(","),
+                });
+            }
+            nulls_enum_types.push(OrderByNullsEnumType {
+                directions,
+                type_name,
+            });
+        } else if enum_type_name.replace(type_name).is_some() {
+            return Err(GraphqlConfigError::MultipleOrderByEnumTypeNamesInGraphqlConfig);
+        }
+    }
+
+    Ok(OrderByInputGraphqlConfig {
+        asc_direction_field_value: mk_name(direction_values.asc.as_str())?,
+        desc_direction_field_value: mk_name(direction_values.desc.as_str())?,
+        nulls_direction_field_values,
+        enum_type_name: enum_type_name
+            .ok_or(GraphqlConfigError::MissingDefaultOrderByEnumTypeNameInGraphqlConfig)?,
+        nulls_enum_types,
+    })
+}
+
+fn resolve_order_by_direction(direction: &OrderByDirection) -> models::OrderByDirection {
+    match direction {
+        OrderByDirection::Asc => models::OrderByDirection::Asc,
+        OrderByDirection::Desc => models::OrderByDirection::Desc,
+        OrderByDirection::AscNullsFirst => models::OrderByDirection::AscNullsFirst,
+        OrderByDirection::AscNullsLast => models::OrderByDirection::AscNullsLast,
+        OrderByDirection::DescNullsFirst => models::OrderByDirection::DescNullsFirst,
+        OrderByDirection::DescNullsLast => models::OrderByDirection::DescNullsLast,
+    }
+}
+
 fn fallback_graphql_config() -> &'static graphql_config::GraphqlConfig {
     static CELL: OnceLock<graphql_config::GraphqlConfig> = OnceLock::new();
     CELL.get_or_init(|| {
@@ -253,6 +334,10 @@ fn fallback_graphql_config() -> &'static graphql_config::GraphqlConfig {
                     enum_direction_values: graphql_config::OrderByDirectionValues {
                         asc: GraphQlFieldName::from("Asc"),
                         desc: GraphQlFieldName::from("Desc"),
+                        asc_nulls_first: None,
+                        asc_nulls_last: None,
+                        desc_nulls_first: None,
+                        desc_nulls_last: None,
                     },
                     enum_type_names: vec![graphql_config::OrderByEnumTypeName {
                         type_name: GraphQlTypeName::from("order_by"),
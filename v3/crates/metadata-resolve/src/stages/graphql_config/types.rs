@@ -1,7 +1,8 @@
 use super::error::GraphqlConfigError;
 use lang_graphql::ast::common as ast;
+use open_dds::models::{EnableAllOrSpecific, OrderByDirection};
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct GraphqlConfig {
@@ -42,7 +43,56 @@ pub enum MultipleOrderByInputObjectFields {
 pub struct OrderByInputGraphqlConfig {
     pub asc_direction_field_value: ast::Name,
     pub desc_direction_field_value: ast::Name,
+    /// The names of the directions that place nulls first or last, where configured
+    pub nulls_direction_field_values: BTreeMap<OrderByDirection, ast::Name>,
+    /// The enum type with only the `Asc` and `Desc` directions
     pub enum_type_name: ast::TypeName,
+    /// The enum types whose directions include placing nulls first or last
+    pub nulls_enum_types: Vec<OrderByNullsEnumType>,
+}
+
+impl OrderByInputGraphqlConfig {
+    /// The name of the enum value for the given direction, if it has one
+    pub fn direction_field_value(&self, direction: &OrderByDirection) -> Option<&ast::Name> {
+        match direction {
+            OrderByDirection::Asc => Some(&self.asc_direction_field_value),
+            OrderByDirection::Desc => Some(&self.desc_direction_field_value),
+            OrderByDirection::AscNullsFirst
+            | OrderByDirection::AscNullsLast
+            | OrderByDirection::DescNullsFirst
+            | OrderByDirection::DescNullsLast => self.nulls_direction_field_values.get(direction),
+        }
+    }
+
+    /// The enum type used to order by a field that can be ordered in the given directions
+    pub fn enum_type_name_for_directions(
+        &self,
+        directions: &EnableAllOrSpecific<OrderByDirection>,
+    ) -> Option<&ast::TypeName> {
+        match directions {
+            EnableAllOrSpecific::EnableAll(_) => Some(&self.enum_type_name),
+            EnableAllOrSpecific::EnableSpecific(directions) => {
+                if directions
+                    .iter()
+                    .any(OrderByDirection::specifies_nulls_order)
+                {
+                    let directions = directions.iter().cloned().collect::<BTreeSet<_>>();
+                    self.nulls_enum_types
+                        .iter()
+                        .find(|enum_type| enum_type.directions == directions)
+                        .map(|enum_type| &enum_type.type_name)
+                } else {
+                    Some(&self.enum_type_name)
+                }
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct OrderByNullsEnumType {
+    pub directions: BTreeSet<OrderByDirection>,
+    pub type_name: ast::TypeName,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
@@ -1,3 +1,4 @@
+pub mod abstract_types;
 pub mod aggregate_boolean_expressions;
 pub mod aggregates;
 pub mod apollo;
@@ -18,6 +19,7 @@ pub mod order_by_expressions;
 pub mod plugins;
 pub mod relationships;
 pub mod relay;
+pub mod role_inheritance;
 pub mod roles;
 pub mod scalar_boolean_expressions;
 pub mod scalar_type_representations;
@@ -59,9 +61,14 @@ fn resolve_internal(
     // Create a empty tracked root fields
     let mut track_root_fields = TrackGraphQLRootFields::new();
 
-    let metadata_accessor: open_dds::accessor::MetadataAccessor =
+    let mut metadata_accessor: open_dds::accessor::MetadataAccessor =
         open_dds::accessor::MetadataAccessor::new(metadata);
 
+    // Expand permissions with those inherited from parent roles, so that the permission
+    // stages below see the effective permissions of every role.
+    let role_inheritance_issues = role_inheritance::resolve(&mut metadata_accessor)?;
+    all_issues.extend(role_inheritance_issues.into_iter().map(Warning::from));
+
     // The graphql config represents the shape of the Hasura features in the graphql schema,
     // and which features should be enabled or disabled. We check this structure is valid.
     let graphql_config =
@@ -107,6 +114,14 @@ fn resolve_internal(
 
     all_issues.extend(issues.into_iter().map(Warning::from));
 
+    // Validate union and interface types, whose members are the object types above
+    let abstract_types::AbstractTypesOutput { abstract_types } = abstract_types::resolve(
+        &metadata_accessor,
+        &object_types,
+        &scalar_types,
+        &mut graphql_types,
+    )?;
+
     // Validate scalar `BooleanExpressionType`s
     let scalar_boolean_expressions::ScalarBooleanExpressionsOutput {
         boolean_expression_scalar_types,
@@ -152,7 +167,6 @@ fn resolve_internal(
         object_aggregates,
         issues,
     } = aggregate_boolean_expressions::resolve(
-        &configuration.unstable_features,
         &metadata_accessor,
         &boolean_expression_scalar_types,
         &aggregate_expressions,
@@ -192,6 +206,8 @@ fn resolve_internal(
         &object_types_with_permissions,
         &relationships,
         &scalar_types,
+        &aggregate_expressions,
+        &graphql_config,
         &mut graphql_types,
     )?;
 
@@ -223,6 +239,7 @@ fn resolve_internal(
         &object_types_with_permissions,
         &mut track_root_fields,
         &scalar_types,
+        &abstract_types,
         &boolean_expression_types,
     )?;
 
@@ -241,6 +258,7 @@ fn resolve_internal(
         &commands,
         &aggregate_expressions,
         &graphql_config,
+        &metadata_accessor.flags,
     )?;
 
     // now we know about relationships, we can check our arguments (particularly, any
@@ -263,12 +281,14 @@ fn resolve_internal(
         issues,
     } = models_graphql::resolve(
         &metadata_accessor,
+        &data_connectors,
         &models,
         &object_types_with_relationships,
         &boolean_expression_types,
         &mut track_root_fields,
         &graphql_config,
         &scalar_types,
+        &aggregate_expressions,
         &mut order_by_expressions,
         &mut graphql_types,
     )?;
@@ -329,6 +349,7 @@ fn resolve_internal(
         Metadata {
             scalar_types: scalar_types_with_representations,
             object_types: object_types_with_relationships,
+            abstract_types,
             models: models_with_permissions,
             commands: commands_with_permissions,
             boolean_expression_types,
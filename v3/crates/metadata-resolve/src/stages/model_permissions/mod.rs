@@ -11,7 +11,9 @@ pub use types::{
     ModelTargetSource, ModelWithPermissions, SelectPermission, UnaryComparisonOperator,
 };
 mod model_permission;
-pub(crate) use model_permission::resolve_model_predicate_with_type;
+pub(crate) use model_permission::{
+    resolve_model_predicate_with_model, resolve_model_predicate_with_type,
+};
 
 use crate::types::error::Error;
 
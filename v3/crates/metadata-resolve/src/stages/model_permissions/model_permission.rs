@@ -30,7 +30,7 @@ use open_dds::{
 use open_dds::{data_connector::DataConnectorName, models::ModelName, types::CustomTypeName};
 use std::collections::BTreeMap;
 
-fn resolve_model_predicate_with_model(
+pub(crate) fn resolve_model_predicate_with_model(
     flags: &open_dds::flags::OpenDdFlags,
     model_predicate: &open_dds::permissions::ModelPredicate,
     model: &models::Model,
@@ -238,6 +238,8 @@ pub fn resolve_model_select_permissions(
                 filter: resolved_predicate.clone(),
                 argument_presets,
                 allow_subscriptions: select.allow_subscriptions,
+                limit: select.limit,
+                allow_aggregations: select.allow_aggregations,
             };
             validated_permissions.insert(model_permission.role.clone(), resolved_permission);
         }
@@ -444,8 +446,41 @@ pub(crate) fn resolve_model_predicate_with_type(
                             relationship_type,
                             target_typename,
                             mappings,
+                            value_mappings,
+                            argument_mappings,
                             relationship_aggregate: _,
+                            connection_field_name: _,
                         } = model_relationship_target.as_ref();
+                        if !value_mappings.is_empty() {
+                            return Err(Error::TypePredicateError {
+                                type_predicate_error:
+                                    TypePredicateError::RelationshipWithValueMappingsInTypePredicate {
+                                        relationship_name: name.clone(),
+                                        type_name: type_name.clone(),
+                                    },
+                            });
+                        }
+                        if !argument_mappings.is_empty() {
+                            return Err(Error::TypePredicateError {
+                                type_predicate_error:
+                                    TypePredicateError::RelationshipWithArgumentMappingsInTypePredicate {
+                                        relationship_name: name.clone(),
+                                        type_name: type_name.clone(),
+                                    },
+                            });
+                        }
+                        if mappings.iter().any(|mapping| {
+                            !mapping.source_nested_fields.is_empty()
+                                || !mapping.target_nested_fields.is_empty()
+                        }) {
+                            return Err(Error::TypePredicateError {
+                                type_predicate_error:
+                                    TypePredicateError::RelationshipWithNestedFieldPathsInTypePredicate {
+                                        relationship_name: name.clone(),
+                                        type_name: type_name.clone(),
+                                    },
+                            });
+                        }
                         let target_model = models.get(model_name).ok_or_else(|| {
                             Error::TypePredicateError { type_predicate_error: TypePredicateError::UnknownModelUsedInRelationshipTypePredicate {
                                 type_name: type_name.clone(),
@@ -455,26 +490,9 @@ pub(crate) fn resolve_model_predicate_with_type(
                         })?;
 
                         if let Some(target_model_source) = &target_model.inner.source {
-                            // Only relationships targetting the models within the same subgraph are allowed
-                            if target_model_source.data_connector.name.subgraph
-                                != data_connector_link.name.subgraph
-                            {
-                                return Err(Error::TypePredicateError {
-                                    type_predicate_error:
-                                        TypePredicateError::RelationshipAcrossSubgraphs {
-                                            relationship_name: name.clone(),
-                                            source_data_connector: data_connector_link.name.clone(),
-                                            target_data_connector: target_model_source
-                                                .data_connector
-                                                .name
-                                                .clone(),
-                                        },
-                                });
-                            } else if
-                            // If this is a remote relationship predicate
-                            target_model_source.data_connector.name.name
-                                != data_connector_link.name.name
-                            {
+                            // If this is a remote relationship predicate, either because the target
+                            // model uses another data connector or because it lives in another subgraph
+                            if target_model_source.data_connector.name != data_connector_link.name {
                                 // Check for the presence of a equality operator on source field mapped NDC columns
                                 // Reject at build time if no equality operator is found for any source field
                                 // in the remote relationship mapping
@@ -626,7 +644,8 @@ pub(crate) fn resolve_model_predicate_with_type(
                                 target_boolean_expression_fields.as_ref(),
                                 target_data_connector_field_mappings,
                                 &target_source.model.data_connector,
-                                subgraph,
+                                // the target model may live in another subgraph
+                                &model_name.subgraph,
                                 scalars,
                                 object_types,
                                 scalar_types,
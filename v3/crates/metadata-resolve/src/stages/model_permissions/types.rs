@@ -42,10 +42,12 @@ pub enum FilterPermission {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct SelectPermission {
     pub filter: FilterPermission,
-    // pub allow_aggregations: bool,
     pub argument_presets:
         BTreeMap<ArgumentName, (QualifiedTypeReference, ValueExpressionOrPredicate)>,
     pub allow_subscriptions: bool,
+    /// The maximum number of rows the role can select, if any
+    pub limit: Option<u32>,
+    pub allow_aggregations: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
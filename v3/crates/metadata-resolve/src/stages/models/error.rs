@@ -12,6 +12,7 @@ use open_dds::{
     data_connector::{CollectionName, DataConnectorName, DataConnectorScalarType},
     models::ModelName,
     order_by_expression::OrderByExpressionName,
+    relationships::RelationshipName,
     spanned::Spanned,
     types::{CustomTypeName, FieldName},
 };
@@ -113,6 +114,33 @@ pub enum ModelsError {
         order_by_expression_type: Qualified<CustomTypeName>,
     },
 
+    #[error("the aggregate expression {aggregate_expression} used to order by the relationship {relationship_name} in order by expression {order_by_expression_identifier} has the operand type {aggregate_operand_type}, which does not match the type {target_model_type} of the relationship's target model {target_model_name}")]
+    OrderableRelationshipAggregateExpressionTypeMismatch {
+        order_by_expression_identifier:
+            Qualified<order_by_expressions::OrderByExpressionIdentifier>,
+        relationship_name: RelationshipName,
+        aggregate_expression: Qualified<AggregateExpressionName>,
+        aggregate_operand_type: QualifiedTypeName,
+        target_model_name: Qualified<ModelName>,
+        target_model_type: Qualified<CustomTypeName>,
+    },
+    #[error("the data connector {data_connector_name} used by model {model_name} does not support ordering by aggregates over relationships, which is required by the orderable relationship {relationship_name} in order by expression {order_by_expression_identifier}")]
+    OrderableRelationshipAggregateNotSupported {
+        model_name: Qualified<ModelName>,
+        data_connector_name: Qualified<DataConnectorName>,
+        relationship_name: RelationshipName,
+        order_by_expression_identifier:
+            Qualified<order_by_expressions::OrderByExpressionIdentifier>,
+    },
+    #[error("the data connector {data_connector_name} used by model {model_name} does not support placing nulls first or last when ordering, which is enabled for the orderable field {field_name} in order by expression {order_by_expression_identifier}")]
+    OrderByNullsDirectionsNotSupported {
+        model_name: Qualified<ModelName>,
+        data_connector_name: Qualified<DataConnectorName>,
+        field_name: FieldName,
+        order_by_expression_identifier:
+            Qualified<order_by_expressions::OrderByExpressionIdentifier>,
+    },
+
     #[error("NDC validation error: {0}")]
     NDCValidationError(#[from] NDCValidationError),
     #[error("{0}")]
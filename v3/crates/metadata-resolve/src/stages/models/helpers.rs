@@ -16,38 +16,90 @@ pub fn get_ndc_column_for_comparison<F: Fn() -> String>(
     field: &FieldName,
     comparison_location: F,
 ) -> Result<NdcColumnForComparison, ModelsError> {
-    // Get field mappings of model data type
-    let object_types::TypeMapping::Object { field_mappings, .. } = model_source
-        .type_mappings
-        .get(model_data_type)
-        .ok_or(ModelsError::TypeMappingRequired {
-            model_name: model_name.clone(),
-            type_name: model_data_type.clone(),
-            data_connector: model_source.data_connector.name.clone(),
-        })?;
+    get_ndc_nested_column_for_comparison(
+        model_name,
+        model_data_type,
+        model_source,
+        field,
+        &[],
+        comparison_location,
+    )
+}
 
-    // Determine field_mapping for the given field
-    let field_mapping =
-        field_mappings
-            .get(field)
-            .ok_or_else(|| ModelsError::NoFieldMappingForComparedField {
-                comparison_location: comparison_location(),
-                field_name: field.clone(),
-                model_name: model_name.clone(),
-            })?;
-
-    let equal_operator = field_mapping
+/// Like `get_ndc_column_for_comparison`, but compares against a field inside
+/// nested objects of `field`. `nested_fields` is the path from `field` to the
+/// compared field, where each step names the object type that contains it.
+pub fn get_ndc_nested_column_for_comparison<F: Fn() -> String>(
+    model_name: &Qualified<ModelName>,
+    model_data_type: &Qualified<CustomTypeName>,
+    model_source: &ModelSource,
+    field: &FieldName,
+    nested_fields: &[(&Qualified<CustomTypeName>, &FieldName)],
+    comparison_location: F,
+) -> Result<NdcColumnForComparison, ModelsError> {
+    let root_field_mapping = get_field_mapping(
+        model_name,
+        model_data_type,
+        model_source,
+        field,
+        &comparison_location,
+    )?;
+
+    let mut field_path = Vec::new();
+    let mut compared_field = field;
+    let mut compared_field_mapping = root_field_mapping;
+    for (object_type, nested_field) in nested_fields {
+        compared_field_mapping = get_field_mapping(
+            model_name,
+            object_type,
+            model_source,
+            nested_field,
+            &comparison_location,
+        )?;
+        field_path.push(compared_field_mapping.column.clone());
+        compared_field = nested_field;
+    }
+
+    let equal_operator = compared_field_mapping
         .comparison_operators
         .as_ref()
         .and_then(|ops| ops.eq_operator.as_ref())
         .ok_or_else(|| ModelsError::NoEqualOperatorForComparedField {
             comparison_location: comparison_location(),
-            field_name: field.clone(),
+            field_name: compared_field.clone(),
             model_name: model_name.clone(),
         })?;
 
     Ok(NdcColumnForComparison {
-        column: field_mapping.column.clone(),
+        column: root_field_mapping.column.clone(),
+        field_path,
         equal_operator: equal_operator.clone(),
     })
 }
+
+fn get_field_mapping<'a, F: Fn() -> String>(
+    model_name: &Qualified<ModelName>,
+    type_name: &Qualified<CustomTypeName>,
+    model_source: &'a ModelSource,
+    field: &FieldName,
+    comparison_location: &F,
+) -> Result<&'a object_types::FieldMapping, ModelsError> {
+    // Get field mappings of the type
+    let object_types::TypeMapping::Object { field_mappings, .. } = model_source
+        .type_mappings
+        .get(type_name)
+        .ok_or(ModelsError::TypeMappingRequired {
+            model_name: model_name.clone(),
+            type_name: type_name.clone(),
+            data_connector: model_source.data_connector.name.clone(),
+        })?;
+
+    // Determine field_mapping for the given field
+    field_mappings
+        .get(field)
+        .ok_or_else(|| ModelsError::NoFieldMappingForComparedField {
+            comparison_location: comparison_location(),
+            field_name: field.clone(),
+            model_name: model_name.clone(),
+        })
+}
@@ -12,7 +12,7 @@ pub use error::ModelsError;
 
 pub use crate::helpers::argument::get_argument_kind;
 pub use aggregation::resolve_aggregate_expression;
-pub use helpers::get_ndc_column_for_comparison;
+pub use helpers::{get_ndc_column_for_comparison, get_ndc_nested_column_for_comparison};
 
 use crate::stages::{
     aggregates, apollo, boolean_expressions, data_connector_scalar_types, data_connectors, relay,
@@ -1,20 +1,28 @@
 use std::sync::Arc;
 
-use open_dds::aggregates::AggregateExpressionName;
+use open_dds::aggregates::{AggregateExpressionName, DataConnectorExtractionFunctionName};
 use open_dds::models::{ModelGraphQlDefinitionV2, ModelName};
 use open_dds::relationships::{ModelRelationshipTarget, RelationshipTarget};
+use open_dds::types::FieldName;
 
 use super::types::{
-    LimitFieldGraphqlConfig, ModelGraphQlApi, ModelGraphqlApiArgumentsConfig, ModelGraphqlIssue,
-    ModelOrderByExpression, OffsetFieldGraphqlConfig, OrderByExpressionInfo,
-    SelectAggregateGraphQlDefinition, SelectManyGraphQlDefinition, SelectUniqueGraphQlDefinition,
-    SubscriptionGraphQlDefinition, UniqueIdentifierField,
+    CursorField, CursorPaginationGraphQlDefinition, DistinctOnGraphQlDefinition, GroupByDimension,
+    GroupByGraphQlDefinition, LimitFieldGraphqlConfig, ModelGraphQlApi,
+    ModelGraphqlApiArgumentsConfig, ModelGraphqlIssue, ModelMutationGraphQlDefinition,
+    ModelMutationsGraphQlDefinition, ModelOrderByExpression, OffsetFieldGraphqlConfig,
+    OrderByExpressionInfo, SelectAggregateGraphQlDefinition, SelectConnectionGraphQlDefinition,
+    SelectManyGraphQlDefinition, SelectUniqueGraphQlDefinition, StreamCursorField,
+    StreamSubscriptionGraphQlDefinition, SubscriptionGraphQlDefinition, UniqueIdentifierField,
+    UpdateByUniqueKeyGraphQlDefinition, MUTATION_OBJECTS_ARGUMENT, MUTATION_POST_CHECK_ARGUMENT,
+    MUTATION_PRE_CHECK_ARGUMENT, MUTATION_UPDATE_COLUMNS_ARGUMENT,
 };
 use crate::helpers::types::{mk_name, TrackGraphQLRootFields};
-use crate::stages::order_by_expressions::{OrderByExpressionIdentifier, OrderByExpressions};
-use crate::stages::{graphql_config, models, object_types};
+use crate::stages::order_by_expressions::{
+    OrderByExpressionIdentifier, OrderByExpressions, OrderableField,
+};
+use crate::stages::{boolean_expressions, data_connectors, graphql_config, models, object_types};
 use crate::types::error::Error;
-use crate::types::subgraph::Qualified;
+use crate::types::subgraph::{Qualified, QualifiedBaseType};
 use crate::Warning;
 use indexmap::IndexMap;
 use lang_graphql::ast::common::{self as ast};
@@ -25,6 +33,8 @@ pub(crate) fn resolve_model_graphql_api(
     metadata_accessor: &open_dds::accessor::MetadataAccessor,
     model_graphql_definition: &ModelGraphQlDefinitionV2,
     model: &models::Model,
+    data_connectors: &data_connectors::DataConnectors,
+    filter_expression_type: Option<&boolean_expressions::ResolvedObjectBooleanExpressionType>,
     track_root_fields: &mut TrackGraphQLRootFields,
     model_description: Option<&String>,
     aggregate_expression_name: Option<&Qualified<AggregateExpressionName>>,
@@ -38,45 +48,11 @@ pub(crate) fn resolve_model_graphql_api(
     let mut graphql_api = ModelGraphQlApi::default();
 
     for select_unique in &model_graphql_definition.select_uniques {
-        let mut unique_identifier_fields = IndexMap::new();
-        for field_name in &select_unique.unique_identifier {
-            let field_type = &model
-                .type_fields
-                .get(field_name)
-                .ok_or_else(|| Error::UnknownFieldInUniqueIdentifier {
-                    model_name: model_name.clone(),
-                    field_name: field_name.clone(),
-                })?
-                .field_type;
-
-            let ndc_column = model
-                .source
-                .as_ref()
-                .map(|model_source| {
-                    models::get_ndc_column_for_comparison(
-                        &model.name,
-                        &model.data_type,
-                        model_source,
-                        field_name,
-                        || "the unique identifier for select unique".to_string(),
-                    )
-                })
-                .transpose()?;
-
-            let unique_identifier_field = UniqueIdentifierField {
-                field_type: field_type.clone(),
-                ndc_column,
-            };
-            if unique_identifier_fields
-                .insert(field_name.clone(), unique_identifier_field)
-                .is_some()
-            {
-                return Err(Error::DuplicateFieldInUniqueIdentifier {
-                    model_name: model_name.clone(),
-                    field_name: field_name.clone(),
-                });
-            }
-        }
+        let unique_identifier_fields = resolve_unique_identifier_fields(
+            model,
+            &select_unique.unique_identifier,
+            "the unique identifier for select unique",
+        )?;
         let select_unique_field_name = mk_name(select_unique.query_root_field.as_str())?;
         // Let's track and check if the select_unique field name is already used
         track_root_fields
@@ -178,6 +154,41 @@ pub(crate) fn resolve_model_graphql_api(
                 .map(|s| resolve_subscription_graphql_api(s, model_name, track_root_fields, issues))
                 .transpose()?;
 
+            let stream_subscription = gql_definition
+                .stream_subscription
+                .as_ref()
+                .map(|s| {
+                    resolve_stream_subscription_graphql_api(
+                        s,
+                        model,
+                        track_root_fields,
+                        graphql_types,
+                        issues,
+                    )
+                })
+                .transpose()?
+                .flatten();
+
+            let cursor_pagination = gql_definition
+                .cursor_pagination
+                .as_ref()
+                .map(|c| resolve_cursor_pagination_graphql_api(c, model))
+                .transpose()?;
+
+            let distinct_on = gql_definition
+                .distinct_on
+                .as_ref()
+                .map(|d| {
+                    resolve_distinct_on_graphql_api(
+                        d,
+                        model,
+                        order_by_expression_identifier,
+                        order_by_expressions,
+                        graphql_types,
+                    )
+                })
+                .transpose()?;
+
             mk_name(gql_definition.query_root_field.as_str()).map(|f: ast::Name| {
                 // Let's track and check if the select_many field name is already used
                 track_root_fields.track_query_root_field(&f).unwrap_or_else(|error| {
@@ -200,6 +211,9 @@ pub(crate) fn resolve_model_graphql_api(
                     description: select_many_description,
                     deprecated: gql_definition.deprecated.clone(),
                     subscription,
+                    stream_subscription,
+                    cursor_pagination,
+                    distinct_on,
                 })
             })
         }
@@ -276,6 +290,20 @@ pub(crate) fn resolve_model_graphql_api(
                         .into(),
                     );
                 });
+            let group_by = graphql_aggregate
+                .group_by
+                .as_ref()
+                .map(|group_by| {
+                    resolve_group_by_graphql_api(
+                        group_by,
+                        model,
+                        graphql_config.query.order_by_field_name.as_ref(),
+                        track_root_fields,
+                        graphql_types,
+                        issues,
+                    )
+                })
+                .transpose()?;
             Some(SelectAggregateGraphQlDefinition {
                 query_root_field: aggregate_root_field,
                 description: graphql_aggregate.description.clone(),
@@ -283,11 +311,44 @@ pub(crate) fn resolve_model_graphql_api(
                 aggregate_expression_name: aggregate_expression_name.clone(),
                 filter_input_field_name: aggregate_config.filter_input_field_name.clone(),
                 subscription,
+                group_by,
             })
         }
         _ => None,
     };
 
+    // record select_connection root field
+    graphql_api.select_connection = model_graphql_definition
+        .select_connection
+        .as_ref()
+        .map(|select_connection| {
+            resolve_select_connection_graphql_api(
+                select_connection,
+                model,
+                track_root_fields,
+                graphql_types,
+                issues,
+            )
+        })
+        .transpose()?;
+
+    // record the mutation root fields
+    graphql_api.mutations = model_graphql_definition
+        .mutations
+        .as_ref()
+        .map(|mutations| {
+            resolve_model_mutations_graphql_api(
+                mutations,
+                model,
+                data_connectors,
+                filter_expression_type,
+                track_root_fields,
+                graphql_types,
+                issues,
+            )
+        })
+        .transpose()?;
+
     // record limit and offset field names
     graphql_api.limit_field = graphql_config
         .query
@@ -341,6 +402,231 @@ pub(crate) fn resolve_model_graphql_api(
     Ok(graphql_api)
 }
 
+/// Resolve the fields of a unique identifier of a model, along with the columns they are
+/// compared with.
+fn resolve_unique_identifier_fields(
+    model: &models::Model,
+    unique_identifier: &[FieldName],
+    comparison_location: &str,
+) -> Result<IndexMap<FieldName, UniqueIdentifierField>, Error> {
+    let mut unique_identifier_fields = IndexMap::new();
+    for field_name in unique_identifier {
+        let field_type = &model
+            .type_fields
+            .get(field_name)
+            .ok_or_else(|| Error::UnknownFieldInUniqueIdentifier {
+                model_name: model.name.clone(),
+                field_name: field_name.clone(),
+            })?
+            .field_type;
+
+        let ndc_column = model
+            .source
+            .as_ref()
+            .map(|model_source| {
+                models::get_ndc_column_for_comparison(
+                    &model.name,
+                    &model.data_type,
+                    model_source,
+                    field_name,
+                    || comparison_location.to_string(),
+                )
+            })
+            .transpose()?;
+
+        let unique_identifier_field = UniqueIdentifierField {
+            field_type: field_type.clone(),
+            ndc_column,
+        };
+        if unique_identifier_fields
+            .insert(field_name.clone(), unique_identifier_field)
+            .is_some()
+        {
+            return Err(Error::DuplicateFieldInUniqueIdentifier {
+                model_name: model.name.clone(),
+                field_name: field_name.clone(),
+            });
+        }
+    }
+    Ok(unique_identifier_fields)
+}
+
+/// Resolve the mutations of a model. Every mutation calls a procedure of the data connector of
+/// the model, which must take the arguments the mutation passes to it. The update and delete
+/// mutations select the objects they affect with the filter expression type of the model.
+fn resolve_model_mutations_graphql_api(
+    mutations: &open_dds::models::ModelMutationsGraphQlDefinition,
+    model: &models::Model,
+    data_connectors: &data_connectors::DataConnectors,
+    filter_expression_type: Option<&boolean_expressions::ResolvedObjectBooleanExpressionType>,
+    track_root_fields: &mut TrackGraphQLRootFields,
+    graphql_types: &mut graphql_config::GraphqlTypeNames,
+    issues: &mut Vec<Warning>,
+) -> Result<ModelMutationsGraphQlDefinition, Error> {
+    let open_dds::models::ModelMutationsGraphQlDefinition {
+        response_type_name,
+        set_input_type_name,
+        insert,
+        update_by_unique_key,
+        update,
+        delete,
+    } = mutations;
+    let model_source = model
+        .source
+        .as_ref()
+        .ok_or_else(|| Error::ModelMutationsWithoutSource {
+            model_name: model.name.clone(),
+        })?;
+
+    let mut resolve_mutation = |mutation_root_field: &open_dds::types::GraphQlFieldName,
+                                procedure: &open_dds::commands::ProcedureName,
+                                procedure_arguments: &[&str],
+                                uses_filter_expression: bool|
+     -> Result<ast::Name, Error> {
+        let mutation_root_field = mk_name(mutation_root_field.as_str())?;
+        let procedure_info = data_connectors
+            .0
+            .get(&model_source.data_connector.name)
+            .and_then(|data_connector| data_connector.schema.procedures.get(procedure))
+            .ok_or_else(|| Error::UnknownModelMutationProcedure {
+                model_name: model.name.clone(),
+                mutation_root_field: mutation_root_field.clone(),
+                procedure: procedure.clone(),
+            })?;
+        for argument_name in procedure_arguments {
+            if !procedure_info.arguments.contains_key(*argument_name) {
+                return Err(Error::MissingModelMutationProcedureArgument {
+                    model_name: model.name.clone(),
+                    mutation_root_field,
+                    procedure: procedure.clone(),
+                    argument_name: (*argument_name).to_string(),
+                });
+            }
+        }
+        if uses_filter_expression
+            && filter_expression_type.is_none_or(|filter| filter.graphql.is_none())
+        {
+            return Err(Error::ModelMutationWithoutFilterExpression {
+                model_name: model.name.clone(),
+                mutation_root_field,
+            });
+        }
+        // Let's track and check if the mutation root field name is already used
+        track_root_fields
+            .track_mutation_root_field(&mutation_root_field)
+            .unwrap_or_else(|error| {
+                issues.push(Warning::from(ModelGraphqlIssue::DuplicateRootField {
+                    model_name: model.name.clone(),
+                    error,
+                }));
+            });
+        Ok(mutation_root_field)
+    };
+
+    let insert = insert
+        .as_ref()
+        .map(|insert| -> Result<_, Error> {
+            Ok(ModelMutationGraphQlDefinition {
+                mutation_root_field: resolve_mutation(
+                    &insert.mutation_root_field,
+                    &insert.procedure,
+                    &[MUTATION_OBJECTS_ARGUMENT, MUTATION_POST_CHECK_ARGUMENT],
+                    false,
+                )?,
+                procedure_name: insert.procedure.clone(),
+                description: insert.description.clone(),
+                deprecated: insert.deprecated.clone(),
+            })
+        })
+        .transpose()?;
+
+    let update_arguments = [
+        MUTATION_PRE_CHECK_ARGUMENT,
+        MUTATION_UPDATE_COLUMNS_ARGUMENT,
+        MUTATION_POST_CHECK_ARGUMENT,
+    ];
+    let update_by_unique_key = update_by_unique_key
+        .as_ref()
+        .map(|update_by_unique_key| -> Result<_, Error> {
+            let mutation_root_field = resolve_mutation(
+                &update_by_unique_key.mutation_root_field,
+                &update_by_unique_key.procedure,
+                &update_arguments,
+                false,
+            )?;
+            let unique_identifier = resolve_unique_identifier_fields(
+                model,
+                &update_by_unique_key.unique_identifier,
+                "the unique identifier for update by unique key",
+            )?;
+            Ok(UpdateByUniqueKeyGraphQlDefinition {
+                mutation_root_field,
+                procedure_name: update_by_unique_key.procedure.clone(),
+                unique_identifier,
+                description: update_by_unique_key.description.clone(),
+                deprecated: update_by_unique_key.deprecated.clone(),
+            })
+        })
+        .transpose()?;
+
+    let update = update
+        .as_ref()
+        .map(|update| -> Result<_, Error> {
+            Ok(ModelMutationGraphQlDefinition {
+                mutation_root_field: resolve_mutation(
+                    &update.mutation_root_field,
+                    &update.procedure,
+                    &update_arguments,
+                    true,
+                )?,
+                procedure_name: update.procedure.clone(),
+                description: update.description.clone(),
+                deprecated: update.deprecated.clone(),
+            })
+        })
+        .transpose()?;
+
+    let delete = delete
+        .as_ref()
+        .map(|delete| -> Result<_, Error> {
+            Ok(ModelMutationGraphQlDefinition {
+                mutation_root_field: resolve_mutation(
+                    &delete.mutation_root_field,
+                    &delete.procedure,
+                    &[MUTATION_PRE_CHECK_ARGUMENT],
+                    true,
+                )?,
+                procedure_name: delete.procedure.clone(),
+                description: delete.description.clone(),
+                deprecated: delete.deprecated.clone(),
+            })
+        })
+        .transpose()?;
+
+    let set_input_type_name = set_input_type_name
+        .as_ref()
+        .map(|type_name| mk_name(type_name.as_str()).map(ast::TypeName))
+        .transpose()?;
+    if set_input_type_name.is_none() && (update_by_unique_key.is_some() || update.is_some()) {
+        return Err(Error::MissingSetInputTypeNameForModelUpdate {
+            model_name: model.name.clone(),
+        });
+    }
+    graphql_types.store(set_input_type_name.as_ref())?;
+
+    let response_type_name = mk_name(response_type_name.as_str()).map(ast::TypeName)?;
+    graphql_types.store(Some(&response_type_name))?;
+
+    Ok(ModelMutationsGraphQlDefinition {
+        response_type_name,
+        set_input_type_name,
+        insert,
+        update_by_unique_key,
+        update,
+        delete,
+    })
+}
+
 fn is_model_used_in_any_aggregate_relationship(
     metadata_accessor: &open_dds::accessor::MetadataAccessor,
     model_name: &Qualified<ModelName>,
@@ -397,3 +683,474 @@ fn resolve_subscription_graphql_api(
         polling_interval_ms: *polling_interval_ms,
     })
 }
+
+/// Resolve the streaming subscription of a model. The streaming subscription is omitted, with a
+/// warning, if none of the fields of the model can be used as the cursor of the stream.
+fn resolve_stream_subscription_graphql_api(
+    stream_subscription: &open_dds::models::StreamSubscriptionGraphQlDefinition,
+    model: &models::Model,
+    track_root_fields: &mut TrackGraphQLRootFields,
+    graphql_types: &mut graphql_config::GraphqlTypeNames,
+    issues: &mut Vec<Warning>,
+) -> Result<Option<StreamSubscriptionGraphQlDefinition>, Error> {
+    let open_dds::models::StreamSubscriptionGraphQlDefinition {
+        root_field,
+        cursor_input_type_name,
+        description,
+        deprecated,
+        polling_interval_ms,
+    } = stream_subscription;
+    let cursor_fields = resolve_stream_cursor_fields(model);
+    if cursor_fields.is_empty() {
+        issues.push(Warning::from(
+            ModelGraphqlIssue::StreamSubscriptionWithoutCursorFields {
+                model_name: model.name.clone(),
+            },
+        ));
+        return Ok(None);
+    }
+    let root_field_name = mk_name(root_field.as_str())?;
+    // Let's track and check if the streaming subscription root field name is already used
+    track_root_fields
+        .track_subscription_root_field(&root_field_name)
+        .unwrap_or_else(|error| {
+            issues.push(Warning::from(ModelGraphqlIssue::DuplicateRootField {
+                model_name: model.name.clone(),
+                error,
+            }));
+        });
+    let cursor_input_type_name = mk_name(cursor_input_type_name.as_str()).map(ast::TypeName)?;
+    graphql_types.store(Some(&cursor_input_type_name))?;
+    Ok(Some(StreamSubscriptionGraphQlDefinition {
+        root_field: root_field_name,
+        cursor_input_type_name,
+        description: description.clone(),
+        deprecated: deprecated.clone(),
+        polling_interval_ms: *polling_interval_ms,
+        cursor_fields,
+    }))
+}
+
+/// The fields of a model that can be used as the cursor of a streaming subscription: scalar
+/// fields without arguments, mapped to a column that supports the "greater than" operator.
+fn resolve_stream_cursor_fields(model: &models::Model) -> IndexMap<FieldName, StreamCursorField> {
+    let Some(model_source) = &model.source else {
+        return IndexMap::new();
+    };
+    let Some(object_types::TypeMapping::Object { field_mappings, .. }) =
+        model_source.type_mappings.get(&model.data_type)
+    else {
+        return IndexMap::new();
+    };
+    let ndc_version = model_source
+        .data_connector
+        .capabilities
+        .supported_ndc_version;
+    model
+        .type_fields
+        .iter()
+        .filter_map(|(field_name, field_definition)| {
+            if matches!(
+                field_definition.field_type.underlying_type,
+                QualifiedBaseType::List(_)
+            ) || !field_definition.field_arguments.is_empty()
+            {
+                return None;
+            }
+            let field_mapping = field_mappings.get(field_name)?;
+            let greater_than_operator = field_mapping
+                .comparison_operators
+                .as_ref()?
+                .get_gt_operator(ndc_version)?;
+            Some((
+                field_name.clone(),
+                StreamCursorField {
+                    field_type: field_definition.field_type.clone(),
+                    ndc_column: field_mapping.column.clone(),
+                    greater_than_operator: greater_than_operator.clone(),
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Resolve the cursor pagination of a model. Every field of the unique identifier must be able
+/// to be part of a cursor, as the rows are always ordered by the unique identifier last.
+fn resolve_cursor_pagination_graphql_api(
+    cursor_pagination: &open_dds::models::CursorPaginationGraphQlDefinition,
+    model: &models::Model,
+) -> Result<CursorPaginationGraphQlDefinition, Error> {
+    let open_dds::models::CursorPaginationGraphQlDefinition {
+        unique_identifier,
+        first_argument_name,
+        after_argument_name,
+        last_argument_name,
+        before_argument_name,
+    } = cursor_pagination;
+    if unique_identifier.is_empty() {
+        return Err(Error::EmptyUniqueIdentifierForCursorPagination {
+            model_name: model.name.clone(),
+        });
+    }
+    let cursor_fields = resolve_cursor_fields(model);
+    let mut unique_identifier_fields = Vec::new();
+    for field_name in unique_identifier {
+        if !model.type_fields.contains_key(field_name) {
+            return Err(Error::UnknownFieldInUniqueIdentifier {
+                model_name: model.name.clone(),
+                field_name: field_name.clone(),
+            });
+        }
+        if unique_identifier_fields.contains(field_name) {
+            return Err(Error::DuplicateFieldInUniqueIdentifier {
+                model_name: model.name.clone(),
+                field_name: field_name.clone(),
+            });
+        }
+        // Models without a source cannot be queried, so their fields are not checked
+        if model.source.is_some() && !cursor_fields.contains_key(field_name) {
+            return Err(Error::UnsupportedFieldInCursorPaginationUniqueIdentifier {
+                model_name: model.name.clone(),
+                field_name: field_name.clone(),
+            });
+        }
+        unique_identifier_fields.push(field_name.clone());
+    }
+    Ok(CursorPaginationGraphQlDefinition {
+        first_argument_name: mk_name(first_argument_name.as_str())?,
+        after_argument_name: mk_name(after_argument_name.as_str())?,
+        last_argument_name: mk_name(last_argument_name.as_str())?,
+        before_argument_name: mk_name(before_argument_name.as_str())?,
+        unique_identifier: unique_identifier_fields,
+        cursor_fields,
+    })
+}
+
+/// Resolve the `distinct_on` argument of a model. The rows can be distinct on the orderable scalar
+/// fields of the model without arguments that are mapped to a column. Distinct rows are fetched
+/// by grouping the rows, so the data connector of the model must support grouping.
+fn resolve_distinct_on_graphql_api(
+    distinct_on: &open_dds::models::DistinctOnGraphQlDefinition,
+    model: &models::Model,
+    order_by_expression_identifier: Option<&Qualified<OrderByExpressionIdentifier>>,
+    order_by_expressions: &OrderByExpressions,
+    graphql_types: &mut graphql_config::GraphqlTypeNames,
+) -> Result<DistinctOnGraphQlDefinition, Error> {
+    let open_dds::models::DistinctOnGraphQlDefinition {
+        argument_name,
+        enum_type_name,
+        row_aggregation_function,
+    } = distinct_on;
+    let mut fields = IndexMap::new();
+    // Models without a source cannot be queried, so their fields are not checked
+    if let Some(model_source) = &model.source {
+        let supports_grouping = model_source
+            .data_connector
+            .capabilities
+            .supports_aggregates
+            .as_ref()
+            .is_some_and(|aggregates| aggregates.supports_grouping.is_some());
+        if !supports_grouping {
+            return Err(Error::DistinctOnNotSupportedByDataConnector {
+                model_name: model.name.clone(),
+                data_connector_name: model_source.data_connector.name.clone(),
+            });
+        }
+        let orderable_fields = order_by_expression_identifier
+            .and_then(|identifier| order_by_expressions.objects.get(identifier))
+            .map(|order_by_expression| &order_by_expression.orderable_fields);
+        if let (
+            Some(orderable_fields),
+            Some(object_types::TypeMapping::Object { field_mappings, .. }),
+        ) = (
+            orderable_fields,
+            model_source.type_mappings.get(&model.data_type),
+        ) {
+            for (field_name, field_definition) in &model.type_fields {
+                // Only the orderable scalar fields can be chosen
+                if !matches!(
+                    orderable_fields.get(field_name),
+                    Some(OrderableField::Scalar(_))
+                ) || matches!(
+                    field_definition.field_type.underlying_type,
+                    QualifiedBaseType::List(_)
+                ) || !field_definition.field_arguments.is_empty()
+                {
+                    continue;
+                }
+                // Only the fields mapped to a column of a scalar type can be grouped on
+                if let Some(field_mapping) = field_mappings
+                    .get(field_name)
+                    .filter(|field_mapping| field_mapping.comparison_operators.is_some())
+                {
+                    fields.insert(field_name.clone(), field_mapping.column.clone());
+                }
+            }
+        }
+        if fields.is_empty() {
+            return Err(Error::DistinctOnWithoutFields {
+                model_name: model.name.clone(),
+            });
+        }
+    }
+    let enum_type_name = mk_name(enum_type_name.as_str()).map(ast::TypeName)?;
+    graphql_types.store(Some(&enum_type_name))?;
+    Ok(DistinctOnGraphQlDefinition {
+        argument_name: mk_name(argument_name.as_str())?,
+        enum_type_name,
+        row_aggregation_function: row_aggregation_function.clone(),
+        fields,
+    })
+}
+
+/// Resolve the group by root field of a model. The rows can be grouped by the scalar fields
+/// without arguments that are mapped to a column, and by the parts of their values that the data
+/// connector can extract, such as the year of a date. The data connector of the model must
+/// support grouping.
+fn resolve_group_by_graphql_api(
+    group_by: &open_dds::models::GroupByGraphQlDefinition,
+    model: &models::Model,
+    order_by_field_name: Option<&ast::Name>,
+    track_root_fields: &mut TrackGraphQLRootFields,
+    graphql_types: &mut graphql_config::GraphqlTypeNames,
+    issues: &mut Vec<Warning>,
+) -> Result<GroupByGraphQlDefinition, Error> {
+    let open_dds::models::GroupByGraphQlDefinition {
+        query_root_field,
+        group_type_name,
+        dimensions_type_name,
+        order_by_type_name,
+        description,
+        deprecated,
+    } = group_by;
+    let mut dimensions = IndexMap::new();
+    // Models without a source cannot be queried, so their fields are not checked
+    if let Some(model_source) = &model.source {
+        let supports_grouping = model_source
+            .data_connector
+            .capabilities
+            .supports_aggregates
+            .as_ref()
+            .is_some_and(|aggregates| aggregates.supports_grouping.is_some());
+        if !supports_grouping {
+            return Err(Error::GroupByNotSupportedByDataConnector {
+                model_name: model.name.clone(),
+                data_connector_name: model_source.data_connector.name.clone(),
+            });
+        }
+        let mut field_mappings_to_group_by = Vec::new();
+        if let Some(object_types::TypeMapping::Object { field_mappings, .. }) =
+            model_source.type_mappings.get(&model.data_type)
+        {
+            for (field_name, field_definition) in &model.type_fields {
+                if matches!(
+                    field_definition.field_type.underlying_type,
+                    QualifiedBaseType::List(_)
+                ) || !field_definition.field_arguments.is_empty()
+                {
+                    continue;
+                }
+                // Only the fields mapped to a column of a scalar type can be grouped by
+                if let Some(field_mapping) = field_mappings
+                    .get(field_name)
+                    .filter(|field_mapping| field_mapping.comparison_operators.is_some())
+                {
+                    field_mappings_to_group_by.push((field_name, field_mapping));
+                    dimensions.insert(
+                        mk_name(field_name.as_str())?,
+                        GroupByDimension {
+                            field_name: field_name.clone(),
+                            column: field_mapping.column.clone(),
+                            extraction: None,
+                        },
+                    );
+                }
+            }
+        }
+        // The extracted parts are named after their field, and never replace a field of the model
+        for (field_name, field_mapping) in field_mappings_to_group_by {
+            let Some(extraction_functions) = &field_mapping.extraction_functions else {
+                continue;
+            };
+            for (part, extraction_function) in extraction_parts(extraction_functions) {
+                let dimension_name = mk_name(&format!("{field_name}_{part}"))?;
+                dimensions
+                    .entry(dimension_name)
+                    .or_insert_with(|| GroupByDimension {
+                        field_name: field_name.clone(),
+                        column: field_mapping.column.clone(),
+                        extraction: Some(extraction_function.clone()),
+                    });
+            }
+        }
+        if dimensions.is_empty() {
+            return Err(Error::GroupByWithoutDimensions {
+                model_name: model.name.clone(),
+            });
+        }
+    }
+    let query_root_field = mk_name(query_root_field.as_str())?;
+    // Let's track and check if the group by field name is already used
+    track_root_fields
+        .track_query_root_field(&query_root_field)
+        .unwrap_or_else(|error| {
+            issues.push(Warning::from(ModelGraphqlIssue::DuplicateRootField {
+                model_name: model.name.clone(),
+                error,
+            }));
+        });
+    let group_type_name = mk_name(group_type_name.as_str()).map(ast::TypeName)?;
+    graphql_types.store(Some(&group_type_name))?;
+    let dimensions_type_name = mk_name(dimensions_type_name.as_str()).map(ast::TypeName)?;
+    graphql_types.store(Some(&dimensions_type_name))?;
+    let order_by_type_name = mk_name(order_by_type_name.as_str()).map(ast::TypeName)?;
+    graphql_types.store(Some(&order_by_type_name))?;
+    Ok(GroupByGraphQlDefinition {
+        query_root_field,
+        group_type_name,
+        dimensions_type_name,
+        order_by_type_name,
+        // The groups are ordered with the same argument as the rows of the model
+        order_by_argument_name: order_by_field_name.cloned(),
+        description: description.clone(),
+        deprecated: deprecated.clone(),
+        dimensions,
+    })
+}
+
+/// The parts of a value that the rows can be grouped by, with the functions of the data
+/// connector that extract them
+fn extraction_parts(
+    extraction_functions: &object_types::ExtractionFunctions,
+) -> Vec<(&'static str, &DataConnectorExtractionFunctionName)> {
+    [
+        ("year", &extraction_functions.year_function),
+        ("quarter", &extraction_functions.quarter_function),
+        ("month", &extraction_functions.month_function),
+        ("week", &extraction_functions.week_function),
+        ("day", &extraction_functions.day_function),
+        ("day_of_week", &extraction_functions.day_of_week_function),
+        ("day_of_year", &extraction_functions.day_of_year_function),
+        ("hour", &extraction_functions.hour_function),
+        ("minute", &extraction_functions.minute_function),
+        ("second", &extraction_functions.second_function),
+        ("microsecond", &extraction_functions.microsecond_function),
+        ("nanosecond", &extraction_functions.nanosecond_function),
+    ]
+    .into_iter()
+    .filter_map(|(part, function)| function.as_ref().map(|function| (part, function)))
+    .collect()
+}
+
+/// Resolve the select connection of a model. The edges of a connection are ordered by the
+/// global ID fields of the model last, so every global ID field must be able to be part of a
+/// cursor. The pagination arguments are named as required by the Relay specification.
+fn resolve_select_connection_graphql_api(
+    select_connection: &open_dds::models::SelectConnectionGraphQlDefinition,
+    model: &models::Model,
+    track_root_fields: &mut TrackGraphQLRootFields,
+    graphql_types: &mut graphql_config::GraphqlTypeNames,
+    issues: &mut Vec<Warning>,
+) -> Result<SelectConnectionGraphQlDefinition, Error> {
+    let open_dds::models::SelectConnectionGraphQlDefinition {
+        query_root_field,
+        connection_type_name,
+        edge_type_name,
+        description,
+        deprecated,
+    } = select_connection;
+    if model.global_id_source.is_none() {
+        return Err(Error::SelectConnectionWithoutGlobalIdSource {
+            model_name: model.name.clone(),
+        });
+    }
+    let cursor_fields = resolve_cursor_fields(model);
+    // Models without a source cannot be queried, so their fields are not checked
+    if model.source.is_some() {
+        if let Some(field_name) = model
+            .global_id_fields
+            .iter()
+            .find(|field_name| !cursor_fields.contains_key(*field_name))
+        {
+            return Err(Error::UnsupportedGlobalIdFieldInSelectConnection {
+                model_name: model.name.clone(),
+                field_name: field_name.clone(),
+            });
+        }
+    }
+    let query_root_field = mk_name(query_root_field.as_str())?;
+    // Let's track and check if the select_connection field name is already used
+    track_root_fields
+        .track_query_root_field(&query_root_field)
+        .unwrap_or_else(|error| {
+            issues.push(Warning::from(ModelGraphqlIssue::DuplicateRootField {
+                model_name: model.name.clone(),
+                error,
+            }));
+        });
+    let connection_type_name = mk_name(connection_type_name.as_str()).map(ast::TypeName)?;
+    graphql_types.store(Some(&connection_type_name))?;
+    let edge_type_name = mk_name(edge_type_name.as_str()).map(ast::TypeName)?;
+    graphql_types.store(Some(&edge_type_name))?;
+    Ok(SelectConnectionGraphQlDefinition {
+        query_root_field,
+        connection_type_name,
+        edge_type_name,
+        description: description.clone(),
+        deprecated: deprecated.clone(),
+        cursor_pagination: CursorPaginationGraphQlDefinition {
+            first_argument_name: mk_name("first")?,
+            after_argument_name: mk_name("after")?,
+            last_argument_name: mk_name("last")?,
+            before_argument_name: mk_name("before")?,
+            unique_identifier: model.global_id_fields.clone(),
+            cursor_fields,
+        },
+    })
+}
+
+/// The fields of a model that can be part of a pagination cursor: non-nullable scalar fields
+/// without arguments, mapped to a column that supports the "equal", "greater than" and
+/// "less than" operators.
+fn resolve_cursor_fields(model: &models::Model) -> IndexMap<FieldName, CursorField> {
+    let Some(model_source) = &model.source else {
+        return IndexMap::new();
+    };
+    let Some(object_types::TypeMapping::Object { field_mappings, .. }) =
+        model_source.type_mappings.get(&model.data_type)
+    else {
+        return IndexMap::new();
+    };
+    let ndc_version = model_source
+        .data_connector
+        .capabilities
+        .supported_ndc_version;
+    model
+        .type_fields
+        .iter()
+        .filter_map(|(field_name, field_definition)| {
+            if field_definition.field_type.nullable
+                || matches!(
+                    field_definition.field_type.underlying_type,
+                    QualifiedBaseType::List(_)
+                )
+                || !field_definition.field_arguments.is_empty()
+            {
+                return None;
+            }
+            let field_mapping = field_mappings.get(field_name)?;
+            let comparison_operators = field_mapping.comparison_operators.as_ref()?;
+            Some((
+                field_name.clone(),
+                CursorField {
+                    ndc_column: field_mapping.column.clone(),
+                    equal_operator: comparison_operators.get_eq_operator(ndc_version)?.clone(),
+                    greater_than_operator: comparison_operators
+                        .get_gt_operator(ndc_version)?
+                        .clone(),
+                    less_than_operator: comparison_operators.get_lt_operator(ndc_version)?.clone(),
+                },
+            ))
+        })
+        .collect()
+}
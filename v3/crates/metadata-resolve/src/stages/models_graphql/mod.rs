@@ -7,26 +7,34 @@ use crate::Warning;
 use indexmap::IndexMap;
 use std::collections::BTreeMap;
 
-use open_dds::{models::ModelName, types::CustomTypeName};
+use open_dds::{aggregates::AggregateExpressionName, models::ModelName, types::CustomTypeName};
 
 use crate::helpers::types::TrackGraphQLRootFields;
 use crate::stages::{
-    boolean_expressions, graphql_config, models, object_relationships, scalar_types,
+    aggregates, boolean_expressions, data_connectors, graphql_config, models, object_relationships,
+    scalar_types,
 };
-use crate::types::error::Error;
+use crate::types::error::{Error, RelationshipError};
 use crate::types::subgraph::Qualified;
 
 pub(crate) use types::ModelWithGraphql;
 pub use types::{
-    ModelGraphQlApi, ModelGraphqlIssue, ModelOrderByExpression, ModelsWithGraphqlOutput,
-    SelectAggregateGraphQlDefinition, SelectManyGraphQlDefinition, SelectUniqueGraphQlDefinition,
-    SubscriptionGraphQlDefinition, UniqueIdentifierField,
+    CursorField, CursorPaginationGraphQlDefinition, DistinctOnGraphQlDefinition, GroupByDimension,
+    GroupByGraphQlDefinition, ModelGraphQlApi, ModelGraphqlIssue, ModelMutationGraphQlDefinition,
+    ModelMutationsGraphQlDefinition, ModelOrderByExpression, ModelsWithGraphqlOutput,
+    SelectAggregateGraphQlDefinition, SelectConnectionGraphQlDefinition,
+    SelectManyGraphQlDefinition, SelectUniqueGraphQlDefinition, StreamCursorField,
+    StreamSubscriptionGraphQlDefinition, SubscriptionGraphQlDefinition, UniqueIdentifierField,
+    UpdateByUniqueKeyGraphQlDefinition, MUTATION_AFFECTED_ROWS_FIELD, MUTATION_OBJECTS_ARGUMENT,
+    MUTATION_POST_CHECK_ARGUMENT, MUTATION_PRE_CHECK_ARGUMENT, MUTATION_RETURNING_FIELD,
+    MUTATION_UPDATE_COLUMNS_ARGUMENT,
 };
 
 use super::order_by_expressions;
 
 pub fn resolve(
     metadata_accessor: &open_dds::accessor::MetadataAccessor,
+    data_connectors: &data_connectors::DataConnectors,
     models: &IndexMap<Qualified<ModelName>, models::Model>,
     object_types: &BTreeMap<
         Qualified<CustomTypeName>,
@@ -36,6 +44,10 @@ pub fn resolve(
     track_root_fields: &mut TrackGraphQLRootFields,
     graphql_config: &graphql_config::GraphqlConfig,
     scalar_types: &BTreeMap<Qualified<CustomTypeName>, scalar_types::ScalarTypeRepresentation>,
+    aggregate_expressions: &BTreeMap<
+        Qualified<AggregateExpressionName>,
+        aggregates::AggregateExpression,
+    >,
     order_by_expressions: &mut order_by_expressions::OrderByExpressions,
     graphql_types: &mut graphql_config::GraphqlTypeNames,
 ) -> Result<ModelsWithGraphqlOutput, Error> {
@@ -85,6 +97,7 @@ pub fn resolve(
             object_types,
             models,
             scalar_types,
+            aggregate_expressions,
             order_by_expressions,
             graphql_types,
             &mut output.issues,
@@ -95,6 +108,8 @@ pub fn resolve(
                 metadata_accessor,
                 model_graphql_definition,
                 &model,
+                data_connectors,
+                filter_expression_type.as_ref(),
                 track_root_fields,
                 model.raw.description.as_ref(),
                 model.aggregate_expression.as_ref(),
@@ -117,5 +132,42 @@ pub fn resolve(
         );
     }
 
+    validate_connection_relationships(object_types, &output.models_with_graphql)?;
+
     Ok(output)
 }
+
+/// A connection field on a relationship paginates through its target model the same way as
+/// the select connection of that model, so the target model must define one.
+fn validate_connection_relationships(
+    object_types: &BTreeMap<
+        Qualified<CustomTypeName>,
+        object_relationships::ObjectTypeWithRelationships,
+    >,
+    models_with_graphql: &IndexMap<Qualified<ModelName>, ModelWithGraphql>,
+) -> Result<(), Error> {
+    for (type_name, object_type) in object_types {
+        for relationship_field in object_type.relationship_fields.values() {
+            let object_relationships::RelationshipTarget::Model(model_relationship_target) =
+                &relationship_field.target
+            else {
+                continue;
+            };
+            if model_relationship_target.connection_field_name.is_some()
+                && models_with_graphql
+                    .get(&model_relationship_target.model_name)
+                    .and_then(|model| model.graphql_api.select_connection.as_ref())
+                    .is_none()
+            {
+                return Err(Error::from(
+                    RelationshipError::ConnectionRelationshipWithoutSelectConnection {
+                        type_name: type_name.clone(),
+                        relationship_name: relationship_field.relationship_name.clone(),
+                        model_name: model_relationship_target.model_name.clone(),
+                    },
+                ));
+            }
+        }
+    }
+    Ok(())
+}
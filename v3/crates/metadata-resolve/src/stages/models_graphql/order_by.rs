@@ -4,12 +4,16 @@ use lang_graphql::ast::common as ast;
 use open_dds::types::CustomTypeName;
 
 use crate::stages::{
-    graphql_config, models, object_relationships, order_by_expressions, scalar_types,
+    aggregates, graphql_config, models, object_relationships, order_by_expressions, scalar_types,
 };
 use crate::types::subgraph::Qualified;
 use indexmap::IndexMap;
 
-use open_dds::{models::ModelName, types::GraphQlTypeName};
+use open_dds::{
+    aggregates::AggregateExpressionName,
+    models::{EnableAllOrSpecific, ModelName, OrderByDirection},
+    types::GraphQlTypeName,
+};
 
 use std::collections::BTreeMap;
 
@@ -27,6 +31,10 @@ pub fn resolve_order_by_expression(
     >,
     models: &IndexMap<Qualified<ModelName>, models::Model>,
     scalar_types: &BTreeMap<Qualified<CustomTypeName>, scalar_types::ScalarTypeRepresentation>,
+    aggregate_expressions: &BTreeMap<
+        Qualified<AggregateExpressionName>,
+        aggregates::AggregateExpression,
+    >,
     order_by_expressions: &mut OrderByExpressions,
     graphql_types: &mut graphql_config::GraphqlTypeNames,
     issues: &mut Vec<Warning>,
@@ -65,6 +73,9 @@ pub fn resolve_order_by_expression(
                     &model.name,
                     order_by_expression_name,
                     model_source,
+                    object_types,
+                    models,
+                    aggregate_expressions,
                     order_by_expressions,
                     &model.data_type,
                     issues,
@@ -179,6 +190,7 @@ pub fn make_order_by_expression(
         // b) the relationship target is a model with a model source
         // c) it's a local rather than remote relationship
         // d) it's an object relationship
+        // e) it has no value or argument mappings, which can't be applied inside an ordering
         if let object_relationships::RelationshipTarget::Model(model_relationship_target) =
             &relationship.target
         {
@@ -187,7 +199,10 @@ pub fn make_order_by_expression(
                 relationship_type,
                 target_typename: _,
                 mappings: _,
+                value_mappings,
+                argument_mappings,
                 relationship_aggregate: _,
+                connection_field_name: _,
             } = model_relationship_target.as_ref();
 
             let target_model = models.get(target_model_name).ok_or_else(|| {
@@ -213,13 +228,17 @@ pub fn make_order_by_expression(
                     )
                 {
                     // TODO(naveen): Support Array relationships in order_by when the support for aggregates is implemented
-                    if open_dds::relationships::RelationshipType::Object == *relationship_type {
+                    if open_dds::relationships::RelationshipType::Object == *relationship_type
+                        && value_mappings.is_empty()
+                        && argument_mappings.is_empty()
+                    {
                         // If the relationship target model does not have orderByExpressionType do not include
                         // it in the source model order_by input type.
                         orderable_relationships.insert(
                             relationship_name.clone(),
                             order_by_expressions::OrderableRelationship {
                                 order_by_expression: None,
+                                aggregate_expression: None,
                             },
                         );
                     }
@@ -255,10 +274,20 @@ pub fn make_order_by_expression(
     Ok(identifier)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn resolve_order_by_expression_for_model(
     qualified_model_name: &Qualified<ModelName>,
     order_by_expression_name: &open_dds::order_by_expression::OrderByExpressionName,
     model_source: Option<&models::ModelSource>,
+    object_types: &BTreeMap<
+        Qualified<CustomTypeName>,
+        object_relationships::ObjectTypeWithRelationships,
+    >,
+    models: &IndexMap<Qualified<ModelName>, models::Model>,
+    aggregate_expressions: &BTreeMap<
+        Qualified<AggregateExpressionName>,
+        aggregates::AggregateExpression,
+    >,
     order_by_expressions: &OrderByExpressions,
     qualified_model_object_type_name: &Qualified<CustomTypeName>,
     issues: &mut Vec<Warning>,
@@ -300,11 +329,168 @@ fn resolve_order_by_expression_for_model(
             order_by_expressions,
             issues,
         )?;
+        validate_nulls_order_compatibility(
+            order_by_expression,
+            model_source,
+            qualified_model_name,
+            order_by_expressions,
+        )?;
     }
 
+    validate_orderable_relationship_aggregates(
+        order_by_expression,
+        model_source,
+        qualified_model_name,
+        object_types,
+        models,
+        aggregate_expressions,
+    )?;
+
     Ok(order_by_expression_identifier)
 }
 
+/// Check that any aggregate expressions used to order by array relationships aggregate the
+/// relationship's target model, and that the model's data connector can order by them
+fn validate_orderable_relationship_aggregates(
+    order_by_expression: &order_by_expressions::ObjectOrderByExpression,
+    model_source: Option<&models::ModelSource>,
+    model_name: &Qualified<ModelName>,
+    object_types: &BTreeMap<
+        Qualified<CustomTypeName>,
+        object_relationships::ObjectTypeWithRelationships,
+    >,
+    models: &IndexMap<Qualified<ModelName>, models::Model>,
+    aggregate_expressions: &BTreeMap<
+        Qualified<AggregateExpressionName>,
+        aggregates::AggregateExpression,
+    >,
+) -> Result<(), models::ModelsError> {
+    for (relationship_name, orderable_relationship) in &order_by_expression.orderable_relationships
+    {
+        let Some(aggregate_expression_name) = &orderable_relationship.aggregate_expression else {
+            continue;
+        };
+
+        let target_model = object_types
+            .get(&order_by_expression.ordered_type)
+            .and_then(|object_type| object_type.relationship_fields.get(relationship_name))
+            .and_then(|relationship| match &relationship.target {
+                object_relationships::RelationshipTarget::Model(model_relationship_target) => {
+                    models.get(&model_relationship_target.model_name)
+                }
+                object_relationships::RelationshipTarget::Command(_) => None,
+            });
+
+        // the order by expressions stage has already checked that these exist, but the target
+        // model may be in an unknown subgraph
+        let (Some(target_model), Some(aggregate_expression)) = (
+            target_model,
+            aggregate_expressions.get(aggregate_expression_name),
+        ) else {
+            continue;
+        };
+
+        if aggregate_expression.operand.aggregated_type
+            != QualifiedTypeName::Custom(target_model.data_type.clone())
+        {
+            return Err(
+                models::ModelsError::OrderableRelationshipAggregateExpressionTypeMismatch {
+                    order_by_expression_identifier: order_by_expression.identifier.clone(),
+                    relationship_name: relationship_name.clone(),
+                    aggregate_expression: aggregate_expression_name.clone(),
+                    aggregate_operand_type: aggregate_expression.operand.aggregated_type.clone(),
+                    target_model_name: target_model.name.clone(),
+                    target_model_type: target_model.data_type.clone(),
+                },
+            );
+        }
+
+        if let Some(model_source) = model_source {
+            let supports_order_by_aggregate = model_source
+                .data_connector
+                .capabilities
+                .supports_relationships
+                .as_ref()
+                .is_some_and(|rel| rel.supports_order_by_aggregate);
+
+            if !supports_order_by_aggregate {
+                return Err(
+                    models::ModelsError::OrderableRelationshipAggregateNotSupported {
+                        model_name: model_name.clone(),
+                        data_connector_name: model_source.data_connector.name.clone(),
+                        relationship_name: relationship_name.clone(),
+                        order_by_expression_identifier: order_by_expression.identifier.clone(),
+                    },
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that the model's data connector can place nulls first or last, if any of the
+/// orderable fields (including those of nested objects) enable directions that do so
+fn validate_nulls_order_compatibility(
+    order_by_expression: &order_by_expressions::ObjectOrderByExpression,
+    model_source: &models::ModelSource,
+    model_name: &Qualified<ModelName>,
+    order_by_expressions: &OrderByExpressions,
+) -> Result<(), models::ModelsError> {
+    if model_source
+        .data_connector
+        .capabilities
+        .supports_order_by_nulls
+    {
+        return Ok(());
+    }
+
+    for (field_name, orderable_field) in &order_by_expression.orderable_fields {
+        match orderable_field {
+            OrderableField::Scalar(orderable_scalar_field) => {
+                let specifies_nulls_order = order_by_expressions
+                    .scalars
+                    .get(&orderable_scalar_field.order_by_expression_identifier)
+                    .is_some_and(|scalar_order_by_expression| {
+                        match &scalar_order_by_expression.enable_order_by_directions {
+                            EnableAllOrSpecific::EnableAll(_) => false,
+                            EnableAllOrSpecific::EnableSpecific(directions) => directions
+                                .iter()
+                                .any(OrderByDirection::specifies_nulls_order),
+                        }
+                    });
+                if specifies_nulls_order {
+                    return Err(models::ModelsError::OrderByNullsDirectionsNotSupported {
+                        model_name: model_name.clone(),
+                        data_connector_name: model_source.data_connector.name.clone(),
+                        field_name: field_name.clone(),
+                        order_by_expression_identifier: order_by_expression.identifier.clone(),
+                    });
+                }
+            }
+            OrderableField::Object(orderable_object_field) => {
+                let nested_order_by_expression = order_by_expressions
+                    .objects
+                    .get(&orderable_object_field.order_by_expression_identifier)
+                    .ok_or_else(|| models::ModelsError::UnknownOrderByExpressionIdentifier {
+                        model_name: model_name.clone(),
+                        order_by_expression_identifier: orderable_object_field
+                            .order_by_expression_identifier
+                            .clone(),
+                    })?;
+                validate_nulls_order_compatibility(
+                    nested_order_by_expression,
+                    model_source,
+                    model_name,
+                    order_by_expressions,
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn validate_data_connector_compatibility(
     order_by_expression: &order_by_expressions::ObjectOrderByExpression,
     model_source: &models::ModelSource,
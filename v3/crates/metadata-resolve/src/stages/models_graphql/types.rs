@@ -2,8 +2,12 @@ use crate::{helpers::types::DuplicateRootFieldError, types::warning::Warning};
 use indexmap::IndexMap;
 use lang_graphql::ast::common::{self as ast};
 use open_dds::{
-    aggregates::AggregateExpressionName,
-    data_connector::{DataConnectorColumnName, DataConnectorName},
+    aggregates::{
+        AggregateExpressionName, DataConnectorAggregationFunctionName,
+        DataConnectorExtractionFunctionName,
+    },
+    commands::ProcedureName,
+    data_connector::{DataConnectorColumnName, DataConnectorName, DataConnectorOperatorName},
     models::ModelName,
     types::{Deprecated, FieldName},
 };
@@ -61,6 +65,27 @@ pub struct SelectManyGraphQlDefinition {
     #[serde(default = "serde_ext::ser_default")]
     #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
     pub subscription: Option<SubscriptionGraphQlDefinition>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub stream_subscription: Option<StreamSubscriptionGraphQlDefinition>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub cursor_pagination: Option<CursorPaginationGraphQlDefinition>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub distinct_on: Option<DistinctOnGraphQlDefinition>,
+}
+
+/// The `distinct_on` argument of the select many root field and the array relationships of a
+/// model. Distinct rows are fetched by grouping the rows on the chosen columns, and by selecting
+/// the columns of the first row of each group with the row aggregation function.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DistinctOnGraphQlDefinition {
+    pub argument_name: ast::Name,
+    pub enum_type_name: ast::TypeName,
+    pub row_aggregation_function: DataConnectorAggregationFunctionName,
+    /// The fields the rows can be distinct on, with the columns they are mapped to
+    pub fields: IndexMap<FieldName, DataConnectorColumnName>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -77,6 +102,118 @@ pub struct SelectAggregateGraphQlDefinition {
     #[serde(default = "serde_ext::ser_default")]
     #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
     pub subscription: Option<SubscriptionGraphQlDefinition>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub group_by: Option<GroupByGraphQlDefinition>,
+}
+
+/// The group by root field of a model, which returns the groups of its rows with the aggregates
+/// over each group
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GroupByGraphQlDefinition {
+    pub query_root_field: ast::Name,
+    pub group_type_name: ast::TypeName,
+    pub dimensions_type_name: ast::TypeName,
+    pub order_by_type_name: ast::TypeName,
+    pub order_by_argument_name: Option<ast::Name>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub description: Option<String>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub deprecated: Option<Deprecated>,
+    /// The values the rows can be grouped by, by the names of their fields in the dimensions type
+    pub dimensions: IndexMap<ast::Name, GroupByDimension>,
+}
+
+/// A value the rows of a model can be grouped by: the value of a field, or a part extracted
+/// from it
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GroupByDimension {
+    pub field_name: FieldName,
+    pub column: DataConnectorColumnName,
+    /// The function of the data connector that extracts the part of the value of the field, such
+    /// as its year. The extracted parts are integers.
+    pub extraction: Option<DataConnectorExtractionFunctionName>,
+}
+
+/// A query root field paginating through the model with a Relay connection, whose cursors are
+/// built from the requested ordering followed by the global ID fields of the model.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SelectConnectionGraphQlDefinition {
+    pub query_root_field: ast::Name,
+    pub connection_type_name: ast::TypeName,
+    pub edge_type_name: ast::TypeName,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub description: Option<String>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub deprecated: Option<Deprecated>,
+    /// The `first`/`after` and `last`/`before` pagination of the connection
+    pub cursor_pagination: CursorPaginationGraphQlDefinition,
+}
+
+/// The argument of the insert procedure of a model that takes the objects to insert
+pub const MUTATION_OBJECTS_ARGUMENT: &str = "objects";
+/// The argument of the update procedures of a model that takes the new values of the columns
+pub const MUTATION_UPDATE_COLUMNS_ARGUMENT: &str = "update_columns";
+/// The argument of the update and delete procedures of a model that takes the predicate
+/// selecting the affected objects
+pub const MUTATION_PRE_CHECK_ARGUMENT: &str = "pre_check";
+/// The argument of the insert and update procedures of a model that takes the predicate the
+/// affected objects must satisfy
+pub const MUTATION_POST_CHECK_ARGUMENT: &str = "post_check";
+/// The field of the result of a mutation procedure with the number of affected objects
+pub const MUTATION_AFFECTED_ROWS_FIELD: &str = "affected_rows";
+/// The field of the result of a mutation procedure with the affected objects
+pub const MUTATION_RETURNING_FIELD: &str = "returning";
+
+/// The mutation root fields of a model, which insert, update and delete its objects by calling
+/// procedures of its data connector.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ModelMutationsGraphQlDefinition {
+    pub response_type_name: ast::TypeName,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub set_input_type_name: Option<ast::TypeName>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub insert: Option<ModelMutationGraphQlDefinition>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub update_by_unique_key: Option<UpdateByUniqueKeyGraphQlDefinition>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub update: Option<ModelMutationGraphQlDefinition>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub delete: Option<ModelMutationGraphQlDefinition>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ModelMutationGraphQlDefinition {
+    pub mutation_root_field: ast::Name,
+    pub procedure_name: ProcedureName,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub description: Option<String>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub deprecated: Option<Deprecated>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateByUniqueKeyGraphQlDefinition {
+    pub mutation_root_field: ast::Name,
+    pub procedure_name: ProcedureName,
+    pub unique_identifier: IndexMap<FieldName, UniqueIdentifierField>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub description: Option<String>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub deprecated: Option<Deprecated>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -91,6 +228,53 @@ pub struct SubscriptionGraphQlDefinition {
     pub polling_interval_ms: u64,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StreamSubscriptionGraphQlDefinition {
+    pub root_field: ast::Name,
+    pub cursor_input_type_name: ast::TypeName,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub description: Option<String>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub deprecated: Option<Deprecated>,
+    pub polling_interval_ms: u64,
+    /// The fields of the model that can be used as the cursor of the stream
+    pub cursor_fields: IndexMap<FieldName, StreamCursorField>,
+}
+
+/// A field that can be used as the cursor of a streaming subscription. Rows are streamed in
+/// the ascending order of the cursor, which is advanced using the "greater than" operator.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StreamCursorField {
+    pub field_type: QualifiedTypeReference,
+    pub ndc_column: DataConnectorColumnName,
+    pub greater_than_operator: DataConnectorOperatorName,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CursorPaginationGraphQlDefinition {
+    pub first_argument_name: ast::Name,
+    pub after_argument_name: ast::Name,
+    pub last_argument_name: ast::Name,
+    pub before_argument_name: ast::Name,
+    /// The fields that uniquely identify a row, by which rows are ordered after the requested ordering
+    pub unique_identifier: Vec<FieldName>,
+    /// The fields of the model that can be part of a cursor, ie. that paginated rows can be ordered by
+    pub cursor_fields: IndexMap<FieldName, CursorField>,
+}
+
+/// A field that can be part of a pagination cursor. The rows after or before a cursor are
+/// selected by comparing the fields of the cursor using the "equal", "greater than" and
+/// "less than" operators.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CursorField {
+    pub ndc_column: DataConnectorColumnName,
+    pub equal_operator: DataConnectorOperatorName,
+    pub greater_than_operator: DataConnectorOperatorName,
+    pub less_than_operator: DataConnectorOperatorName,
+}
+
 // TODO: add support for aggregates
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct OrderByExpressionInfo {
@@ -126,6 +310,8 @@ pub struct ModelGraphQlApi {
     pub select_uniques: Vec<SelectUniqueGraphQlDefinition>,
     pub select_many: Option<SelectManyGraphQlDefinition>,
     pub select_aggregate: Option<SelectAggregateGraphQlDefinition>,
+    pub select_connection: Option<SelectConnectionGraphQlDefinition>,
+    pub mutations: Option<ModelMutationsGraphQlDefinition>,
     pub order_by_expression: Option<ModelOrderByExpression>,
     pub limit_field: Option<LimitFieldGraphqlConfig>,
     pub offset_field: Option<OffsetFieldGraphqlConfig>,
@@ -147,6 +333,8 @@ pub enum ModelGraphqlIssue {
     UnnecessaryModelArgumentsGraphQlInputConfiguration { model_name: Qualified<ModelName> },
     #[error("an unnecessary filter input type name graphql configuration has been specified for model {model_name:} that does not use aggregates")]
     UnnecessaryFilterInputTypeNameGraphqlConfiguration { model_name: Qualified<ModelName> },
+    #[error("the model {model_name} has defined a streaming subscription, but it will not appear in the GraphQL API as none of its fields can be used as a cursor. A cursor field must be a scalar field mapped to a column with a 'greater than' operator")]
+    StreamSubscriptionWithoutCursorFields { model_name: Qualified<ModelName> },
 }
 
 impl ShouldBeAnError for ModelGraphqlIssue {
@@ -154,7 +342,8 @@ impl ShouldBeAnError for ModelGraphqlIssue {
         match self {
             ModelGraphqlIssue::MissingAggregateFilterInputFieldNameInGraphqlConfig { .. }
             | ModelGraphqlIssue::UnnecessaryModelArgumentsGraphQlInputConfiguration { .. }
-            | ModelGraphqlIssue::UnnecessaryFilterInputTypeNameGraphqlConfiguration { .. } => false,
+            | ModelGraphqlIssue::UnnecessaryFilterInputTypeNameGraphqlConfiguration { .. }
+            | ModelGraphqlIssue::StreamSubscriptionWithoutCursorFields { .. } => false,
             ModelGraphqlIssue::DuplicateRootField { .. } => {
                 flags.contains(open_dds::flags::Flag::RequireUniqueModelGraphqlNames)
             }
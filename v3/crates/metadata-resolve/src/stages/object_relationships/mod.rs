@@ -9,8 +9,11 @@ use lang_graphql::ast::common::{self as ast};
 use open_dds::aggregates::AggregateExpressionName;
 use open_dds::relationships::{FieldAccess, RelationshipName, RelationshipType, RelationshipV1};
 use open_dds::{
-    commands::CommandName, data_connector::DataConnectorName, models::ModelName,
-    types::CustomTypeName,
+    arguments::ArgumentName,
+    commands::CommandName,
+    data_connector::DataConnectorName,
+    models::ModelName,
+    types::{CustomTypeName, FieldName},
 };
 
 use crate::helpers::types::mk_name;
@@ -19,13 +22,16 @@ use crate::stages::{
     object_types, relationships, type_permissions,
 };
 use crate::types::error::{Error, RelationshipError};
-use crate::types::subgraph::Qualified;
+use crate::types::subgraph::{
+    Qualified, QualifiedBaseType, QualifiedTypeName, QualifiedTypeReference,
+};
 
 pub use types::{
     AggregateRelationship, CommandRelationshipTarget, FieldNestedness, ModelRelationshipTarget,
     ObjectTypeWithRelationships, RelationshipCapabilities, RelationshipCommandMapping,
-    RelationshipExecutionCategory, RelationshipField, RelationshipModelMapping, RelationshipTarget,
-    RelationshipTargetName,
+    RelationshipCommandValueMapping, RelationshipExecutionCategory, RelationshipField,
+    RelationshipModelArgumentMapping, RelationshipModelMapping, RelationshipModelValueMapping,
+    RelationshipNestedField, RelationshipTarget, RelationshipTargetName,
 };
 
 /// resolve relationships
@@ -45,6 +51,7 @@ pub fn resolve(
         aggregates::AggregateExpression,
     >,
     graphql_config: &graphql_config::GraphqlConfig,
+    flags: &open_dds::flags::OpenDdFlags,
 ) -> Result<BTreeMap<Qualified<CustomTypeName>, ObjectTypeWithRelationships>, Error> {
     // For each object type, get all its relationships and resolve them into the fields
     // we add to the object type that represent the relationship navigation
@@ -69,6 +76,7 @@ pub fn resolve(
                             &object_types_with_permissions,
                             graphql_config,
                             &object_type_with_permissions.object_type,
+                            flags,
                         )?;
                         let field_name = resolved_relationship_field.field_name.clone();
                         if relationship_fields
@@ -167,95 +175,181 @@ pub fn relationship_execution_category(
     }
 }
 
+/// The resolved source of a relationship mapping
+enum RelationshipMappingSource<'a> {
+    Field(&'a FieldAccess, Vec<RelationshipNestedField>),
+    Value(&'a open_dds::permissions::ValueExpression),
+}
+
+/// Whether any of a relationship's mappings use nested field paths
+struct RelationshipMappingNesting {
+    source: bool,
+    target: bool,
+}
+
 fn resolve_relationship_source_mapping<'a>(
     relationship_name: &'a RelationshipName,
     source_type_name: &'a Qualified<CustomTypeName>,
     source_type: &object_types::ObjectTypeRepresentation,
     relationship_mapping: &'a open_dds::relationships::RelationshipMapping,
-) -> Result<&'a FieldAccess, Error> {
+    object_types: &type_permissions::ObjectTypesWithPermissions,
+) -> Result<RelationshipMappingSource<'a>, Error> {
     match &relationship_mapping.source {
-        open_dds::relationships::RelationshipMappingSource::Value(_v) => Err(Error::NotSupported {
-            reason: "Relationship mappings from value expressions are not supported yet."
-                .to_string(),
-        }),
+        open_dds::relationships::RelationshipMappingSource::Value(value) => {
+            Ok(RelationshipMappingSource::Value(value))
+        }
         open_dds::relationships::RelationshipMappingSource::FieldPath(field_path) => {
-            match &field_path[..] {
-                [] => Err(Error::EmptyFieldPath {
+            let Some((field_access, nested_field_path)) = field_path.split_first() else {
+                return Err(Error::EmptyFieldPath {
                     location: "source".to_string(),
                     type_name: source_type_name.clone(),
                     relationship_name: relationship_name.clone(),
-                }),
-                [field_access] => {
-                    if !source_type.fields.contains_key(&field_access.field_name) {
-                        return Err(Error::ObjectRelationshipError {
-                            relationship_error:
-                                RelationshipError::UnknownSourceFieldInRelationshipMapping {
-                                    relationship_name: relationship_name.clone(),
-                                    source_type: source_type_name.clone(),
-                                    field_name: field_access.field_name.clone(),
-                                },
-                        });
-                    };
-                    Ok(field_access)
-                }
-                _ => Err(Error::NotSupported {
-                    reason: "Relationships with nested field paths are not supported yet."
-                        .to_string(),
-                }),
-            }
+                });
+            };
+            let Some(field_definition) = source_type.fields.get(&field_access.field_name) else {
+                return Err(Error::ObjectRelationshipError {
+                    relationship_error:
+                        RelationshipError::UnknownSourceFieldInRelationshipMapping {
+                            relationship_name: relationship_name.clone(),
+                            source_type: source_type_name.clone(),
+                            field_name: field_access.field_name.clone(),
+                        },
+                });
+            };
+            let (nested_fields, _) = resolve_nested_field_path(
+                relationship_name,
+                source_type_name,
+                "source",
+                field_access,
+                &field_definition.field_type,
+                nested_field_path,
+                object_types,
+            )?;
+            Ok(RelationshipMappingSource::Field(
+                field_access,
+                nested_fields,
+            ))
         }
     }
 }
 
+/// Resolves the part of a relationship mapping field path that follows its
+/// first field, returning the nested fields along the path and the type of the
+/// last field. Every field on the path but the last must be a nested object.
+fn resolve_nested_field_path(
+    relationship_name: &RelationshipName,
+    source_type_name: &Qualified<CustomTypeName>,
+    location: &str,
+    first_field: &FieldAccess,
+    first_field_type: &QualifiedTypeReference,
+    nested_field_path: &[FieldAccess],
+    object_types: &type_permissions::ObjectTypesWithPermissions,
+) -> Result<(Vec<RelationshipNestedField>, QualifiedTypeReference), Error> {
+    let mut nested_fields = Vec::new();
+    let mut parent_field_name = &first_field.field_name;
+    let mut field_type = first_field_type;
+    for field_access in nested_field_path {
+        let nested_object_type = match &field_type.underlying_type {
+            QualifiedBaseType::Named(QualifiedTypeName::Custom(type_name)) => object_types
+                .0
+                .get(type_name)
+                .map(|object_type| (type_name, object_type)),
+            QualifiedBaseType::Named(QualifiedTypeName::Inbuilt(_))
+            | QualifiedBaseType::List(_) => None,
+        };
+        let Some((object_type_name, object_type)) = nested_object_type else {
+            return Err(RelationshipError::NestedFieldPathThroughNonObjectField {
+                source_type: source_type_name.clone(),
+                relationship_name: relationship_name.clone(),
+                location: location.to_string(),
+                field_name: parent_field_name.clone(),
+            }
+            .into());
+        };
+        let Some(field_definition) = object_type.object_type.fields.get(&field_access.field_name)
+        else {
+            return Err(RelationshipError::UnknownNestedFieldInRelationshipMapping {
+                source_type: source_type_name.clone(),
+                relationship_name: relationship_name.clone(),
+                location: location.to_string(),
+                type_name: object_type_name.clone(),
+                field_name: field_access.field_name.clone(),
+            }
+            .into());
+        };
+        nested_fields.push(RelationshipNestedField {
+            object_type: object_type_name.clone(),
+            field_name: field_access.field_name.clone(),
+        });
+        parent_field_name = &field_access.field_name;
+        field_type = &field_definition.field_type;
+    }
+    Ok((nested_fields, field_type.clone()))
+}
+
 fn resolve_relationship_mappings_model(
     relationship: &RelationshipV1,
     source_type_name: &Qualified<CustomTypeName>,
     source_type: &object_types::ObjectTypeRepresentation,
     target_model: &models::Model,
-) -> Result<Vec<RelationshipModelMapping>, Error> {
+    object_types: &type_permissions::ObjectTypesWithPermissions,
+    flags: &open_dds::flags::OpenDdFlags,
+) -> Result<
+    (
+        Vec<RelationshipModelMapping>,
+        Vec<RelationshipModelValueMapping>,
+        Vec<RelationshipModelArgumentMapping>,
+    ),
+    Error,
+> {
     let mut resolved_relationship_mappings = Vec::new();
+    let mut resolved_relationship_value_mappings = Vec::new();
+    let mut resolved_relationship_argument_mappings = Vec::new();
     let mut field_mapping_btree_set_for_validation = BTreeSet::new();
+    let mut target_model_arguments_btree_set_for_validation = BTreeSet::new();
     for relationship_mapping in &relationship.mapping {
         let resolved_relationship_source_mapping = resolve_relationship_source_mapping(
             &relationship.name,
             source_type_name,
             source_type,
             relationship_mapping,
+            object_types,
         )?;
 
-        let resolved_relationship_target_mapping = match &relationship_mapping.target {
-            open_dds::relationships::RelationshipMappingTarget::Argument(
-                _argument_mapping_target,
-            ) => return Err(Error::NotSupported {
-                reason:
-                    "Relationship mappings to model arguments expressions are not supported yet."
-                        .to_string(),
-            }),
-            open_dds::relationships::RelationshipMappingTarget::ModelField(field_path) => {
-                match &field_path[..] {
-                    [] => {
+        let (resolved_relationship_target_mapping, target_nested_field_path) =
+            match &relationship_mapping.target {
+                open_dds::relationships::RelationshipMappingTarget::Argument(
+                    argument_mapping_target,
+                ) => {
+                    let argument_mapping = resolve_relationship_model_argument_mapping(
+                        relationship,
+                        source_type_name,
+                        target_model,
+                        &argument_mapping_target.argument_name,
+                        resolved_relationship_source_mapping,
+                        &mut field_mapping_btree_set_for_validation,
+                        &mut target_model_arguments_btree_set_for_validation,
+                    )?;
+                    resolved_relationship_argument_mappings.push(argument_mapping);
+                    continue;
+                }
+                open_dds::relationships::RelationshipMappingTarget::ModelField(field_path) => {
+                    let Some(target_field_path) = field_path.split_first() else {
                         return Err(Error::EmptyFieldPath {
                             location: "target".to_string(),
                             type_name: source_type_name.clone(),
                             relationship_name: relationship.name.clone(),
-                        })
-                    }
-                    [t] => t,
-                    _ => {
-                        return Err(Error::NotSupported {
-                            reason: "Relationships with nested field paths are not supported yet."
-                                .to_string(),
-                        })
-                    }
+                        });
+                    };
+                    target_field_path
                 }
-            }
-        };
+            };
 
         // Check if the target field exists in the target model.
-        if !target_model
+        let Some(target_field_definition) = target_model
             .type_fields
-            .contains_key(&resolved_relationship_target_mapping.field_name)
-        {
+            .get(&resolved_relationship_target_mapping.field_name)
+        else {
             return Err(Error::ObjectRelationshipError {
                 relationship_error: RelationshipError::UnknownTargetFieldInRelationshipMapping {
                     relationship_name: relationship.name.clone(),
@@ -264,50 +358,152 @@ fn resolve_relationship_mappings_model(
                     field_name: resolved_relationship_target_mapping.field_name.clone(),
                 },
             });
-        }
+        };
 
-        // Check if the source field is already mapped to a target field
-        let resolved_relationship_mapping = {
-            if field_mapping_btree_set_for_validation
-                .insert(&resolved_relationship_source_mapping.field_name)
-            {
-                let target_ndc_column = target_model
-                    .source
-                    .as_ref()
-                    .map(|target_model_source| {
-                        models::get_ndc_column_for_comparison(
-                            &target_model.name,
-                            &target_model.data_type,
-                            target_model_source,
-                            &resolved_relationship_target_mapping.field_name,
-                            || {
-                                format!(
-                                    "the mapping for relationship {} on type {}",
-                                    relationship.name, source_type_name
-                                )
-                            },
+        let (target_nested_fields, target_field_type) = resolve_nested_field_path(
+            &relationship.name,
+            source_type_name,
+            "target",
+            resolved_relationship_target_mapping,
+            &target_field_definition.field_type,
+            target_nested_field_path,
+            object_types,
+        )?;
+
+        let target_ndc_column = target_model
+            .source
+            .as_ref()
+            .map(|target_model_source| {
+                models::get_ndc_nested_column_for_comparison(
+                    &target_model.name,
+                    &target_model.data_type,
+                    target_model_source,
+                    &resolved_relationship_target_mapping.field_name,
+                    &target_nested_fields
+                        .iter()
+                        .map(|nested_field| (&nested_field.object_type, &nested_field.field_name))
+                        .collect::<Vec<_>>(),
+                    || {
+                        format!(
+                            "the mapping for relationship {} on type {}",
+                            relationship.name, source_type_name
                         )
-                    })
-                    .transpose()?;
-                Ok(RelationshipModelMapping {
-                    source_field: resolved_relationship_source_mapping.clone(),
+                    },
+                )
+            })
+            .transpose()?;
+
+        match resolved_relationship_source_mapping {
+            RelationshipMappingSource::Field(source_field, source_nested_fields) => {
+                // Check if the source field is already mapped to a target field
+                if !field_mapping_btree_set_for_validation
+                    .insert(types::field_path(source_field, &source_nested_fields))
+                {
+                    return Err(Error::ObjectRelationshipError {
+                        relationship_error: RelationshipError::MappingExistsInRelationship {
+                            type_name: source_type_name.clone(),
+                            field_name: source_field.field_name.clone(),
+                            relationship_name: relationship.name.clone(),
+                        },
+                    });
+                }
+                resolved_relationship_mappings.push(RelationshipModelMapping {
+                    source_field: source_field.clone(),
+                    source_nested_fields,
                     target_field: resolved_relationship_target_mapping.clone(),
+                    target_nested_fields,
                     target_ndc_column,
-                })
-            } else {
-                Err(Error::ObjectRelationshipError {
+                });
+            }
+            RelationshipMappingSource::Value(source_value) => {
+                resolved_relationship_value_mappings.push(RelationshipModelValueMapping {
+                    source_value: type_permissions::resolve_value_expression(flags, source_value),
+                    target_field: resolved_relationship_target_mapping.clone(),
+                    target_nested_fields,
+                    target_field_type,
+                    target_ndc_column,
+                });
+            }
+        }
+    }
+
+    Ok((
+        resolved_relationship_mappings,
+        resolved_relationship_value_mappings,
+        resolved_relationship_argument_mappings,
+    ))
+}
+
+/// Resolves a relationship mapping from a field of the source object to an
+/// argument of the target model
+fn resolve_relationship_model_argument_mapping<'a>(
+    relationship: &RelationshipV1,
+    source_type_name: &Qualified<CustomTypeName>,
+    target_model: &models::Model,
+    target_argument_name: &'a ArgumentName,
+    source_mapping: RelationshipMappingSource<'a>,
+    field_mapping_btree_set_for_validation: &mut BTreeSet<Vec<FieldName>>,
+    target_model_arguments_btree_set_for_validation: &mut BTreeSet<&'a ArgumentName>,
+) -> Result<RelationshipModelArgumentMapping, Error> {
+    // Check if the target argument exists in the target model.
+    if !target_model.arguments.contains_key(target_argument_name) {
+        return Err(Error::ObjectRelationshipError {
+            relationship_error:
+                RelationshipError::UnknownTargetModelArgumentInRelationshipMapping {
+                    relationship_name: relationship.name.clone(),
+                    source_type: source_type_name.clone(),
+                    model_name: target_model.name.clone(),
+                    argument_name: target_argument_name.clone(),
+                },
+        });
+    }
+
+    // Check if the target argument is already mapped to a field.
+    if !target_model_arguments_btree_set_for_validation.insert(target_argument_name) {
+        return Err(Error::ObjectRelationshipError {
+            relationship_error: RelationshipError::ModelArgumentMappingExistsInRelationship {
+                argument_name: target_argument_name.clone(),
+                model_name: target_model.name.clone(),
+                relationship_name: relationship.name.clone(),
+                type_name: source_type_name.clone(),
+            },
+        });
+    }
+
+    match source_mapping {
+        RelationshipMappingSource::Field(source_field, source_nested_fields) => {
+            // Check if the source field is already mapped to a target argument
+            if !field_mapping_btree_set_for_validation
+                .insert(types::field_path(source_field, &source_nested_fields))
+            {
+                return Err(Error::ObjectRelationshipError {
                     relationship_error: RelationshipError::MappingExistsInRelationship {
                         type_name: source_type_name.clone(),
-                        field_name: resolved_relationship_source_mapping.field_name.clone(),
+                        field_name: source_field.field_name.clone(),
                         relationship_name: relationship.name.clone(),
                     },
-                })
+                });
             }
-        }?;
-        resolved_relationship_mappings.push(resolved_relationship_mapping);
+            Ok(RelationshipModelArgumentMapping {
+                source_field: source_field.clone(),
+                source_nested_fields,
+                argument_name: target_argument_name.clone(),
+                ndc_argument_name: target_model
+                    .source
+                    .as_ref()
+                    .and_then(|target_model_source| {
+                        target_model_source
+                            .argument_mappings
+                            .get(target_argument_name)
+                            .cloned()
+                    }),
+            })
+        }
+        RelationshipMappingSource::Value(_) => Err(Error::NotSupported {
+            reason: "Relationship mappings from values to model arguments are not supported yet."
+                .to_string(),
+        }),
     }
-
-    Ok(resolved_relationship_mappings)
 }
 
 fn resolve_relationship_mappings_command(
@@ -315,8 +511,17 @@ fn resolve_relationship_mappings_command(
     source_type_name: &Qualified<CustomTypeName>,
     source_type: &object_types::ObjectTypeRepresentation,
     target_command: &commands::Command,
-) -> Result<Vec<RelationshipCommandMapping>, Error> {
+    object_types: &type_permissions::ObjectTypesWithPermissions,
+    flags: &open_dds::flags::OpenDdFlags,
+) -> Result<
+    (
+        Vec<RelationshipCommandMapping>,
+        Vec<RelationshipCommandValueMapping>,
+    ),
+    Error,
+> {
     let mut resolved_relationship_mappings = Vec::new();
+    let mut resolved_relationship_value_mappings = Vec::new();
     let mut field_mapping_btree_set_for_validation = BTreeSet::new();
     let mut target_command_arguments_btree_set_for_validation = BTreeSet::new();
 
@@ -326,6 +531,7 @@ fn resolve_relationship_mappings_command(
             source_type_name,
             source_type,
             relationship_mapping,
+            object_types,
         )?;
         let target_argument_name = match &relationship_mapping.target {
             open_dds::relationships::RelationshipMappingTarget::Argument(
@@ -340,7 +546,7 @@ fn resolve_relationship_mappings_command(
         };
 
         // Check if the target argument exists in the target command.
-        if !target_command.arguments.contains_key(target_argument_name) {
+        let Some(target_argument) = target_command.arguments.get(target_argument_name) else {
             return Err(Error::ObjectRelationshipError {
                 relationship_error: RelationshipError::UnknownTargetArgumentInRelationshipMapping {
                     relationship_name: relationship.name.clone(),
@@ -349,9 +555,9 @@ fn resolve_relationship_mappings_command(
                     argument_name: target_argument_name.clone(),
                 },
             });
-        }
+        };
 
-        // Check if the target argument is already mapped to a field in the source type.
+        // Check if the target argument is already mapped to a field or value.
         if !target_command_arguments_btree_set_for_validation.insert(target_argument_name) {
             return Err(Error::ObjectRelationshipError {
                 relationship_error: RelationshipError::ArgumentMappingExistsInRelationship {
@@ -363,29 +569,40 @@ fn resolve_relationship_mappings_command(
             });
         };
 
-        // Check if the source field is already mapped to a target argument
-        let resolved_relationship_mapping = {
-            if field_mapping_btree_set_for_validation
-                .insert(&resolved_relationship_source_mapping.field_name)
-            {
-                Ok(RelationshipCommandMapping {
-                    source_field: resolved_relationship_source_mapping.clone(),
+        match resolved_relationship_source_mapping {
+            RelationshipMappingSource::Field(source_field, source_nested_fields) => {
+                // Check if the source field is already mapped to a target argument
+                if !field_mapping_btree_set_for_validation
+                    .insert(types::field_path(source_field, &source_nested_fields))
+                {
+                    return Err(Error::ObjectRelationshipError {
+                        relationship_error: RelationshipError::MappingExistsInRelationship {
+                            type_name: source_type_name.clone(),
+                            field_name: source_field.field_name.clone(),
+                            relationship_name: relationship.name.clone(),
+                        },
+                    });
+                }
+                resolved_relationship_mappings.push(RelationshipCommandMapping {
+                    source_field: source_field.clone(),
+                    source_nested_fields,
                     argument_name: target_argument_name.clone(),
-                })
-            } else {
-                Err(Error::ObjectRelationshipError {
-                    relationship_error: RelationshipError::MappingExistsInRelationship {
-                        type_name: source_type_name.clone(),
-                        field_name: resolved_relationship_source_mapping.field_name.clone(),
-                        relationship_name: relationship.name.clone(),
-                    },
-                })
+                });
             }
-        }?;
-        resolved_relationship_mappings.push(resolved_relationship_mapping);
+            RelationshipMappingSource::Value(source_value) => {
+                resolved_relationship_value_mappings.push(RelationshipCommandValueMapping {
+                    source_value: type_permissions::resolve_value_expression(flags, source_value),
+                    argument_name: target_argument_name.clone(),
+                    argument_type: target_argument.argument_type.clone(),
+                });
+            }
+        }
     }
 
-    Ok(resolved_relationship_mappings)
+    Ok((
+        resolved_relationship_mappings,
+        resolved_relationship_value_mappings,
+    ))
 }
 
 fn get_relationship_capabilities(
@@ -396,6 +613,7 @@ fn get_relationship_capabilities(
     data_connectors: &data_connectors::DataConnectors,
     models: &IndexMap<Qualified<ModelName>, models::Model>,
     commands: &IndexMap<Qualified<CommandName>, commands::Command>,
+    mapping_nesting: &RelationshipMappingNesting,
 ) -> Result<Option<RelationshipCapabilities>, Error> {
     let Some(data_connector) = source_data_connector else {
         return Ok(None);
@@ -449,9 +667,36 @@ fn get_relationship_capabilities(
         });
     };
 
+    // Mappings to nested fields are compared against the target fields in a filter
+    if mapping_nesting.target && !capabilities.supports_nested_object_filtering {
+        return Err(Error::ObjectRelationshipError {
+            relationship_error:
+                RelationshipError::RelationshipTargetDoesNotSupportNestedObjectFiltering {
+                    type_name: type_name.clone(),
+                    relationship_name: relationship_name.clone(),
+                    data_connector_name: data_connector.name.clone(),
+                },
+        });
+    }
+
+    // NDC relationships can't map from fields in nested objects, and can only map to them if
+    // the connector supports nested relationships. Such relationships are executed as remote
+    // joins instead.
+    let supports_relationships =
+        capabilities
+            .supports_relationships
+            .clone()
+            .filter(|relationship_capabilities| {
+                !mapping_nesting.source
+                    && (!mapping_nesting.target
+                        || relationship_capabilities
+                            .supports_nested_relationships
+                            .is_some())
+            });
+
     Ok(Some(RelationshipCapabilities {
         foreach: (),
-        supports_relationships: capabilities.supports_relationships.clone(),
+        supports_relationships,
     }))
 }
 
@@ -560,6 +805,7 @@ fn resolve_model_relationship_fields(
     >,
     object_types: &type_permissions::ObjectTypesWithPermissions,
     graphql_config: &graphql_config::GraphqlConfig,
+    flags: &open_dds::flags::OpenDdFlags,
 ) -> Result<RelationshipField, Error> {
     let qualified_target_model_name = Qualified::new(
         target_model
@@ -580,6 +826,30 @@ fn resolve_model_relationship_fields(
         .as_ref()
         .map(|source| &source.data_connector);
 
+    let (mappings, value_mappings, argument_mappings) = resolve_relationship_mappings_model(
+        relationship,
+        source_type_name,
+        source_type,
+        resolved_target_model,
+        object_types,
+        flags,
+    )?;
+
+    let mapping_nesting = RelationshipMappingNesting {
+        source: mappings
+            .iter()
+            .any(|mapping| !mapping.source_nested_fields.is_empty())
+            || argument_mappings
+                .iter()
+                .any(|argument_mapping| !argument_mapping.source_nested_fields.is_empty()),
+        target: mappings
+            .iter()
+            .any(|mapping| !mapping.target_nested_fields.is_empty())
+            || value_mappings
+                .iter()
+                .any(|value_mapping| !value_mapping.target_nested_fields.is_empty()),
+    };
+
     let target_capabilities = get_relationship_capabilities(
         source_type_name,
         &relationship.name,
@@ -588,13 +858,7 @@ fn resolve_model_relationship_fields(
         data_connectors,
         models,
         &IndexMap::new(),
-    )?;
-
-    let mappings = resolve_relationship_mappings_model(
-        relationship,
-        source_type_name,
-        source_type,
-        resolved_target_model,
+        &mapping_nesting,
     )?;
 
     let relationship_aggregate = resolve_aggregate_relationship(
@@ -608,6 +872,25 @@ fn resolve_model_relationship_fields(
         data_connector_scalars,
     )?;
 
+    let connection_field_name = relationship
+        .graphql
+        .as_ref()
+        .and_then(|graphql| graphql.connection_field_name.as_ref())
+        .map(|field_name| -> Result<_, Error> {
+            // A connection pages through the objects of an array relationship
+            if target_model.relationship_type != RelationshipType::Array {
+                return Err(
+                    RelationshipError::ConnectionIsOnlyAllowedOnArrayRelationships {
+                        type_name: source_type_name.clone(),
+                        relationship_name: relationship.name.clone(),
+                    }
+                    .into(),
+                );
+            }
+            mk_name(field_name.as_str())
+        })
+        .transpose()?;
+
     let relationship_field = RelationshipField {
         field_name: make_relationship_field_name(&relationship.name)?,
         relationship_name: relationship.name.clone(),
@@ -617,7 +900,10 @@ fn resolve_model_relationship_fields(
             relationship_type: target_model.relationship_type.clone(),
             target_typename: resolved_target_model.data_type.clone(),
             mappings,
+            value_mappings,
+            argument_mappings,
             relationship_aggregate,
+            connection_field_name,
         })),
         target_capabilities,
         description: relationship.description.clone(),
@@ -641,6 +927,8 @@ fn resolve_command_relationship_field(
     source_type_name: &Qualified<CustomTypeName>,
     relationship: &RelationshipV1,
     source_type: &object_types::ObjectTypeRepresentation,
+    object_types: &type_permissions::ObjectTypesWithPermissions,
+    flags: &open_dds::flags::OpenDdFlags,
 ) -> Result<RelationshipField, Error> {
     let qualified_target_command_name = Qualified::new(
         target_command
@@ -661,15 +949,27 @@ fn resolve_command_relationship_field(
         .as_ref()
         .map(|source| &source.data_connector);
 
+    let (mappings, value_mappings) = resolve_relationship_mappings_command(
+        relationship,
+        source_type_name,
+        source_type,
+        resolved_target_command,
+        object_types,
+        flags,
+    )?;
+
+    let mapping_nesting = RelationshipMappingNesting {
+        source: mappings
+            .iter()
+            .any(|mapping| !mapping.source_nested_fields.is_empty()),
+        target: false,
+    };
+
     let target = RelationshipTarget::Command(CommandRelationshipTarget {
         command_name: qualified_target_command_name,
         target_type: resolved_target_command.output_type.clone(),
-        mappings: resolve_relationship_mappings_command(
-            relationship,
-            source_type_name,
-            source_type,
-            resolved_target_command,
-        )?,
+        mappings,
+        value_mappings,
     });
 
     let target_capabilities = get_relationship_capabilities(
@@ -680,6 +980,7 @@ fn resolve_command_relationship_field(
         data_connectors,
         &IndexMap::new(),
         commands,
+        &mapping_nesting,
     )?;
 
     let field_name = mk_name(relationship.name.as_str())?;
@@ -711,6 +1012,7 @@ fn resolve_relationship_field(
     object_types: &type_permissions::ObjectTypesWithPermissions,
     graphql_config: &graphql_config::GraphqlConfig,
     source_type: &object_types::ObjectTypeRepresentation,
+    flags: &open_dds::flags::OpenDdFlags,
 ) -> Result<RelationshipField, Error> {
     match &relationship.target {
         open_dds::relationships::RelationshipTarget::Model(target_model) => {
@@ -725,6 +1027,7 @@ fn resolve_relationship_field(
                 aggregate_expressions,
                 object_types,
                 graphql_config,
+                flags,
             )
         }
         open_dds::relationships::RelationshipTarget::Command(target_command) => {
@@ -735,6 +1038,8 @@ fn resolve_relationship_field(
                 source_type_name,
                 relationship,
                 source_type,
+                object_types,
+                flags,
             )?;
             Ok(command_relationship_field)
         }
@@ -1,9 +1,14 @@
 use crate::stages::{data_connectors, object_types, type_permissions};
+use crate::types::permission::ValueExpression;
 use crate::types::subgraph::{Qualified, QualifiedTypeReference};
 use indexmap::IndexMap;
 use open_dds::aggregates::AggregateExpressionName;
 use open_dds::permissions::Role;
-use open_dds::{commands::CommandName, models::ModelName, types::CustomTypeName};
+use open_dds::{
+    commands::CommandName,
+    models::ModelName,
+    types::{CustomTypeName, DataConnectorArgumentName, FieldName},
+};
 use serde::{Deserialize, Serialize};
 
 use crate::helpers::types::NdcColumnForComparison;
@@ -19,7 +24,7 @@ pub struct ObjectTypeWithRelationships {
     pub object_type: object_types::ObjectTypeRepresentation,
     /// permissions on this type, when it is used in an output context (e.g. as
     /// a return type of Model or Command)
-    pub type_output_permissions: BTreeMap<Role, open_dds::permissions::TypeOutputPermission>,
+    pub type_output_permissions: BTreeMap<Role, type_permissions::TypeOutputPermission>,
     /// permissions on this type, when it is used in an input context (e.g. in
     /// an argument type of Model or Command)
     pub type_input_permissions: BTreeMap<Role, type_permissions::TypeInputPermission>,
@@ -42,7 +47,10 @@ pub struct ModelRelationshipTarget {
     pub relationship_type: RelationshipType,
     pub target_typename: Qualified<CustomTypeName>,
     pub mappings: Vec<RelationshipModelMapping>,
+    pub value_mappings: Vec<RelationshipModelValueMapping>,
+    pub argument_mappings: Vec<RelationshipModelArgumentMapping>,
     pub relationship_aggregate: Option<AggregateRelationship>, // only applicable to array relationships
+    pub connection_field_name: Option<ast::Name>, // only applicable to array relationships
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -58,6 +66,7 @@ pub struct CommandRelationshipTarget {
     pub command_name: Qualified<CommandName>,
     pub target_type: QualifiedTypeReference,
     pub mappings: Vec<RelationshipCommandMapping>,
+    pub value_mappings: Vec<RelationshipCommandValueMapping>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -69,7 +78,9 @@ pub enum RelationshipTargetName {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct RelationshipModelMapping {
     pub source_field: FieldAccess,
+    pub source_nested_fields: Vec<RelationshipNestedField>,
     pub target_field: FieldAccess,
+    pub target_nested_fields: Vec<RelationshipNestedField>,
     // Optional because we allow building schema without specifying a data source
     pub target_ndc_column: Option<NdcColumnForComparison>,
 }
@@ -77,9 +88,87 @@ pub struct RelationshipModelMapping {
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct RelationshipCommandMapping {
     pub source_field: FieldAccess,
+    pub source_nested_fields: Vec<RelationshipNestedField>,
     pub argument_name: ArgumentName,
 }
 
+/// A relationship mapping from a field of the source object to an argument of
+/// the target model
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RelationshipModelArgumentMapping {
+    pub source_field: FieldAccess,
+    pub source_nested_fields: Vec<RelationshipNestedField>,
+    pub argument_name: ArgumentName,
+    // Optional because we allow building schema without specifying a data source
+    pub ndc_argument_name: Option<DataConnectorArgumentName>,
+}
+
+impl RelationshipModelMapping {
+    /// The path of the source field, starting from a field of the source type
+    pub fn source_field_path(&self) -> Vec<FieldName> {
+        field_path(&self.source_field, &self.source_nested_fields)
+    }
+}
+
+impl RelationshipModelArgumentMapping {
+    /// The path of the source field, starting from a field of the source type
+    pub fn source_field_path(&self) -> Vec<FieldName> {
+        field_path(&self.source_field, &self.source_nested_fields)
+    }
+}
+
+impl RelationshipCommandMapping {
+    /// The path of the source field, starting from a field of the source type
+    pub fn source_field_path(&self) -> Vec<FieldName> {
+        field_path(&self.source_field, &self.source_nested_fields)
+    }
+}
+
+pub(crate) fn field_path(
+    field: &FieldAccess,
+    nested_fields: &[RelationshipNestedField],
+) -> Vec<FieldName> {
+    std::iter::once(field.field_name.clone())
+        .chain(
+            nested_fields
+                .iter()
+                .map(|nested_field| nested_field.field_name.clone()),
+        )
+        .collect()
+}
+
+/// A field inside a nested object, reached by following a relationship
+/// mapping's field path from a top-level field of the source or target type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RelationshipNestedField {
+    /// The object type that contains the field
+    pub object_type: Qualified<CustomTypeName>,
+    pub field_name: FieldName,
+}
+
+/// A relationship mapping whose source is a literal or session variable rather
+/// than a field of the source object. It restricts the target model to rows
+/// whose target field is equal to the value.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RelationshipModelValueMapping {
+    pub source_value: ValueExpression,
+    pub target_field: FieldAccess,
+    pub target_nested_fields: Vec<RelationshipNestedField>,
+    pub target_field_type: QualifiedTypeReference,
+    // Optional because we allow building schema without specifying a data source
+    pub target_ndc_column: Option<NdcColumnForComparison>,
+}
+
+/// A relationship mapping whose source is a literal or session variable rather
+/// than a field of the source object. The value is passed as the target
+/// command argument.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RelationshipCommandValueMapping {
+    pub source_value: ValueExpression,
+    pub argument_name: ArgumentName,
+    pub argument_type: QualifiedTypeReference,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct RelationshipField {
     pub field_name: ast::Name,
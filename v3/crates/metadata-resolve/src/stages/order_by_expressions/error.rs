@@ -2,6 +2,7 @@ use crate::stages::graphql_config;
 use crate::types::subgraph::{Qualified, QualifiedBaseType};
 
 use open_dds::{
+    aggregates::AggregateExpressionName,
     order_by_expression::OrderByExpressionName,
     relationships::RelationshipName,
     types::{CustomTypeName, FieldName, TypeName},
@@ -34,6 +35,17 @@ pub enum OrderByExpressionError {
         order_by_expression_name: OrderByExpressionName,
         relationship_name: RelationshipName,
     },
+    #[error("The aggregate expression {aggregate_expression_name} referenced in orderable relationship {relationship_name} has not been defined")]
+    UnknownAggregateExpressionNameInOrderableRelationship {
+        aggregate_expression_name: AggregateExpressionName,
+        relationship_name: RelationshipName,
+    },
+    #[error("An aggregate expression can only be used to order by the array relationship to a model, but {relationship_name} is not an array relationship to a model")]
+    AggregateExpressionInNonArrayOrderableRelationship { relationship_name: RelationshipName },
+    #[error("The relationship {relationship_name} has mappings from values and cannot be used as an orderable relationship")]
+    OrderableRelationshipWithValueMappings { relationship_name: RelationshipName },
+    #[error("The relationship {relationship_name} has mappings to model arguments and cannot be used as an orderable relationship")]
+    OrderableRelationshipWithArgumentMappings { relationship_name: RelationshipName },
     #[error("The type of the order by expression {order_by_expression_name} referenced in field {field_name} does not match the field type. Order by expression type: {order_by_expression_type}; field type: {field_type}. ")]
     OrderableFieldTypeError {
         order_by_expression_name: OrderByExpressionName,
@@ -43,6 +55,10 @@ pub enum OrderByExpressionError {
     },
     #[error("{0}")]
     GraphqlConfigError(#[from] graphql_config::GraphqlConfigError),
+    #[error(
+        "there is no enum type in orderByInput of GraphqlConfig with the directions: {directions}"
+    )]
+    NoOrderByEnumTypeForDirections { directions: String },
     #[error("{message}")]
     UnsupportedFeature { message: String },
 }
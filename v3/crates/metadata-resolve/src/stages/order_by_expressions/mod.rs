@@ -2,11 +2,13 @@ use std::collections::BTreeMap;
 
 use indexmap::IndexMap;
 use lang_graphql::ast::common as ast;
+use open_dds::aggregates::AggregateExpressionName;
 use open_dds::identifier::SubgraphName;
-use open_dds::models::EnableAllOrSpecific;
+use open_dds::models::{EnableAllOrSpecific, OrderByDirection};
 use open_dds::order_by_expression::{self, OrderByExpressionName, OrderByExpressionOperand};
 use open_dds::relationships::{
-    ModelRelationshipTarget, RelationshipName, RelationshipTarget, RelationshipType,
+    ModelRelationshipTarget, RelationshipMappingSource, RelationshipMappingTarget,
+    RelationshipName, RelationshipTarget, RelationshipType,
 };
 use open_dds::types::{CustomTypeName, FieldName, TypeName};
 mod error;
@@ -18,7 +20,9 @@ pub use error::OrderByExpressionError;
 mod types;
 pub use types::*;
 
-use crate::stages::{graphql_config, object_types, relationships, scalar_types, type_permissions};
+use crate::stages::{
+    aggregates, graphql_config, object_types, relationships, scalar_types, type_permissions,
+};
 
 /// Resolve order by expressions.
 /// Returns the map of OrderByExpressions and updated graphql_types.
@@ -27,6 +31,11 @@ pub fn resolve(
     object_types: &type_permissions::ObjectTypesWithPermissions,
     relationships: &relationships::Relationships,
     scalar_types: &BTreeMap<Qualified<CustomTypeName>, scalar_types::ScalarTypeRepresentation>,
+    aggregate_expressions: &BTreeMap<
+        Qualified<AggregateExpressionName>,
+        aggregates::AggregateExpression,
+    >,
+    graphql_config: &graphql_config::GraphqlConfig,
     graphql_types: &mut graphql_config::GraphqlTypeNames,
 ) -> Result<OrderByExpressionsOutput, Error> {
     let mut resolved_order_by_expressions = OrderByExpressions {
@@ -66,6 +75,7 @@ pub fn resolve(
                     scalar_operand,
                     order_by_expression.graphql.as_ref(),
                     order_by_expression.description.as_ref(),
+                    graphql_config,
                     graphql_types,
                 )
                 .map_err(|error| Error::OrderByExpressionError {
@@ -104,6 +114,7 @@ pub fn resolve(
                         order_by_expression.graphql.as_ref(),
                         order_by_expression.description.as_ref(),
                         relationships,
+                        aggregate_expressions,
                         graphql_types,
                     )
                     .map_err(|error| Error::OrderByExpressionError {
@@ -145,18 +156,40 @@ fn resolve_scalar_order_by_expression(
         &order_by_expression::OrderByExpressionGraphQlConfiguration,
     >,
     description: Option<&String>,
+    graphql_config: &graphql_config::GraphqlConfig,
     graphql_types: &mut graphql_config::GraphqlTypeNames,
 ) -> Result<ScalarOrderByExpression, OrderByExpressionError> {
-    // because we essentially enforce all orderable fields have 'allow all', we don't actually
-    // generate GraphQL types for ordering. If we change this we will need to actually generate all
-    // the enums in the GraphQL schema.
+    // we don't generate GraphQL enums per set of directions, so fields must be orderable in
+    // both 'Asc' and 'Desc' directions. Directions that place nulls first or last may be enabled
+    // in addition, in which case the GraphqlConfig must name an enum type with exactly those
+    // directions.
     let resolved_enable_order_by_directions = match &scalar_operand.enable_order_by_directions {
         EnableAllOrSpecific::EnableAll(true) => {
             Ok(scalar_operand.enable_order_by_directions.clone())
         }
+        EnableAllOrSpecific::EnableSpecific(directions)
+            if directions.contains(&OrderByDirection::Asc)
+                && directions.contains(&OrderByDirection::Desc) =>
+        {
+            if let Some(order_by_input) = &graphql_config.global.order_by_input {
+                if order_by_input
+                    .enum_type_name_for_directions(&scalar_operand.enable_order_by_directions)
+                    .is_none()
+                {
+                    return Err(OrderByExpressionError::NoOrderByEnumTypeForDirections {
+                        directions: directions
+                            .iter()
+                            .map(|direction| format!("{direction:?}"))
+                            .collect::<Vec<_>>()
+                            .join(","),
+                    });
+                }
+            }
+            Ok(scalar_operand.enable_order_by_directions.clone())
+        }
         _ => Err(OrderByExpressionError::UnsupportedFeature {
             message:
-                "Order by configuration is not fully supported yet. Please use \"enableAll\":true."
+                "Order by configuration is not fully supported yet. Please use \"enableAll\":true, or enable both \"Asc\" and \"Desc\"."
                     .to_string(),
         }),
     }?;
@@ -208,6 +241,10 @@ fn resolve_object_order_by_expression(
     >,
     description: Option<&String>,
     relationships: &relationships::Relationships,
+    aggregate_expressions: &BTreeMap<
+        Qualified<AggregateExpressionName>,
+        aggregates::AggregateExpression,
+    >,
     graphql_types: &mut graphql_config::GraphqlTypeNames,
 ) -> Result<(ObjectOrderByExpression, Vec<OrderByExpressionIssue>), OrderByExpressionError> {
     let identifier = Qualified::new(
@@ -237,8 +274,10 @@ fn resolve_object_order_by_expression(
             order_by_expression_names_and_types,
             &orderable_relationship.relationship_name,
             orderable_relationship.order_by_expression.as_ref(),
+            orderable_relationship.aggregate_expression.as_ref(),
             &identifier,
             relationships,
+            aggregate_expressions,
         )?;
 
         issues.extend(new_issues);
@@ -409,15 +448,22 @@ struct ResolvedOrderableRelationship {
 
 /// Resolve an orderable relationship.
 /// Verifies that the order by expression for the relationship exists.
-/// Checks that we only accept object relationships
+/// Checks that we only accept object relationships, or array relationships that
+/// are ordered by an aggregate expression
+#[allow(clippy::too_many_arguments)]
 fn resolve_orderable_relationship(
     subgraph: &SubgraphName,
     ordered_type: &Qualified<CustomTypeName>,
     order_by_expression_names_and_types: &BTreeMap<OrderByExpressionName, TypeName>,
     relationship_name: &RelationshipName,
     relationship_order_by_expression: Option<&OrderByExpressionName>,
+    relationship_aggregate_expression: Option<&AggregateExpressionName>,
     order_by_expression_identifier: &Qualified<OrderByExpressionIdentifier>,
     relationships: &relationships::Relationships,
+    aggregate_expressions: &BTreeMap<
+        Qualified<AggregateExpressionName>,
+        aggregates::AggregateExpression,
+    >,
 ) -> Result<ResolvedOrderableRelationship, OrderByExpressionError> {
     let mut issues = Vec::new();
 
@@ -439,21 +485,82 @@ fn resolve_orderable_relationship(
             })
         }
         relationships::Relationship::Relationship(relationship) => {
-            // if relationship is an array, raise a warning (that will become an error)
-            if let RelationshipTarget::Model(ModelRelationshipTarget {
-                relationship_type: RelationshipType::Array,
-                ..
-            }) = relationship.target
-            {
-                issues.push(OrderByExpressionIssue::CannotOrderByAnArrayRelationship {
-                    order_by_expression: order_by_expression_identifier.clone(),
-                    relationship_name: relationship_name.clone(),
-                });
+            let is_array_relationship = matches!(
+                relationship.target,
+                RelationshipTarget::Model(ModelRelationshipTarget {
+                    relationship_type: RelationshipType::Array,
+                    ..
+                })
+            );
+
+            // array relationships can only be ordered by aggregates of the related rows
+            let aggregate_expression = match relationship_aggregate_expression {
+                Some(aggregate_expression_name) => {
+                    if !is_array_relationship {
+                        return Err(
+                            OrderByExpressionError::AggregateExpressionInNonArrayOrderableRelationship {
+                                relationship_name: relationship_name.clone(),
+                            },
+                        );
+                    }
+                    let aggregate_expression =
+                        Qualified::new(subgraph.clone(), aggregate_expression_name.clone());
+                    if !aggregate_expressions.contains_key(&aggregate_expression) {
+                        return Err(
+                            OrderByExpressionError::UnknownAggregateExpressionNameInOrderableRelationship {
+                                aggregate_expression_name: aggregate_expression_name.clone(),
+                                relationship_name: relationship_name.clone(),
+                            },
+                        );
+                    }
+                    Some(aggregate_expression)
+                }
+                None => {
+                    // if relationship is an array, raise a warning (that will become an error)
+                    if is_array_relationship {
+                        issues.push(OrderByExpressionIssue::CannotOrderByAnArrayRelationship {
+                            order_by_expression: order_by_expression_identifier.clone(),
+                            relationship_name: relationship_name.clone(),
+                        });
+                    }
+                    None
+                }
             };
 
+            // value mappings are applied as constant filters on the target model, which can't be
+            // expressed inside an ordering
+            if relationship
+                .mapping
+                .iter()
+                .any(|mapping| matches!(mapping.source, RelationshipMappingSource::Value(_)))
+            {
+                return Err(
+                    OrderByExpressionError::OrderableRelationshipWithValueMappings {
+                        relationship_name: relationship_name.clone(),
+                    },
+                );
+            }
+
+            // argument mappings take their values from each row of the source, which can't be
+            // expressed inside an ordering either
+            if let RelationshipTarget::Model(_) = relationship.target {
+                if relationship
+                    .mapping
+                    .iter()
+                    .any(|mapping| matches!(mapping.target, RelationshipMappingTarget::Argument(_)))
+                {
+                    return Err(
+                        OrderByExpressionError::OrderableRelationshipWithArgumentMappings {
+                            relationship_name: relationship_name.clone(),
+                        },
+                    );
+                }
+            }
+
             let resolved_orderable_relationship = match relationship_order_by_expression {
                 None => Ok(OrderableRelationship {
                     order_by_expression: None,
+                    aggregate_expression,
                 }),
                 Some(order_by_expression_name) => {
                     if order_by_expression_names_and_types.contains_key(order_by_expression_name) {
@@ -462,6 +569,7 @@ fn resolve_orderable_relationship(
                                 subgraph.clone(),
                                 order_by_expression_name.clone(),
                             )),
+                            aggregate_expression,
                         })
                     } else {
                         Err(
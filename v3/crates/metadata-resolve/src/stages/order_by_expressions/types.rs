@@ -1,6 +1,7 @@
 use crate::types::error::ShouldBeAnError;
 use lang_graphql::ast::common::{self as ast};
 use open_dds::{
+    aggregates::AggregateExpressionName,
     models::{EnableAllOrSpecific, ModelName, OrderByDirection},
     order_by_expression::OrderByExpressionName,
     relationships::RelationshipName,
@@ -95,6 +96,9 @@ pub struct OrderableRelationship {
     /// If not present we will use order_by_expression from the model
     /// that the relationship targets.
     pub order_by_expression: Option<Qualified<OrderByExpressionName>>,
+    /// aggregate_expression is only present for array relationships,
+    /// which can only be ordered by aggregates of the related rows.
+    pub aggregate_expression: Option<Qualified<AggregateExpressionName>>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -0,0 +1,605 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use hasura_authn_core::Role;
+use open_dds::accessor::MetadataAccessor;
+use open_dds::permissions::{
+    AllowedField, ArgumentPredicate, CommandPermission, ConditionalAllowedField, ModelPermission,
+    ModelPredicate, NullableModelPredicate, TypePermission,
+};
+
+use crate::Qualified;
+
+mod types;
+pub use types::{InheritedPreset, RoleInheritanceError, RoleInheritanceIssue};
+
+/// Expand the type, model and command permissions in the metadata with the permissions
+/// that roles inherit from their parent roles. The later permission stages then resolve
+/// the merged permissions as if they had been written out for each role.
+pub fn resolve(
+    metadata_accessor: &mut MetadataAccessor,
+) -> Result<Vec<RoleInheritanceIssue>, RoleInheritanceError> {
+    let mut issues = vec![];
+
+    let mut parent_roles = BTreeMap::new();
+    for role_definition in &metadata_accessor.roles {
+        let role = &role_definition.object;
+        if parent_roles
+            .insert(role.name.clone(), role.parent_roles.clone())
+            .is_some()
+        {
+            return Err(RoleInheritanceError::DuplicateRoleDefinition {
+                role: role.name.clone(),
+            });
+        }
+    }
+
+    check_for_cycles(&parent_roles)?;
+
+    // Only roles that have parents can inherit anything
+    parent_roles.retain(|_, parents| !parents.is_empty());
+    if parent_roles.is_empty() {
+        return Ok(issues);
+    }
+
+    for type_permissions in &mut metadata_accessor.type_permissions {
+        let type_name = Qualified::new(
+            type_permissions.subgraph.clone(),
+            type_permissions.object.type_name.clone(),
+        );
+        let conflicts =
+            inherit_permissions(&mut type_permissions.object.permissions, &parent_roles);
+        issues.extend(conflicts.into_iter().map(|conflict| {
+            RoleInheritanceIssue::ConflictingTypePermissionPreset {
+                role: conflict.role,
+                parent_role: conflict.parent_role,
+                type_name: type_name.clone(),
+                preset: conflict.preset,
+            }
+        }));
+    }
+
+    for model_permissions in &mut metadata_accessor.model_permissions {
+        let model_name = Qualified::new(
+            model_permissions.subgraph.clone(),
+            model_permissions.object.model_name.clone(),
+        );
+        let conflicts =
+            inherit_permissions(&mut model_permissions.object.permissions, &parent_roles);
+        issues.extend(conflicts.into_iter().map(|conflict| {
+            RoleInheritanceIssue::ConflictingModelPermissionPreset {
+                role: conflict.role,
+                parent_role: conflict.parent_role,
+                model_name: model_name.clone(),
+                preset: conflict.preset,
+            }
+        }));
+    }
+
+    for command_permissions in &mut metadata_accessor.command_permissions {
+        let command_name = Qualified::new(
+            command_permissions.subgraph.clone(),
+            command_permissions.object.command_name.clone(),
+        );
+        let conflicts =
+            inherit_permissions(&mut command_permissions.object.permissions, &parent_roles);
+        issues.extend(conflicts.into_iter().map(|conflict| {
+            RoleInheritanceIssue::ConflictingCommandPermissionPreset {
+                role: conflict.role,
+                parent_role: conflict.parent_role,
+                command_name: command_name.clone(),
+                preset: conflict.preset,
+            }
+        }));
+    }
+
+    Ok(issues)
+}
+
+fn check_for_cycles(parent_roles: &BTreeMap<Role, Vec<Role>>) -> Result<(), RoleInheritanceError> {
+    fn visit<'a>(
+        role: &'a Role,
+        parent_roles: &'a BTreeMap<Role, Vec<Role>>,
+        visiting: &mut Vec<&'a Role>,
+        visited: &mut BTreeSet<&'a Role>,
+    ) -> Result<(), RoleInheritanceError> {
+        if visiting.contains(&role) {
+            return Err(RoleInheritanceError::RoleInheritanceCycle { role: role.clone() });
+        }
+        if !visited.insert(role) {
+            return Ok(());
+        }
+        visiting.push(role);
+        for parent_role in parent_roles.get(role).into_iter().flatten() {
+            visit(parent_role, parent_roles, visiting, visited)?;
+        }
+        visiting.pop();
+        Ok(())
+    }
+
+    let mut visited = BTreeSet::new();
+    for role in parent_roles.keys() {
+        visit(role, parent_roles, &mut vec![], &mut visited)?;
+    }
+    Ok(())
+}
+
+/// A preset inherited from `parent_role` that was dropped because `role` already has a
+/// different preset for the same argument or field, of its own or inherited from another
+/// parent.
+struct PresetConflict {
+    role: Role,
+    parent_role: Role,
+    preset: InheritedPreset,
+}
+
+/// A permission for a single role that can be combined with the permissions of
+/// other roles.
+trait InheritablePermission: Clone {
+    fn role(&self) -> &Role;
+
+    fn set_role(&mut self, role: Role);
+
+    /// Widen this permission with everything allowed by `other`. Presets already
+    /// present in this permission take precedence; the conflicting presets of `other`
+    /// are returned.
+    fn merge(&mut self, other: &Self) -> Vec<InheritedPreset>;
+}
+
+/// Replace the permissions of every role that has parent roles with the merge of its own
+/// permission and the effective permissions of its parents.
+fn inherit_permissions<P: InheritablePermission>(
+    permissions: &mut Vec<P>,
+    parent_roles: &BTreeMap<Role, Vec<Role>>,
+) -> Vec<PresetConflict> {
+    let mut own_permissions = BTreeMap::new();
+    for permission in permissions.iter() {
+        own_permissions
+            .entry(permission.role().clone())
+            .or_insert_with(|| permission.clone());
+    }
+
+    let mut conflicts = vec![];
+    let mut effective_permissions = BTreeMap::new();
+    for role in parent_roles.keys() {
+        effective_permission(
+            role,
+            &own_permissions,
+            parent_roles,
+            &mut effective_permissions,
+            &mut conflicts,
+        );
+    }
+
+    for role in parent_roles.keys() {
+        let Some(Some(effective)) = effective_permissions.remove(role) else {
+            continue;
+        };
+        match permissions.iter().position(|p| p.role() == role) {
+            Some(index) => permissions[index] = effective,
+            None => permissions.push(effective),
+        }
+    }
+    conflicts
+}
+
+fn effective_permission<P: InheritablePermission>(
+    role: &Role,
+    own_permissions: &BTreeMap<Role, P>,
+    parent_roles: &BTreeMap<Role, Vec<Role>>,
+    effective_permissions: &mut BTreeMap<Role, Option<P>>,
+    conflicts: &mut Vec<PresetConflict>,
+) -> Option<P> {
+    if let Some(effective) = effective_permissions.get(role) {
+        return effective.clone();
+    }
+
+    // The role's own presets take precedence over inherited ones, and the presets of
+    // earlier parent roles over those of later ones
+    let mut effective: Option<P> = own_permissions.get(role).cloned();
+    for parent_role in parent_roles.get(role).into_iter().flatten() {
+        let Some(parent_permission) = effective_permission(
+            parent_role,
+            own_permissions,
+            parent_roles,
+            effective_permissions,
+            conflicts,
+        ) else {
+            continue;
+        };
+        match &mut effective {
+            None => {
+                let mut inherited = parent_permission;
+                inherited.set_role(role.clone());
+                effective = Some(inherited);
+            }
+            Some(effective) => {
+                conflicts.extend(
+                    effective
+                        .merge(&parent_permission)
+                        .into_iter()
+                        .map(|preset| PresetConflict {
+                            role: role.clone(),
+                            parent_role: parent_role.clone(),
+                            preset,
+                        }),
+                );
+            }
+        }
+    }
+
+    effective_permissions.insert(role.clone(), effective.clone());
+    effective
+}
+
+/// Merge presets keyed by `key`, keeping existing presets and returning the keys of
+/// the `other` presets that conflict with them.
+fn merge_presets<T: Clone + PartialEq>(
+    presets: &mut Vec<T>,
+    other: &[T],
+    key: impl Fn(&T) -> InheritedPreset,
+) -> Vec<InheritedPreset> {
+    let mut conflicts = vec![];
+    for preset in other {
+        let preset_key = key(preset);
+        match presets.iter().find(|p| key(p) == preset_key) {
+            None => presets.push(preset.clone()),
+            Some(existing) if existing == preset => {}
+            Some(_) => conflicts.push(preset_key),
+        }
+    }
+    conflicts
+}
+
+fn or_model_predicates(predicate: &mut ModelPredicate, other: &ModelPredicate) {
+    if predicate == other {
+        return;
+    }
+    match predicate {
+        ModelPredicate::Or(predicates) => {
+            if !predicates.contains(other) {
+                predicates.push(other.clone());
+            }
+        }
+        _ => *predicate = ModelPredicate::Or(vec![predicate.clone(), other.clone()]),
+    }
+}
+
+fn or_argument_predicates(predicate: &mut ArgumentPredicate, other: &ArgumentPredicate) {
+    if predicate == other {
+        return;
+    }
+    match predicate {
+        ArgumentPredicate::Or(predicates) => {
+            if !predicates.contains(other) {
+                predicates.push(other.clone());
+            }
+        }
+        _ => *predicate = ArgumentPredicate::Or(vec![predicate.clone(), other.clone()]),
+    }
+}
+
+/// A null predicate means no restriction, so it wins over any other predicate.
+fn or_optional_predicates<T>(
+    predicate: &mut Option<T>,
+    other: Option<&T>,
+    or_predicates: impl Fn(&mut T, &T),
+) {
+    match (predicate.as_mut(), other) {
+        (Some(predicate), Some(other)) => or_predicates(predicate, other),
+        (Some(_), None) => *predicate = None,
+        (None, _) => {}
+    }
+}
+
+fn merge_allowed_fields(allowed_fields: &mut Vec<AllowedField>, other: &[AllowedField]) {
+    for other_field in other {
+        match allowed_fields
+            .iter_mut()
+            .find(|f| f.field_name() == other_field.field_name())
+        {
+            None => allowed_fields.push(other_field.clone()),
+            Some(allowed_field) => match (&mut *allowed_field, other_field) {
+                (AllowedField::Field(_), _) => {}
+                (AllowedField::Conditional(_), AllowedField::Field(_)) => {
+                    *allowed_field = other_field.clone();
+                }
+                (
+                    AllowedField::Conditional(ConditionalAllowedField { condition, .. }),
+                    AllowedField::Conditional(other),
+                ) => or_model_predicates(condition, &other.condition),
+            },
+        }
+    }
+}
+
+impl InheritablePermission for TypePermission {
+    fn role(&self) -> &Role {
+        &self.role
+    }
+
+    fn set_role(&mut self, role: Role) {
+        self.role = role;
+    }
+
+    fn merge(&mut self, other: &Self) -> Vec<InheritedPreset> {
+        let mut conflicts = vec![];
+        match (&mut self.output, &other.output) {
+            (Some(output), Some(other_output)) => {
+                merge_allowed_fields(&mut output.allowed_fields, &other_output.allowed_fields);
+                conflicts.extend(merge_presets(
+                    &mut output.field_argument_presets,
+                    &other_output.field_argument_presets,
+                    |preset| InheritedPreset::FieldArgument {
+                        field: preset.field.clone(),
+                        argument: preset.argument.clone(),
+                    },
+                ));
+            }
+            (None, Some(other_output)) => self.output = Some(other_output.clone()),
+            (_, None) => {}
+        }
+        match (&mut self.input, &other.input) {
+            (Some(input), Some(other_input)) => {
+                conflicts.extend(merge_presets(
+                    &mut input.field_presets,
+                    &other_input.field_presets,
+                    |preset| InheritedPreset::Field(preset.field.clone()),
+                ));
+            }
+            (None, Some(other_input)) => self.input = Some(other_input.clone()),
+            (_, None) => {}
+        }
+        conflicts
+    }
+}
+
+impl InheritablePermission for ModelPermission {
+    fn role(&self) -> &Role {
+        &self.role
+    }
+
+    fn set_role(&mut self, role: Role) {
+        self.role = role;
+    }
+
+    fn merge(&mut self, other: &Self) -> Vec<InheritedPreset> {
+        let (select, other_select) = match (&mut self.select, &other.select) {
+            (Some(select), Some(other_select)) => (select, other_select),
+            (None, Some(other_select)) => {
+                self.select = Some(other_select.clone());
+                return vec![];
+            }
+            (_, None) => return vec![],
+        };
+        match (&mut select.filter, &other_select.filter) {
+            (NullableModelPredicate::NotNull(filter), NullableModelPredicate::NotNull(other)) => {
+                or_model_predicates(filter, other);
+            }
+            (NullableModelPredicate::NotNull(_), NullableModelPredicate::Null(())) => {
+                select.filter = NullableModelPredicate::Null(());
+            }
+            (NullableModelPredicate::Null(()), _) => {}
+        }
+        select.allow_subscriptions |= other_select.allow_subscriptions;
+        select.allow_aggregations |= other_select.allow_aggregations;
+        select.limit = select
+            .limit
+            .zip(other_select.limit)
+            .map(|(limit, other_limit)| limit.max(other_limit));
+        merge_presets(
+            &mut select.argument_presets,
+            &other_select.argument_presets,
+            |preset| InheritedPreset::Argument(preset.argument.clone()),
+        )
+    }
+}
+
+impl InheritablePermission for CommandPermission {
+    fn role(&self) -> &Role {
+        &self.role
+    }
+
+    fn set_role(&mut self, role: Role) {
+        self.role = role;
+    }
+
+    fn merge(&mut self, other: &Self) -> Vec<InheritedPreset> {
+        if !other.allow_execution {
+            return vec![];
+        }
+        if self.allow_execution {
+            or_optional_predicates(
+                &mut self.argument_predicate,
+                other.argument_predicate.as_ref(),
+                or_argument_predicates,
+            );
+            or_optional_predicates(
+                &mut self.output_filter,
+                other.output_filter.as_ref(),
+                or_model_predicates,
+            );
+        } else {
+            self.allow_execution = true;
+            self.argument_predicate
+                .clone_from(&other.argument_predicate);
+            self.output_filter.clone_from(&other.output_filter);
+        }
+        merge_presets(
+            &mut self.argument_presets,
+            &other.argument_presets,
+            |preset| InheritedPreset::Argument(preset.argument.clone()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use hasura_authn_core::Role;
+    use open_dds::permissions::ModelPermission;
+
+    use super::{inherit_permissions, InheritedPreset};
+
+    fn model_permission(json: serde_json::Value) -> ModelPermission {
+        open_dds::traits::OpenDd::deserialize(json, jsonpath::JSONPath::new()).unwrap()
+    }
+
+    fn parent_roles(roles: &[(&str, &[&str])]) -> BTreeMap<Role, Vec<Role>> {
+        roles
+            .iter()
+            .map(|(role, parents)| {
+                (
+                    Role::new(role),
+                    parents.iter().map(|p| Role::new(p)).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_model_permissions_are_merged_with_parent_roles() {
+        let mut permissions = vec![
+            model_permission(serde_json::json!({
+                "role": "employee",
+                "select": {
+                    "filter": {
+                        "fieldComparison": {
+                            "field": "author_id",
+                            "operator": "_eq",
+                            "value": { "sessionVariable": "x-hasura-user-id" }
+                        }
+                    },
+                    "argumentPresets": [{ "argument": "region", "value": { "literal": "eu" } }],
+                    "limit": 10
+                }
+            })),
+            model_permission(serde_json::json!({
+                "role": "manager",
+                "select": {
+                    "filter": {
+                        "fieldComparison": {
+                            "field": "manager_id",
+                            "operator": "_eq",
+                            "value": { "sessionVariable": "x-hasura-user-id" }
+                        }
+                    },
+                    "limit": 100
+                }
+            })),
+        ];
+
+        let conflicts = inherit_permissions(
+            &mut permissions,
+            &parent_roles(&[("manager", &["employee"]), ("director", &["manager"])]),
+        );
+        assert!(conflicts.is_empty());
+
+        let manager = model_permission(serde_json::json!({
+            "role": "manager",
+            "select": {
+                "filter": {
+                    "or": [
+                        {
+                            "fieldComparison": {
+                                "field": "manager_id",
+                                "operator": "_eq",
+                                "value": { "sessionVariable": "x-hasura-user-id" }
+                            }
+                        },
+                        {
+                            "fieldComparison": {
+                                "field": "author_id",
+                                "operator": "_eq",
+                                "value": { "sessionVariable": "x-hasura-user-id" }
+                            }
+                        }
+                    ]
+                },
+                "argumentPresets": [{ "argument": "region", "value": { "literal": "eu" } }],
+                "limit": 100
+            }
+        }));
+        let mut director = manager.clone();
+        director.role = Role::new("director");
+
+        assert_eq!(permissions.len(), 3);
+        assert_eq!(permissions[1], manager);
+        assert_eq!(permissions[2], director);
+    }
+
+    #[test]
+    fn test_conflicting_presets_from_parent_roles() {
+        let mut permissions = vec![
+            model_permission(serde_json::json!({
+                "role": "sales",
+                "select": {
+                    "filter": null,
+                    "argumentPresets": [{ "argument": "region", "value": { "literal": "eu" } }]
+                }
+            })),
+            model_permission(serde_json::json!({
+                "role": "support",
+                "select": {
+                    "filter": null,
+                    "argumentPresets": [{ "argument": "region", "value": { "literal": "us" } }]
+                }
+            })),
+        ];
+
+        let conflicts = inherit_permissions(
+            &mut permissions,
+            &parent_roles(&[("lead", &["sales", "support"])]),
+        );
+
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].role, Role::new("lead"));
+        assert_eq!(conflicts[0].parent_role, Role::new("support"));
+        assert_eq!(
+            conflicts[0].preset,
+            InheritedPreset::Argument(open_dds::arguments::ArgumentName::new(
+                open_dds::identifier::Identifier::new("region").unwrap()
+            ))
+        );
+
+        let mut lead = permissions[0].clone();
+        lead.role = Role::new("lead");
+        assert_eq!(permissions[2], lead);
+    }
+
+    #[test]
+    fn test_own_presets_conflicting_with_parent_roles() {
+        let mut permissions = vec![
+            model_permission(serde_json::json!({
+                "role": "sales",
+                "select": {
+                    "filter": null,
+                    "argumentPresets": [{ "argument": "region", "value": { "literal": "eu" } }]
+                }
+            })),
+            model_permission(serde_json::json!({
+                "role": "lead",
+                "select": {
+                    "filter": null,
+                    "argumentPresets": [{ "argument": "region", "value": { "literal": "us" } }]
+                }
+            })),
+        ];
+        let lead = permissions[1].clone();
+
+        let conflicts =
+            inherit_permissions(&mut permissions, &parent_roles(&[("lead", &["sales"])]));
+
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].role, Role::new("lead"));
+        assert_eq!(conflicts[0].parent_role, Role::new("sales"));
+        assert_eq!(
+            conflicts[0].preset,
+            InheritedPreset::Argument(open_dds::arguments::ArgumentName::new(
+                open_dds::identifier::Identifier::new("region").unwrap()
+            ))
+        );
+
+        assert_eq!(permissions[1], lead);
+    }
+}
@@ -0,0 +1,61 @@
+use hasura_authn_core::Role;
+use open_dds::arguments::ArgumentName;
+use open_dds::commands::CommandName;
+use open_dds::models::ModelName;
+use open_dds::types::{CustomTypeName, FieldName};
+
+use crate::types::error::ShouldBeAnError;
+use crate::Qualified;
+
+/// A preset of a permission that is inherited from a parent role.
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
+pub enum InheritedPreset {
+    #[display("argument {_0}")]
+    Argument(ArgumentName),
+    #[display("field {_0}")]
+    Field(FieldName),
+    #[display("argument {argument} of field {field}")]
+    FieldArgument {
+        field: FieldName,
+        argument: ArgumentName,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RoleInheritanceError {
+    #[error("the role {role} has been defined more than once")]
+    DuplicateRoleDefinition { role: Role },
+    #[error("the role {role} inherits from itself through its parent roles")]
+    RoleInheritanceCycle { role: Role },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RoleInheritanceIssue {
+    #[error("role {role} inherits a preset for {preset} in the permissions of type {type_name} from parent role {parent_role} that conflicts with its own preset or a preset inherited from another parent role; the preset from {parent_role} is ignored")]
+    ConflictingTypePermissionPreset {
+        role: Role,
+        parent_role: Role,
+        type_name: Qualified<CustomTypeName>,
+        preset: InheritedPreset,
+    },
+    #[error("role {role} inherits a preset for {preset} in the permissions of model {model_name} from parent role {parent_role} that conflicts with its own preset or a preset inherited from another parent role; the preset from {parent_role} is ignored")]
+    ConflictingModelPermissionPreset {
+        role: Role,
+        parent_role: Role,
+        model_name: Qualified<ModelName>,
+        preset: InheritedPreset,
+    },
+    #[error("role {role} inherits a preset for {preset} in the permissions of command {command_name} from parent role {parent_role} that conflicts with its own preset or a preset inherited from another parent role; the preset from {parent_role} is ignored")]
+    ConflictingCommandPermissionPreset {
+        role: Role,
+        parent_role: Role,
+        command_name: Qualified<CommandName>,
+        preset: InheritedPreset,
+    },
+}
+
+impl ShouldBeAnError for RoleInheritanceIssue {
+    fn should_be_an_error(&self, _flags: &open_dds::flags::OpenDdFlags) -> bool {
+        false
+    }
+}
@@ -34,6 +34,7 @@ pub fn resolve(
                 graphql_type_name: scalar_type.graphql_type_name.clone(),
                 description: scalar_type.description.clone(),
                 representations,
+                enum_values: scalar_type.enum_values.clone(),
             },
         );
     }
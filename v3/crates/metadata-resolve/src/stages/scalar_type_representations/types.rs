@@ -1,3 +1,4 @@
+use crate::stages::scalar_types::EnumTypeValue;
 use crate::{deserialize_non_string_key_btreemap, serialize_non_string_key_btreemap, Qualified};
 use lang_graphql::ast::common as ast;
 use open_dds::data_connector::DataConnectorName;
@@ -15,4 +16,8 @@ pub struct ScalarTypeRepresentation {
         deserialize_with = "deserialize_non_string_key_btreemap"
     )]
     pub representations: BTreeMap<Qualified<DataConnectorName>, ndc_models::TypeRepresentation>,
+    /// The values of the type, if it is an enum type
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub enum_values: Option<Vec<EnumTypeValue>>,
 }
@@ -8,4 +8,18 @@ pub enum ScalarTypesError {
     GraphqlConfigError(#[from] graphql_config::GraphqlConfigError),
     #[error("the following type is defined more than once: {name:}")]
     DuplicateTypeDefinition { name: Qualified<CustomTypeName> },
+    #[error("the enum type {type_name} must define at least one value")]
+    EmptyEnumType {
+        type_name: Qualified<CustomTypeName>,
+    },
+    #[error("the value {value} is defined more than once in the enum type {type_name}")]
+    DuplicateEnumValue {
+        type_name: Qualified<CustomTypeName>,
+        value: String,
+    },
+    #[error("the value {value} of the enum type {type_name} is not a valid GraphQL enum value")]
+    InvalidGraphQlEnumValue {
+        type_name: Qualified<CustomTypeName>,
+        value: String,
+    },
 }
@@ -1,15 +1,15 @@
 use crate::helpers::types::mk_name;
 use crate::types::subgraph::Qualified;
 use lang_graphql::ast::common as ast;
-use open_dds::types::InbuiltType;
-use std::collections::BTreeMap;
+use open_dds::types::{CustomTypeName, InbuiltType};
+use std::collections::{BTreeMap, BTreeSet};
 mod error;
 pub use error::ScalarTypesError;
 pub mod types;
 use crate::stages::graphql_config;
 use std::sync::LazyLock;
 use strum::IntoEnumIterator;
-pub use types::{ScalarTypeRepresentation, ScalarTypesIssue, ScalarTypesOutput};
+pub use types::{EnumTypeValue, ScalarTypeRepresentation, ScalarTypesIssue, ScalarTypesOutput};
 
 // enumerate over inbuilt types to create list of disallowed names
 static BUILT_IN_NAMES: LazyLock<Vec<String>> = LazyLock::new(|| {
@@ -55,6 +55,7 @@ pub fn resolve(
                 ScalarTypeRepresentation {
                     graphql_type_name: graphql_type_name.clone(),
                     description: scalar_type.description.clone(),
+                    enum_values: None,
                 },
             )
             .is_some()
@@ -67,8 +68,100 @@ pub fn resolve(
             .store(graphql_type_name.as_ref())
             .map_err(ScalarTypesError::GraphqlConfigError)?;
     }
+
+    for open_dds::accessor::QualifiedObject {
+        path: _,
+        subgraph,
+        object: enum_type,
+    } in &metadata_accessor.enum_types
+    {
+        let qualified_enum_type_name = Qualified::new(subgraph.clone(), enum_type.name.clone());
+
+        let graphql_type_name = match enum_type.graphql.as_ref() {
+            None => Ok(None),
+            Some(type_name) => mk_name(type_name.type_name.as_ref())
+                .map(ast::TypeName)
+                .map(Some),
+        }
+        .map_err(ScalarTypesError::GraphqlConfigError)?;
+
+        if BUILT_IN_NAMES.contains(&enum_type.name.to_string()) {
+            issues.push(ScalarTypesIssue::NameConflictsWithBuiltInType {
+                type_name: enum_type.name.clone(),
+            });
+        }
+
+        let enum_values = resolve_enum_values(
+            &qualified_enum_type_name,
+            &enum_type.values,
+            graphql_type_name.is_some(),
+        )?;
+
+        if scalar_types
+            .insert(
+                qualified_enum_type_name.clone(),
+                ScalarTypeRepresentation {
+                    graphql_type_name: graphql_type_name.clone(),
+                    description: enum_type.description.clone(),
+                    enum_values: Some(enum_values),
+                },
+            )
+            .is_some()
+        {
+            return Err(ScalarTypesError::DuplicateTypeDefinition {
+                name: qualified_enum_type_name,
+            });
+        }
+        graphql_types
+            .store(graphql_type_name.as_ref())
+            .map_err(ScalarTypesError::GraphqlConfigError)?;
+    }
+
     Ok(ScalarTypesOutput {
         scalar_types,
         issues,
     })
 }
+
+/// validate the values of an enum type. When the enum type appears in the GraphQL schema, every
+/// value must also be a valid GraphQL enum value.
+fn resolve_enum_values(
+    type_name: &Qualified<CustomTypeName>,
+    values: &[open_dds::types::EnumValueDefinition],
+    is_graphql_enum: bool,
+) -> Result<Vec<EnumTypeValue>, ScalarTypesError> {
+    if values.is_empty() {
+        return Err(ScalarTypesError::EmptyEnumType {
+            type_name: type_name.clone(),
+        });
+    }
+
+    let mut seen_values = BTreeSet::new();
+    let mut enum_values = Vec::with_capacity(values.len());
+    for value in values {
+        if !seen_values.insert(value.value.as_str()) {
+            return Err(ScalarTypesError::DuplicateEnumValue {
+                type_name: type_name.clone(),
+                value: value.value.clone(),
+            });
+        }
+
+        // `true`, `false` and `null` are valid names but cannot be used as GraphQL enum values
+        if is_graphql_enum
+            && (mk_name(&value.value).is_err()
+                || matches!(value.value.as_str(), "true" | "false" | "null"))
+        {
+            return Err(ScalarTypesError::InvalidGraphQlEnumValue {
+                type_name: type_name.clone(),
+                value: value.value.clone(),
+            });
+        }
+
+        enum_values.push(EnumTypeValue {
+            value: value.value.clone(),
+            description: value.description.clone(),
+            deprecated: value.deprecated.clone(),
+        });
+    }
+    Ok(enum_values)
+}
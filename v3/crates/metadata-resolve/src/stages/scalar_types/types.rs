@@ -4,7 +4,7 @@ use lang_graphql::ast::common as ast;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
-use open_dds::types::CustomTypeName;
+use open_dds::types::{CustomTypeName, Deprecated};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ScalarTypeRepresentation {
@@ -12,6 +12,22 @@ pub struct ScalarTypeRepresentation {
     #[serde(default = "serde_ext::ser_default")]
     #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
     pub description: Option<String>,
+    /// The values of the type, if it is an enum type
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub enum_values: Option<Vec<EnumTypeValue>>,
+}
+
+/// A value of an enum type
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct EnumTypeValue {
+    pub value: String,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub description: Option<String>,
+    #[serde(default = "serde_ext::ser_default")]
+    #[serde(skip_serializing_if = "serde_ext::is_ser_default")]
+    pub deprecated: Option<Deprecated>,
 }
 
 pub struct ScalarTypesOutput {
@@ -1,6 +1,7 @@
 use crate::helpers::typecheck::{self, TypecheckIssue};
 use crate::types::error::{Error, ShouldBeAnError};
-use open_dds::types::{CustomTypeName, FieldName};
+use open_dds::arguments::ArgumentName;
+use open_dds::types::{CustomTypeName, FieldName, OperatorName};
 
 use crate::types::subgraph::Qualified;
 
@@ -19,6 +20,62 @@ pub enum TypeOutputPermissionError {
         field_name: FieldName,
         type_name: CustomTypeName,
     },
+    #[error("conditionally allowed field '{field_name:}' is allowed more than once in output permissions of type '{type_name:}'")]
+    DuplicateConditionalAllowedField {
+        field_name: FieldName,
+        type_name: CustomTypeName,
+    },
+    #[error("field '{field_name:}' of type '{type_name:}' is not nullable, so it cannot be conditionally allowed in output permissions")]
+    NonNullableConditionalField {
+        field_name: FieldName,
+        type_name: CustomTypeName,
+    },
+    #[error("the condition of field '{field_name:}' in output permissions of type '{type_name:}' uses field '{condition_field_name:}', which is not allowed on all objects")]
+    FieldConditionFieldNotAllowed {
+        field_name: FieldName,
+        condition_field_name: FieldName,
+        type_name: CustomTypeName,
+    },
+    #[error("the condition of field '{field_name:}' in output permissions of type '{type_name:}' uses the operator '{operator:}'; only '_eq' and '_neq' are supported")]
+    UnsupportedFieldConditionOperator {
+        field_name: FieldName,
+        operator: OperatorName,
+        type_name: CustomTypeName,
+    },
+    #[error("the condition of field '{field_name:}' in output permissions of type '{type_name:}' uses a relationship; only fields of the type can be used")]
+    UnsupportedRelationshipInFieldCondition {
+        field_name: FieldName,
+        type_name: CustomTypeName,
+    },
+    #[error(
+        "Type error in condition of field {field_name:}, for output type permissions definition of type {type_name:}: {type_error:}"
+    )]
+    FieldConditionTypeError {
+        field_name: FieldName,
+        type_name: CustomTypeName,
+        type_error: typecheck::TypecheckError,
+    },
+    #[error("unknown argument '{argument_name:}' of field '{field_name:}' used in output permissions of type '{type_name:}'")]
+    UnknownFieldArgumentInOutputPermissionsDefinition {
+        field_name: FieldName,
+        argument_name: ArgumentName,
+        type_name: CustomTypeName,
+    },
+    #[error("multiple presets have been defined for argument '{argument_name:}' of field '{field_name:}' in output permissions of type '{type_name:}'")]
+    DuplicateFieldArgumentPreset {
+        field_name: FieldName,
+        argument_name: ArgumentName,
+        type_name: CustomTypeName,
+    },
+    #[error(
+        "Type error in preset of argument {argument_name:} of field {field_name:}, for output type permissions definition of type {type_name:}: {type_error:}"
+    )]
+    FieldArgumentPresetTypeError {
+        field_name: FieldName,
+        argument_name: ArgumentName,
+        type_name: CustomTypeName,
+        type_error: typecheck::TypecheckError,
+    },
 }
 
 impl From<TypeOutputPermissionError> for TypePermissionError {
@@ -76,6 +133,19 @@ pub enum TypePermissionIssue {
         type_name: CustomTypeName,
         typecheck_issue: TypecheckIssue,
     },
+    #[error("Type error in preset of argument {argument_name:} of field {field_name:}, for output type permissions definition of type {type_name:}: {typecheck_issue:}")]
+    FieldArgumentPresetTypecheckIssue {
+        field_name: FieldName,
+        argument_name: ArgumentName,
+        type_name: CustomTypeName,
+        typecheck_issue: TypecheckIssue,
+    },
+    #[error("Type error in condition of field {field_name:}, for output type permissions definition of type {type_name:}: {typecheck_issue:}")]
+    FieldConditionTypecheckIssue {
+        field_name: FieldName,
+        type_name: CustomTypeName,
+        typecheck_issue: TypecheckIssue,
+    },
 }
 
 impl ShouldBeAnError for TypePermissionIssue {
@@ -83,6 +153,12 @@ impl ShouldBeAnError for TypePermissionIssue {
         match self {
             TypePermissionIssue::FieldPresetTypecheckIssue {
                 typecheck_issue, ..
+            }
+            | TypePermissionIssue::FieldArgumentPresetTypecheckIssue {
+                typecheck_issue, ..
+            }
+            | TypePermissionIssue::FieldConditionTypecheckIssue {
+                typecheck_issue, ..
             } => typecheck_issue.should_be_an_error(flags),
         }
     }
@@ -5,9 +5,14 @@ mod types;
 pub use error::{
     TypeInputPermissionError, TypeOutputPermissionError, TypePermissionError, TypePermissionIssue,
 };
-use open_dds::permissions::{FieldPreset, Role, TypeOutputPermission, TypePermissionsV1};
+use indexmap::IndexSet;
+use open_dds::permissions::{
+    AllowedField, FieldArgumentPreset, FieldPreset, ModelPredicate, Role, TypePermissionsV1,
+};
+use open_dds::types::FieldName;
 pub use types::{
-    FieldPresetInfo, ObjectTypeWithPermissions, ObjectTypesWithPermissions, TypeInputPermission,
+    FieldCondition, FieldConditionOperator, FieldPresetInfo, ObjectTypeWithPermissions,
+    ObjectTypesWithPermissions, TypeInputPermission, TypeOutputPermission,
 };
 
 use crate::types::subgraph::Qualified;
@@ -48,8 +53,11 @@ pub fn resolve(
             }
             Some(object_type) => {
                 let type_output_permissions = resolve_output_type_permission(
+                    &metadata_accessor.flags,
+                    &object_types_context,
                     &object_type.object_type,
                     output_type_permission,
+                    &mut issues,
                 )?;
                 let type_input_permissions = resolve_input_type_permission(
                     &metadata_accessor.flags,
@@ -91,8 +99,14 @@ pub fn resolve(
 }
 
 pub fn resolve_output_type_permission(
+    flags: &open_dds::flags::OpenDdFlags,
+    object_types: &BTreeMap<
+        &Qualified<open_dds::types::CustomTypeName>,
+        &object_types::ObjectTypeRepresentation,
+    >,
     object_type_representation: &object_types::ObjectTypeRepresentation,
     type_permissions: &TypePermissionsV1,
+    issues: &mut Vec<TypePermissionIssue>,
 ) -> Result<BTreeMap<Role, TypeOutputPermission>, TypeOutputPermissionError> {
     let mut resolved_type_permissions = BTreeMap::new();
 
@@ -100,7 +114,9 @@ pub fn resolve_output_type_permission(
     // exist in this type definition
     for type_permission in &type_permissions.permissions {
         if let Some(output) = &type_permission.output {
-            for field_name in &output.allowed_fields {
+            let mut allowed_fields = IndexSet::new();
+            for allowed_field in &output.allowed_fields {
+                let field_name = allowed_field.field_name();
                 if !object_type_representation.fields.contains_key(field_name) {
                     return Err(
                         TypeOutputPermissionError::UnknownFieldInOutputPermissionsDefinition {
@@ -109,9 +125,119 @@ pub fn resolve_output_type_permission(
                         },
                     );
                 }
+                allowed_fields.insert(field_name.clone());
+            }
+            let mut field_conditions = BTreeMap::new();
+            for allowed_field in &output.allowed_fields {
+                if let AllowedField::Conditional(conditional) = allowed_field {
+                    // a conditional field can't be allowed again, with or without a condition
+                    let occurrences = output
+                        .allowed_fields
+                        .iter()
+                        .filter(|allowed_field| allowed_field.field_name() == &conditional.field)
+                        .count();
+                    if occurrences > 1 {
+                        return Err(
+                            TypeOutputPermissionError::DuplicateConditionalAllowedField {
+                                field_name: conditional.field.clone(),
+                                type_name: type_permissions.type_name.clone(),
+                            },
+                        );
+                    }
+                    // the field is null on the objects that don't match the condition
+                    let field_type =
+                        &object_type_representation.fields[&conditional.field].field_type;
+                    if !field_type.nullable {
+                        return Err(TypeOutputPermissionError::NonNullableConditionalField {
+                            field_name: conditional.field.clone(),
+                            type_name: type_permissions.type_name.clone(),
+                        });
+                    }
+                    let condition = resolve_field_condition(
+                        flags,
+                        object_types,
+                        object_type_representation,
+                        &output.allowed_fields,
+                        &conditional.field,
+                        &conditional.condition,
+                        &type_permissions.type_name,
+                        issues,
+                    )?;
+                    field_conditions.insert(conditional.field.clone(), condition);
+                }
+            }
+            let mut field_argument_presets = BTreeMap::<_, BTreeMap<_, _>>::new();
+            for FieldArgumentPreset {
+                field: field_name,
+                argument: argument_name,
+                value,
+            } in &output.field_argument_presets
+            {
+                // check if the field and its argument exist on this type
+                let argument_type = &object_type_representation
+                    .fields
+                    .get(field_name)
+                    .ok_or_else(|| {
+                        TypeOutputPermissionError::UnknownFieldInOutputPermissionsDefinition {
+                            field_name: field_name.clone(),
+                            type_name: type_permissions.type_name.clone(),
+                        }
+                    })?
+                    .field_arguments
+                    .get(argument_name)
+                    .ok_or_else(|| {
+                        TypeOutputPermissionError::UnknownFieldArgumentInOutputPermissionsDefinition {
+                            field_name: field_name.clone(),
+                            argument_name: argument_name.clone(),
+                            type_name: type_permissions.type_name.clone(),
+                        }
+                    })?
+                    .argument_type;
+                // check if the value is provided typechecks
+                let new_issues =
+                    typecheck::typecheck_value_expression(object_types, argument_type, value)
+                        .map_err(|type_error| {
+                            TypeOutputPermissionError::FieldArgumentPresetTypeError {
+                                field_name: field_name.clone(),
+                                argument_name: argument_name.clone(),
+                                type_name: type_permissions.type_name.clone(),
+                                type_error,
+                            }
+                        })?;
+                // Convert typecheck issues into type permission issues and collect them
+                for issue in new_issues {
+                    issues.push(TypePermissionIssue::FieldArgumentPresetTypecheckIssue {
+                        field_name: field_name.clone(),
+                        argument_name: argument_name.clone(),
+                        type_name: type_permissions.type_name.clone(),
+                        typecheck_issue: issue,
+                    });
+                }
+                if field_argument_presets
+                    .entry(field_name.clone())
+                    .or_default()
+                    .insert(
+                        argument_name.clone(),
+                        resolve_value_expression(flags, value),
+                    )
+                    .is_some()
+                {
+                    return Err(TypeOutputPermissionError::DuplicateFieldArgumentPreset {
+                        field_name: field_name.clone(),
+                        argument_name: argument_name.clone(),
+                        type_name: type_permissions.type_name.clone(),
+                    });
+                }
             }
             if resolved_type_permissions
-                .insert(type_permission.role.clone(), output.clone())
+                .insert(
+                    type_permission.role.clone(),
+                    TypeOutputPermission {
+                        allowed_fields,
+                        field_argument_presets,
+                        field_conditions,
+                    },
+                )
                 .is_some()
             {
                 return Err(TypeOutputPermissionError::DuplicateOutputTypePermissions {
@@ -123,6 +249,133 @@ pub fn resolve_output_type_permission(
     Ok(resolved_type_permissions)
 }
 
+/// Resolve the condition of a conditionally allowed field. Conditions are evaluated by
+/// the engine on the objects returned by the data connector, so they can only compare
+/// fields that the role can always access, with the equality operators.
+#[allow(clippy::too_many_arguments)]
+fn resolve_field_condition(
+    flags: &open_dds::flags::OpenDdFlags,
+    object_types: &BTreeMap<
+        &Qualified<open_dds::types::CustomTypeName>,
+        &object_types::ObjectTypeRepresentation,
+    >,
+    object_type_representation: &object_types::ObjectTypeRepresentation,
+    allowed_fields: &[AllowedField],
+    conditional_field_name: &FieldName,
+    predicate: &ModelPredicate,
+    type_name: &open_dds::types::CustomTypeName,
+    issues: &mut Vec<TypePermissionIssue>,
+) -> Result<FieldCondition, TypeOutputPermissionError> {
+    // check that the field used in the condition is accessible on all objects
+    let resolve_condition_field = |field_name: &FieldName| {
+        let is_unconditionally_allowed = allowed_fields
+            .iter()
+            .any(|allowed_field| matches!(allowed_field, AllowedField::Field(name) if name == field_name));
+        if !is_unconditionally_allowed {
+            return Err(TypeOutputPermissionError::FieldConditionFieldNotAllowed {
+                field_name: conditional_field_name.clone(),
+                condition_field_name: field_name.clone(),
+                type_name: type_name.clone(),
+            });
+        }
+        object_type_representation
+            .fields
+            .get(field_name)
+            .ok_or_else(
+                || TypeOutputPermissionError::UnknownFieldInOutputPermissionsDefinition {
+                    field_name: field_name.clone(),
+                    type_name: type_name.clone(),
+                },
+            )
+    };
+    let resolve_nested = |predicate: &ModelPredicate, issues: &mut Vec<TypePermissionIssue>| {
+        resolve_field_condition(
+            flags,
+            object_types,
+            object_type_representation,
+            allowed_fields,
+            conditional_field_name,
+            predicate,
+            type_name,
+            issues,
+        )
+    };
+    match predicate {
+        ModelPredicate::FieldComparison(open_dds::permissions::FieldComparisonPredicate {
+            field,
+            operator,
+            value,
+        }) => {
+            let field_definition = resolve_condition_field(field)?;
+            let operator = match operator.as_str() {
+                "_eq" => FieldConditionOperator::Equal,
+                "_neq" => FieldConditionOperator::NotEqual,
+                _ => {
+                    return Err(
+                        TypeOutputPermissionError::UnsupportedFieldConditionOperator {
+                            field_name: conditional_field_name.clone(),
+                            operator: operator.clone(),
+                            type_name: type_name.clone(),
+                        },
+                    )
+                }
+            };
+            let new_issues = typecheck::typecheck_value_expression(
+                object_types,
+                &field_definition.field_type,
+                value,
+            )
+            .map_err(|type_error| {
+                TypeOutputPermissionError::FieldConditionTypeError {
+                    field_name: conditional_field_name.clone(),
+                    type_name: type_name.clone(),
+                    type_error,
+                }
+            })?;
+            for issue in new_issues {
+                issues.push(TypePermissionIssue::FieldConditionTypecheckIssue {
+                    field_name: conditional_field_name.clone(),
+                    type_name: type_name.clone(),
+                    typecheck_issue: issue,
+                });
+            }
+            Ok(FieldCondition::FieldComparison {
+                field: field.clone(),
+                field_type: field_definition.field_type.clone(),
+                operator,
+                value: resolve_value_expression(flags, value),
+            })
+        }
+        ModelPredicate::FieldIsNull(open_dds::permissions::FieldIsNullPredicate { field }) => {
+            resolve_condition_field(field)?;
+            Ok(FieldCondition::FieldIsNull {
+                field: field.clone(),
+            })
+        }
+        ModelPredicate::Relationship(_) => Err(
+            TypeOutputPermissionError::UnsupportedRelationshipInFieldCondition {
+                field_name: conditional_field_name.clone(),
+                type_name: type_name.clone(),
+            },
+        ),
+        ModelPredicate::And(predicates) => Ok(FieldCondition::And(
+            predicates
+                .iter()
+                .map(|predicate| resolve_nested(predicate, issues))
+                .collect::<Result<_, _>>()?,
+        )),
+        ModelPredicate::Or(predicates) => Ok(FieldCondition::Or(
+            predicates
+                .iter()
+                .map(|predicate| resolve_nested(predicate, issues))
+                .collect::<Result<_, _>>()?,
+        )),
+        ModelPredicate::Not(predicate) => Ok(FieldCondition::Not(Box::new(resolve_nested(
+            predicate, issues,
+        )?))),
+    }
+}
+
 pub(crate) fn resolve_input_type_permission(
     flags: &open_dds::flags::OpenDdFlags,
     object_types: &BTreeMap<
@@ -178,24 +431,10 @@ pub(crate) fn resolve_input_type_permission(
                         );
                     }
                 };
-                let resolved_value = match &value {
-                    open_dds::permissions::ValueExpression::Literal(literal) => {
-                        ValueExpression::Literal(literal.clone())
-                    }
-                    open_dds::permissions::ValueExpression::SessionVariable(session_variable) => {
-                        ValueExpression::SessionVariable(
-                            hasura_authn_core::SessionVariableReference {
-                                name: session_variable.clone(),
-                                passed_as_json: flags
-                                    .contains(open_dds::flags::Flag::JsonSessionVariables),
-                            },
-                        )
-                    }
-                };
                 resolved_field_presets.insert(
                     field_name.clone(),
                     FieldPresetInfo {
-                        value: resolved_value,
+                        value: resolve_value_expression(flags, value),
                         deprecated: field_definition.deprecated.clone(),
                     },
                 );
@@ -217,3 +456,20 @@ pub(crate) fn resolve_input_type_permission(
     }
     Ok(resolved_type_permissions)
 }
+
+pub(crate) fn resolve_value_expression(
+    flags: &open_dds::flags::OpenDdFlags,
+    value: &open_dds::permissions::ValueExpression,
+) -> ValueExpression {
+    match value {
+        open_dds::permissions::ValueExpression::Literal(literal) => {
+            ValueExpression::Literal(literal.clone())
+        }
+        open_dds::permissions::ValueExpression::SessionVariable(session_variable) => {
+            ValueExpression::SessionVariable(hasura_authn_core::SessionVariableReference {
+                name: session_variable.clone(),
+                passed_as_json: flags.contains(open_dds::flags::Flag::JsonSessionVariables),
+            })
+        }
+    }
+}
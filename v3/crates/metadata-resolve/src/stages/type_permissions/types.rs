@@ -1,12 +1,10 @@
 use std::collections::BTreeMap;
 
-use open_dds::{
-    permissions::{Role, TypeOutputPermission},
-    types::Deprecated,
-};
+use indexmap::IndexSet;
+use open_dds::{arguments::ArgumentName, permissions::Role, types::Deprecated};
 
-use crate::Qualified;
 use crate::{stages::object_types, ValueExpression};
+use crate::{Qualified, QualifiedTypeReference};
 use open_dds::types::{CustomTypeName, FieldName};
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
@@ -36,6 +34,73 @@ impl ObjectTypesWithPermissions {
     }
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TypeOutputPermission {
+    pub allowed_fields: IndexSet<FieldName>,
+    /// preset values for the arguments of each field, which are not exposed to the role
+    pub field_argument_presets: BTreeMap<FieldName, BTreeMap<ArgumentName, ValueExpression>>,
+    /// conditions for the fields that are only accessible on some objects. These fields are
+    /// also in `allowed_fields`, and are null on the objects that don't match their condition.
+    pub field_conditions: BTreeMap<FieldName, FieldCondition>,
+}
+
+impl TypeOutputPermission {
+    /// Whether the field is accessible on all objects of the type
+    pub fn is_unconditionally_allowed(&self, field_name: &FieldName) -> bool {
+        self.allowed_fields.contains(field_name) && !self.field_conditions.contains_key(field_name)
+    }
+}
+
+/// A condition on the fields of an object, evaluated by the engine on each object
+/// returned by the data connector.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum FieldCondition {
+    FieldComparison {
+        field: FieldName,
+        field_type: QualifiedTypeReference,
+        operator: FieldConditionOperator,
+        value: ValueExpression,
+    },
+    FieldIsNull {
+        field: FieldName,
+    },
+    And(Vec<FieldCondition>),
+    Or(Vec<FieldCondition>),
+    Not(Box<FieldCondition>),
+}
+
+impl FieldCondition {
+    /// The fields that the condition depends on
+    pub fn fields(&self) -> Vec<&FieldName> {
+        let mut fields = Vec::new();
+        self.collect_fields(&mut fields);
+        fields
+    }
+
+    fn collect_fields<'a>(&'a self, fields: &mut Vec<&'a FieldName>) {
+        match self {
+            FieldCondition::FieldComparison { field, .. }
+            | FieldCondition::FieldIsNull { field } => {
+                if !fields.contains(&field) {
+                    fields.push(field);
+                }
+            }
+            FieldCondition::And(conditions) | FieldCondition::Or(conditions) => {
+                for condition in conditions {
+                    condition.collect_fields(fields);
+                }
+            }
+            FieldCondition::Not(condition) => condition.collect_fields(fields),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldConditionOperator {
+    Equal,
+    NotEqual,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct TypeInputPermission {
     pub field_presets: BTreeMap<FieldName, FieldPresetInfo>,
@@ -13,8 +13,8 @@ use open_dds::{
 use crate::types::subgraph::Qualified;
 
 use crate::stages::{
-    aggregates, boolean_expressions, command_permissions, graphql_config, model_permissions,
-    object_relationships, order_by_expressions, scalar_type_representations,
+    abstract_types, aggregates, boolean_expressions, command_permissions, graphql_config,
+    model_permissions, object_relationships, order_by_expressions, scalar_type_representations,
 };
 
 use super::plugins::LifecyclePluginConfigs;
@@ -30,6 +30,9 @@ pub struct Metadata {
     pub scalar_types:
         BTreeMap<Qualified<CustomTypeName>, scalar_type_representations::ScalarTypeRepresentation>,
     #[serde_as(as = "Vec<(_, _)>")]
+    pub abstract_types:
+        BTreeMap<Qualified<CustomTypeName>, abstract_types::AbstractTypeRepresentation>,
+    #[serde_as(as = "Vec<(_, _)>")]
     pub models: IndexMap<Qualified<ModelName>, model_permissions::ModelWithPermissions>,
     #[serde_as(as = "Vec<(_, _)>")]
     pub commands: IndexMap<Qualified<CommandName>, command_permissions::CommandWithPermissions>,
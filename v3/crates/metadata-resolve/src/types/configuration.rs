@@ -15,5 +15,4 @@ pub struct Configuration {
 #[allow(clippy::struct_excessive_bools)]
 pub struct UnstableFeatures {
     pub enable_ndc_v02_support: bool,
-    pub enable_aggregation_predicates: bool,
 }
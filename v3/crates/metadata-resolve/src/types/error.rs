@@ -3,10 +3,10 @@ use crate::helpers::{
     ndc_validation::NDCValidationError, type_mappings::TypeMappingCollectionError, typecheck,
 };
 use crate::stages::{
-    aggregate_boolean_expressions, aggregates::AggregateExpressionError, apollo,
-    boolean_expressions, commands, data_connector_scalar_types, data_connectors, graphql_config,
-    models, object_types, order_by_expressions, relationships, relay, scalar_boolean_expressions,
-    scalar_types, type_permissions,
+    abstract_types, aggregate_boolean_expressions, aggregates::AggregateExpressionError, apollo,
+    boolean_expressions, command_permissions, commands, data_connector_scalar_types,
+    data_connectors, graphql_config, models, object_types, order_by_expressions, relationships,
+    relay, role_inheritance, scalar_boolean_expressions, scalar_types, type_permissions,
 };
 use crate::types::subgraph::{Qualified, QualifiedTypeReference};
 use hasura_authn_core::Role;
@@ -16,7 +16,7 @@ use open_dds::flags;
 use open_dds::order_by_expression::OrderByExpressionName;
 use open_dds::{
     arguments::ArgumentName,
-    commands::CommandName,
+    commands::{CommandName, ProcedureName},
     data_connector::{DataConnectorName, DataConnectorObjectType},
     models::ModelName,
     relationships::RelationshipName,
@@ -123,6 +123,56 @@ pub enum Error {
         model_name: Qualified<ModelName>,
         field_name: FieldName,
     },
+    #[error("the cursor pagination of model {model_name:} must define a unique identifier with at least one field")]
+    EmptyUniqueIdentifierForCursorPagination { model_name: Qualified<ModelName> },
+    #[error("field {field_name:} in the cursor pagination unique identifier of model {model_name:} cannot be part of a cursor. A cursor field must be a non-nullable scalar field without arguments, mapped to a column with 'equal', 'greater than' and 'less than' operators")]
+    UnsupportedFieldInCursorPaginationUniqueIdentifier {
+        model_name: Qualified<ModelName>,
+        field_name: FieldName,
+    },
+    #[error("model {model_name:} defines a distinct_on argument, but its data connector {data_connector_name:} does not support grouping. Distinct rows are fetched by grouping the rows")]
+    DistinctOnNotSupportedByDataConnector {
+        model_name: Qualified<ModelName>,
+        data_connector_name: Qualified<DataConnectorName>,
+    },
+    #[error("model {model_name:} defines a distinct_on argument, but none of its fields can be used to select distinct rows. A distinct on field must be an orderable scalar field without arguments, mapped to a column")]
+    DistinctOnWithoutFields { model_name: Qualified<ModelName> },
+    #[error("model {model_name:} defines a group by root field, but its data connector {data_connector_name:} does not support grouping")]
+    GroupByNotSupportedByDataConnector {
+        model_name: Qualified<ModelName>,
+        data_connector_name: Qualified<DataConnectorName>,
+    },
+    #[error("model {model_name:} defines a group by root field, but none of its fields can be grouped by. A field to group by must be a scalar field without arguments, mapped to a column")]
+    GroupByWithoutDimensions { model_name: Qualified<ModelName> },
+    #[error("model {model_name:} defines a select connection, but is not a global ID source. The edges of a connection are identified by the global ID fields of the model")]
+    SelectConnectionWithoutGlobalIdSource { model_name: Qualified<ModelName> },
+    #[error("model {model_name:} defines mutations, but has no source. Mutations call procedures of the data connector of the model")]
+    ModelMutationsWithoutSource { model_name: Qualified<ModelName> },
+    #[error("the procedure {procedure:} of the mutation {mutation_root_field:} of model {model_name:} is not defined in its data connector")]
+    UnknownModelMutationProcedure {
+        model_name: Qualified<ModelName>,
+        mutation_root_field: ast::Name,
+        procedure: ProcedureName,
+    },
+    #[error("the procedure {procedure:} of the mutation {mutation_root_field:} of model {model_name:} must have an argument named {argument_name:}")]
+    MissingModelMutationProcedureArgument {
+        model_name: Qualified<ModelName>,
+        mutation_root_field: ast::Name,
+        procedure: ProcedureName,
+        argument_name: String,
+    },
+    #[error("model {model_name:} defines update mutations, but no setInputTypeName for the new values of the fields")]
+    MissingSetInputTypeNameForModelUpdate { model_name: Qualified<ModelName> },
+    #[error("the mutation {mutation_root_field:} of model {model_name:} filters the objects it affects, but the model has no filter expression type with graphql config")]
+    ModelMutationWithoutFilterExpression {
+        model_name: Qualified<ModelName>,
+        mutation_root_field: ast::Name,
+    },
+    #[error("global ID field {field_name:} of model {model_name:} cannot be part of the cursors of its select connection. A cursor field must be a non-nullable scalar field without arguments, mapped to a column with 'equal', 'greater than' and 'less than' operators")]
+    UnsupportedGlobalIdFieldInSelectConnection {
+        model_name: Qualified<ModelName>,
+        field_name: FieldName,
+    },
     #[error("graphql config must be defined for a filter expression to be used in a {model:}")]
     CannotUseFilterExpressionsWithoutGraphQlConfig {
         model: Qualified<ModelName>,
@@ -311,6 +361,8 @@ pub enum Error {
     NDCValidationError(#[from] NDCValidationError),
     #[error("{0}")]
     ScalarTypesError(#[from] scalar_types::ScalarTypesError),
+    #[error("{0}")]
+    AbstractTypesError(#[from] abstract_types::AbstractTypesError),
     #[error("{type_error:}")]
     TypeError { type_error: TypeError },
     #[error("{0}")]
@@ -328,8 +380,12 @@ pub enum Error {
     #[error("{0}")]
     CommandsError(#[from] commands::CommandsError),
     #[error("{0}")]
+    CommandPermissionError(#[from] command_permissions::CommandPermissionError),
+    #[error("{0}")]
     RelationshipError(#[from] relationships::RelationshipError),
     #[error("{0}")]
+    RoleInheritanceError(#[from] role_inheritance::RoleInheritanceError),
+    #[error("{0}")]
     DataConnectorScalarTypesError(
         #[from] data_connector_scalar_types::DataConnectorScalarTypesError,
     ),
@@ -401,6 +457,28 @@ pub enum RelationshipError {
         command_name: Qualified<CommandName>,
         argument_name: ArgumentName,
     },
+    #[error("target argument {argument_name} in argument mapping for relationship {relationship_name} on type {source_type} to model {model_name} is unknown.")]
+    UnknownTargetModelArgumentInRelationshipMapping {
+        source_type: Qualified<CustomTypeName>,
+        relationship_name: RelationshipName,
+        model_name: Qualified<ModelName>,
+        argument_name: ArgumentName,
+    },
+    #[error("field {field_name} in the {location} field path of relationship {relationship_name} on type {source_type} is not a field of the nested object type {type_name}")]
+    UnknownNestedFieldInRelationshipMapping {
+        source_type: Qualified<CustomTypeName>,
+        relationship_name: RelationshipName,
+        location: String,
+        type_name: Qualified<CustomTypeName>,
+        field_name: FieldName,
+    },
+    #[error("the {location} field path of relationship {relationship_name} on type {source_type} continues past field {field_name}, which is not an object type. Only fields of nested objects (not arrays) can be used in relationship field paths")]
+    NestedFieldPathThroughNonObjectField {
+        source_type: Qualified<CustomTypeName>,
+        relationship_name: RelationshipName,
+        location: String,
+        field_name: FieldName,
+    },
     #[error("Mapping for source field {field_name} already exists in the relationship {relationship_name} on type {type_name}")]
     MappingExistsInRelationship {
         type_name: Qualified<CustomTypeName>,
@@ -414,6 +492,13 @@ pub enum RelationshipError {
         relationship_name: RelationshipName,
         type_name: Qualified<CustomTypeName>,
     },
+    #[error("Mapping for target argument {argument_name} of model {model_name} already exists in the relationship {relationship_name} on type {type_name}")]
+    ModelArgumentMappingExistsInRelationship {
+        argument_name: ArgumentName,
+        model_name: Qualified<ModelName>,
+        relationship_name: RelationshipName,
+        type_name: Qualified<CustomTypeName>,
+    },
     #[error("No mapping for target command argument {argument_name} in the relationship {relationship_name} on type {type_name}")]
     MissingArgumentMappingInRelationship {
         type_name: Qualified<CustomTypeName>,
@@ -426,6 +511,12 @@ pub enum RelationshipError {
         relationship_name: RelationshipName,
         data_connector_name: Qualified<DataConnectorName>,
     },
+    #[error("The target data connector {data_connector_name} for relationship {relationship_name} on type {type_name} does not support filtering by nested object fields, which is required for mappings to nested fields")]
+    RelationshipTargetDoesNotSupportNestedObjectFiltering {
+        type_name: Qualified<CustomTypeName>,
+        relationship_name: RelationshipName,
+        data_connector_name: Qualified<DataConnectorName>,
+    },
     #[error("The target data connector {data_connector_name} for relationship {relationship_name} on type {type_name} has not defined any capabilities")]
     NoRelationshipCapabilitiesDefined {
         type_name: Qualified<CustomTypeName>,
@@ -437,6 +528,17 @@ pub enum RelationshipError {
         type_name: Qualified<CustomTypeName>,
         relationship_name: RelationshipName,
     },
+    #[error("The relationship {relationship_name} on type {type_name} defines a connection field, but connections can only be used with array relationships, not object relationships")]
+    ConnectionIsOnlyAllowedOnArrayRelationships {
+        type_name: Qualified<CustomTypeName>,
+        relationship_name: RelationshipName,
+    },
+    #[error("The relationship {relationship_name} on type {type_name} defines a connection field, but its target model {model_name} does not define a select connection")]
+    ConnectionRelationshipWithoutSelectConnection {
+        type_name: Qualified<CustomTypeName>,
+        relationship_name: RelationshipName,
+        model_name: Qualified<ModelName>,
+    },
     #[error("The aggregate defined on the relationship {relationship_name} on type {type_name} has an error: {error}")]
     ModelAggregateExpressionError {
         type_name: Qualified<CustomTypeName>,
@@ -558,15 +660,20 @@ pub enum TypePredicateError {
         ndc_column: DataConnectorColumnName,
         data_connector_name: Qualified<DataConnectorName>,
     },
-    #[error(
-        "Relationships across subgraphs are not supported in filter predicates. \
-         Relationship: {relationship_name:} is defined between source data connector: {source_data_connector:} \
-         and target data connector: {target_data_connector:}"
-    )]
-    RelationshipAcrossSubgraphs {
+    #[error("relationship '{relationship_name:}' on type '{type_name:}' has mappings from values and cannot be used in predicates")]
+    RelationshipWithValueMappingsInTypePredicate {
         relationship_name: RelationshipName,
-        source_data_connector: Qualified<DataConnectorName>,
-        target_data_connector: Qualified<DataConnectorName>,
+        type_name: Qualified<CustomTypeName>,
+    },
+    #[error("relationship '{relationship_name:}' on type '{type_name:}' has mappings to model arguments and cannot be used in predicates")]
+    RelationshipWithArgumentMappingsInTypePredicate {
+        relationship_name: RelationshipName,
+        type_name: Qualified<CustomTypeName>,
+    },
+    #[error("relationship '{relationship_name:}' on type '{type_name:}' has mappings with nested field paths and cannot be used in predicates")]
+    RelationshipWithNestedFieldPathsInTypePredicate {
+        relationship_name: RelationshipName,
+        type_name: Qualified<CustomTypeName>,
     },
 }
 
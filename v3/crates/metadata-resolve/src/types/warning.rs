@@ -5,7 +5,8 @@ use crate::{
     stages::{
         aggregate_boolean_expressions, aggregates, boolean_expressions, command_permissions,
         commands, data_connectors, model_permissions, models, models_graphql, object_types,
-        order_by_expressions, scalar_boolean_expressions, scalar_types, type_permissions,
+        order_by_expressions, role_inheritance, scalar_boolean_expressions, scalar_types,
+        type_permissions,
     },
     Qualified,
 };
@@ -49,6 +50,8 @@ pub enum Warning {
     #[error("{0}")]
     ScalarTypesIssue(#[from] scalar_types::ScalarTypesIssue),
     #[error("{0}")]
+    RoleInheritanceIssue(#[from] role_inheritance::RoleInheritanceIssue),
+    #[error("{0}")]
     ConflictingNameAcrossTypes(ConflictingNameAcrossTypes),
 }
 
@@ -67,6 +70,7 @@ impl ShouldBeAnError for Warning {
             Warning::ModelPermissionIssue(issue) => issue.should_be_an_error(flags),
             Warning::TypePermissionIssue(issue) => issue.should_be_an_error(flags),
             Warning::CommandPermissionIssue(issue) => issue.should_be_an_error(flags),
+            Warning::RoleInheritanceIssue(issue) => issue.should_be_an_error(flags),
             _ => false,
         }
     }
@@ -33,6 +33,9 @@ pub struct MetadataAccessor {
     pub object_types: Vec<QualifiedObject<types::ObjectTypeV1>>,
     pub object_boolean_expression_types: Vec<QualifiedObject<types::ObjectBooleanExpressionTypeV1>>,
     pub scalar_types: Vec<QualifiedObject<types::ScalarTypeV1>>,
+    pub enum_types: Vec<QualifiedObject<types::EnumTypeV1>>,
+    pub union_types: Vec<QualifiedObject<types::UnionTypeV1>>,
+    pub interface_types: Vec<QualifiedObject<types::InterfaceTypeV1>>,
     pub boolean_expression_types: Vec<QualifiedObject<boolean_expression::BooleanExpressionTypeV1>>,
     pub order_by_expressions: Vec<QualifiedObject<order_by_expression::OrderByExpressionV1>>,
     pub data_connector_scalar_representations:
@@ -44,6 +47,7 @@ pub struct MetadataAccessor {
     pub relationships: Vec<QualifiedObject<relationships::RelationshipV1>>,
     pub commands: Vec<QualifiedObject<commands::CommandV1>>,
     pub command_permissions: Vec<QualifiedObject<permissions::CommandPermissionsV1>>,
+    pub roles: Vec<QualifiedObject<permissions::RoleDefinitionV1>>,
     pub flags: flags::OpenDdFlags,
     // `graphql_config` is a vector because we want to do some validation depending on the presence of the object
     pub graphql_config: Vec<QualifiedObject<graphql_config::GraphqlConfig>>,
@@ -86,6 +90,27 @@ fn load_metadata_objects(
                     scalar_type.value.upgrade(),
                 ));
             }
+            OpenDdSubgraphObject::EnumType(enum_type) => {
+                accessor.enum_types.push(QualifiedObject::new(
+                    enum_type.path,
+                    subgraph,
+                    enum_type.value.upgrade(),
+                ));
+            }
+            OpenDdSubgraphObject::UnionType(union_type) => {
+                accessor.union_types.push(QualifiedObject::new(
+                    union_type.path,
+                    subgraph,
+                    union_type.value.upgrade(),
+                ));
+            }
+            OpenDdSubgraphObject::InterfaceType(interface_type) => {
+                accessor.interface_types.push(QualifiedObject::new(
+                    interface_type.path,
+                    subgraph,
+                    interface_type.value.upgrade(),
+                ));
+            }
             OpenDdSubgraphObject::ObjectBooleanExpressionType(object_boolean_expression_type) => {
                 accessor
                     .object_boolean_expression_types
@@ -167,6 +192,13 @@ fn load_metadata_objects(
                     permissions.value.upgrade(),
                 ));
             }
+            OpenDdSubgraphObject::Role(role) => {
+                accessor.roles.push(QualifiedObject::new(
+                    role.path,
+                    subgraph,
+                    role.value.upgrade(),
+                ));
+            }
             OpenDdSubgraphObject::LifecyclePluginHook(plugin) => {
                 accessor.plugins.push(QualifiedObject::new(
                     plugin.path,
@@ -238,6 +270,9 @@ impl MetadataAccessor {
             data_connectors: vec![],
             object_types: vec![],
             scalar_types: vec![],
+            enum_types: vec![],
+            union_types: vec![],
+            interface_types: vec![],
             object_boolean_expression_types: vec![],
             boolean_expression_types: vec![],
             order_by_expressions: vec![],
@@ -249,6 +284,7 @@ impl MetadataAccessor {
             relationships: vec![],
             commands: vec![],
             command_permissions: vec![],
+            roles: vec![],
             flags: flags.unwrap_or_default(),
             graphql_config: vec![],
             plugins: vec![],
@@ -138,10 +138,10 @@ pub enum BooleanExpressionOperand {
     #[opendd(json_schema(title = "Scalar"))]
     Scalar(BooleanExpressionScalarOperand),
     /// Definition of a boolean expression on an aggregate of an OpenDD object type
-    #[opendd(hidden, json_schema(title = "ObjectAggregate"))]
+    #[opendd(json_schema(title = "ObjectAggregate"))]
     ObjectAggregate(BooleanExpressionObjectAggregateOperand),
-    /// Definition of a boolean expression on an aggregate of an OpenDD object type
-    #[opendd(hidden, json_schema(title = "ScalarAggregate"))]
+    /// Definition of a boolean expression on an aggregate of a scalar type
+    #[opendd(json_schema(title = "ScalarAggregate"))]
     ScalarAggregate(BooleanExpressionScalarAggregateOperand),
 }
 
@@ -314,7 +314,6 @@ pub struct BooleanExpressionComparableRelationship {
     /// The boolean expression type to use for comparisons against aggregates of the target of
     /// the relationship. The specified boolean expression must be an objectAggregate boolean expression.
     /// This is optional, but if omitted one will be unable to perform aggregate comparisons.
-    #[opendd(hidden)]
     pub aggregate_boolean_expression_type: Option<CustomTypeName>,
 }
 
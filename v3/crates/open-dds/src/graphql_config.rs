@@ -129,6 +129,18 @@ pub struct OrderByDirectionValues {
     pub asc: GraphQlFieldName,
     /// The name of the descending parameter. Usually `Desc`.
     pub desc: GraphQlFieldName,
+    /// The name of the ascending parameter that places nulls first. Usually `AscNullsFirst`.
+    /// Required if any enum type includes the `AscNullsFirst` direction.
+    pub asc_nulls_first: Option<GraphQlFieldName>,
+    /// The name of the ascending parameter that places nulls last. Usually `AscNullsLast`.
+    /// Required if any enum type includes the `AscNullsLast` direction.
+    pub asc_nulls_last: Option<GraphQlFieldName>,
+    /// The name of the descending parameter that places nulls first. Usually `DescNullsFirst`.
+    /// Required if any enum type includes the `DescNullsFirst` direction.
+    pub desc_nulls_first: Option<GraphQlFieldName>,
+    /// The name of the descending parameter that places nulls last. Usually `DescNullsLast`.
+    /// Required if any enum type includes the `DescNullsLast` direction.
+    pub desc_nulls_last: Option<GraphQlFieldName>,
 }
 
 /// Sort direction.
@@ -152,6 +164,14 @@ pub enum OrderByDirection {
     Asc,
     /// Descending.
     Desc,
+    /// Ascending, with nulls before all other values.
+    AscNullsFirst,
+    /// Ascending, with nulls after all other values.
+    AscNullsLast,
+    /// Descending, with nulls before all other values.
+    DescNullsFirst,
+    /// Descending, with nulls after all other values.
+    DescNullsLast,
 }
 
 /// Type name for a sort directions enum, with the given set of possible directions.
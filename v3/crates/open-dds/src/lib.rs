@@ -95,6 +95,9 @@ pub enum OpenDdSubgraphObject {
     // Types
     ObjectType(Spanned<types::ObjectType>),
     ScalarType(Spanned<types::ScalarType>),
+    EnumType(Spanned<types::EnumType>),
+    UnionType(Spanned<types::UnionType>),
+    InterfaceType(Spanned<types::InterfaceType>),
     ObjectBooleanExpressionType(Spanned<types::ObjectBooleanExpressionType>),
     BooleanExpressionType(Spanned<boolean_expression::BooleanExpressionType>),
 
@@ -121,6 +124,9 @@ pub enum OpenDdSubgraphObject {
     ModelPermissions(Spanned<permissions::ModelPermissions>),
     CommandPermissions(Spanned<permissions::CommandPermissions>),
 
+    // Roles
+    Role(Spanned<permissions::RoleDefinition>),
+
     // Plugin
     LifecyclePluginHook(Spanned<plugins::LifecyclePluginHook>),
 }
@@ -2,9 +2,9 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    aggregates::AggregateExpressionName,
+    aggregates::{AggregateExpressionName, DataConnectorAggregationFunctionName},
     arguments::ArgumentDefinition,
-    commands::ArgumentMapping,
+    commands::{ArgumentMapping, ProcedureName},
     data_connector::{CollectionName, DataConnectorName},
     identifier::Identifier,
     order_by_expression::OrderByExpressionName,
@@ -278,6 +278,12 @@ pub struct ModelGraphQlDefinition {
     /// Configures the query root field added to the GraphQL API that can be used to
     /// aggregate over the model
     pub aggregate: Option<ModelAggregateGraphQlDefinition>,
+    /// Select connection configuration for a model adds a query root field to the GraphQL API
+    /// that can be used to paginate through the objects of the model with a Relay connection.
+    pub select_connection: Option<SelectConnectionGraphQlDefinition>,
+    /// Mutations configuration for a model adds mutation root fields to the GraphQL API that
+    /// can be used to insert, update and delete the objects of the model.
+    pub mutations: Option<ModelMutationsGraphQlDefinition>,
 }
 
 impl ModelGraphQlDefinition {
@@ -312,6 +318,8 @@ impl ModelGraphQlDefinition {
             apollo_federation: self.apollo_federation,
             filter_input_type_name: self.filter_input_type_name,
             aggregate: self.aggregate,
+            select_connection: self.select_connection,
+            mutations: self.mutations,
         }
     }
 }
@@ -342,6 +350,12 @@ pub struct ModelGraphQlDefinitionV2 {
     /// Configures the query root field added to the GraphQL API that can be used to
     /// aggregate over the model
     pub aggregate: Option<ModelAggregateGraphQlDefinition>,
+    /// Select connection configuration for a model adds a query root field to the GraphQL API
+    /// that can be used to paginate through the objects of the model with a Relay connection.
+    pub select_connection: Option<SelectConnectionGraphQlDefinition>,
+    /// Mutations configuration for a model adds mutation root fields to the GraphQL API that
+    /// can be used to insert, update and delete the objects of the model.
+    pub mutations: Option<ModelMutationsGraphQlDefinition>,
 }
 
 impl ModelGraphQlDefinitionV2 {
@@ -402,6 +416,13 @@ pub struct SelectManyGraphQlDefinition {
     pub deprecated: Option<Deprecated>,
     /// Enable subscription on this select many root field.
     pub subscription: Option<SubscriptionGraphQlDefinition>,
+    /// Enable streaming subscription on this select many root field.
+    pub stream_subscription: Option<StreamSubscriptionGraphQlDefinition>,
+    /// Enable cursor pagination on this select many root field.
+    pub cursor_pagination: Option<CursorPaginationGraphQlDefinition>,
+    /// Enable selecting distinct rows on this select many root field, and on the array
+    /// relationships to the model.
+    pub distinct_on: Option<DistinctOnGraphQlDefinition>,
 }
 
 /// The definition of the GraphQL API for enabling subscription on query root fields.
@@ -422,6 +443,186 @@ pub struct SubscriptionGraphQlDefinition {
     pub polling_interval_ms: u64,
 }
 
+/// The definition of the GraphQL API for streaming subscriptions on a model. A streaming
+/// subscription fetches the rows of the model in batches, ordered by a cursor field, and only
+/// sends rows that come after the last row of the previous batch.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(title = "StreamSubscriptionGraphQlDefinition"))]
+pub struct StreamSubscriptionGraphQlDefinition {
+    /// The name of the streaming subscription root field.
+    pub root_field: GraphQlFieldName,
+    /// The name of the input type used to provide the cursor field and its initial value.
+    pub cursor_input_type_name: GraphQlTypeName,
+    /// The description of the streaming subscription graphql definition.
+    /// Gets added to the description of the streaming subscription root field in the graphql schema.
+    pub description: Option<String>,
+    /// Whether this streaming subscription root field is deprecated.
+    /// If set, the deprecation status is added to the streaming subscription root field's graphql schema.
+    pub deprecated: Option<Deprecated>,
+    /// Polling interval in milliseconds for the streaming subscription.
+    #[opendd(default = 1000)]
+    pub polling_interval_ms: u64,
+}
+
+/// The definition of the GraphQL API for cursor (keyset) pagination on a select many root
+/// field. A page of rows is fetched relative to an opaque cursor, which encodes the values of
+/// the fields the rows are ordered by, followed by the fields of the unique identifier.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(title = "CursorPaginationGraphQlDefinition"))]
+pub struct CursorPaginationGraphQlDefinition {
+    /// The fields of the model that uniquely identify a row. The rows are ordered by these
+    /// fields after the requested ordering, so that every row has a distinct cursor.
+    pub unique_identifier: Vec<FieldName>,
+    /// The name of the argument used to fetch the first N rows after the `after` cursor.
+    pub first_argument_name: GraphQlFieldName,
+    /// The name of the argument used to provide the cursor after which rows are fetched.
+    pub after_argument_name: GraphQlFieldName,
+    /// The name of the argument used to fetch the last N rows before the `before` cursor.
+    pub last_argument_name: GraphQlFieldName,
+    /// The name of the argument used to provide the cursor before which rows are fetched.
+    pub before_argument_name: GraphQlFieldName,
+}
+
+/// The definition of the GraphQL API for selecting distinct rows from a model. Only the first
+/// row, in the requested ordering, of each distinct combination of the values of the chosen
+/// fields is selected. The rows can be distinct on the scalar orderable fields of the model.
+///
+/// Distinct rows are fetched by grouping the rows on the chosen fields, which requires the data
+/// connector to support grouping.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(title = "DistinctOnGraphQlDefinition"))]
+pub struct DistinctOnGraphQlDefinition {
+    /// The name of the argument used to choose the fields the rows must be distinct on.
+    pub argument_name: GraphQlFieldName,
+    /// The name of the enum type of the fields the rows can be distinct on.
+    pub enum_type_name: GraphQlTypeName,
+    /// The aggregation function of the data connector that returns the value of a column in
+    /// the first row of each group, in the requested ordering. It is used to fetch every
+    /// selected field of the distinct rows, so it must be defined for all of their scalar types.
+    pub row_aggregation_function: DataConnectorAggregationFunctionName,
+}
+
+/// The definition of the GraphQL API for paginating through a model with the connections of
+/// the Relay cursor connections specification. The model must be a global ID source, as the
+/// edges of a connection are ordered by the requested ordering followed by the global ID fields
+/// of the model.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(title = "SelectConnectionGraphQlDefinition"))]
+pub struct SelectConnectionGraphQlDefinition {
+    /// The name of the query root field for this API.
+    pub query_root_field: GraphQlFieldName,
+    /// The name of the connection type of the model, eg. `ArticleConnection`.
+    pub connection_type_name: GraphQlTypeName,
+    /// The name of the edge type of the model, eg. `ArticleEdge`.
+    pub edge_type_name: GraphQlTypeName,
+    /// The description of the select connection graphql definition of the model.
+    /// Gets added to the description of the select connection root field of the model in the graphql schema.
+    pub description: Option<String>,
+    /// Whether this select connection query field is deprecated.
+    /// If set, the deprecation status is added to the select connection root field's graphql schema.
+    pub deprecated: Option<Deprecated>,
+}
+
+/// The definition of the GraphQL API for inserting, updating and deleting the objects of a
+/// model. Each mutation calls a procedure of the data connector of the model.
+///
+/// The insert procedure takes the objects to insert in the `objects` argument, and the update
+/// procedures take the new values of the updated columns in the `update_columns` argument. The
+/// update and delete procedures take a predicate that selects the affected objects in the
+/// `pre_check` argument, and the insert and update procedures take a predicate that the
+/// affected objects must satisfy in the `post_check` argument. The procedures return the
+/// number of affected objects in the `affected_rows` field and the affected objects in the
+/// `returning` field.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(
+    title = "ModelMutationsGraphQlDefinition",
+    example = "ModelMutationsGraphQlDefinition::example"
+))]
+pub struct ModelMutationsGraphQlDefinition {
+    /// The name of the type of the response of the mutations, eg. `ArticleMutationResponse`.
+    pub response_type_name: GraphQlTypeName,
+    /// The name of the input type used to provide the new values of the fields of the updated
+    /// objects, eg. `ArticleSetInput`. It is required by the update mutations.
+    pub set_input_type_name: Option<GraphQlTypeName>,
+    /// Adds a mutation root field that inserts objects into the model.
+    pub insert: Option<ModelMutationGraphQlDefinition>,
+    /// Adds a mutation root field that updates the object of the model identified by a set of
+    /// unique fields.
+    pub update_by_unique_key: Option<UpdateByUniqueKeyGraphQlDefinition>,
+    /// Adds a mutation root field that updates the objects of the model matching a filter.
+    pub update: Option<ModelMutationGraphQlDefinition>,
+    /// Adds a mutation root field that deletes the objects of the model matching a filter.
+    pub delete: Option<ModelMutationGraphQlDefinition>,
+}
+
+impl ModelMutationsGraphQlDefinition {
+    fn example() -> serde_json::Value {
+        serde_json::json!({
+            "responseTypeName": "ArticleMutationResponse",
+            "setInputTypeName": "ArticleSetInput",
+            "insert": {
+                "mutationRootField": "insert_Article",
+                "procedure": "insert_article"
+            },
+            "updateByUniqueKey": {
+                "mutationRootField": "update_Article_by_id",
+                "procedure": "update_article",
+                "uniqueIdentifier": ["article_id"]
+            },
+            "update": {
+                "mutationRootField": "update_Article",
+                "procedure": "update_article"
+            },
+            "delete": {
+                "mutationRootField": "delete_Article",
+                "procedure": "delete_article"
+            }
+        })
+    }
+}
+
+/// The definition of a mutation root field of a model.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(title = "ModelMutationGraphQlDefinition"))]
+pub struct ModelMutationGraphQlDefinition {
+    /// The name of the mutation root field.
+    pub mutation_root_field: GraphQlFieldName,
+    /// The procedure of the data connector of the model that performs the mutation.
+    pub procedure: ProcedureName,
+    /// The description of the mutation.
+    /// Gets added to the description of the mutation root field in the graphql schema.
+    pub description: Option<String>,
+    /// Whether this mutation root field is deprecated.
+    /// If set, the deprecation status is added to the mutation root field's graphql schema.
+    pub deprecated: Option<Deprecated>,
+}
+
+/// The definition of the mutation root field that updates the object of a model identified by
+/// a set of unique fields.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(title = "UpdateByUniqueKeyGraphQlDefinition"))]
+pub struct UpdateByUniqueKeyGraphQlDefinition {
+    /// The name of the mutation root field.
+    pub mutation_root_field: GraphQlFieldName,
+    /// The procedure of the data connector of the model that performs the update.
+    pub procedure: ProcedureName,
+    /// A set of fields which can uniquely identify an object in the model.
+    pub unique_identifier: Vec<FieldName>,
+    /// The description of the mutation.
+    /// Gets added to the description of the mutation root field in the graphql schema.
+    pub description: Option<String>,
+    /// Whether this mutation root field is deprecated.
+    /// If set, the deprecation status is added to the mutation root field's graphql schema.
+    pub deprecated: Option<Deprecated>,
+}
+
 /// A field that can be used to order the objects in a model.
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, opendds_derive::OpenDd)]
 #[serde(rename_all = "camelCase")]
@@ -472,6 +673,23 @@ impl<'de, T: serde::Deserialize<'de> + JsonSchema> OpenDd for EnableAllOrSpecifi
 pub enum OrderByDirection {
     Asc,
     Desc,
+    AscNullsFirst,
+    AscNullsLast,
+    DescNullsFirst,
+    DescNullsLast,
+}
+
+impl OrderByDirection {
+    /// Whether this direction also specifies if nulls are placed first or last
+    pub fn specifies_nulls_order(&self) -> bool {
+        match self {
+            OrderByDirection::Asc | OrderByDirection::Desc => false,
+            OrderByDirection::AscNullsFirst
+            | OrderByDirection::AscNullsLast
+            | OrderByDirection::DescNullsFirst
+            | OrderByDirection::DescNullsLast => true,
+        }
+    }
 }
 
 /// Apollo Federation configuration for a model.
@@ -500,4 +718,32 @@ pub struct ModelAggregateGraphQlDefinition {
     pub deprecated: Option<Deprecated>,
     /// Enable subscription on this aggregate root field.
     pub subscription: Option<SubscriptionGraphQlDefinition>,
+    /// Enable a root field that groups the rows of the model and aggregates over each group.
+    pub group_by: Option<GroupByGraphQlDefinition>,
+}
+
+/// The definition of the GraphQL API for grouping the rows of a model. Each group has the values
+/// of the fields the rows are grouped by, which are chosen by selecting them, and the aggregates
+/// over the rows of the group. Date and time fields can also be grouped by the parts extracted
+/// from them, such as the year or the month, if the data connector defines the functions to
+/// extract them.
+///
+/// The groups are fetched from the data connector, so it must support grouping.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+#[opendd(json_schema(title = "GroupByGraphQlDefinition"))]
+pub struct GroupByGraphQlDefinition {
+    /// The name of the query root field that returns the groups.
+    pub query_root_field: GraphQlFieldName,
+    /// The name of the object type of a group, with its dimensions and its aggregates.
+    pub group_type_name: GraphQlTypeName,
+    /// The name of the object type of the values of the fields the rows of a group are grouped by.
+    pub dimensions_type_name: GraphQlTypeName,
+    /// The name of the input type used to order the groups by their dimensions or aggregates.
+    pub order_by_type_name: GraphQlTypeName,
+    /// The description of the group by root field.
+    pub description: Option<String>,
+    /// Whether the group by root field is deprecated.
+    pub deprecated: Option<Deprecated>,
 }
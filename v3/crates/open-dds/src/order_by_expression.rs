@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    aggregates::AggregateExpressionName,
     identifier::Identifier,
     models::{EnableAllOrSpecific, OrderByDirection},
     relationships::RelationshipName,
@@ -182,6 +183,11 @@ pub struct OrderByExpressionOrderableRelationship {
     /// If not specified we use the model's OrderByExpression configuration.
     /// For local command relationships this is required.
     pub order_by_expression: Option<OrderByExpressionName>,
+
+    /// The AggregateExpression to use for ordering by aggregates over this relationship.
+    /// This is required to order by an array relationship, and is not allowed
+    /// for object relationships.
+    pub aggregate_expression: Option<AggregateExpressionName>,
 }
 
 #[derive(Serialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
@@ -1,4 +1,3 @@
-use indexmap::IndexSet;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
@@ -6,7 +5,7 @@ use serde_json::Value as JsonValue;
 use crate::{
     arguments::ArgumentName,
     commands::CommandName,
-    impl_JsonSchema_with_OpenDd_for,
+    impl_JsonSchema_with_OpenDd_for, impl_OpenDd_default_for,
     models::ModelName,
     relationships::RelationshipName,
     session_variables::SessionVariableName,
@@ -36,6 +35,55 @@ impl Role {
     }
 }
 
+#[derive(Serialize, Clone, Debug, Eq, PartialEq, opendds_derive::OpenDd)]
+#[serde(tag = "version", content = "definition")]
+#[serde(rename_all = "camelCase")]
+#[opendd(
+    as_versioned_with_definition,
+    json_schema(title = "RoleDefinition", example = "RoleDefinition::example")
+)]
+/// Definition of a role. A role inherits the type, model and command permissions
+/// of its parent roles.
+pub enum RoleDefinition {
+    V1(RoleDefinitionV1),
+}
+
+impl RoleDefinition {
+    fn example() -> serde_json::Value {
+        serde_json::json!(
+            {
+                "kind": "Role",
+                "version": "v1",
+                "definition": {
+                    "name": "manager",
+                    "parentRoles": ["employee"]
+                }
+            }
+        )
+    }
+
+    pub fn upgrade(self) -> RoleDefinitionV1 {
+        match self {
+            RoleDefinition::V1(v1) => v1,
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug, Eq, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(title = "RoleDefinitionV1"))]
+/// Definition of a role. A role inherits the type, model and command permissions
+/// of its parent roles.
+pub struct RoleDefinitionV1 {
+    /// The name of the role.
+    pub name: Role,
+    /// The roles whose permissions are inherited by this role. The effective
+    /// permissions of the role are the union of its own permissions and the
+    /// effective permissions of its parent roles.
+    #[opendd(default, json_schema(default_exp = "serde_json::json!([])"))]
+    pub parent_roles: Vec<Role>,
+}
+
 #[derive(Serialize, Clone, Debug, Eq, PartialEq, opendds_derive::OpenDd)]
 #[serde(rename_all = "camelCase")]
 /// Preset value for an argument
@@ -157,10 +205,85 @@ impl TypePermission {
 #[opendd(json_schema(title = "TypeOutputPermission"))]
 /// Permissions for a type for a particular role when used in an output context.
 pub struct TypeOutputPermission {
-    /// Fields of the type that are accessible for a role
-    pub allowed_fields: IndexSet<FieldName>,
-    // TODO: Presets for field arguments
-    // pub field_argument_presets: HashMap<FieldName, Vec<ParameterPreset>>,
+    /// Fields of the type that are accessible for a role. A field can be made
+    /// accessible only on the objects that match a condition.
+    pub allowed_fields: Vec<AllowedField>,
+    /// Preset values for arguments of fields of the type. Preset arguments are
+    /// removed from the GraphQL schema of the role.
+    #[opendd(default, json_schema(default_exp = "serde_json::json!([])"))]
+    pub field_argument_presets: Vec<FieldArgumentPreset>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(untagged)]
+#[schemars(title = "AllowedField")]
+/// A field of a type that is accessible for a role.
+pub enum AllowedField {
+    /// The field is accessible on all objects.
+    Field(FieldName),
+    /// The field is accessible only on the objects that match a condition.
+    Conditional(ConditionalAllowedField),
+}
+
+impl_OpenDd_default_for!(AllowedField);
+
+impl AllowedField {
+    pub fn field_name(&self) -> &FieldName {
+        match self {
+            AllowedField::Field(field_name) => field_name,
+            AllowedField::Conditional(conditional) => &conditional.field,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+#[schemars(title = "ConditionalAllowedField")]
+#[schemars(example = "ConditionalAllowedField::example")]
+/// A field that is accessible only on the objects that match a condition. On other
+/// objects, the value of the field is null.
+pub struct ConditionalAllowedField {
+    /// The name of the field.
+    pub field: FieldName,
+    /// The condition that an object must match for the field to be accessible. The
+    /// condition can only compare fields of the type that are accessible on all objects,
+    /// with the `_eq` and `_neq` operators.
+    pub condition: ModelPredicate,
+}
+
+impl ConditionalAllowedField {
+    fn example() -> Self {
+        serde_json::from_str(
+            r#"
+            {
+                "field": "salary",
+                "condition": {
+                    "fieldComparison": {
+                        "field": "employee_id",
+                        "operator": "_eq",
+                        "value": {
+                            "sessionVariable": "x-hasura-user-id"
+                        }
+                    }
+                }
+            }
+        "#,
+        )
+        .unwrap()
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, Eq, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+/// Preset value for an argument of a field
+pub struct FieldArgumentPreset {
+    /// Field name for preset
+    pub field: FieldName,
+    /// Argument name for preset
+    pub argument: ArgumentName,
+    /// Value for preset
+    pub value: ValueExpression,
 }
 
 #[derive(Deserialize, Serialize, Clone, Debug, Eq, PartialEq, opendds_derive::OpenDd)]
@@ -346,6 +469,14 @@ pub struct SelectPermission {
     /// Whether the role is allowed to subscribe to the root fields of this model.
     #[opendd(default, json_schema(default_exp = "serde_json::json!(false)"))]
     pub allow_subscriptions: bool,
+    /// The maximum number of rows this role can select from the model.
+    /// Caps any limit requested by the role, and applies when no limit is requested.
+    /// Null implies no maximum.
+    pub limit: Option<u32>,
+    /// Whether the role is allowed to use the aggregate root fields and aggregate
+    /// relationship fields of this model.
+    #[opendd(default = "true")]
+    pub allow_aggregations: bool,
 }
 
 // We use this instead of an Option, so that we can make the filter field in
@@ -411,6 +542,19 @@ pub struct CommandPermission {
     /// Preset values for arguments for this role
     #[opendd(default, json_schema(default_exp = "serde_json::json!([])"))]
     pub argument_presets: Vec<ArgumentPreset>,
+    /// A predicate over the arguments of the command that must hold for the
+    /// command to be executed by this role. It is checked after argument
+    /// presets have been applied. Null implies no restriction.
+    pub argument_predicate: Option<ArgumentPredicate>,
+    /// A predicate over the objects returned by the command, with the same
+    /// semantics as the filter of a model select permission. It can be used
+    /// for commands that return the object type of a model without arguments
+    /// that has a select unique (or an array of that type): each returned
+    /// object is looked up in the model by its unique identifier with the
+    /// predicate applied. If any returned object is not found, the command
+    /// results in a permission error. For commands backed by procedures, the
+    /// check happens after the procedure has run. Null implies no restriction.
+    pub output_filter: Option<ModelPredicate>,
 }
 
 impl CommandPermission {
@@ -424,9 +568,83 @@ impl CommandPermission {
                     "value": {
                         "session_variable": "x-hasura-user_id"
                     }
-                }]
+                }],
+                "argumentPredicate": {
+                    "argumentComparison": {
+                        "argument": "id",
+                        "operator": "_eq",
+                        "value": {
+                            "sessionVariable": "x-hasura-user-id"
+                        }
+                    }
+                }
+            }
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+#[schemars(title = "ArgumentComparisonPredicate")]
+/// Argument comparison predicate checks the value of a command argument.
+pub struct ArgumentComparisonPredicate {
+    /// The name of the argument of the command to compare.
+    pub argument: ArgumentName,
+    /// The name of the operator to use for comparison. Only `_eq` and `_neq` are supported.
+    pub operator: OperatorName,
+    /// The value expression to compare against.
+    pub value: ValueExpression,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+#[schemars(title = "ArgumentIsNullPredicate")]
+/// Predicate to check if the given command argument is null or not provided.
+pub struct ArgumentIsNullPredicate {
+    /// The name of the argument that should be checked for a null value.
+    pub argument: ArgumentName,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[schemars(title = "ArgumentPredicate")]
+#[schemars(example = "ArgumentPredicate::argument_comparison_example")]
+#[serde(deny_unknown_fields)]
+/// A predicate over the arguments of a command, checked before the command is executed.
+pub enum ArgumentPredicate {
+    /// Checks the value of an argument.
+    ArgumentComparison(ArgumentComparisonPredicate),
+    /// Checks whether an argument is null or not provided.
+    ArgumentIsNull(ArgumentIsNullPredicate),
+    #[schemars(title = "ArgumentPredicateAnd")]
+    /// Evaluates to true if all sub-predicates evaluate to true.
+    And(Vec<ArgumentPredicate>),
+    #[schemars(title = "ArgumentPredicateOr")]
+    /// Evaluates to true if any of the sub-predicates evaluate to true.
+    Or(Vec<ArgumentPredicate>),
+    #[schemars(title = "ArgumentPredicateNot")]
+    /// Evaluates to true if the sub-predicate evaluates to false.
+    Not(Box<ArgumentPredicate>),
+}
+
+impl ArgumentPredicate {
+    fn argument_comparison_example() -> Self {
+        serde_json::from_str(
+            r#"
+            {
+                "argumentComparison": {
+                  "argument": "id",
+                  "operator": "_eq",
+                  "value": {
+                    "sessionVariable": "x-hasura-user-id"
+                  }
+                }
             }
+        "#,
         )
+        .unwrap()
     }
 }
 
@@ -406,6 +406,10 @@ impl OrderByElement {
             match self.direction {
                 OrderByDirection::Asc => "asc",
                 OrderByDirection::Desc => "desc",
+                OrderByDirection::AscNullsFirst => "asc nulls first",
+                OrderByDirection::AscNullsLast => "asc nulls last",
+                OrderByDirection::DescNullsFirst => "desc nulls first",
+                OrderByDirection::DescNullsLast => "desc nulls last",
             }
         )
     }
@@ -200,6 +200,9 @@ impl RelationshipMapping {
 pub struct RelationshipGraphQlDefinition {
     /// The field name to use for the field that represents an aggregate over the relationship
     pub aggregate_field_name: Option<FieldName>,
+    /// The field name to use for the field that represents a Relay connection over the
+    /// relationship. Only valid for array relationships to models with a select connection.
+    pub connection_field_name: Option<FieldName>,
 }
 
 #[derive(Serialize, Clone, Debug, PartialEq, Eq, opendds_derive::OpenDd)]
@@ -515,6 +515,293 @@ pub struct ScalarTypeV1 {
     pub description: Option<String>,
 }
 
+/// GraphQL configuration of an Open DD enum type
+#[derive(Serialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(title = "EnumTypeGraphQLConfiguration"))]
+pub struct EnumTypeGraphQLConfiguration {
+    /// The name of the GraphQl enum type to use for this enum type.
+    pub type_name: GraphQlTypeName,
+}
+
+/// Definition of a user-defined enum type, whose values are a fixed set of strings.
+#[derive(Serialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(tag = "version", content = "definition")]
+#[serde(rename_all = "camelCase")]
+#[opendd(
+    as_versioned_with_definition,
+    json_schema(title = "EnumType", example = "EnumType::example")
+)]
+pub enum EnumType {
+    V1(EnumTypeV1),
+}
+
+impl EnumType {
+    fn example() -> serde_json::Value {
+        serde_json::json!(
+            {
+                "kind": "EnumType",
+                "version": "v1",
+                "definition": {
+                    "name": "OrderStatus",
+                    "values": [
+                        {
+                            "value": "PENDING",
+                            "description": "The order has not been shipped yet"
+                        },
+                        {
+                            "value": "SHIPPED"
+                        },
+                        {
+                            "value": "LOST",
+                            "deprecated": {
+                                "reason": "Orders are no longer marked as lost"
+                            }
+                        }
+                    ],
+                    "graphql": {
+                        "typeName": "OrderStatus"
+                    },
+                    "description": "The status of an order"
+                }
+            }
+        )
+    }
+
+    pub fn upgrade(self) -> EnumTypeV1 {
+        match self {
+            EnumType::V1(v1) => v1,
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(title = "EnumTypeV1"))]
+/// Definition of a user-defined enum type, whose values are a fixed set of strings.
+pub struct EnumTypeV1 {
+    /// The name to give this enum type, used to refer to it elsewhere in the metadata.
+    /// Must be unique across all types defined in this subgraph.
+    pub name: CustomTypeName,
+    /// The values of this enum type.
+    pub values: Vec<EnumValueDefinition>,
+    /// Configuration for how this enum type should appear in the GraphQL schema.
+    pub graphql: Option<EnumTypeGraphQLConfiguration>,
+    /// The description of this enum type.
+    /// Gets added to the description of the enum's definition in the graphql schema.
+    pub description: Option<String>,
+}
+
+/// The definition of a value of an enum type.
+#[derive(Serialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(title = "EnumValueDefinition"))]
+pub struct EnumValueDefinition {
+    /// The value, as it is stored by data connectors and returned in responses.
+    pub value: String,
+    /// The description of this value.
+    /// Gets added to the description of the enum value in the graphql schema.
+    pub description: Option<String>,
+    /// Whether this value is deprecated.
+    /// If set, the deprecation status is added to the enum value in the graphql schema.
+    pub deprecated: Option<Deprecated>,
+}
+
+/// GraphQL configuration of an Open DD union type
+#[derive(Serialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(title = "UnionTypeGraphQLConfiguration"))]
+pub struct UnionTypeGraphQLConfiguration {
+    /// The name of the GraphQl union type to use for this union type.
+    pub type_name: GraphQlTypeName,
+}
+
+/// Definition of a user-defined tagged union of object types. The concrete object type
+/// of a value is selected by the value of a discriminator field in the data connector's response.
+#[derive(Serialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(tag = "version", content = "definition")]
+#[serde(rename_all = "camelCase")]
+#[opendd(
+    as_versioned_with_definition,
+    json_schema(title = "UnionType", example = "UnionType::example")
+)]
+pub enum UnionType {
+    V1(UnionTypeV1),
+}
+
+impl UnionType {
+    fn example() -> serde_json::Value {
+        serde_json::json!(
+            {
+                "kind": "UnionType",
+                "version": "v1",
+                "definition": {
+                    "name": "SearchResult",
+                    "discriminatorField": "kind",
+                    "members": [
+                        {
+                            "objectType": "Article",
+                            "discriminatorValue": "article"
+                        },
+                        {
+                            "objectType": "Author",
+                            "discriminatorValue": "author"
+                        }
+                    ],
+                    "graphql": {
+                        "typeName": "SearchResult"
+                    },
+                    "description": "A result of a search"
+                }
+            }
+        )
+    }
+
+    pub fn upgrade(self) -> UnionTypeV1 {
+        match self {
+            UnionType::V1(v1) => v1,
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(title = "UnionTypeV1"))]
+/// Definition of a user-defined tagged union of object types.
+pub struct UnionTypeV1 {
+    /// The name to give this union type, used to refer to it elsewhere in the metadata.
+    /// Must be unique across all types defined in this subgraph.
+    pub name: CustomTypeName,
+    /// The name of the field in the data connector's response whose value selects the
+    /// object type of each value of this union type.
+    pub discriminator_field: FieldName,
+    /// The object types that are members of this union type.
+    pub members: Vec<DiscriminatedObjectType>,
+    /// Configuration for how this union type should appear in the GraphQL schema.
+    pub graphql: Option<UnionTypeGraphQLConfiguration>,
+    /// The description of this union type.
+    /// Gets added to the description of the union's definition in the graphql schema.
+    pub description: Option<String>,
+}
+
+/// GraphQL configuration of an Open DD interface type
+#[derive(Serialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(title = "InterfaceTypeGraphQLConfiguration"))]
+pub struct InterfaceTypeGraphQLConfiguration {
+    /// The name of the GraphQl interface type to use for this interface type.
+    pub type_name: GraphQlTypeName,
+}
+
+/// Definition of a user-defined interface type, a set of fields shared by several object types.
+/// The concrete object type of a value is selected by the value of a discriminator field in the
+/// data connector's response.
+#[derive(Serialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(tag = "version", content = "definition")]
+#[serde(rename_all = "camelCase")]
+#[opendd(
+    as_versioned_with_definition,
+    json_schema(title = "InterfaceType", example = "InterfaceType::example")
+)]
+pub enum InterfaceType {
+    V1(InterfaceTypeV1),
+}
+
+impl InterfaceType {
+    fn example() -> serde_json::Value {
+        serde_json::json!(
+            {
+                "kind": "InterfaceType",
+                "version": "v1",
+                "definition": {
+                    "name": "Node",
+                    "fields": [
+                        {
+                            "name": "id",
+                            "type": "Int!"
+                        }
+                    ],
+                    "discriminatorField": "kind",
+                    "implementations": [
+                        {
+                            "objectType": "Article",
+                            "discriminatorValue": "article"
+                        },
+                        {
+                            "objectType": "Author",
+                            "discriminatorValue": "author"
+                        }
+                    ],
+                    "graphql": {
+                        "typeName": "Node"
+                    },
+                    "description": "An object with an id"
+                }
+            }
+        )
+    }
+
+    pub fn upgrade(self) -> InterfaceTypeV1 {
+        match self {
+            InterfaceType::V1(v1) => v1,
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(title = "InterfaceTypeV1"))]
+/// Definition of a user-defined interface type.
+pub struct InterfaceTypeV1 {
+    /// The name to give this interface type, used to refer to it elsewhere in the metadata.
+    /// Must be unique across all types defined in this subgraph.
+    pub name: CustomTypeName,
+    /// The fields of this interface type. Every implementation must define each of these
+    /// fields with the same type.
+    pub fields: Vec<InterfaceFieldDefinition>,
+    /// The name of the field in the data connector's response whose value selects the
+    /// object type of each value of this interface type.
+    pub discriminator_field: FieldName,
+    /// The object types that implement this interface type.
+    pub implementations: Vec<DiscriminatedObjectType>,
+    /// Configuration for how this interface type should appear in the GraphQL schema.
+    pub graphql: Option<InterfaceTypeGraphQLConfiguration>,
+    /// The description of this interface type.
+    /// Gets added to the description of the interface's definition in the graphql schema.
+    pub description: Option<String>,
+}
+
+/// The definition of a field of an interface type.
+#[derive(Serialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(title = "InterfaceFieldDefinition"))]
+pub struct InterfaceFieldDefinition {
+    /// The name of the field.
+    pub name: FieldName,
+    /// The type of this field.
+    #[serde(rename = "type")]
+    #[opendd(rename = "type")]
+    pub field_type: TypeReference,
+    /// The description of this field.
+    /// Gets added to the description of the field's definition in the graphql schema.
+    pub description: Option<String>,
+    /// Whether this field is deprecated.
+    /// If set, the deprecation status is added to the field's graphql schema.
+    pub deprecated: Option<Deprecated>,
+}
+
+/// An object type that is a member of a union type or an implementation of an interface type,
+/// along with the discriminator value that selects it.
+#[derive(Serialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
+#[serde(rename_all = "camelCase")]
+#[opendd(json_schema(title = "DiscriminatedObjectType"))]
+pub struct DiscriminatedObjectType {
+    /// The name of the object type.
+    pub object_type: CustomTypeName,
+    /// The value of the discriminator field that selects this object type.
+    pub discriminator_value: String,
+}
+
 /// GraphQL configuration of a data connector scalar
 #[derive(Serialize, Clone, Debug, PartialEq, opendds_derive::OpenDd)]
 #[serde(rename_all = "camelCase")]
@@ -7,9 +7,14 @@ mod mutation;
 mod query;
 mod relationships;
 mod remote_joins;
+use crate::NdcFieldAlias;
+use open_dds::data_connector::{DataConnectorColumnName, DataConnectorOperatorName};
 use std::sync::Arc;
 
-pub use aggregates::{AggregateFieldSelection, AggregateSelectionSet, Dimension, Grouping};
+pub use aggregates::{
+    AggregateFieldSelection, AggregateSelectionSet, Dimension, GroupOrderByElement,
+    GroupOrderByTarget, Grouping,
+};
 pub use arguments::{Argument, MutationArgument};
 pub use field::{Field, NestedArray, NestedField, NestedObject};
 pub use filter::ResolvedFilterExpression;
@@ -20,8 +25,9 @@ pub use query::{
 };
 pub use relationships::{Relationship, RelationshipArgument};
 pub use remote_joins::{
-    JoinLocations, JoinNode, Location, LocationKind, RemoteJoin, RemoteJoinArgument,
-    RemoteJoinType, SourceFieldAlias, TargetField,
+    make_model_argument_variable_name, make_model_field_variable_name, JoinLocations, JoinNode,
+    Location, LocationKind, RemoteJoin, RemoteJoinArgument, RemoteJoinType, SourceFieldAlias,
+    SourceFieldPath, TargetField, COMMAND_OUTPUT_FILTER_ALIAS,
 };
 
 // these versions of the types are equivalent to the old "Resolved" versions
@@ -32,6 +38,22 @@ pub struct NDCQueryExecution {
     pub execution_span_attribute: &'static str,
     pub field_span_attribute: String,
     pub process_response_as: ProcessResponseAs,
+    /// Only set for cursor paginated queries, whose rows are trimmed to the requested page
+    pub cursor_pagination: Option<CursorPagination>,
+}
+
+/// The cursor pagination of a query. The query fetches one row more than the page size, to
+/// tell whether the page is followed by more rows, along with the ordering fields of each row,
+/// to build the cursors of the page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorPagination {
+    /// The number of rows in the page, if limited
+    pub page_size: Option<u32>,
+    /// Whether the page ends before the cursor rather than starting after it. The rows are
+    /// then fetched in the reverse order, and are reversed back after being fetched.
+    pub backwards: bool,
+    /// The aliases the ordering fields are fetched with, in the order of the ordering
+    pub cursor_field_aliases: Vec<NdcFieldAlias>,
 }
 
 #[derive(Debug)]
@@ -51,6 +73,22 @@ pub struct NDCSubscriptionExecution {
     pub execution_span_attribute: &'static str,
     pub field_span_attribute: String,
     pub process_response_as: ProcessResponseAs,
+    /// Only set for streaming subscriptions, whose query fetches the batch of rows after the cursor
+    pub stream_cursor: Option<StreamCursor>,
+}
+
+/// The cursor of a streaming subscription. The rows are fetched in batches, in the ascending
+/// order of the cursor column, and the cursor is advanced to the last row of each batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamCursor {
+    /// The column the rows are streamed by
+    pub column: DataConnectorColumnName,
+    /// The operator used to fetch the rows after the cursor
+    pub greater_than_operator: DataConnectorOperatorName,
+    /// The alias the cursor column is fetched with, to read the cursor from the last row
+    pub field_alias: NdcFieldAlias,
+    /// The value after which to start streaming
+    pub initial_value: serde_json::Value,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -60,6 +98,13 @@ pub struct ExecutionTree {
     pub remote_join_executions: remote_joins::JoinLocations,
 }
 
+#[derive(Debug, Clone)]
+pub struct MutationExecutionTree {
+    pub remote_predicates: PredicateQueryTrees,
+    pub mutation_execution_plan: mutation::MutationExecutionPlan,
+    pub remote_join_executions: remote_joins::JoinLocations,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum CommandReturnKind {
     Array,
@@ -80,8 +125,25 @@ pub enum ProcessResponseAs {
         return_kind: CommandReturnKind,
         // how to process a command response
         response_config: Option<Arc<metadata_resolve::data_connectors::CommandsResponseConfig>>,
+        // whether each object returned by the command must be found by the join of the
+        // output filter of the command permission
+        check_output_filter: bool,
+        // how to decide the member object type of each returned value, when the
+        // output type of the command is a union or interface type
+        type_discrimination: Option<metadata_resolve::TypeDiscrimination>,
     },
     Aggregates,
+    // the rows are the nodes of a page of a Relay connection
+    Connection {
+        cursor_pagination: CursorPagination,
+    },
+    // the groups of the rows, whose dimension values are returned in the order of these aliases
+    Groups {
+        dimensions: Vec<NdcFieldAlias>,
+    },
+    // the result of a generated model mutation, with the number of affected rows and the
+    // affected rows themselves
+    ModelMutationResponse,
 }
 
 impl ProcessResponseAs {
@@ -90,7 +152,10 @@ impl ProcessResponseAs {
             ProcessResponseAs::Object { is_nullable }
             | ProcessResponseAs::Array { is_nullable }
             | ProcessResponseAs::CommandResponse { is_nullable, .. } => *is_nullable,
-            ProcessResponseAs::Aggregates { .. } => false,
+            ProcessResponseAs::Aggregates { .. }
+            | ProcessResponseAs::Connection { .. }
+            | ProcessResponseAs::Groups { .. }
+            | ProcessResponseAs::ModelMutationResponse => false,
         }
     }
 }
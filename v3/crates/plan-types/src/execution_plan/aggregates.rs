@@ -1,4 +1,4 @@
-use crate::NdcFieldAlias;
+use crate::{NdcFieldAlias, OrderByDirection};
 use indexmap::IndexMap;
 use nonempty::NonEmpty;
 use open_dds::{
@@ -14,6 +14,23 @@ pub struct Grouping {
     pub dimensions: IndexMap<NdcFieldAlias, Dimension>,
     pub limit: Option<u32>,
     pub offset: Option<u32>,
+    /// The ordering of the groups. The groups are returned in an unspecified order if it is
+    /// empty.
+    pub order_by: Vec<GroupOrderByElement>,
+}
+
+#[derive(Debug, Serialize, PartialEq, Clone, Eq)]
+pub struct GroupOrderByElement {
+    pub order_direction: OrderByDirection,
+    pub target: GroupOrderByTarget,
+}
+
+#[derive(Debug, Serialize, PartialEq, Clone, Eq)]
+pub enum GroupOrderByTarget {
+    /// The value of a dimension of the group, by its position in the dimensions
+    Dimension { index: usize },
+    /// The value of an aggregate over the rows of the group
+    Aggregate { aggregate: AggregateFieldSelection },
 }
 
 #[derive(Debug, Serialize, PartialEq, Clone, Eq, Hash)]
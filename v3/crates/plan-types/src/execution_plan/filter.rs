@@ -1,4 +1,7 @@
-use crate::{LocalFieldComparison, NdcRelationshipName, RemotePredicateKey};
+use crate::{
+    AggregateComparison, AggregateFieldSelection, LocalFieldComparison, NdcRelationshipName,
+    RemotePredicateKey,
+};
 use open_dds::data_connector::DataConnectorColumnName;
 
 /// Filter expression plan to be resolved
@@ -29,6 +32,13 @@ pub enum ResolvedFilterExpression {
         relationship: NdcRelationshipName,
         predicate: Box<ResolvedFilterExpression>,
     },
+    LocalRelationshipAggregateComparison {
+        field_path: Vec<DataConnectorColumnName>,
+        relationship: NdcRelationshipName,
+        filter_predicate: Option<Box<ResolvedFilterExpression>>,
+        aggregate: AggregateFieldSelection,
+        comparison: AggregateComparison,
+    },
     RemoteRelationshipComparison {
         remote_predicate_id: RemotePredicateKey,
     },
@@ -5,8 +5,10 @@ use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Relationship {
-    /// A mapping between columns on the source collection to columns on the target collection
-    pub column_mapping: BTreeMap<DataConnectorColumnName, DataConnectorColumnName>,
+    /// A mapping between columns on the source collection to columns on the target collection.
+    /// The target is a path, which has more than one element when it is a field of a nested
+    /// object.
+    pub column_mapping: BTreeMap<DataConnectorColumnName, Vec<DataConnectorColumnName>>,
     pub relationship_type: RelationshipType,
     /// The name of a collection
     pub target_collection: CollectionName,
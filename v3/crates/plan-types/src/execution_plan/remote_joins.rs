@@ -4,6 +4,7 @@ use super::{query, ProcessResponseAs};
 use indexmap::IndexMap;
 use json_ext::ValueExt;
 use open_dds::arguments::ArgumentName;
+use open_dds::data_connector::DataConnectorColumnName;
 use open_dds::types::FieldName;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
@@ -115,36 +116,68 @@ pub struct RemoteJoin {
     pub target_ndc_execution: query::QueryExecutionPlan,
     /// Mapping of the fields in source to fields in target.
     /// The HashMap has the following info -
-    ///   - key: is the field name in the source
+    ///   - key: is the path of the field in the source
     ///   - value->first item: is the alias we create for the
     ///     source field. If the user did not request the join field in the
     ///     selection set, we include the join mapping field and call it a phantom
     ///     field.
     ///   - value->second item: is the target NDC field. This could be a model
     ///     field or an argument name.
-    pub join_mapping: HashMap<SourceFieldName, (SourceFieldAlias, TargetField)>,
+    pub join_mapping: HashMap<SourceFieldPath, (SourceFieldAlias, TargetField)>,
     /// Represents how to process the join response.
     pub process_response_as: ProcessResponseAs,
     /// Represents the type of the remote join
     pub remote_join_type: RemoteJoinType,
 }
 
-/// Name of the source field used in the join mapping
-pub type SourceFieldName = FieldName;
+/// Path of the source field used in the join mapping. This is a field of the
+/// source object, followed by the fields of any nested objects leading to the
+/// field used in the join.
+pub type SourceFieldPath = Vec<FieldName>;
 
-/// Alias of the source field used in the join mapping. This is basically a NDC
-/// field alias (which in the NDC IR is `String`). Change this when modifying
-/// the IR to have a newtype Alias.
+/// Alias of the source field used in the join mapping.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SourceFieldAlias(pub String);
+pub struct SourceFieldAlias {
+    /// The NDC field alias (which in the NDC IR is `String`) of the top-level
+    /// source field. Change this when modifying the IR to have a newtype Alias.
+    pub alias: String,
+    /// Path to the join value within the nested objects of the aliased field,
+    /// when joining on a field of a nested object
+    pub nested_path: Vec<DataConnectorColumnName>,
+}
 
 /// Target field used in the join mapping
 #[derive(Debug, Clone, PartialEq)]
 pub enum TargetField {
     ModelField((FieldName, metadata_resolve::NdcColumnForComparison)),
+    ModelArgument(ArgumentName),
     CommandField(ArgumentName),
 }
 
+/// The alias under which the objects returned by a command are joined with the objects
+/// selected from the model of their type, with the output filter of the command permission
+/// applied. An object satisfies the output filter when a row is joined for it.
+pub const COMMAND_OUTPUT_FILTER_ALIAS: &str = "__hasura_output_filter";
+
+/// Name of the variable in the target query that carries the join value for a
+/// model field. Nested target fields are told apart by their field path.
+pub fn make_model_field_variable_name(
+    target_column: &metadata_resolve::NdcColumnForComparison,
+) -> crate::VariableName {
+    let mut variable_name = format!("${}", target_column.column);
+    for nested_column in &target_column.field_path {
+        variable_name.push('.');
+        variable_name.push_str(nested_column.as_str());
+    }
+    crate::VariableName(variable_name)
+}
+
+/// Name of the variable in the target query that carries the join value for a
+/// model argument. The prefix keeps it apart from the variables for model fields.
+pub fn make_model_argument_variable_name(argument_name: &ArgumentName) -> crate::VariableName {
+    crate::VariableName(format!("$argument:{argument_name}"))
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum RemoteJoinType {
     ToModel,
@@ -5,7 +5,9 @@ use open_dds::relationships::RelationshipName;
 use serde::Serialize;
 use std::sync::Arc;
 
-use crate::{LocalModelRelationshipInfo, NdcRelationshipName, VariableName};
+use crate::{
+    AggregateFieldSelection, LocalModelRelationshipInfo, NdcRelationshipName, VariableName,
+};
 
 pub const EXPRESSION_SCALAR_VALUE_VIRTUAL_COLUMN_NAME: &str = "__value";
 
@@ -47,6 +49,18 @@ pub enum Expression<'s> {
         predicate: Box<Expression<'s>>,
         info: LocalModelRelationshipInfo<'s>,
     },
+    /// Expression that compares the result of an aggregate over the rows of an array
+    /// relationship, and that can be pushed down in the same query.
+    /// This requires the `aggregates.filter_by` NDC capability.
+    RelationshipLocalAggregateComparison {
+        field_path: Vec<DataConnectorColumnName>,
+        relationship: NdcRelationshipName,
+        /// Filters the related rows before they are aggregated
+        filter_predicate: Option<Box<Expression<'s>>>,
+        aggregate: AggregateFieldSelection,
+        comparison: AggregateComparison,
+        info: LocalModelRelationshipInfo<'s>,
+    },
     /// Expression that uses a relationship, and that requires a separate
     /// execution to evaluate.
     ///
@@ -172,6 +186,18 @@ pub enum ComparisonTarget {
     },
 }
 
+/// Represent a comparison against the result of an aggregate
+#[derive(Debug, Serialize, Clone, PartialEq, Eq, Hash)]
+pub enum AggregateComparison {
+    /// A comparison with just the aggregate without a target value
+    UnaryComparison { operator: UnaryComparisonOperator },
+    /// A comparison between the aggregate and a value
+    BinaryComparison {
+        operator: DataConnectorOperatorName,
+        value: ComparisonValue,
+    },
+}
+
 #[derive(Debug, Serialize, Clone, PartialEq, Eq, Hash)]
 pub enum ComparisonValue {
     Scalar { value: serde_json::Value },
@@ -9,18 +9,20 @@ mod usage_counts;
 mod variable_name;
 
 pub use execution_plan::{
-    AggregateFieldSelection, AggregateFieldsSelection, AggregateSelectionSet, Argument,
-    CommandReturnKind, Dimension, ExecutionTree, Field, FieldsSelection, Grouping, JoinLocations,
-    JoinNode, Location, LocationKind, MutationArgument, MutationExecutionPlan,
-    NDCMutationExecution, NDCQueryExecution, NDCSubscriptionExecution, NestedArray, NestedField,
-    NestedObject, PredicateQueryTree, PredicateQueryTrees, ProcessResponseAs, QueryExecutionPlan,
-    QueryNodeNew, Relationship, RelationshipArgument, RemoteJoin, RemoteJoinArgument,
-    RemoteJoinType, RemotePredicateKey, ResolvedFilterExpression, SourceFieldAlias, TargetField,
-    UniqueNumber,
+    make_model_argument_variable_name, make_model_field_variable_name, AggregateFieldSelection,
+    AggregateFieldsSelection, AggregateSelectionSet, Argument, CommandReturnKind, CursorPagination,
+    Dimension, ExecutionTree, Field, FieldsSelection, GroupOrderByElement, GroupOrderByTarget,
+    Grouping, JoinLocations, JoinNode, Location, LocationKind, MutationArgument,
+    MutationExecutionPlan, MutationExecutionTree, NDCMutationExecution, NDCQueryExecution,
+    NDCSubscriptionExecution, NestedArray, NestedField, NestedObject, PredicateQueryTree,
+    PredicateQueryTrees, ProcessResponseAs, QueryExecutionPlan, QueryNodeNew, Relationship,
+    RelationshipArgument, RemoteJoin, RemoteJoinArgument, RemoteJoinType, RemotePredicateKey,
+    ResolvedFilterExpression, SourceFieldAlias, SourceFieldPath, StreamCursor, TargetField,
+    UniqueNumber, COMMAND_OUTPUT_FILTER_ALIAS,
 };
 pub use expression::{
-    ComparisonTarget, ComparisonValue, Expression, LocalFieldComparison, RelationshipColumnMapping,
-    SourceNdcColumn, EXPRESSION_SCALAR_VALUE_VIRTUAL_COLUMN_NAME,
+    AggregateComparison, ComparisonTarget, ComparisonValue, Expression, LocalFieldComparison,
+    RelationshipColumnMapping, SourceNdcColumn, EXPRESSION_SCALAR_VALUE_VIRTUAL_COLUMN_NAME,
 };
 pub use ndc_field_alias::NdcFieldAlias;
 pub use ndc_function_ir_value::FUNCTION_IR_VALUE_COLUMN_NAME;
@@ -2,11 +2,16 @@ use open_dds::data_connector::DataConnectorColumnName;
 use serde::Serialize;
 
 use super::relationships::RelationshipPathElement;
+use crate::AggregateFieldSelection;
 
 #[derive(Serialize, Clone, Debug, PartialEq, Eq)]
 pub enum OrderByDirection {
     Asc,
     Desc,
+    AscNullsFirst,
+    AscNullsLast,
+    DescNullsFirst,
+    DescNullsLast,
 }
 
 #[derive(Serialize, Debug, Clone, PartialEq, Eq)]
@@ -22,4 +27,10 @@ pub enum OrderByTarget<TExpression> {
         name: DataConnectorColumnName,
         field_path: Vec<DataConnectorColumnName>,
     },
+    /// An aggregate over the rows reached through the relationship path, which
+    /// must end in an array relationship
+    Aggregate {
+        relationship_path: Vec<RelationshipPathElement<TExpression>>,
+        aggregate: AggregateFieldSelection,
+    },
 }
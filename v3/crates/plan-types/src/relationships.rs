@@ -23,6 +23,7 @@ pub struct LocalModelRelationshipInfo<'s> {
     pub target_source: &'s metadata_resolve::ModelSource,
     pub target_type: &'s Qualified<CustomTypeName>,
     pub mappings: &'s Vec<metadata_resolve::RelationshipModelMapping>,
+    pub argument_mappings: &'s [metadata_resolve::RelationshipModelArgumentMapping],
 }
 
 #[derive(Serialize, Debug, Clone, PartialEq, Eq)]
@@ -1,24 +1,32 @@
-use crate::process_model_predicate;
+use crate::{count_model, process_model_predicate};
 
 use super::column::{to_resolved_column, ResolvedColumn};
+use super::query::{process_model_relationship_definition, to_ndc_aggregate};
 use super::types::PlanError;
 use hasura_authn_core::Session;
 use metadata_resolve::{DataConnectorLink, Qualified, TypeMapping};
 use open_dds::{
-    query::{BooleanExpression, ComparisonOperator},
-    types::CustomTypeName,
+    query::{
+        AggregationFunction, BooleanExpression, ComparisonOperator, RelationshipAggregateOperand,
+    },
+    relationships::{RelationshipName, RelationshipType},
+    types::{CustomTypeName, FieldName, OperatorName},
 };
 use plan_types::{ResolvedFilterExpression, UniqueNumber, UsagesCounts};
 use std::collections::BTreeMap;
 
 pub fn to_resolved_filter_expr(
     metadata: &metadata_resolve::Metadata,
+    session: &Session,
     type_mappings: &BTreeMap<Qualified<CustomTypeName>, TypeMapping>,
     type_name: &Qualified<CustomTypeName>,
     model_object_type: &metadata_resolve::ObjectTypeWithRelationships,
     boolean_expression_type: Option<&metadata_resolve::ResolvedObjectBooleanExpressionType>,
     expr: &BooleanExpression,
     data_connector: &DataConnectorLink,
+    collect_relationships: &mut BTreeMap<plan_types::NdcRelationshipName, plan_types::Relationship>,
+    unique_number: &mut UniqueNumber,
+    usage_counts: &mut UsagesCounts,
 ) -> Result<ResolvedFilterExpression, PlanError> {
     match expr {
         BooleanExpression::And(exprs) => Ok(ResolvedFilterExpression::mk_and(
@@ -27,12 +35,16 @@ pub fn to_resolved_filter_expr(
                 .map(|expr| {
                     to_resolved_filter_expr(
                         metadata,
+                        session,
                         type_mappings,
                         type_name,
                         model_object_type,
                         boolean_expression_type,
                         expr,
                         data_connector,
+                        collect_relationships,
+                        unique_number,
+                        usage_counts,
                     )
                 })
                 .collect::<Result<Vec<_>, PlanError>>()?,
@@ -43,12 +55,16 @@ pub fn to_resolved_filter_expr(
                 .map(|expr| {
                     to_resolved_filter_expr(
                         metadata,
+                        session,
                         type_mappings,
                         type_name,
                         model_object_type,
                         boolean_expression_type,
                         expr,
                         data_connector,
+                        collect_relationships,
+                        unique_number,
+                        usage_counts,
                     )
                 })
                 .collect::<Result<Vec<_>, PlanError>>()?,
@@ -56,12 +72,16 @@ pub fn to_resolved_filter_expr(
         BooleanExpression::Not(expr) => {
             Ok(ResolvedFilterExpression::mk_not(to_resolved_filter_expr(
                 metadata,
+                session,
                 type_mappings,
                 type_name,
                 model_object_type,
                 boolean_expression_type,
                 expr,
                 data_connector,
+                collect_relationships,
+                unique_number,
+                usage_counts,
             )?))
         }
         BooleanExpression::IsNull(open_dds::query::Operand::Field(field)) => {
@@ -284,12 +304,400 @@ pub fn to_resolved_filter_expr(
                 }
             }
         }
+        BooleanExpression::IsNull(open_dds::query::Operand::RelationshipAggregate(
+            relationship_aggregate,
+        )) => to_resolved_relationship_aggregate_comparison(
+            metadata,
+            session,
+            type_mappings,
+            type_name,
+            model_object_type,
+            boolean_expression_type,
+            relationship_aggregate,
+            &AggregateComparisonOperator::IsNull,
+            data_connector,
+            collect_relationships,
+            unique_number,
+            usage_counts,
+        ),
+        BooleanExpression::Comparison {
+            operand: open_dds::query::Operand::RelationshipAggregate(relationship_aggregate),
+            operator,
+            argument,
+        } => {
+            let ComparisonOperator::Custom(custom_operator) = operator else {
+                return Err(PlanError::Internal(format!(
+                    "only custom operators are supported in aggregate comparisons: {operator:?}"
+                )));
+            };
+            let value = match argument.as_ref() {
+                open_dds::query::Value::Literal(value) => plan_types::ComparisonValue::Scalar {
+                    value: value.clone(),
+                },
+                open_dds::query::Value::BooleanExpression(b) => {
+                    return Err(PlanError::Internal(format!(
+                        "boolean expressions in comparison values are not supported: {b:?}"
+                    )))
+                }
+            };
+            to_resolved_relationship_aggregate_comparison(
+                metadata,
+                session,
+                type_mappings,
+                type_name,
+                model_object_type,
+                boolean_expression_type,
+                relationship_aggregate,
+                &AggregateComparisonOperator::Custom {
+                    operator: custom_operator,
+                    value,
+                },
+                data_connector,
+                collect_relationships,
+                unique_number,
+                usage_counts,
+            )
+        }
         _ => Err(PlanError::Internal(format!(
             "unsupported boolean expression: {expr:?}"
         ))),
     }
 }
 
+enum AggregateComparisonOperator<'a> {
+    IsNull,
+    Custom {
+        operator: &'a OperatorName,
+        value: plan_types::ComparisonValue,
+    },
+}
+
+// Resolve a comparison against an aggregate over the rows of a local array relationship
+#[allow(clippy::too_many_arguments)]
+fn to_resolved_relationship_aggregate_comparison(
+    metadata: &metadata_resolve::Metadata,
+    session: &Session,
+    type_mappings: &BTreeMap<Qualified<CustomTypeName>, TypeMapping>,
+    type_name: &Qualified<CustomTypeName>,
+    model_object_type: &metadata_resolve::ObjectTypeWithRelationships,
+    boolean_expression_type: Option<&metadata_resolve::ResolvedObjectBooleanExpressionType>,
+    relationship_aggregate: &RelationshipAggregateOperand,
+    comparison_operator: &AggregateComparisonOperator,
+    data_connector: &DataConnectorLink,
+    collect_relationships: &mut BTreeMap<plan_types::NdcRelationshipName, plan_types::Relationship>,
+    unique_number: &mut UniqueNumber,
+    usage_counts: &mut UsagesCounts,
+) -> Result<ResolvedFilterExpression, PlanError> {
+    let RelationshipAggregateOperand { target, aggregate } = relationship_aggregate;
+    let relationship_name = &target.relationship_name;
+
+    let relationship = model_object_type
+        .relationship_fields
+        .get(relationship_name)
+        .ok_or_else(|| {
+            PlanError::Internal(format!(
+                "can't find relationship {relationship_name} in type: {type_name}"
+            ))
+        })?;
+
+    let metadata_resolve::RelationshipTarget::Model(model_relationship_target) =
+        &relationship.target
+    else {
+        return Err(PlanError::Internal(format!(
+            "only relationships to models can be used in aggregate comparisons: {relationship_name}"
+        )));
+    };
+    if model_relationship_target.relationship_type != RelationshipType::Array {
+        return Err(PlanError::Internal(format!(
+            "only array relationships can be used in aggregate comparisons: {relationship_name}"
+        )));
+    }
+
+    let target_model_name = &model_relationship_target.model_name;
+    let target_model = metadata.models.get(target_model_name).ok_or_else(|| {
+        PlanError::Internal(format!("model {target_model_name} not found in metadata"))
+    })?;
+    let target_model_source =
+        target_model.model.source.as_deref().ok_or_else(|| {
+            PlanError::Internal(format!("model {target_model_name} has no source"))
+        })?;
+    let target_type = &model_relationship_target.target_typename;
+    let target_object_type = metadata.object_types.get(target_type).ok_or_else(|| {
+        PlanError::Internal(format!(
+            "target object type {target_type} not found in metadata"
+        ))
+    })?;
+
+    // Aggregate comparisons are always pushed down to the data connector
+    super::query::field_selection::reject_remote_relationship(
+        relationship_name,
+        data_connector,
+        &target_model_source.data_connector,
+    )?;
+    let supports_filtering_by_aggregates = data_connector
+        .capabilities
+        .supports_aggregates
+        .as_ref()
+        .is_some_and(|aggregates| aggregates.supports_filtering_by_aggregates);
+    if !supports_filtering_by_aggregates {
+        return Err(PlanError::Internal(format!(
+            "data connector {} does not support filtering by aggregates",
+            data_connector.name
+        )));
+    }
+
+    count_model(target_model_name, usage_counts);
+
+    // The rows of the relationship are filtered by the permissions of the target model and the
+    // filter of the relationship target before they are aggregated
+    let permission_filter = resolve_model_permission_filter(
+        session,
+        target_model,
+        target_model_source,
+        collect_relationships,
+        unique_number,
+        usage_counts,
+    )?;
+    let relationship_filter = target
+        .filter
+        .as_ref()
+        .map(|filter| {
+            to_resolved_filter_expr(
+                metadata,
+                session,
+                &target_model_source.type_mappings,
+                target_type,
+                target_object_type,
+                target_model.filter_expression_type.as_ref(),
+                filter,
+                &target_model_source.data_connector,
+                collect_relationships,
+                unique_number,
+                usage_counts,
+            )
+        })
+        .transpose()?;
+    let filter_predicate = match (relationship_filter, permission_filter) {
+        (None, filter) | (filter, None) => filter,
+        (Some(filter), Some(permission_filter)) => Some(ResolvedFilterExpression::mk_and(vec![
+            filter,
+            permission_filter,
+        ])),
+    };
+
+    let local_model_relationship_info = plan_types::LocalModelRelationshipInfo {
+        relationship_name,
+        relationship_type: &model_relationship_target.relationship_type,
+        source_type: type_name,
+        source_data_connector: data_connector,
+        source_type_mappings: type_mappings,
+        target_source: target_model_source,
+        target_type,
+        mappings: &model_relationship_target.mappings,
+        // relationships with argument mappings can't be used in predicates
+        argument_mappings: &[],
+    };
+    let ndc_relationship_name = plan_types::NdcRelationshipName::new(type_name, relationship_name);
+    collect_relationships.insert(
+        ndc_relationship_name.clone(),
+        process_model_relationship_definition(&local_model_relationship_info)?,
+    );
+
+    let resolved_column = match aggregate.operand.as_ref() {
+        None => None,
+        Some(open_dds::query::Operand::Field(operand)) => Some(to_resolved_column(
+            metadata,
+            &target_model_source.type_mappings,
+            target_type,
+            target_object_type,
+            operand,
+        )?),
+        Some(_) => Err(PlanError::Internal("unsupported aggregate operand".into()))?,
+    };
+    let ndc_aggregate = to_ndc_aggregate(
+        metadata,
+        &target_model_name.subgraph,
+        &target_model_source.data_connector,
+        aggregate,
+        resolved_column,
+        relationship_name.as_str(),
+        target_model_source
+            .data_connector
+            .capabilities
+            .supported_ndc_version,
+    )?;
+
+    let comparison = match comparison_operator {
+        AggregateComparisonOperator::IsNull => plan_types::AggregateComparison::UnaryComparison {
+            operator: metadata_resolve::UnaryComparisonOperator::IsNull,
+        },
+        AggregateComparisonOperator::Custom { operator, value } => {
+            // Boolean expression type is required to resolve custom operators
+            let boolean_expression_type = boolean_expression_type.ok_or_else(|| {
+                PlanError::Internal("Custom operators require a boolean expression type".into())
+            })?;
+            let operator_mapping = operator_mapping_for_aggregate_comparison(
+                metadata,
+                boolean_expression_type,
+                relationship_name,
+                aggregate,
+                data_connector,
+            )?;
+            plan_types::AggregateComparison::BinaryComparison {
+                operator: operator_mapping.get(operator).clone(),
+                value: value.clone(),
+            }
+        }
+    };
+
+    Ok(
+        ResolvedFilterExpression::LocalRelationshipAggregateComparison {
+            field_path: vec![],
+            relationship: ndc_relationship_name,
+            filter_predicate: filter_predicate.map(Box::new),
+            aggregate: ndc_aggregate,
+            comparison,
+        },
+    )
+}
+
+// follow the aggregate boolean expressions until we get to the scalar boolean expression used
+// to compare against the result of the aggregate, and return its operators
+fn operator_mapping_for_aggregate_comparison(
+    metadata: &metadata_resolve::Metadata,
+    boolean_expression_type: &metadata_resolve::ResolvedObjectBooleanExpressionType,
+    relationship_name: &RelationshipName,
+    aggregate: &open_dds::query::Aggregate,
+    data_connector: &DataConnectorLink,
+) -> Result<metadata_resolve::OperatorMapping, PlanError> {
+    let boolean_expression_types = &metadata.boolean_expression_types;
+
+    let aggregate_boolean_expression_type_name = boolean_expression_type
+        .fields
+        .relationship_fields
+        .get(&FieldName::new(relationship_name.inner().clone()))
+        .and_then(|relationship| relationship.aggregate_boolean_expression_type.as_ref())
+        .ok_or_else(|| {
+            PlanError::Internal(format!(
+                "relationship {relationship_name} cannot be used in aggregate comparisons"
+            ))
+        })?;
+    let mut object_aggregate = boolean_expression_types
+        .object_aggregates
+        .get(aggregate_boolean_expression_type_name)
+        .ok_or_else(|| {
+            PlanError::Internal(format!(
+                "Could not find aggregate boolean expression {aggregate_boolean_expression_type_name}"
+            ))
+        })?;
+
+    // follow the fields of the aggregate operand through the aggregate boolean expressions
+    let mut operand = aggregate.operand.as_ref();
+    let mut scalar_aggregate = None;
+    while let Some(current_operand) = operand {
+        let open_dds::query::Operand::Field(field_operand) = current_operand else {
+            return Err(PlanError::Internal("unsupported aggregate operand".into()));
+        };
+        let field_name = &field_operand.target.field_name;
+        let field_aggregate_type_name = &object_aggregate
+            .comparable_fields
+            .iter()
+            .find(|field| field.field_name == *field_name)
+            .ok_or_else(|| {
+                PlanError::Internal(format!(
+                    "field {field_name} cannot be used in aggregate comparisons"
+                ))
+            })?
+            .aggregate_boolean_expression_type;
+
+        match field_operand.nested.as_deref() {
+            Some(nested) => {
+                object_aggregate = boolean_expression_types
+                    .object_aggregates
+                    .get(field_aggregate_type_name)
+                    .ok_or_else(|| {
+                        PlanError::Internal(format!(
+                            "Could not find aggregate boolean expression {field_aggregate_type_name}"
+                        ))
+                    })?;
+                operand = Some(nested);
+            }
+            None => {
+                scalar_aggregate = Some(
+                    boolean_expression_types
+                        .scalar_aggregates
+                        .get(field_aggregate_type_name)
+                        .ok_or_else(|| {
+                            PlanError::Internal(format!(
+                                "Could not find aggregate boolean expression {field_aggregate_type_name}"
+                            ))
+                        })?,
+                );
+                operand = None;
+            }
+        }
+    }
+
+    let (count_aggregation, count_distinct_aggregation, aggregation_functions) =
+        match scalar_aggregate {
+            Some(scalar_aggregate) => (
+                &scalar_aggregate.count_aggregation,
+                &scalar_aggregate.count_distinct_aggregation,
+                scalar_aggregate.aggregation_functions.as_slice(),
+            ),
+            None => (
+                &object_aggregate.count_aggregation,
+                &object_aggregate.count_distinct_aggregation,
+                &[][..],
+            ),
+        };
+
+    let comparison_boolean_expression_type = match &aggregate.function {
+        AggregationFunction::Count {} => count_aggregation
+            .as_ref()
+            .map(|count| &count.boolean_expression_type),
+        AggregationFunction::CountDistinct {} => count_distinct_aggregation
+            .as_ref()
+            .map(|count| &count.boolean_expression_type),
+        AggregationFunction::Custom { name, .. } => aggregation_functions
+            .iter()
+            .find(|function| function.aggregate_function_name == *name)
+            .map(|function| &function.boolean_expression_type),
+        AggregationFunction::Sum
+        | AggregationFunction::Min
+        | AggregationFunction::Max
+        | AggregationFunction::Average => None,
+    }
+    .ok_or_else(|| {
+        PlanError::Internal(format!(
+            "aggregation function {:?} cannot be used in aggregate comparisons",
+            aggregate.function
+        ))
+    })?;
+
+    let scalar_boolean_expression_type = boolean_expression_types
+        .scalars
+        .get(comparison_boolean_expression_type)
+        .ok_or_else(|| {
+            PlanError::Internal(format!(
+                "Could not find boolean expression {comparison_boolean_expression_type:?}"
+            ))
+        })?;
+    let data_connector_operators = scalar_boolean_expression_type
+        .data_connector_operator_mappings
+        .get(&data_connector.name)
+        .ok_or_else(|| {
+            PlanError::Internal(format!(
+                "Operators for data connector {} could not be found",
+                data_connector.name
+            ))
+        })?;
+
+    Ok(metadata_resolve::OperatorMapping(
+        data_connector_operators.operator_mapping.clone(),
+    ))
+}
+
 // follow fields until we get to leaf boolean expression
 fn boolean_expression_for_comparison(
     metadata: &metadata_resolve::Metadata,
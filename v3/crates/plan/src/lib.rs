@@ -12,9 +12,12 @@ pub use filter::to_resolved_filter_expr;
 pub use model_tracking::{count_command, count_model, extend_usage_count};
 pub use order_by::to_resolved_order_by_element;
 pub use query::{
-    build_relationship_comparison_expression, from_command, from_model_aggregate_selection,
-    from_model_group_by, from_model_selection, get_field_mapping_of_field_name, plan_expression,
-    plan_query_request, process_argument_presets_for_command, process_argument_presets_for_model,
+    apply_input_field_presets_to_partial_object, apply_input_field_presets_to_value,
+    build_relationship_aggregate_comparison_expression, build_relationship_comparison_expression,
+    check_command_argument_predicate, from_command, from_model_aggregate_selection,
+    from_model_group_by, from_model_selection, get_field_mapping_of_field_name,
+    make_argument_from_value_expression, plan_expression, plan_query_request,
+    process_argument_presets_for_command, process_argument_presets_for_model,
     process_command_relationship_definition, process_model_predicate,
     process_model_relationship_definition, query_to_plan, CommandPlan, ExecutionPlan, FromCommand,
     SingleNodeExecutionPlan, UnresolvedArgument,
@@ -1,10 +1,14 @@
 use std::collections::BTreeMap;
 
+use super::column::to_resolved_column;
 use super::types::{OrderByError, PlanError};
 use hasura_authn_core::Session;
 use metadata_resolve::{ObjectTypeWithRelationships, Qualified, RelationshipTarget, TypeMapping};
 use open_dds::{
-    data_connector::DataConnectorColumnName, query::OrderByElement, types::CustomTypeName,
+    data_connector::DataConnectorColumnName,
+    query::OrderByElement,
+    relationships::{RelationshipName, RelationshipType},
+    types::CustomTypeName,
 };
 use plan_types::{ResolvedFilterExpression, UniqueNumber, UsagesCounts};
 
@@ -20,9 +24,28 @@ pub fn to_resolved_order_by_element(
     unique_number: &mut UniqueNumber,
     usage_counts: &mut UsagesCounts,
 ) -> Result<plan_types::OrderByElement<ResolvedFilterExpression>, PlanError> {
+    if element.direction.specifies_nulls_order()
+        && !data_connector.capabilities.supports_order_by_nulls
+    {
+        return Err(
+            OrderByError::NullsOrderNotSupported(data_connector.name.clone()).into_plan_error(),
+        );
+    }
     let order_direction = match element.direction {
         open_dds::models::OrderByDirection::Asc => plan_types::OrderByDirection::Asc,
         open_dds::models::OrderByDirection::Desc => plan_types::OrderByDirection::Desc,
+        open_dds::models::OrderByDirection::AscNullsFirst => {
+            plan_types::OrderByDirection::AscNullsFirst
+        }
+        open_dds::models::OrderByDirection::AscNullsLast => {
+            plan_types::OrderByDirection::AscNullsLast
+        }
+        open_dds::models::OrderByDirection::DescNullsFirst => {
+            plan_types::OrderByDirection::DescNullsFirst
+        }
+        open_dds::models::OrderByDirection::DescNullsLast => {
+            plan_types::OrderByDirection::DescNullsLast
+        }
     };
     let target = from_operand(
         metadata,
@@ -89,11 +112,21 @@ fn from_operand(
                 usage_counts,
             )
         }
-        open_dds::query::Operand::RelationshipAggregate(relationship_operand) => {
-            Err(OrderByError::RelationshipAggregateNotSupported(
-                relationship_operand.target.relationship_name.clone(),
+        open_dds::query::Operand::RelationshipAggregate(relationship_aggregate_operand) => {
+            resolve_relationship_aggregate_operand(
+                metadata,
+                session,
+                type_mappings,
+                type_name,
+                object_type,
+                data_connector,
+                relationship_aggregate_operand,
+                relationship_path,
+                field_path,
+                collect_relationships,
+                unique_number,
+                usage_counts,
             )
-            .into_plan_error())?
         }
     }
 }
@@ -207,20 +240,157 @@ fn resolve_relationship_operand(
     data_connector: &metadata_resolve::DataConnectorLink,
     operand: &open_dds::query::RelationshipOperand,
     mut relationship_path: Vec<plan_types::RelationshipPathElement<ResolvedFilterExpression>>,
-    mut field_path: Vec<DataConnectorColumnName>,
+    field_path: Vec<DataConnectorColumnName>,
+    collect_relationships: &mut BTreeMap<plan_types::NdcRelationshipName, plan_types::Relationship>,
+    unique_number: &mut UniqueNumber,
+    usage_counts: &mut UsagesCounts,
+) -> Result<plan_types::OrderByTarget<ResolvedFilterExpression>, PlanError> {
+    let RelationshipPathTarget {
+        model_relationship_target,
+        target_model_source,
+        target_object_type,
+    } = push_relationship_path_element(
+        metadata,
+        session,
+        type_mappings,
+        type_name,
+        object_type,
+        data_connector,
+        &operand.target.relationship_name,
+        &mut relationship_path,
+        field_path,
+        collect_relationships,
+        unique_number,
+        usage_counts,
+    )?;
+
+    // Handle nested operand
+    match operand.nested.as_ref() {
+        Some(nested_operand) => from_operand(
+            metadata,
+            session,
+            &target_model_source.type_mappings,
+            &model_relationship_target.target_typename,
+            target_object_type,
+            data_connector,
+            nested_operand,
+            relationship_path,
+            vec![], // Field path resets as we pass through a relationship
+            collect_relationships,
+            unique_number,
+            usage_counts,
+        ),
+        None => Err(OrderByError::Internal(
+            "Relationship operand must have a nested field".to_string(),
+        )
+        .into_plan_error())?,
+    }
+}
+
+fn resolve_relationship_aggregate_operand(
+    metadata: &metadata_resolve::Metadata,
+    session: &Session,
+    type_mappings: &BTreeMap<Qualified<CustomTypeName>, TypeMapping>,
+    type_name: &Qualified<CustomTypeName>,
+    object_type: &ObjectTypeWithRelationships,
+    data_connector: &metadata_resolve::DataConnectorLink,
+    operand: &open_dds::query::RelationshipAggregateOperand,
+    mut relationship_path: Vec<plan_types::RelationshipPathElement<ResolvedFilterExpression>>,
+    field_path: Vec<DataConnectorColumnName>,
     collect_relationships: &mut BTreeMap<plan_types::NdcRelationshipName, plan_types::Relationship>,
     unique_number: &mut UniqueNumber,
     usage_counts: &mut UsagesCounts,
 ) -> Result<plan_types::OrderByTarget<ResolvedFilterExpression>, PlanError> {
     let relationship_name = &operand.target.relationship_name;
+    let RelationshipPathTarget {
+        model_relationship_target,
+        target_model_source,
+        target_object_type,
+    } = push_relationship_path_element(
+        metadata,
+        session,
+        type_mappings,
+        type_name,
+        object_type,
+        data_connector,
+        relationship_name,
+        &mut relationship_path,
+        field_path,
+        collect_relationships,
+        unique_number,
+        usage_counts,
+    )?;
+
+    // Only array relationships have more than one row to aggregate
+    if model_relationship_target.relationship_type != RelationshipType::Array {
+        return Err(OrderByError::RelationshipAggregateOnNonArrayRelationship(
+            relationship_name.clone(),
+        )
+        .into_plan_error());
+    }
+
+    let target_type = &model_relationship_target.target_typename;
+    let resolved_column = match operand.aggregate.operand.as_ref() {
+        None => None,
+        Some(open_dds::query::Operand::Field(field_operand)) => Some(to_resolved_column(
+            metadata,
+            &target_model_source.type_mappings,
+            target_type,
+            target_object_type,
+            field_operand,
+        )?),
+        Some(_) => Err(OrderByError::Internal(format!(
+            "unsupported aggregate operand in order_by for relationship {relationship_name}"
+        ))
+        .into_plan_error())?,
+    };
+
+    let aggregate = super::query::to_ndc_aggregate(
+        metadata,
+        &model_relationship_target.model_name.subgraph,
+        data_connector,
+        &operand.aggregate,
+        resolved_column,
+        relationship_name.as_str(),
+        data_connector.capabilities.supported_ndc_version,
+    )?;
+
+    Ok(plan_types::OrderByTarget::Aggregate {
+        relationship_path,
+        aggregate,
+    })
+}
+
+/// The target of a local model relationship that has been added to a relationship path
+struct RelationshipPathTarget<'a> {
+    model_relationship_target: &'a metadata_resolve::ModelRelationshipTarget,
+    target_model_source: &'a metadata_resolve::ModelSource,
+    target_object_type: &'a ObjectTypeWithRelationships,
+}
+
+/// Resolve a local model relationship, record it in `collect_relationships`, and push it
+/// (along with the target model's permission filter) onto the relationship path
+fn push_relationship_path_element<'a>(
+    metadata: &'a metadata_resolve::Metadata,
+    session: &Session,
+    type_mappings: &BTreeMap<Qualified<CustomTypeName>, TypeMapping>,
+    type_name: &Qualified<CustomTypeName>,
+    object_type: &'a ObjectTypeWithRelationships,
+    data_connector: &metadata_resolve::DataConnectorLink,
+    relationship_name: &RelationshipName,
+    relationship_path: &mut Vec<plan_types::RelationshipPathElement<ResolvedFilterExpression>>,
+    field_path: Vec<DataConnectorColumnName>,
+    collect_relationships: &mut BTreeMap<plan_types::NdcRelationshipName, plan_types::Relationship>,
+    unique_number: &mut UniqueNumber,
+    usage_counts: &mut UsagesCounts,
+) -> Result<RelationshipPathTarget<'a>, PlanError> {
     // Get the relationship field
     let relationship = object_type
         .relationship_fields
         .get(relationship_name)
         .ok_or_else(|| {
             OrderByError::Internal(format!(
-                "can't find relationship {} in type: {}",
-                operand.target.relationship_name, type_name
+                "can't find relationship {relationship_name} in type: {type_name}"
             ))
             .into_plan_error()
         })?;
@@ -248,6 +418,7 @@ fn resolve_relationship_operand(
                 target_source: target_model_source,
                 target_type: &model_relationship_target.target_typename,
                 mappings: &model_relationship_target.mappings,
+                argument_mappings: &model_relationship_target.argument_mappings,
             };
 
             let ndc_relationship_name =
@@ -282,16 +453,13 @@ fn resolve_relationship_operand(
                 )?,
             );
             let target_type = &model_relationship_target.target_typename;
-            let target_type_mappings = &target_model_source.type_mappings;
 
             // Create relationship path element
-            let path_element = plan_types::RelationshipPathElement {
-                field_path: field_path.clone(),
+            relationship_path.push(plan_types::RelationshipPathElement {
+                field_path,
                 relationship_name: ndc_relationship_name,
                 filter_predicate: target_permission_filter,
-            };
-            relationship_path.push(path_element);
-            field_path = vec![]; // Field path resets as we pass through a relationship
+            });
 
             let target_object_type = metadata.object_types.get(target_type).ok_or_else(|| {
                 OrderByError::Internal(format!(
@@ -300,27 +468,11 @@ fn resolve_relationship_operand(
                 .into_plan_error()
             })?;
 
-            // Handle nested operand
-            match operand.nested.as_ref() {
-                Some(nested_operand) => from_operand(
-                    metadata,
-                    session,
-                    target_type_mappings,
-                    target_type,
-                    target_object_type,
-                    data_connector,
-                    nested_operand,
-                    relationship_path,
-                    field_path,
-                    collect_relationships,
-                    unique_number,
-                    usage_counts,
-                ),
-                None => Err(OrderByError::Internal(
-                    "Relationship operand must have a nested field".to_string(),
-                )
-                .into_plan_error())?,
-            }
+            Ok(RelationshipPathTarget {
+                model_relationship_target,
+                target_model_source,
+                target_object_type,
+            })
         }
         RelationshipTarget::Command(_command_relationship_target) => {
             // Command relationships are not supported in order_by
@@ -10,15 +10,19 @@ mod relationships;
 mod types;
 use crate::types::PlanError;
 pub use arguments::{
-    process_argument_presets_for_command, process_argument_presets_for_model, UnresolvedArgument,
+    apply_input_field_presets_to_partial_object, apply_input_field_presets_to_value,
+    check_command_argument_predicate, process_argument_presets_for_command,
+    process_argument_presets_for_model, UnresolvedArgument,
 };
 pub use command::{from_command, CommandPlan, FromCommand};
 pub use filter::{
-    build_relationship_comparison_expression, get_field_mapping_of_field_name, plan_expression,
+    build_relationship_aggregate_comparison_expression, build_relationship_comparison_expression,
+    get_field_mapping_of_field_name, plan_expression,
 };
 use indexmap::IndexMap;
+pub(crate) use model::to_ndc_aggregate;
 pub use model::{from_model_aggregate_selection, from_model_group_by, from_model_selection};
-pub use permissions::process_model_predicate;
+pub use permissions::{make_argument_from_value_expression, process_model_predicate};
 pub use relationships::{
     process_command_relationship_definition, process_model_relationship_definition,
 };
@@ -32,13 +36,13 @@ use plan_types::{ExecutionTree, UniqueNumber};
 #[derive(Debug)]
 pub enum SingleNodeExecutionPlan {
     Query(plan_types::ExecutionTree),
-    Mutation(plan_types::MutationExecutionPlan),
+    Mutation(plan_types::MutationExecutionTree),
 }
 
 #[derive(Debug)]
 pub enum ExecutionPlan {
     Queries(IndexMap<Alias, ExecutionTree>),
-    Mutation(plan_types::MutationExecutionPlan), // currently only support a single mutation
+    Mutation(plan_types::MutationExecutionTree), // currently only support a single mutation
 }
 
 // make a query execution plan from OpenDD IR
@@ -70,13 +74,13 @@ where
             SingleNodeExecutionPlan::Query(execution_tree) => {
                 queries.insert(alias.clone(), execution_tree);
             }
-            SingleNodeExecutionPlan::Mutation(mutation_execution_plan) => {
+            SingleNodeExecutionPlan::Mutation(mutation_execution_tree) => {
                 if mutation.is_some() {
                     return Err(PlanError::Internal(
                         "Multiple mutations not currently supported in OpenDD pipeline".into(),
                     ));
                 }
-                mutation = Some(mutation_execution_plan);
+                mutation = Some(mutation_execution_tree);
             }
         }
     }
@@ -144,8 +148,8 @@ where
                 command::CommandPlan::Function(execution_tree) => {
                     Ok(SingleNodeExecutionPlan::Query(execution_tree))
                 }
-                command::CommandPlan::Procedure(mutation_execution_plan) => {
-                    Ok(SingleNodeExecutionPlan::Mutation(mutation_execution_plan))
+                command::CommandPlan::Procedure(mutation_execution_tree) => {
+                    Ok(SingleNodeExecutionPlan::Mutation(mutation_execution_tree))
                 }
             }
         }
@@ -5,13 +5,14 @@ use hasura_authn_core::{Session, SessionVariables};
 use indexmap::IndexMap;
 use metadata_resolve::data_connectors::ArgumentPresetValue;
 use metadata_resolve::{
-    unwrap_custom_type_name, ArgumentInfo, CommandWithPermissions, Metadata, ModelWithPermissions,
+    unwrap_custom_type_name, ArgumentInfo, ArgumentPredicate, CommandWithPermissions,
+    FieldConditionOperator, FieldMapping, Metadata, ModelWithPermissions,
     ObjectTypeWithRelationships, Qualified, QualifiedBaseType, QualifiedTypeReference, TypeMapping,
     ValueExpressionOrPredicate,
 };
 use open_dds::{
     arguments::ArgumentName,
-    types::{CustomTypeName, DataConnectorArgumentName},
+    types::{CustomTypeName, DataConnectorArgumentName, FieldName},
 };
 use plan_types::{Argument, Expression, Relationship, UniqueNumber, UsagesCounts};
 use reqwest::header::HeaderMap;
@@ -116,6 +117,118 @@ pub fn process_argument_presets_for_command<'s>(
     )
 }
 
+/// Check the argument predicate of the command permission for the role of the session
+/// against the arguments of the command, after presets have been applied. Returns
+/// `false` if the role is not allowed to execute the command with these arguments.
+pub fn check_command_argument_predicate(
+    arguments: &BTreeMap<DataConnectorArgumentName, UnresolvedArgument<'_>>,
+    command: &CommandWithPermissions,
+    session: &Session,
+) -> Result<bool, InternalError> {
+    let Some(argument_predicate) = command
+        .permissions
+        .get(&session.role)
+        .and_then(|permission| permission.argument_predicate.as_ref())
+    else {
+        return Ok(true);
+    };
+
+    let command_source = command.command.source.as_ref().ok_or_else(|| {
+        InternalEngineError::ArgumentPresetExecution {
+            description: format!(
+                "Command source not found for command '{}'",
+                command.command.name
+            ),
+        }
+    })?;
+
+    evaluate_argument_predicate(
+        argument_predicate,
+        arguments,
+        &command_source.argument_mappings,
+        &session.variables,
+    )
+}
+
+/// Comparisons against arguments that are null, not provided, or are boolean
+/// expressions evaluate to false.
+fn evaluate_argument_predicate(
+    argument_predicate: &ArgumentPredicate,
+    arguments: &BTreeMap<DataConnectorArgumentName, UnresolvedArgument<'_>>,
+    argument_mappings: &BTreeMap<ArgumentName, DataConnectorArgumentName>,
+    session_variables: &SessionVariables,
+) -> Result<bool, InternalError> {
+    let get_argument_value = |argument_name: &ArgumentName| {
+        let data_connector_argument_name =
+            argument_mappings.get(argument_name).ok_or_else(|| {
+                InternalEngineError::ArgumentPresetExecution {
+                    description: format!("argument mapping not found for '{argument_name}'"),
+                }
+            })?;
+        Ok::<_, InternalError>(arguments.get(data_connector_argument_name))
+    };
+    match argument_predicate {
+        ArgumentPredicate::ArgumentComparison {
+            argument,
+            argument_type,
+            operator,
+            value,
+        } => match get_argument_value(argument)? {
+            Some(UnresolvedArgument::Literal {
+                value: argument_value,
+            }) if !argument_value.is_null() => {
+                let value = permissions::make_argument_from_value_expression(
+                    value,
+                    argument_type,
+                    session_variables,
+                )?;
+                Ok(match operator {
+                    FieldConditionOperator::Equal => *argument_value == value,
+                    FieldConditionOperator::NotEqual => *argument_value != value,
+                })
+            }
+            _ => Ok(false),
+        },
+        ArgumentPredicate::ArgumentIsNull { argument } => Ok(match get_argument_value(argument)? {
+            None => true,
+            Some(UnresolvedArgument::Literal { value }) => value.is_null(),
+            Some(UnresolvedArgument::BooleanExpression { .. }) => false,
+        }),
+        ArgumentPredicate::And(predicates) => {
+            for predicate in predicates {
+                if !evaluate_argument_predicate(
+                    predicate,
+                    arguments,
+                    argument_mappings,
+                    session_variables,
+                )? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        ArgumentPredicate::Or(predicates) => {
+            for predicate in predicates {
+                if evaluate_argument_predicate(
+                    predicate,
+                    arguments,
+                    argument_mappings,
+                    session_variables,
+                )? {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+        ArgumentPredicate::Not(predicate) => Ok(!evaluate_argument_predicate(
+            predicate,
+            arguments,
+            argument_mappings,
+            session_variables,
+        )?),
+    }
+}
+
 fn process_argument_presets<'s>(
     mut arguments: BTreeMap<DataConnectorArgumentName, UnresolvedArgument<'s>>,
     argument_infos: &IndexMap<ArgumentName, ArgumentInfo>,
@@ -192,7 +305,7 @@ fn process_argument_presets<'s>(
     Ok(arguments)
 }
 
-fn apply_input_field_presets_to_value(
+pub fn apply_input_field_presets_to_value(
     value: &mut serde_json::Value,
     type_reference: &QualifiedTypeReference,
     type_mappings: &BTreeMap<Qualified<CustomTypeName>, TypeMapping>,
@@ -244,47 +357,16 @@ fn apply_input_field_presets_to_value(
                     value.as_object_mut().unwrap() // This is safe because we just created an object value
                 };
 
-            // Get the input permissions for this object type for the current role
-            let field_presets = object_type_info
-                .type_input_permissions
-                .get(&session.role)
-                .map_or_else(
-                    || Cow::Owned(BTreeMap::new()),
-                    |input_permissions| Cow::Borrowed(&input_permissions.field_presets),
-                );
-
             // Get the data connector type mapping for this object type
-            let TypeMapping::Object { field_mappings, .. } = type_mappings
-                .get(object_type_name)
-                .ok_or_else(|| InternalEngineError::ArgumentPresetExecution {
-                    description: format!(
-                        "no data connector type mapping found for object type '{object_type_name}'"
-                    ),
-                })?;
-
-            // Apply all input field presets to the object value
-            for (field_name, field_preset) in field_presets.as_ref() {
-                // Get the data connector field mapping for this field
-                let field_mapping = field_mappings.get(field_name).ok_or_else(|| {
-                    InternalEngineError::ArgumentPresetExecution {
-                        description: format!("no data connector field mapping found for field '{field_name}' of object type '{object_type_name}'"),
-                    }
-                })?;
-                // Get the type information about the field
-                let field_info = object_type_info.object_type.fields.get(field_name).ok_or_else(|| {
-                    InternalEngineError::ArgumentPresetExecution {
-                        description: format!("no field definition found for field '{field_name}' of object type '{object_type_name}'"),
-                    }
-                })?;
-
-                let argument_value = permissions::make_argument_from_value_expression(
-                    &field_preset.value,
-                    &field_info.field_type,
-                    &session.variables,
-                )?;
-
-                object_value.insert(field_mapping.column.as_str().to_owned(), argument_value);
-            }
+            let field_mappings = get_object_type_field_mappings(object_type_name, type_mappings)?;
+
+            insert_input_field_presets(
+                object_value,
+                object_type_name,
+                object_type_info,
+                field_mappings,
+                session,
+            )?;
 
             // Recur and apply input field presets to the values of all the object fields
             for (field_name, field_info) in &object_type_info.object_type.fields {
@@ -329,6 +411,107 @@ fn apply_input_field_presets_to_value(
     Ok(())
 }
 
+/// Apply the input field presets of an object type to an object that sets only some of its
+/// fields, like the new values of the fields of an update. The preset fields are always set,
+/// but, unlike `apply_input_field_presets_to_value`, the fields that are missing from the
+/// object are left missing.
+pub fn apply_input_field_presets_to_partial_object(
+    object_value: &mut serde_json::Map<String, serde_json::Value>,
+    object_type_name: &Qualified<CustomTypeName>,
+    type_mappings: &BTreeMap<Qualified<CustomTypeName>, TypeMapping>,
+    object_types: &BTreeMap<Qualified<CustomTypeName>, ObjectTypeWithRelationships>,
+    session: &Session,
+) -> Result<(), InternalError> {
+    let object_type_info = object_types.get(object_type_name).ok_or_else(|| {
+        InternalEngineError::ArgumentPresetExecution {
+            description: format!("no object type definition found for '{object_type_name}'"),
+        }
+    })?;
+    let field_mappings = get_object_type_field_mappings(object_type_name, type_mappings)?;
+
+    // Recur into the fields that are set before the presets are inserted, so that the preset
+    // values are left as they are
+    for (field_name, field_info) in &object_type_info.object_type.fields {
+        if let Some(field_mapping) = field_mappings.get(field_name) {
+            if let Some(field_value) = object_value.get_mut(field_mapping.column.as_str()) {
+                apply_input_field_presets_to_value(
+                    field_value,
+                    &field_info.field_type,
+                    type_mappings,
+                    object_types,
+                    session,
+                )?;
+            }
+        }
+    }
+
+    insert_input_field_presets(
+        object_value,
+        object_type_name,
+        object_type_info,
+        field_mappings,
+        session,
+    )
+}
+
+fn get_object_type_field_mappings<'a>(
+    object_type_name: &Qualified<CustomTypeName>,
+    type_mappings: &'a BTreeMap<Qualified<CustomTypeName>, TypeMapping>,
+) -> Result<&'a BTreeMap<FieldName, FieldMapping>, InternalError> {
+    let TypeMapping::Object { field_mappings, .. } = type_mappings
+        .get(object_type_name)
+        .ok_or_else(|| InternalEngineError::ArgumentPresetExecution {
+            description: format!(
+                "no data connector type mapping found for object type '{object_type_name}'"
+            ),
+        })?;
+    Ok(field_mappings)
+}
+
+/// Set the fields of an object that are preset by the input permissions of the role of the
+/// session for its object type
+fn insert_input_field_presets(
+    object_value: &mut serde_json::Map<String, serde_json::Value>,
+    object_type_name: &Qualified<CustomTypeName>,
+    object_type_info: &ObjectTypeWithRelationships,
+    field_mappings: &BTreeMap<FieldName, FieldMapping>,
+    session: &Session,
+) -> Result<(), InternalError> {
+    // Get the input permissions for this object type for the current role
+    let field_presets = object_type_info
+        .type_input_permissions
+        .get(&session.role)
+        .map_or_else(
+            || Cow::Owned(BTreeMap::new()),
+            |input_permissions| Cow::Borrowed(&input_permissions.field_presets),
+        );
+
+    // Apply all input field presets to the object value
+    for (field_name, field_preset) in field_presets.as_ref() {
+        // Get the data connector field mapping for this field
+        let field_mapping = field_mappings.get(field_name).ok_or_else(|| {
+            InternalEngineError::ArgumentPresetExecution {
+                description: format!("no data connector field mapping found for field '{field_name}' of object type '{object_type_name}'"),
+            }
+        })?;
+        // Get the type information about the field
+        let field_info = object_type_info.object_type.fields.get(field_name).ok_or_else(|| {
+            InternalEngineError::ArgumentPresetExecution {
+                description: format!("no field definition found for field '{field_name}' of object type '{object_type_name}'"),
+            }
+        })?;
+
+        let argument_value = permissions::make_argument_from_value_expression(
+            &field_preset.value,
+            &field_info.field_type,
+            &session.variables,
+        )?;
+
+        object_value.insert(field_mapping.column.as_str().to_owned(), argument_value);
+    }
+    Ok(())
+}
+
 fn get_value_array_or_null<'a>(
     value: &'a mut serde_json::Value,
     expected_type: &QualifiedTypeReference,
@@ -1,4 +1,11 @@
-use super::arguments::{get_unresolved_arguments, resolve_arguments};
+use super::arguments::{
+    check_command_argument_predicate, get_unresolved_arguments, resolve_arguments,
+};
+use super::filter::resolve_filter_expression;
+use super::permissions::process_model_predicate;
+use super::relationships::{
+    process_remote_relationship_field_mapping, ProcessedRemoteRelationship,
+};
 use super::{field_selection, process_argument_presets_for_command};
 use crate::PlanError;
 use hasura_authn_core::Session;
@@ -14,17 +21,20 @@ use open_dds::{
     types::CustomTypeName,
 };
 use plan_types::{
-    Argument, ExecutionTree, Field, JoinLocations, MutationArgument, MutationExecutionPlan,
-    NdcFieldAlias, NdcRelationshipName, NestedArray, NestedField, NestedObject,
-    PredicateQueryTrees, QueryExecutionPlan, QueryNodeNew, Relationship,
+    make_model_field_variable_name, Argument, ComparisonTarget, ComparisonValue, ExecutionTree,
+    Field, JoinLocations, JoinNode, LocalFieldComparison, Location, MutationArgument,
+    MutationExecutionPlan, MutationExecutionTree, NdcFieldAlias, NdcRelationshipName, NestedArray,
+    NestedField, NestedObject, PredicateQueryTrees, ProcessResponseAs, QueryExecutionPlan,
+    QueryNodeNew, Relationship, RemoteJoin, RemoteJoinType, ResolvedFilterExpression, TargetField,
+    UsagesCounts,
 };
-use plan_types::{UniqueNumber, FUNCTION_IR_VALUE_COLUMN_NAME};
-use std::collections::BTreeMap;
+use plan_types::{UniqueNumber, COMMAND_OUTPUT_FILTER_ALIAS, FUNCTION_IR_VALUE_COLUMN_NAME};
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Debug)]
 pub enum CommandPlan {
     Function(ExecutionTree),
-    Procedure(MutationExecutionPlan),
+    Procedure(MutationExecutionTree),
 }
 
 pub struct FromCommand {
@@ -77,10 +87,12 @@ fn from_command_output_type(
     session: &Session,
     request_headers: &reqwest::header::HeaderMap,
     command_source: &metadata_resolve::CommandSource,
+    output_filter: Option<&metadata_resolve::CommandOutputFilter>,
     relationships: &mut BTreeMap<NdcRelationshipName, Relationship>,
     remote_join_executions: &mut JoinLocations,
     remote_predicates: &mut PredicateQueryTrees,
     unique_number: &mut UniqueNumber,
+    usage_counts: &mut UsagesCounts,
 ) -> Result<
     (
         IndexMap<NdcFieldAlias, Field>,
@@ -97,10 +109,12 @@ fn from_command_output_type(
             session,
             request_headers,
             command_source,
+            output_filter,
             relationships,
             remote_join_executions,
             remote_predicates,
             unique_number,
+            usage_counts,
         ),
         OutputShape::Object {
             object: output_object_type,
@@ -110,7 +124,7 @@ fn from_command_output_type(
                 Some(selection_set) => selection_set,
                 None => &IndexMap::new(),
             };
-            let ndc_fields = field_selection::resolve_field_selection(
+            let mut ndc_fields = field_selection::resolve_field_selection(
                 metadata,
                 session,
                 request_headers,
@@ -125,6 +139,20 @@ fn from_command_output_type(
                 unique_number,
             )?;
 
+            if let Some(output_filter) = output_filter {
+                plan_output_filter(
+                    output_filter,
+                    output_object_type_name,
+                    &command_source.type_mappings,
+                    session,
+                    &mut ndc_fields,
+                    remote_join_executions,
+                    remote_predicates,
+                    unique_number,
+                    usage_counts,
+                )?;
+            }
+
             match &command_source.data_connector.response_config {
                 // if the data connector has 'responseHeaders' configured, we'll need to wrap the ndc fields
                 // under the 'result' field if the command's response at opendd layer refers to the 'result'
@@ -158,12 +186,17 @@ pub(crate) fn from_command_selection(
     unique_number: &mut UniqueNumber,
 ) -> Result<FromCommand, PlanError> {
     let mut relationships = BTreeMap::new();
-    let mut usage_counts = plan_types::UsagesCounts::default();
+    let mut usage_counts = UsagesCounts::default();
     let mut remote_join_executions = JoinLocations::new();
     let mut remote_predicates = PredicateQueryTrees::new();
 
     let output_shape = return_type_shape(&command.command.output_type, metadata)?;
 
+    let output_filter = command
+        .permissions
+        .get(&session.role)
+        .and_then(|permission| permission.output_filter.as_ref());
+
     let (ndc_fields, extract_response_from) = from_command_output_type(
         &output_shape,
         command_selection,
@@ -171,10 +204,12 @@ pub(crate) fn from_command_selection(
         session,
         request_headers,
         command_source,
+        output_filter,
         &mut relationships,
         &mut remote_join_executions,
         &mut remote_predicates,
         unique_number,
+        &mut usage_counts,
     )?;
 
     if !command
@@ -208,6 +243,16 @@ pub(crate) fn from_command_selection(
         &mut usage_counts,
     )
     .map_err(|e| PlanError::Internal(e.to_string()))?;
+
+    if !check_command_argument_predicate(&unresolved_arguments, command, session)
+        .map_err(|e| PlanError::Internal(e.to_string()))?
+    {
+        Err(PlanError::Permission(format!(
+            "role {} does not have permission to execute command {} with the given arguments",
+            session.role, qualified_command_name
+        )))?;
+    }
+
     let resolved_arguments =
         resolve_arguments(unresolved_arguments, &mut relationships, unique_number)?;
 
@@ -238,28 +283,34 @@ pub(crate) fn from_command_selection(
             },
         }),
         DataConnectorCommand::Procedure(procedure_name) => {
-            CommandPlan::Procedure(MutationExecutionPlan {
-                procedure_name: procedure_name.clone(),
-                procedure_arguments: resolved_arguments
-                    .into_iter()
-                    .map(|(name, argument)| {
-                        (
-                            name,
-                            match argument {
-                                Argument::Literal { value } => MutationArgument::Literal { value },
-                                Argument::BooleanExpression { predicate } => {
-                                    MutationArgument::BooleanExpression { predicate }
-                                }
-                                Argument::Variable { name: _ } => {
-                                    todo!("variable in mutation argument")
-                                }
-                            },
-                        )
-                    })
-                    .collect(),
-                procedure_fields: Some(wrap_procedure_ndc_fields(&output_shape, ndc_fields)),
-                collection_relationships: relationships.clone(),
-                data_connector: command_source.data_connector.clone(),
+            CommandPlan::Procedure(MutationExecutionTree {
+                remote_predicates,
+                remote_join_executions,
+                mutation_execution_plan: MutationExecutionPlan {
+                    procedure_name: procedure_name.clone(),
+                    procedure_arguments: resolved_arguments
+                        .into_iter()
+                        .map(|(name, argument)| {
+                            (
+                                name,
+                                match argument {
+                                    Argument::Literal { value } => {
+                                        MutationArgument::Literal { value }
+                                    }
+                                    Argument::BooleanExpression { predicate } => {
+                                        MutationArgument::BooleanExpression { predicate }
+                                    }
+                                    Argument::Variable { name: _ } => {
+                                        todo!("variable in mutation argument")
+                                    }
+                                },
+                            )
+                        })
+                        .collect(),
+                    procedure_fields: Some(wrap_procedure_ndc_fields(&output_shape, ndc_fields)),
+                    collection_relationships: relationships.clone(),
+                    data_connector: command_source.data_connector.clone(),
+                },
             })
         }
     };
@@ -269,6 +320,128 @@ pub(crate) fn from_command_selection(
     })
 }
 
+/// Join the objects returned by a command with the objects selected from the model of
+/// their type by their unique identifier, with the output filter of the command permission
+/// applied, so that the objects that do not satisfy the filter are found while processing
+/// the response.
+fn plan_output_filter(
+    output_filter: &metadata_resolve::CommandOutputFilter,
+    output_object_type_name: &Qualified<CustomTypeName>,
+    type_mappings: &BTreeMap<Qualified<CustomTypeName>, metadata_resolve::TypeMapping>,
+    session: &Session,
+    ndc_fields: &mut IndexMap<NdcFieldAlias, Field>,
+    remote_join_executions: &mut JoinLocations,
+    remote_predicates: &mut PredicateQueryTrees,
+    unique_number: &mut UniqueNumber,
+    usage_counts: &mut UsagesCounts,
+) -> Result<(), PlanError> {
+    let metadata_resolve::TypeMapping::Object { field_mappings, .. } =
+        type_mappings.get(output_object_type_name).ok_or_else(|| {
+            PlanError::Internal(format!(
+                "couldn't fetch type_mapping of type {output_object_type_name}",
+            ))
+        })?;
+
+    let mut join_mapping = HashMap::new();
+    let mut join_filter_expressions = Vec::new();
+    let mut key_fields = IndexMap::new();
+    for (field_name, target_column) in &output_filter.unique_identifier {
+        let field_mapping = field_mappings.get(field_name).ok_or_else(|| {
+            PlanError::Internal(format!(
+                "invalid field in command output filter: {field_name}"
+            ))
+        })?;
+
+        let ProcessedRemoteRelationship {
+            source_field_alias,
+            field: (phantom_field_alias, phantom_field),
+        } = process_remote_relationship_field_mapping(&field_mapping.column, vec![]);
+        ndc_fields.insert(phantom_field_alias, phantom_field);
+
+        join_mapping.insert(
+            vec![field_name.clone()],
+            (
+                source_field_alias,
+                TargetField::ModelField((field_name.clone(), target_column.clone())),
+            ),
+        );
+
+        join_filter_expressions.push(ResolvedFilterExpression::LocalFieldComparison(
+            LocalFieldComparison::BinaryComparison {
+                column: ComparisonTarget::Column {
+                    name: target_column.column.clone(),
+                    field_path: target_column.field_path.clone(),
+                },
+                operator: target_column.equal_operator.clone(),
+                value: ComparisonValue::Variable {
+                    name: make_model_field_variable_name(target_column),
+                },
+            },
+        ));
+
+        key_fields.insert(
+            NdcFieldAlias::from(target_column.column.as_str()),
+            Field::Column {
+                column: target_column.column.clone(),
+                fields: None,
+                arguments: BTreeMap::new(),
+            },
+        );
+    }
+
+    // the output filter is applied in the same way as a model select permission filter
+    let model_source = &output_filter.model_source;
+    let filter_ir = process_model_predicate(
+        &model_source.data_connector,
+        &model_source.type_mappings,
+        &output_filter.predicate,
+        &session.variables,
+        usage_counts,
+    )
+    .map_err(PlanError::InternalError)?;
+
+    let mut collection_relationships = BTreeMap::new();
+    let (filter, filter_remote_predicates) =
+        resolve_filter_expression(&filter_ir, &mut collection_relationships, unique_number)?;
+    join_filter_expressions.push(filter);
+
+    // push any remote predicates to the outer list
+    remote_predicates.0.extend(filter_remote_predicates.0);
+
+    let remote_join = RemoteJoin {
+        target_ndc_execution: QueryExecutionPlan {
+            query_node: QueryNodeNew {
+                fields: Some(plan_types::FieldsSelection { fields: key_fields }),
+                aggregates: None,
+                limit: None,
+                offset: None,
+                order_by: None,
+                predicate: Some(ResolvedFilterExpression::mk_and(join_filter_expressions)),
+                group_by: None,
+            },
+            collection: model_source.collection.clone(),
+            arguments: BTreeMap::new(),
+            collection_relationships,
+            variables: None,
+            data_connector: model_source.data_connector.clone(),
+        },
+        target_data_connector: model_source.data_connector.clone(),
+        join_mapping,
+        process_response_as: ProcessResponseAs::Array { is_nullable: true },
+        remote_join_type: RemoteJoinType::ToModel,
+    };
+
+    remote_join_executions.locations.insert(
+        COMMAND_OUTPUT_FILTER_ALIAS.to_owned(),
+        Location {
+            join_node: JoinNode::Remote(remote_join),
+            rest: JoinLocations::new(),
+        },
+    );
+
+    Ok(())
+}
+
 fn wrap_procedure_ndc_fields(
     output_shape: &OutputShape,
     ndc_fields: IndexMap<NdcFieldAlias, Field>,
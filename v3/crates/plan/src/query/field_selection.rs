@@ -4,6 +4,7 @@ use indexmap::IndexMap;
 use std::collections::BTreeMap;
 
 use super::{
+    permissions,
     relationships::{
         calculate_remote_relationship_fields_for_command_target,
         calculate_remote_relationship_fields_for_model_target,
@@ -19,9 +20,10 @@ use open_dds::{
     arguments::ArgumentName,
     commands::DataConnectorCommand,
     query::{
-        Alias, CommandSelection, CommandTarget, ModelSelection, ModelTarget, ObjectFieldSelection,
-        ObjectFieldTarget, ObjectSubSelection, RelationshipAggregateSelection,
-        RelationshipSelection, RelationshipTarget, Value,
+        Alias, BooleanExpression, CommandSelection, CommandTarget, ComparisonOperator,
+        ModelSelection, ModelTarget, ObjectFieldOperand, ObjectFieldSelection, ObjectFieldTarget,
+        ObjectSubSelection, Operand, RelationshipAggregateSelection, RelationshipSelection,
+        RelationshipTarget, Value,
     },
     relationships::RelationshipName,
     types::{CustomTypeName, DataConnectorArgumentName, FieldName},
@@ -161,6 +163,14 @@ fn from_field_selection(
             session.role
         )));
     }
+    // The conditions of conditionally allowed fields are not evaluated here, so selecting
+    // such a field would expose its value on objects that do not match the condition.
+    if !type_permissions.is_unconditionally_allowed(field_name) {
+        return Err(PlanError::Permission(format!(
+            "role {} does not have permission to select the field {field_name} from type {object_type_name} on all objects, as the conditions of conditionally allowed fields are not evaluated in this pipeline",
+            session.role
+        )));
+    }
 
     let field_mapping = field_mappings.get(field_name).ok_or_else(|| {
         PlanError::Internal(format!(
@@ -168,14 +178,14 @@ fn from_field_selection(
         ))
     })?;
 
-    let field_type = &object_type
+    let field_definition = object_type
         .object_type
         .fields
         .get(field_name)
         .ok_or_else(|| {
             PlanError::Internal(format!("could not look up type of field {field_name}"))
-        })?
-        .field_type;
+        })?;
+    let field_type = &field_definition.field_type;
 
     let fields = resolve_nested_field_selection(
         metadata,
@@ -191,7 +201,14 @@ fn from_field_selection(
         unique_number,
     )?;
 
-    let field_arguments = resolve_field_arguments(field_name, arguments, field_mapping)?;
+    let field_arguments = resolve_field_arguments(
+        session,
+        field_name,
+        field_definition,
+        arguments,
+        type_permissions.field_argument_presets.get(field_name),
+        field_mapping,
+    )?;
 
     let ndc_field = Field::Column {
         column: field_mapping.column.clone(),
@@ -202,11 +219,13 @@ fn from_field_selection(
 }
 
 fn resolve_field_arguments(
+    session: &Session,
     field_name: &FieldName,
+    field_definition: &metadata_resolve::FieldDefinition,
     input_arguments: &IndexMap<ArgumentName, Value>,
+    argument_presets: Option<&BTreeMap<ArgumentName, metadata_resolve::ValueExpression>>,
     field_mapping: &metadata_resolve::FieldMapping,
 ) -> Result<BTreeMap<DataConnectorArgumentName, plan_types::Argument>, PlanError> {
-    // NOTE: Presets for field arguments are not currently supported.
     let mut arguments = BTreeMap::new();
     for (argument_name, argument_value) in input_arguments {
         let ndc_argument_name = field_mapping.argument_mappings.get(argument_name).ok_or_else(|| {
@@ -224,6 +243,34 @@ fn resolve_field_arguments(
         };
         arguments.insert(ndc_argument_name.clone(), argument);
     }
+    // Preset arguments from TypeOutputPermission field argument presets, which take
+    // precedence over any provided values
+    for (argument_name, argument_value) in argument_presets.into_iter().flatten() {
+        let ndc_argument_name = field_mapping.argument_mappings.get(argument_name).ok_or_else(|| {
+            PlanError::Internal(format!(
+                "couldn't find the argument mapping for argument {argument_name} in field {field_name}"
+            ))
+        })?;
+        let argument_type = &field_definition
+            .field_arguments
+            .get(argument_name)
+            .ok_or_else(|| {
+                PlanError::Internal(format!(
+                    "couldn't find the type of argument {argument_name} in field {field_name}"
+                ))
+            })?
+            .argument_type;
+        let value = permissions::make_argument_from_value_expression(
+            argument_value,
+            argument_type,
+            &session.variables,
+        )
+        .map_err(PlanError::InternalError)?;
+        arguments.insert(
+            ndc_argument_name.clone(),
+            plan_types::Argument::Literal { value },
+        );
+    }
     Ok(arguments)
 }
 
@@ -422,7 +469,11 @@ fn from_model_relationship(
         subgraph: target_model_name.subgraph.clone(),
         model_name: target_model_name.name.clone(),
         arguments: arguments.clone(),
-        filter: filter.clone(),
+        filter: filter_with_value_mappings(
+            filter.as_ref(),
+            &model_relationship_target.value_mappings,
+            session,
+        )?,
         order_by: order_by.clone(),
         limit: *limit,
         offset: *offset,
@@ -445,10 +496,12 @@ fn from_model_relationship(
                 join_mapping,
                 phantom_fields,
                 mut relationship_join_filter_expressions,
+                arguments: new_arguments,
             } = calculate_remote_relationship_fields_for_model_target(
                 object_type_name,
                 relationship_name,
                 &model_relationship_target.mappings,
+                &model_relationship_target.argument_mappings,
                 source_type_mappings,
             )
             .map_err(PlanError::Relationship)?;
@@ -465,6 +518,9 @@ fn from_model_relationship(
                 unique_number,
             )?;
 
+            // add the arguments mapped from the source fields
+            query_execution.arguments.extend(new_arguments);
+
             // store remote predicates
             remote_predicates.0.extend(new_remote_predicates.0);
 
@@ -624,10 +680,22 @@ fn from_command_relationship(
         })?
         .clone();
 
+    // arguments mapped from values are fixed by the relationship
+    let mut arguments = arguments.clone();
+    for value_mapping in &command_relationship_target.value_mappings {
+        let value = permissions::make_argument_from_value_expression(
+            &value_mapping.source_value,
+            &value_mapping.argument_type,
+            &session.variables,
+        )
+        .map_err(PlanError::InternalError)?;
+        arguments.insert(value_mapping.argument_name.clone(), Value::Literal(value));
+    }
+
     let command_target = CommandTarget {
         subgraph: command_name.subgraph.clone(),
         command_name: command_name.name.clone(),
-        arguments: arguments.clone(),
+        arguments,
     };
 
     let command_selection = CommandSelection {
@@ -704,6 +772,10 @@ fn from_command_relationship(
                     is_nullable: command.command.output_type.nullable,
                     return_kind,
                     response_config: command_source.data_connector.response_config.clone(),
+                    // the output filter and the member types of a command relationship
+                    // are resolved when processing the relationship field of the source object
+                    check_output_filter: false,
+                    type_discrimination: None,
                 },
                 remote_join_type: RemoteJoinType::ToCommand,
             };
@@ -803,6 +875,7 @@ fn record_local_model_relationship(
         target_source: &target_source.model,
         target_type: &model_relationship_target.target_typename,
         mappings: &model_relationship_target.mappings,
+        argument_mappings: &model_relationship_target.argument_mappings,
     };
 
     let ndc_relationship_name =
@@ -877,6 +950,7 @@ fn from_relationship_aggregate_selection(
                 target_source: target_model_source,
                 target_type: &model_relationship_target.target_typename,
                 mappings: &model_relationship_target.mappings,
+                argument_mappings: &model_relationship_target.argument_mappings,
             };
 
             let ndc_relationship_name =
@@ -891,7 +965,11 @@ fn from_relationship_aggregate_selection(
                 subgraph: target_model_name.subgraph.clone(),
                 model_name: target_model_name.name.clone(),
                 arguments: arguments.clone(),
-                filter: filter.clone(),
+                filter: filter_with_value_mappings(
+                    filter.as_ref(),
+                    &model_relationship_target.value_mappings,
+                    session,
+                )?,
                 order_by: order_by.clone(),
                 limit: *limit,
                 offset: *offset,
@@ -933,6 +1011,55 @@ fn from_relationship_aggregate_selection(
     }
 }
 
+/// Restrict the target model of a relationship to the rows whose fields are
+/// equal to the values of the relationship's value mappings
+fn filter_with_value_mappings(
+    filter: Option<&BooleanExpression>,
+    value_mappings: &[metadata_resolve::RelationshipModelValueMapping],
+    session: &Session,
+) -> Result<Option<BooleanExpression>, PlanError> {
+    let mut expressions = filter.cloned().into_iter().collect::<Vec<_>>();
+    for value_mapping in value_mappings {
+        let value = permissions::make_argument_from_value_expression(
+            &value_mapping.source_value,
+            &value_mapping.target_field_type,
+            &session.variables,
+        )
+        .map_err(PlanError::InternalError)?;
+        // build the operand inside out, from the innermost nested field
+        let nested =
+            value_mapping
+                .target_nested_fields
+                .iter()
+                .rev()
+                .fold(None, |nested, nested_field| {
+                    Some(Box::new(Operand::Field(ObjectFieldOperand {
+                        target: Box::new(ObjectFieldTarget {
+                            field_name: nested_field.field_name.clone(),
+                            arguments: IndexMap::new(),
+                        }),
+                        nested,
+                    })))
+                });
+        expressions.push(BooleanExpression::Comparison {
+            operand: Operand::Field(ObjectFieldOperand {
+                target: Box::new(ObjectFieldTarget {
+                    field_name: value_mapping.target_field.field_name.clone(),
+                    arguments: IndexMap::new(),
+                }),
+                nested,
+            }),
+            operator: ComparisonOperator::Equals,
+            argument: Box::new(Value::Literal(value)),
+        });
+    }
+    Ok(match expressions.len() {
+        0 => None,
+        1 => expressions.pop(),
+        _ => Some(BooleanExpression::And(expressions)),
+    })
+}
+
 fn get_relationship_field<'a>(
     object_type_name: &'a Qualified<CustomTypeName>,
     object_type: &'a metadata_resolve::ObjectTypeWithRelationships,
@@ -984,8 +1111,10 @@ fn ndc_nested_field_selection_for(
                     let mut fields = IndexMap::new();
 
                     for (field_name, field_mapping) in field_mappings {
-                        // Only include field if the role has access to it.
-                        if type_output_permissions.allowed_fields.contains(field_name) {
+                        // Only include field if the role has access to it on all objects,
+                        // as the conditions of conditionally allowed fields are not
+                        // evaluated here.
+                        if type_output_permissions.is_unconditionally_allowed(field_name) {
                             let field_def = object_type.object_type.fields.get(field_name).ok_or_else(|| PlanError::Internal(format!(
                                 "can't find object field definition for field {field_name} in type: {name}"
                             )))?;
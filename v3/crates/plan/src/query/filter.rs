@@ -10,10 +10,10 @@ use open_dds::{
     types::{CustomTypeName, FieldName},
 };
 use plan_types::{
-    ExecutionTree, Expression, Field, FieldsSelection, JoinLocations, LocalModelRelationshipInfo,
-    NdcFieldAlias, NdcRelationshipName, PredicateQueryTree, PredicateQueryTrees,
-    QueryExecutionPlan, QueryNodeNew, Relationship, RelationshipColumnMapping,
-    ResolvedFilterExpression, SourceNdcColumn, UniqueNumber,
+    AggregateComparison, AggregateFieldSelection, ExecutionTree, Expression, Field,
+    FieldsSelection, JoinLocations, LocalModelRelationshipInfo, NdcFieldAlias, NdcRelationshipName,
+    PredicateQueryTree, PredicateQueryTrees, QueryExecutionPlan, QueryNodeNew, Relationship,
+    RelationshipColumnMapping, ResolvedFilterExpression, SourceNdcColumn, UniqueNumber,
 };
 use std::borrow::Cow;
 use std::collections::BTreeMap;
@@ -130,6 +130,41 @@ pub fn plan_expression<'a>(
                 predicate: Box::new(relationship_filter),
             })
         }
+        Expression::RelationshipLocalAggregateComparison {
+            field_path,
+            relationship,
+            filter_predicate,
+            aggregate,
+            comparison,
+            info,
+        } => {
+            let resolved_filter_predicate = filter_predicate
+                .as_ref()
+                .map(|filter_predicate| {
+                    plan_expression(
+                        filter_predicate,
+                        relationships,
+                        remote_predicates,
+                        unique_number,
+                    )
+                })
+                .transpose()?;
+
+            relationships.insert(
+                relationship.clone(),
+                process_model_relationship_definition(info)?,
+            );
+
+            Ok(
+                ResolvedFilterExpression::LocalRelationshipAggregateComparison {
+                    field_path: field_path.clone(),
+                    relationship: relationship.clone(),
+                    filter_predicate: resolved_filter_predicate.map(Box::new),
+                    aggregate: aggregate.clone(),
+                    comparison: comparison.clone(),
+                },
+            )
+        }
         Expression::RelationshipRemoteComparison {
             relationship: _,
             target_model_name,
@@ -227,7 +262,7 @@ fn build_ndc_query_fields(
 }
 
 /// Build a relationship comparison expression from a relationship predicate.
-/// This is used for for both query and permission filter predicates.
+/// This is used for both query and permission filter predicates.
 /// Corresponding inner predicates need to be resolved prior to this function call
 /// and passed as `relationship_predicate`.
 pub fn build_relationship_comparison_expression<'s>(
@@ -261,6 +296,8 @@ pub fn build_relationship_comparison_expression<'s>(
                 target_source: &target_source.model,
                 target_type,
                 mappings,
+                // relationships with argument mappings can't be used in predicates
+                argument_mappings: &[],
             };
 
             Ok(Expression::RelationshipLocalComparison {
@@ -330,6 +367,69 @@ pub fn build_relationship_comparison_expression<'s>(
     }
 }
 
+/// Build a comparison against an aggregate over the rows of an array relationship.
+/// This is used for both query and permission filter predicates.
+/// Unlike relationship comparisons, aggregate comparisons are never evaluated in the engine,
+/// so the relationship must be local and the data connector must support filtering by aggregates.
+pub fn build_relationship_aggregate_comparison_expression<'s>(
+    type_mappings: &'s BTreeMap<Qualified<CustomTypeName>, metadata_resolve::TypeMapping>,
+    column_path: &[&'s DataConnectorColumnName],
+    data_connector_link: &'s DataConnectorLink,
+    relationship_name: &'s RelationshipName,
+    relationship_type: &'s RelationshipType,
+    source_type: &'s Qualified<CustomTypeName>,
+    target_source: &'s metadata_resolve::ModelTargetSource,
+    target_type: &'s Qualified<CustomTypeName>,
+    mappings: &'s Vec<metadata_resolve::RelationshipModelMapping>,
+    filter_predicate: Option<Expression<'s>>,
+    aggregate: AggregateFieldSelection,
+    comparison: AggregateComparison,
+) -> Result<Expression<'s>, InternalError> {
+    let supports_filtering_by_aggregates = data_connector_link
+        .capabilities
+        .supports_aggregates
+        .as_ref()
+        .is_some_and(|aggregates| aggregates.supports_filtering_by_aggregates);
+
+    match get_relationship_predicate_execution_strategy(
+        data_connector_link,
+        &target_source.model.data_connector,
+        &target_source.capabilities,
+    ) {
+        RelationshipPredicateExecutionStrategy::NDCPushdown
+            if supports_filtering_by_aggregates =>
+        {
+            Ok(Expression::RelationshipLocalAggregateComparison {
+                field_path: column_path.iter().copied().cloned().collect(),
+                relationship: NdcRelationshipName::new(source_type, relationship_name),
+                filter_predicate: filter_predicate.map(Box::new),
+                aggregate,
+                comparison,
+                info: LocalModelRelationshipInfo {
+                    relationship_name,
+                    relationship_type,
+                    source_type,
+                    source_data_connector: data_connector_link,
+                    source_type_mappings: type_mappings,
+                    target_source: &target_source.model,
+                    target_type,
+                    mappings,
+                    // relationships with argument mappings can't be used in predicates
+                    argument_mappings: &[],
+                },
+            })
+        }
+        _ => Err(InternalEngineError::InternalGeneric {
+            description: format!(
+                "Cannot use relationship '{relationship_name}' in an aggregate comparison. \
+                 Aggregate comparisons are only supported on local relationships whose data connector \
+                 supports filtering by aggregates"
+            ),
+        }
+        .into()),
+    }
+}
+
 /// Determines the strategy for executing relationship predicates based on the connectors and their capabilities.
 fn get_relationship_predicate_execution_strategy(
     source_connector: &DataConnectorLink,
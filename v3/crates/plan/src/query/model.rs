@@ -43,6 +43,8 @@ pub fn from_model_group_by(
         ))
     })?;
 
+    check_aggregation_permission(model, session)?;
+
     let model_source = model.model.source.as_ref().ok_or_else(|| {
         PlanError::Internal(format!("model {qualified_model_name} has no source"))
     })?;
@@ -215,11 +217,11 @@ pub fn from_model_group_by(
 
         let ndc_aggregate = to_ndc_aggregate(
             metadata,
-            model_target,
-            model_source,
+            &model_target.subgraph,
+            &model_source.data_connector,
             aggregate,
             resolved_column,
-            field_alias,
+            field_alias.as_str(),
             ndc_version,
         )?;
 
@@ -269,6 +271,7 @@ pub fn from_model_group_by(
                 dimensions,
                 limit,
                 offset,
+                order_by: vec![],
             }),
         },
         collection: query.collection_name.clone(),
@@ -304,6 +307,8 @@ pub fn from_model_aggregate_selection(
         ))
     })?;
 
+    check_aggregation_permission(model, session)?;
+
     let model_source = model.model.source.as_ref().ok_or_else(|| {
         PlanError::Internal(format!("model {qualified_model_name} has no source"))
     })?;
@@ -341,11 +346,11 @@ pub fn from_model_aggregate_selection(
 
         let ndc_aggregate = to_ndc_aggregate(
             metadata,
-            model_target,
-            model_source,
+            &model_target.subgraph,
+            &model_source.data_connector,
             aggregate,
             resolved_column,
-            field_alias,
+            field_alias.as_str(),
             ndc_version,
         )?;
 
@@ -402,13 +407,13 @@ pub fn from_model_aggregate_selection(
     })
 }
 
-fn to_ndc_aggregate(
+pub(crate) fn to_ndc_aggregate(
     metadata: &Metadata,
-    model_target: &ModelTarget,
-    model_source: &metadata_resolve::ModelSource,
+    subgraph: &SubgraphName,
+    data_connector: &metadata_resolve::DataConnectorLink,
     aggregate: &Aggregate,
     resolved_column: Option<ResolvedColumn>,
-    field_alias: &Name,
+    field_alias: &str,
     ndc_version: NdcVersion,
 ) -> Result<AggregateFieldSelection, PlanError> {
     let column_path = match resolved_column.clone() {
@@ -442,8 +447,7 @@ fn to_ndc_aggregate(
                 .aggregate_functions
                 .ok_or_else(|| {
                     PlanError::Internal(format!(
-                        "no aggregate functions defined for field {}",
-                        field_alias.as_str()
+                        "no aggregate functions defined for field {field_alias}"
                     ))
                 })?;
             let data_connector_function_name = match &aggregate.function {
@@ -502,8 +506,8 @@ fn to_ndc_aggregate(
         } => {
             let ndc_aggregation_function_name = get_ndc_aggregation_function(
                 metadata,
-                &model_target.subgraph,
-                &model_source.data_connector,
+                subgraph,
+                data_connector,
                 aggregation_function_name,
                 aggregate_expression,
             )?;
@@ -655,3 +659,17 @@ pub fn from_model_selection(
         remote_join_executions,
     })
 }
+
+/// Check that the role's select permission allows aggregating the model
+fn check_aggregation_permission(
+    model: &metadata_resolve::ModelWithPermissions,
+    session: &Session,
+) -> Result<(), PlanError> {
+    match model.select_permissions.get(&session.role) {
+        Some(select_permission) if select_permission.allow_aggregations => Ok(()),
+        _ => Err(PlanError::Permission(format!(
+            "role {} is not allowed to aggregate model {}",
+            session.role, model.model.name
+        ))),
+    }
+}
@@ -60,12 +60,16 @@ pub fn model_target_to_ndc_query(
     let model_filter = match &model_target.filter {
         Some(expr) => Ok(Some(to_resolved_filter_expr(
             metadata,
+            session,
             &model_source.type_mappings,
             &model.model.data_type,
             model_object_type,
             model.filter_expression_type.as_ref(),
             expr,
             &model_source.data_connector,
+            &mut relationships,
+            unique_number,
+            &mut usage_counts,
         )?)),
         _ => Ok(None),
     }?;
@@ -103,6 +107,17 @@ pub fn model_target_to_ndc_query(
         .transpose()
         .map_err(|_| PlanError::Internal("limit out of range".into()))?;
 
+    // The limit of the role's select permission caps the requested limit, and applies
+    // when no limit is requested
+    let permission_limit = model
+        .select_permissions
+        .get(&session.role)
+        .and_then(|select_permission| select_permission.limit);
+    let limit = match (limit, permission_limit) {
+        (Some(limit), Some(permission_limit)) => Some(limit.min(permission_limit)),
+        (limit, None) | (None, limit) => limit,
+    };
+
     let offset: Option<u32> = model_target
         .offset
         .map(u32::try_from)
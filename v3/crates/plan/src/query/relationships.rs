@@ -1,19 +1,21 @@
 use crate::types::{PlanError, RelationshipError};
 use indexmap::IndexMap;
 use metadata_resolve::{
-    Qualified, RelationshipCommandMapping, RelationshipModelMapping, TypeMapping,
+    Qualified, RelationshipCommandMapping, RelationshipModelArgumentMapping,
+    RelationshipModelMapping, TypeMapping,
 };
 use open_dds::{
     arguments::ArgumentName,
     commands::CommandName,
     data_connector::{CollectionName, DataConnectorColumnName},
     relationships::{RelationshipName, RelationshipType},
-    types::{CustomTypeName, DataConnectorArgumentName, FieldName},
+    types::{CustomTypeName, DataConnectorArgumentName},
 };
 use plan_types::{
-    Argument, ComparisonTarget, ComparisonValue, Field, LocalCommandRelationshipInfo,
-    LocalFieldComparison, LocalModelRelationshipInfo, NdcFieldAlias, Relationship,
-    ResolvedFilterExpression, SourceFieldAlias, TargetField, VariableName,
+    make_model_argument_variable_name, make_model_field_variable_name, Argument, ComparisonTarget,
+    ComparisonValue, Field, LocalCommandRelationshipInfo, LocalFieldComparison,
+    LocalModelRelationshipInfo, NdcFieldAlias, Relationship, ResolvedFilterExpression,
+    SourceFieldAlias, SourceFieldPath, TargetField, VariableName,
 };
 use std::collections::{BTreeMap, HashMap};
 
@@ -29,12 +31,15 @@ pub fn process_model_relationship_definition(
         target_source,
         target_type: _,
         mappings,
+        argument_mappings,
     } = relationship_info;
 
     let mut column_mapping = BTreeMap::new();
     for metadata_resolve::RelationshipModelMapping {
         source_field: source_field_path,
+        source_nested_fields,
         target_field: _,
+        target_nested_fields: _,
         target_ndc_column,
     } in mappings
     {
@@ -44,6 +49,12 @@ pub fn process_model_relationship_definition(
             ))
         })?;
 
+        if !source_nested_fields.is_empty() {
+            return Err(PlanError::Internal(format!(
+                "Relationship {relationship_name} on {source_type} maps from a nested field and cannot be a local relationship"
+            )));
+        }
+
         let source_column = metadata_resolve::get_field_mapping_of_field_name(
             source_type_mappings,
             source_type,
@@ -52,8 +63,12 @@ pub fn process_model_relationship_definition(
         )
         .map_err(|e| PlanError::Internal(e.to_string()))?;
 
+        let target_column_path = std::iter::once(target_column.column.clone())
+            .chain(target_column.field_path.iter().cloned())
+            .collect();
+
         if column_mapping
-            .insert(source_column.column, target_column.column.clone())
+            .insert(source_column.column, target_column_path)
             .is_some()
         {
             Err(PlanError::Relationship(
@@ -64,15 +79,62 @@ pub fn process_model_relationship_definition(
             ))?;
         }
     }
+
+    // Arguments of the target model mapped from fields of the source
+    let mut arguments = BTreeMap::new();
+    for argument_mapping in argument_mappings {
+        if !argument_mapping.source_nested_fields.is_empty() {
+            return Err(PlanError::Internal(format!(
+                "Relationship {relationship_name} on {source_type} maps from a nested field and cannot be a local relationship"
+            )));
+        }
+
+        let source_column = metadata_resolve::get_field_mapping_of_field_name(
+            source_type_mappings,
+            source_type,
+            relationship_name,
+            &argument_mapping.source_field.field_name,
+        )
+        .map_err(|e| PlanError::Internal(e.to_string()))?;
+
+        let connector_argument_name = get_model_relationship_ndc_argument_name(
+            source_type,
+            relationship_name,
+            argument_mapping,
+        )
+        .map_err(PlanError::Relationship)?;
+
+        arguments.insert(
+            connector_argument_name.clone(),
+            plan_types::RelationshipArgument::Column {
+                name: source_column.column,
+            },
+        );
+    }
+
     let relationship = Relationship {
         column_mapping,
         relationship_type: relationship_type.clone(),
         target_collection: target_source.collection.clone(),
-        arguments: BTreeMap::new(),
+        arguments,
     };
     Ok(relationship)
 }
 
+fn get_model_relationship_ndc_argument_name<'a>(
+    source_type: &Qualified<CustomTypeName>,
+    relationship_name: &RelationshipName,
+    argument_mapping: &'a metadata_resolve::RelationshipModelArgumentMapping,
+) -> Result<&'a DataConnectorArgumentName, RelationshipError> {
+    argument_mapping.ndc_argument_name.as_ref().ok_or_else(|| {
+        RelationshipError::MissingArgumentMappingInModelRelationship {
+            source_type: source_type.clone(),
+            relationship_name: relationship_name.clone(),
+            argument_name: argument_mapping.argument_name.clone(),
+        }
+    })
+}
+
 pub fn process_command_relationship_definition(
     relationship_info: &LocalCommandRelationshipInfo,
 ) -> Result<Relationship, PlanError> {
@@ -89,9 +151,16 @@ pub fn process_command_relationship_definition(
     let mut arguments = BTreeMap::new();
     for metadata_resolve::RelationshipCommandMapping {
         source_field: source_field_path,
+        source_nested_fields,
         argument_name: target_argument,
     } in mappings
     {
+        if !source_nested_fields.is_empty() {
+            return Err(PlanError::Internal(format!(
+                "Relationship {relationship_name} on {source_type} maps from a nested field and cannot be a local relationship"
+            )));
+        }
+
         let source_column = metadata_resolve::get_field_mapping_of_field_name(
             source_type_mappings,
             source_type,
@@ -141,7 +210,7 @@ pub fn process_command_relationship_definition(
 }
 
 pub struct CommandRemoteRelationshipParts {
-    pub join_mapping: HashMap<FieldName, (SourceFieldAlias, TargetField)>,
+    pub join_mapping: HashMap<SourceFieldPath, (SourceFieldAlias, TargetField)>,
     pub phantom_fields: BTreeMap<NdcFieldAlias, Field>,
     pub arguments: IndexMap<DataConnectorArgumentName, Argument>,
 }
@@ -158,11 +227,13 @@ pub fn calculate_remote_relationship_fields_for_command_target(
     let mut phantom_fields = BTreeMap::new();
     let mut arguments = IndexMap::new();
 
-    for metadata_resolve::RelationshipCommandMapping {
-        source_field,
-        argument_name,
-    } in relationship_command_mappings
-    {
+    for mapping in relationship_command_mappings {
+        let metadata_resolve::RelationshipCommandMapping {
+            source_field,
+            source_nested_fields,
+            argument_name,
+        } = mapping;
+
         let source_column = metadata_resolve::get_field_mapping_of_field_name(
             source_type_mappings,
             object_type_name,
@@ -171,10 +242,17 @@ pub fn calculate_remote_relationship_fields_for_command_target(
         )
         .map_err(RelationshipError::RelationshipFieldMappingError)?;
 
+        let source_nested_columns = metadata_resolve::get_nested_field_columns(
+            source_type_mappings,
+            relationship_name,
+            source_nested_fields,
+        )
+        .map_err(RelationshipError::RelationshipFieldMappingError)?;
+
         let ProcessedRemoteRelationship {
             source_field_alias: ndc_field_alias,
             field: processed_field,
-        } = process_remote_relationship_field_mapping(&source_column.column);
+        } = process_remote_relationship_field_mapping(&source_column.column, source_nested_columns);
 
         phantom_fields.insert(processed_field.0, processed_field.1);
 
@@ -201,7 +279,7 @@ pub fn calculate_remote_relationship_fields_for_command_target(
 
         // add join mapping
         join_mapping.insert(
-            source_field.field_name.clone(),
+            mapping.source_field_path(),
             (
                 ndc_field_alias,
                 TargetField::CommandField(argument_name.clone()),
@@ -217,27 +295,33 @@ pub fn calculate_remote_relationship_fields_for_command_target(
 }
 
 pub struct ModelRemoteRelationshipParts {
-    pub join_mapping: HashMap<FieldName, (SourceFieldAlias, TargetField)>,
+    pub join_mapping: HashMap<SourceFieldPath, (SourceFieldAlias, TargetField)>,
     pub phantom_fields: BTreeMap<NdcFieldAlias, Field>,
     pub relationship_join_filter_expressions: Vec<ResolvedFilterExpression>,
+    pub arguments: IndexMap<DataConnectorArgumentName, Argument>,
 }
 
 pub fn calculate_remote_relationship_fields_for_model_target(
     object_type_name: &Qualified<CustomTypeName>,
     relationship_name: &RelationshipName,
     relationship_model_mappings: &Vec<RelationshipModelMapping>,
+    relationship_model_argument_mappings: &Vec<RelationshipModelArgumentMapping>,
     source_type_mappings: &BTreeMap<Qualified<CustomTypeName>, TypeMapping>,
 ) -> Result<ModelRemoteRelationshipParts, RelationshipError> {
     let mut join_mapping = HashMap::new();
     let mut phantom_fields = BTreeMap::new();
     let mut relationship_join_filter_expressions = vec![];
+    let mut arguments = IndexMap::new();
+
+    for mapping in relationship_model_mappings {
+        let metadata_resolve::RelationshipModelMapping {
+            source_field,
+            source_nested_fields,
+            target_field,
+            target_nested_fields: _,
+            target_ndc_column,
+        } = mapping;
 
-    for metadata_resolve::RelationshipModelMapping {
-        source_field,
-        target_field,
-        target_ndc_column,
-    } in relationship_model_mappings
-    {
         let source_column = metadata_resolve::get_field_mapping_of_field_name(
             source_type_mappings,
             object_type_name,
@@ -246,10 +330,17 @@ pub fn calculate_remote_relationship_fields_for_model_target(
         )
         .map_err(RelationshipError::RelationshipFieldMappingError)?;
 
+        let source_nested_columns = metadata_resolve::get_nested_field_columns(
+            source_type_mappings,
+            relationship_name,
+            source_nested_fields,
+        )
+        .map_err(RelationshipError::RelationshipFieldMappingError)?;
+
         let ProcessedRemoteRelationship {
             source_field_alias: ndc_field_alias,
             field: processed_field,
-        } = process_remote_relationship_field_mapping(&source_column.column);
+        } = process_remote_relationship_field_mapping(&source_column.column, source_nested_columns);
 
         phantom_fields.insert(processed_field.0, processed_field.1);
 
@@ -264,7 +355,7 @@ pub fn calculate_remote_relationship_fields_for_model_target(
 
         // add join mapping
         join_mapping.insert(
-            source_field.field_name.clone(),
+            mapping.source_field_path(),
             (
                 ndc_field_alias,
                 TargetField::ModelField((
@@ -275,15 +366,14 @@ pub fn calculate_remote_relationship_fields_for_model_target(
         );
 
         // add extra comparison to filter for target model
-        let target_value_variable = format!("${}", &target_ndc_column.column);
         let comparison_exp = LocalFieldComparison::BinaryComparison {
             column: ComparisonTarget::Column {
                 name: target_ndc_column.column.clone(),
-                field_path: vec![],
+                field_path: target_ndc_column.field_path.clone(),
             },
             operator: target_ndc_column.equal_operator.clone(),
             value: ComparisonValue::Variable {
-                name: VariableName(target_value_variable),
+                name: make_model_field_variable_name(&target_ndc_column),
             },
         };
 
@@ -293,26 +383,79 @@ pub fn calculate_remote_relationship_fields_for_model_target(
         ));
     }
 
+    for argument_mapping in relationship_model_argument_mappings {
+        let source_column = metadata_resolve::get_field_mapping_of_field_name(
+            source_type_mappings,
+            object_type_name,
+            relationship_name,
+            &argument_mapping.source_field.field_name,
+        )
+        .map_err(RelationshipError::RelationshipFieldMappingError)?;
+
+        let source_nested_columns = metadata_resolve::get_nested_field_columns(
+            source_type_mappings,
+            relationship_name,
+            &argument_mapping.source_nested_fields,
+        )
+        .map_err(RelationshipError::RelationshipFieldMappingError)?;
+
+        let ProcessedRemoteRelationship {
+            source_field_alias: ndc_field_alias,
+            field: processed_field,
+        } = process_remote_relationship_field_mapping(&source_column.column, source_nested_columns);
+
+        phantom_fields.insert(processed_field.0, processed_field.1);
+
+        // add argument referencing variable
+        let data_connector_argument_name = get_model_relationship_ndc_argument_name(
+            object_type_name,
+            relationship_name,
+            argument_mapping,
+        )?;
+        arguments.insert(
+            data_connector_argument_name.clone(),
+            Argument::Variable {
+                name: make_model_argument_variable_name(&argument_mapping.argument_name),
+            },
+        );
+
+        // add join mapping
+        join_mapping.insert(
+            argument_mapping.source_field_path(),
+            (
+                ndc_field_alias,
+                TargetField::ModelArgument(argument_mapping.argument_name.clone()),
+            ),
+        );
+    }
+
     Ok(ModelRemoteRelationshipParts {
         join_mapping,
         phantom_fields,
         relationship_join_filter_expressions,
+        arguments,
     })
 }
 
-struct ProcessedRemoteRelationship {
-    source_field_alias: SourceFieldAlias,
-    field: (NdcFieldAlias, Field),
+pub(crate) struct ProcessedRemoteRelationship {
+    pub(crate) source_field_alias: SourceFieldAlias,
+    pub(crate) field: (NdcFieldAlias, Field),
 }
 
 /// Processes a remote relationship field mapping, and returns the alias used in
-/// the NDC IR for that field
-fn process_remote_relationship_field_mapping(
+/// the NDC IR for that field. When the source field is inside nested objects of
+/// the column, the whole column is selected and the join value is picked out of
+/// it using the nested columns.
+pub(crate) fn process_remote_relationship_field_mapping(
     ndc_column_name: &DataConnectorColumnName,
+    nested_columns: Vec<DataConnectorColumnName>,
 ) -> ProcessedRemoteRelationship {
     let internal_alias = make_hasura_phantom_field(ndc_column_name);
     ProcessedRemoteRelationship {
-        source_field_alias: SourceFieldAlias(internal_alias.clone()),
+        source_field_alias: SourceFieldAlias {
+            alias: internal_alias.clone(),
+            nested_path: nested_columns,
+        },
         field: (
             NdcFieldAlias::from(internal_alias.as_str()),
             Field::Column {
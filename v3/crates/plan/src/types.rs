@@ -3,6 +3,7 @@ use metadata_resolve::Qualified;
 use open_dds::{
     arguments::ArgumentName,
     commands::CommandName,
+    data_connector::DataConnectorName,
     relationships::RelationshipName,
     types::{CustomTypeName, FieldName},
 };
@@ -53,6 +54,12 @@ pub enum RelationshipError {
         command_name: Qualified<CommandName>,
         argument_name: ArgumentName,
     },
+    #[error("Missing argument mapping to the target model data connector source for argument {argument_name} used in relationship {relationship_name} on type {source_type}")]
+    MissingArgumentMappingInModelRelationship {
+        source_type: Qualified<CustomTypeName>,
+        relationship_name: RelationshipName,
+        argument_name: ArgumentName,
+    },
     #[error("Missing NDC column name in relationship {relationship_name} in the mapping between source field {source_field} and target field {target_field}")]
     MissingTargetColumn {
         relationship_name: RelationshipName,
@@ -75,12 +82,16 @@ impl TraceableError for RelationshipError {
 
 #[derive(Debug, thiserror::Error)]
 pub enum OrderByError {
-    #[error("Aggregate relationship {0} is not supported in order_by")]
-    RelationshipAggregateNotSupported(RelationshipName),
+    #[error(
+        "Cannot order by an aggregate over relationship {0} as it is not an array relationship"
+    )]
+    RelationshipAggregateOnNonArrayRelationship(RelationshipName),
     #[error("{0}")]
     RemoteRelationshipNotSupported(String),
     #[error("Nested order by is not supported: {0}")]
     NestedOrderByNotSupported(String),
+    #[error("The data connector {0} does not support placing nulls first or last when ordering")]
+    NullsOrderNotSupported(Qualified<DataConnectorName>),
     #[error("An internal error occurred in order_by: {0}")]
     Internal(String),
 }
@@ -94,8 +105,9 @@ impl OrderByError {
 impl TraceableError for OrderByError {
     fn visibility(&self) -> ErrorVisibility {
         match self {
-            Self::RelationshipAggregateNotSupported(_)
+            Self::RelationshipAggregateOnNonArrayRelationship(_)
             | Self::NestedOrderByNotSupported(_)
+            | Self::NullsOrderNotSupported(_)
             | Self::RemoteRelationshipNotSupported(_) => ErrorVisibility::User,
             Self::Internal(_) => ErrorVisibility::Internal,
         }